@@ -1,7 +0,0 @@
-/// A module for managing and handling text parsing functions, the current functions are used for
-/// parsing and analyzing different aspects of the VCF text files  
-///``` 
-///``` 
-pub mod text_parser; 
-pub mod vcf_tools; 
-pub mod summary;
\ No newline at end of file
@@ -1,5 +0,0 @@
-/// a collection of high level assemblies that can either be used in a standalone version or in integration tests 
-/// 
-pub mod io; 
-pub mod exec; 
-pub mod cli; 
\ No newline at end of file
@@ -0,0 +1,1891 @@
+use clap::{Arg, App, AppSettings, ArgMatches};
+use core::panic;
+use std::{path::Path, str::FromStr, sync::{Arc,Mutex}};
+use ppgg::data_structures::InternalRep::engines::{Engine,EngineSpec,THREADS_ENV_VAR}; 
+use ppgg::data_structures::csq_map::CsqMap;
+use ppgg::data_structures::id_map::IdMap;
+use ppgg::data_structures::transcript_filter::TranscriptFilter;
+use ppgg::data_structures::consequence_selection::ConsequenceSelection;
+use ppgg::functions::sample_selection::SampleSelection;
+use ppgg::functions::somatic::SomaticMode;
+use ppgg::functions::quality_filters::QualityFilters;
+use ppgg::data_structures::InternalRep::consensus::ConsensusRule;
+use ppgg::data_structures::InternalRep::engine_selection::{select_engine,InputSize,SystemResources};
+use ppgg::data_structures::InternalRep::gir::{PlaceholderPolicy,PLACEHOLDER_RESIDUE};
+use ppgg::parts::exec::ExecutionOptions;
+use ppgg::writers::{RetryPolicy,BufferPolicy,BufferSizing,OutputQuota,FastaLayout,NameTemplate,OutputLayout,CompressionFormat,CompressionPolicy,OutputPolicy,WriterOptions};
+use ppgg::data_structures::InternalRep::personalized_genome::UnmodifiedHaplotypes;
+use ppgg::data_structures::InternalRep::sequence_pool::PoolMembers;
+use ppgg::data_structures::InternalRep::sequence_tape::StopCodonPolicy;
+use ppgg::data_structures::InternalRep::transcript_instructions::OverlapPolicy;
+use ppgg::functions::contigs::ContigPolicy;
+use ppgg::functions::missing_genotypes::MissingGenotypePolicy;
+use ppgg::functions::silent_variants::SilentVariantPolicy;
+use ppgg::data_structures::vcf_ds::{DecodingOptions,HaplotypeSource,MultiAllelicPolicy,UnphasedPolicy};
+use ppgg::functions::sex_chromosomes::ParBuild;
+use ppgg::functions::smoke::SmokeConfig;
+use ppgg::functions::progress::{ProgressMode,ProgressReporter,ProgressTracker};
+use ppgg::functions::diagnostics::{CheckPolicy,DebugFilter,DiagnosticOptions,LogFormat};
+use ppgg::functions::metrics::{MetricsAccumulator,NUM_SHARDS};
+use log::LevelFilter;
+use ppgg::writers::{ChangesFormat,IntMapFormat};
+use ppgg::readers::{self,ParseOptions};
+use ppgg::readers::tabix::Region;
+
+/// ## Summary 
+/// A generic representation for the parsed input parameters used by the executable 
+#[derive(Debug,Clone)]
+pub struct ParsedInput
+{
+    pub path2vcf:String, 
+    pub path2maf:Option<String>,
+    pub paths2mutation_tsv:Vec<String>,
+    pub path2fasta:String,
+    pub res_path:String,
+    pub engine:Engine, 
+    pub compute_state:bool,
+    pub is_verbose:bool,
+    pub progress_mode:ProgressMode,
+    pub log_level:LevelFilter,
+    pub log_format:LogFormat,
+    pub write_i_map:bool,
+    pub write_all:bool,
+    pub emit_reference:bool,
+    pub write_compressed:bool,
+    pub write_single_thread:bool,
+    pub min_length:usize,
+    pub csq_map:Option<CsqMap>,
+    pub write_gff:bool,
+    pub write_changes:Option<ChangesFormat>,
+    pub peptide_window:Option<usize>,
+    pub pool_sequences:Option<PoolMembers>,
+    pub consensus:Option<ConsensusRule>,
+    pub path2genome:Option<String>,
+    pub aggregate_only:bool,
+    pub min_carriers:u64,
+    pub path2append_fasta:Option<String>,
+    pub only_transcript:Option<String>,
+    pub trace:bool,
+    pub write_hgvs:bool,
+    pub hgvs_headers:bool,
+    pub gene_report:bool,
+    pub write_sample_vcfs:bool,
+    pub missing_transcripts_threshold:Option<f64>,
+    pub preflight_positions:Option<usize>,
+    pub status_exit_codes:bool,
+    pub sort_output:bool,
+    pub unmodified_haplotypes:UnmodifiedHaplotypes,
+    pub count_only:bool,
+    pub contig_policy:ContigPolicy,
+    pub missing_gt_policy:MissingGenotypePolicy,
+    pub incremental:bool,
+    pub resume:bool,
+    pub int_map_format:IntMapFormat,
+    pub path2sample_sex:Option<String>,
+    pub stream_chunk_size:Option<usize>,
+    pub regions:Vec<Region>,
+    pub par_build:ParBuild,
+    pub skip_errors:bool,
+    pub coordinate_audit:bool,
+    pub validate:bool,
+    pub smoke:Option<SmokeConfig>,
+    pub pipeline_capacity:Option<usize>,
+    pub parse_options:ParseOptions,
+    pub execution_options:ExecutionOptions,
+    pub writer_options:WriterOptions,
+    /// the progress of the run, which is shared by the parse, the execution and the writer options 
+    pub progress:Arc<ProgressTracker>,
+    /// the reporter of the progress, see start_reporter, which prints its last report when it is finished or dropped 
+    reporter:Arc<Mutex<Option<ProgressReporter>>>
+}
+impl ParsedInput
+{
+    pub fn new(args:ArgMatches)->Self
+    {
+        // parse the path 2 MAF file, which replaces the VCF file, hence, the path to the VCF file is left empty 
+        let path2maf=args.value_of("maf_file").map(|path2file|path2file.to_string()); 
+        if let Some(path2file)=path2maf.as_ref()
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the MAF file: {} does not exists",path2file)
+            }
+        }
+        // parse the paths 2 the mutation lists, which replace the VCF file as well 
+        let paths2mutation_tsv=match args.values_of("mutation_tsv")
+        {
+            Some(paths2file)=>paths2file.map(|path2file|path2file.to_string()).collect::<Vec<String>>(),
+            None=>Vec::new()
+        };
+        for path2file in paths2mutation_tsv.iter()
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the mutation list: {} does not exists",path2file)
+            }
+        }
+        // the flag of the input that replaces the VCF file, if any, used to reject the options that work on the records of a VCF file 
+        let vcf_free_input=match (path2maf.is_some(),paths2mutation_tsv.is_empty())
+        {
+            (true,_)=>Some("maf_file"),
+            (false,false)=>Some("mutation_tsv"),
+            (false,true)=>None
+        };
+        // parse the path 2 VCF files‚
+        let path2vcf= match (args.value_of("vcf_file"),vcf_free_input)
+        {
+            (Some(path2file),_)=>path2file.to_string(),
+            (None,Some(_))=>String::new(),
+            (None,None)=>panic!("Path to the VCF file has not been provided")
+        }; 
+        if vcf_free_input.is_none() && !(Path::new(&path2vcf).exists())
+        {
+            panic!("The provided path to the VCF file: {} does not exists",path2vcf)
+        }
+        // parse the path 2 fasta file 
+        let path2fasta= match args.value_of("fasta_ref")
+        {
+            Some(path2file)=>path2file.to_string(),
+            None=>panic!("Path to the fasta file has not been provided")
+        }; 
+        if !(Path::new(&path2fasta).exists())
+        {
+            panic!("The provided path to the fasta file: {} does not exists",path2fasta)
+        }
+        // check the output directory exists
+        let res_path= match args.value_of("output_path")
+        {
+            Some(path2file)=>path2file.to_string(),
+            None=>panic!("Path to the fasta file has not been provided")
+        }; 
+        if !(Path::new(&res_path).exists())
+        {
+            panic!("The provided path to write the results: {} does not exists",path2fasta)
+        }
+        // the smoke mode writes a fixed set of files, hence, the options reading the whole VCF file or changing the written files are rejected 
+        let smoke=match args.is_present("smoke")
+        {
+            true=>
+            {
+                for flag in ["maf_file","mutation_tsv","stream_vcf","region","incremental","resume","aggregate_only","count_only","write_compressed","genome_fasta",
+                    "gene_report","write_sample_vcfs","sample_sex","stats"].iter()
+                {
+                    if args.is_present(flag)
+                    {
+                        panic!("--{} can not be used with --smoke, which only processes the first records and samples of the VCF file and writes a fixed set of files",flag)
+                    }
+                }
+                let parse_smoke_size=|name:&str|match args.value_of(name).unwrap().parse::<usize>()
+                {
+                    Ok(0)=>panic!("The value of --{} must be at least 1",name),
+                    Ok(size)=>size,
+                    Err(err_msg)=>panic!("The provided value of --{}: {} is not a valid positive integer, parsing it failed with: {}",name,args.value_of(name).unwrap(),err_msg)
+                };
+                Some(SmokeConfig{num_records:parse_smoke_size("smoke_records"),num_samples:parse_smoke_size("smoke_samples")})
+            },
+            false=>
+            {
+                if args.occurrences_of("smoke_records")!=0 || args.occurrences_of("smoke_samples")!=0
+                {
+                    panic!("--smoke_records and --smoke_samples require the smoke mode to be switched on using --smoke")
+                }
+                None
+            }
+        };
+        // now store the value of the flags, an automatic engine is resolved from the size of the VCF file and the available resources  
+        let engine_spec=parse_engine_spec(&args); 
+        let engine=match engine_spec.get_engine()
+        {
+            // the smoke mode is always executed with the single-thread engine, which is deterministic and fast enough for its input 
+            _ if smoke.is_some()=>Engine::ST,
+            engine @ (Engine::MT | Engine::ST)=>engine,
+            Engine::Auto if vcf_free_input.is_some()=>panic!("The automatic engine selection sizes the input from the VCF file, use the single-thread (st) or the multi-thread (mt) engine with --{}",vcf_free_input.unwrap()),
+            Engine::Auto=>
+            {
+                let input_size=match InputSize::from_vcf(Path::new(&path2vcf))
+                {
+                    Ok(input_size)=>input_size,
+                    Err(err_msg)=>panic!("Selecting the engine automatically failed with the following error: {}",err_msg)
+                };
+                // the selection is sized for the requested number of worker threads, if any 
+                let mut resources=SystemResources::detect(); 
+                resources.num_cores=engine_spec.get_num_threads().unwrap_or(resources.num_cores); 
+                let selection=select_engine(&input_size, &resources, false); 
+                println!("Automatic engine selection: {}",selection.reason); 
+                selection.engine
+            },
+            Engine::GPU=> panic!("The current version is a CPU-only version with a single-thread (st) and multi-thread (mt) versions only, \
+             however, you asked for a GPU engine, which is not supported in this version. check the project web-page at: https://github.com/ikmb/ppg for more details.")
+        }; 
+        /* write_e_map:bool, write_i_map:bool */
+        let compute_state=args.is_present("stats"); 
+        let is_verbose=args.is_present("verbose");
+        let progress_mode=match ProgressMode::from_str(args.value_of("progress").unwrap())
+        {
+            Ok(mode)=>mode,
+            Err(err_msg)=>panic!("Parsing the progress mode failed with the following error: {}",err_msg)
+        };
+        let log_format=match LogFormat::from_str(args.value_of("log_format").unwrap())
+        {
+            Ok(format)=>format,
+            Err(err_msg)=>panic!("Parsing the log format failed with the following error: {}",err_msg)
+        };
+        // the validation pass excludes the offending transcripts instead of stopping the run, hence, the checks only warn unless requested otherwise 
+        let validate=args.is_present("validate"); 
+        let check_policy=match CheckPolicy::from_str(args.value_of("checks").unwrap())
+        {
+            Ok(_) if validate && args.occurrences_of("checks")==0=>CheckPolicy::Warn,
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("Parsing the check policy failed with the following error: {}",err_msg)
+        };
+        let overlap_policy=args.value_of("overlap_policy").map(|policy|match OverlapPolicy::from_str(policy)
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        }); 
+        // the detailed logs of the debugged transcripts are printed at the debug level, hence, it is implied by --debug_transcript 
+        let debug_transcripts=match args.values_of("debug_transcript")
+        {
+            Some(transcripts)=>transcripts.map(|transcript|transcript.to_string()).collect::<Vec<String>>(),
+            None=>Vec::new()
+        };
+        let log_level=match LevelFilter::from_str(args.value_of("log_level").unwrap())
+        {
+            Ok(level) if !debug_transcripts.is_empty()=>level.max(LevelFilter::Debug),
+            Ok(level)=>level,
+            Err(err_msg)=>panic!("Parsing the log level failed with the following error: {}",err_msg)
+        };
+        let debug_filter=DebugFilter::new(debug_transcripts); 
+        let write_i_map=args.is_present("write_int_map"); 
+        let int_map_format=match IntMapFormat::from_str(args.value_of("int_map_format").unwrap())
+        {
+            Ok(format)=>format,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        if args.occurrences_of("int_map_format")!=0 && !write_i_map
+        {
+            panic!("--int_map_format requires the intermediate maps to be written using --write_int_map")
+        }
+        // the reference transcripts are only emitted along with the reference sequences, hence, --emit_reference implies --write_all_proteins 
+        let emit_reference=args.is_present("emit_reference"); 
+        let write_all=args.is_present("write_all_proteins") || emit_reference; 
+        let stop_codon_policy=args.value_of("stop_codon").map(|policy|match StopCodonPolicy::from_str(policy)
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        }); 
+        let write_compressed = args.is_present("write_compressed");
+        let write_single_thread = args.is_present("write_single_thread");
+        let compression_format=match CompressionFormat::from_str(args.value_of("compression").unwrap())
+        {
+            Ok(format)=>format,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let compression_level=match args.value_of("compression_level").unwrap().parse::<u32>()
+        {
+            Ok(level)=>level,
+            Err(err_msg)=>panic!("The provided compression level: {} is not a valid integer, parsing it failed with: {}",args.value_of("compression_level").unwrap(),err_msg)
+        };
+        let compression_policy=match CompressionPolicy::new(compression_format, compression_level)
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        for flag in ["compression","compression_level"].iter()
+        {
+            if args.occurrences_of(flag)!=0 && !write_compressed
+            {
+                panic!("--{} requires --write_compressed, as it only applies to the compressed fasta files",flag)
+            }
+        }
+        let write_gff = args.is_present("write_gff");
+        let write_changes= match args.value_of("write_changes")
+        {
+            Some(format)=>match ChangesFormat::from_str(format)
+            {
+                Ok(format)=>Some(format),
+                Err(err_msg)=>panic!("Parsing the format of the applied changes failed with the following error: {}",err_msg)
+            },
+            None=>None
+        };
+        let write_hgvs = args.is_present("hgvs");
+        let hgvs_headers = args.is_present("hgvs_headers");
+        let gene_report = args.is_present("gene_report");
+        let write_sample_vcfs = args.is_present("write_sample_vcfs");
+        let min_length= match args.value_of("min_length")
+        {
+            Some(min_length)=>match min_length.parse::<usize>()
+            {
+                Ok(min_length)=>min_length,
+                Err(err_msg)=>panic!("The provided minimum length: {} is not a valid non-negative integer, parsing it failed with: {}",min_length,err_msg)
+            },
+            None=>0
+        };
+        // load the user-supplied consequence map 
+        let csq_map= match args.value_of("csq_map")
+        {
+            Some(path2file)=>
+            {
+                if !(Path::new(path2file).exists())
+                {
+                    panic!("The provided path to the consequence map: {} does not exists",path2file)
+                }
+                match CsqMap::from_file(Path::new(path2file))
+                {
+                    Ok(csq_map)=>Some(csq_map),
+                    Err(err_msg)=>panic!("Loading the consequence map failed with the following error: {}",err_msg)
+                }
+            },
+            None=>None
+        };
+        // load the user-supplied map between the transcript ids and the protein ids 
+        let id_map= match args.value_of("id_map")
+        {
+            Some(path2file)=>
+            {
+                if !(Path::new(path2file).exists())
+                {
+                    panic!("The provided path to the id map: {} does not exists",path2file)
+                }
+                match IdMap::from_file(Path::new(path2file))
+                {
+                    Ok(id_map)=>Some(id_map),
+                    Err(err_msg)=>panic!("Loading the id map failed with the following error: {}",err_msg)
+                }
+            },
+            None=>None
+        };
+        // load the lists of the included and excluded transcripts and genes 
+        let [include_transcripts,include_genes,exclude_transcripts,exclude_genes]=["include_transcripts","include_genes","exclude_transcripts","exclude_genes"]
+            .map(|flag|match args.value_of(flag)
+            {
+                Some(path2file)=>
+                {
+                    if !(Path::new(path2file).exists())
+                    {
+                        panic!("The provided path to --{}: {} does not exists",flag,path2file)
+                    }
+                    match TranscriptFilter::read_ids(Path::new(path2file))
+                    {
+                        Ok(ids)=>ids,
+                        Err(err_msg)=>panic!("Loading the ids of --{} failed with the following error: {}",flag,err_msg)
+                    }
+                },
+                None=>Vec::new()
+            });
+        let transcript_filter=TranscriptFilter::new(include_transcripts,include_genes,exclude_transcripts,exclude_genes); 
+        // parse the included and excluded consequence types 
+        let [included_types,excluded_types]=["consequences","exclude_consequences"]
+            .map(|flag|args.value_of(flag).map(ConsequenceSelection::parse_list).unwrap_or_default());
+        let consequence_selection=match ConsequenceSelection::new(included_types,excluded_types)
+        {
+            Ok(consequence_selection)=>consequence_selection,
+            Err(err_msg)=>panic!("Parsing --consequences or --exclude_consequences failed with the following error: {}",err_msg)
+        };
+        // only the columns of the selected samples are parsed, the samples of the list and of the file are combined 
+        let mut samples=match args.value_of("samples")
+        {
+            Some(list)=>match SampleSelection::from_list(list)
+            {
+                Ok(selection)=>selection.get_samples().to_vec(),
+                Err(err_msg)=>panic!("Parsing --samples failed with the following error: {}",err_msg)
+            },
+            None=>Vec::new()
+        };
+        if let Some(path2file)=args.value_of("samples_file")
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the samples file: {} does not exists",path2file)
+            }
+            match SampleSelection::from_file(Path::new(path2file))
+            {
+                Ok(selection)=>samples.extend(selection.get_samples().iter().cloned()),
+                Err(err_msg)=>panic!("Loading the samples file failed with the following error: {}",err_msg)
+            }
+        }
+        let sample_selection=match samples.is_empty()
+        {
+            true=>None,
+            false=>Some(SampleSelection::new(samples))
+        };
+        // the somatic mode processes the tumor sample only, hence, the tumor replaces the sample selection 
+        let somatic_mode=match (args.value_of("tumor"),args.value_of("normal"))
+        {
+            (Some(tumor),normal)=>
+            {
+                if sample_selection.is_some()
+                {
+                    panic!("--tumor can not be used with --samples or --samples_file, as the tumor is the only processed sample")
+                }
+                match SomaticMode::new(tumor.to_string(),normal.map(|normal|normal.to_string()))
+                {
+                    Ok(somatic_mode)=>Some(somatic_mode),
+                    Err(err_msg)=>panic!("Parsing the somatic mode failed with the following error: {}",err_msg)
+                }
+            },
+            (None,Some(_))=>panic!("--normal can only be used with --tumor, as the normal sample is used for removing the germline variants of the tumor"),
+            (None,None)=>None
+        };
+        let sample_selection=match somatic_mode.as_ref()
+        {
+            Some(somatic_mode)=>Some(SampleSelection::new(vec![somatic_mode.get_tumor().to_string()])),
+            None=>sample_selection
+        };
+        // the low-quality records and calls are filtered while the VCF file is read 
+        let [min_dp,min_gq]=[("min_dp","depth"),("min_gq","genotype quality")].map(|(flag,name)|match args.value_of(flag)
+        {
+            Some(minimum)=>match minimum.parse::<u32>()
+            {
+                Ok(minimum)=>Some(minimum),
+                Err(err_msg)=>panic!("The provided minimum {}: {} is not a valid positive integer, parsing it failed with: {}",name,minimum,err_msg)
+            },
+            None=>None
+        });
+        let [min_af,max_af]=["min_af","max_af"].map(|flag|match args.value_of(flag)
+        {
+            Some(frequency)=>match frequency.parse::<f64>()
+            {
+                Ok(frequency) if (0.0..=1.0).contains(&frequency)=>Some(frequency),
+                _=>panic!("The provided allele frequency of --{}: {} is not a valid frequency between 0 and 1",flag,frequency)
+            },
+            None=>None
+        });
+        if let (Some(min_af),Some(max_af))=(min_af,max_af)
+        {
+            if min_af>max_af
+            {
+                panic!("The minimum allele frequency: {} is larger than the maximum allele frequency: {}",min_af,max_af)
+            }
+        }
+        let af_key=args.value_of("af_key").map(|af_key|af_key.to_string());
+        if af_key.is_some() && min_af.is_none() && max_af.is_none()
+        {
+            panic!("--af_key can only be used with --min_af or --max_af")
+        }
+        let quality_filters=QualityFilters{min_dp,min_gq,pass_only:args.is_present("pass_only"),min_af,max_af,af_key};
+        // parse the consensus rule 
+        let consensus= match args.value_of("consensus")
+        {
+            Some(rule)=>match ConsensusRule::from_str(rule)
+            {
+                Ok(rule)=>Some(rule),
+                Err(err_msg)=>panic!("Parsing the consensus rule failed with the following error: {}",err_msg)
+            },
+            None=>None
+        };
+        // check the genome used for validating the consequences exists 
+        let path2genome=args.value_of("genome_fasta").map(|path2file|path2file.to_string()); 
+        if let Some(path2file)=path2genome.as_ref()
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the genome fasta file: {} does not exists",path2file)
+            }
+        }
+        let sort_output=args.is_present("sort_output") || smoke.is_some(); 
+        // unmodified haplotypes are only written with --write_all_proteins, hence, the policy can not be applied otherwise 
+        let unmodified_haplotypes=match UnmodifiedHaplotypes::from_str(args.value_of("unmodified_haplotypes").unwrap())
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        if unmodified_haplotypes!=UnmodifiedHaplotypes::Write && !write_all
+        {
+            panic!("--unmodified_haplotypes {} requires --write_all_proteins, as unmodified haplotypes are only written along with the reference sequences",
+                args.value_of("unmodified_haplotypes").unwrap())
+        }
+        let incremental=args.is_present("incremental"); 
+        let resume=args.is_present("resume"); 
+        if resume && incremental
+        {
+            panic!("--resume can not be used with --incremental, --resume only writes the samples whose files failed in the previous run")
+        }
+        let max_retries=match args.value_of("write_retries").unwrap().parse::<u32>()
+        {
+            Ok(max_retries)=>max_retries,
+            Err(err_msg)=>panic!("The provided number of write retries: {} is not a valid positive integer, parsing it failed with: {}",
+                args.value_of("write_retries").unwrap(),err_msg)
+        };
+        let backoff_ms=match args.value_of("write_retry_backoff").unwrap().parse::<u64>()
+        {
+            Ok(backoff_ms)=>backoff_ms,
+            Err(err_msg)=>panic!("The provided waiting time between write retries: {} is not a valid number of milliseconds, parsing it failed with: {}",
+                args.value_of("write_retry_backoff").unwrap(),err_msg)
+        };
+        let retry_policy=RetryPolicy{max_retries,backoff_ms}; 
+        let sizing=match args.value_of("output_buffer_size").unwrap()
+        {
+            "auto" | "AUTO"=>BufferSizing::Auto,
+            buffer_size=>match buffer_size.parse::<usize>()
+            {
+                Ok(0)=>panic!("The size of the output buffers must be at least one byte"),
+                Ok(buffer_size)=>BufferSizing::Fixed(buffer_size),
+                Err(err_msg)=>panic!("The provided size of the output buffers: {} is neither auto nor a valid number of bytes, parsing it failed with: {}",
+                    buffer_size,err_msg)
+            }
+        };
+        let max_buffer_size=match args.value_of("max_output_buffer_size").unwrap().parse::<usize>()
+        {
+            Ok(max_buffer_size)=>max_buffer_size,
+            Err(err_msg)=>panic!("The provided maximum size of the output buffers: {} is not a valid number of bytes, parsing it failed with: {}",
+                args.value_of("max_output_buffer_size").unwrap(),err_msg)
+        };
+        let buffer_policy=BufferPolicy{sizing,max_buffer_size,preallocate:args.is_present("preallocate_output")}; 
+        let parse_quota=|name:&str|match args.value_of(name)
+        {
+            Some(num_bytes)=>match num_bytes.parse::<u64>()
+            {
+                Ok(num_bytes)=>Some(num_bytes),
+                Err(err_msg)=>panic!("The provided output quota: {} is not a valid number of bytes, parsing it failed with: {}",num_bytes,err_msg)
+            },
+            None=>None
+        };
+        let output_quota=OutputQuota{max_sample_bytes:parse_quota("max_sample_output_size"),max_total_bytes:parse_quota("max_total_output_size")}; 
+        let placeholder_residue=match args.value_of("placeholder_residue")
+        {
+            Some(residue) if residue.chars().count()==1=>residue.chars().next().unwrap(),
+            Some(residue)=>panic!("The provided placeholder residue: {} is not a single character",residue),
+            None=>PLACEHOLDER_RESIDUE
+        };
+        let placeholder_policy=match PlaceholderPolicy::new(placeholder_residue, !args.is_present("no_strict_placeholder_check"))
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        // in the aggregate-only mode per-sample outputs can not be requested 
+        let aggregate_only=args.is_present("aggregate_only"); 
+        if aggregate_only
+        {
+            for (is_requested,flag) in [(compute_state,"stats"),(write_i_map,"write_int_map"),(write_gff,"write_gff"),(write_changes.is_some(),"write_changes"),(consensus.is_some(),"consensus"),
+                (write_hgvs,"hgvs"),(hgvs_headers,"hgvs_headers"),(sort_output,"sort_output"),
+                (unmodified_haplotypes!=UnmodifiedHaplotypes::Write,"unmodified_haplotypes"),(incremental,"incremental"),(resume,"resume"),
+                (write_sample_vcfs,"write_sample_vcfs")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} writes per-sample files, which is not allowed with --aggregate_only",flag)
+                }
+            }
+        }
+        let output_policy=match aggregate_only
+        {
+            true=>OutputPolicy::AggregateOnly,
+            false=>OutputPolicy::PerSample
+        };
+        let min_carriers= match args.value_of("min_carriers")
+        {
+            Some(min_carriers)=>match min_carriers.parse::<u64>()
+            {
+                Ok(0)=>panic!("The minimum number of carriers must be at least 1"),
+                Ok(min_carriers)=>min_carriers,
+                Err(err_msg)=>panic!("The provided minimum number of carriers: {} is not a valid positive integer, parsing it failed with: {}",min_carriers,err_msg)
+            },
+            None=>5
+        };
+        // check the fasta file with the custom records exists 
+        let path2append_fasta=args.value_of("append_fasta").map(|path2file|path2file.to_string()); 
+        if let Some(path2file)=path2append_fasta.as_ref()
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the fasta file with the records to append: {} does not exists",path2file)
+            }
+        }
+        // the trace is only defined for a single transcript 
+        let only_transcript=args.value_of("only_transcript").map(|transcript|transcript.to_string()); 
+        let trace=args.is_present("trace"); 
+        if trace && only_transcript.is_none()
+        {
+            panic!("--trace requires the transcript to trace to be provided using --only_transcript")
+        }
+        // in the count-only mode the sequences are neither generated nor written 
+        let count_only=args.is_present("count_only"); 
+        if count_only
+        {
+            for (is_requested,flag) in [(compute_state,"stats"),(write_i_map,"write_int_map"),(write_gff,"write_gff"),(write_changes.is_some(),"write_changes"),(consensus.is_some(),"consensus"),
+                (write_hgvs,"hgvs"),(hgvs_headers,"hgvs_headers"),(gene_report,"gene_report"),(sort_output,"sort_output"),(aggregate_only,"aggregate_only"),(trace,"trace"),
+                (incremental,"incremental"),(resume,"resume"),(write_sample_vcfs,"write_sample_vcfs"),(args.is_present("skip_errors"),"skip_errors"),
+                (args.is_present("coordinate_audit"),"coordinate_audit"),(validate,"validate"),(args.is_present("preflight"),"preflight")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --count_only, which neither generates nor writes the personalized sequences",flag)
+                }
+            }
+        }
+        let missing_transcripts_threshold=match args.value_of("fail_on_missing_transcripts_threshold")
+        {
+            Some(threshold)=>match threshold.parse::<f64>()
+            {
+                Ok(threshold) if (0.0..=100.0).contains(&threshold) =>Some(threshold),
+                Ok(threshold)=>panic!("The provided threshold of missing transcripts: {} is not a percentage between 0 and 100",threshold),
+                Err(err_msg)=>panic!("The provided threshold of missing transcripts: {} is not a valid number, parsing it failed with: {}",threshold,err_msg)
+            },
+            None=>None
+        };
+        // the pre-flight check compares the reference residues of the first positions of each transcript to the reference proteome 
+        let preflight_positions=match (args.is_present("preflight"),args.value_of("preflight_positions").unwrap().parse::<usize>())
+        {
+            (true,Ok(positions)) if positions!=0=>Some(positions),
+            (true,Ok(_))=>panic!("The number of positions checked per transcript by the pre-flight check must be positive"),
+            (_,Err(err_msg))=>panic!("The provided number of positions per transcript: {} is not a valid positive integer, parsing it failed with: {}",
+                args.value_of("preflight_positions").unwrap(),err_msg),
+            (false,Ok(_)) if args.occurrences_of("preflight_positions")!=0=>panic!("--preflight_positions requires the pre-flight check to be requested using --preflight"),
+            (false,Ok(_))=>None
+        };
+        let status_exit_codes=args.is_present("status_exit_codes"); 
+        let contig_policy=match args.is_present("include_non_primary_contigs")
+        {
+            true=>ContigPolicy::All,
+            false=>ContigPolicy::SkipNonPrimary
+        };
+        let missing_gt_policy=match MissingGenotypePolicy::from_str(args.value_of("missing_gt").unwrap())
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let silent_variant_policy=match SilentVariantPolicy::from_str(args.value_of("silent_variants").unwrap())
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let multi_allelic_policy=match MultiAllelicPolicy::from_str(args.value_of("multi_allelic").unwrap())
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let haplotype_source=match HaplotypeSource::from_str(args.value_of("haplotype_source").unwrap())
+        {
+            Ok(source)=>source,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let unphased_policy=match UnphasedPolicy::from_str(args.value_of("unphased").unwrap())
+        {
+            Ok(policy)=>policy,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        // check the sample-sex table exists 
+        let path2sample_sex=args.value_of("sample_sex").map(|path2file|path2file.to_string()); 
+        if let Some(path2file)=path2sample_sex.as_ref()
+        {
+            if !(Path::new(path2file).exists())
+            {
+                panic!("The provided path to the sample-sex table: {} does not exists",path2file)
+            }
+        }
+        // the MAF records and the mutation lists carry the protein changes directly, hence, the options working on the records or the genotypes of a VCF file are rejected 
+        if let Some(input_flag)=vcf_free_input
+        {
+            for (is_requested,flag) in [(path2genome.is_some(),"genome_fasta"),(path2sample_sex.is_some(),"sample_sex"),(gene_report,"gene_report"),
+                (write_sample_vcfs,"write_sample_vcfs"),(csq_map.is_some(),"csq_map"),(!transcript_filter.is_empty(),"include_transcripts, --include_genes, --exclude_transcripts or --exclude_genes"),
+                (!consequence_selection.is_empty(),"consequences or --exclude_consequences"),
+                (sample_selection.is_some(),"samples, --samples_file or --tumor"),(!quality_filters.is_empty(),"min_dp, --min_gq, --pass_only, --min_af or --max_af"),(contig_policy==ContigPolicy::All,"include_non_primary_contigs"),(args.occurrences_of("missing_gt")!=0,"missing_gt"),
+                (silent_variant_policy!=SilentVariantPolicy::Skip,"silent_variants"),
+                (args.occurrences_of("multi_allelic")!=0,"multi_allelic"),(args.occurrences_of("haplotype_source")!=0,"haplotype_source"),
+                (args.occurrences_of("unphased")!=0,"unphased")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --{}, as it works on the records of a VCF file",flag,input_flag)
+                }
+            }
+        }
+        // the VCF file is streamed in chunks of records instead of being loaded at once 
+        let stream_chunk_size=match (args.is_present("stream_vcf"),args.value_of("stream_chunk_size"))
+        {
+            (true,Some(chunk_size))=>match chunk_size.parse::<usize>()
+            {
+                Ok(0)=>panic!("The number of records per chunk must be at least 1"),
+                Ok(chunk_size)=>Some(chunk_size),
+                Err(err_msg)=>panic!("The provided number of records per chunk: {} is not a valid positive integer, parsing it failed with: {}",chunk_size,err_msg)
+            },
+            (true,None)=>Some(readers::DEFAULT_STREAM_CHUNK_SIZE),
+            (false,Some(_))=>panic!("--stream_chunk_size requires the VCF file to be streamed using --stream_vcf"),
+            (false,None)=>None
+        };
+        if let (Some(_),Some(input_flag))=(stream_chunk_size,vcf_free_input)
+        {
+            panic!("--stream_vcf can not be used with --{}, as it works on the records of a VCF file",input_flag)
+        }
+        // the options below read the whole VCF file a second time, which defeats streaming it 
+        if stream_chunk_size.is_some()
+        {
+            for (is_requested,flag) in [(path2sample_sex.is_some(),"sample_sex"),(write_sample_vcfs,"write_sample_vcfs"),(path2genome.is_some(),"genome_fasta")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --stream_vcf, as it loads the whole VCF file into memory",flag)
+                }
+            }
+        }
+        // only the records overlapping the regions are read through the index of the VCF file
+        let regions=match args.values_of("region")
+        {
+            Some(regions)=>regions.map(|region|match Region::from_str(region)
+            {
+                Ok(region)=>region,
+                Err(err_msg)=>panic!("Parsing the region failed with the following error: {}",err_msg)
+            }).collect::<Vec<Region>>(),
+            None=>Vec::new()
+        };
+        if let (false,Some(input_flag))=(regions.is_empty(),vcf_free_input)
+        {
+            panic!("--region can not be used with --{}, as it works on the records of an indexed VCF file",input_flag)
+        }
+        if !regions.is_empty() && stream_chunk_size.is_some()
+        {
+            panic!("--region can not be used with --stream_vcf, the records of the regions are fetched through the index of the VCF file instead")
+        }
+        let par_build=match ParBuild::from_str(args.value_of("par_build").unwrap())
+        {
+            Ok(build)=>build,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        if args.occurrences_of("par_build")!=0 && path2sample_sex.is_none()
+        {
+            panic!("--par_build requires a sample-sex table to be provided using --sample_sex")
+        }
+        // with a pipeline the genomes are written while the execution is running, hence, the options that need all genomes at once are rejected 
+        let pipeline_capacity=match args.value_of("pipeline_capacity")
+        {
+            Some(capacity)=>match capacity.parse::<usize>()
+            {
+                Ok(0)=>panic!("The capacity of the pipeline must be at least 1"),
+                Ok(capacity)=>Some(capacity),
+                Err(err_msg)=>panic!("The provided pipeline capacity: {} is not a valid positive integer, parsing it failed with: {}",capacity,err_msg)
+            },
+            None=>None
+        };
+        if pipeline_capacity.is_some()
+        {
+            for (is_requested,flag) in [(compute_state,"stats"),(write_gff,"write_gff"),(write_changes.is_some(),"write_changes"),(consensus.is_some(),"consensus"),(hgvs_headers,"hgvs_headers"),
+                (aggregate_only,"aggregate_only"),(write_sample_vcfs,"write_sample_vcfs"),(count_only,"count_only"),(args.is_present("skip_errors"),"skip_errors"),
+                (args.is_present("coordinate_audit"),"coordinate_audit")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --pipeline_capacity, as it needs the personalized genomes of all samples at once",flag)
+                }
+            }
+        }
+        let output_layout=match OutputLayout::from_str(args.value_of("output_layout").unwrap())
+        {
+            Ok(layout)=>layout,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        let name_template=match args.value_of("name_template").map(NameTemplate::parse)
+        {
+            Some(Ok(template))=>template,
+            Some(Err(err_msg))=>panic!("{}",err_msg),
+            None=>NameTemplate::default_for(output_layout)
+        };
+        let fasta_layout=match FastaLayout::new(output_layout, name_template)
+        {
+            Ok(fasta_layout)=>fasta_layout,
+            Err(err_msg)=>panic!("{}",err_msg)
+        };
+        // the peptide windows replace the personalized proteome of each proband, hence, the options working on its fasta file are rejected 
+        let peptide_window=match args.value_of("peptide_window")
+        {
+            Some(flank)=>match flank.parse::<usize>()
+            {
+                Ok(flank)=>Some(flank),
+                Err(err_msg)=>panic!("The provided peptide window: {} is not a valid non-negative integer, parsing it failed with: {}",flank,err_msg)
+            },
+            None=>None
+        };
+        if peptide_window.is_some()
+        {
+            for (is_requested,flag) in [(write_all,"write_all_proteins or --emit_reference"),(incremental,"incremental"),(resume,"resume"),(smoke.is_some(),"smoke"),
+                (pipeline_capacity.is_some(),"pipeline_capacity"),(aggregate_only,"aggregate_only"),(count_only,"count_only"),(path2append_fasta.is_some(),"append_fasta"),
+                (output_layout!=OutputLayout::PerProband,"output_layout"),(args.occurrences_of("max_sample_output_size")!=0,"max_sample_output_size"),
+                (args.occurrences_of("max_total_output_size")!=0,"max_total_output_size")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --peptide_window, which writes the peptide windows instead of the personalized proteome of each sample",flag)
+                }
+            }
+        }
+        // the pooled sequences replace the fasta file of each proband, hence, the options working on these files are rejected 
+        let pool_sequences=match args.value_of("pool_sequences")
+        {
+            Some(members)=>match PoolMembers::from_str(members)
+            {
+                Ok(members)=>Some(members),
+                Err(err_msg)=>panic!("{}",err_msg)
+            },
+            None=>None
+        };
+        if pool_sequences.is_some()
+        {
+            for (is_requested,flag) in [(write_all,"write_all_proteins or --emit_reference"),(incremental,"incremental"),(resume,"resume"),(smoke.is_some(),"smoke"),
+                (pipeline_capacity.is_some(),"pipeline_capacity"),(aggregate_only,"aggregate_only"),(count_only,"count_only"),(peptide_window.is_some(),"peptide_window"),
+                (hgvs_headers,"hgvs_headers"),(output_layout!=OutputLayout::PerProband,"output_layout"),(args.occurrences_of("max_sample_output_size")!=0,"max_sample_output_size"),
+                (args.occurrences_of("max_total_output_size")!=0,"max_total_output_size")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --pool_sequences, which writes the unique sequences of the cohort instead of the fasta file of each sample",flag)
+                }
+            }
+        }
+        // the probands share the files of the other layouts, hence, the options working on the file of each proband are rejected 
+        if output_layout!=OutputLayout::PerProband
+        {
+            for (is_requested,flag) in [(incremental,"incremental"),(resume,"resume"),(smoke.is_some(),"smoke"),(pipeline_capacity.is_some(),"pipeline_capacity"),
+                (aggregate_only,"aggregate_only"),(path2append_fasta.is_some(),"append_fasta"),(args.occurrences_of("max_sample_output_size")!=0,"max_sample_output_size"),
+                (args.occurrences_of("max_total_output_size")!=0,"max_total_output_size")].iter()
+            {
+                if *is_requested
+                {
+                    panic!("--{} can not be used with --output_layout {}, as it works on the fasta file of each sample",flag,output_layout.as_str())
+                }
+            }
+        }
+        let progress=Arc::new(ProgressTracker::new()); 
+        let parse_options=ParseOptions{decoding_options:DecodingOptions{multi_allelic_policy,haplotype_source,unphased_policy,check_policy},transcript_filter,
+            consequence_selection,sample_selection,somatic_mode,quality_filters,silent_variant_policy,progress:Some(progress.clone()),
+            collect_transcript_genes:compute_state || gene_report}; 
+        let execution_options=ExecutionOptions{placeholder_policy,overlap_policy,diagnostic_options:DiagnosticOptions{check_policy,debug_filter},
+            progress:Some(progress.clone()),metrics:Some(Arc::new(MetricsAccumulator::new(NUM_SHARDS)))}; 
+        let writer_options=WriterOptions{output_policy,retry_policy,buffer_policy,output_quota,fasta_layout,compression_policy,id_map,
+            stop_codon_policy,write_all,write_compressed,progress:Some(progress.clone()),..WriterOptions::default()}; 
+        ParsedInput{path2vcf,path2maf,paths2mutation_tsv,path2fasta,res_path,engine,compute_state,is_verbose,progress_mode,log_level,log_format,write_i_map,write_all,emit_reference,write_compressed,write_single_thread,min_length,csq_map,write_gff,write_changes,peptide_window,pool_sequences,consensus,path2genome,aggregate_only,min_carriers,path2append_fasta,
+            only_transcript,trace,write_hgvs,hgvs_headers,gene_report,write_sample_vcfs,missing_transcripts_threshold,preflight_positions,status_exit_codes,sort_output,
+            unmodified_haplotypes,count_only,contig_policy,missing_gt_policy,incremental,resume,int_map_format,path2sample_sex,stream_chunk_size,regions,par_build,
+            skip_errors:args.is_present("skip_errors"),coordinate_audit:args.is_present("coordinate_audit"),validate,smoke,pipeline_capacity,parse_options,execution_options,writer_options,
+            progress,reporter:Arc::new(Mutex::new(None))}
+    }
+    /// ## Summary 
+    /// Start reporting the progress of the run with the progress mode, a reporter that is still running is finished first 
+    pub fn start_reporter(&self)
+    {
+        let reporter=ProgressReporter::start(self.progress_mode, self.progress.clone()); 
+        if let Some(previous)=std::mem::replace(&mut *self.reporter.lock().unwrap(),reporter)
+        {
+            previous.finish(); 
+        }
+    }
+    /// ## Summary 
+    /// Finish the reporter of the run, if any, which prints the last report, e.g. before the process exits 
+    pub fn finish_reporter(&self)
+    {
+        let reporter=self.reporter.lock().unwrap().take(); 
+        if let Some(reporter)=reporter
+        {
+            reporter.finish(); 
+        }
+    }
+}
+
+/// ## Summary
+/// Parse the engine along with the number of worker threads, which is resolved from --threads, the threads parameter of the engine and 
+/// the VCF2PROT_THREADS environment variable, see EngineSpec::with_num_threads 
+pub fn parse_engine_spec(args:&ArgMatches)->EngineSpec
+{
+    let engine_spec=match args.value_of("engine").map(EngineSpec::from_str)
+    {
+        Some(Ok(engine_spec))=>engine_spec,
+        Some(Err(err_msg))=>panic!("Parsing the engine failed with the following error: {}",err_msg),
+        None=>panic!("The value of engine has not been provided")
+    };
+    let num_threads=match args.value_of("threads")
+    {
+        Some(num_threads)=>match num_threads.parse::<usize>()
+        {
+            Ok(num_threads)=>Some(num_threads),
+            Err(err_msg)=>panic!("The provided number of threads: {} is not a valid positive integer, parsing it failed with: {}",num_threads,err_msg)
+        },
+        None=>None
+    };
+    match engine_spec.with_num_threads(num_threads, std::env::var(THREADS_ENV_VAR).ok())
+    {
+        Ok(engine_spec)=>engine_spec,
+        Err(err_msg)=>panic!("{}",err_msg)
+    }
+}
+
+//#[cfg(target_os="macos")]
+pub fn parse_command_line()->ArgMatches
+{
+    let app=App::new("Vcf2prot")
+    .version("0.1.4")
+    .author("Hesham ElAbd <h.elabd@ikmb.uni-kiel.de>")
+    .about("A rust binary that takes as input a FASTA file containing the reference proteome and \
+     a VCF file containing the consequence calling and apply the mutations of each patient \
+     to the reference file to generate a FASTA file per sample containing the personalized proteome of that individual. \
+     The quality-control checks are controlled with --checks and the diagnostic messages with --log_level, --log_format and --debug_transcript.
+     
+     For more details, see the project webpage at: https://github.com/ikmb/ppg")
+    .arg(Arg::new("vcf_file")
+        .short('f')
+        .long("vcf_file")
+        .value_name("FILE")
+        .about("A VCF File containing the consequences calling for each sample.")
+        .required_unless_present_any(["maf_file","mutation_tsv"]))
+    .arg(Arg::new("maf_file")
+        .long("maf_file")
+        .alias("maf-file")
+        .value_name("FILE")
+        .conflicts_with("vcf_file")
+        .about("A MAF (Mutation Annotation Format) file, e.g. of a TCGA cohort, that is used instead of the VCF file to generate a personalized proteome \
+         per tumor sample. The protein changes are read from the Protein_position and Amino_acids columns or from HGVSp_Short. Frameshift and nonstop \
+         records are skipped as a MAF file lacks their altered sequences, and a summary of the converted and skipped records is printed."))
+    .arg(Arg::new("mutation_tsv")
+        .long("mutation_tsv")
+        .alias("mutation-tsv")
+        .value_name("FILE")
+        .multiple_occurrences(true)
+        .conflicts_with_all(&["vcf_file","maf_file"])
+        .about("The mutation list of a sample that is used instead of the VCF file, i.e. a tab-separated file with the transcript, the consequence \
+         type, e.g. missense, and the protein change, e.g. 640V>640E, of each mutation and, optionally, the haplotype carrying it, i.e. 1, 2 or 1|2. \
+         The sample is named after the file without its extension. The option is repeated once per sample."))
+    .arg(Arg::new("fasta_ref")
+        .short('r')
+        .long("fasta_ref")
+        .value_name("FILE")
+        .about("A VCF File containing the reference proteome with transcript id as identifiers and protein sequences as the body.")
+        .required(true))
+    .arg(Arg::new("output_path")
+        .short('o')
+        .long("output_path")
+        .value_name("PATH")
+        .about("The path to a directory where fasta files will be written.")
+        .required(true))
+    .arg(Arg::new("engine")
+        .short('g')
+        .long("engine")
+        .value_name("VALUE")
+        .about("The Execution engine, can be any of four values, 'st' for single thread, 'mt' for multiple threads, 'gpu' for \
+         for using GPU accelerators and 'auto' for selecting the engine from the number of samples and records in the VCF file, the available \
+         cores and memory and the presence of a GPU, the selected engine is printed along with the reason for the selection. The engine can be \
+         followed by a colon and comma-separated parameters, i.e. 'mt:threads=16' or 'auto:threads=16' for the number of worker threads.")
+        .required(true))
+    .arg(Arg::new("threads")
+        .long("threads")
+        .alias("num_threads")
+        .value_name("NUM")
+        .required(false)
+        .takes_value(true)
+        .about("An optional number of worker threads, if set, parsing, execution and writing run on a thread pool of this size instead of using one thread per core, \
+         which can be used to share a node politely, e.g. with the number of cores allocated by the scheduler. It must agree with the threads parameter of the engine, \
+         if both are provided. If neither is provided, the number of threads is read from the VCF2PROT_THREADS environment variable, if set."))
+    .arg(Arg::new("verbose")
+        .short('v')
+        .long("verbose")
+        .required(false)
+        .takes_value(false)
+        .about("If set, print a verbose output about the program state."))
+    .arg(Arg::new("progress")
+        .long("progress")
+        .value_name("MODE")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["log","bar","json"])
+        .default_value("log")
+        .about("An optional mode for reporting the progress of the run, either log, bar or json. log prints the start and the end of each stage with a \
+         timestamp when --verbose is set, bar redraws a progress bar on the standard error and json prints a JSON line on the standard error every 5 seconds \
+         and on every stage change. The bar and the JSON lines show the stage, the parsed records, the translated transcripts, the translated and the \
+         written probands and the estimated remaining time of the stage. Defaults to log."))
+    .arg(Arg::new("log_level")
+        .long("log_level")
+        .alias("log-level")
+        .value_name("LEVEL")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["off","error","warn","info","debug","trace"])
+        .default_value("info")
+        .about("An optional maximum level of the diagnostic messages printed on the standard error, either off, error, warn, info, debug or trace. \
+         debug logs the inspection of the translation of each transcript and the task tables of failed checks, trace adds the validation of the \
+         tasks of each execution. Defaults to info."))
+    .arg(Arg::new("log_format")
+        .long("log_format")
+        .alias("log-format")
+        .value_name("FORMAT")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["text","json"])
+        .default_value("text")
+        .about("An optional format of the diagnostic messages, either text, i.e. LEVEL:: message, or json, i.e. a JSON object per line with the \
+         timestamp, the level, the module and the message. Defaults to text."))
+    .arg(Arg::new("checks")
+        .long("checks")
+        .value_name("POLICY")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["strict","warn","off"])
+        .default_value("strict")
+        .about("An optional policy for the quality-control checks of the translation from mutations to instructions and of the tasks of the \
+         execution engine, either strict, warn or off. strict stops the run at overlapping mutations or undecodable bit-masks, warn logs them as \
+         errors and skips the affected transcript or record, and off skips the checks to avoid their runtime cost. Defaults to strict."))
+    .arg(Arg::new("overlap_policy")
+        .long("overlap_policy")
+        .alias("overlap-policy")
+        .value_name("POLICY")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["keep-first","keep-most-severe","merge-if-compatible","drop-transcript"])
+        .about("An optional strategy resolving the mutations of a transcript that overlap or start at the same position, instead of reporting them \
+         as a failed check, see --checks. keep-first applies the first of the overlapping mutations, keep-most-severe applies the most severe one, e.g. \
+         a frameshift or a stop gained over a missense, merge-if-compatible merges mutations that write the same residues and skips the transcript \
+         otherwise, and drop-transcript skips the transcript without failing the checks. The strategy and the number of resolved overlaps are printed \
+         at the end of the run. By default, the overlaps are handled by the check policy."))
+    .arg(Arg::new("debug_transcript")
+        .long("debug_transcript")
+        .alias("debug-transcript")
+        .value_name("TRANSCRIPT_ID")
+        .required(false)
+        .multiple_occurrences(true)
+        .about("An optional transcript ID to debug, the step by step trace of the transcript, as generated by --trace, is logged before the \
+         personalized proteomes are generated and the inspection of its translation is logged, while the other transcripts are not. The \
+         option can be repeated and implies --log_level debug."))
+    .arg(Arg::new("stats")
+        .short('s')
+        .long("stats")
+        .required(false)
+        .takes_value(false)
+        .about("If set, stats are computed and are written to the output directory along with the fasta file"))
+    .arg(Arg::new("write_int_map")
+        .short('i')
+        .long("write_int_map")
+        .required(false)
+        .takes_value(false)
+        .about("Write an intermediate map containing the observed mutation per transcript per patient to sub directory in the provided output \
+        directory, the directory has a predefined name of 'int_maps'. Inside the directory a JSON file containing the \
+        intermediate map of each patient is written."))      
+    .arg(Arg::new("int_map_format")
+        .long("int_map_format")
+        .alias("int-map-format")
+        .value_name("FORMAT")
+        .required(false)
+        .default_value("json")
+        .possible_values(&["json","ndjson"])
+        .about("The format of the intermediate maps written with --write_int_map, 'json' writes a JSON file per patient, while 'ndjson' writes \
+         a single int_maps.ndjson file with the intermediate map of one patient per line, which is preferable for large cohorts. Defaults to json."))
+    .arg(Arg::new("write_all_proteins")
+        .short('a')
+        .long("write_all_proteins")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag to control the writing behavior of Vcf2prot, if set Vcf2prot will write the altered and the non-altered, i.e. \
+        reference sequences, to the fasta file of each proband. This might increase the size of the generated files considerably. \
+        By default this option is switched off."))
+    .arg(Arg::new("emit_reference")
+        .long("emit_reference")
+        .alias("emit-reference")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the transcripts without any qualifying mutation in a proband are written once to its fasta file, copied from the \
+        reference with a source=reference tag, e.g. >ENST00000406869_1 source=reference, so every output proteome is complete and can be used directly as a \
+        search database. Implies --write_all_proteins, where these transcripts are written once per haplotype. By default this option is switched off."))
+    .arg(Arg::new("stop_codon")
+        .long("stop_codon")
+        .alias("stop-codon")
+        .value_name("CONVENTION")
+        .required(false)
+        .possible_values(&["keep","strip","truncate"])
+        .about("An optional convention for the stop codons of the written sequences, as downstream tools expect different ones. Use 'keep' to end each \
+        sequence with a single '*', 'strip' to remove the trailing '*' and 'truncate' to truncate each sequence before its first stop, internal stops \
+        of the reference included. The convention applies to the altered and the reference sequences alike, whatever the consequence that produced them, e.g. \
+        a stop gained or a frameshift. By default, the sequences are written as produced, i.e. the altered sequences end without a '*' while the reference \
+        sequences are copied as provided."))
+    .arg(Arg::new("write_compressed")
+        .short('c')
+        .long("write_compressed")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag to control the writing behavior of Vcf2prot, if set Vcf2prot will write the generated fasta files as compressed \
+        files, i.e. with the extension .fasta.gz or .fasta.zst, see --compression, this can be used to decrease the disk space needed by the generated files, especially, \
+        when generating 1000s of files.By default this option is switched off. "))    
+    .arg(Arg::new("compression")
+        .long("compression")
+        .value_name("FORMAT")
+        .required(false)
+        .default_value("gzip")
+        .possible_values(&["gzip","bgzip","zstd"])
+        .about("An optional format of the compressed fasta files written with --write_compressed. Use 'gzip' for a single gzip stream, 'bgzip' \
+         for the blocked gzip format of bgzip, which samtools faidx can index, or 'zstd' for a zstd frame, which zstd -d reads. Both gzip formats \
+         use the .fasta.gz extension, while zstd uses the .fasta.zst extension. Defaults to gzip."))
+    .arg(Arg::new("compression_level")
+        .long("compression_level")
+        .alias("compression-level")
+        .value_name("LEVEL")
+        .required(false)
+        .default_value("9")
+        .about("An optional compression level of the files written with --write_compressed, from 0, i.e. no compression, to 9, i.e. the best \
+         and slowest compression. Lower levels write much faster at the cost of larger files. Defaults to 9."))
+    .arg(Arg::new("write_single_thread")
+        .short('w')
+        .long("write_single_thread")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag to control the writing behavior of Vcf2prot, if set only one thread is used to write all generated fasta files, \
+        by default, this is the case with a single thread engine, i.e. g st, however, this parameter can be used to overwrite this parameter and \
+        to enable a single threaded writing of files when a multi-threaded or a GPU engines have been used for parsing and generating the sequences. "))       
+    .arg(Arg::new("pipeline_capacity")
+        .long("pipeline_capacity")
+        .alias("pipeline-capacity")
+        .value_name("NUM")
+        .required(false)
+        .about("An optional number of personalized genomes, if provided, the genomes are written while the execution is running and at most NUM \
+         generated genomes wait for a writer at any time, once NUM genomes are waiting the execution pauses until a writer is free, so the memory \
+         stays bounded when writing is slower than the execution, e.g. with compressed files on a network file system. It can not be combined with \
+         the options that need all genomes at once, e.g. --stats, --write_gff or --consensus. By default all genomes are generated before they are written."))
+    .arg(Arg::new("output_layout")
+        .long("output_layout")
+        .alias("output-layout")
+        .value_name("LAYOUT")
+        .required(false)
+        .default_value("per-proband")
+        .possible_values(&["per-proband","per-transcript","combined"])
+        .about("An optional layout of the generated fasta files. Use 'per-proband' to write one file per sample, 'per-transcript' to write one file \
+         per transcript holding the records of every sample, or 'combined' to write the records of every sample to personalized_proteomes.fasta. \
+         The layouts other than per-proband can not be combined with the options working on the file of each sample, e.g. --resume or --append_fasta. \
+         Defaults to per-proband."))
+    .arg(Arg::new("name_template")
+        .long("name_template")
+        .alias("name-template")
+        .value_name("TEMPLATE")
+        .required(false)
+        .about("An optional template of the record names of the generated fasta files, where {proband}, {haplotype} and {transcript} are replaced by \
+         the sample name, the haplotype and the transcript id, e.g. {proband}_{haplotype}_{transcript}. With --output_layout per-transcript, the text \
+         around {transcript} names the files as well. The template must contain the fields keeping the names of a file unique, e.g. {transcript} and \
+         {haplotype} for per-proband files. Defaults to {transcript}_{haplotype}, {proband}_{haplotype} and {proband}_{transcript}_{haplotype} for \
+         the per-proband, the per-transcript and the combined layout, respectively."))
+    .arg(Arg::new("min_length")
+        .short('m')
+        .long("min_length")
+        .value_name("VALUE")
+        .required(false)
+        .default_value("0")
+        .about("The minimum length of a protein sequence to be written to the output fasta files, sequences shorter than this value, for example, \
+        short products generated by frameshifts near the N-terminus, are dropped before writing. The number of dropped sequences per sample \
+        is written to the stats if the stats flag is set. By default, this is 0, i.e. all sequences are written."))
+    .arg(Arg::new("csq_map")
+        .short('t')
+        .long("csq_map")
+        .alias("csq-map")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional tab-separated file with two columns that maps consequence type strings found in the VCF file to the supported \
+        consequence types, for example, 'feature_elongation&frameshift\tframeshift'. The second column can be a supported consequence type, e.g. frameshift, \
+        or the name of a mutation type, e.g. FrameShift. The map is applied before unsupported consequences are filtered, this can be used to \
+        adapt to the names used by different BCFtools versions and forks."))
+    .arg(Arg::new("id_map")
+        .long("id_map")
+        .alias("id-map")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional tab-separated file with two columns that maps the transcript ids of the VCF file to the ids of their protein sequences, \
+        for example, 'ENST00000406869\tENSP00000384129' or a UniProt accession. The reference sequences stored under a mapped protein id are looked up \
+        by the transcript ids mapped to it, and the record headers of the output name mapped transcripts by their protein id. This can be used with a \
+        reference proteome keyed by protein ids without pre-processing the FASTA file. A transcript can be mapped to one protein only."))
+    .arg(Arg::new("include_transcripts")
+        .long("include_transcripts")
+        .alias("include-transcripts")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional file with one transcript id per line, if provided, only the consequences of these transcripts, and of the genes \
+        provided with --include_genes, are processed. The filter is applied while the VCF file is read, hence, the records without a selected \
+        consequence are neither decoded nor translated, which speeds up the analysis of a few genes. By default all transcripts are processed."))
+    .arg(Arg::new("include_genes")
+        .long("include_genes")
+        .alias("include-genes")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional file with one gene name per line, as written in the BCSQ field, e.g. HOXB3, if provided, only the consequences of \
+        the transcripts of these genes, and of the transcripts provided with --include_transcripts, are processed."))
+    .arg(Arg::new("exclude_transcripts")
+        .long("exclude_transcripts")
+        .alias("exclude-transcripts")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional file with one transcript id per line whose consequences are skipped while the VCF file is read, the exclusions \
+        take precedence over --include_transcripts and --include_genes."))
+    .arg(Arg::new("exclude_genes")
+        .long("exclude_genes")
+        .alias("exclude-genes")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional file with one gene name per line whose consequences are skipped while the VCF file is read, the exclusions \
+        take precedence over --include_transcripts and --include_genes."))
+    .arg(Arg::new("consequences")
+        .long("consequences")
+        .value_name("TYPE1,TYPE2,...")
+        .required(false)
+        .about("An optional comma-separated list of consequence types, e.g. missense,frameshift, if provided, only the consequences of these types \
+        are applied. A type matches the supported types containing it, e.g. missense matches *missense and missense&inframe_altering, and the \
+        other consequences are skipped while the VCF file is read. By default all supported types are applied."))
+    .arg(Arg::new("exclude_consequences")
+        .long("exclude_consequences")
+        .alias("exclude-consequences")
+        .value_name("TYPE1,TYPE2,...")
+        .required(false)
+        .about("An optional comma-separated list of consequence types that are skipped while the VCF file is read, e.g. stop_lost, the exclusions \
+        take precedence over --consequences."))
+    .arg(Arg::new("samples")
+        .long("samples")
+        .value_name("SAMPLE1,SAMPLE2,...")
+        .required(false)
+        .about("An optional comma-separated list of samples, if provided, only the personalized proteomes of these samples are generated. The \
+        columns of the other samples are removed from each record while the VCF file is read, hence, their genotypes are never decoded. The \
+        samples are processed in the order of the VCF header, and the run fails if a sample is not part of the VCF file. By default all \
+        samples are processed."))
+    .arg(Arg::new("samples_file")
+        .long("samples_file")
+        .alias("samples-file")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional file with one sample name per line, the samples are selected as with --samples and both options can be combined."))
+    .arg(Arg::new("tumor")
+        .long("tumor")
+        .value_name("SAMPLE")
+        .required(false)
+        .about("An optional tumor sample for the somatic mode, if provided, only the personalized proteome of the tumor is generated and its altered \
+        records are tagged with the variant allele fraction and the read depth of their variants, taken from the AF, AD and DP FORMAT fields, \
+        e.g. vaf=0.250 dp=40. Can not be combined with --samples or --samples_file."))
+    .arg(Arg::new("normal")
+        .long("normal")
+        .value_name("SAMPLE")
+        .required(false)
+        .about("An optional matched normal sample for the somatic mode, the variants of the tumor that are carried by the normal sample, i.e. every \
+        ALT allele called in the tumor is called in the normal as well, are removed from the tumor. Requires --tumor."))
+    .arg(Arg::new("min_dp")
+        .long("min_dp")
+        .alias("min-dp")
+        .value_name("VALUE")
+        .required(false)
+        .about("An optional minimum read depth, the calls whose DP FORMAT field is below the minimum are set to the reference while the VCF file \
+        is read, calls without a DP value are kept. By default, no minimum is used."))
+    .arg(Arg::new("min_gq")
+        .long("min_gq")
+        .alias("min-gq")
+        .value_name("VALUE")
+        .required(false)
+        .about("An optional minimum genotype quality, the calls whose GQ FORMAT field is below the minimum are set to the reference while the VCF \
+        file is read, calls without a GQ value are kept. By default, no minimum is used."))
+    .arg(Arg::new("pass_only")
+        .long("pass_only")
+        .alias("pass-only")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the records whose FILTER column is neither PASS nor . are skipped for all samples. By default \
+        this option is switched off."))
+    .arg(Arg::new("max_af")
+        .long("max_af")
+        .alias("max-af")
+        .value_name("FREQUENCY")
+        .required(false)
+        .about("An optional maximum population allele frequency between 0 and 1, e.g. 0.01 for rare variants only, the records where no ALT allele \
+        has a frequency of at most the maximum are skipped while the VCF file is read. The frequencies are read from the INFO column, see --af_key, \
+        and records without a frequency are kept. By default, no maximum is used."))
+    .arg(Arg::new("min_af")
+        .long("min_af")
+        .alias("min-af")
+        .value_name("FREQUENCY")
+        .required(false)
+        .about("An optional minimum population allele frequency between 0 and 1, the records where no ALT allele has a frequency of at least the \
+        minimum are skipped as with --max_af. By default, no minimum is used."))
+    .arg(Arg::new("af_key")
+        .long("af_key")
+        .alias("af-key")
+        .value_name("KEY")
+        .required(false)
+        .about("An optional INFO key holding the allele frequencies for --min_af and --max_af, e.g. AF_nfe or gnomAD_AF for gnomAD annotations, if \
+        a record lacks the key, the frequencies are computed from the matching AC and AN keys, e.g. AC_nfe and AN_nfe. By default, this is AF."))
+    .arg(Arg::new("write_gff")
+        .short('n')
+        .long("write_gff")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, a GFF3 file is written per sample along with the fasta file, the GFF3 file marks the interval of each \
+        applied variant on the personalized sequences, where sequence ids match the names of the fasta records, i.e. transcript id followed by the haplotype index. \
+        This can be used to load the sequences along with the variants into protein viewers. By default this option is switched off."))
+    .arg(Arg::new("pool_sequences")
+        .long("pool_sequences")
+        .alias("pool-sequences")
+        .value_name("MEMBERS")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["sidecar","header"])
+        .about("An optional mode for large cohorts, if set, each unique altered sequence of a transcript is written once to pooled_sequences.fasta instead of \
+         writing a fasta file per sample. The members of each sequence, i.e. the sample and the haplotype of each carrier, are listed either in \
+         pooled_sequences.members.tsv, with 'sidecar', or in the header of the record, with 'header', e.g. '>ENST00000406869_pool1 members=2 ids=HG00096_1,HG00097_2'. \
+         By default a fasta file is written per sample."))
+    .arg(Arg::new("peptide_window")
+        .long("peptide_window")
+        .alias("peptide-window")
+        .value_name("NUM")
+        .required(false)
+        .takes_value(true)
+        .about("An optional number of flanking residues, if set, only the peptide windows around the applied changes are written instead of the full personalized \
+         proteins, i.e. <sample>.peptides.fasta. The window of a substituted residue has a length of 2*NUM+1 and is centered on it, windows of insertions and \
+         deletions span the inserted residues or the residue at the junction extended by NUM residues on both sides, while the window of a frameshift spans NUM \
+         residues before it and the whole novel tail. Changes without alternative residues, e.g. stop-gained, have no window. By default the full proteins are written."))
+    .arg(Arg::new("write_changes")
+        .long("write_changes")
+        .alias("write-changes")
+        .value_name("FORMAT")
+        .required(false)
+        .takes_value(true)
+        .possible_values(&["tsv","json"])
+        .about("An optional format, either tsv or json, for writing a table of the applied changes per sample along with the fasta file, i.e. <sample>.changes.tsv or \
+         <sample>.changes.json. Each change lists the transcript id, the haplotype, the instruction code, the reference and the alternative amino acids and their \
+         0-based half-open intervals on the reference and on the personalized sequence. By default no table is written."))
+    .arg(Arg::new("hgvs")
+        .long("hgvs")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the mutations applied to each haplotype of each sample are written along with their HGVS.p description, \
+        e.g. p.Arg263Gln or p.Val600_Lys601insSer, to hgvs_changes.tsv in the output directory. By default this option is switched off."))
+    .arg(Arg::new("hgvs_headers")
+        .long("hgvs_headers")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the HGVS.p descriptions of the mutations applied to an altered transcript are added as an hgvs tag to the header \
+        of its fasta record, e.g. >ENST00000406869_1 hgvs=p.Gly5His,p.Val10His. By default this option is switched off."))
+    .arg(Arg::new("gene_report")
+        .long("gene_report")
+        .alias("gene-report")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the protein changes of the cohort are rolled up by gene symbol and written to gene_report.tsv in the output \
+        directory, with the number of samples carrying at least one protein-altering change in the gene and the list of distinct changes, e.g. \
+        ENST00000288602:p.Val600Glu. The gene symbols are read from the BCSQ field of the VCF file. By default this option is switched off."))
+    .arg(Arg::new("write_sample_vcfs")
+        .long("write_sample_vcfs")
+        .alias("write-sample-vcfs")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, a small VCF file is written for each sample to the sample_vcfs directory inside the output directory, \
+        containing exactly the records whose consequences were applied to the personalized proteome of the sample, with the original INFO column and \
+        only the column of the sample. This allows the protein sequences to be cross-referenced with a minimal variant file instead of the VCF of the \
+        cohort. By default this option is switched off."))
+    .arg(Arg::new("consensus")
+        .short('u')
+        .long("consensus")
+        .value_name("RULE")
+        .required(false)
+        .about("An optional rule for writing a consensus fasta file per sample, i.e. <sample>.consensus.fasta, containing a single sequence per \
+        altered transcript along with the default per-haplotype fasta file. The rule can be either 'severity', where the haplotype with the larger \
+        number of residues differing from the reference is chosen, or 'ambiguity', where differing residues of equal-length haplotypes are encoded \
+        with IUPAC ambiguity codes, i.e. B, Z, J or X, and the severity rule is used otherwise. The header of each record states the chosen \
+        haplotype, e.g. '>ENST00000406869 haplotype=2 rule=severity'."))
+    .arg(Arg::new("genome_fasta")
+        .short('e')
+        .long("genome_fasta")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional fasta file containing the genome used for consequence calling, if provided, the genomic change of each consequence, \
+        e.g. 193236A>T, is cross-checked against the genome and against the stated protein change, and inconsistent consequences are written \
+        to inconsistent_annotations.tsv in the output directory. The reference alleles, the reading frame of indels and the codons of single nucleotide \
+        missense and stop-gained changes are checked. Flagged consequences are still applied."))
+    .arg(Arg::new("aggregate_only")
+        .long("aggregate_only")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag for settings where per-individual outputs are not allowed, if set, only cohort-level files are written, i.e. \
+        cohort.unique_sequences.fasta, containing the unique personalized sequences carried by at least --min_carriers samples along with their number of carriers, \
+        cohort_carriers_per_transcript.tsv and cohort_summary.tsv. No per-sample file is written and the per-sample flags, i.e. --stats, --write_int_map, \
+        --write_gff, --write_changes, --consensus, --hgvs, --hgvs_headers, --sort_output, --unmodified_haplotypes and --write_sample_vcfs, can not be used. By default this option is switched off."))
+    .arg(Arg::new("min_carriers")
+        .long("min_carriers")
+        .value_name("VALUE")
+        .required(false)
+        .default_value("5")
+        .about("The minimum number of samples carrying a sequence or an altered transcript for it to be reported in the aggregate-only mode, sequences \
+        and transcripts with less carriers are suppressed. By default, this is 5."))
+    .arg(Arg::new("append_fasta")
+        .long("append_fasta")
+        .alias("append-fasta")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional fasta file with custom records, e.g. the common contaminants of the cRAP database, which are appended to the fasta file of every \
+        sample, or to cohort.unique_sequences.fasta in the aggregate-only mode, after the generated sequences. A custom record is skipped if its id, i.e. the first \
+        word of its header, or its sequence matches a generated record, or if it repeats a previous custom record."))
+    .arg(Arg::new("fail_on_missing_transcripts_threshold")
+        .long("fail_on_missing_transcripts_threshold")
+        .alias("fail-on-missing-transcripts-threshold")
+        .value_name("PERCENT")
+        .required(false)
+        .about("An optional quality gate, if provided, the percentage of transcripts referenced in the VCF that are missing from the reference or that are skipped \
+        due to errors is printed along with a breakdown, and the run fails with a non-zero exit status, before any output is written, if the percentage is larger \
+        than the provided value, e.g. 5. This can be used to catch reference proteomes that do not match the annotation of the VCF in automated workflows."))
+    .arg(Arg::new("preflight")
+        .long("preflight")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, a pre-flight check compares, for a sample of positions of each transcript referenced in the VCF, the reference residues \
+        stated by the consequences to the reference proteome before the sequences are generated. The compatibility report and the transcripts with \
+        mismatches are printed and the mismatches per transcript are written to reference_mismatches.tsv. By default this option is switched off."))
+    .arg(Arg::new("preflight_positions")
+        .long("preflight_positions")
+        .alias("preflight-positions")
+        .value_name("NUM")
+        .required(false)
+        .takes_value(true)
+        .default_value("10")
+        .about("An optional number of unique positions checked per transcript by --preflight, the first positions encountered in the VCF are checked. \
+        Defaults to 10."))
+    .arg(Arg::new("sort_output")
+        .long("sort_output")
+        .alias("sort-output")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the records of each personalized fasta file are sorted by transcript id and then by haplotype, e.g. ENST00000001_1, \
+         ENST00000001_2, ENST00000002_1, instead of being written in processing order, which makes the files of different runs or pipeline versions \
+         comparable with diff. The processing order is identical between runs of the same input as well. By default this option is switched off."))
+    .arg(Arg::new("placeholder_residue")
+        .long("placeholder_residue")
+        .alias("placeholder-residue")
+        .value_name("CHAR")
+        .required(false)
+        .about("An optional character the results arrays are initialized with before the execution, i.e. the residue of every position that is not written \
+         by any execution task, e.g. '#'. Upper-case letters, '*' and '>' are not allowed. Together with --no_strict_placeholder_check, it makes unwritten \
+         residues easy to spot when debugging the execution tables. Defaults to '.'."))
+    .arg(Arg::new("no_strict_placeholder_check")
+        .long("no_strict_placeholder_check")
+        .alias("no-strict-placeholder-check")
+        .required(false)
+        .takes_value(false)
+        .about("An optional debugging flag, if set, transcripts whose sequence still contains the placeholder residue after the execution are written and \
+         marked with status=recovered. By default, such transcripts are not written and are listed as rejected in the execution report, so a bug in the \
+         execution tables can never silently corrupt the fasta files."))
+    .arg(Arg::new("incremental")
+        .long("incremental")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the run manifest of the previous run, i.e. run_manifest.tsv in the output directory, is compared to the current run and \
+         only the samples whose variants changed, or whose files are missing, are regenerated, while the files of the other samples are kept as they are. All \
+         samples are regenerated if the reference, the VCF parsing or the output options changed. The per-sample tables of --stats only list the regenerated \
+         samples. The manifest is written by every run. By default this option is switched off."))
+    .arg(Arg::new("resume")
+        .long("resume")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, only the samples whose files could not be written by the previous run, i.e. the samples marked as failed in \
+         run_manifest.tsv in the output directory, are generated and written again, while the files of the other samples are kept as they are. If the \
+         previous run was interrupted, i.e. the output directory still contains its progress file run_progress.tsv, only the samples that it did not \
+         complete are written. The run fails if the output directory has neither a progress file nor a manifest or if the reference, the VCF parsing \
+         or the output options changed since the previous run. By default this option is switched off."))
+    .arg(Arg::new("stream_vcf")
+        .long("stream_vcf")
+        .alias("stream-vcf")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the VCF file is read line by line in chunks of records and the consequences of each chunk are added to \
+         the intermediate representation before the next chunk is read, so the file is never loaded into memory, which is needed for population-scale \
+         files. Duplicated records are only collapsed if they are part of the same chunk, which is always the case for files sorted by their position. \
+         The options that load the VCF file again, i.e. --sample_sex, --write_sample_vcfs and --genome_fasta, can not be used. \
+         By default this option is switched off."))
+    .arg(Arg::new("stream_chunk_size")
+        .long("stream_chunk_size")
+        .alias("stream-chunk-size")
+        .value_name("NUM")
+        .required(false)
+        .about("The minimum number of records per chunk of a streamed VCF file, see --stream_vcf, a chunk is only closed between two loci. Defaults to 10000."))
+    .arg(Arg::new("region")
+        .long("region")
+        .value_name("CHR:START-END")
+        .required(false)
+        .multiple_occurrences(true)
+        .about("An optional region, i.e. CHR, CHR:POS or CHR:START-END with one-based positions, if provided, only the records overlapping the \
+         region are read from the VCF file, which must be bgzipped and indexed using tabix, i.e. the index, VCF_FILE.tbi or VCF_FILE.csi, must be \
+         next to the file. The option can be repeated to read the records of many regions, each record is read once. Regions on contigs that are \
+         not part of the index are reported with a warning. By default the whole file is read."))
+    .arg(Arg::new("write_retries")
+        .long("write_retries")
+        .alias("write-retries")
+        .value_name("NUM")
+        .required(false)
+        .default_value("3")
+        .about("The number of times the creation of an output file and every write to it are retried if they fail with a transient error of the file system, \
+         e.g. EIO or ENOSPC on Lustre or NFS. A sample whose files still can not be written is marked as failed in run_manifest.tsv and the other samples \
+         are written, so the failed samples can be written again with --resume. Set it to 0 to disable the retries. Defaults to 3."))
+    .arg(Arg::new("write_retry_backoff")
+        .long("write_retry_backoff")
+        .alias("write-retry-backoff")
+        .value_name("MILLISECONDS")
+        .required(false)
+        .default_value("500")
+        .about("The waiting time before the first retry of a failed write in milliseconds, which is doubled for every further retry up to one minute. \
+         Defaults to 500."))
+    .arg(Arg::new("output_buffer_size")
+        .long("output_buffer_size")
+        .alias("output-buffer-size")
+        .value_name("auto|BYTES")
+        .required(false)
+        .default_value("auto")
+        .about("The size of the buffer the records of a personalized fasta file are collected in before they are written. With auto, each buffer holds the \
+         expected size of the file, computed from the lengths of its sequences, up to --max_output_buffer_size, otherwise every buffer has the provided \
+         number of bytes. Defaults to auto."))
+    .arg(Arg::new("max_output_buffer_size")
+        .long("max_output_buffer_size")
+        .alias("max-output-buffer-size")
+        .value_name("BYTES")
+        .required(false)
+        .default_value("67108864")
+        .about("The maximum size of an automatically sized output buffer in bytes, as every thread writing a sample holds one buffer. Defaults to 67108864, i.e. 64 MiB."))
+    .arg(Arg::new("preallocate_output")
+        .long("preallocate_output")
+        .alias("preallocate-output")
+        .required(false)
+        .takes_value(false)
+        .about("An optional control flag, if set, the expected size of each uncompressed personalized fasta file is allocated on the disk before it is written, \
+         which reduces the fragmentation of large files. The allocation is only supported on Linux and is skipped otherwise. By default this option is switched off."))
+    .arg(Arg::new("max_sample_output_size")
+        .long("max_sample_output_size")
+        .alias("max-sample-output-size")
+        .value_name("BYTES")
+        .required(false)
+        .about("An optional quota on the size of the personalized fasta file of each sample in bytes, counted before compression. Once a sequence does not fit \
+         into the quota, no further sequence is written for the sample, the sample is marked as truncated in run_manifest.tsv and the other samples are \
+         written. By default the size is unlimited."))
+    .arg(Arg::new("max_total_output_size")
+        .long("max_total_output_size")
+        .alias("max-total-output-size")
+        .value_name("BYTES")
+        .required(false)
+        .about("An optional quota on the total size of the personalized fasta files of all samples in bytes, counted before compression. A sample whose \
+         sequences no longer fit into the quota is truncated as with --max_sample_output_size. By default the size is unlimited."))
+    .arg(Arg::new("unmodified_haplotypes")
+        .long("unmodified_haplotypes")
+        .alias("unmodified-haplotypes")
+        .value_name("POLICY")
+        .required(false)
+        .default_value("write")
+        .possible_values(&["write","omit","mark"])
+        .about("An optional policy for transcripts whose variants are all on one haplotype, where the other haplotype is identical to the reference. \
+         Use 'write' to write both haplotypes, 'omit' to write only the altered haplotype and 'mark' to write both haplotypes while tagging the header of the \
+         unmodified one with haplotype=reference, e.g. >ENST00000001_2 haplotype=reference. Requires --write_all_proteins, defaults to write."))
+    .arg(Arg::new("include_non_primary_contigs")
+        .long("include_non_primary_contigs")
+        .alias("include-non-primary-contigs")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the records on non-primary contigs, i.e. ALT, HLA, decoy, unlocalized and unplaced contigs such as chr6_GL000251v2_alt, \
+         are parsed as well. By default, these records are skipped, as their transcripts are rarely part of the reference proteome, and the number of skipped \
+         records per contig class is printed."))
+    .arg(Arg::new("missing_gt")
+        .long("missing_gt")
+        .alias("missing-gt")
+        .value_name("POLICY")
+        .required(false)
+        .default_value("ref")
+        .possible_values(&["ref","skip-record","major-allele","flag"])
+        .about("An optional policy for missing genotype calls, e.g. ./. or .|. Use 'ref' to treat the sample as carrying none of the consequences of the record, \
+         'skip-record' to skip records with a missing call in at least one sample for all samples and 'major-allele' to impute the sample as homozygous for the \
+         allele carried by the majority of the called haplotypes. Use 'flag' to treat the sample as 'ref' while tagging the records of the transcripts of the record \
+         with genotype=missing, partially missing calls, e.g. .|1, are flagged as well. The number of missing calls is printed and, with --stats, written per sample to \
+         missing_genotypes_per_proband.tsv. Defaults to ref."))
+    .arg(Arg::new("silent_variants")
+        .long("silent_variants")
+        .alias("silent-variants")
+        .value_name("POLICY")
+        .required(false)
+        .default_value("skip")
+        .possible_values(&["skip","report","headers"])
+        .about("An optional policy for the silent variants, i.e. the synonymous and the start retained consequences, which do not change the sequences. \
+         Use 'skip' to drop them, 'report' to write the silent consequences carried by each haplotype of each proband as zero-change annotations to \
+         silent_variants.tsv in the results directory and 'headers' to tag the written records of the transcripts with the silent changes of their \
+         haplotype as well, e.g. silent=3L,377H. Defaults to skip."))
+    .arg(Arg::new("multi_allelic")
+        .long("multi_allelic")
+        .alias("multi-allelic")
+        .value_name("POLICY")
+        .required(false)
+        .default_value("genotype")
+        .possible_values(&["genotype","bitmask"])
+        .about("An optional policy for records with more than one ALT allele. Use 'genotype' to assign each consequence to the ALT allele whose DNA change it \
+         describes and to give each haplotype the consequences of the allele called by its genotype, records whose consequences can not be assigned to exactly \
+         one allele and missing calls are decoded from the bit-mask. Use 'bitmask' to decode all records from the bit-mask. Defaults to genotype."))
+    .arg(Arg::new("haplotype_source")
+        .long("haplotype_source")
+        .alias("haplotype-source")
+        .value_name("SOURCE")
+        .required(false)
+        .default_value("bitmask")
+        .possible_values(&["bitmask","phase"])
+        .about("An optional source for assigning the consequences of a sample to its haplotypes. Use 'bitmask' to decode the BCSQ bit-mask in the sample \
+         fields. Use 'phase' to assign the consequences of every record from the phased genotype call, e.g. 0|1, 1|0 or 1|1, where each haplotype carries the \
+         consequences of the ALT allele it calls, which does not need the bit-mask, e.g. for VCF files produced by other versions of bcftools csq. Unphased \
+         heterozygous calls, missing calls and records whose consequences can not be assigned to an ALT allele are decoded from the bit-mask if present, and \
+         as reference otherwise. Defaults to bitmask."))
+    .arg(Arg::new("unphased")
+        .long("unphased")
+        .value_name("POLICY")
+        .required(false)
+        .default_value("assume")
+        .possible_values(&["assume","collapse","both","skip"])
+        .about("An optional policy for heterozygous calls whose phase is unknown, e.g. 0/1. Use 'assume' to assign the consequences to the haplotypes as \
+         decoded, i.e. as if the call was phased. Use 'collapse' to give the consequences of both haplotypes to both haplotypes, i.e. a single mutated \
+         sequence. Use 'both' to give them to the first haplotype and the reference to the second, i.e. both possible haplotypes. Use 'skip' to treat \
+         the call as reference. Defaults to assume."))
+    .arg(Arg::new("sample_sex")
+        .long("sample_sex")
+        .alias("sample-sex")
+        .value_name("FILE")
+        .required(false)
+        .about("An optional tab-separated table with the sample names in the first column and their sexes, i.e. XX or XY, also F, female or 2 and M, male or 1, \
+         in the second column. For XY samples, the transcripts located outside the pseudoautosomal regions of chrX and chrY are emitted as a single haplotype, \
+         i.e. the variants of both haplotypes are collapsed into the first one, whose header is tagged with ploidy=haploid. Samples missing from the table are \
+         handled as diploid. By default, all samples are handled as diploid."))
+    .arg(Arg::new("par_build")
+        .long("par_build")
+        .alias("par-build")
+        .value_name("BUILD")
+        .required(false)
+        .default_value("GRCh38")
+        .possible_values(&["GRCh38","GRCh37","grch38","grch37","hg38","hg19"])
+        .about("The genome build defining the coordinates of the pseudoautosomal regions used with --sample_sex, either GRCh38 or GRCh37. Defaults to GRCh38."))
+    .arg(Arg::new("skip_errors")
+        .long("skip_errors")
+        .alias("skip-errors")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, a transcript whose instructions or tasks fail, e.g. with an indexing error, is skipped instead of aborting the run, \
+         the rest of the proteome is generated and the skipped transcripts, or probands, are written along with the reason to failures.tsv in the \
+         output directory. Probands that fail as a whole are marked as failed in run_manifest.tsv. By default this option is switched off."))
+    .arg(Arg::new("coordinate_audit")
+        .long("coordinate_audit")
+        .alias("coordinate-audit")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, every clamped task, off-by-one correction, size mismatch and unwritten residue encountered while executing the \
+         cohort is written to coordinate_audit.tsv, grouped by transcript together with the instruction codes of the transcript and the number of \
+         haplotypes and probands with the same issue, and the most frequent issues across transcripts are printed. By default this option is switched off."))
+    .arg(Arg::new("validate")
+        .long("validate")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the altered transcripts of every proband are translated into tasks before the execution and checked for \
+         contiguous tasks and results arrays of the expected size, the transcripts violating them are excluded from the proband instead of stopping \
+         the run, the violations are written to validation_report.tsv and summarized on the standard output. Implies --checks warn unless --checks \
+         is provided. By default this option is switched off."))
+    .arg(Arg::new("count_only")
+        .long("count_only")
+        .alias("count-only")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the VCF is parsed and the instructions are generated, however, they are not executed and nothing is written, \
+         instead, the number of sequences that would be generated per proband and haplotype, and in total, is printed as a tab-separated table. \
+         This is useful for capacity planning and for quickly validating new annotation files. By default this option is switched off."))
+    .arg(Arg::new("smoke")
+        .long("smoke")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag for testing the integration of Vcf2prot into downstream pipelines, if set, only the first records and the first samples of \
+         the VCF file are processed, see --smoke_records and --smoke_samples, with the single-thread engine and sorted records, so the run completes in \
+         seconds regardless of the size of the input. A fasta file per sample and the run manifest are written and listed along with their size and \
+         digest in smoke_manifest.tsv, which only changes with the input and the version of Vcf2prot. By default this option is switched off."))
+    .arg(Arg::new("smoke_records")
+        .long("smoke_records")
+        .alias("smoke-records")
+        .value_name("NUM")
+        .required(false)
+        .default_value("100")
+        .about("The number of supported records, taken from the start of the VCF file, processed with --smoke. Defaults to 100."))
+    .arg(Arg::new("smoke_samples")
+        .long("smoke_samples")
+        .alias("smoke-samples")
+        .value_name("NUM")
+        .required(false)
+        .default_value("2")
+        .about("The number of samples, taken in the order of the VCF header, processed with --smoke. Defaults to 2."))
+    .arg(Arg::new("status_exit_codes")
+        .long("status_exit_codes")
+        .alias("status-exit-codes")
+        .required(false)
+        .takes_value(false)
+        .about("An optional flag, if set, the transcripts that could not be personalized, the reference residues that do not match the consequences of the \
+         VCF and, if --genome is provided, the consequences that are inconsistent with the genome are counted and a final machine-parsable line starting with \
+         VCF2PROT_STATUS is printed. The run then exits with 0 if it is clean, 3 if transcripts were skipped, 4 if reference residues mismatch \
+         and 5 if consequences are inconsistent with the genome, where the highest applicable code is used. By default this option is switched off."))
+    .arg(Arg::new("only_transcript")
+        .long("only_transcript")
+        .alias("only-transcript")
+        .value_name("TRANSCRIPT_ID")
+        .required(false)
+        .about("An optional transcript id, e.g. ENST00000406869, if provided, only this transcript is processed across all samples and the alterations in all \
+        other transcripts are ignored. This is mainly used for debugging a specific transcript, see --trace."))
+    .arg(Arg::new("trace")
+        .long("trace")
+        .required(false)
+        .takes_value(false)
+        .about("An optional debugging flag that can only be used with --only_transcript, if set, the derivation of the transcript in every haplotype carrying it, \
+        i.e. its mutations, the instructions they are translated into, the execution tasks and the resulting sequence, is printed step by step and the program \
+        exits without writing any output file."))
+    .setting(AppSettings::SubcommandsNegateReqs)
+    .subcommand(App::new("demo")
+        .about("Run the complete pipeline on a small example VCF and reference proteome that are bundled with Vcf2prot and print \
+        a walkthrough of the generated outputs. The input and the results are written to a new directory inside the system temp directory \
+        unless an output directory is provided.")
+        .arg(Arg::new("output_path")
+            .short('o')
+            .long("output_path")
+            .value_name("PATH")
+            .required(false)
+            .about("An optional path to a directory where the demo input and results will be written.")))
+    .subcommand(App::new("check")
+        .about("Check whether a reference proteome matches the annotation of a VCF file, i.e. whether both were generated from the same \
+        Ensembl release. The transcript ids, their version suffixes and the reference residues stated by the consequences are compared \
+        to the reference and the likely cause of a mismatch is reported. The program exits with a non-zero status if the pair is not compatible.")
+        .arg(Arg::new("vcf_file")
+            .short('f')
+            .long("vcf_file")
+            .value_name("FILE")
+            .required(true)
+            .about("The path to the VCF file containing the consequence calls."))
+        .arg(Arg::new("fasta_ref")
+            .short('r')
+            .long("fasta_ref")
+            .value_name("FILE")
+            .required(true)
+            .about("The path to the FASTA file containing the reference proteome."))
+        .arg(Arg::new("max_listed")
+            .long("max_listed")
+            .value_name("NUMBER")
+            .required(false)
+            .default_value("10")
+            .about("The maximum number of transcripts and positions listed per category in the report, defaults to 10.")))
+    .subcommand(App::new("self-test")
+        .alias("self_test")
+        .about("Run the built-in translation vectors, i.e. the translation scenarios of the test suite, through the selected engine and compare \
+        the personalized sequences to their expected values. A PASS or FAIL line is printed per vector and the program exits with a non-zero \
+        status if any vector failed, this confirms that a downloaded binary behaves correctly on the current platform.")
+        .arg(Arg::new("engine")
+            .short('g')
+            .long("engine")
+            .value_name("ENGINE")
+            .required(false)
+            .default_value("mt")
+            .possible_values(&["st","mt","auto"])
+            .about("The execution engine the vectors are run through, either st, mt or auto, defaults to mt."))); 
+    #[cfg(feature = "server")]
+    let app=app.subcommand(App::new("serve")
+        .about("Load the reference proteome once and answer requests for personalized sequences over HTTP until the program is stopped. \
+        POST /sequences expects a JSON object with a sample name and the consequences of each haplotype, as written by bcftools csq, and \
+        returns the sequences of both haplotypes of every altered transcript, GET /health returns the number of loaded transcripts.")
+        .arg(Arg::new("fasta_ref")
+            .short('r')
+            .long("fasta_ref")
+            .value_name("FILE")
+            .required(true)
+            .about("The path to the FASTA file containing the reference proteome."))
+        .arg(Arg::new("address")
+            .short('a')
+            .long("address")
+            .value_name("ADDRESS")
+            .required(false)
+            .default_value("127.0.0.1:8080")
+            .about("The address the server listens on, defaults to 127.0.0.1:8080.")));
+    app.get_matches()
+}
+
+/* 
+#[cfg(target_os="linux")]
+pub fn parse_command_line()->ArgMatches
+{
+    App::new("Vcf2prot")
+    .version("0.1.4")
+    .author("Hesham ElAbd <h.elabd@ikmb.uni-kiel.de>")
+    .about("A rust binary that takes as input a FASTA file containing the reference proteome and \
+     a VCF file containing the consequence calling and apply the mutations of each patient \
+     to the reference file to generate a FASTA file per sample containing the personalized proteome of that individual. \
+     The quality-control checks are controlled with --checks and the diagnostic messages with --log_level, --log_format and --debug_transcript.
+     
+     For more details, see the project webpage at: https://github.com/ikmb/ppg")
+    .arg(Arg::new("vcf_file")
+        .short('f')
+        .long("vcf_file")
+        .value_name("FILE")
+        .help("A VCF File containing the consequences calling for each sample.")
+        .required(true))
+    .arg(Arg::new("fasta_ref")
+        .short('r')
+        .long("fasta_ref")
+        .value_name("FILE")
+        .help("A VCF File containing the reference proteome with transcript id as identifiers and protein sequences as the body.")
+        .required(true))
+    .arg(Arg::new("output_path")
+        .short('o')
+        .long("output_path")
+        .value_name("PATH")
+        .help("The path to a directory where fasta files will be written.")
+        .required(true))
+    .arg(Arg::new("engine")
+        .short('g')
+        .long("engine")
+        .value_name("VALUE")
+        .help("The Execution engine, can be any of three values, 'st' for single thread, 'mt' for multiple threads and 'gpu' for \
+         for using GPU accelerators.")
+        .required(true))
+    .arg(Arg::new("verbose")
+        .short('v')
+        .long("verbose")
+        .required(false)
+        .takes_value(false)
+        .help("If set, print a verbose output about the program state."))
+    .arg(Arg::new("stats")
+        .short('s')
+        .long("stats")
+        .required(false)
+        .takes_value(false)
+        .help("If set, stats are computed and are written to the output directory along with the fasta file"))
+    .arg(Arg::new("write_int_map")
+        .short('i')
+        .long("write_int_map")
+        .required(false)
+        .takes_value(false)
+        .help("Write an intermediate map containing the observed mutation per transcript per patient to sub directory in the provided output \
+        directory, the directory has a predefined name of 'int_maps'. Inside the directory a JSON file containing the \
+        intermediate map of each patient is written."))      
+    .arg(Arg::new("write_all_proteins")
+        .short('a')
+        .long("write_all_proteins")
+        .required(false)
+        .takes_value(false)
+        .help("An optional control flag to control the writing behavior of Vcf2prot, if set Vcf2prot will write the altered and the non-altered, i.e. \
+        reference sequences, to the fasta file of each proband. This might increase the size of the generated files considerably. \
+        By default this option is switched off."))
+    .arg(Arg::new("write_compressed")
+        .short('c')
+        .long("write_compressed")
+        .required(false)
+        .takes_value(false)
+        .help("An optional control flag to control the writing behavior of Vcf2prot, if set Vcf2prot will write the generated fasta files as g-zipped \
+        files, i.e. with the extension .fasta.gz, this can be used to decrease the disk space needed by the generated files, especially, \
+        when generating 1000s of files.By default this option is switched off. "))    
+    .arg(Arg::new("write_single_thread")
+        .short('w')
+        .long("write_single_thread")
+        .required(false)
+        .takes_value(false)
+        .help("An optional control flag to control the writing behavior of Vcf2prot, if set only one thread is used to write all generated fasta files, \
+        by default, this is the case with a single thread engine, i.e. g st, however, this parameter can be used to overwrite this parameter and \
+        to enable a single threaded writing of files when a multi-threaded or a GPU engines have been used for parsing and generating the sequences. "))       
+    .get_matches()
+}
+*/
+
+
+/// The environmental variables that controlled the quality-control checks and the debugging of transcripts before --checks, --log_level 
+/// and --debug_transcript, along with the option replacing each of them 
+const LEGACY_ENV_VARS:[(&str,&str);8]=[("NO_TEST","--checks off"),("RUN_SELECTED_TEST","--checks"),("DEBUG_GPU","--checks"),
+    ("DEBUG_CPU_EXEC","--checks"),("INSPECT_TXP","--checks"),("INSPECT_INS_GEN","--checks"),("PANIC_INSPECT_ERR","--checks strict"),
+    ("DEBUG_TXP","--debug_transcript")];
+
+/// ## Summary
+/// Warn about the legacy environmental variables that are set, they are ignored by this version 
+pub fn warn_legacy_env_vars()
+{
+    for (env_var,option) in LEGACY_ENV_VARS
+    {
+        if std::env::var(env_var).is_ok()
+        {
+            log::warn!("{} is no longer supported and is ignored, use {} instead",env_var,option); 
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,787 @@
+mod cli; 
+use ppgg::parts::{io,exec,demo};
+use ppgg::functions::{hgvs,summary,length_stats,self_test};
+use ppgg::functions::incremental::{self,RunManifest};
+use ppgg::functions::compatibility::CompatibilityReport;
+use ppgg::functions::metrics::MetricsSnapshot;
+use ppgg::functions::run_status::RunBudget;
+use ppgg::functions::coordinate_audit::CoordinateAudit;
+use ppgg::functions::smoke;
+use ppgg::functions::provenance::ProvenanceManifest;
+use ppgg::functions::progress::{ProgressMode,ProgressStage};
+use ppgg::functions::diagnostics::Logger;
+use ppgg::data_structures::InternalRep::engines::Engine;
+use ppgg::data_structures::InternalRep::personalized_genome::{PersonalizedGenome,RecordOrder,UnmodifiedHaplotypes};
+use ppgg::data_structures::InternalRep::transcript_instructions::OverlapSummary;
+use ppgg::data_structures::Map::IntMap;
+use ppgg::data_structures::InternalRep::{sequence_pool,validation};
+use ppgg::data_structures::vcf_ds::UnphasedPolicy;
+use ppgg::functions::sex_chromosomes::SexChromosomeSummary;
+use ppgg::functions::missing_genotypes::MissingGenotypePolicy;
+use ppgg::functions::somatic;
+use ppgg::functions::silent_variants::{self,SilentVariant,SilentVariantPolicy};
+use std::path::{Path, PathBuf}; 
+use std::collections::{HashMap,HashSet};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize,Ordering};
+use std::str::FromStr;
+use ppgg::writers::{self,write_intmaps,write_number_of_dropped_sequences_per_proband}; 
+use chrono::Utc;
+use clap::ArgMatches;
+
+/// ## Summary
+/// The main execution logic of the program which is based on the modular units provided by the ppgg library
+
+
+fn main()
+{
+    let matches=cli::parse_command_line(); 
+    if let Some(demo_args)=matches.subcommand_matches("demo")
+    {
+        println!("Running Vcf2prot on the bundled demo data, starting at: {}",Utc::now()); 
+        let res_path=demo::run_demo(demo_args.value_of("output_path").map(Path::new)).unwrap(); 
+        demo::print_walkthrough(&res_path).unwrap(); 
+        return
+    }
+    if let Some(check_args)=matches.subcommand_matches("check")
+    {
+        let max_listed=match check_args.value_of("max_listed").unwrap().parse::<usize>()
+        {
+            Ok(res)=>res,
+            Err(_)=>panic!("The provided maximum number of listed entries: {} is not a valid number",check_args.value_of("max_listed").unwrap())
+        };
+        let report=io::check_compatibility(Path::new(check_args.value_of("vcf_file").unwrap()),
+            Path::new(check_args.value_of("fasta_ref").unwrap()), Engine::ST, None).unwrap(); 
+        print!("{}",report.to_report(max_listed)); 
+        if !report.is_compatible()
+        {
+            std::process::exit(1); 
+        }
+        return
+    }
+    if let Some(self_test_args)=matches.subcommand_matches("self-test")
+    {
+        let engine=Engine::from_str(self_test_args.value_of("engine").unwrap()).unwrap(); 
+        let report=self_test::run_self_test(engine); 
+        print!("{}",report.to_report()); 
+        if !report.is_passed()
+        {
+            std::process::exit(1); 
+        }
+        return
+    }
+    #[cfg(feature = "server")]
+    if let Some(serve_args)=matches.subcommand_matches("serve")
+    {
+        let ref_seq=io::read_fasta(Path::new(serve_args.value_of("fasta_ref").unwrap()),Engine::ST); 
+        let listener=match std::net::TcpListener::bind(serve_args.value_of("address").unwrap())
+        {
+            Ok(listener)=>listener,
+            Err(err_msg)=>panic!("Binding the address: {} failed with the following error: {}",serve_args.value_of("address").unwrap(),err_msg)
+        };
+        println!("Serving {} reference transcripts on: {}, starting at: {}",ref_seq.len(),serve_args.value_of("address").unwrap(),Utc::now()); 
+        ppgg::parts::server::SequenceServer::new(ref_seq).serve(listener).unwrap(); 
+        return
+    }
+    // the parsing, the execution and the writing run on a scoped thread pool if a number of threads is requested, otherwise on the global pool 
+    match cli::parse_engine_spec(&matches).build_scoped_thread_pool()
+    {
+        Ok(Some(pool))=>pool.install(move ||run(matches)),
+        Ok(None)=>run(matches),
+        Err(err_msg)=>panic!("{}",err_msg)
+    }
+}
+
+/// ## Summary
+/// Print the message of a stage along with the current time, the messages are only printed with --progress log, as the progress bar and
+/// the JSON lines report the stages on their own 
+fn log_stage(args:&cli::ParsedInput, message:&str)
+{
+    if args.progress_mode==ProgressMode::Log
+    {
+        println!("{}: {}",message,Utc::now()); 
+    }
+}
+
+/// ## Summary
+/// Read the reference sequences and, if an id map was provided, re-key the sequences stored under a protein id by the mapped transcripts 
+fn load_reference(args:&cli::ParsedInput)->HashMap<String,String>
+{
+    let mut ref_seq=io::read_fasta(Path::new(&args.path2fasta),args.engine.clone()); 
+    if let Some(id_map)=args.writer_options.id_map.as_ref()
+    {
+        let num_rekeyed=id_map.apply_to_reference(&mut ref_seq); 
+        println!("{} reference sequences were mapped to transcripts using the id map of {} transcripts",num_rekeyed,id_map.len()); 
+    }
+    ref_seq
+}
+
+/// ## Summary
+/// Generate the personalized proteomes as requested on the command line, i.e. everything besides the subcommands 
+fn run(matches:ArgMatches)
+{
+    let args = cli::ParsedInput::new(matches);
+
+    if let Err(err_msg)=Logger::new(args.log_level, args.log_format).init()
+    {
+        eprintln!("ERROR:: {}",err_msg); 
+    }
+    cli::warn_legacy_env_vars(); 
+    args.progress.start_stage(ProgressStage::Parsing); 
+    args.start_reporter(); 
+    if args.is_verbose
+    {
+        println!("The execution engine is: {:?}",args.engine); 
+        log_stage(&args, "Reading and loading the VCF file, starting time is"); 
+    }
+    let mut budget=RunBudget::default(); 
+    if let Some(path2genome)=args.path2genome.as_ref()
+    {
+        log_stage(&args, "Cross-checking the consequences against the genome, starting at"); 
+        let num_inconsistent=io::validate_variant_effects(Path::new(&args.path2vcf), Path::new(path2genome), Path::new(&args.res_path),
+            args.engine.clone(), args.csq_map.as_ref(), args.contig_policy, &args.parse_options, &args.writer_options).unwrap(); 
+        if num_inconsistent!=0
+        {
+            println!("WARNING:: {} consequences are inconsistent with the genome or with their protein change, check inconsistent_annotations.tsv for more details",num_inconsistent); 
+        }
+        budget.add_inconsistent_annotations(num_inconsistent); 
+    }
+    // a MAF file needs the reference before its conversion, as its pure insertions and deletions are anchored to the reference residues 
+    let (mut vec_int_repr,vcf_summaries,preloaded_ref_seq)=match args.path2maf.as_ref()
+    {
+        Some(path2maf)=>
+        {
+            let ref_seq=load_reference(&args); 
+            let (vec_int_repr,maf_summary)=io::parse_maf(Path::new(path2maf),args.engine.clone(),&ref_seq).unwrap(); 
+            print!("{}",maf_summary.to_report(10)); 
+            (vec_int_repr,None,Some(ref_seq))
+        },
+        None if !args.paths2mutation_tsv.is_empty()=>
+        {
+            let paths2mutation_tsv=args.paths2mutation_tsv.iter().map(Path::new).collect::<Vec<&Path>>(); 
+            let vec_int_repr=io::parse_mutation_tsvs(&paths2mutation_tsv,args.engine.clone()).unwrap(); 
+            println!("The mutation lists of {} samples were read",vec_int_repr.len()); 
+            (vec_int_repr,None,None)
+        },
+        None=>
+        {
+            let mut parse_outcome=match (args.smoke.as_ref(),args.stream_chunk_size)
+            {
+                (Some(config),_)=>io::parse_vcf_smoke(Path::new(&args.path2vcf),args.engine.clone(),args.csq_map.as_ref(),
+                    args.contig_policy,args.missing_gt_policy,config,&args.parse_options).unwrap(),
+                (None,None) if !args.regions.is_empty()=>io::parse_vcf_regions(Path::new(&args.path2vcf),&args.regions,args.engine.clone(),
+                    args.csq_map.as_ref(),args.contig_policy,args.missing_gt_policy,&args.parse_options).unwrap(),
+                (None,Some(chunk_size))=>io::parse_vcf_streaming(Path::new(&args.path2vcf),args.engine.clone(),args.csq_map.as_ref(),
+                    args.contig_policy,args.missing_gt_policy,chunk_size,&args.parse_options).unwrap(),
+                (None,None)=>io::parse_vcf_with_policies(Path::new(&args.path2vcf),args.engine.clone(),args.csq_map.as_ref(),
+                    args.contig_policy,args.missing_gt_policy,&args.parse_options).unwrap()
+            };
+            if let Some(metrics)=args.execution_options.metrics.as_ref()
+            {
+                metrics.record_parse(&parse_outcome.vec_int_map, &parse_outcome.decoding_summary); 
+            }
+            let vec_int_repr=std::mem::take(&mut parse_outcome.vec_int_map); 
+            (vec_int_repr,Some(parse_outcome),None)
+        }
+    };
+    let sex_chromosomes=match args.path2sample_sex.as_ref()
+    {
+        Some(path2table)=>
+        {
+            let summary=io::apply_sample_sexes(Path::new(&args.path2vcf), &mut vec_int_repr, Path::new(path2table), args.par_build, 
+                args.engine.clone(), args.csq_map.as_ref(), args.contig_policy, &args.parse_options).unwrap(); 
+            print!("{}",summary.to_report(10)); 
+            Some(summary)
+        },
+        None=>None
+    };
+    if args.is_verbose
+    {
+        log_stage(&args, "VCF file have been parsed and encoded into a vector of intermediate representations, finished at"); 
+        log_stage(&args, "Loading the Reference file, starting time is"); 
+    }
+    args.progress.start_stage(ProgressStage::Loading); 
+    let mut ref_seq=match preloaded_ref_seq
+    {
+        Some(ref_seq)=>ref_seq,
+        None=>load_reference(&args)
+    }; 
+    if let Some(positions)=args.preflight_positions
+    {
+        let report=CompatibilityReport::with_sample_size(&vec_int_repr, &ref_seq, positions); 
+        writers::write_reference_mismatches(Path::new(&args.res_path), &report, &args.writer_options).unwrap(); 
+        print!("Pre-flight check of the reference:\n{}{}",report.to_report(10),report.to_mismatch_summary(10)); 
+        if !report.is_compatible()
+        {
+            println!("WARNING:: the reference proteome likely does not match the annotation of the VCF, check reference_mismatches.tsv for the mismatches per transcript"); 
+        }
+    }
+    if let Some(threshold)=args.missing_transcripts_threshold
+    {
+        let coverage=summary::compute_transcript_coverage(&vec_int_repr, &ref_seq, args.engine.clone(), args.execution_options.overlap_policy, &args.execution_options.diagnostic_options); 
+        print!("{}",coverage.to_report(10)); 
+        if coverage.percent_failed()>threshold
+        {
+            eprintln!("ERROR:: {:.2}% of the transcripts referenced in the VCF could not be personalized, which is more than the allowed threshold of {}%, \
+                check that the reference proteome matches the annotation of the VCF",coverage.percent_failed(),threshold); 
+            std::process::exit(1); 
+        }
+    }
+    if let Some(transcript)=args.only_transcript.as_ref()
+    {
+        exec::restrict_to_transcript(&mut vec_int_repr, &mut ref_seq, transcript).unwrap(); 
+        if args.trace
+        {
+            print!("{}",exec::trace_transcript(&vec_int_repr, transcript, &ref_seq, &args.execution_options).unwrap().to_report()); 
+            return
+        }
+    }
+    if args.validate
+    {
+        let report=validation::validate_representations(&mut vec_int_repr, &ref_seq, args.engine.clone(), args.execution_options.overlap_policy, &args.execution_options.diagnostic_options); 
+        writers::write_validation_report(Path::new(&args.res_path), &report, &args.writer_options).unwrap(); 
+        match report.is_empty()
+        {
+            true=>println!("Validation: all {} altered transcripts have contiguous tasks of the expected size",report.num_validated),
+            false=>print!("Validation: the number of violations per kind and the transcripts with the most violations, see validation_report.tsv for the violations per proband:\n{}",
+                report.to_summary(10))
+        }
+    }
+    for transcript in args.execution_options.diagnostic_options.debug_filter.get_transcripts()
+    {
+        match exec::trace_transcript(&vec_int_repr, transcript, &ref_seq, &args.execution_options)
+        {
+            Ok(trace)=>log::debug!("The trace of the transcript: {} is:\n{}",transcript,trace.to_report().trim_end()),
+            Err(err_msg)=>log::warn!("tracing the transcript: {} set by --debug_transcript failed with the following error: {}",transcript,err_msg)
+        }
+    }
+    if args.status_exit_codes
+    {
+        budget.add_coverage(&summary::compute_transcript_coverage(&vec_int_repr, &ref_seq, args.engine.clone(), args.execution_options.overlap_policy, &args.execution_options.diagnostic_options)); 
+        budget.add_compatibility(&CompatibilityReport::new(&vec_int_repr, &ref_seq)); 
+    }
+    if args.count_only
+    {
+        print!("{}",exec::sequence_counts_to_report(&exec::count_sequences(vec_int_repr, args.engine.clone(), &ref_seq, &args.execution_options))); 
+        if args.status_exit_codes
+        {
+            exit_with_status(&args, &budget); 
+        }
+        return
+    }
+    if args.write_i_map
+    {
+        log_stage(&args, "Writing the intermediate representation map, starting at"); 
+        let mut pathbuf=PathBuf::from(&args.res_path.clone());
+        pathbuf.push("int_maps"); 
+        let write_path=Path::new(&pathbuf); 
+        write_intmaps(write_path,&vec_int_repr,args.int_map_format,&args.writer_options).unwrap(); 
+    }
+    // the gene symbols are collected while the VCF file is parsed, the MAF and the mutation lists do not carry them 
+    let no_genes=HashMap::new(); 
+    let transcript_genes=vcf_summaries.as_ref().map(|parse_outcome|&parse_outcome.annotations.transcript_genes).unwrap_or(&no_genes); 
+    // the cohort statistics are computed before the execution consumes the representations and written once the execution metrics are known 
+    let mut cohort_statistics=None; 
+    if args.compute_state
+    {
+        log_stage(&args, "Computing and writing the stats, starting at"); 
+        cohort_statistics=Some(summary::compute_cohort_statistics(&vec_int_repr, &ref_seq, transcript_genes)); 
+        // the remaining summary tables are written from the metrics accumulated during the execution 
+        writers::write_haplotype_spread_per_proband(Path::new(&args.res_path), summary::compute_haplotype_spread(&vec_int_repr), &args.writer_options).unwrap(); 
+        io::compute_and_write_normalized_load(Path::new(&args.res_path), &vec_int_repr, &ref_seq, &args.writer_options); 
+        io::compute_and_write_transcript_decoration(Path::new(&args.res_path), &vec_int_repr, &ref_seq, &args.writer_options).unwrap(); 
+        if let Some(summary)=sex_chromosomes.as_ref()
+        {
+            writers::write_sex_chromosomes_per_proband(Path::new(&args.res_path), summary, &args.writer_options).unwrap(); 
+        }
+        if let Some(parse_outcome)=vcf_summaries.as_ref()
+        {
+            writers::write_missing_genotypes_per_proband(Path::new(&args.res_path), &parse_outcome.missing_genotypes, &args.writer_options).unwrap();
+            writers::write_duplicate_records_per_proband(Path::new(&args.res_path), &parse_outcome.duplicate_records, &args.writer_options).unwrap(); 
+        }
+        log_stage(&args, "Computing and writing the stats, finished at"); 
+        log_stage(&args, "Generating personalized genomes: starting at"); 
+    }
+    let silent_variants:&[SilentVariant]=vcf_summaries.as_ref().map(|parse_outcome|parse_outcome.annotations.silent_variants.as_slice()).unwrap_or_default(); 
+    if args.parse_options.silent_variant_policy!=SilentVariantPolicy::Skip
+    {
+        writers::write_silent_variants(Path::new(&args.res_path), silent_variants, &args.writer_options).unwrap(); 
+    }
+    let hgvs_changes=match (args.write_hgvs,args.hgvs_headers || args.gene_report)
+    {
+        (true,_)=>io::compute_and_write_hgvs_changes(&vec_int_repr, &ref_seq, args.engine.clone(), &args.res_path, &args.writer_options).unwrap(),
+        (false,true)=>hgvs::get_hgvs_changes(&vec_int_repr, &ref_seq, args.engine.clone()),
+        (false,false)=>Vec::new()
+    };
+    if args.gene_report
+    {
+        let num_genes=io::compute_and_write_gene_report(&hgvs_changes, transcript_genes, Path::new(&args.res_path), &args.writer_options).unwrap(); 
+        if args.is_verbose
+        {
+            log_stage(&args, &format!("The protein changes of {} genes have been written to gene_report.tsv, finished at",num_genes)); 
+        }
+    }
+    let custom_records=match args.path2append_fasta.as_ref()
+    {
+        Some(path2file)=>io::read_custom_records(Path::new(path2file),args.engine.clone()).unwrap(),
+        None=>Vec::new()
+    };
+    let settings=format!("{:?}",(args.write_all,args.emit_reference,args.writer_options.stop_codon_policy,args.execution_options.overlap_policy,args.write_compressed,args.min_length,args.write_gff,args.write_changes,&args.consensus,args.sort_output,
+        args.unmodified_haplotypes,args.hgvs_headers)); 
+    let mut manifest=RunManifest::new(incremental::get_settings_digest(&settings, &ref_seq, &custom_records), &vec_int_repr, args.engine.clone()); 
+    let is_output_present=|file_stem:&str|Path::new(&args.res_path).join(args.writer_options.compression_policy.get_fasta_name(file_stem,args.write_compressed)).exists(); 
+    // the samples whose files are complete before the writing starts, they are the starting point of the progress file 
+    let mut completed_samples=HashSet::new(); 
+    if args.incremental
+    {
+        match io::read_run_manifest(&args.res_path).unwrap()
+        {
+            Some(previous)=>
+            {
+                let reusable=manifest.get_reusable_samples(&previous, is_output_present); 
+                vec_int_repr.retain(|int_map|!reusable.contains(int_map.get_name())); 
+                println!("Incremental run: the files of {} samples are reused, {} samples are regenerated",reusable.len(),vec_int_repr.len()); 
+                completed_samples=reusable; 
+            },
+            None=>println!("Incremental run: no run manifest was found in the output directory, all {} samples are regenerated",vec_int_repr.len())
+        }
+    }
+    let progress=match args.resume
+    {
+        true=>io::read_run_progress(&args.res_path).unwrap(),
+        false=>None
+    };
+    if let Some(progress)=progress.as_ref()
+    {
+        if progress.settings_digest!=manifest.settings_digest
+        {
+            eprintln!("ERROR:: the reference, the VCF parsing or the output options changed since the interrupted run, hence, its completed samples can not \
+                be kept, run again without --resume"); 
+            std::process::exit(1); 
+        }
+        completed_samples=progress.get_completed_samples(&manifest, is_output_present); 
+        manifest.set_truncated_samples(&progress.get_truncated_samples().into_iter().filter(|proband_name|completed_samples.contains(proband_name)).collect()); 
+        vec_int_repr.retain(|int_map|!completed_samples.contains(int_map.get_name())); 
+        println!("Resumed run: the previous run was interrupted, the files of {} samples were completed and are kept, {} samples are written",
+            completed_samples.len(),vec_int_repr.len()); 
+    }
+    else if args.resume
+    {
+        let mut previous=match io::read_run_manifest(&args.res_path).unwrap()
+        {
+            Some(previous)=>previous,
+            None=>
+            {
+                eprintln!("ERROR:: --resume requires the run manifest of the previous run, i.e. {}, in the output directory: {}",
+                    writers::RUN_MANIFEST_FILE_NAME,args.res_path); 
+                std::process::exit(1); 
+            }
+        };
+        if previous.settings_digest!=manifest.settings_digest
+        {
+            eprintln!("ERROR:: the reference, the VCF parsing or the output options changed since the previous run, hence, its failed samples can not be \
+                resumed, use --incremental instead"); 
+            std::process::exit(1); 
+        }
+        let failed_samples=previous.get_failed_samples().into_iter().collect::<HashSet<String>>(); 
+        vec_int_repr.retain(|int_map|failed_samples.contains(int_map.get_name())); 
+        println!("Resumed run: the files of {} samples that could not be written by the previous run are written again",vec_int_repr.len()); 
+        previous.update_entries(&manifest, &failed_samples); 
+        manifest=previous; 
+        completed_samples=manifest.entries.iter().map(|entry|entry.proband_name.clone()).filter(|proband_name|!failed_samples.contains(proband_name)).collect(); 
+    }
+    // the subsets are extracted before the execution consumes the representations, and written once the file stems are assigned 
+    let sample_vcfs=match args.write_sample_vcfs
+    {
+        true=>Some(io::compute_sample_vcf_subsets(Path::new(&args.path2vcf), &vec_int_repr, &ref_seq, args.engine.clone(), args.csq_map.as_ref(), 
+            args.contig_policy, args.missing_gt_policy, &args.parse_options).unwrap()),
+        false=>None
+    };
+    args.progress.set_num_probands(vec_int_repr.len()); 
+    if let Some(capacity)=args.pipeline_capacity
+    {
+        // the genomes are written while they are executed, hence, the progress follows the written genomes 
+        args.progress.start_stage(ProgressStage::Writing); 
+        writers::start_run_progress(Path::new(&args.res_path), &manifest.get_progress(&completed_samples), &args.writer_options).unwrap(); 
+        let (outcome,overlap_summary)=execute_and_write_pipelined(&args, vec_int_repr, &ref_seq, sex_chromosomes.as_ref(), vcf_summaries.as_ref(), 
+            Some(silent_variants).filter(|_|args.parse_options.silent_variant_policy==SilentVariantPolicy::Headers), &manifest, &custom_records, capacity); 
+        finish_run(&args, manifest, outcome, HashSet::new(), &budget, vcf_summaries.as_ref(), &overlap_summary); 
+        return
+    }
+    // the features are needed for the GFF3 files, the tables of applied changes and the peptide windows 
+    let compute_features=args.write_gff || args.write_changes.is_some() || args.peptide_window.is_some(); 
+    args.progress.start_stage(ProgressStage::Executing); 
+    let (mut vec_per_genomes,skipped_probands)=match args.skip_errors
+    {
+        true=>
+        {
+            let (vec_per_genomes,execution_failures)=exec::execute_with_recovery(vec_int_repr, args.engine.clone(), &ref_seq, compute_features, &args.execution_options); 
+            writers::write_execution_failures(Path::new(&args.res_path), &execution_failures, &args.writer_options).unwrap(); 
+            let skipped_probands=execution_failures.iter().filter(|failure|failure.haplotype.is_none())
+                .map(|failure|failure.proband_name.clone()).collect::<HashSet<String>>(); 
+            if !execution_failures.is_empty()
+            {
+                println!("WARNING:: {} transcripts and {} probands failed during the execution and were skipped, the rest of the proteome was generated, \
+                    the skipped transcripts and probands along with the reason are listed in failures.tsv",
+                    execution_failures.len()-skipped_probands.len(),skipped_probands.len()); 
+            }
+            (vec_per_genomes,skipped_probands)
+        },
+        false=>(exec::execute(vec_int_repr, args.engine.clone(), &ref_seq, compute_features, &args.execution_options),HashSet::new())
+    };
+    if args.hgvs_headers
+    {
+        io::add_hgvs_header_tags(&mut vec_per_genomes, &hgvs_changes); 
+    }
+    if let Some(summary)=sex_chromosomes.as_ref()
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_single_copy_transcripts(summary.get_single_copy_transcripts(genome.get_proband_name()))); 
+    }
+    if let Some(missing_genotypes)=vcf_summaries.as_ref().map(|parse_outcome|&parse_outcome.missing_genotypes).filter(|missing_genotypes|missing_genotypes.policy==MissingGenotypePolicy::Flag)
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_missing_call_transcripts(missing_genotypes.get_flagged_transcripts(genome.get_proband_name()))); 
+    }
+    if let Some(parse_outcome)=vcf_summaries.as_ref().filter(|_|args.parse_options.somatic_mode.is_some())
+    {
+        let support_tags=somatic::get_support_tags(&parse_outcome.annotations.variant_support); 
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_variant_support_tags(support_tags.clone())); 
+    }
+    if args.parse_options.silent_variant_policy==SilentVariantPolicy::Headers
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_silent_variant_tags(silent_variants::get_header_tags(silent_variants, genome.get_proband_name()))); 
+    }
+    if args.sort_output
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_record_order(RecordOrder::Sorted)); 
+    }
+    if args.emit_reference
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_emit_reference(true)); 
+    }
+    if args.unmodified_haplotypes!=UnmodifiedHaplotypes::Write
+    {
+        vec_per_genomes.iter_mut().for_each(|genome|genome.set_unmodified_haplotypes(args.unmodified_haplotypes)); 
+        if args.is_verbose
+        {
+            let num_single_haplotype=vec_per_genomes.iter().map(|genome|genome.get_num_single_haplotype_transcripts()).sum::<u64>(); 
+            println!("{} transcripts, summed over all probands, are only altered in one haplotype, their unmodified haplotype is handled with the policy: {:?}",
+                num_single_haplotype,args.unmodified_haplotypes);
+        }
+    }
+    if args.is_verbose
+    {
+        log_stage(&args, "Personalized proteomes have been generated, finished at"); 
+    }
+    let overlap_summary=vec_per_genomes.iter().fold(OverlapSummary::default(),|mut overlap_summary,genome|
+    {
+        overlap_summary.merge(genome.get_overlap_summary()); 
+        overlap_summary
+    }); 
+    let num_rejected=vec_per_genomes.iter()
+        .map(|genome|{let (report1,report2)=genome.get_execution_reports(); report1.rejected_transcripts.len()+report2.rejected_transcripts.len()})
+        .sum::<usize>(); 
+    if num_rejected!=0
+    {
+        println!("WARNING:: {} transcripts, summed over all haplotypes, still contained placeholder residues after the execution and were not written, \
+            they are listed as rejected transcripts in execution_status_per_proband.tsv when --stats is set",num_rejected); 
+    }
+    let num_recovered=vec_per_genomes.iter().map(|genome|genome.get_num_recovered_haplotypes()).sum::<usize>(); 
+    if num_recovered!=0
+    {
+        println!("WARNING:: the sequences of {} haplotypes were produced under error-recovery heuristics, their altered records are marked with status=recovered",num_recovered); 
+    }
+    if args.coordinate_audit
+    {
+        let audit=CoordinateAudit::from_genomes(&vec_per_genomes); 
+        writers::write_coordinate_audit(Path::new(&args.res_path), &audit, &args.writer_options).unwrap(); 
+        match audit.is_empty()
+        {
+            true=>println!("Coordinate audit: no clamped task, size mismatch or unwritten residue was encountered"),
+            false=>print!("Coordinate audit: the number of haplotypes per issue and the most frequent issues, see coordinate_audit.tsv for the issues per transcript:\n{}",
+                audit.to_summary(10))
+        }
+    }
+    if args.compute_state
+    {
+        writers::write_execution_reports(Path::new(&args.res_path), &vec_per_genomes, &args.writer_options).unwrap(); 
+        writers::write_length_delta_histograms(Path::new(&args.res_path), &summary::compute_length_deltas(&vec_per_genomes, &ref_seq), &args.writer_options).unwrap(); 
+        let snapshot=get_metrics_snapshot(&args); 
+        io::write_accumulated_summary(Path::new(&args.res_path), &snapshot, &args.writer_options).unwrap(); 
+        if let Some(mut statistics)=cohort_statistics
+        {
+            statistics.add_execution_metrics(&snapshot); 
+            writers::write_cohort_statistics(Path::new(&args.res_path), &statistics, &args.writer_options).unwrap(); 
+            let (num_mutations,num_missing,num_dropped)=statistics.get_totals(); 
+            println!("Cohort statistics: {} mutations in {} transcripts of {} genes, {} mutations on transcripts missing from the reference were skipped \
+                and {} consequences were dropped, see the stats_per_*.tsv tables and {}",num_mutations,statistics.transcripts.len(),statistics.genes.len(),
+                num_missing,num_dropped,writers::COHORT_STATISTICS_FILE_NAME); 
+        }
+    }
+    if !args.aggregate_only
+    {
+        let cohort=manifest.entries.iter().map(|entry|entry.proband_name.clone()).collect::<Vec<String>>(); 
+        let num_renamed=io::assign_file_stems_for_cohort(&mut vec_per_genomes, &cohort, &args.res_path, &args.writer_options).unwrap(); 
+        if num_renamed!=0
+        {
+            println!("The names of {} probands can not be used as file names, their files are renamed as listed in: sample_file_names.tsv",num_renamed);
+        }
+    }
+    if args.is_verbose
+    {
+        log_stage(&args, "Write the generated results, starting at"); 
+    }
+    args.progress.start_stage(ProgressStage::Writing); 
+    if let Some(subsets)=sample_vcfs.as_ref()
+    {
+        let num_records=io::write_sample_vcfs(subsets, &vec_per_genomes, &args.res_path, &args.writer_options).unwrap(); 
+        if args.is_verbose
+        {
+            println!("The VCF subsets of {} samples, with {} applied records in total, have been written to: {}",subsets.samples.len(),num_records,
+                writers::SAMPLE_VCF_DIR_NAME); 
+        }
+    }
+    if args.write_gff
+    {
+        io::write_variant_features(&vec_per_genomes, args.engine.clone(), &args.res_path, &args.writer_options); 
+    }
+    if let Some(format)=args.write_changes
+    {
+        io::write_applied_changes(&vec_per_genomes, args.engine.clone(), &args.res_path, &ref_seq, format, &args.writer_options); 
+    }
+    if let Some(rule)=args.consensus.as_ref()
+    {
+        io::write_consensus_genomes(&vec_per_genomes, args.engine.clone(), &args.res_path, &ref_seq, args.min_length, rule, 
+            &args.writer_options); 
+    }
+    if args.aggregate_only
+    {
+        let summary=io::write_cohort_aggregate(&vec_per_genomes, args.engine.clone(), &args.res_path, args.write_compressed, 
+            args.min_length, args.min_carriers, &custom_records, &args.writer_options).unwrap(); 
+        println!("{} unique sequences carried by at least {} samples have been written, {} sequences have been suppressed",
+            summary.num_reported_sequences, summary.min_carriers, summary.num_suppressed_sequences); 
+        args.progress.start_stage(ProgressStage::Finished); 
+        write_provenance(&args, vec_per_genomes.len()); 
+        args.finish_reporter(); 
+        if args.is_verbose
+        {
+            log_stage(&args, "Execution finished at"); 
+        }
+        if args.status_exit_codes
+        {
+            exit_with_status(&args, &budget); 
+        }
+        return
+    }
+    if let Some(members)=args.pool_sequences
+    {
+        let (summary,outcome)=io::write_pooled_genomes(&vec_per_genomes, args.engine.clone(), &args.res_path, args.write_compressed, &ref_seq, 
+            args.min_length, members, &custom_records, &args.writer_options).unwrap(); 
+        println!("{} sequences of {} samples have been pooled into {} unique sequences, {} of them have been written to {}.fasta",summary.num_pooled_sequences,
+            summary.num_probands,summary.num_unique_sequences,summary.num_written_sequences,sequence_pool::POOLED_FASTA_FILE_STEM); 
+        finish_run(&args, manifest, outcome, skipped_probands, &budget, vcf_summaries.as_ref(), &overlap_summary); 
+        return
+    }
+    if let Some(flank)=args.peptide_window
+    {
+        let outcome=io::write_peptide_windows(&vec_per_genomes, args.engine.clone(), &args.res_path, args.write_compressed, &ref_seq, flank, &args.writer_options); 
+        finish_run(&args, manifest, outcome, skipped_probands, &budget, vcf_summaries.as_ref(), &overlap_summary); 
+        return
+    }
+    if args.writer_options.fasta_layout.layout==writers::OutputLayout::PerProband
+    {
+        writers::start_run_progress(Path::new(&args.res_path), &manifest.get_progress(&completed_samples), &args.writer_options).unwrap(); 
+    }
+    let outcome=io::write_personalized_genomes_with_layout(vec_per_genomes, args.engine.clone(), args.res_path.clone(),
+         args.write_single_thread,args.write_all,
+         args.write_compressed, &ref_seq, args.min_length, &custom_records, &args.writer_options).unwrap();
+    finish_run(&args, manifest, outcome, skipped_probands, &budget, vcf_summaries.as_ref(), &overlap_summary); 
+}
+
+/// ## Summary
+/// Generate and write the personalized genomes through a bounded pipeline, see io::execute_and_write_pipelined, where the genomes are 
+/// prepared for writing one by one, as the options that need all genomes at once are rejected with --pipeline_capacity, returns the outcome
+/// of writing along with the overlapping mutations resolved in all genomes 
+#[allow(clippy::too_many_arguments)]
+fn execute_and_write_pipelined(args:&cli::ParsedInput, vec_int_repr:Vec<IntMap>, ref_seq:&HashMap<String,String>, 
+    sex_chromosomes:Option<&SexChromosomeSummary>, parse_outcome:Option<&io::ParseOutcome>, silent_variants:Option<&[SilentVariant]>, manifest:&RunManifest, custom_records:&[(String,String)], capacity:usize)->(io::WriteOutcome,OverlapSummary)
+{
+    let cohort=manifest.entries.iter().map(|entry|entry.proband_name.clone()).collect::<Vec<String>>(); 
+    let (file_stems,num_renamed)=io::get_file_stems_for_cohort(&cohort, &args.res_path, &args.writer_options).unwrap(); 
+    if num_renamed!=0
+    {
+        println!("The names of {} probands can not be used as file names, their files are renamed as listed in: sample_file_names.tsv",num_renamed);
+    }
+    if args.is_verbose
+    {
+        log_stage(args, &format!("Generate and write the personalized genomes with at most {} genomes waiting for a writer, starting at",capacity)); 
+    }
+    let (num_rejected,num_recovered)=(AtomicUsize::new(0),AtomicUsize::new(0)); 
+    let overlap_summary=Mutex::new(OverlapSummary::default()); 
+    let support_tags=parse_outcome.filter(|_|args.parse_options.somatic_mode.is_some())
+        .map(|parse_outcome|somatic::get_support_tags(&parse_outcome.annotations.variant_support)); 
+    let missing_genotypes=parse_outcome.map(|parse_outcome|&parse_outcome.missing_genotypes); 
+    let prepare=|genome:&mut PersonalizedGenome|
+    {
+        if let Some(support_tags)=support_tags.as_ref()
+        {
+            genome.set_variant_support_tags(support_tags.clone()); 
+        }
+        if let Some(summary)=sex_chromosomes
+        {
+            genome.set_single_copy_transcripts(summary.get_single_copy_transcripts(genome.get_proband_name())); 
+        }
+        if let Some(summary)=missing_genotypes.filter(|summary|summary.policy==MissingGenotypePolicy::Flag)
+        {
+            genome.set_missing_call_transcripts(summary.get_flagged_transcripts(genome.get_proband_name())); 
+        }
+        if let Some(silent_variants)=silent_variants
+        {
+            genome.set_silent_variant_tags(silent_variants::get_header_tags(silent_variants, genome.get_proband_name())); 
+        }
+        if args.sort_output
+        {
+            genome.set_record_order(RecordOrder::Sorted); 
+        }
+        if args.unmodified_haplotypes!=UnmodifiedHaplotypes::Write
+        {
+            genome.set_unmodified_haplotypes(args.unmodified_haplotypes); 
+        }
+        genome.set_emit_reference(args.emit_reference); 
+        if let Some(file_stem)=file_stems.get(genome.get_proband_name())
+        {
+            genome.set_file_stem(file_stem.clone()); 
+        }
+        let (report1,report2)=genome.get_execution_reports(); 
+        num_rejected.fetch_add(report1.rejected_transcripts.len()+report2.rejected_transcripts.len(), Ordering::Relaxed); 
+        num_recovered.fetch_add(genome.get_num_recovered_haplotypes(), Ordering::Relaxed); 
+        overlap_summary.lock().unwrap().merge(genome.get_overlap_summary()); 
+    };
+    let outcome=io::execute_and_write_pipelined(vec_int_repr, args.engine.clone(), ref_seq, args.write_gff, capacity, prepare, 
+        args.res_path.clone(), args.write_single_thread, args.write_all, args.write_compressed, args.min_length, custom_records, &args.execution_options, 
+        &args.writer_options); 
+    if num_rejected.load(Ordering::Relaxed)!=0
+    {
+        println!("WARNING:: {} transcripts, summed over all haplotypes, still contained placeholder residues after the execution and were not written",
+            num_rejected.load(Ordering::Relaxed)); 
+    }
+    if num_recovered.load(Ordering::Relaxed)!=0
+    {
+        println!("WARNING:: the sequences of {} haplotypes were produced under error-recovery heuristics, their altered records are marked with status=recovered",
+            num_recovered.load(Ordering::Relaxed)); 
+    }
+    (outcome,overlap_summary.into_inner().unwrap())
+}
+
+/// ## Summary
+/// Record the outcome of writing the personalized genomes in the run manifest, report the truncated and the failed samples and write the 
+/// length statistics along with the warnings of the parse, if a VCF file was parsed, the process exits with 1 if the files of a sample could 
+/// not be written 
+fn finish_run(args:&cli::ParsedInput, mut manifest:RunManifest, outcome:io::WriteOutcome, skipped_probands:HashSet<String>, budget:&RunBudget, 
+    parse_outcome:Option<&io::ParseOutcome>, overlap_summary:&OverlapSummary)
+{
+    let (num_dropped,length_stats,failures)=(outcome.num_dropped,outcome.length_stats,outcome.failures); 
+    manifest.set_failed_samples(&failures.iter().map(|(proband_name,_)|proband_name.clone()).chain(skipped_probands).collect::<HashSet<String>>()); 
+    manifest.set_truncated_samples(&outcome.truncated.iter().map(|(proband_name,_)|proband_name.clone()).collect::<HashSet<String>>()); 
+    writers::write_run_manifest(Path::new(&args.res_path), &manifest, &args.writer_options).unwrap(); 
+    writers::finish_run_progress(Path::new(&args.res_path), &args.writer_options).unwrap(); 
+    let num_unphased_calls=parse_outcome.map(|parse_outcome|parse_outcome.decoding_summary.num_unphased_calls).unwrap_or(0); 
+    if args.parse_options.decoding_options.unphased_policy==UnphasedPolicy::Assume && num_unphased_calls!=0
+    {
+        println!("WARNING:: the consequences of {} unphased heterozygous calls were assigned to the haplotypes as if the calls were phased, \
+        use --unphased collapse, both or skip to handle them explicitly",num_unphased_calls); 
+    }
+    if let Some(overlap_policy)=args.execution_options.overlap_policy
+    {
+        print!("{}",overlap_summary.to_report(overlap_policy)); 
+    }
+    if let Some(config)=args.smoke.as_ref()
+    {
+        let smoke_files=smoke::get_smoke_files(Path::new(&args.res_path), &manifest).unwrap(); 
+        writers::write_smoke_manifest(Path::new(&args.res_path), &smoke_files, &args.writer_options).unwrap(); 
+        println!("Smoke run: the first {} records and {} samples were processed, the {} written files are listed in {}",config.num_records,
+            config.num_samples,smoke_files.len(),writers::SMOKE_MANIFEST_FILE_NAME); 
+    }
+    if !outcome.truncated.is_empty()
+    {
+        println!("WARNING:: the fasta files of {} samples exceeded the output quota and were truncated, they are marked as truncated in {}, \
+            {} bytes were written in total, the first truncated samples and the number of sequences that were not written are:",
+            outcome.truncated.len(),writers::RUN_MANIFEST_FILE_NAME,outcome.num_written_bytes); 
+        for (proband_name,num_truncated) in outcome.truncated.iter().take(10)
+        {
+            println!("\t{}\t{}",proband_name,num_truncated); 
+        }
+    }
+    if !failures.is_empty()
+    {
+        eprintln!("ERROR:: the files of {} samples could not be written, even after {} retries, they are marked as failed in {} and can be written again \
+            with --resume, the first failed samples are:",failures.len(),args.writer_options.retry_policy.max_retries,writers::RUN_MANIFEST_FILE_NAME); 
+        for (proband_name,err_msg) in failures.iter().take(10)
+        {
+            eprintln!("\t{}\t{}",proband_name,err_msg); 
+        }
+    }
+    let anomalies_report=length_stats::anomalies_to_report(&length_stats, 10); 
+    if !anomalies_report.is_empty()
+    {
+        print!("{}",anomalies_report); 
+    }
+    if args.compute_state
+    {
+        log_stage(args, "Writing the number of dropped sequences per proband, starting at"); 
+        write_number_of_dropped_sequences_per_proband(Path::new(&args.res_path), num_dropped, &args.writer_options).unwrap(); 
+        log_stage(args, "Writing the sequence length histogram and anomalies per proband, starting at"); 
+        writers::write_sequence_length_histograms(Path::new(&args.res_path), &length_stats, &args.writer_options).unwrap(); 
+        writers::write_sequence_length_anomalies(Path::new(&args.res_path), &length_stats, &args.writer_options).unwrap(); 
+    }
+    args.progress.start_stage(ProgressStage::Finished); 
+    write_provenance(args, manifest.entries.len()); 
+    args.finish_reporter(); 
+    if args.is_verbose
+    {
+        log_stage(args, "Execution finished at"); 
+    } 
+    if !failures.is_empty()
+    {
+        std::process::exit(1); 
+    }
+    if args.status_exit_codes
+    {
+        exit_with_status(args, budget); 
+    }
+}
+
+/// ## Summary
+/// Write the provenance manifest of the run to the output directory, i.e. the version, the command line, the digests of the input files, 
+/// the number of probands, the counts of processed and skipped transcripts and the time spent per stage, see provenance::ProvenanceManifest 
+fn write_provenance(args:&cli::ParsedInput, num_probands:usize)
+{
+    let mut manifest=ProvenanceManifest::new("vcf2prot", env!("CARGO_PKG_VERSION"), std::env::args().collect()); 
+    let inputs=[("maf",args.path2maf.as_ref()),("vcf",Some(&args.path2vcf).filter(|path2vcf|!path2vcf.is_empty())),("reference",Some(&args.path2fasta)),
+        ("genome",args.path2genome.as_ref()),("append_fasta",args.path2append_fasta.as_ref()),("sample_sex",args.path2sample_sex.as_ref())]; 
+    for (role,path2file) in inputs.iter().filter_map(|(role,path2file)|path2file.map(|path2file|(role,path2file)))
+    {
+        manifest.add_input(role, Path::new(path2file)).unwrap(); 
+    }
+    for path2file in args.paths2mutation_tsv.iter()
+    {
+        manifest.add_input("mutation_tsv", Path::new(path2file)).unwrap(); 
+    }
+    manifest.set_counts(num_probands, &get_metrics_snapshot(args)); 
+    manifest.set_stage_durations(&args.progress.get_stage_durations()); 
+    writers::write_provenance_manifest(Path::new(&args.res_path), &manifest, &args.writer_options).unwrap(); 
+}
+
+/// ## Summary
+/// Return the metrics recorded by the run so far, see metrics::MetricsAccumulator 
+fn get_metrics_snapshot(args:&cli::ParsedInput)->MetricsSnapshot
+{
+    args.execution_options.metrics.as_ref().map(|metrics|metrics.snapshot()).unwrap_or_default()
+}
+/// ## Summary
+/// Print the machine-parsable status line of the run and exit with the exit code of its status
+fn exit_with_status(args:&cli::ParsedInput, budget:&RunBudget)
+{
+    args.finish_reporter(); 
+    println!("{}",budget.to_status_line()); 
+    std::process::exit(budget.get_status().exit_code()); 
+}
\ No newline at end of file
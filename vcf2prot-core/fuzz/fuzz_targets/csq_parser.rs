@@ -0,0 +1,16 @@
+//! A fuzz target for the consequence parser, i.e. the consequence strings of the BCSQ field and the bitmasks of the proband fields,
+//! every parser is called with the input and must return either a value or an error, any panic is reported as a crash by libFuzzer 
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use ppgg::functions::text_parser;
+
+fuzz_target!(|data:&[u8]|
+{
+    if let Ok(input)=std::str::from_utf8(data)
+    {
+        let _=text_parser::split_csq_string(&input.to_string());
+        let _=text_parser::parse_amino_acid_field(input);
+        let _=text_parser::try_get_bit_mask(input);
+        let _=text_parser::try_parse_consequence(input);
+    }
+});
@@ -0,0 +1,105 @@
+/*
+ * The C ABI of vcf2prot, i.e. the functions of the ffi module of the vcf2prot-core crate, which is built with the ffi feature, e.g.
+ * cargo rustc -p vcf2prot-core --release --features ffi --crate-type cdylib
+ * The functions never unwind into the caller, a failure is returned as an error code and its message by vcf2prot_last_error.
+ */
+#ifndef VCF2PROT_H
+#define VCF2PROT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* the error codes */
+#define VCF2PROT_OK 0
+#define VCF2PROT_INVALID_ARGUMENT 1
+#define VCF2PROT_PIPELINE_ERROR 2
+#define VCF2PROT_ABORTED 3
+#define VCF2PROT_PANIC 4
+
+/*
+ * The options of the pipeline:
+ * engine, 0 for the single-threaded engine and 1 for the multi-threaded engine.
+ * contig_policy, 0 skips the records of the non-primary contigs and 1 reads every record.
+ * missing_genotype_policy, 0 for ref, 1 for skip-record, 2 for major-allele and 3 for flag.
+ * include_reference, if non-zero, the unaltered transcripts of each haplotype are returned with their reference sequence as well.
+ */
+typedef struct Vcf2ProtOptions {
+    uint8_t engine;
+    uint8_t contig_policy;
+    uint8_t missing_genotype_policy;
+    uint8_t include_reference;
+} Vcf2ProtOptions;
+
+/* A generated sequence, the strings are NUL-terminated and haplotype is 1 or 2 */
+typedef struct Vcf2ProtRecord {
+    const char *proband;
+    uint8_t haplotype;
+    const char *transcript;
+    const char *sequence;
+    size_t sequence_len;
+    uint8_t is_altered;
+} Vcf2ProtRecord;
+
+/* The callback receiving the generated sequences, the record is only valid during the call, a non-zero return value stops the generation */
+typedef int (*Vcf2ProtCallback)(const Vcf2ProtRecord *record, void *user_data);
+
+/* The sequences collected by vcf2prot_generate_to_buffer */
+typedef struct Vcf2ProtResult Vcf2ProtResult;
+
+/* The options of a call as an opaque handle, created by vcf2prot_options_new and released by vcf2prot_options_free */
+typedef struct Vcf2ProtOptionsHandle Vcf2ProtOptionsHandle;
+
+/* Fill the options with their defaults, i.e. every field is 0 */
+void vcf2prot_options_default(Vcf2ProtOptions *options);
+
+/* Create an options handle from the options, the default options are used if options is NULL, NULL is returned if a code is out of range */
+Vcf2ProtOptionsHandle *vcf2prot_options_new(const Vcf2ProtOptions *options);
+
+/*
+ * Set a named setting of a handle, the names and the values follow the flags of the command line:
+ * overlap_policy, checks, placeholder_residue, strict_placeholder_check, unphased, multi_allelic, haplotype_source and silent_variants,
+ * along with samples and transcripts, which are comma separated lists. Returns VCF2PROT_OK or VCF2PROT_INVALID_ARGUMENT.
+ */
+int vcf2prot_options_set(Vcf2ProtOptionsHandle *handle, const char *name, const char *value);
+
+/* Release an options handle, NULL is ignored */
+void vcf2prot_options_free(Vcf2ProtOptionsHandle *handle);
+
+/*
+ * Generate the personalized sequences of the samples of a VCF file, plain or gzipped, and pass each sequence to the callback along with
+ * user_data, the default options are used if options is NULL. The sequences of each sample are passed haplotype by haplotype, the altered
+ * transcripts first. Transcripts that can not be personalized are skipped.
+ */
+int vcf2prot_generate(const char *path2vcf, const char *path2fasta, const Vcf2ProtOptions *options, Vcf2ProtCallback callback,
+    void *user_data);
+
+/* As vcf2prot_generate, however, the sequences are collected into a buffer, NULL is returned on failure and the code is written to error_code */
+Vcf2ProtResult *vcf2prot_generate_to_buffer(const char *path2vcf, const char *path2fasta, const Vcf2ProtOptions *options, int *error_code);
+
+/* As vcf2prot_generate and vcf2prot_generate_to_buffer, however, the options are taken from a handle, the defaults are used if it is NULL */
+int vcf2prot_generate_with_options(const char *path2vcf, const char *path2fasta, const Vcf2ProtOptionsHandle *handle,
+    Vcf2ProtCallback callback, void *user_data);
+Vcf2ProtResult *vcf2prot_generate_to_buffer_with_options(const char *path2vcf, const char *path2fasta, const Vcf2ProtOptionsHandle *handle,
+    int *error_code);
+
+/* Return the number of sequences in a buffer */
+size_t vcf2prot_result_len(const Vcf2ProtResult *result);
+
+/* Return the sequence at index, NULL if the index is out of range, the record is valid until the buffer is released */
+const Vcf2ProtRecord *vcf2prot_result_get(const Vcf2ProtResult *result, size_t index);
+
+/* Release a buffer, NULL is ignored */
+void vcf2prot_result_free(Vcf2ProtResult *result);
+
+/* Return the message of the last failed call on the calling thread, NULL if no call has failed */
+const char *vcf2prot_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VCF2PROT_H */
@@ -1,10 +1,11 @@
 /// the module contain constant used throughout the library 
 pub static DEF_CONSEQ:&str =""; 
-pub static  SUP_TYPE:[&str; 22]=["missense","*missense","frameshift","*frameshift",
+pub static  SUP_TYPE:[&str; 24]=["missense","*missense","frameshift","*frameshift",
 "inframe_insertion","*inframe_insertion","inframe_deletion","*inframe_deletion",
 "stop_gained", "stop_lost", "*missense&inframe_altering","*frameshift&stop_retained",
 "*stop_gained&inframe_altering","frameshift&stop_retained","inframe_deletion&stop_retained",
 "inframe_insertion&stop_retained","stop_gained&inframe_altering","start_lost","*stop_gained",
-"stop_lost&frameshift","missense&inframe_altering","start_lost&splice_region"];
+"stop_lost&frameshift","missense&inframe_altering","start_lost&splice_region",
+"start_reselection","stop_reselection"];
 
 
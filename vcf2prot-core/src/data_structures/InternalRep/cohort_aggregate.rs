@@ -0,0 +1,294 @@
+use std::collections::{HashMap,HashSet};
+use std::io::Write;
+use std::path::Path;
+use rayon::prelude::*;
+use super::engines::Engine;
+use super::personalized_genome::PersonalizedGenome;
+use crate::writers::{append_custom_records,create_file,WriterOptions};
+
+/// ## Summary
+/// A cohort-level abstraction of a collection of personalized genomes, it contains the number of probands, the number of carriers of each
+/// unique altered sequence per transcript and the number of carriers of each altered transcript, where a proband carrying the same
+/// sequence on both haplotypes is counted once. No proband names are stored, hence, only cohort-level outputs can be derived from it.
+#[derive(Debug,Clone,Default)]
+pub struct CohortAggregate
+{
+    num_probands:u64,
+    carriers_per_sequence:HashMap<String,HashMap<String,u64>>,
+    carriers_per_transcript:HashMap<String,u64>
+}
+/// ## Summary
+/// A summary of the reported cohort-level results, sequences and transcripts with less than min_carriers carriers are suppressed, while
+/// reported sequences shorter than the minimum length are dropped
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct AggregateSummary
+{
+    pub num_probands:u64,
+    pub min_carriers:u64,
+    pub num_altered_transcripts:u64,
+    pub num_reported_transcripts:u64,
+    pub num_unique_sequences:u64,
+    pub num_reported_sequences:u64,
+    pub num_suppressed_sequences:u64,
+    pub num_dropped_sequences:u64
+}
+impl CohortAggregate
+{
+    /// ## Summary
+    /// create a new empty aggregate
+    pub fn new()->Self
+    {
+        CohortAggregate::default()
+    }
+    /// ## Summary
+    /// aggregate a collection of personalized genomes using the provided engine
+    pub fn from_genomes(vec_genomes:&[PersonalizedGenome], engine:Engine)->Self
+    {
+        match engine
+        {
+            Engine::ST=>
+            {
+                let mut aggregate=CohortAggregate::new();
+                vec_genomes.iter().for_each(|genome|aggregate.add_genome(genome));
+                aggregate
+            },
+            Engine::MT | Engine::GPU | Engine::Auto=>
+            {
+                vec_genomes.par_iter()
+                .fold(CohortAggregate::new,|mut aggregate,genome|{aggregate.add_genome(genome); aggregate})
+                .reduce(CohortAggregate::new,CohortAggregate::merge)
+            }
+        }
+    }
+    /// ## Summary
+    /// add the altered sequences of a personalized genome to the aggregate
+    pub fn add_genome(&mut self, genome:&PersonalizedGenome)
+    {
+        self.num_probands+=1;
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+        let mut transcripts=seq_tape1.get_annotation().keys().chain(seq_tape2.get_annotation().keys()).collect::<Vec<&String>>();
+        transcripts.sort();
+        transcripts.dedup();
+        for transcript in transcripts
+        {
+            *self.carriers_per_transcript.entry(transcript.clone()).or_insert(0)+=1;
+            let mut sequences=[seq_tape1.get_seq(transcript),seq_tape2.get_seq(transcript)].iter()
+                .filter_map(|seq|seq.as_ref().ok().copied())
+                .collect::<Vec<&str>>();
+            sequences.sort_unstable();
+            sequences.dedup();
+            let carriers=self.carriers_per_sequence.entry(transcript.clone()).or_default();
+            for seq in sequences
+            {
+                match carriers.get_mut(seq)
+                {
+                    Some(count)=>*count+=1,
+                    None=>{carriers.insert(seq.to_string(),1);}
+                }
+            }
+        }
+    }
+    /// ## Summary
+    /// merge two aggregates of disjoint sets of probands
+    pub fn merge(mut self, other:CohortAggregate)->Self
+    {
+        self.num_probands+=other.num_probands;
+        for (transcript,count) in other.carriers_per_transcript
+        {
+            *self.carriers_per_transcript.entry(transcript).or_insert(0)+=count;
+        }
+        for (transcript,sequences) in other.carriers_per_sequence
+        {
+            let carriers=self.carriers_per_sequence.entry(transcript).or_default();
+            for (seq,count) in sequences
+            {
+                *carriers.entry(seq).or_insert(0)+=count;
+            }
+        }
+        self
+    }
+    /// ## Summary
+    /// return the number of aggregated probands
+    pub fn get_num_probands(&self)->u64
+    {
+        self.num_probands
+    }
+    /// ## Summary
+    /// return the unique sequences carried by at least min_carriers probands as a vector of transcript id, sequence and number of carriers,
+    /// sorted by transcript id, then by decreasing number of carriers and then by sequence
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::InternalRep::{sequence_tape::SequenceTape,personalized_genome::PersonalizedGenome,cohort_aggregate::CohortAggregate};
+    /// use std::collections::HashMap;
+    /// let mut annotations=HashMap::new();
+    /// annotations.insert("T1".to_string(),(0,3));
+    /// let genome=|name:&str|PersonalizedGenome::new(name.to_string(),SequenceTape::new("MKV".to_string(),annotations.clone()).unwrap(),
+    ///     SequenceTape::new(String::new(),HashMap::new()).unwrap());
+    /// let mut aggregate=CohortAggregate::new();
+    /// aggregate.add_genome(&genome("proband_1"));
+    /// aggregate.add_genome(&genome("proband_2"));
+    /// assert_eq!(aggregate.get_unique_sequences(2),vec![(&"T1".to_string(),&"MKV".to_string(),2)]);
+    /// assert!(aggregate.get_unique_sequences(3).is_empty());
+    ///```
+    pub fn get_unique_sequences(&self, min_carriers:u64)->Vec<(&String,&String,u64)>
+    {
+        let mut unique_sequences=self.carriers_per_sequence.iter()
+            .flat_map(|(transcript,sequences)|sequences.iter().map(move |(seq,count)|(transcript,seq,*count)))
+            .filter(|(_,_,count)|*count>=min_carriers)
+            .collect::<Vec<_>>();
+        unique_sequences.sort_by(|lhs,rhs|lhs.0.cmp(rhs.0).then(rhs.2.cmp(&lhs.2)).then(lhs.1.cmp(rhs.1)));
+        unique_sequences
+    }
+    /// ## Summary
+    /// return the altered transcripts carried by at least min_carriers probands as a vector of transcript id, number of carriers and the number
+    /// of unique sequences of the transcript that are carried by at least min_carriers probands, sorted by transcript id
+    pub fn get_carriers_per_transcript(&self, min_carriers:u64)->Vec<(&String,u64,u64)>
+    {
+        let mut carriers=self.carriers_per_transcript.iter()
+            .filter(|(_,count)|**count>=min_carriers)
+            .map(|(transcript,count)|
+            {
+                let num_reported=self.carriers_per_sequence[transcript].values().filter(|seq_count|**seq_count>=min_carriers).count() as u64;
+                (transcript,*count,num_reported)
+            })
+            .collect::<Vec<_>>();
+        carriers.sort_by(|lhs,rhs|lhs.0.cmp(rhs.0));
+        carriers
+    }
+    /// ## Summary
+    /// write the unique sequences carried by at least min_carriers probands to a fasta file named cohort.unique_sequences.fasta, the header of
+    /// each record contains the transcript id followed by the rank of the sequence among the sequences of the transcript and the number of carriers,
+    /// for example, "ENST00000406869_var1 carriers=12". Sequences shorter than min_length are dropped and a summary of the reported results is returned.
+    /// The custom records, e.g. contaminants, are appended after the unique sequences, see writers::append_custom_records for more details.
+    pub fn write_unique_sequences(&self, outdir:&String, write_compressed:&bool, min_length:&usize, min_carriers:u64,
+        custom_records:&[(String,String)], options:&WriterOptions)->Result<AggregateSummary,String>
+    {
+        let res_string=format!("{}/{}",outdir,options.compression_policy.get_fasta_name("cohort.unique_sequences",*write_compressed));
+        let res_path=Path::new(&res_string);
+        let file_handle=match create_file(res_path, &options.retry_policy)
+        {
+            Ok(file)=>file,
+            Err(err_msg)=>return Err(format!("Could not create {} because {}",res_path.display(),err_msg))
+        };
+        let mut writer:Box<dyn Write>=match write_compressed
+        {
+            true=>options.compression_policy.create_encoder(file_handle),
+            false=>Box::new(file_handle)
+        };
+        let unique_sequences=self.get_unique_sequences(min_carriers);
+        let mut num_dropped=0;
+        let mut rank=0;
+        let mut written_ids=Vec::with_capacity(unique_sequences.len());
+        let mut written_seqs=HashSet::new();
+        for (index,(transcript,seq,count)) in unique_sequences.iter().enumerate()
+        {
+            rank=match index
+            {
+                0=>1,
+                _ if unique_sequences[index-1].0==*transcript=>rank+1,
+                _=>1
+            };
+            if seq.len() < *min_length
+            {
+                num_dropped+=1;
+                continue
+            }
+            if let Err(err_msg)=write!(writer,">{}_var{} carriers={}\n{}\n",transcript,rank,count,seq)
+            {
+                return Err(format!("Writing the sequence of: {}_var{} failed because {}",transcript,rank,err_msg))
+            }
+            written_ids.push(format!("{}_var{}",transcript,rank));
+            written_seqs.insert(seq.as_str());
+        }
+        if !custom_records.is_empty()
+        {
+            let written_ids=written_ids.iter().map(|id|id.as_str()).collect::<HashSet<&str>>();
+            append_custom_records(&mut writer, custom_records, &written_ids, &written_seqs)?;
+        }
+        let num_unique_sequences=self.carriers_per_sequence.values().map(|sequences|sequences.len() as u64).sum::<u64>();
+        Ok(AggregateSummary
+        {
+            num_probands:self.num_probands,
+            min_carriers,
+            num_altered_transcripts:self.carriers_per_transcript.len() as u64,
+            num_reported_transcripts:self.get_carriers_per_transcript(min_carriers).len() as u64,
+            num_unique_sequences,
+            num_reported_sequences:unique_sequences.len() as u64-num_dropped,
+            num_suppressed_sequences:num_unique_sequences-unique_sequences.len() as u64,
+            num_dropped_sequences:num_dropped
+        })
+    }
+}
+#[cfg(test)]
+mod test_cohort_aggregate
+{
+    use super::*;
+    use super::super::sequence_tape::SequenceTape;
+    fn get_genome(name:&str, haplotype1:&[(&str,&str)], haplotype2:&[(&str,&str)])->PersonalizedGenome
+    {
+        let get_tape=|haplotype:&[(&str,&str)]|
+        {
+            let mut seq_str=String::new();
+            let mut annotations=HashMap::new();
+            for (transcript,seq) in haplotype.iter()
+            {
+                annotations.insert(transcript.to_string(),(seq_str.len(),seq_str.len()+seq.len()));
+                seq_str.push_str(seq);
+            }
+            SequenceTape::new(seq_str,annotations).unwrap()
+        };
+        PersonalizedGenome::new(name.to_string(),get_tape(haplotype1),get_tape(haplotype2))
+    }
+    fn get_cohort()->Vec<PersonalizedGenome>
+    {
+        vec![
+            get_genome("proband_1",&[("T1","MKV"),("T2","MEK")],&[("T1","MKV")]),
+            get_genome("proband_2",&[("T1","MKV")],&[("T1","MKL")]),
+            get_genome("proband_3",&[("T1","MKL")],&[]),
+            get_genome("proband_4",&[],&[("T1","MKV"),("T2","M")]),
+        ]
+    }
+    #[test]
+    fn test_carrier_counts()
+    {
+        let aggregate=CohortAggregate::from_genomes(&get_cohort(),Engine::ST);
+        assert_eq!(aggregate.get_num_probands(),4);
+        // homozygous carriers are counted once
+        assert_eq!(aggregate.get_unique_sequences(1),vec![(&"T1".to_string(),&"MKV".to_string(),3),(&"T1".to_string(),&"MKL".to_string(),2),
+            (&"T2".to_string(),&"M".to_string(),1),(&"T2".to_string(),&"MEK".to_string(),1)]);
+        assert_eq!(aggregate.get_unique_sequences(3),vec![(&"T1".to_string(),&"MKV".to_string(),3)]);
+        assert_eq!(aggregate.get_carriers_per_transcript(2),vec![(&"T1".to_string(),4,2),(&"T2".to_string(),2,0)]);
+    }
+    #[test]
+    fn test_engines_agree()
+    {
+        let cohort=get_cohort();
+        let aggregate_st=CohortAggregate::from_genomes(&cohort,Engine::ST);
+        let aggregate_mt=CohortAggregate::from_genomes(&cohort,Engine::MT);
+        assert_eq!(aggregate_st.get_num_probands(),aggregate_mt.get_num_probands());
+        assert_eq!(aggregate_st.get_unique_sequences(1),aggregate_mt.get_unique_sequences(1));
+        assert_eq!(aggregate_st.get_carriers_per_transcript(1),aggregate_mt.get_carriers_per_transcript(1));
+    }
+    #[test]
+    fn test_write_unique_sequences()
+    {
+        let aggregate=CohortAggregate::from_genomes(&get_cohort(),Engine::ST);
+        let mut out_dir=std::env::temp_dir();
+        out_dir.push("cohort_aggregate_test");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let out_dir=out_dir.to_str().unwrap().to_string();
+        let summary=aggregate.write_unique_sequences(&out_dir,&false,&0,2,&[],&WriterOptions::default()).unwrap();
+        let written=std::fs::read_to_string(format!("{}/cohort.unique_sequences.fasta",out_dir)).unwrap();
+        assert_eq!(written,">T1_var1 carriers=3\nMKV\n>T1_var2 carriers=2\nMKL\n");
+        assert!(!written.contains("proband"));
+        assert_eq!(summary,AggregateSummary{num_probands:4,min_carriers:2,num_altered_transcripts:2,num_reported_transcripts:2,
+            num_unique_sequences:4,num_reported_sequences:2,num_suppressed_sequences:2,num_dropped_sequences:0});
+        let summary=aggregate.write_unique_sequences(&out_dir,&false,&4,1,&[],&WriterOptions::default()).unwrap();
+        assert_eq!((summary.num_reported_sequences,summary.num_dropped_sequences),(0,4));
+        // custom records are appended unless they repeat a reported sequence
+        let custom_records=vec![("CONT_1".to_string(),"IVGG".to_string()),("CONT_2".to_string(),"MKV".to_string())];
+        aggregate.write_unique_sequences(&out_dir,&false,&0,3,&custom_records,&WriterOptions::default()).unwrap();
+        let written=std::fs::read_to_string(format!("{}/cohort.unique_sequences.fasta",out_dir)).unwrap();
+        assert_eq!(written,">T1_var1 carriers=3\nMKV\n>CONT_1\nIVGG\n");
+    }
+}
@@ -0,0 +1,153 @@
+// load the modules and crates
+use std::str::FromStr;
+
+/// ## Summary
+/// The rule used for collapsing the two haplotypes of a transcript into a single consensus sequence:
+/// 1. Severity ==> the more severely altered haplotype is chosen, i.e. the haplotype with the larger number of residues differing from the reference.
+/// 2. Ambiguity ==> differing residues of equal-length haplotypes are encoded using IUPAC ambiguity codes, otherwise the severity rule is applied.
+///
+/// The severity of a haplotype is the number of mismatches over the prefix it shares with the reference plus the absolute difference in length,
+/// hence, truncations and frameshifts are ranked above substitutions and ties are resolved in favor of the first haplotype. The ambiguity codes
+/// for amino acids are B for D or N, Z for E or Q, J for I or L and X otherwise.
+/// ## Example
+///```rust
+/// use std::str::FromStr;
+/// use ppgg::data_structures::InternalRep::consensus::{ConsensusRule,ConsensusSource};
+/// let rule=ConsensusRule::from_str("ambiguity").unwrap();
+/// let (seq,source)=rule.get_consensus("MKDAL","MKNAI","MKDAL");
+/// assert_eq!(seq,"MKBAJ");
+/// assert_eq!(source,ConsensusSource::Ambiguous);
+///```
+#[derive(Debug,Clone,PartialEq)]
+pub enum ConsensusRule{Severity,Ambiguity}
+
+impl FromStr for ConsensusRule
+{
+    type Err=String;
+    fn from_str(rule_name:&str)->Result<ConsensusRule,String>
+    {
+        match rule_name
+        {
+            "severity" | "SEVERITY" =>Ok(ConsensusRule::Severity),
+            "ambiguity" | "AMBIGUITY" =>Ok(ConsensusRule::Ambiguity),
+            _=>Err(format!("{} is not a supported consensus rule, supported rules are: severity and ambiguity",rule_name))
+        }
+    }
+}
+
+/// ## Summary
+/// The origin of a consensus sequence, used to annotate each consensus record with the haplotype that has been chosen
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ConsensusSource{Haplotype1,Haplotype2,Both,Ambiguous}
+
+impl ConsensusSource
+{
+    /// ## Summary
+    /// Return the annotation written to the header of the consensus record
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ConsensusSource::Haplotype1=>"1",
+            ConsensusSource::Haplotype2=>"2",
+            ConsensusSource::Both=>"both",
+            ConsensusSource::Ambiguous=>"ambiguous"
+        }
+    }
+}
+
+impl ConsensusRule
+{
+    /// ## Summary
+    /// Return the name of the rule as written to the header of the consensus record
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ConsensusRule::Severity=>"severity",
+            ConsensusRule::Ambiguity=>"ambiguity"
+        }
+    }
+    /// ## Summary
+    /// Compute the consensus of the two haplotypes of a transcript, transcripts that are only altered in one haplotype should be provided
+    /// with the reference sequence as the other haplotype. Returns the consensus sequence along with the haplotype it originates from.
+    pub fn get_consensus(&self, seq1:&str, seq2:&str, ref_seq:&str)->(String,ConsensusSource)
+    {
+        if seq1==seq2
+        {
+            return (seq1.to_string(),ConsensusSource::Both)
+        }
+        match self
+        {
+            ConsensusRule::Ambiguity if seq1.len()==seq2.len()=>
+            {
+                let consensus=seq1.chars().zip(seq2.chars())
+                    .map(|(res1,res2)|if res1==res2 {res1} else {get_ambiguity_code(res1,res2)})
+                    .collect::<String>();
+                (consensus,ConsensusSource::Ambiguous)
+            },
+            _=>
+            {
+                if get_severity(seq2,ref_seq)>get_severity(seq1,ref_seq)
+                {
+                    (seq2.to_string(),ConsensusSource::Haplotype2)
+                }
+                else
+                {
+                    (seq1.to_string(),ConsensusSource::Haplotype1)
+                }
+            }
+        }
+    }
+}
+
+/// ## Summary
+/// Return the number of residues in a sequence that differ from the reference, i.e. the mismatches over the shared prefix
+/// plus the absolute difference in length
+pub fn get_severity(seq:&str, ref_seq:&str)->usize
+{
+    let mismatches=seq.chars().zip(ref_seq.chars()).filter(|(res,ref_res)|res!=ref_res).count();
+    let (seq_len,ref_len)=(seq.chars().count(),ref_seq.chars().count());
+    mismatches+std::cmp::max(seq_len,ref_len)-std::cmp::min(seq_len,ref_len)
+}
+
+/// ## Summary
+/// Return the IUPAC ambiguity code for two different amino acids
+fn get_ambiguity_code(res1:char, res2:char)->char
+{
+    match (res1,res2)
+    {
+        ('D','N') | ('N','D') =>'B',
+        ('E','Q') | ('Q','E') =>'Z',
+        ('I','L') | ('L','I') =>'J',
+        _=>'X'
+    }
+}
+
+#[cfg(test)]
+mod test_consensus
+{
+    use super::*;
+    #[test]
+    fn test_severity_rule()
+    {
+        let rule=ConsensusRule::Severity;
+        // a truncation is more severe than a substitution
+        assert_eq!(rule.get_consensus("MKLAT","MK","MKLAV"),("MK".to_string(),ConsensusSource::Haplotype2));
+        // the altered haplotype is chosen over the reference
+        assert_eq!(rule.get_consensus("MKLAV","MKLAT","MKLAV"),("MKLAT".to_string(),ConsensusSource::Haplotype2));
+        // ties are resolved in favor of the first haplotype
+        assert_eq!(rule.get_consensus("MELAV","MKLAT","MKLAV"),("MELAV".to_string(),ConsensusSource::Haplotype1));
+        assert_eq!(rule.get_consensus("MK","MK","MKLAV"),("MK".to_string(),ConsensusSource::Both));
+        assert_eq!(get_severity("MKLATSS","MKLAV"),3);
+    }
+    #[test]
+    fn test_ambiguity_rule()
+    {
+        let rule=ConsensusRule::Ambiguity;
+        assert_eq!(rule.get_consensus("MEKAT","MQKAW","MEKAT"),("MZKAX".to_string(),ConsensusSource::Ambiguous));
+        // haplotypes with different lengths fall back to the severity rule
+        assert_eq!(rule.get_consensus("MEKAT","MEK","MEKAT"),("MEK".to_string(),ConsensusSource::Haplotype2));
+        assert!(ConsensusRule::from_str("majority").is_err());
+    }
+}
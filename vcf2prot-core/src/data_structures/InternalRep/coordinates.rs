@@ -0,0 +1,258 @@
+// load the modules and crates
+use std::fmt;
+use std::ops::{Add, Sub};
+use serde::{Deserialize, Serialize};
+
+/// ## Summary
+/// A zero-based position in a protein sequence, i.e. the index that is used for slicing the reference stream
+/// or the results array. Mutations are reported by csq using one-based positions, see RefPos1, and must be
+/// explicitly converted before they can be used for indexing.
+/// ## Example
+/// ```rust
+/// use ppgg::data_structures::InternalRep::coordinates::{RefPos0,RefPos1,Len};
+/// let pos=RefPos1::new(32).unwrap().to_zero_based();
+/// assert_eq!(pos,RefPos0::new(31));
+/// assert_eq!(pos.next(),RefPos0::new(32));
+/// assert_eq!(pos+Len::new(4),RefPos0::new(35));
+/// assert_eq!(pos.span_to(RefPos0::new(40)),Len::new(9));
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Serialize,Deserialize)]
+pub struct RefPos0(usize);
+
+/// ## Summary
+/// A one-based position in a protein sequence, this is the coordinate system used by the consequence strings, e.g. 32Q>32*
+/// the only valid way to index with it is to convert it into a RefPos0
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Serialize,Deserialize)]
+pub struct RefPos1(usize);
+
+/// ## Summary
+/// A number of amino acids, e.g. the length of a reference protein, the length of a deletion or the number of chars copied by a task
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Serialize,Deserialize)]
+pub struct Len(usize);
+
+impl RefPos0
+{
+    /// ## Summary
+    /// Create a new zero-based position
+    pub fn new(pos:usize)->Self
+    {
+        RefPos0(pos)
+    }
+    /// ## Summary
+    /// Return the first position in a sequence, i.e. position 0
+    pub fn start()->Self
+    {
+        RefPos0(0)
+    }
+    /// ## Summary
+    /// Return the raw index, this should only be used for slicing and for writing to the task arrays
+    pub fn get(&self)->usize
+    {
+        self.0
+    }
+    /// ## Summary
+    /// Convert the position into a one-based position
+    pub fn to_one_based(&self)->RefPos1
+    {
+        RefPos1(self.0+1)
+    }
+    /// ## Summary
+    /// Return the position directly after the current position
+    pub fn next(&self)->Self
+    {
+        RefPos0(self.0+1)
+    }
+    /// ## Summary
+    /// Return the number of amino acids located before the current position, e.g. position 3 is preceded by 3 amino acids
+    pub fn prefix_len(&self)->Len
+    {
+        Len(self.0)
+    }
+    /// ## Summary
+    /// Return the number of amino acids between the current position (inclusive) and the provided end position (exclusive)
+    /// or None if the end position is located before the current position
+    pub fn checked_span_to(&self, end:RefPos0)->Option<Len>
+    {
+        end.0.checked_sub(self.0).map(Len)
+    }
+    /// ## Summary
+    /// Return the number of amino acids between the current position (inclusive) and the provided end position (exclusive)
+    /// ## Panics
+    /// if the end position is located before the current position
+    pub fn span_to(&self, end:RefPos0)->Len
+    {
+        match self.checked_span_to(end)
+        {
+            Some(len)=>len,
+            None=>panic!("Invalid span, the end position: {} is located before the start position: {}",end.0,self.0)
+        }
+    }
+    /// ## Summary
+    /// Return the number of amino acids between the current position (inclusive) and the end of a sequence of length ref_len
+    /// ## Panics
+    /// if the current position is located after the end of the sequence
+    pub fn span_to_end(&self, ref_len:Len)->Len
+    {
+        self.span_to(RefPos0(ref_len.0))
+    }
+    /// ## Summary
+    /// Return true if the position is the last amino acid in a sequence of length ref_len
+    pub fn is_last_residue(&self, ref_len:Len)->bool
+    {
+        self.0+1==ref_len.0
+    }
+    /// ## Summary
+    /// Return true if the position is directly after the last amino acid of a sequence of length ref_len,
+    /// i.e. the position of the stop codon
+    pub fn is_stop_position(&self, ref_len:Len)->bool
+    {
+        self.0==ref_len.0
+    }
+}
+impl RefPos1
+{
+    /// ## Summary
+    /// Create a new one-based position, returns an error if the provided position is zero
+    pub fn new(pos:usize)->Result<Self,String>
+    {
+        match pos
+        {
+            0=>Err("Position 0 is not a valid one-based position".to_string()),
+            _=>Ok(RefPos1(pos))
+        }
+    }
+    /// ## Summary
+    /// Return the raw one-based position, this should only be used for reporting
+    pub fn get(&self)->usize
+    {
+        self.0
+    }
+    /// ## Summary
+    /// Convert the position into a zero-based position
+    pub fn to_zero_based(&self)->RefPos0
+    {
+        RefPos0(self.0-1)
+    }
+}
+impl Len
+{
+    /// ## Summary
+    /// Create a new length
+    pub fn new(len:usize)->Self
+    {
+        Len(len)
+    }
+    /// ## Summary
+    /// Return an empty length
+    pub fn zero()->Self
+    {
+        Len(0)
+    }
+    /// ## Summary
+    /// Return the raw length
+    pub fn get(&self)->usize
+    {
+        self.0
+    }
+}
+impl Add<Len> for RefPos0
+{
+    type Output=RefPos0;
+    fn add(self, other:Len)->RefPos0
+    {
+        RefPos0(self.0+other.0)
+    }
+}
+impl Add for Len
+{
+    type Output=Len;
+    fn add(self, other:Len)->Len
+    {
+        Len(self.0+other.0)
+    }
+}
+impl Sub for Len
+{
+    type Output=Len;
+    fn sub(self, other:Len)->Len
+    {
+        match self.0.checked_sub(other.0)
+        {
+            Some(len)=>Len(len),
+            None=>panic!("Invalid length, subtracting: {} from: {} results in a negative length",other.0,self.0)
+        }
+    }
+}
+impl fmt::Display for RefPos0
+{
+    fn fmt(&self, f:&mut fmt::Formatter<'_>)->fmt::Result
+    {
+        write!(f,"{}",self.0)
+    }
+}
+impl fmt::Display for RefPos1
+{
+    fn fmt(&self, f:&mut fmt::Formatter<'_>)->fmt::Result
+    {
+        write!(f,"{}",self.0)
+    }
+}
+impl fmt::Display for Len
+{
+    fn fmt(&self, f:&mut fmt::Formatter<'_>)->fmt::Result
+    {
+        write!(f,"{}",self.0)
+    }
+}
+impl PartialEq<usize> for RefPos0
+{
+    fn eq(&self, other:&usize)->bool
+    {
+        self.0==*other
+    }
+}
+impl PartialEq<usize> for Len
+{
+    fn eq(&self, other:&usize)->bool
+    {
+        self.0==*other
+    }
+}
+
+#[cfg(test)]
+mod test_coordinates
+{
+    use super::*;
+    #[test]
+    fn test_conversion()
+    {
+        let pos=RefPos1::new(1).unwrap();
+        assert_eq!(pos.to_zero_based(),RefPos0::start());
+        assert_eq!(pos.to_zero_based().to_one_based(),pos);
+        assert!(RefPos1::new(0).is_err());
+    }
+    #[test]
+    fn test_spans()
+    {
+        let pos=RefPos0::new(5);
+        assert_eq!(pos.span_to(RefPos0::new(5)),Len::zero());
+        assert_eq!(pos.span_to(RefPos0::new(9)),4);
+        assert_eq!(pos.span_to_end(Len::new(10)),5);
+        assert_eq!(pos.checked_span_to(RefPos0::new(4)),None);
+        assert_eq!(pos.prefix_len(),5);
+        assert!(RefPos0::new(9).is_last_residue(Len::new(10)));
+        assert!(RefPos0::new(10).is_stop_position(Len::new(10)));
+    }
+    #[test]
+    #[should_panic]
+    fn test_negative_span()
+    {
+        RefPos0::new(5).span_to(RefPos0::new(4));
+    }
+    #[test]
+    #[should_panic]
+    fn test_negative_length()
+    {
+        let _=Len::new(4)-Len::new(5);
+    }
+}
@@ -0,0 +1,220 @@
+// load the modules and crates
+use std::fs;
+use std::io::{BufRead,BufReader};
+use std::path::Path;
+use super::engines::Engine;
+use super::gpu_config::{self,GpuConfig,BYTES_PER_RESIDUE};
+
+/// ## Summary
+/// The minimum workload, i.e. the number of samples times the number of records, to use the multi-threaded engine, smaller inputs are
+/// parsed and executed with a single thread as the overhead of spawning and scheduling the threads is larger than the gain.
+pub const MIN_MT_WORKLOAD:u64=1_000_000;
+/// ## Summary
+/// The minimum workload to use the GPU engine, smaller inputs do not amortize the copies to and from the device
+pub const MIN_GPU_WORKLOAD:u64=1_000_000_000;
+/// ## Summary
+/// The fraction of the available host memory that can be used for the host buffers of the GPU batches
+pub const HOST_MEMORY_FRACTION:f64=0.25;
+/// ## Summary
+/// The ratio between the memory needed for parsing a VCF file and its size on disk, i.e. the lines and the records that are held in memory
+pub const PARSING_MEMORY_RATIO:u64=3;
+
+/// ## Summary
+/// The resources available for a run, i.e. the number of logical cores, the available host memory and the memory of the first visible device,
+/// where the memory is None if it could not be detected
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SystemResources
+{
+    pub num_cores:usize,
+    pub available_memory:Option<u64>,
+    pub device_memory:Option<u64>
+}
+impl SystemResources
+{
+    /// ## Summary
+    /// Detect the resources of the current machine, the available memory is read from /proc/meminfo and the device memory is queried
+    /// using nvidia-smi, see gpu_config::detect_device_memory
+    pub fn detect()->Self
+    {
+        SystemResources{num_cores:detect_num_cores(),available_memory:detect_available_memory(),device_memory:gpu_config::detect_device_memory()}
+    }
+}
+
+/// the number of cores of the machine, wasm32 builds run on the calling thread
+#[cfg(not(target_arch = "wasm32"))]
+fn detect_num_cores()->usize
+{
+    num_cpus::get()
+}
+#[cfg(target_arch = "wasm32")]
+fn detect_num_cores()->usize
+{
+    1
+}
+
+/// ## Summary
+/// Return the memory available for starting new processes in bytes, i.e. MemAvailable of /proc/meminfo, None is returned if the memory can
+/// not be detected, e.g. on systems without /proc
+pub fn detect_available_memory()->Option<u64>
+{
+    let meminfo=fs::read_to_string("/proc/meminfo").ok()?;
+    let kibibytes=meminfo.lines()
+        .find_map(|line|line.strip_prefix("MemAvailable:"))?
+        .trim().trim_end_matches("kB").trim().parse::<u64>().ok()?;
+    Some(kibibytes*1024)
+}
+
+/// ## Summary
+/// The size of an input VCF file, i.e. the number of samples, the number of records and the size of the file in bytes
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct InputSize
+{
+    pub num_samples:usize,
+    pub num_records:usize,
+    pub num_bytes:u64
+}
+impl InputSize
+{
+    /// ## Summary
+    /// Measure the size of a VCF file by streaming its lines, i.e. without loading the file into memory, the samples are counted from
+    /// the #CHROM line and the records are the lines of the body
+    pub fn from_vcf(path2load:&Path)->Result<Self,String>
+    {
+        let file=match fs::File::open(path2load)
+        {
+            Ok(file)=>file,
+            Err(err_msg)=>return Err(format!("Could not open the VCF file: {}, the following error was generated: {}",path2load.display(),err_msg))
+        };
+        let num_bytes=match file.metadata()
+        {
+            Ok(metadata)=>metadata.len(),
+            Err(err_msg)=>return Err(format!("Could not read the metadata of the VCF file: {}, the following error was generated: {}",path2load.display(),err_msg))
+        };
+        let (mut num_samples,mut num_records)=(0,0);
+        for line in BufReader::new(file).lines()
+        {
+            let line=match line
+            {
+                Ok(line)=>line,
+                Err(err_msg)=>return Err(format!("Could not read the VCF file: {}, the following error was generated: {}",path2load.display(),err_msg))
+            };
+            if line.starts_with("#CHROM")
+            {
+                num_samples=line.split('\t').count().saturating_sub(9);
+            }
+            else if !line.starts_with('#') && !line.is_empty()
+            {
+                num_records+=1;
+            }
+        }
+        Ok(InputSize{num_samples,num_records,num_bytes})
+    }
+    /// ## Summary
+    /// Return the workload of the input, i.e. the number of genotypes, which is the number of samples times the number of records
+    pub fn get_workload(&self)->u64
+    {
+        self.num_samples as u64*self.num_records as u64
+    }
+}
+
+/// ## Summary
+/// The engine picked for a run along with the GPU config, which is only set for the GPU engine, and a human readable reason for the decision
+#[derive(Debug,Clone)]
+pub struct EngineSelection
+{
+    pub engine:Engine,
+    pub gpu_config:Option<GpuConfig>,
+    pub reason:String
+}
+
+/// ## Summary
+/// Pick an engine from the size of the input and the available resources, the reason also warns if the VCF file is not expected to fit
+/// into the available memory:
+/// 1. The single-threaded engine is picked for workloads below MIN_MT_WORKLOAD or on machines with a single core.
+/// 2. The GPU engine is picked for workloads of at least MIN_GPU_WORKLOAD if a device was detected and gpu_supported is true, with a batch size derived from the device memory and limited to HOST_MEMORY_FRACTION of the available host memory.
+/// 3. The multi-threaded engine is picked otherwise, the batch sizes of the threads are derived by the adaptive schedule, see schedule::Schedule.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::engine_selection::{select_engine,InputSize,SystemResources};
+/// use ppgg::data_structures::InternalRep::engines::Engine;
+/// let resources=SystemResources{num_cores:16,available_memory:None,device_memory:None};
+/// let selection=select_engine(&InputSize{num_samples:2504,num_records:50_000,num_bytes:1<<30},&resources,false);
+/// assert!(matches!(selection.engine,Engine::MT));
+/// let selection=select_engine(&InputSize{num_samples:1,num_records:500,num_bytes:1<<20},&resources,false);
+/// assert!(matches!(selection.engine,Engine::ST));
+///```
+pub fn select_engine(input:&InputSize, resources:&SystemResources, gpu_supported:bool)->EngineSelection
+{
+    let workload=input.get_workload();
+    let description=format!("{} samples x {} records = {} genotypes on {} cores",input.num_samples,input.num_records,workload,resources.num_cores);
+    let mut selection=if workload < MIN_MT_WORKLOAD || resources.num_cores<=1
+    {
+        EngineSelection{engine:Engine::ST,gpu_config:None,
+            reason:format!("the single-threaded engine was selected for {}, i.e. a workload below {} genotypes or a single core",description,MIN_MT_WORKLOAD)}
+    }
+    else
+    {
+        match (resources.device_memory,gpu_supported)
+        {
+            (Some(device_memory),true) if workload >= MIN_GPU_WORKLOAD=>
+            {
+                let mut gpu_config=GpuConfig::from_device_memory(device_memory);
+                if let Some(available_memory)=resources.available_memory
+                {
+                    let max_batch_size=(available_memory as f64*HOST_MEMORY_FRACTION) as u64/(BYTES_PER_RESIDUE*gpu_config.get_num_streams() as u64);
+                    if (max_batch_size as usize) < gpu_config.get_batch_size()
+                    {
+                        gpu_config.set_batch_size(std::cmp::max(1,max_batch_size as usize)).unwrap();
+                    }
+                }
+                EngineSelection{engine:Engine::GPU,reason:format!("the GPU engine was selected for {} with a batch size of {} residues and {} streams",
+                    description,gpu_config.get_batch_size(),gpu_config.get_num_streams()),gpu_config:Some(gpu_config)}
+            },
+            (Some(_),false)=>EngineSelection{engine:Engine::MT,gpu_config:None,
+                reason:format!("the multi-threaded engine was selected for {}, a GPU was detected but the GPU engine is not supported by this build",description)},
+            _=>EngineSelection{engine:Engine::MT,gpu_config:None,reason:format!("the multi-threaded engine was selected for {}",description)}
+        }
+    };
+    if let Some(available_memory)=resources.available_memory
+    {
+        if input.num_bytes.saturating_mul(PARSING_MEMORY_RATIO) > available_memory
+        {
+            selection.reason.push_str(&format!(", WARNING: parsing the VCF file of {} bytes may need more than the {} bytes of available memory",
+                input.num_bytes,available_memory));
+        }
+    }
+    selection
+}
+
+#[cfg(test)]
+mod test_engine_selection
+{
+    use super::*;
+    #[test]
+    fn test_select_engine()
+    {
+        let gib=1024*1024*1024;
+        let large=InputSize{num_samples:500_000,num_records:1_000_000,num_bytes:gib};
+        let resources=SystemResources{num_cores:1,available_memory:None,device_memory:Some(16*gib)};
+        assert!(matches!(select_engine(&large,&resources,true).engine,Engine::ST));
+        let resources=SystemResources{num_cores:32,available_memory:Some(8*gib),device_memory:Some(16*gib)};
+        let selection=select_engine(&large,&resources,true);
+        assert!(matches!(selection.engine,Engine::GPU));
+        let gpu_config=selection.gpu_config.unwrap();
+        assert_eq!(gpu_config.get_batch_size(),(2*gib/(BYTES_PER_RESIDUE*4)) as usize);
+        let selection=select_engine(&large,&resources,false);
+        assert!(matches!(selection.engine,Engine::MT) && selection.gpu_config.is_none());
+        assert!(selection.reason.contains("not supported"));
+        let huge=InputSize{num_bytes:4*gib,..large};
+        assert!(select_engine(&huge,&resources,false).reason.contains("WARNING"));
+    }
+    #[test]
+    fn test_input_size_from_vcf()
+    {
+        let path=std::env::temp_dir().join("test_input_size_from_vcf.vcf");
+        fs::write(&path,"##fileformat=VCFv4.2\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1\tS2\n1\t10\t.\tA\tT\t.\tPASS\t.\tGT\t0|1\t1|1\n1\t20\t.\tA\tT\t.\tPASS\t.\tGT\t0|1\t0|0\n").unwrap();
+        let input=InputSize::from_vcf(&path).unwrap();
+        assert_eq!((input.num_samples,input.num_records,input.get_workload()),(2,2,4));
+        fs::remove_file(&path).unwrap();
+    }
+}
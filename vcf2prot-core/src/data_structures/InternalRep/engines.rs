@@ -0,0 +1,304 @@
+use std::str::FromStr;
+
+/// An enumerator describing possible cases to generate an execution engine 
+/// Currently, three engines are supported, a single-threaded engine, a multi-threaded engine and a GPU execution engine, along with
+/// Auto, which is a placeholder that should be resolved into one of the three engines using engine_selection::select_engine before
+/// parsing, functions that are called with an unresolved Auto engine fall back to the multi-threaded implementation
+/// the enumerator implements the FromStr trait and derives the Debug and Clone trait 
+/// ```rust
+/// let engine=Engine::from_str("st"); 
+/// match engine
+/// {
+///     Engine::ST=>println!("An Engine instance of type: {:#?}",engine),
+///     _=>println!("This line will not be printed")
+/// }
+/// ´´´
+#[derive(Debug,Clone)]
+pub enum Engine{ST,MT,GPU,Auto}
+
+impl FromStr for Engine 
+{
+    type Err=String;
+    fn from_str(eninge_name:&str)->Result<Engine,String>
+    {
+        match eninge_name
+        {
+            "st"  | "ST" =>Ok(Engine::ST), 
+            "mt"  | "MT" =>Ok(Engine::MT),
+            "gpu" | "GPU"=>Ok(Engine::GPU),
+            "auto" | "AUTO"=>Ok(Engine::Auto),
+            _=>Err(format!("{} is not a supported engine",eninge_name))
+        }
+    }
+}
+/// ## Summary
+/// An engine that can be requested from the command line, i.e. its name, the parameters it accepts along with a placeholder of their 
+/// values and a short description
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct EngineDescriptor
+{
+    pub name:&'static str,
+    pub params:&'static [(&'static str,&'static str)],
+    pub description:&'static str
+}
+impl EngineDescriptor
+{
+    /// ## Summary
+    /// Return the usage of the engine, i.e. its name followed by its parameters, e.g. mt[:threads=N]
+    pub fn get_usage(&self)->String
+    {
+        match self.params.is_empty()
+        {
+            true=>self.name.to_string(),
+            false=>format!("{}[:{}]",self.name,self.params.iter().map(|(param,value)|format!("{}={}",param,value)).collect::<Vec<String>>().join(","))
+        }
+    }
+}
+/// ## Summary
+/// The engines known to the library along with their parameters, see EngineSpec, the GPU engine is only available if the library is 
+/// compiled with the cuda feature, see EngineSpec::get_available_engines
+pub const ENGINE_DESCRIPTORS:[EngineDescriptor;4]=[
+    EngineDescriptor{name:"st",params:&[],description:"a single-thread engine"},
+    EngineDescriptor{name:"mt",params:&[("threads","N")],description:"a multi-thread engine, threads sets the number of worker threads"},
+    EngineDescriptor{name:"gpu",params:&[("device","N"),("batch_size","N"),("num_streams","N"),("pinned_memory","true|false")],
+        description:"the GPU engine, device sets the index of the device, the other parameters overwrite the GPU config"},
+    EngineDescriptor{name:"auto",params:&[("threads","N")],
+        description:"selects one of the other engines from the size of the input and the available resources, threads sets the number of worker threads"}
+]; 
+/// The environment variable providing the number of worker threads if it is neither provided by the caller nor by the engine, see EngineSpec::with_num_threads
+pub const THREADS_ENV_VAR:&str="VCF2PROT_THREADS"; 
+
+/// ## Summary
+/// A parsed engine specification, i.e. the name of an engine optionally followed by a colon and comma-separated key=value parameters, 
+/// e.g. mt:threads=16 or gpu:device=1,num_streams=2, where the plain engine names, e.g. st or MT, are parsed as before. The parameters 
+/// are validated against the parameters each engine accepts, see ENGINE_DESCRIPTORS, and the errors list the available engines. 
+/// ## Example
+///```rust
+/// use std::str::FromStr;
+/// use ppgg::data_structures::InternalRep::engines::{Engine,EngineSpec};
+/// let spec=EngineSpec::from_str("mt:threads=16").unwrap();
+/// assert!(matches!(spec.get_engine(),Engine::MT));
+/// assert_eq!(spec.get_num_threads(),Some(16));
+/// assert!(matches!(EngineSpec::from_str("ST").unwrap().get_engine(),Engine::ST));
+/// assert!(EngineSpec::from_str("st:threads=2").is_err());
+/// assert!(EngineSpec::from_str("simd").unwrap_err().contains("mt[:threads=N]"));
+///```
+#[derive(Debug,Clone)]
+pub struct EngineSpec
+{
+    engine:Engine,
+    num_threads:Option<usize>,
+    device:Option<usize>,
+    gpu_params:Vec<(String,String)>
+}
+impl EngineSpec
+{
+    /// ## Summary
+    /// Return the engines that can be requested with the current build of the library 
+    pub fn get_available_engines()->Vec<EngineDescriptor>
+    {
+        ENGINE_DESCRIPTORS.iter().filter(|descriptor|descriptor.name!="gpu" || cfg!(feature = "cuda")).copied().collect()
+    }
+    /// ## Summary
+    /// Return a description of the available engines, one engine per line, which is printed along with the errors of the parser 
+    pub fn describe_available_engines()->String
+    {
+        EngineSpec::get_available_engines().iter()
+            .map(|descriptor|format!("  {} => {}",descriptor.get_usage(),descriptor.description))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+    /// ## Summary
+    /// Return the requested engine 
+    pub fn get_engine(&self)->Engine
+    {
+        self.engine.clone()
+    }
+    /// ## Summary
+    /// Return the requested number of worker threads, if any 
+    pub fn get_num_threads(&self)->Option<usize>
+    {
+        self.num_threads
+    }
+    /// ## Summary
+    /// Return the index of the requested GPU device, if any 
+    pub fn get_device(&self)->Option<usize>
+    {
+        self.device
+    }
+    /// ## Summary
+    /// Return the parameters of the GPU config, i.e. batch_size, num_streams and pinned_memory, as key=value lines in the format of 
+    /// GpuConfig::update_from_str 
+    pub fn get_gpu_params(&self)->String
+    {
+        self.gpu_params.iter().map(|(key,value)|format!("{}={}\n",key,value)).collect()
+    }
+    /// ## Summary
+    /// Build the global thread pool with the requested number of worker threads, nothing is done if no number of threads was requested. 
+    /// An error is returned if the global thread pool has already been built, hence, this must be called before any parallel work. 
+    pub fn build_thread_pool(&self)->Result<(),String>
+    {
+        match self.num_threads
+        {
+            Some(num_threads)=>match rayon::ThreadPoolBuilder::new().num_threads(num_threads).build_global()
+            {
+                Ok(_)=>Ok(()),
+                Err(err_msg)=>Err(format!("Building a thread pool with {} threads failed with the following error: {}",num_threads,err_msg))
+            },
+            None=>Ok(())
+        }
+    }
+    /// ## Summary
+    /// Resolve the number of worker threads from, in decreasing priority, the provided number of threads, e.g. --threads, the threads 
+    /// parameter of the engine and the value of the VCF2PROT_THREADS environment variable, which is passed as env_value. The resolved number 
+    /// replaces the threads parameter, also for the single-thread engine, as parsing and writing use the thread pool as well. An error is 
+    /// returned if the provided number disagrees with the threads parameter or if one of them is not a positive integer. 
+    /// ## Example
+    ///```rust
+    /// use std::str::FromStr;
+    /// use ppgg::data_structures::InternalRep::engines::EngineSpec;
+    /// let spec=EngineSpec::from_str("mt").unwrap().with_num_threads(None,Some("4".to_string())).unwrap();
+    /// assert_eq!(spec.get_num_threads(),Some(4));
+    /// let spec=EngineSpec::from_str("st").unwrap().with_num_threads(Some(2),Some("4".to_string())).unwrap();
+    /// assert_eq!(spec.get_num_threads(),Some(2));
+    /// assert!(EngineSpec::from_str("mt:threads=8").unwrap().with_num_threads(Some(2),None).is_err());
+    ///```
+    pub fn with_num_threads(mut self, num_threads:Option<usize>, env_value:Option<String>)->Result<Self,String>
+    {
+        let from_env=match env_value.as_deref().map(str::trim)
+        {
+            None | Some("")=>None,
+            Some(value)=>match value.parse::<usize>()
+            {
+                Ok(num_threads) if num_threads!=0=>Some(num_threads),
+                _=>return Err(format!("The number of threads in {} must be a positive integer, however, {} was provided",THREADS_ENV_VAR,value))
+            }
+        };
+        self.num_threads=match (num_threads,self.num_threads)
+        {
+            (Some(0),_)=>return Err("The number of threads must be a positive integer, however, 0 was provided".to_string()),
+            (Some(requested),Some(param)) if requested!=param=>return Err(format!("The requested number of threads: {} disagrees with the threads parameter \
+                of the engine: {}, provide only one of them",requested,param)),
+            (Some(requested),_)=>Some(requested),
+            (None,Some(param))=>Some(param),
+            (None,None)=>from_env
+        };
+        Ok(self)
+    }
+    /// ## Summary
+    /// Build a thread pool with the requested number of worker threads, which can be entered with ThreadPool::install to run the parsing, 
+    /// the execution and the writing on it, None is returned if no number of threads was requested, i.e. the global pool with one thread 
+    /// per core is used. In contrast to build_thread_pool, the pool is not global, hence, it can be built more than once in a process. 
+    pub fn build_scoped_thread_pool(&self)->Result<Option<rayon::ThreadPool>,String>
+    {
+        match self.num_threads
+        {
+            Some(num_threads)=>match rayon::ThreadPoolBuilder::new().num_threads(num_threads).thread_name(|index|format!("vcf2prot-worker-{}",index)).build()
+            {
+                Ok(pool)=>Ok(Some(pool)),
+                Err(err_msg)=>Err(format!("Building a thread pool with {} threads failed with the following error: {}",num_threads,err_msg))
+            },
+            None=>Ok(None)
+        }
+    }
+    /// parse the value of a parameter that must be a positive, or for the device a non-negative, integer 
+    fn parse_count(engine_name:&str, key:&str, value:&str)->Result<usize,String>
+    {
+        match value.parse::<usize>()
+        {
+            Ok(0) if key!="device"=>Err(format!("The parameter {} of the engine {} must be a positive integer, however, 0 was provided",key,engine_name)),
+            Ok(count)=>Ok(count),
+            Err(_)=>Err(format!("The parameter {} of the engine {} must be an integer, however, {} was provided",key,engine_name,value))
+        }
+    }
+}
+impl FromStr for EngineSpec
+{
+    type Err=String;
+    fn from_str(spec:&str)->Result<EngineSpec,String>
+    {
+        let (engine_name,params)=match spec.split_once(':')
+        {
+            Some((engine_name,params))=>(engine_name,Some(params)),
+            None=>(spec,None)
+        };
+        let available=EngineSpec::get_available_engines(); 
+        let descriptor=match available.iter().find(|descriptor|descriptor.name.eq_ignore_ascii_case(engine_name.trim()))
+        {
+            Some(descriptor)=>descriptor,
+            None=>return Err(format!("{} is not a supported engine, the available engines are:\n{}",engine_name,EngineSpec::describe_available_engines()))
+        };
+        let engine=Engine::from_str(descriptor.name)?; 
+        let mut engine_spec=EngineSpec{engine,num_threads:None,device:None,gpu_params:Vec::new()}; 
+        let mut seen_keys=Vec::new(); 
+        for param in params.into_iter().flat_map(|params|params.split(','))
+        {
+            let (key,value)=match param.split_once('=')
+            {
+                Some((key,value)) if !key.trim().is_empty() && !value.trim().is_empty()=>(key.trim(),value.trim()),
+                _=>return Err(format!("The parameter: {} of the engine {} is not a key=value pair, the engine is used as {}",param,descriptor.name,descriptor.get_usage()))
+            };
+            if !descriptor.params.iter().any(|(param,_)|*param==key)
+            {
+                return Err(match descriptor.params.is_empty()
+                {
+                    true=>format!("The engine {} does not accept any parameter, however, {} was provided",descriptor.name,key),
+                    false=>format!("The engine {} does not accept the parameter {}, the supported parameters are: {}",descriptor.name,key,descriptor.params.iter().map(|(param,_)|*param).collect::<Vec<&str>>().join(", "))
+                })
+            }
+            if seen_keys.contains(&key)
+            {
+                return Err(format!("The parameter {} of the engine {} has been provided more than once",key,descriptor.name))
+            }
+            seen_keys.push(key); 
+            match key
+            {
+                "threads"=>engine_spec.num_threads=Some(EngineSpec::parse_count(descriptor.name,key,value)?),
+                "device"=>engine_spec.device=Some(EngineSpec::parse_count(descriptor.name,key,value)?),
+                _=>engine_spec.gpu_params.push((key.to_string(),value.to_string()))
+            }
+        }
+        Ok(engine_spec)
+    }
+}
+
+#[cfg(test)]
+mod test_engine_spec
+{
+    use super::*;
+    #[test]
+    fn test_parse_engine_spec()
+    {
+        for (spec,num_threads) in [("st",None),("MT",None),("mt:threads=16",Some(16)),("auto:threads=4",Some(4))]
+        {
+            assert_eq!(EngineSpec::from_str(spec).unwrap().get_num_threads(),num_threads);
+        }
+        #[cfg(feature = "cuda")]
+        {
+            let spec=EngineSpec::from_str("gpu:device=1,num_streams=2").unwrap();
+            assert_eq!((spec.get_device(),spec.get_gpu_params()),(Some(1),"num_streams=2\n".to_string()));
+        }
+        for (spec,expected) in [("mt:threads=0","positive integer"),("mt:threads=x","must be an integer"),("mt:device=1","supported parameters are: threads"),
+            ("st:threads=2","does not accept any parameter"),("mt:threads","key=value"),("mt:threads=2,threads=3","more than once"),("naive","available engines")]
+        {
+            let err_msg=EngineSpec::from_str(spec).unwrap_err();
+            assert!(err_msg.contains(expected),"{} => {}",spec,err_msg);
+        }
+    }
+    #[test]
+    fn test_with_num_threads()
+    {
+        let spec=|spec:&str|EngineSpec::from_str(spec).unwrap();
+        // the requested number has the highest priority, followed by the parameter and the environment variable
+        assert_eq!(spec("mt:threads=3").with_num_threads(None,Some("4".to_string())).unwrap().get_num_threads(),Some(3));
+        assert_eq!(spec("mt:threads=3").with_num_threads(Some(3),None).unwrap().get_num_threads(),Some(3));
+        assert_eq!(spec("mt").with_num_threads(None,Some(" ".to_string())).unwrap().get_num_threads(),None);
+        for (requested,env_value) in [(Some(0),None),(None,Some("0")),(None,Some("all"))]
+        {
+            assert!(spec("mt").with_num_threads(requested,env_value.map(|value|value.to_string())).is_err());
+        }
+        let pool=spec("st").with_num_threads(Some(2),None).unwrap().build_scoped_thread_pool().unwrap().unwrap();
+        assert_eq!(pool.install(rayon::current_num_threads),2);
+        assert!(spec("mt").build_scoped_thread_pool().unwrap().is_none());
+    }
+}
@@ -0,0 +1,673 @@
+// load the modules and crate library 
+use std::collections::HashMap; 
+use super::task::Task; 
+use super::engines::Engine; 
+use crate::error::PpggError; 
+use crate::functions::diagnostics::CheckPolicy; 
+use super::schedule::inner_min_len; 
+use rayon::prelude::*; 
+
+/// ## Summary
+/// The default residue the results array is initialized with, residues that still hold it after the execution were not written by any task, 
+/// see PlaceholderPolicy for using another residue 
+pub const PLACEHOLDER_RESIDUE:char='.'; 
+
+/// ## Summary
+/// The handling of placeholder residues, i.e. the residue the results arrays are initialized with and whether the strict check is performed.
+/// With the strict check, which is the default, the transcripts whose sequence still contains a placeholder residue after the execution are 
+/// rejected, i.e. they are removed from the personalized genome and listed in the execution report, so a task-table bug can never leak into 
+/// the fasta files, without it, they are written as recovered transcripts, which is useful for inspecting the unwritten residues with a 
+/// distinctive placeholder. The policy is passed to each execution, see exec::ExecutionOptions. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct PlaceholderPolicy
+{
+    pub residue:char,
+    pub is_strict:bool
+}
+impl Default for PlaceholderPolicy
+{
+    fn default()->Self
+    {
+        PlaceholderPolicy{residue:PLACEHOLDER_RESIDUE,is_strict:true}
+    }
+}
+impl PlaceholderPolicy
+{
+    /// ## Summary
+    /// Create a new policy, an error is returned if the residue could be mistaken for a residue of a protein sequence, i.e. an upper-case 
+    /// letter or '*', or breaks the fasta format, i.e. '>', a whitespace or a non-ASCII character 
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::InternalRep::gir::PlaceholderPolicy; 
+    /// assert!(PlaceholderPolicy::new('#',false).is_ok()); 
+    /// assert!(PlaceholderPolicy::new('X',true).is_err()); 
+    /// assert!(PlaceholderPolicy::new('>',true).is_err()); 
+    ///```
+    pub fn new(residue:char, is_strict:bool)->Result<Self,String>
+    {
+        if !residue.is_ascii_graphic() || residue.is_ascii_uppercase() || residue=='*' || residue=='>'
+        {
+            return Err(format!("The placeholder residue: {:?} is not supported, it must be a printable ASCII character that is neither an upper-case letter, '*' nor '>'",residue))
+        }
+        Ok(PlaceholderPolicy{residue,is_strict})
+    }
+}
+/// ## Summary
+/// Format a vector of tasks as the table of the CPU execution engine, which is logged when the tasks fail the consistency check 
+pub(crate) fn format_task_table(tasks:&[Task])->String
+{
+    let mut table=String::from("************ CPU Execution Table *********\nindex\tstream\tstart_position\tlength\tposition_results\t"); 
+    for (idx,task) in tasks.iter().enumerate()
+    {
+        table.push_str(&format!("\n{}\t{}\t{}\t{}\t{}\t",idx,task.get_execution_stream(),task.get_start_pos(),task.get_length(),task.get_start_pos_res())); 
+    }
+    table
+}
+
+/// ## Summary
+/// The validity of an executed representation, Valid if every task was executed as generated and every residue of the results array was 
+/// written, and Recovered if the sequences were produced under an error-recovery heuristic, i.e. a task was clamped, a residue was left 
+/// unwritten or a transcript was skipped because its instructions could not be translated into tasks 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ExecutionStatus
+{
+    Valid,
+    Recovered
+}
+impl Default for ExecutionStatus
+{
+    fn default()->Self
+    {
+        ExecutionStatus::Valid
+    }
+}
+impl ExecutionStatus
+{
+    /// ## Summary
+    /// Return the name of the status as used in the reports and in the fasta headers 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ExecutionStatus::Valid=>"valid",
+            ExecutionStatus::Recovered=>"recovered"
+        }
+    }
+}
+
+/// ## Summary
+/// A task whose length was clamped before the execution because it reads beyond the end of its input stream or writes beyond the end of 
+/// the results array, the transcript is the one whose interval in the results array contains the start of the task, if any 
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Clamp
+{
+    pub task_index:usize,
+    pub transcript:Option<String>,
+    pub requested_length:usize,
+    pub performed_length:usize
+}
+
+/// ## Summary
+/// The diagnostics of an execution, i.e. the number of applied instructions and executed tasks along with the clamped tasks, the number of 
+/// unwritten residues and the skipped transcripts along with the errors they failed with, recovered transcripts are the transcripts that contain
+/// a clamped task or an unwritten residue, while rejected transcripts contained an unwritten residue and were removed by the strict placeholder
+/// check, see PlaceholderPolicy 
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct ExecutionReport
+{
+    pub num_applied_instructions:usize,
+    pub num_executed_tasks:usize,
+    pub clamps:Vec<Clamp>,
+    pub num_unfilled_residues:usize,
+    pub skipped_transcripts:Vec<String>,
+    pub skip_reasons:Vec<PpggError>,
+    pub recovered_transcripts:Vec<String>,
+    pub rejected_transcripts:Vec<String>
+}
+impl ExecutionReport
+{
+    /// ## Summary
+    /// Return the status of the execution, see ExecutionStatus 
+    pub fn get_status(&self)->ExecutionStatus
+    {
+        match self.clamps.is_empty() && self.num_unfilled_residues==0 && self.skipped_transcripts.is_empty()
+        {
+            true=>ExecutionStatus::Valid,
+            false=>ExecutionStatus::Recovered
+        }
+    }
+    /// ## Summary
+    /// Return whether the sequence of a transcript was produced under an error-recovery heuristic 
+    pub fn is_recovered(&self, transcript:&str)->bool
+    {
+        self.recovered_transcripts.iter().any(|recovered|recovered==transcript)
+    }
+}
+
+/// ## Summary
+/// The result of executing a representation, the same for every engine, made of the results array, the interval of each transcript in the 
+/// results array and the diagnostics of the execution 
+#[derive(Debug,Clone)]
+pub struct ExecutionResult
+{
+    pub sequence:Vec<char>,
+    pub annotation:HashMap<String,(usize,usize)>,
+    pub report:ExecutionReport
+}
+impl ExecutionResult
+{
+    /// ## Summary
+    /// Return the status of the execution, see ExecutionStatus 
+    pub fn get_status(&self)->ExecutionStatus
+    {
+        self.report.get_status()
+    }
+    /// ## Summary
+    /// Remove the transcripts whose sequence contains the placeholder residue from the annotation and move them from the recovered to the 
+    /// rejected transcripts of the report, returns the number of rejected transcripts 
+    pub fn reject_placeholder_transcripts(&mut self, placeholder:char)->usize
+    {
+        let sequence=&self.sequence; 
+        let mut rejected=self.annotation.iter()
+            .filter(|(_,(start,end))|sequence.get(*start..*end).map_or(false,|seq|seq.contains(&placeholder)))
+            .map(|(transcript,_)|transcript.clone())
+            .collect::<Vec<String>>(); 
+        rejected.sort(); 
+        for transcript in rejected.iter()
+        {
+            self.annotation.remove(transcript); 
+        }
+        self.report.recovered_transcripts.retain(|transcript|rejected.binary_search(transcript).is_err()); 
+        self.report.rejected_transcripts.extend(rejected); 
+        self.report.rejected_transcripts.len()
+    }
+    /// ## Summary
+    /// Consume the instance and return the results array along with the interval of each transcript 
+    pub fn into_parts(self)->(Vec<char>,HashMap<String,(usize,usize)>)
+    {
+        (self.sequence,self.annotation)
+    }
+}
+
+
+/// GIRL: Genomic intermediate representation language (GIRL) which us derived from sequence intermediate representation (SIR)
+/// a generic representation for sequence editing tasks, it is composite of 
+/// 1- a vector of tasks, see(Tasks for more details)
+/// 2- an hashmap containing the bodundires of the resulting hashmap array 
+/// 3- alt_stream: a vector of chars containing alterations, i.e. mutated amino acids 
+/// 4- ref_stream: a vector of chars containing the reference stream
+/// 5- res_array: a vector of chars containing the resulting arrays
+/// 6- num_instructions: the number of instructions the tasks were generated from 
+/// 7- skipped_transcripts: the transcripts whose instructions could not be translated into tasks 
+/// 8- skip_reasons: the errors the skipped transcripts failed with, in the order of skipped_transcripts 
+/// 9- placeholder: the residue the results array was initialized with 
+/// 10- check_policy: the policy of the checks of the tasks before they are executed 
+/// the struct derives the Debug and the clone traits 
+#[derive(Debug,Clone)]
+pub struct GIR
+{
+    g_rep:Vec<Task>,
+    annotation:HashMap<String,(usize,usize)>, 
+    alt_stream:Vec<char>,
+    ref_stream:Vec<char>,
+    res_array:Vec<char>,
+    num_instructions:usize,
+    skipped_transcripts:Vec<String>,
+    skip_reasons:Vec<PpggError>,
+    placeholder:char,
+    check_policy:CheckPolicy
+}
+impl GIR
+{
+    /// ## Summary 
+    /// Create a new instance using the main 5 units needed for representation 
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation:HashMap<String,(usize,usize)>=HashMap::new(); 
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// println!("The generated GIRL is: {:#?}",res);
+    ///```
+    pub fn new(g_rep:Vec<Task>, annotation:HashMap<String,(usize,usize)>, 
+            alt_stream:Vec<char>, ref_stream:Vec<char>, res_array:Vec<char> )->Self
+    {
+        GIR{g_rep,annotation,alt_stream,ref_stream,res_array,num_instructions:0,skipped_transcripts:Vec::new(),skip_reasons:Vec::new(),
+            placeholder:PLACEHOLDER_RESIDUE,check_policy:CheckPolicy::default()}
+    }
+    /// ## Summary
+    /// set the residue the results array was initialized with, PLACEHOLDER_RESIDUE by default, the residues that still hold it after the 
+    /// execution are counted as unwritten residues 
+    pub fn set_placeholder(&mut self, placeholder:char)
+    {
+        self.placeholder=placeholder; 
+    }
+    /// ## Summary
+    /// set the policy the tasks are checked with before they are executed, CheckPolicy::Off by default, see diagnostics::CheckPolicy 
+    pub fn set_check_policy(&mut self, check_policy:CheckPolicy)
+    {
+        self.check_policy=check_policy; 
+    }
+    /// ## Summary
+    /// set the number of instructions the tasks were generated from, which is reported as the number of applied instructions 
+    pub fn set_num_instructions(&mut self, num_instructions:usize)
+    {
+        self.num_instructions=num_instructions; 
+    }
+    /// ## Summary
+    /// return the number of instructions the tasks were generated from 
+    pub fn get_num_instructions(&self)->usize
+    {
+        self.num_instructions
+    }
+    /// ## Summary
+    /// set the transcripts that were skipped while generating the tasks, which marks the execution as recovered 
+    pub fn set_skipped_transcripts(&mut self, skipped_transcripts:Vec<String>)
+    {
+        self.skipped_transcripts=skipped_transcripts; 
+    }
+    /// ## Summary
+    /// set the errors the skipped transcripts failed with, in the order of the skipped transcripts 
+    pub fn set_skip_reasons(&mut self, skip_reasons:Vec<PpggError>)
+    {
+        self.skip_reasons=skip_reasons; 
+    }
+    /// ## Summary
+    /// Return a reference to the instance vector of tasks
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation:HashMap<String,(usize,usize)>=HashMap::new(); 
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // return a reference to the instance vector of tasks 
+    /// let task = res.get_tasks();
+    /// println!("The instance's tasks are: {:?}",task);
+    ///´´´
+    pub fn get_tasks(&self)->&Vec<Task>
+    {
+        &self.g_rep
+    }
+    /// ## Summary 
+    /// Consume the instance and move its vector of tasks to the caller  
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation:HashMap<String,(usize,usize)>=HashMap::new(); 
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // return a reference to the instance vector of tasks 
+    /// let task = res.consume_and_get_tasks();
+    /// println!("The instance's tasks are: {:?}",task);
+    /// //println!("This line will cause an error if printed as res has been consumed: {:#?}",res);
+    ///´´´
+    pub fn consume_and_get_tasks(self)->Vec<Task>
+    {
+        self.g_rep
+    }
+    /// ## Summary 
+    /// Consume the instance and return its component as a tuple of elements  
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation:HashMap<String,(usize,usize)>=HashMap::new(); 
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // return a reference to the instance vector of tasks 
+    /// let task = res.consume_and_get_tasks();
+    /// println!("The instance's tasks are: {:?}",task);
+    /// //println!("This line will cause an error if printed as res has been consumed: {:#?}",res);
+    pub fn consumer_and_get_resources(self)->(Vec<Task>,HashMap<String,(usize,usize)>,Vec<char>,Vec<char>,Vec<char>)
+    {
+        (self.g_rep,self.annotation,self.alt_stream,self.ref_stream,self.res_array)
+    }
+    /// ## Summary 
+    /// Return a hash map containing the annotations associated in the reference array 
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation:HashMap<String,(usize,usize)>=HashMap::new(); 
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // return a reference to the instance vector of tasks 
+    /// let annotations = res.get_annotation();
+    /// println!("The instance's annotation is: {:?}",annotations);
+    ///```
+    pub fn get_annotation(&self)->&HashMap<String,(usize,usize)>
+    {
+        &self.annotation
+    }
+    /// ## Summary 
+    /// Return the max index in the tasks vector
+    /// ## Examples 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::task; 
+    /// use std::collections::HashMap; 
+    /// // let's define some place holders for a task 
+    /// let g_rep:Vec<Task>=Vec::new(); 
+    /// let annotation=HashMap::new(); 
+    /// // let's add some annotations to the code 
+    /// annotations.insert("Sequence_1".to_string(), (0 as usize,25 as usize));
+    /// annotations.insert("Sequence_2".to_string(), (25 as usize, 50 as usize))
+    /// let alt_stream:Vec<char>=Vec::new(); 
+    /// let ref_stream:Vec<char>=Vec::new(); 
+    /// let res_array:Vec<char> =Vec::new(); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // return a reference to the instance vector of tasks 
+    /// assert_eq!(res.get_results_max(),50 as usize);
+    ///``` 
+    pub fn get_results_max(&self)->usize
+    {
+        let mut max_length=0; 
+        for (_,value) in self.annotation.iter()
+        {
+            if value.1>max_length
+            {
+                max_length=value.1
+            }
+        }
+        max_length
+    }
+    /// ## Summary 
+    /// execute and consume the representation to return the edited sequences along with a hashmap containing index of features in the 
+    /// results vector and the diagnostics of the execution, see ExecutionResult. Tasks that read beyond the end of their input stream or 
+    /// write beyond the end of the results array are clamped to the available residues and are reported as clamps instead of aborting 
+    /// the execution 
+    /// ## Example 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::{task,engines}; 
+    /// use std::collections::HashMap;
+    /// // let's define some dummy example data
+    /// let g_rep=Vec::new(); 
+    /// let g_rep.push(task::Task::new(0/* execution_code is zero, i.e. reference stream */,
+    ///                 0 /* start copying from position 0 */,
+    ///                 4 /* copies 4 amino acids*/, 
+    ///                 0 /* insert at position 0 in the reference array */)); 
+    /// let g_rep.push(task::Task::new(1 /* execution_code is 1, i.e. alternative stream */,
+    ///                 0 /* start copying from position 0 */,
+    ///                 1 /* copies 1 amino acids*/, 
+    ///                 4 /* insert at position 0 in the reference array */)); 
+    /// let annotation=HashMap::new(); 
+    /// annotation.insert("Seq_1".to_string(),(0 as usize,5 as usize)); 
+    /// let alt_stream=vec!['G']; 
+    /// let ref_stream=vec!['T','E','S','T']; 
+    /// let res_array:Vec<char> =Vec::with_capacity(5); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // execute the GIR with a single threaded engine 
+    /// let result=res.execute(engines::Engine::from_str("st")).unwrap(); 
+    /// println!("Results array: {:#?}",result.sequence); 
+    /// println!("Result hashmap is: {:#?}", result.annotation);
+    /// println!("Execution status is: {:?}", result.get_status());
+    ///``` 
+    /// An error is returned if the tasks are inconsistent, i.e. unless the quality-control checks are off, see CheckPolicy, if a task fails or if the engine is not supported
+    pub fn execute(self, engine:Engine)->Result<ExecutionResult,PpggError>
+    {        
+        match engine 
+        {
+            Engine::ST | Engine::MT | Engine::Auto =>
+            {
+                if self.check_policy.is_enabled()
+                {
+                    log::trace!("Validating the execution tasks on the CPU engine ....");
+                    for idx in 1..self.g_rep.len()
+                    {
+                        if self.g_rep[idx].get_start_pos_res()!=self.g_rep[idx-1].get_start_pos_res() + self.g_rep[idx-1].get_length()
+                        {
+                            log::debug!("{}",format_task_table(&self.g_rep));
+                            let expected_start=self.g_rep[idx-1].get_start_pos_res() + self.g_rep[idx-1].get_length();
+                            return Err(PpggError::InconsistentTasks{transcript:GIR::find_transcript(&self.annotation, expected_start),
+                                task_index:idx, expected_start, actual_start:self.g_rep[idx].get_start_pos_res()});
+                        }
+                    }
+                }
+                let mut res_array=self.res_array; 
+                let ref_stream=self.ref_stream;
+                let alt_stream=self.alt_stream;
+                let (g_rep,clamps)=GIR::clamp_tasks(self.g_rep, ref_stream.len(), alt_stream.len(), res_array.len(), &self.annotation); 
+                match engine
+                {
+                    Engine::MT | Engine::Auto=>
+                    {
+                        match GIR::split_results_array(&g_rep, &mut res_array)
+                        {
+                            Some(task_slices)=>
+                            {
+                                let min_len=inner_min_len(task_slices.len()); 
+                                task_slices.into_par_iter()
+                                    .with_min_len(min_len)
+                                    .try_for_each(|(task,res_slice)|task.execute_into(res_slice, &ref_stream, &alt_stream))?
+                            },
+                            // tasks that do not cover the results array contiguously are executed sequentially
+                            None=>g_rep.iter().try_for_each(|task| task.execute(&mut res_array, &ref_stream, &alt_stream))?
+                        }
+                    },
+                    _=>g_rep.iter().try_for_each(|task| task.execute(&mut res_array, &ref_stream, &alt_stream))?
+                }
+                let annotation=self.annotation; 
+                let placeholder=self.placeholder; 
+                let unfilled=res_array.iter().enumerate().filter(|(_,residue)|**residue==placeholder).map(|(index,_)|index).collect::<Vec<usize>>(); 
+                let mut recovered_transcripts=clamps.iter().filter_map(|clamp|clamp.transcript.clone())
+                    .chain(unfilled.iter().filter_map(|index|GIR::find_transcript(&annotation, *index)))
+                    .collect::<Vec<String>>(); 
+                recovered_transcripts.sort(); 
+                recovered_transcripts.dedup(); 
+                let report=ExecutionReport{num_applied_instructions:self.num_instructions, num_executed_tasks:g_rep.iter().filter(|task|task.get_length()!=0).count(),
+                    clamps, num_unfilled_residues:unfilled.len(), skipped_transcripts:self.skipped_transcripts, 
+                    skip_reasons:self.skip_reasons, recovered_transcripts, rejected_transcripts:Vec::new()}; 
+                Ok(ExecutionResult{sequence:res_array, annotation, report})
+            },
+            Engine::GPU => 
+            {
+                Err(PpggError::UnsupportedEngine("gpu".to_string()))
+            }
+        }
+    }   
+    /// ## Summary 
+    /// Clamp the length of each task to the residues available in its input stream and in the results array, returns the clamped tasks 
+    /// along with the clamps that were performed 
+    fn clamp_tasks(mut g_rep:Vec<Task>, ref_len:usize, alt_len:usize, res_len:usize, annotation:&HashMap<String,(usize,usize)>)->(Vec<Task>,Vec<Clamp>)
+    {
+        let mut clamps=Vec::new(); 
+        for (task_index,task) in g_rep.iter_mut().enumerate()
+        {
+            let stream_len=match task.get_stream()
+            {
+                0=>ref_len,
+                _=>alt_len
+            };
+            let available=stream_len.saturating_sub(task.get_start_pos()).min(res_len.saturating_sub(task.get_start_pos_res())); 
+            if task.get_length()>available
+            {
+                clamps.push(Clamp{task_index, transcript:GIR::find_transcript(annotation, task.get_start_pos_res()), 
+                    requested_length:task.get_length(), performed_length:available}); 
+                *task.get_mut_length()=available; 
+            }
+        }
+        (g_rep,clamps)
+    }
+    /// ## Summary 
+    /// Return the name of the transcript whose interval in the results array contains the provided index, if any 
+    fn find_transcript(annotation:&HashMap<String,(usize,usize)>, index:usize)->Option<String>
+    {
+        annotation.iter().find(|(_,(start,end))|*start<=index && index<*end).map(|(name,_)|name.clone())
+    }
+    /// ## Summary 
+    /// Split the results array into one disjoint slice per task, so the tasks can be executed in parallel, returns None if the tasks 
+    /// are not ordered head-to-tail in the results array or if a task writes beyond the end of the results array 
+    fn split_results_array<'a>(g_rep:&'a [Task], res_array:&'a mut [char])->Option<Vec<(&'a Task,&'a mut [char])>>
+    {
+        let mut task_slices=Vec::with_capacity(g_rep.len()); 
+        let mut remaining=res_array; 
+        let mut cursor=0; 
+        for task in g_rep.iter()
+        {
+            if task.get_start_pos_res()!=cursor || task.get_length()>remaining.len()
+            {
+                return None
+            }
+            let (head,tail)=remaining.split_at_mut(task.get_length()); 
+            task_slices.push((task,head)); 
+            remaining=tail; 
+            cursor+=task.get_length(); 
+        }
+        Some(task_slices)
+    }
+    /// ## Summary  ,ref_array,alt_array,annotation)
+    /// Consume the instance and return the following arrays:
+    /// 1. A vector of usize containing the execution code 
+    /// 2. A vector of usize containing the start positions in reference
+    /// 3. A vector of usize containing the length of each instruction  
+    /// 4. A char array containing the results array
+    /// 5. A char array containing the reference array 
+    /// 6. A char array containing the alternative array 
+    /// 7. A hashmap array containing a map between a string and a tuple of two usize integers 
+    /// ## Example 
+    /// ```rust
+    /// // let's load the need modules first 
+    /// use ppgg_rust::data_structures::internal_representation::{task,engines}; 
+    /// use std::collections::HashMap;
+    /// // let's define some dummy example data
+    /// let g_rep=Vec::new(); 
+    /// let g_rep.push(task::Task::new(0/* execution_code is zero, i.e. reference stream */,
+    ///                 0 /* start copying from position 0 */,
+    ///                 4 /* copies 4 amino acids*/, 
+    ///                 0 /* insert at position 0 in the reference array */)); 
+    /// let g_rep.push(task::Task::new(1 /* execution_code is 1, i.e. alternative stream */,
+    ///                 0 /* start copying from position 0 */,
+    ///                 1 /* copies 1 amino acids*/, 
+    ///                 4 /* insert at position 0 in the reference array */)); 
+    /// let annotation=HashMap::new(); 
+    /// annotation.insert("Seq_1".to_string(),(0 as usize,5 as usize)); 
+    /// let alt_stream=vec!['G']; 
+    /// let ref_stream=vec!['T','E','S','T']; 
+    /// let res_array:Vec<char> =Vec::with_capacity(5); 
+    /// let res=GIR::new(g_rep, annotation, alt_stream, ref_stream, res_array); 
+    /// // execute the GIR with a single threaded engine 
+    /// let (exec_code,start_pos,length,start_pos_res,res_array,ref_array,alt_array,annotation)=res.consume_and_produce_produce_content(); 
+    /// println!("The execution code is {:#?}",exec_code); 
+    /// println!("The execution code is {:#?}",start_pos);
+    /// println!("The length is {:#?}",length); 
+    /// println!("The start position in results is {:#?}",start_pos_res);  
+    /// println!("The results array is {:#?}",res_array);  
+    /// println!("The reference array is {:#?}",ref_array);  
+    /// println!("The alternative array is {:#?}",alt_array);  
+    /// println!("The annotation map is {:#?}",annotation);  
+    ///``` 
+    fn consume_and_produce_produce_content(self)->(Vec<usize>,Vec<usize>,Vec<usize>, 
+        Vec<usize>, Vec<char>, Vec<char>, Vec<char>, HashMap<String,(usize,usize)>)
+    {
+        let mut exec_code=Vec::with_capacity(self.g_rep.len()); 
+        let mut start_pos=Vec::with_capacity(self.g_rep.len()); 
+        let mut length=Vec::with_capacity(self.g_rep.len()); 
+        let mut start_pos_res=Vec::with_capacity(self.g_rep.len()); 
+        for task in self.g_rep.iter()
+        {
+            exec_code.push(task.get_stream() as usize); 
+            start_pos.push(task.get_start_pos()); 
+            length.push(task.get_length()); 
+            start_pos_res.push(task.get_start_pos_res())
+        }
+        let ( res_array,  ref_array,  alt_array, annotation)=(self.res_array, self.ref_stream, self.alt_stream, self.annotation); 
+        (exec_code,start_pos,length,start_pos_res,res_array,ref_array,alt_array,annotation)
+    }
+}
+
+#[cfg(test)]
+mod test_gir
+{
+    use super::*; 
+    #[test]
+    fn test_parallel_execution()
+    {
+        let g_rep=vec![Task::new(0,0,2,0),Task::new(1,0,1,2),Task::new(0,3,3,3),Task::new(1,1,2,6)]; 
+        let mut annotation=HashMap::new(); 
+        annotation.insert("Seq_1".to_string(),(0,8)); 
+        let alt_stream="KLM".chars().collect::<Vec<char>>(); 
+        let ref_stream="TESTSEQ".chars().collect::<Vec<char>>(); 
+        let gir=GIR::new(g_rep, annotation, alt_stream, ref_stream, vec!['.';8]); 
+        let res_st=gir.clone().execute(Engine::ST).unwrap(); 
+        let res_mt=gir.execute(Engine::MT).unwrap(); 
+        assert_eq!(res_st.sequence.iter().collect::<String>(),"TEKTSELM".to_string()); 
+        assert_eq!(res_st.sequence,res_mt.sequence); 
+        assert_eq!(res_st.get_status(),ExecutionStatus::Valid); 
+        assert_eq!(res_st.report.num_executed_tasks,4); 
+    }
+    #[test]
+    fn test_non_contiguous_execution()
+    {
+        // the second task leaves a gap in the results array, hence, the tasks are executed sequentially 
+        let g_rep=vec![Task::new(0,0,2,0),Task::new(0,2,2,3)]; 
+        let gir=GIR::new(g_rep, HashMap::new(), Vec::new(), "TEST".chars().collect::<Vec<char>>(), vec!['.';5]); 
+        let res_mt=gir.execute(Engine::MT).unwrap(); 
+        assert_eq!(res_mt.sequence.iter().collect::<String>(),"TE.ST".to_string()); 
+        assert_eq!((res_mt.get_status(),res_mt.report.num_unfilled_residues),(ExecutionStatus::Recovered,1)); 
+        // the unwritten residues are found with the placeholder the results array was initialized with 
+        let mut gir=GIR::new(vec![Task::new(0,0,2,0),Task::new(0,2,2,3)], HashMap::new(), Vec::new(), "TEST".chars().collect::<Vec<char>>(), vec!['#';5]); 
+        gir.set_placeholder('#'); 
+        let res_st=gir.execute(Engine::ST).unwrap(); 
+        assert_eq!((res_st.sequence.iter().collect::<String>(),res_st.report.num_unfilled_residues),("TE#ST".to_string(),1)); 
+    }
+    #[test]
+    fn test_clamped_execution()
+    {
+        // the second task reads beyond the end of the alternative stream, hence, it is clamped instead of aborting the execution 
+        let g_rep=vec![Task::new(0,0,2,0),Task::new(1,0,3,2)]; 
+        let mut annotation=HashMap::new(); 
+        annotation.insert("Seq_1".to_string(),(0,5)); 
+        let mut gir=GIR::new(g_rep, annotation, vec!['K'], "TEST".chars().collect::<Vec<char>>(), vec![PLACEHOLDER_RESIDUE;5]); 
+        gir.set_num_instructions(1); 
+        let result=gir.execute(Engine::ST).unwrap(); 
+        assert_eq!(result.sequence.iter().collect::<String>(),"TEK..".to_string()); 
+        assert_eq!(result.report.clamps,vec![Clamp{task_index:1,transcript:Some("Seq_1".to_string()),requested_length:3,performed_length:1}]); 
+        assert_eq!((result.report.num_applied_instructions,result.report.num_unfilled_residues),(1,2)); 
+        assert!(result.report.is_recovered("Seq_1")); 
+        assert_eq!(result.get_status().as_str(),"recovered"); 
+    }
+    #[test]
+    fn test_reject_placeholder_transcripts()
+    {
+        // the second transcript is left partially unwritten, hence, it is rejected by the strict placeholder check 
+        let g_rep=vec![Task::new(0,0,4,0),Task::new(0,0,2,4)]; 
+        let mut annotation=HashMap::new(); 
+        annotation.insert("Seq_1".to_string(),(0,4)); 
+        annotation.insert("Seq_2".to_string(),(4,8)); 
+        let gir=GIR::new(g_rep, annotation, Vec::new(), "TEST".chars().collect::<Vec<char>>(), vec![PLACEHOLDER_RESIDUE;8]); 
+        let mut result=gir.execute(Engine::ST).unwrap(); 
+        assert!(result.report.is_recovered("Seq_2")); 
+        assert_eq!(result.reject_placeholder_transcripts(PLACEHOLDER_RESIDUE),1); 
+        assert_eq!(result.annotation.keys().collect::<Vec<&String>>(),vec!["Seq_1"]); 
+        assert_eq!((result.report.rejected_transcripts.clone(),result.report.recovered_transcripts.len()),(vec!["Seq_2".to_string()],0)); 
+    }
+}
@@ -0,0 +1,229 @@
+// load the modules and crates
+use std::fs;
+use std::path::Path;
+#[cfg(feature = "cuda")]
+use std::process::Command;
+
+/// ## Summary
+/// The number of bytes needed on the device per residue of a batch, i.e. one char in the results array, the reference and the alternative
+/// streams, each stored as a 4 bytes char, along with the share of the task arrays
+pub const BYTES_PER_RESIDUE:u64=16;
+/// ## Summary
+/// The device memory assumed when the memory of the device can not be detected, i.e. 4 GiB
+pub const DEFAULT_DEVICE_MEMORY:u64=4*1024*1024*1024;
+/// ## Summary
+/// The fraction of the device memory that is used for the batches, the rest is left for the driver and other processes
+pub const DEVICE_MEMORY_FRACTION:f64=0.5;
+
+/// ## Summary
+/// The tunable parameters of the GPU execution engine, i.e. the number of residues that are copied to the device per batch, the number of
+/// streams used to overlap copies and kernel execution, and whether the host buffers are allocated as pinned (page-locked) memory.
+/// The defaults are derived from the memory of the device, see GpuConfig::auto, and each parameter can be overwritten from the command line
+/// or from a config file, see GpuConfig::update_from_str, so the throughput can be adapted to different devices without recompiling.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::gpu_config::GpuConfig;
+/// let mut config=GpuConfig::from_device_memory(16*1024*1024*1024);
+/// assert_eq!(config.get_num_streams(),4);
+/// config.update_from_str("batch_size=1000000\npinned_memory=false\n").unwrap();
+/// assert_eq!(config.get_batch_size(),1_000_000);
+/// assert!(!config.use_pinned_memory());
+///```
+#[derive(Debug,Clone,PartialEq)]
+pub struct GpuConfig
+{
+    batch_size:usize,
+    num_streams:usize,
+    pinned_memory:bool
+}
+impl GpuConfig
+{
+    /// ## Summary
+    /// Create a new config from the provided parameters, returns an error if the batch size or the number of streams is zero
+    pub fn new(batch_size:usize, num_streams:usize, pinned_memory:bool)->Result<Self,String>
+    {
+        if batch_size==0 || num_streams==0
+        {
+            return Err(format!("The GPU batch size and the number of streams must be positive, however, a batch size of: {} and {} streams were provided",
+                batch_size,num_streams))
+        }
+        Ok(GpuConfig{batch_size,num_streams,pinned_memory})
+    }
+    /// ## Summary
+    /// Create the default config for a device with the provided memory in bytes, devices with more memory use more streams and larger batches,
+    /// where the streams share the fraction of the device memory defined by DEVICE_MEMORY_FRACTION
+    pub fn from_device_memory(device_memory:u64)->Self
+    {
+        let gib=1024*1024*1024;
+        let num_streams=match device_memory
+        {
+            mem if mem < 8*gib =>2,
+            mem if mem <= 16*gib =>4,
+            _=>8
+        };
+        let usable_memory=(device_memory as f64*DEVICE_MEMORY_FRACTION) as u64;
+        let batch_size=std::cmp::max(1,usable_memory/(BYTES_PER_RESIDUE*num_streams as u64)) as usize;
+        GpuConfig{batch_size,num_streams,pinned_memory:true}
+    }
+    /// ## Summary
+    /// Create the default config for the first visible device, the memory of the device is queried using nvidia-smi and DEFAULT_DEVICE_MEMORY is
+    /// used if no device could be detected
+    pub fn auto()->Self
+    {
+        GpuConfig::from_device_memory(detect_device_memory().unwrap_or(DEFAULT_DEVICE_MEMORY))
+    }
+    /// ## Summary
+    /// Overwrite the parameters of the config from a string with one key=value pair per line, the supported keys are batch_size, num_streams
+    /// and pinned_memory, where empty lines and lines starting with # are ignored.
+    pub fn update_from_str(&mut self, content:&str)->Result<(),String>
+    {
+        for (line_num,line) in content.lines().enumerate()
+        {
+            let line=line.trim();
+            if line.is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+            let (key,value)=match line.split_once('=')
+            {
+                Some((key,value))=>(key.trim(),value.trim()),
+                None=>return Err(format!("Line {} of the GPU config: {} is not a key=value pair",line_num+1,line))
+            };
+            match key
+            {
+                "batch_size"=>self.set_batch_size(parse_value(key,value)?)?,
+                "num_streams"=>self.set_num_streams(parse_value(key,value)?)?,
+                "pinned_memory"=>self.pinned_memory=parse_value(key,value)?,
+                _=>return Err(format!("Line {} of the GPU config contains an unknown key: {}, supported keys are: batch_size, num_streams and pinned_memory",
+                    line_num+1,key))
+            }
+        }
+        Ok(())
+    }
+    /// ## Summary
+    /// Overwrite the parameters of the config from a config file, see update_from_str for the expected layout
+    pub fn update_from_file(&mut self, path2load:&Path)->Result<(),String>
+    {
+        match fs::read_to_string(path2load)
+        {
+            Ok(content)=>self.update_from_str(&content),
+            Err(err_msg)=>Err(format!("Reading the GPU config: {:?} failed with the following error: {}",path2load,err_msg))
+        }
+    }
+    /// ## Summary
+    /// Return the number of residues that are copied to the device per batch
+    pub fn get_batch_size(&self)->usize
+    {
+        self.batch_size
+    }
+    /// ## Summary
+    /// Return the number of streams used by the engine
+    pub fn get_num_streams(&self)->usize
+    {
+        self.num_streams
+    }
+    /// ## Summary
+    /// Return true if the host buffers are allocated as pinned memory
+    pub fn use_pinned_memory(&self)->bool
+    {
+        self.pinned_memory
+    }
+    /// ## Summary
+    /// Set the number of residues per batch, returns an error if the batch size is zero
+    pub fn set_batch_size(&mut self, batch_size:usize)->Result<(),String>
+    {
+        if batch_size==0
+        {
+            return Err("The GPU batch size must be positive".to_string())
+        }
+        self.batch_size=batch_size;
+        Ok(())
+    }
+    /// ## Summary
+    /// Set the number of streams, returns an error if the number of streams is zero
+    pub fn set_num_streams(&mut self, num_streams:usize)->Result<(),String>
+    {
+        if num_streams==0
+        {
+            return Err("The number of GPU streams must be positive".to_string())
+        }
+        self.num_streams=num_streams;
+        Ok(())
+    }
+    /// ## Summary
+    /// Set whether the host buffers are allocated as pinned memory
+    pub fn set_pinned_memory(&mut self, pinned_memory:bool)
+    {
+        self.pinned_memory=pinned_memory;
+    }
+}
+impl Default for GpuConfig
+{
+    fn default()->Self
+    {
+        GpuConfig::from_device_memory(DEFAULT_DEVICE_MEMORY)
+    }
+}
+/// ## Summary
+/// Parse the value of a config key and return an error message containing the key if parsing failed
+fn parse_value<T:std::str::FromStr>(key:&str, value:&str)->Result<T,String>
+{
+    match value.parse::<T>()
+    {
+        Ok(value)=>Ok(value),
+        Err(_)=>Err(format!("The value: {} of the GPU config key: {} is not valid",value,key))
+    }
+}
+/// ## Summary
+/// Return the total memory of the first visible device in bytes as reported by nvidia-smi, or None if no device could be queried
+#[cfg(feature = "cuda")]
+pub fn detect_device_memory()->Option<u64>
+{
+    let output=Command::new("nvidia-smi")
+        .args(["--query-gpu=memory.total","--format=csv,noheader,nounits"])
+        .output()
+        .ok()?;
+    if !output.status.success()
+    {
+        return None
+    }
+    // the memory is reported in MiB, one line per device
+    let mebibytes=String::from_utf8_lossy(&output.stdout).lines().next()?.trim().parse::<u64>().ok()?;
+    Some(mebibytes*1024*1024)
+}
+/// ## Summary
+/// Builds without the cuda feature do not query any device, hence, the GPU engine is never selected automatically, see engine_selection 
+#[cfg(not(feature = "cuda"))]
+pub fn detect_device_memory()->Option<u64>
+{
+    None
+}
+
+#[cfg(test)]
+mod test_gpu_config
+{
+    use super::*;
+    #[test]
+    fn test_defaults_from_device_memory()
+    {
+        let gib=1024*1024*1024;
+        let small=GpuConfig::from_device_memory(4*gib);
+        assert_eq!(small.get_num_streams(),2);
+        assert_eq!(small.get_batch_size(),(2*gib/(BYTES_PER_RESIDUE*2)) as usize);
+        let large=GpuConfig::from_device_memory(32*gib);
+        assert_eq!(large.get_num_streams(),8);
+        assert!(large.get_batch_size()>small.get_batch_size());
+        assert_eq!(GpuConfig::default(),small);
+    }
+    #[test]
+    fn test_update_from_str()
+    {
+        let mut config=GpuConfig::default();
+        config.update_from_str("# tuned for a V100\nnum_streams = 6\n\nbatch_size=4096\n").unwrap();
+        assert_eq!(config,GpuConfig::new(4096,6,true).unwrap());
+        assert!(config.update_from_str("num_streams=0").is_err());
+        assert!(config.update_from_str("streams=2").is_err());
+        assert!(config.update_from_str("pinned_memory").is_err());
+        assert!(GpuConfig::new(0,2,false).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 // load libraries and caret 
 use std::{collections::HashMap, panic, usize};
 use crate::data_structures::vcf_ds::AltTranscript;
-use super::{engines::Engine, task::Task, transcript_instructions::TranscriptInstruction}; 
+use super::{engines::Engine, task::Task, transcript_instructions::{OverlapPolicy,OverlapSummary,TranscriptInstruction}, variant_feature::VariantFeature}; 
 use rayon::prelude::*; 
 use serde::{Deserialize, Serialize};
 use crate::data_structures::InternalRep::gir::GIR; 
+use crate::data_structures::InternalRep::schedule::inner_min_len; 
+use crate::functions::diagnostics::DiagnosticOptions; 
 
 /// ## Summary
 /// An abstraction for a collection on instruction in the same Haplotype of a proband
@@ -36,46 +38,78 @@ impl HaplotypeInstruction
     /// Generate an instance from a vector of AltTranscript, a reference sequence and an execution engine
     pub fn from_vec_t_ins(alt_trans_vec:Vec<AltTranscript>, engine:Engine, ref_seq:&HashMap<String,String>)->Self
     {
-        match engine
+        HaplotypeInstruction::from_vec_t_ins_with_policies(alt_trans_vec, engine, ref_seq, None, &DiagnosticOptions::default()).0
+    }
+    /// ## Summary 
+    /// Similar to from_vec_t_ins, however, the overlapping mutations of each transcript are resolved with the provided strategy, if any, 
+    /// and checked with the diagnostics otherwise, see TranscriptInstruction::from_alt_transcript_with_policies, and the resolutions summed 
+    /// over the transcripts are returned as well 
+    pub fn from_vec_t_ins_with_policies(alt_trans_vec:Vec<AltTranscript>, engine:Engine, ref_seq:&HashMap<String,String>, 
+        overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions)->(Self,OverlapSummary)
+    {
+        let get_instruction=|alt_transcript:AltTranscript|
         {
-            Engine::ST=>
+            let mut summary=OverlapSummary::default(); 
+            match TranscriptInstruction::from_alt_transcript_with_policies(alt_transcript, ref_seq, overlap_policy, diagnostic_options, &mut summary)
             {
-                let vec_transcriot_ins= alt_trans_vec.into_iter()
-                .map(|alt_transcript| 
-                {
-                    match TranscriptInstruction::from_alt_transcript(alt_transcript, ref_seq)
-                    {
-                        Ok(res)=>res,
-                        Err(_) => TranscriptInstruction::empty_t_instruction()
-                    }
-                })
-                .filter(|elem| *elem.get_transcript_name() != "")
-                .collect::<Vec<_>>();
-                HaplotypeInstruction::new(vec_transcriot_ins)
+                Ok(res)=>(res,summary),
+                Err(_) => (TranscriptInstruction::empty_t_instruction(),summary)
             }
-            Engine::MT | Engine::GPU=>
+        }; 
+        let results=match engine
+        {
+            Engine::ST=>alt_trans_vec.into_iter().map(get_instruction).collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>
             {
-                let vec_transcriot_ins= alt_trans_vec.into_par_iter()
-                .map(|alt_transcript| 
-                    {
-                        match TranscriptInstruction::from_alt_transcript(alt_transcript, ref_seq)
-                        {
-                            Ok(res)=>res,
-                            Err(_) => TranscriptInstruction::empty_t_instruction()
-                        }
-                    })
-                    .filter(|elem| *elem.get_transcript_name() != "")
-                    .collect::<Vec<_>>();
-                HaplotypeInstruction::new(vec_transcriot_ins)
+                let min_len=inner_min_len(alt_trans_vec.len()); 
+                alt_trans_vec.into_par_iter().with_min_len(min_len).map(get_instruction).collect::<Vec<_>>()
             },
-        }
+        }; 
+        let mut summary=OverlapSummary::default(); 
+        let vec_transcriot_ins=results.into_iter()
+            .filter_map(|(elem,transcript_summary)|
+            {
+                summary.merge(&transcript_summary); 
+                Some(elem).filter(|elem| *elem.get_transcript_name() != "")
+            })
+            .collect::<Vec<_>>();
+        (HaplotypeInstruction::new(vec_transcriot_ins),summary)
+    }
+    /// ## Summary
+    /// Return the codes of the instructions of a transcript in the haplotype, e.g. "MF" for a missense and a frameshift, or None if the 
+    /// transcript has no instructions in the haplotype 
+    pub fn get_instruction_codes(&self, transcript_name:&str)->Option<String>
+    {
+        self.instructions.iter()
+            .find(|ins|ins.get_transcript_name()==transcript_name)
+            .map(|ins|ins.get_instructions().iter().map(|instruction|instruction.get_code()).collect::<String>())
+    }
+    /// ## Summary
+    /// Return the instructions of the transcripts in the haplotype 
+    pub fn get_transcript_instructions(&self)->&[TranscriptInstruction]
+    {
+        &self.instructions
+    }
+    /// ## Summary
+    /// Consume the instance and return the instructions of its transcripts
+    pub fn consume_and_get_instructions(self)->Vec<TranscriptInstruction>
+    {
+        self.instructions
+    }
+    /// ## Summary
+    /// Return the number of transcripts with instructions, i.e. the number of sequences the haplotype generates 
+    pub fn get_num_transcripts(&self)->usize
+    {
+        self.instructions.len()
     }
     /// ## Summary
-    /// Generate a G Representation from a ref_seq and an execution engine   
-    pub fn get_g_rep(&mut self,ref_seq:&HashMap<String,String>, engine:Engine)->GIR
+    /// Generate a G Representation from a ref_seq and an execution engine, where the results array is initialized with the provided 
+    /// placeholder residue, see gir::PlaceholderPolicy, and the tasks are checked with the check policy of the diagnostics, 
+    /// see TranscriptInstruction::get_g_rep_with_checks 
+    pub fn get_g_rep(&mut self,ref_seq:&HashMap<String,String>, engine:Engine, placeholder:char, diagnostic_options:&DiagnosticOptions)->GIR
     {
         // Allocate resources 
-        let results_array=vec!['.'; self.get_size_results_array()];
+        let results_array=vec![placeholder; self.get_size_results_array()];
         let mut alt_array=Vec::with_capacity(self.get_size_alt_array()); 
         let mut reference_array=Vec::with_capacity(self.get_size_ref_array(ref_seq));
         let mut annotation=HashMap::new(); 
@@ -83,22 +117,30 @@ impl HaplotypeInstruction
         // Compute the GIRL representation for each transcript 
         let vec_g_rep= match engine
         {
-            Engine::ST=>self.instructions.iter().map(|ins|ins.get_g_rep(ref_seq)).collect::<Vec<_>>(),
-            Engine::MT | Engine::GPU =>self.instructions.par_iter().map(|ins|ins.get_g_rep(ref_seq)).collect::<Vec<_>>(),
+            Engine::ST=>self.instructions.iter().map(|ins|ins.get_g_rep_with_checks(ref_seq,placeholder,diagnostic_options)).collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto =>self.instructions.par_iter().with_min_len(inner_min_len(self.instructions.len())).map(|ins|ins.get_g_rep_with_checks(ref_seq,placeholder,diagnostic_options)).collect::<Vec<_>>(),
         };
         // compute some counter 
         let mut ref_counter=0; let mut alt_counter=0; let mut res_counter=0; 
         let mut len_vec=Vec::with_capacity(1000); 
+        let mut num_instructions=0; 
+        let mut skipped_transcripts=Vec::new(); 
+        let mut skip_reasons=Vec::new(); 
         //println!("**************** Checking the correctness of the re-indexing loop: ");
         // loop-and-reindex 
-        for g_rep_e in vec_g_rep
+        for (g_rep_e,ins) in vec_g_rep.into_iter().zip(self.instructions.iter())
         {
             // consume the resources 
             let res=match g_rep_e
             {
-                Ok(res)=>res.consumer_and_get_resources(),
-                Err(err_msg)=>{println!("While creating instruction for a haplotype, the following error was encountered,{:#?}, skipping this transcript ...\
+                Ok(res)=>
+                {
+                    num_instructions+=res.get_num_instructions(); 
+                    res.consumer_and_get_resources()
+                },
+                Err(err_msg)=>{skipped_transcripts.push(ins.get_transcript_name().clone()); log::warn!("While creating instruction for a haplotype, the following error was encountered,{}, skipping this transcript ... \
                 Please check your input VCF file, otherwise feel free to contact the developer at: h.elabd@ikmb.uni-kiel.de or at the project webpage: https://github.com/ikmb/ppg", err_msg);
+                skip_reasons.push(err_msg); 
                 continue;},
             };
             // re-index and push the tasks 
@@ -133,7 +175,31 @@ impl HaplotypeInstruction
         }
         // return the results 
         //println!("************** Vector of Tasks \n {:#?}",g_rep);
-        GIR::new(g_rep, annotation, alt_array, reference_array, results_array)
+        let mut g_rep=GIR::new(g_rep, annotation, alt_array, reference_array, results_array); 
+        g_rep.set_num_instructions(num_instructions); 
+        g_rep.set_skipped_transcripts(skipped_transcripts); 
+        g_rep.set_skip_reasons(skip_reasons); 
+        g_rep.set_placeholder(placeholder); 
+        g_rep.set_check_policy(diagnostic_options.check_policy); 
+        g_rep
+    }
+    /// ## Summary
+    /// Return the applied variants of all transcripts in the haplotype projected onto the coordinates of the personalized sequences,
+    /// transcripts that can not be translated into tasks are skipped as they are skipped while generating the G representation 
+    pub fn get_variant_features(&self,ref_seq:&HashMap<String,String>, engine:Engine)->Vec<VariantFeature>
+    {
+        match engine
+        {
+            Engine::ST=>self.instructions.iter()
+                        .filter_map(|ins|ins.get_variant_features(ref_seq).ok())
+                        .flatten()
+                        .collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto =>self.instructions.par_iter()
+                        .with_min_len(inner_min_len(self.instructions.len()))
+                        .filter_map(|ins|ins.get_variant_features(ref_seq).ok())
+                        .flatten()
+                        .collect::<Vec<_>>()
+        }
     }
     /// ## Summary
     /// Update the task index by shifting, i.e. adjusting the position of the task indices 
@@ -157,11 +223,12 @@ impl HaplotypeInstruction
         task
     }
     /// ## Summary 
-    /// compute the size of the results array 
+    /// compute the size of the results array, transcripts whose size can not be computed are skipped while generating the G representation 
+    /// and do not take any space 
     fn get_size_results_array(&self)->usize
     {
         self.instructions.iter()
-        .map(|trans_ins|trans_ins.compute_expected_results_array_size())
+        .map(|trans_ins|trans_ins.compute_expected_results_array_size().unwrap_or(0))
         .collect::<Vec<_>>()
         .iter()
         .sum::<usize>()
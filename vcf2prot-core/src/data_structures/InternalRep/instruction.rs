@@ -1,6 +1,8 @@
 // load the modules and crates 
 use crate::data_structures::mutation_ds::*; 
+use crate::data_structures::InternalRep::coordinates::{RefPos0,Len};
 use serde::{Deserialize, Serialize};
+use crate::error::PpggError;
 
 /// A simple for an instruction
 #[derive(Debug,Clone,Serialize,Deserialize,PartialEq)]
@@ -8,9 +10,9 @@ pub struct Instruction
 {
     code:char,
     s_state:bool,
-    pos_ref:usize,
-    pos_res:usize,
-    len:usize,
+    pos_ref:RefPos0,
+    pos_res:RefPos0,
+    len:Len,
     data:Vec<char>
 }
 impl Instruction
@@ -39,42 +41,46 @@ impl Instruction
     /// stop_lost&frameshift ->             W
     /// missense&inframe_altering ->        Y
     /// start_lost&splice_region ->         U
+    /// start_reselection ->                S
+    /// stop_reselection ->                 V
     /// ```rust
     /// // load the modules 
     /// use ppgg_rust::data_structures::InternalRep::instruction::Instruction; 
+    /// use ppgg_rust::data_structures::InternalRep::coordinates::{RefPos0,Len}; 
     /// // define some example data 
     /// let code = 'M'; 
     /// let s_state = false; 
-    /// let pos_ref= 5 as usize; 
-    /// let pos_res= 5 as usize; 
-    /// let len = 1 as usize;
+    /// let pos_ref= RefPos0::new(5); 
+    /// let pos_res= RefPos0::new(5); 
+    /// let len = Len::new(1);
     /// let data= vec!['K']; 
     /// // create an instance 
     /// instance = Instruction::new(code,s_state,pos_ref,pos_res,len,data);  
     /// // print it out 
     /// println!("The instance data are: {}", instance); 
     /// ```
-    pub fn new(code:char, s_state:bool, pos_ref:usize, pos_res:usize, len:usize, data:Vec<char>)->Self
+    pub fn new(code:char, s_state:bool, pos_ref:RefPos0, pos_res:RefPos0, len:Len, data:Vec<char>)->Self
     {
         Instruction{code, s_state, pos_ref, pos_res, len, data}
     }
     /// ## Summary 
     /// this is going to be the main translator of the language, it takes as input the mutation type 
-    /// an returns an instruction Representing the interpreted code 
-    pub fn from_mutation(mutation:&Mutation, vec_mut:&Vec<Mutation>)->Self
+    /// an returns an instruction Representing the interpreted code, an error is returned if the amino acids of the mutation can not be 
+    /// interpreted, e.g. a missense or a stop-lost without alternative amino acids 
+    pub fn from_mutation(mutation:&Mutation, vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
-        match &mutation.mut_type
+        let instruction=match &mutation.mut_type
         {
-            MutationType::MisSense=>Instruction::interpret_missense(mutation,vec_mut), 
-            MutationType::SMisSense=>Instruction::interpret_s_missense(mutation,vec_mut),
+            MutationType::MisSense=>Instruction::interpret_missense(mutation,vec_mut)?, 
+            MutationType::SMisSense=>Instruction::interpret_s_missense(mutation,vec_mut)?,
             MutationType::FrameShift=>Instruction::interpret_frameshift(mutation,vec_mut),
             MutationType::SFrameShift=>Instruction::interpret_s_frameshift(mutation,vec_mut),
-            MutationType::InframeInsertion=>Instruction::interpret_inframe_insertion(mutation,vec_mut),
-            MutationType::SInframeInsertion=>Instruction::interpret_s_inframe_insertion(mutation,vec_mut),
-            MutationType::InframeDeletion=>Instruction::interpret_inframe_deletion(mutation, vec_mut),
-            MutationType::SInframeDeletion=>Instruction::interpret_s_inframe_deletion(mutation, vec_mut),
+            MutationType::InframeInsertion=>Instruction::interpret_inframe_insertion(mutation,vec_mut)?,
+            MutationType::SInframeInsertion=>Instruction::interpret_s_inframe_insertion(mutation,vec_mut)?,
+            MutationType::InframeDeletion=>Instruction::interpret_inframe_deletion(mutation, vec_mut)?,
+            MutationType::SInframeDeletion=>Instruction::interpret_s_inframe_deletion(mutation, vec_mut)?,
             MutationType::StartLost=>Instruction::interpret_start_lost(mutation,vec_mut),
-            MutationType::StopLost=>Instruction::interpret_stop_lost(mutation,vec_mut),
+            MutationType::StopLost=>Instruction::interpret_stop_lost(mutation,vec_mut)?,
             MutationType::StopGained=>Instruction::interpret_stop_gained(mutation,vec_mut),
             MutationType::SStopGained=>Instruction::interpret_s_stop_gained(mutation,vec_mut), 
             MutationType::SMisSenseAndInframeAltering=>Instruction::interpret_s_missense_and_inframe_altering(mutation,vec_mut), 
@@ -84,10 +90,13 @@ impl Instruction
             MutationType::InframeDeletionAndStopRetained=>Instruction::interpret_inframe_deletion_and_stop_retained(mutation,vec_mut),
             MutationType::InframeInsertionAndStopRetained=>Instruction::interpret_inframe_insertion_and_stop_retained(mutation),
             MutationType::StopGainedAndInframeAltering=>Instruction::interpret_stop_gained_and_inframe_altering(mutation,vec_mut),
-            MutationType::StopLostAndFrameShift=>Instruction::interpret_stop_lost_and_frameshift(mutation,vec_mut), 
-            MutationType::MissenseAndInframeAltering=>Instruction::interpret_missense_and_inframe_altering(mutation,vec_mut),
+            MutationType::StopLostAndFrameShift=>Instruction::interpret_stop_lost_and_frameshift(mutation,vec_mut)?, 
+            MutationType::MissenseAndInframeAltering=>Instruction::interpret_missense_and_inframe_altering(mutation,vec_mut)?,
             MutationType::StartLostAndSpliceRegion=>Instruction::interpret_start_lost_and_splice_region(mutation,vec_mut),        
-        }
+            MutationType::StartReselection=>Instruction::interpret_start_reselection(mutation,vec_mut)?,
+            MutationType::StopReselection=>Instruction::interpret_stop_reselection(mutation,vec_mut)?,
+        };
+        Ok(instruction)
     }
     /// ## Summary 
     /// return the code of the instruction
@@ -97,19 +106,19 @@ impl Instruction
     }
     /// ## Summary 
     /// return the position of the instruction in the reference code 
-    pub fn get_position_ref(&self)->usize
+    pub fn get_position_ref(&self)->RefPos0
     {
         self.pos_ref
     }
     /// ## Summary 
     /// return the position of the instruction in the result code 
-    pub fn get_position_res(&self)->usize
+    pub fn get_position_res(&self)->RefPos0
     {
         self.pos_res
     }
     /// ## Summary 
     /// return the length of the instruction
-    pub fn get_length(&self)->usize
+    pub fn get_length(&self)->Len
     {
         self.len
     }
@@ -138,8 +147,8 @@ impl Instruction
         self.s_state=s_state; 
     }
     /// ## Summary 
-    /// Update the starting position of the instruction, takes a new usize and update the instruction start position
-    pub fn update_start_pos(&mut self, start_pos:usize)
+    /// Update the starting position of the instruction, takes a new zero-based position and update the instruction start position
+    pub fn update_start_pos(&mut self, start_pos:RefPos0)
     {
         self.pos_res=start_pos; 
     }
@@ -148,9 +157,9 @@ impl Instruction
     fn generate_phi_instruction()->Self
     {
         let code='E'; 
-        let len=0;
-        let pos_ref=0;
-        let pos_res=0;
+        let len=Len::zero();
+        let pos_ref=RefPos0::start();
+        let pos_res=RefPos0::start();
         let data=Vec::new(); 
         let s_state=false;
         Instruction{code, s_state, pos_ref, pos_res, len, data}
@@ -180,12 +189,12 @@ impl Instruction
     /// assert_eq!(ins.get_data().len(),1); 
     /// assert_eq!(ins.get_data()[0],'R'); 
     /// ```
-    fn interpret_missense(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Self
+    fn interpret_missense(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {   
         let code='M'; 
         //println!("Mutation is: {:?}",&mutation);
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
         let data= match &mutation.mut_info.mut_aa
         {
             MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -195,11 +204,11 @@ impl Instruction
                 data.remove(data.len()-1);   
                 data
             }
-            MutatedString::NotSeq =>panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+            MutatedString::NotSeq =>return Err(Instruction::uninterpretable(mutation))
         }; 
-        let len=1;
+        let len=Len::new(1);
         let s_state=false;
-        Instruction{code, s_state, pos_ref, pos_res, len, data}
+        Ok(Instruction{code, s_state, pos_ref, pos_res, len, data})
     }
     /// ## Summary 
     /// Generate an instruction from an asterisk missense mutation, i.e. *missense 
@@ -219,20 +228,20 @@ impl Instruction
     /// let ins=Instruction::interpret_s_missense(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_s_missense(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Self
+    fn interpret_s_missense(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         match Instruction::validate_s_state(mutation,vec_mut)
         {
             true=>
             {
-                let mut n_inst=Instruction::interpret_missense(mutation,vec_mut);
+                let mut n_inst=Instruction::interpret_missense(mutation,vec_mut)?;
                 n_inst.update_code('N'); 
                 n_inst.update_s_state(true); 
-                n_inst
+                Ok(n_inst)
             },
             false=>
             {
-                Instruction::generate_phi_instruction()
+                Ok(Instruction::generate_phi_instruction())
             }
         }
     }
@@ -254,11 +263,11 @@ impl Instruction
     /// let ins=Instruction::interpret_inframe_insertion(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_inframe_insertion(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Self
+    fn interpret_inframe_insertion(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         let code='I'; 
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
         // check the correctness of the reference sequence 
         match &mutation.mut_info.ref_aa
         {
@@ -268,8 +277,8 @@ impl Instruction
                 if mut_str.len() != 1 
                 {
                     let code='2';
-                    let pos_res=mutation.mut_info.ref_aa_position as usize;
-                    let pos_ref=mutation.mut_info.mut_aa_position as usize; 
+                    let pos_res=mutation.mut_info.get_ref_pos();
+                    let pos_ref=mutation.mut_info.get_mut_pos(); 
                     let data= match &mutation.mut_info.mut_aa
                     {
                         MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -279,7 +288,7 @@ impl Instruction
                             data.remove(data.len()-1);   
                             data
                         }
-                        MutatedString::NotSeq => return Instruction::interpret_stop_gained(mutation,_vec_mut)
+                        MutatedString::NotSeq => return Ok(Instruction::interpret_stop_gained(mutation,_vec_mut))
                     };
                     let ref_seq=match &mutation.mut_info.ref_aa
                     {
@@ -295,36 +304,35 @@ impl Instruction
                     if data.len()!=ref_seq.len()
                     {
                         let code='3';
-                        let pos_res=mutation.mut_info.ref_aa_position as usize;
-                        let pos_ref=mutation.mut_info.mut_aa_position as usize; 
-                        let len=ref_seq.len(); 
+                        let pos_res=mutation.mut_info.get_ref_pos();
+                        let pos_ref=mutation.mut_info.get_mut_pos(); 
+                        let len=Len::new(ref_seq.len()); 
                         let s_state=false; 
-                        return Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                        return Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data)) 
                     }
-                    let len=data.len(); 
+                    let len=Len::new(data.len()); 
                     let s_state=false;
-                    return Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                    return Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data)) 
                 } // this is an 2 instruction                  
             }
             MutatedString::EndSequence(_)=>
             {
-                return Instruction::interpret_frameshift(mutation, _vec_mut); // interpret the mutation as a frame shift 
+                return Ok(Instruction::interpret_frameshift(mutation, _vec_mut)); // interpret the mutation as a frame shift 
             },
-            MutatedString::NotSeq =>panic!("In interpreting an inframe insertion the reference amino acids was just an asterisk,\
-             please check your input and contact the developer at h.elabd@ikmb.uni-kiel.de or at the project webpage: https://github.com/ikmb/ppg")
+            MutatedString::NotSeq =>return Err(Instruction::uninterpretable(mutation))
         };
         let data= match &mutation.mut_info.mut_aa
         {
             MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
             MutatedString::EndSequence(_)=>
             {
-                return Instruction::interpret_frameshift(mutation, _vec_mut)
+                return Ok(Instruction::interpret_frameshift(mutation, _vec_mut))
             }
-            MutatedString::NotSeq =>return Instruction::interpret_stop_gained(mutation, _vec_mut)
+            MutatedString::NotSeq =>return Ok(Instruction::interpret_stop_gained(mutation, _vec_mut))
         }; 
-        let len=data.len();
+        let len=Len::new(data.len());
         let s_state=false;
-        Instruction{code, s_state, pos_ref,pos_res,len, data}
+        Ok(Instruction{code, s_state, pos_ref,pos_res,len, data})
     }
     // ## Summary 
     /// generate an instruction from a asterisk inframe insertion, i.e. *inframe_insertion 
@@ -344,27 +352,27 @@ impl Instruction
     /// let ins=Instruction::interpret_s_inframe_insertion(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_s_inframe_insertion(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Self
+    fn interpret_s_inframe_insertion(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         match Instruction::validate_s_state(mutation,vec_mut)
         {
             true=>
             {
-                let mut n_inst=Instruction::interpret_inframe_insertion(mutation,vec_mut);
+                let mut n_inst=Instruction::interpret_inframe_insertion(mutation,vec_mut)?;
                 match n_inst.get_code()
                 {
                     'I'=>
                     {
                         n_inst.update_code('J'); 
                         n_inst.update_s_state(true); 
-                        n_inst
+                        Ok(n_inst)
                     },
-                    _=>n_inst
+                    _=>Ok(n_inst)
                 }    
             }
             false=>
             {
-                Instruction::generate_phi_instruction()
+                Ok(Instruction::generate_phi_instruction())
             }
         }
     }
@@ -386,12 +394,12 @@ impl Instruction
     /// let ins=Instruction::interpret_inframe_deletion(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_inframe_deletion(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Self
+    fn interpret_inframe_deletion(mutation:&Mutation,_vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         
         let code='D'; 
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
         let len = match &mutation.mut_info.ref_aa
         {
             MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>().len(),
@@ -401,7 +409,7 @@ impl Instruction
                 data.remove(data.len()-1);   
                 data.len()
             }
-            MutatedString::NotSeq => return Instruction::interpret_stop_gained(mutation, _vec_mut)
+            MutatedString::NotSeq => return Ok(Instruction::interpret_stop_gained(mutation, _vec_mut))
         }; 
         let data=match &mutation.mut_info.mut_aa
         {
@@ -415,8 +423,8 @@ impl Instruction
                 else 
                 {
                     let code='2';
-                    let pos_res=mutation.mut_info.ref_aa_position as usize;
-                    let pos_ref=mutation.mut_info.mut_aa_position as usize; 
+                    let pos_res=mutation.mut_info.get_ref_pos();
+                    let pos_ref=mutation.mut_info.get_mut_pos(); 
                     let data= match &mutation.mut_info.mut_aa
                     {
                         MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -426,7 +434,7 @@ impl Instruction
                             data.remove(data.len()-1);   
                             data
                         }
-                        MutatedString::NotSeq => panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+                        MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
                     };
                     let ref_seq=match &mutation.mut_info.ref_aa
                     {
@@ -437,20 +445,20 @@ impl Instruction
                         data.remove(data.len()-1);   
                         data
                     }
-                    MutatedString::NotSeq => panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+                    MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
                     };
                     if data.len()!=ref_seq.len()
                     {
                         let code='3';
-                        let pos_res=mutation.mut_info.ref_aa_position as usize;
-                        let pos_ref=mutation.mut_info.mut_aa_position as usize; 
-                        let len=ref_seq.len(); 
+                        let pos_res=mutation.mut_info.get_ref_pos();
+                        let pos_ref=mutation.mut_info.get_mut_pos(); 
+                        let len=Len::new(ref_seq.len()); 
                         let s_state=false; 
-                        return Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                        return Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data)) 
                     }
-                    let len=data.len(); 
+                    let len=Len::new(data.len()); 
                     let s_state=false;
-                    return Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                    return Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data)) 
                 } // this is an 2 instruction  
             },
             MutatedString::EndSequence(seq_str)=>
@@ -463,14 +471,14 @@ impl Instruction
                 }
                 else
                 {
-                    return Instruction::interpret_frameshift(mutation, _vec_mut); // interpret the mutation as a frame shift 
+                    return Ok(Instruction::interpret_frameshift(mutation, _vec_mut)); // interpret the mutation as a frame shift 
                 }
             }
-            MutatedString::NotSeq => return Instruction::interpret_stop_gained(mutation, _vec_mut)
+            MutatedString::NotSeq => return Ok(Instruction::interpret_stop_gained(mutation, _vec_mut))
         }; 
         // the length of deletion is 1.
         let s_state=false;
-        Instruction::new(code, s_state, pos_ref, pos_res, len - data.len(), data)
+        Ok(Instruction::new(code, s_state, pos_ref, pos_res, Len::new(len) - Len::new(data.len()), data))
     }
     // ## Summary 
     /// generates an instruction from an asterisk inframe deletion, i.e. *inframe_deletion
@@ -490,20 +498,20 @@ impl Instruction
     /// let ins=Instruction::interpret_s_inframe_insertion(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_s_inframe_deletion(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Self
+    fn interpret_s_inframe_deletion(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         match Instruction::validate_s_state(mutation,vec_mut)
         {
             true=>
             {
-                let mut n_inst=Instruction::interpret_inframe_deletion(mutation,vec_mut);
+                let mut n_inst=Instruction::interpret_inframe_deletion(mutation,vec_mut)?;
                 n_inst.update_code('C'); 
                 n_inst.update_s_state(true); 
-                n_inst
+                Ok(n_inst)
             },
             false=>
             {
-                Instruction::generate_phi_instruction()
+                Ok(Instruction::generate_phi_instruction())
             }
         }
     }
@@ -528,8 +536,8 @@ impl Instruction
     fn interpret_frameshift(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Self
     {
         let code='F'; 
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
         let data= match &mutation.mut_info.mut_aa
         {
             MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -541,7 +549,7 @@ impl Instruction
             }
             MutatedString::NotSeq => return Instruction::generate_phi_instruction()
         }; 
-        let len=data.len();// Because we have the first amino acid in the mutated sequences already, for example, 115SL>115S
+        let len=Len::new(data.len());// Because we have the first amino acid in the mutated sequences already, for example, 115SL>115S
         // the length of deletion is 1.
         let s_state=false;
         Instruction{code, s_state, pos_ref, pos_res, len, data}
@@ -609,9 +617,9 @@ impl Instruction
     fn interpret_stop_gained(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Self
     {
         let code='G'; 
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
-        let len=0;
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
+        let len=Len::zero();
         let data=Vec::new(); 
         let s_state=false;
         Instruction{code, s_state, pos_ref, pos_res, len, data}
@@ -669,11 +677,11 @@ impl Instruction
     /// let ins=Instruction::interpret_stop_lost(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```
-    fn interpret_stop_lost(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Self
+    fn interpret_stop_lost(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         let code='L'; 
-        let pos_ref=mutation.mut_info.ref_aa_position as usize; // the position of the reference 
-        let pos_res=mutation.mut_info.mut_aa_position as usize; // the position of the result 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); // the position of the result 
         let data= match &mutation.mut_info.mut_aa
         {
             MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -683,11 +691,11 @@ impl Instruction
                 data.remove(data.len()-1);   
                 data
             }
-            MutatedString::NotSeq => panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+            MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
         }; 
-        let len=data.len();
+        let len=Len::new(data.len());
         let s_state=false;
-        Instruction{code, s_state, pos_ref, pos_res, len, data}
+        Ok(Instruction{code, s_state, pos_ref, pos_res, len, data})
     }
     // ## Summary 
     /// generates an instruction from a start_lost mutation or alteration
@@ -710,9 +718,9 @@ impl Instruction
     fn interpret_start_lost(_mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Self
     {
         let code='0'; 
-        let len=0;
-        let pos_ref=0;
-        let pos_res=0;
+        let len=Len::zero();
+        let pos_ref=RefPos0::start();
+        let pos_res=RefPos0::start();
         let data=Vec::new(); 
         let s_state=false;
         Instruction{code, s_state, pos_ref, pos_res, len, data}
@@ -773,10 +781,10 @@ impl Instruction
                 {
                     true=>
                     {
-                        let pos_ref=mutation.mut_info.ref_aa_position as usize; 
-                        let pos_res=mutation.mut_info.mut_aa_position as usize; 
+                        let pos_ref=mutation.mut_info.get_ref_pos(); 
+                        let pos_res=mutation.mut_info.get_mut_pos(); 
                         let code='Q'; 
-                        let len=0; 
+                        let len=Len::zero(); 
                         let data:Vec<char>=Vec::new(); 
                         let s_state=true;
                         return Instruction::new(code, s_state, pos_ref, pos_res, len, data)
@@ -879,7 +887,7 @@ impl Instruction
                 {
                     MutatedString::EndSequence(seq)=>
                     {
-                        n_inst.len=seq.len()-1 as usize;
+                        n_inst.len=Len::new(seq.len()) - Len::new(1);
                     },
                     _=>()
                 }
@@ -914,7 +922,7 @@ impl Instruction
             _=>
             {
                 n_inst.update_code('Z');
-                n_inst.update_start_pos(mutation.mut_info.mut_aa_position as usize);
+                n_inst.update_start_pos(mutation.mut_info.get_mut_pos());
                 n_inst
             }
         }
@@ -964,12 +972,12 @@ impl Instruction
     /// let ins=Instruction::interpret_stop_lost_and_frameshift(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```  
-    fn interpret_stop_lost_and_frameshift(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Self
+    fn interpret_stop_lost_and_frameshift(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         match &mutation.mut_info.ref_aa
         {
             MutatedString::NotSeq=>Instruction::interpret_stop_lost(mutation,vec_mut),
-            _=>Instruction::interpret_frameshift(mutation,vec_mut)   
+            _=>Ok(Instruction::interpret_frameshift(mutation,vec_mut))
         }
     }
     // ## Summary 
@@ -990,7 +998,7 @@ impl Instruction
     /// let ins=Instruction::interpret_missense_and_inframe_altering(&test_mutation, &vec_mut); 
     /// println!("The instruction is: {:#?}", ins); 
     /// ```  
-    fn interpret_missense_and_inframe_altering(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Self
+    fn interpret_missense_and_inframe_altering(mutation:&Mutation,vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
     {
         match mutation.mut_info.mut_aa
         {
@@ -999,15 +1007,15 @@ impl Instruction
                 let mut n_inst=Instruction::interpret_frameshift(mutation,vec_mut);
                 match n_inst.get_code()
                 {
-                    'E'=>n_inst,
-                    _=>{n_inst.update_code('Y'); n_inst}
+                    'E'=>Ok(n_inst),
+                    _=>{n_inst.update_code('Y'); Ok(n_inst)}
                 }
             },
             _=>
             {
                 let code='2';
-                let pos_res=mutation.mut_info.ref_aa_position as usize;
-                let pos_ref=mutation.mut_info.mut_aa_position as usize; 
+                let pos_res=mutation.mut_info.get_ref_pos();
+                let pos_ref=mutation.mut_info.get_mut_pos(); 
                 let data= match &mutation.mut_info.mut_aa
                 {
                     MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
@@ -1017,7 +1025,7 @@ impl Instruction
                         data.remove(data.len()-1);   
                         data
                     }
-                    MutatedString::NotSeq => panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+                    MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
                 };
                 let ref_seq=match &mutation.mut_info.ref_aa
                 {
@@ -1028,20 +1036,20 @@ impl Instruction
                         data.remove(data.len()-1);   
                         data
                     }
-                    MutatedString::NotSeq => panic!("Something went wrong, interpreting: {:#?}, failed",&mutation)
+                    MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
                 };
                 if data.len()!=ref_seq.len()
                 {
                     let code='3';
-                    let pos_res=mutation.mut_info.ref_aa_position as usize;
-                    let pos_ref=mutation.mut_info.mut_aa_position as usize; 
-                    let len=ref_seq.len(); 
+                    let pos_res=mutation.mut_info.get_ref_pos();
+                    let pos_ref=mutation.mut_info.get_mut_pos(); 
+                    let len=Len::new(ref_seq.len()); 
                     let s_state=false; 
-                    return Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                    return Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data)) 
                 }
-                let len=data.len(); 
+                let len=Len::new(data.len()); 
                 let s_state=false;
-                Instruction::new(code, s_state, pos_ref, pos_res, len, data) 
+                Ok(Instruction::new(code, s_state, pos_ref, pos_res, len, data))
             }
         }
     } 
@@ -1070,6 +1078,80 @@ impl Instruction
         n_inst
     }
     // ## Summary 
+    /// generates an instruction from a start_reselection mutation, i.e. the protein starts at a downstream residue of the reference, 
+    /// e.g. 4M>1M, the residues preceding the new start are trimmed and the new start is replaced by the mutated residues, which can 
+    /// also prepend residues to the new start, e.g. 1M>1MKLM for an upstream start. An error is returned if the mutated residues 
+    /// are empty or contain a stop codon 
+    /// ## Example 
+    /// ```rust
+    /// // load instruction and mutation into scope
+    /// use ppgg::data_structures::InternalRep::instruction::Instruction; 
+    /// use ppgg::data_structures::mutation_ds::Mutation; 
+    /// // create a mutation instance  
+    /// let test_case=vec!["start_reselection".to_string(),"ENST00000275358".to_string(), "4M>1M".to_string()];
+    /// let test_mutation = Mutation::new(Ok(test_case)).unwrap();
+    /// // create an instruction, which is interpreted by from_mutation according to the type of the mutation 
+    /// let ins=Instruction::from_mutation(&test_mutation, &vec![test_mutation.clone()]).unwrap(); 
+    /// assert_eq!(ins.get_code(),'S'); 
+    /// ``` 
+    fn interpret_start_reselection(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
+    {
+        let code='S'; 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the new start in the reference 
+        let pos_res=RefPos0::start(); // the new start is the first residue of the result 
+        let data= match &mutation.mut_info.mut_aa
+        {
+            MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
+            MutatedString::EndSequence(_) | MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
+        }; 
+        let len=Len::new(1);
+        let s_state=false;
+        Ok(Instruction{code, s_state, pos_ref, pos_res, len, data})
+    }
+    // ## Summary 
+    /// generates an instruction from a stop_reselection mutation, i.e. the stop codon of the reference is read through until a 
+    /// downstream stop codon, e.g. 120*>120KLQ*, where the residues preceding the new stop codon are appended after the last residue 
+    /// of the reference. The mutation must start at the stop codon of the reference, which is checked by TranscriptInstruction::from_alt_transcript, 
+    /// and an error is returned if it does not append any residue 
+    /// ## Example 
+    /// ```rust
+    /// // load instruction and mutation into scope
+    /// use ppgg::data_structures::InternalRep::instruction::Instruction; 
+    /// use ppgg::data_structures::mutation_ds::Mutation; 
+    /// // create a mutation instance  
+    /// let test_case=vec!["stop_reselection".to_string(),"ENST00000275358".to_string(), "120*>120KLQ*".to_string()];
+    /// let test_mutation = Mutation::new(Ok(test_case)).unwrap();
+    /// // create an instruction, which is interpreted by from_mutation according to the type of the mutation 
+    /// let ins=Instruction::from_mutation(&test_mutation, &vec![test_mutation.clone()]).unwrap(); 
+    /// assert_eq!(ins.get_data(),vec!['K','L','Q']); 
+    /// ``` 
+    fn interpret_stop_reselection(mutation:&Mutation, _vec_mut:&Vec<Mutation>)->Result<Self,PpggError>
+    {
+        let code='V'; 
+        let pos_ref=mutation.mut_info.get_ref_pos(); // the position of the stop codon in the reference 
+        let pos_res=mutation.mut_info.get_mut_pos(); 
+        let data= match &mutation.mut_info.mut_aa
+        {
+            MutatedString::Sequence(seq_str)=>seq_str.chars().collect::<Vec<char>>(),
+            MutatedString::EndSequence(seq_str)=>seq_str.chars().take_while(|residue|*residue!='*').collect::<Vec<char>>(),
+            MutatedString::NotSeq => return Err(Instruction::uninterpretable(mutation))
+        }; 
+        if data.is_empty()
+        {
+            return Err(Instruction::uninterpretable(mutation))
+        }
+        let len=Len::new(data.len());
+        let s_state=false;
+        Ok(Instruction{code, s_state, pos_ref, pos_res, len, data})
+    }
+    /// ## Summary 
+    /// Return the error of a mutation whose amino acids can not be interpreted as an instruction 
+    fn uninterpretable(mutation:&Mutation)->PpggError
+    {
+        PpggError::UninterpretableMutation{transcript:mutation.transcript_name.to_string(),
+            mutation:format!("{:?} {:?}",mutation.mut_type,mutation.mut_info)}
+    }
+    // ## Summary 
     /// validate an input asterisk-based instruction, true if the provided instruction is not precedent by  
     /// a frameshift, stop_gained or *stop_gained or an inframe_insertion and deletion with * as a mutated amino acid
     fn validate_s_state(mutation:&Mutation,vec_mut:&Vec<Mutation>)->bool
@@ -1106,7 +1188,7 @@ pub mod test_instructions
     {
         let test_case=vec!["missense".to_string(),"ENST00000484547".to_string(), "32Q>32R".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=Instruction::interpret_missense(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_missense(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'M'); 
         assert_eq!(ins.get_s_state(),false); 
         assert_eq!(ins.get_position_ref(),31); 
@@ -1120,7 +1202,7 @@ pub mod test_instructions
     {
         let test_case=vec!["*missense".to_string(),"ENST00000484547".to_string(), "32Q>32R".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=Instruction::interpret_s_missense(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_s_missense(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'N'); 
         assert_eq!(ins.get_s_state(),true); 
         assert_eq!(ins.get_position_ref(),31); 
@@ -1135,7 +1217,7 @@ pub mod test_instructions
     {
         let test_case=vec!["*missense".to_string(),"ENST00000484547".to_string(), "32Q>32*".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=Instruction::interpret_s_missense(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_s_missense(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'N'); 
     }
     #[test]
@@ -1144,7 +1226,7 @@ pub mod test_instructions
     {
         let test_case=vec!["*missense".to_string(),"ENST00000484547".to_string(), "3200>32M".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=Instruction::interpret_missense(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_missense(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'M'); 
     }
     #[test]
@@ -1154,7 +1236,7 @@ pub mod test_instructions
         let test_case=vec!["inframe_insertion".to_string(),"ENST00000484547".to_string(), "125Y>125YRR".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);
-        let ins=Instruction::interpret_inframe_insertion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_inframe_insertion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'I'); 
         assert_eq!(ins.get_s_state(),false); 
         assert_eq!(ins.get_position_ref(),124); 
@@ -1172,7 +1254,7 @@ pub mod test_instructions
         let test_case=vec!["*inframe_insertion".to_string(),"ENST00000484547".to_string(), "125Y>125YRR".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);
-        let ins=Instruction::interpret_s_inframe_insertion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_s_inframe_insertion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         assert_eq!(ins.get_code(),'J'); 
         assert_eq!(ins.get_s_state(),true); 
         assert_eq!(ins.get_position_ref(),124); 
@@ -1189,7 +1271,7 @@ pub mod test_instructions
         let test_case=vec!["inframe_deletion".to_string(),"ENST00000506382".to_string(), "115SL>115S".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);  
-        let ins=Instruction::interpret_inframe_deletion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_inframe_deletion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         println!("{:#?}",&ins);  
         assert_eq!(ins.get_code(),'D'); 
         assert_eq!(ins.get_s_state(),false); 
@@ -1204,7 +1286,7 @@ pub mod test_instructions
         let test_case=vec!["*inframe_deletion".to_string(),"ENST00000506382".to_string(), "115SL>115S".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);  
-        let ins=Instruction::interpret_s_inframe_deletion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/);
+        let ins=Instruction::interpret_s_inframe_deletion(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap();
         println!("{:#?}",&ins); 
         assert_eq!(ins.get_code(),'C'); 
         assert_eq!(ins.get_s_state(),true); 
@@ -1264,7 +1346,7 @@ pub mod test_instructions
         let test_case=vec!["stop_lost".to_string(),"ENST00000650310".to_string(), "489*>489S".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);  
-        let ins=Instruction::interpret_stop_lost(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_stop_lost(&test_mutation, &vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         println!("{:#?}",&ins); 
         assert_eq!(ins.get_code(),'L'); 
         assert_eq!(ins.get_s_state(),false); 
@@ -1411,7 +1493,7 @@ pub mod test_instructions
         let test_case=vec!["stop_lost&frameshift".to_string(),"ENST00000398786".to_string(), "134*>134N".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         println!("{:#?}",&test_mutation);  
-        let ins=Instruction::interpret_stop_lost_and_frameshift(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/); 
+        let ins=Instruction::interpret_stop_lost_and_frameshift(&test_mutation,&vec![test_mutation.clone()]/* A toy example for testing the code*/).unwrap(); 
         println!("{:#?}",&ins); 
         assert_eq!(ins.get_code(),'W'); 
         assert_eq!(ins.get_s_state(),false); 
@@ -0,0 +1,55 @@
+/// The sub-modules contain functions for generating and manipulating SIR representations
+/// 1. instruction ==> for the encoding of a mutation into an instruction 
+/// 2. transcript_instructions ==> For a collection of instructions observed in a transcript 
+/// 3. haplotype_instruction ==> For a collection of mutated transcripts
+/// 4. proband_instructions ==> For a collection of two haplotypes representing all the alterations in a transcript 
+/// 5. sequence_tape ==> Write the generated sequence into a fasta file 
+/// 6. personalized_genome ==> A wrapper for two sequence-tapes used to represent the alteration in a transcript 
+/// 7. task ==> a representation for generation a sequence 
+/// 8. gir ==> a representation for generating tasks
+/// 9. coordinates ==> typed zero-based, one-based positions and lengths used for building instructions and tasks
+/// 10. variant_feature ==> applied variants projected onto the coordinates of the personalized sequences
+/// 11. schedule ==> an adaptive two-level schedule over the samples and the transcripts of each sample
+/// 12. consensus ==> rules for collapsing the two haplotypes of a transcript into a single consensus sequence
+/// 13. gpu_config ==> the tunable parameters of the GPU engine, i.e. batch sizes, stream counts and pinned memory usage
+/// 14. cohort_aggregate ==> cohort-level unique personalized sequences along with their number of carriers
+/// 15. transcript_trace ==> a step by step report of the mutations, instructions, tasks and sequences derived for a single transcript
+/// 16. engine_selection ==> an automatic selection of the engine and its batch sizes from the input size and the available resources
+/// 17. sequence_pool ==> unique personalized sequences of a cohort along with the probands and haplotypes carrying them
+/// 18. validation ==> a validation pass over the tasks of every transcript that reports and excludes the transcripts violating their invariants
+#[cfg(feature = "engine")]
+pub mod instruction; 
+#[cfg(feature = "engine")]
+pub mod transcript_instructions;
+#[cfg(feature = "engine")]
+pub mod haplotype_instruction;  
+#[cfg(feature = "engine")]
+pub mod proband_instructions; 
+#[cfg(feature = "engine")]
+pub mod sequence_tape; 
+#[cfg(feature = "engine")]
+pub mod personalized_genome; 
+#[cfg(feature = "engine")]
+pub mod task; 
+pub mod engines;
+#[cfg(feature = "engine")]
+pub mod gir; 
+pub mod coordinates;
+#[cfg(feature = "engine")]
+pub mod variant_feature;
+#[cfg(feature = "engine")]
+pub mod schedule;
+#[cfg(feature = "engine")]
+pub mod consensus;
+#[cfg(feature = "engine")]
+pub mod gpu_config;
+#[cfg(feature = "writers")]
+pub mod cohort_aggregate;
+#[cfg(feature = "writers")]
+pub mod sequence_pool;
+#[cfg(feature = "engine")]
+pub mod transcript_trace;
+#[cfg(feature = "engine")]
+pub mod engine_selection;
+#[cfg(feature = "engine")]
+pub mod validation;
@@ -0,0 +1,1332 @@
+use std::collections::HashMap;
+use std::collections::HashSet; 
+#[cfg(feature = "writers")]
+use std::collections::BinaryHeap; 
+#[cfg(feature = "writers")]
+use std::cmp::Reverse;
+#[cfg(feature = "writers")]
+use std::io::Write;
+#[cfg(feature = "writers")]
+use std::path::Path; 
+#[cfg(feature = "writers")]
+use std::sync::atomic::AtomicU64; 
+use std::str::FromStr;
+use super::engines::Engine;
+use super::proband_instructions::ProbandInstruction;
+use super::haplotype_instruction::HaplotypeInstruction;
+use super::sequence_tape::SequenceTape; 
+#[cfg(feature = "writers")]
+use super::sequence_tape::StopCodonPolicy; 
+use super::variant_feature::{AppliedChange,VariantFeature};
+use super::transcript_instructions::OverlapSummary;
+#[cfg(feature = "writers")]
+use super::consensus::ConsensusRule;
+use super::gir::{ExecutionReport,ExecutionResult,ExecutionStatus,PlaceholderPolicy};
+use crate::functions::coordinate_audit::{get_coordinate_issues,CoordinateIssue};
+use crate::functions::hgvs::HgvsHeaderTags;
+use crate::functions::length_stats::get_anomaly_kind;
+use crate::functions::diagnostics::DiagnosticOptions;
+use crate::error::PpggError;
+#[cfg(feature = "writers")]
+use crate::functions::length_stats::SampleLengthStats;
+#[cfg(feature = "writers")]
+use crate::data_structures::id_map::IdMap;
+#[cfg(feature = "writers")]
+use crate::writers::{append_custom_records,write_applied_changes,write_grouped_fasta,ChangesFormat,create_file,create_output_writer,flush_output,NameTemplate,SampleQuota,WriterOptions};
+
+
+/// The sequences of the two haplotypes of each altered transcript of a proband, with transcript ids as keys and the sequences of the 
+/// first and the second haplotype as values 
+pub type HaplotypeSequences=HashMap<String,(String,String)>; 
+
+/// The order of the records in the fasta file of a personalized genome:
+/// 1. Processing => the records are written in the order they are stored in the sequence tapes, i.e. the first haplotype then the second, the default, 
+///    where the unaltered transcripts of each haplotype follow its altered transcripts ordered by transcript id, see SequenceTape::get_ordered_keys. 
+/// 2. Sorted => the records are sorted by transcript id and then by haplotype, e.g. ENST00000001_1, ENST00000001_2, ENST00000002_1. 
+///
+/// Both orders are identical between runs of the same input, sorted files of different runs or pipeline versions can moreover be compared with diff.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum RecordOrder
+{
+    Processing,
+    Sorted
+}
+
+/// How the reference record of a haplotype is written when all the variants of the transcript are on the other haplotype, e.g. T1_2 if only 
+/// the first haplotype of T1 is altered. The policy only affects files containing all the proteins, as files containing only the altered 
+/// proteins never include unmodified haplotypes:
+/// 1. Write => the reference sequence is written as any other record, which is the default. 
+/// 2. Omit => the record is not written, as it is identical to the reference record of the transcript. 
+/// 3. Mark => the record is written with a haplotype=reference tag, e.g. >T1_2 haplotype=reference. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum UnmodifiedHaplotypes
+{
+    Write,
+    Omit,
+    Mark
+}
+impl FromStr for UnmodifiedHaplotypes
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<UnmodifiedHaplotypes,String>
+    {
+        match policy
+        {
+            "write" | "WRITE" =>Ok(UnmodifiedHaplotypes::Write),
+            "omit" | "OMIT" =>Ok(UnmodifiedHaplotypes::Omit),
+            "mark" | "MARK" =>Ok(UnmodifiedHaplotypes::Mark),
+            _=>Err(format!("{} is not a supported policy for unmodified haplotypes, supported policies are: write, omit and mark",policy))
+        }
+    }
+}
+
+/// an abstraction for a personalized proteome, it contains the proband_name and the sequence tap which contain the mutated_sequences
+#[derive(Debug,Clone)]
+pub struct PersonalizedGenome
+{
+    proband_name:String,
+    seq_tape1:SequenceTape,
+    seq_tape2:SequenceTape,
+    features1:Vec<VariantFeature>,
+    features2:Vec<VariantFeature>,
+    header_tags:HgvsHeaderTags,
+    record_order:RecordOrder,
+    unmodified_haplotypes:UnmodifiedHaplotypes,
+    file_stem:Option<String>,
+    execution_reports:(ExecutionReport,ExecutionReport),
+    single_copy_transcripts:HashSet<String>,
+    missing_call_transcripts:HashSet<String>,
+    variant_support_tags:HashMap<String,String>,
+    silent_variant_tags:HashMap<(String,u8),String>,
+    emit_reference:bool,
+    anomaly_instruction_codes:(HashMap<String,String>,HashMap<String,String>),
+    coordinate_issues:Vec<CoordinateIssue>,
+    overlap_summary:OverlapSummary
+}
+impl PersonalizedGenome
+{
+    /// Create a new instance from a sequence tape and a proband name 
+    pub fn new(proband_name:String,seq_tape1:SequenceTape,seq_tape2:SequenceTape)->Self
+    {
+        PersonalizedGenome{proband_name,seq_tape1,seq_tape2,features1:Vec::new(),features2:Vec::new(),header_tags:HashMap::new(),record_order:RecordOrder::Processing,unmodified_haplotypes:UnmodifiedHaplotypes::Write,
+            file_stem:None,execution_reports:(ExecutionReport::default(),ExecutionReport::default()),
+            single_copy_transcripts:HashSet::new(),missing_call_transcripts:HashSet::new(),variant_support_tags:HashMap::new(),silent_variant_tags:HashMap::new(),emit_reference:false,anomaly_instruction_codes:(HashMap::new(),HashMap::new()),
+            coordinate_issues:Vec::new(),overlap_summary:OverlapSummary::default()}
+    }
+    /// ## Summary
+    /// set the HGVS.p descriptions that are added as an hgvs tag to the fasta headers of the altered transcripts of each haplotype, 
+    /// e.g. >ENST00000406869_1 hgvs=p.Gly5His,p.Val10His
+    pub fn set_header_tags(&mut self, header_tags:HgvsHeaderTags)
+    {
+        self.header_tags=header_tags; 
+    }
+    /// ## Summary
+    /// set the order of the records in the personalized fasta file, see RecordOrder for more details 
+    pub fn set_record_order(&mut self, record_order:RecordOrder)
+    {
+        self.record_order=record_order; 
+    }
+    /// ## Summary
+    /// set how unmodified haplotypes of transcripts altered in one haplotype only are written, see UnmodifiedHaplotypes for more details 
+    pub fn set_unmodified_haplotypes(&mut self, unmodified_haplotypes:UnmodifiedHaplotypes)
+    {
+        self.unmodified_haplotypes=unmodified_haplotypes; 
+    }
+    /// ## Summary
+    /// set whether the transcripts that are altered in neither haplotype are written once with a source=reference tag instead of once 
+    /// per haplotype when the reference sequences are written, see write_all, so the fasta file is a complete proteome of the proband 
+    /// without duplicated reference records, e.g. >ENST00000406869_1 source=reference 
+    pub fn set_emit_reference(&mut self, emit_reference:bool)
+    {
+        self.emit_reference=emit_reference; 
+    }
+    /// ## Summary
+    /// return the number of transcripts that are altered in exactly one of the two haplotypes 
+    pub fn get_num_single_haplotype_transcripts(&self)->u64
+    {
+        let (altered1,altered2)=(self.seq_tape1.get_annotation(),self.seq_tape2.get_annotation()); 
+        (altered1.keys().filter(|key|!altered2.contains_key(*key)).count()+altered2.keys().filter(|key|!altered1.contains_key(*key)).count()) as u64
+    }
+    /// ## Summary
+    /// set the diagnostics of the execution of the first and the second haplotype, see gir::ExecutionReport, the altered records of 
+    /// recovered transcripts are written with a status=recovered tag 
+    pub fn set_execution_reports(&mut self, execution_reports:(ExecutionReport,ExecutionReport))
+    {
+        self.execution_reports=execution_reports; 
+    }
+    /// ## Summary
+    /// set the transcripts that have a single copy in the proband, e.g. the transcripts outside the pseudoautosomal regions of an XY proband, 
+    /// see sex_chromosomes::SexChromosomeSummary, their records are only written for the first haplotype with a ploidy=haploid tag 
+    pub fn set_single_copy_transcripts(&mut self, single_copy_transcripts:HashSet<String>)
+    {
+        self.single_copy_transcripts=single_copy_transcripts; 
+    }
+    /// ## Summary
+    /// set the transcripts with a missing or a partially missing genotype call in the proband, see missing_genotypes::MissingGenotypePolicy::Flag, 
+    /// their records are written with a genotype=missing tag as their sequences are not known to be the reference 
+    pub fn set_missing_call_transcripts(&mut self, missing_call_transcripts:HashSet<String>)
+    {
+        self.missing_call_transcripts=missing_call_transcripts; 
+    }
+    /// ## Summary
+    /// set the allele fractions and the depths of the variants of the somatic mode per transcript, see somatic::get_support_tags, the 
+    /// altered records of a transcript are tagged with them, e.g. >ENST00000406869_1 vaf=0.250 dp=40 
+    pub fn set_variant_support_tags(&mut self, variant_support_tags:HashMap<String,String>)
+    {
+        self.variant_support_tags=variant_support_tags; 
+    }
+    /// ## Summary
+    /// set the silent changes of the proband per transcript and haplotype, see silent_variants::get_header_tags, the written records of a 
+    /// transcript are tagged with the silent changes of their haplotype, e.g. >ENST00000406869_1 silent=3L,377H 
+    pub fn set_silent_variant_tags(&mut self, silent_variant_tags:HashMap<(String,u8),String>)
+    {
+        self.silent_variant_tags=silent_variant_tags; 
+    }
+    /// ## Summary
+    /// set the overlapping mutations of the proband that were resolved with an overlap policy, summed over both haplotypes, see 
+    /// exec::ExecutionOptions 
+    pub fn set_overlap_summary(&mut self, overlap_summary:OverlapSummary)
+    {
+        self.overlap_summary=overlap_summary; 
+    }
+    /// ## Summary
+    /// return the overlapping mutations of the proband that were resolved with an overlap policy, see transcript_instructions::OverlapPolicy 
+    pub fn get_overlap_summary(&self)->&OverlapSummary
+    {
+        &self.overlap_summary
+    }
+    /// ## Summary
+    /// return the diagnostics of the execution of the first and the second haplotype 
+    pub fn get_execution_reports(&self)->(&ExecutionReport,&ExecutionReport)
+    {
+        (&self.execution_reports.0,&self.execution_reports.1)
+    }
+    /// ## Summary
+    /// return the number of haplotypes, i.e. 0, 1 or 2, whose sequences were produced under an error-recovery heuristic 
+    pub fn get_num_recovered_haplotypes(&self)->usize
+    {
+        [&self.execution_reports.0,&self.execution_reports.1].iter().filter(|report|report.get_status()==ExecutionStatus::Recovered).count()
+    }
+    /// ## Summary
+    /// return the clamped tasks, size mismatches and unwritten residues encountered while executing both haplotypes, see coordinate_audit 
+    pub fn get_coordinate_issues(&self)->&[CoordinateIssue]
+    {
+        &self.coordinate_issues
+    }
+    /// ## Summary
+    /// return the name of the proband 
+    pub fn get_proband_name(&self)->&String
+    {
+        &self.proband_name
+    }
+    /// ## Summary
+    /// set the stem of the files written for the proband, e.g. a sanitized version of the proband name, see writers::sanitize_file_stems, 
+    /// by default, the proband name is used 
+    pub fn set_file_stem(&mut self, file_stem:String)
+    {
+        self.file_stem=Some(file_stem); 
+    }
+    /// ## Summary
+    /// return the stem of the files written for the proband 
+    pub fn get_file_stem(&self)->&str
+    {
+        match self.file_stem.as_ref()
+        {
+            Some(file_stem)=>file_stem,
+            None=>&self.proband_name
+        }
+    }
+    /// ## Summary
+    /// return the sequence tapes of the first and the second haplotype 
+    pub fn get_seq_tapes(&self)->(&SequenceTape,&SequenceTape)
+    {
+        (&self.seq_tape1,&self.seq_tape2)
+    }
+    /// ## Summary
+    /// return the sequences of the two haplotypes of each transcript altered in at least one of the haplotypes, where the sequence of a
+    /// transcript that is only altered in one haplotype is taken from ref_seq for the other haplotype, or is empty if the transcript is 
+    /// not part of ref_seq. 
+    /// ## Example 
+    ///``` 
+    /// use ppgg::data_structures::InternalRep::{sequence_tape::SequenceTape,personalized_genome::PersonalizedGenome}; 
+    /// use std::collections::HashMap; 
+    /// let mut annotations=HashMap::new();
+    /// annotations.insert("T1".to_string(),(0,3));
+    /// let genome=PersonalizedGenome::new("proband_1".to_string(),SequenceTape::new("MKV".to_string(),annotations).unwrap(),
+    ///     SequenceTape::new(String::new(),HashMap::new()).unwrap());
+    /// let mut ref_seq=HashMap::new();
+    /// ref_seq.insert("T1".to_string(),"MKL".to_string());
+    /// ref_seq.insert("T2".to_string(),"MEK".to_string());
+    /// let sequences=genome.to_haplotype_sequences(&ref_seq);
+    /// assert_eq!(sequences.len(),1);
+    /// assert_eq!(sequences["T1"],("MKV".to_string(),"MKL".to_string()));
+    ///``` 
+    pub fn to_haplotype_sequences(&self, ref_seq:&HashMap<String,String>)->HaplotypeSequences
+    {
+        self.seq_tape1.get_annotation().keys().chain(self.seq_tape2.get_annotation().keys())
+            .map(|key|
+            {
+                let reference=match ref_seq.get(key)
+                {
+                    Some(seq)=>seq.as_str(),
+                    None=>""
+                };
+                let seq1=self.seq_tape1.get_seq(key).unwrap_or(reference).to_string(); 
+                let seq2=self.seq_tape2.get_seq(key).unwrap_or(reference).to_string(); 
+                (key.clone(),(seq1,seq2))
+            })
+            .collect::<HaplotypeSequences>()
+    }
+    #[cfg(feature = "writers")]
+    /// write the personlized proteome to the results directory, sequences shorter than min_length amino acids are not written 
+    /// and the function returns the number of dropped sequences, an error is returned in the aggregate-only mode, see writers::OutputPolicy 
+    /// ## Example 
+    ///``` 
+    /// use ppgg_rust::data_structures::InternalRep::{sequence_tape::SequenceTape,personalized_genome::PersonalizedGenome}; 
+    /// use std::collections::HashMap; 
+    /// let code_string1="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+    /// let code_string2="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+    /// let proband_name="code".to_string();
+    /// let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+    /// res_map.insert("1".to_string(), (0,4)); 
+    /// res_map.insert("2".to_string(), (5,9)); 
+    /// res_map.insert("3".to_string(), (10,14)); 
+    /// let seq_tape1=SequenceTape::new(code_string1, res_map.clone()).unwrap(); // this panic incase of length mismatch 
+    /// let seq_tape2=SequenceTape::new(code_string2, res_map).unwrap(); 
+    /// let personalized_proteome=PersonalizedGenome::new(proband_name, seq_tape1, seq_tape2); 
+    /// let num_dropped=personalized_proteome.write(&"test_data".to_string(),&false,&false,&HashMap::new(),&0).unwrap();
+    ///```     
+    pub fn write(&self, outdir:&String,write_all:&bool,write_compressed:&bool,ref_seq:&HashMap<String,String>,min_length:&usize,
+        options:&WriterOptions)->Result<u64,String>
+    {
+        self.write_with_custom_records(outdir, write_all, write_compressed, ref_seq, min_length, &[], options)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// similar to write, however, the custom records, e.g. contaminants, are appended to the fasta file after the personalized sequences, 
+    /// custom records whose id or sequence matches a written record are skipped, see writers::append_custom_records for more details.
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_with_custom_records(&self, outdir:&String,write_all:&bool,write_compressed:&bool,ref_seq:&HashMap<String,String>,min_length:&usize,
+        custom_records:&[(String,String)], options:&WriterOptions)->Result<u64,String>
+    {
+        self.write_with_length_stats(outdir, write_all, write_compressed, ref_seq, min_length, custom_records, options).map(|(num_dropped,_)|num_dropped)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// similar to write_with_custom_records, however, the length statistics of the altered sequences are collected while writing and are 
+    /// returned along with the number of dropped sequences. Altered sequences that are dropped because they are shorter than min_length are 
+    /// part of the statistics, so empty sequences are flagged as anomalies, see length_stats::SampleLengthStats for more details. The output 
+    /// quota of the options only covers the file of the proband, see write_under_quota for sharing the total quota between several probands. 
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_with_length_stats(&self, outdir:&String,write_all:&bool,write_compressed:&bool,ref_seq:&HashMap<String,String>,min_length:&usize,
+        custom_records:&[(String,String)], options:&WriterOptions)->Result<(u64,SampleLengthStats),String>
+    {
+        self.write_under_quota(outdir, write_all, write_compressed, ref_seq, min_length, custom_records, &AtomicU64::new(0), options)
+            .map(|(num_dropped,length_stats,_)|(num_dropped,length_stats))
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// similar to write_with_length_stats, however, the number of sequences that were not written because they did not fit into the output 
+    /// quota of the options is returned as well, where a non-zero number means the file of the proband is truncated, i.e. it holds the 
+    /// records written before the quota was exceeded, see writers::OutputQuota. total_bytes counts the bytes written to the fasta files of 
+    /// all probands of the run, which the total quota is checked against. 
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_under_quota(&self, outdir:&String,write_all:&bool,write_compressed:&bool,ref_seq:&HashMap<String,String>,min_length:&usize,
+        custom_records:&[(String,String)], total_bytes:&AtomicU64, options:&WriterOptions)->Result<(u64,SampleLengthStats,u64),String>
+    {
+        options.output_policy.check_per_sample("the personalized fasta files")?; 
+        match write_all 
+        {
+            true=>
+            {
+                self.write_all(write_compressed, ref_seq,outdir,min_length,custom_records,total_bytes,options)
+            },
+            false=>
+            {
+                self.write_altered_only(write_compressed,ref_seq,outdir,min_length,custom_records,total_bytes,options)
+            }    
+        }
+    }
+    /// ## Summary
+    /// create a new summary from a proband instruction, a reference proteome and an execution engine, the residues that were not written 
+    /// by any task are handled according to the placeholder policy, see gir::PlaceholderPolicy, and the tasks are checked with the check 
+    /// policy of the diagnostics, see diagnostics::DiagnosticOptions 
+    /// ## Panics
+    /// if the execution of one of the haplotypes fails, see try_from_proband_instruction for the fallible version 
+    pub fn from_proband_instruction(proband_instruction:ProbandInstruction, engine:Engine, ref_seq:&HashMap<String,String>, 
+        placeholder_policy:&PlaceholderPolicy, diagnostic_options:&DiagnosticOptions)->Self
+    {
+        match PersonalizedGenome::try_from_proband_instruction(proband_instruction, engine, ref_seq, placeholder_policy, diagnostic_options)
+        {
+            Ok(genome)=>genome,
+            Err(err_msg)=>panic!("{}",err_msg)
+        }
+    }
+    /// ## Summary
+    /// similar to from_proband_instruction, however, a failure while executing one of the haplotypes is returned as an error, so the 
+    /// caller can skip the proband and continue with the rest of the cohort  
+    pub fn try_from_proband_instruction(mut proband_instruction:ProbandInstruction, engine:Engine, ref_seq:&HashMap<String,String>, 
+        placeholder_policy:&PlaceholderPolicy, diagnostic_options:&DiagnosticOptions)->Result<Self,PpggError>
+    {
+        let proband_name=proband_instruction.proband_name; 
+        let mut result1=proband_instruction.haplotype1_instruction.get_g_rep(ref_seq, engine.clone(), placeholder_policy.residue, diagnostic_options).execute(engine.clone())?; 
+        let mut result2=proband_instruction.haplotype2_instruction.get_g_rep(ref_seq, engine.clone(), placeholder_policy.residue, diagnostic_options).execute(engine.clone())?;
+        // the final guard before writing, transcripts with a residue that was not written by any task never reach the fasta files 
+        let mut coordinate_issues=PersonalizedGenome::get_haplotype_coordinate_issues(1, &result1, &proband_instruction.haplotype1_instruction, placeholder_policy.residue); 
+        coordinate_issues.extend(PersonalizedGenome::get_haplotype_coordinate_issues(2, &result2, &proband_instruction.haplotype2_instruction, placeholder_policy.residue)); 
+        if placeholder_policy.is_strict
+        {
+            result1.reject_placeholder_transcripts(placeholder_policy.residue); 
+            result2.reject_placeholder_transcripts(placeholder_policy.residue); 
+        }
+        let seq_tape1=SequenceTape::new(result1.sequence.iter().collect::<String>(), result1.annotation).unwrap(); 
+        let seq_tape2=SequenceTape::new(result2.sequence.iter().collect::<String>(), result2.annotation).unwrap();
+        let anomaly_instruction_codes=(PersonalizedGenome::get_anomaly_instruction_codes(&proband_instruction.haplotype1_instruction, &seq_tape1, ref_seq),
+            PersonalizedGenome::get_anomaly_instruction_codes(&proband_instruction.haplotype2_instruction, &seq_tape2, ref_seq)); 
+        let mut genome=PersonalizedGenome::new(proband_name, seq_tape1, seq_tape2); 
+        genome.set_execution_reports((result1.report,result2.report)); 
+        genome.anomaly_instruction_codes=anomaly_instruction_codes; 
+        genome.coordinate_issues=coordinate_issues; 
+        Ok(genome)
+    }
+    /// ## Summary
+    /// return the coordinate issues of an executed haplotype, the unwritten residues are only counted for the recovered transcripts 
+    fn get_haplotype_coordinate_issues(haplotype:u8, result:&ExecutionResult, haplotype_instruction:&HaplotypeInstruction, placeholder:char)->Vec<CoordinateIssue>
+    {
+        let unfilled_per_transcript=result.report.recovered_transcripts.iter()
+            .filter_map(|transcript|result.annotation.get(transcript).map(|(start,end)|(transcript.clone(),
+                result.sequence.get(*start..*end).map_or(0,|seq|seq.iter().filter(|residue|**residue==placeholder).count()))))
+            .collect::<HashMap<String,usize>>(); 
+        get_coordinate_issues(haplotype, &result.report, &unfilled_per_transcript, |transcript|haplotype_instruction.get_instruction_codes(transcript).unwrap_or_default())
+    }
+    /// ## Summary
+    /// return the instruction codes of the transcripts of a haplotype whose sequence has an implausible length, see length_stats::get_anomaly_kind,
+    /// only the codes of these transcripts are kept so they can be reported while writing without holding the instructions of every transcript 
+    fn get_anomaly_instruction_codes(haplotype_instruction:&HaplotypeInstruction, seq_tape:&SequenceTape, ref_seq:&HashMap<String,String>)->HashMap<String,String>
+    {
+        seq_tape.get_annotation().keys()
+            .filter(|key|get_anomaly_kind(seq_tape.get_seq(key).map_or(0,|seq|seq.len()), ref_seq.get(*key).map(|seq|seq.len())).is_some())
+            .map(|key|(key.clone(),haplotype_instruction.get_instruction_codes(key).unwrap_or_default()))
+            .collect::<HashMap<String,String>>()
+    }
+    /// ## Summary
+    /// create a new instance from a proband instruction, a reference proteome and an execution engine, where the applied variants
+    /// are projected onto the personalized sequences and are stored with the instance, so they can be written using write_gff3 
+    /// ## Panics
+    /// if the execution of one of the haplotypes fails, see try_from_proband_instruction_with_features for the fallible version 
+    pub fn from_proband_instruction_with_features(proband_instruction:ProbandInstruction, engine:Engine, ref_seq:&HashMap<String,String>, 
+        placeholder_policy:&PlaceholderPolicy, diagnostic_options:&DiagnosticOptions)->Self
+    {
+        match PersonalizedGenome::try_from_proband_instruction_with_features(proband_instruction, engine, ref_seq, placeholder_policy, diagnostic_options)
+        {
+            Ok(genome)=>genome,
+            Err(err_msg)=>panic!("{}",err_msg)
+        }
+    }
+    /// ## Summary
+    /// similar to from_proband_instruction_with_features, however, a failure while executing one of the haplotypes is returned as an error 
+    pub fn try_from_proband_instruction_with_features(proband_instruction:ProbandInstruction, engine:Engine, ref_seq:&HashMap<String,String>, 
+        placeholder_policy:&PlaceholderPolicy, diagnostic_options:&DiagnosticOptions)->Result<Self,PpggError>
+    {
+        let (features1,features2)=proband_instruction.get_variant_features(ref_seq, engine.clone()); 
+        let mut genome=PersonalizedGenome::try_from_proband_instruction(proband_instruction, engine, ref_seq, placeholder_policy, diagnostic_options)?; 
+        genome.features1=features1; 
+        genome.features2=features2; 
+        Ok(genome)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write the applied variants of both haplotypes as a GFF3 file named after the proband to the results directory, 
+    /// the sequence ids match the record names of the personalized fasta file, i.e. the transcript id followed by the haplotype index.
+    /// Features of transcripts with an empty personalized sequence, e.g. start-lost transcripts, are not written.
+    pub fn write_gff3(&self, out_dir:&String, options:&WriterOptions)->Result<(),String>
+    {
+        options.output_policy.check_per_sample("the GFF3 files")?; 
+        let res_string=format!("{}/{}.gff3",out_dir,self.get_file_stem()); 
+        let res_path=Path::new(&res_string); 
+        let mut file_handle=match create_file(res_path, &options.retry_policy)
+        {
+            Ok(file)=>std::io::BufWriter::new(file),
+            Err(err_msg)=>return Err(format!("Could not create {} because {}",res_path.display(),err_msg))
+        }; 
+        let mut lines=vec!["##gff-version 3".to_string()]; 
+        for (haplotype,seq_tape,features) in [(1,&self.seq_tape1,&self.features1),(2,&self.seq_tape2,&self.features2)].iter()
+        {
+            // group the features by transcript while keeping the order of the features within each transcript
+            let mut per_transcript:HashMap<&String,Vec<&VariantFeature>>=HashMap::new(); 
+            for feature in features.iter()
+            {
+                per_transcript.entry(feature.get_transcript_name()).or_default().push(feature); 
+            }
+            let mut transcripts=per_transcript.keys().copied().collect::<Vec<&String>>(); 
+            transcripts.sort(); 
+            for transcript in transcripts
+            {
+                let seq_len=match seq_tape.get_seq(transcript)
+                {
+                    Ok(seq)=>seq.len(),
+                    Err(_)=>0
+                };
+                if seq_len==0
+                {
+                    continue
+                }
+                lines.push(format!("##sequence-region {}_{} 1 {}",transcript,haplotype,seq_len)); 
+                for (index,feature) in per_transcript[transcript].iter().enumerate()
+                {
+                    lines.push(feature.to_gff3(*haplotype,index)); 
+                }
+            }
+        }
+        match writeln!(file_handle,"{}",lines.join("\n"))
+        {
+            Ok(_)=>Ok(()),
+            Err(err_msg)=>Err(format!("Writing the features of: {} failed because {}",self.proband_name,err_msg))
+        }
+    }
+    /// ## Summary
+    /// return the changes applied to both haplotypes, sorted by haplotype and transcript while keeping the order of the changes within 
+    /// each transcript, where the residues and the positions are resolved using the reference proteome and the personalized sequences, 
+    /// see AppliedChange. Only features computed along with the sequences are returned, see from_proband_instruction_with_features, and 
+    /// transcripts with an empty personalized sequence, e.g. start-lost transcripts, are skipped. 
+    pub fn get_applied_changes(&self, ref_seq:&HashMap<String,String>)->Vec<AppliedChange>
+    {
+        let mut changes=Vec::with_capacity(self.features1.len()+self.features2.len()); 
+        for (haplotype,seq_tape,features) in [(1,&self.seq_tape1,&self.features1),(2,&self.seq_tape2,&self.features2)].iter()
+        {
+            let mut transcript_features=features.iter().enumerate().collect::<Vec<(usize,&VariantFeature)>>(); 
+            transcript_features.sort_by(|(index1,feature1),(index2,feature2)|(feature1.get_transcript_name(),index1).cmp(&(feature2.get_transcript_name(),index2))); 
+            for (_,feature) in transcript_features
+            {
+                let personalized_seq=match seq_tape.get_seq(feature.get_transcript_name())
+                {
+                    Ok(seq) if !seq.is_empty()=>seq,
+                    _=>continue
+                };
+                let reference=ref_seq.get(feature.get_transcript_name()).map_or("",|seq|seq.as_str()); 
+                changes.push(feature.to_applied_change(*haplotype, reference, personalized_seq)); 
+            }
+        }
+        changes
+    }
+    /// ## Summary
+    /// return the peptide windows of the changes applied to both haplotypes as fasta records, i.e. the header and the sequence, where each window
+    /// spans the alternative residues of a change extended by flank residues on both sides, see AppliedChange::get_peptide_window. The record
+    /// name is the transcript id followed by the haplotype and the index of the window in the transcript, e.g. ENST00000406869_1.w2, and the 
+    /// header lists the proband, the instruction code, the 1-based position of the change on the reference, the 1-based inclusive window on 
+    /// the personalized sequence and the haplotypes carrying it. A window found on both haplotypes of a transcript is only returned once.
+    pub fn get_peptide_windows(&self, ref_seq:&HashMap<String,String>, flank:usize)->Vec<(String,String)>
+    {
+        let mut windows:Vec<(String,Vec<u8>,&str)>=Vec::new(); 
+        let mut seen:HashMap<(String,&str),usize>=HashMap::new(); 
+        let mut num_windows:HashMap<(u8,String),usize>=HashMap::new(); 
+        for change in self.get_applied_changes(ref_seq)
+        {
+            let seq_tape=match change.haplotype
+            {
+                1=>&self.seq_tape1,
+                _=>&self.seq_tape2
+            };
+            let seq=match seq_tape.get_seq(&change.transcript_name)
+            {
+                Ok(seq)=>seq,
+                Err(_)=>continue
+            };
+            let (start,end)=match change.get_peptide_window(seq.len(),flank)
+            {
+                Some(window)=>window,
+                None=>continue
+            };
+            let index=num_windows.entry((change.haplotype,change.transcript_name.clone())).or_insert(0); 
+            *index+=1; 
+            let window=&seq[start..end]; 
+            match seen.get(&(change.transcript_name.clone(),window))
+            {
+                Some(position)=>windows[*position].1.push(change.haplotype),
+                None=>
+                {
+                    seen.insert((change.transcript_name.clone(),window),windows.len()); 
+                    let header=format!("{}_{}.w{} proband={} code={} ref_position={} window={}-{}",change.transcript_name,change.haplotype,index,
+                        self.proband_name,change.code,change.ref_start+1,start+1,end); 
+                    windows.push((header,vec![change.haplotype],window)); 
+                }
+            }
+        }
+        windows.into_iter()
+            .map(|(header,haplotypes,window)|(format!("{} haplotypes={}",header,haplotypes.iter().map(|haplotype|haplotype.to_string()).collect::<Vec<String>>().join(",")),
+                window.to_string()))
+            .collect::<Vec<(String,String)>>()
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write the peptide windows of the applied changes, see get_peptide_windows, as a fasta file named <proband>.peptides.fasta to the results
+    /// directory, or <proband>.peptides.fasta.gz if write_compressed is set, returns the number of written windows 
+    pub fn write_peptide_windows(&self, out_dir:&String, ref_seq:&HashMap<String,String>, flank:usize, write_compressed:bool, options:&WriterOptions)->Result<usize,String>
+    {
+        options.output_policy.check_per_sample("the peptide windows")?; 
+        let windows=self.get_peptide_windows(ref_seq, flank); 
+        let records=windows.iter().map(|(header,window)|(header.clone(),window.as_str())).collect::<Vec<(String,&str)>>(); 
+        write_grouped_fasta(Path::new(out_dir), &format!("{}.peptides",self.get_file_stem()), &[records], write_compressed, 0, options)?; 
+        Ok(windows.len())
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write the changes applied to both haplotypes, see get_applied_changes, as a table named after the proband to the results directory,
+    /// i.e. <proband>.changes.tsv or <proband>.changes.json depending on the format. 
+    pub fn write_changes(&self, out_dir:&String, ref_seq:&HashMap<String,String>, format:ChangesFormat, options:&WriterOptions)->Result<(),String>
+    {
+        let res_string=format!("{}/{}.{}",out_dir,self.get_file_stem(),format.get_extension()); 
+        write_applied_changes(Path::new(&res_string), &self.get_applied_changes(ref_seq), format, options)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write a single consensus sequence per altered transcript to a fasta file named <proband>.consensus.fasta, the two haplotypes are collapsed
+    /// using the provided rule, see ConsensusRule for the selection rules, where a transcript altered in only one haplotype is compared against the 
+    /// reference sequence. The header of each record contains the transcript id followed by the chosen haplotype and the rule, for example, 
+    /// "ENST00000406869 haplotype=2 rule=severity", where the haplotype is either 1, 2, both if the two haplotypes are identical or ambiguous. 
+    /// If write_all is set in the options the non-altered transcripts are written with haplotype=reference, and the file is compressed if 
+    /// write_compressed is set. Sequences shorter than min_length are dropped and the number of dropped sequences is returned.
+    pub fn write_consensus(&self, outdir:&String, ref_seq:&HashMap<String,String>, min_length:&usize, rule:&ConsensusRule, 
+        options:&WriterOptions)->Result<u64,String>
+    {
+        options.output_policy.check_per_sample("the consensus fasta files")?; 
+        let file_stem=format!("{}.consensus",self.get_file_stem()); 
+        let res_string=format!("{}/{}",outdir,options.compression_policy.get_fasta_name(&file_stem,options.write_compressed));
+        let mut altered=self.seq_tape1.get_annotation().keys().chain(self.seq_tape2.get_annotation().keys()).collect::<Vec<&String>>(); 
+        altered.sort(); 
+        altered.dedup(); 
+        let expected_size=self.get_expected_consensus_size(&altered, options.write_all, ref_seq, rule); 
+        let mut writer=create_output_writer(Path::new(&res_string), expected_size, options.write_compressed, options)?; 
+        let mut num_dropped=0; 
+        for key in altered.iter()
+        {
+            let reference=match ref_seq.get(*key)
+            {
+                Some(seq)=>seq.as_str(),
+                None=>""
+            };
+            let seq1=self.seq_tape1.get_seq(key).unwrap_or(reference); 
+            let seq2=self.seq_tape2.get_seq(key).unwrap_or(reference); 
+            let (consensus,source)=rule.get_consensus(seq1, seq2, reference); 
+            let header=format!("{} haplotype={} rule={}",key,source.as_str(),rule.as_str()); 
+            num_dropped+=PersonalizedGenome::write_named_record(&mut writer, &header, &consensus, min_length, options.stop_codon_policy)?;
+        }
+        if options.write_all
+        {
+            let mut non_altered=ref_seq.keys().filter(|key|altered.binary_search(key).is_err()).collect::<Vec<&String>>(); 
+            non_altered.sort(); 
+            for key in non_altered
+            {
+                let header=format!("{} haplotype=reference rule={}",key,rule.as_str()); 
+                num_dropped+=PersonalizedGenome::write_named_record(&mut writer, &header, &ref_seq[key], min_length, options.stop_codon_policy)?;
+            }
+        }
+        flush_output(&mut writer, &res_string)?; 
+        Ok(num_dropped)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write only altered protein to the fasta file, sequences shorter than min_length are dropped and
+    /// the number of dropped sequences is returned along with the length statistics of the altered sequences and the number of sequences 
+    /// that were not written because of the output quota, see writers::OutputQuota 
+    #[allow(clippy::too_many_arguments)]
+    fn write_altered_only(&self,write_compressed:&bool,ref_seq:&HashMap<String,String>,out_dir:&String,min_length:&usize,
+        custom_records:&[(String,String)], total_bytes:&AtomicU64, options:&WriterOptions)->Result<(u64,SampleLengthStats,u64),String>
+    {
+        let res_string=format!("{}/{}",out_dir,options.compression_policy.get_fasta_name(self.get_file_stem(),*write_compressed)); 
+        let mut writer=create_output_writer(Path::new(&res_string), self.get_expected_file_size(None), *write_compressed, options)?; 
+        let mut num_dropped=0; 
+        let mut written_ids=HashSet::new(); 
+        let mut written_seqs=HashSet::new(); 
+        let mut length_stats=SampleLengthStats::new(self.proband_name.clone()); 
+        let mut quota=options.output_quota.start_sample(total_bytes); 
+        let (template,id_map)=(&options.fasta_layout.template,options.id_map.as_ref()); 
+        self.for_each_record(None, |key,haplotype,seq,is_altered|
+        {
+            if is_altered
+            {
+                self.add_length_stats(&mut length_stats, key, haplotype, seq, ref_seq); 
+            }
+            let header=match self.get_record_header(template, id_map, key, haplotype, is_altered, false)
+            {
+                Some(header)=>header,
+                None=>return Ok(())
+            };
+            match PersonalizedGenome::write_named_record_under_quota(&mut writer, &header, seq, min_length, options.stop_codon_policy, &mut quota)?
+            {
+                0 if quota.is_truncated()=>(),
+                0=>{written_ids.insert(PersonalizedGenome::get_record_name(&header).to_string()); written_seqs.insert(seq);},
+                dropped=>num_dropped+=dropped
+            }
+            Ok(())
+        })?;
+        if !custom_records.is_empty() && !quota.is_truncated()
+        {
+            let written_ids=written_ids.iter().map(|id|id.as_str()).collect::<HashSet<&str>>(); 
+            append_custom_records(&mut writer, custom_records, &written_ids, &written_seqs)?; 
+        }
+        flush_output(&mut writer, &res_string)?; 
+        Ok((num_dropped,length_stats,quota.get_num_truncated()))
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write all proteins, i.e. altered or mutated along with the non-mutated reference, sequences shorter than min_length are dropped and
+    /// the number of dropped sequences is returned along with the length statistics of the altered sequences and the number of sequences 
+    /// that were not written because of the output quota, see writers::OutputQuota 
+    #[allow(clippy::too_many_arguments)]
+    fn write_all(&self,write_compressed:&bool, ref_seq:&HashMap<String,String>,out_dir:&String,min_length:&usize,
+        custom_records:&[(String,String)], total_bytes:&AtomicU64, options:&WriterOptions)->Result<(u64,SampleLengthStats,u64),String>
+    {
+        let res_string=format!("{}/{}",out_dir,options.compression_policy.get_fasta_name(self.get_file_stem(),*write_compressed)); 
+        let mut writer=create_output_writer(Path::new(&res_string), self.get_expected_file_size(Some(ref_seq)), *write_compressed, options)?; 
+        let mut num_dropped=0; 
+        let mut written_ids=HashSet::new(); 
+        let mut written_seqs=HashSet::new(); 
+        let mut length_stats=SampleLengthStats::new(self.proband_name.clone()); 
+        let mut quota=options.output_quota.start_sample(total_bytes); 
+        let (template,id_map)=(&options.fasta_layout.template,options.id_map.as_ref()); 
+        self.for_each_record(Some(ref_seq), |key,haplotype,seq,is_altered|
+        {
+            if is_altered
+            {
+                self.add_length_stats(&mut length_stats, key, haplotype, seq, ref_seq); 
+            }
+            let header=match self.get_record_header(template, id_map, key, haplotype, is_altered, true)
+            {
+                Some(header)=>header,
+                None=>return Ok(())
+            };
+            match PersonalizedGenome::write_named_record_under_quota(&mut writer, &header, seq, min_length, options.stop_codon_policy, &mut quota)?
+            {
+                0 if quota.is_truncated()=>(),
+                0=>{written_ids.insert(PersonalizedGenome::get_record_name(&header).to_string()); written_seqs.insert(seq);},
+                dropped=>num_dropped+=dropped
+            }
+            Ok(())
+        })?;
+        if !custom_records.is_empty() && !quota.is_truncated()
+        {
+            let written_ids=written_ids.iter().map(|id|id.as_str()).collect::<HashSet<&str>>(); 
+            append_custom_records(&mut writer, custom_records, &written_ids, &written_seqs)?; 
+        }
+        flush_output(&mut writer, &res_string)?; 
+        Ok((num_dropped,length_stats,quota.get_num_truncated()))
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// add an altered sequence to the length statistics, the instruction codes are only looked up for sequences with an implausible length 
+    fn add_length_stats(&self, length_stats:&mut SampleLengthStats, key:&str, haplotype:u8, seq:&str, ref_seq:&HashMap<String,String>)
+    {
+        let codes=match haplotype
+        {
+            1=>&self.anomaly_instruction_codes.0,
+            _=>&self.anomaly_instruction_codes.1
+        };
+        length_stats.add(key, haplotype, seq.len(), ref_seq.get(key).map(|seq|seq.len()), ||codes.get(key).cloned().unwrap_or_default()); 
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the expected size in bytes of the fasta file of the proband, computed from the spans of the altered sequences stored in the 
+    /// annotation of the sequence tapes, i.e. without reading the sequences, and, if ref_seq is provided, the reference sequences of the 
+    /// transcripts that are not altered in a haplotype, see write_all. The tags added to the headers of the altered records are not counted. 
+    pub fn get_expected_file_size(&self, ref_seq:Option<&HashMap<String,String>>)->usize
+    {
+        let mut expected_size=0; 
+        for seq_tape in [&self.seq_tape1,&self.seq_tape2]
+        {
+            expected_size+=seq_tape.get_annotation().iter()
+                .map(|(key,(start,end))|PersonalizedGenome::get_record_size(key.len()+2, end.saturating_sub(*start)))
+                .sum::<usize>(); 
+            if let Some(ref_seq)=ref_seq
+            {
+                expected_size+=ref_seq.iter()
+                    .filter(|(key,_)|!seq_tape.get_annotation().contains_key(*key))
+                    .map(|(key,seq)|PersonalizedGenome::get_record_size(key.len()+2, seq.len()))
+                    .sum::<usize>(); 
+            }
+        }
+        expected_size
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the expected size in bytes of the consensus fasta file of the proband, where the length of a consensus sequence is bounded 
+    /// by the longest sequence of the transcript and the header by the longest haplotype source 
+    fn get_expected_consensus_size(&self, altered:&[&String], write_all:bool, ref_seq:&HashMap<String,String>, rule:&ConsensusRule)->usize
+    {
+        let tag_length=" haplotype=ambiguous rule=".len()+rule.as_str().len(); 
+        let get_length=|seq_tape:&SequenceTape,key:&String|seq_tape.get_annotation().get(key).map(|(start,end)|end.saturating_sub(*start))
+            .unwrap_or_else(||ref_seq.get(key).map(|seq|seq.len()).unwrap_or(0)); 
+        let mut expected_size=altered.iter()
+            .map(|key|PersonalizedGenome::get_record_size(key.len()+tag_length, get_length(&self.seq_tape1,key).max(get_length(&self.seq_tape2,key))))
+            .sum::<usize>(); 
+        if write_all
+        {
+            expected_size+=ref_seq.iter()
+                .filter(|(key,_)|altered.binary_search(key).is_err())
+                .map(|(key,seq)|PersonalizedGenome::get_record_size(key.len()+tag_length, seq.len()))
+                .sum::<usize>(); 
+        }
+        expected_size
+    }
+    #[cfg(feature = "writers")]
+    /// the size of a fasta record in bytes, i.e. the '>' and the header followed by the sequence, each terminated by a new line  
+    fn get_record_size(header_length:usize, seq_length:usize)->usize
+    {
+        header_length+seq_length+3
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// call write_fn on each record of the fasta file, i.e. the transcript id, the haplotype, the sequence and whether the sequence is altered, 
+    /// in the order set by the record order. If ref_seq is provided, the reference sequence of every transcript that is not altered in a 
+    /// haplotype is included as a non-altered record. Only references to the sequences are held, i.e. sorting the records merges the
+    /// sorted transcript ids of the sequence tapes and the reference instead of collecting the records. 
+    fn for_each_record<'a,F>(&'a self, ref_seq:Option<&'a HashMap<String,String>>, mut write_fn:F)->Result<(),String>
+    where F:FnMut(&'a str,u8,&'a str,bool)->Result<(),String>
+    {
+        let seq_tapes=[(1,&self.seq_tape1),(2,&self.seq_tape2)]; 
+        match self.record_order
+        {
+            RecordOrder::Processing=>
+            {
+                for (haplotype,seq_tape) in seq_tapes.iter()
+                {
+                    for key in seq_tape.get_ordered_keys()
+                    {
+                        write_fn(key, *haplotype, seq_tape.get_seq(key).unwrap(), true)?; 
+                    }
+                    if let Some(ref_seq)=ref_seq
+                    {
+                        // sequences that have not been altered are written in their reference form, ordered by transcript id 
+                        let mut unaltered=ref_seq.iter().filter(|(key,_)|!seq_tape.get_annotation().contains_key(*key)).collect::<Vec<(&String,&String)>>(); 
+                        unaltered.sort_unstable_by_key(|(key,_)|*key); 
+                        for (key,value) in unaltered
+                        {
+                            write_fn(key, *haplotype, value, false)?; 
+                        }
+                    }
+                }
+            },
+            RecordOrder::Sorted=>
+            {
+                let mut runs=seq_tapes.iter().map(|(_,seq_tape)|seq_tape.get_annotation().keys().collect::<Vec<&String>>()).collect::<Vec<_>>(); 
+                if let Some(ref_seq)=ref_seq
+                {
+                    runs.push(ref_seq.keys().collect::<Vec<&String>>()); 
+                }
+                for key in SortedMerge::new(runs)
+                {
+                    for (haplotype,seq_tape) in seq_tapes.iter()
+                    {
+                        match (seq_tape.get_seq(key),ref_seq.and_then(|ref_seq|ref_seq.get(key)))
+                        {
+                            (Ok(seq),_)=>write_fn(key, *haplotype, seq, true)?,
+                            (Err(_),Some(value))=>write_fn(key, *haplotype, value, false)?,
+                            (Err(_),None)=>()
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the header of the record of a transcript in a haplotype, i.e. its name made with the template, see writers::NameTemplate, 
+    /// where the transcript is named by its protein id if it is mapped by the id map, see id_map::IdMap, followed by its tags, or None if the record is not written, where with_reference is set if the reference sequences of the 
+    /// unaltered transcripts are written, see write_all. The record of an altered transcript is tagged with the HGVS.p descriptions of the 
+    /// haplotype, if set, and with status=recovered if the sequence was produced under an error-recovery heuristic, see gir::ExecutionStatus, 
+    /// while an unmodified haplotype is handled with the policy of the genome, see UnmodifiedHaplotypes. The records of the transcripts with 
+    /// a missing genotype call are tagged with genotype=missing, see set_missing_call_transcripts, and the records carrying silent changes 
+    /// with them, see set_silent_variant_tags. The transcripts altered in neither haplotype are written once if set, see set_emit_reference. 
+    fn get_record_header(&self, template:&NameTemplate, id_map:Option<&IdMap>, key:&str, haplotype:u8, is_altered:bool, with_reference:bool)->Option<String>
+    {
+        let is_single_copy=self.single_copy_transcripts.contains(key); 
+        if with_reference && is_single_copy && haplotype==2
+        {
+            return None
+        }
+        let mut header=match id_map.and_then(|id_map|id_map.get_protein_id(key))
+        {
+            Some(protein)=>template.render(&self.proband_name, haplotype, protein),
+            None=>template.render(&self.proband_name, haplotype, key)
+        }; 
+        if !is_altered
+        {
+            let is_unmodified=match haplotype
+            {
+                1=>self.seq_tape2.get_annotation().contains_key(key),
+                _=>self.seq_tape1.get_annotation().contains_key(key)
+            };
+            if self.emit_reference && !is_unmodified && haplotype==2
+            {
+                return None
+            }
+            match (is_single_copy,is_unmodified,self.unmodified_haplotypes)
+            {
+                (true,_,_)=>header.push_str(" ploidy=haploid"),
+                (false,true,UnmodifiedHaplotypes::Omit)=>return None,
+                (false,true,UnmodifiedHaplotypes::Mark)=>header.push_str(" haplotype=reference"),
+                (false,_,_)=>()
+            }
+            if self.emit_reference && !is_unmodified
+            {
+                header.push_str(" source=reference"); 
+            }
+            if self.missing_call_transcripts.contains(key)
+            {
+                header.push_str(" genotype=missing"); 
+            }
+            if let Some(tag)=self.silent_variant_tags.get(&(key.to_string(),haplotype))
+            {
+                header.push_str(&format!(" {}",tag)); 
+            }
+            return Some(header)
+        }
+        let (tag,report)=match haplotype
+        {
+            1=>(self.header_tags.get(key).map(|tags|&tags.0),&self.execution_reports.0),
+            _=>(self.header_tags.get(key).map(|tags|&tags.1),&self.execution_reports.1)
+        };
+        if let Some(tag)=tag.filter(|tag|!tag.is_empty())
+        {
+            header.push_str(&format!(" hgvs={}",tag)); 
+        }
+        if let Some(tag)=self.variant_support_tags.get(key)
+        {
+            header.push_str(&format!(" {}",tag)); 
+        }
+        if is_single_copy
+        {
+            header.push_str(" ploidy=haploid"); 
+        }
+        if report.is_recovered(key)
+        {
+            header.push_str(&format!(" status={}",ExecutionStatus::Recovered.as_str())); 
+        }
+        if self.missing_call_transcripts.contains(key)
+        {
+            header.push_str(" genotype=missing"); 
+        }
+        if let Some(tag)=self.silent_variant_tags.get(&(key.to_string(),haplotype))
+        {
+            header.push_str(&format!(" {}",tag)); 
+        }
+        Some(header)
+    }
+    #[cfg(feature = "writers")]
+    /// the name of a record, i.e. its header without the tags 
+    fn get_record_name(header:&str)->&str
+    {
+        header.split(' ').next().unwrap_or(header)
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the records of a transcript in both haplotypes, i.e. the header of each record made with the template, see get_record_header, 
+    /// along with its sequence, where the reference sequence is returned for a haplotype that does not alter the transcript if ref_seq is 
+    /// provided. Used for writing the records of a transcript across the probands, see writers::OutputLayout::PerTranscript. 
+    pub fn get_transcript_records<'a>(&'a self, key:&String, ref_seq:Option<&'a HashMap<String,String>>, template:&NameTemplate, id_map:Option<&IdMap>)->Vec<(String,&'a str)>
+    {
+        let mut records=Vec::with_capacity(2); 
+        for (haplotype,seq_tape) in [(1,&self.seq_tape1),(2,&self.seq_tape2)].iter()
+        {
+            let (seq,is_altered)=match (seq_tape.get_seq(key),ref_seq.and_then(|ref_seq|ref_seq.get(key)))
+            {
+                (Ok(seq),_)=>(seq,true),
+                (Err(_),Some(seq))=>(seq.as_str(),false),
+                (Err(_),None)=>continue
+            };
+            if let Some(header)=self.get_record_header(template, id_map, key, *haplotype, is_altered, ref_seq.is_some())
+            {
+                records.push((header,seq)); 
+            }
+        }
+        records
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the records of every transcript in the order set by the record order, see get_transcript_records, used for writing the 
+    /// records of the probands to a single file, see writers::OutputLayout::Combined 
+    pub fn get_fasta_records<'a>(&'a self, ref_seq:Option<&'a HashMap<String,String>>, template:&NameTemplate, id_map:Option<&IdMap>)->Vec<(String,&'a str)>
+    {
+        let mut records=Vec::new(); 
+        self.for_each_record(ref_seq, |key,haplotype,seq,is_altered|
+        {
+            if let Some(header)=self.get_record_header(template, id_map, key, haplotype, is_altered, ref_seq.is_some())
+            {
+                records.push((header,seq)); 
+            }
+            Ok(())
+        }).unwrap(); 
+        records
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// return the length statistics of the altered sequences of the proband, as collected while writing its fasta file, see write_with_length_stats 
+    pub fn get_length_stats(&self, ref_seq:&HashMap<String,String>)->SampleLengthStats
+    {
+        let mut length_stats=SampleLengthStats::new(self.proband_name.clone()); 
+        self.for_each_record(None, |key,haplotype,seq,_|
+        {
+            self.add_length_stats(&mut length_stats, key, haplotype, seq, ref_seq); 
+            Ok(())
+        }).unwrap(); 
+        length_stats
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// write a single fasta record with the provided header if the sequence is at least min_length amino acids long after the stop codon 
+    /// policy is applied, see StopCodonPolicy::apply_optional, returns 1 if the record has been dropped and 0 otherwise 
+    fn write_named_record(writer:&mut Box<dyn Write>, header:&str, seq:&str, min_length:&usize, stop_codon_policy:Option<StopCodonPolicy>)->Result<u64,String>
+    {
+        let seq=StopCodonPolicy::apply_optional(stop_codon_policy, seq); 
+        if seq.len() < *min_length
+        {
+            return Ok(1)
+        }
+        match write!(writer,">{}\n{}\n", header, seq)
+        {
+            Ok(_)=>Ok(0),
+            Err(err_msg)=>Err(format!("Writing the sequence of: {} failed because {}",header,err_msg))
+        }
+    }
+    #[cfg(feature = "writers")]
+    /// ## Summary
+    /// similar to write_named_record, however, the record is only written if it fits into the quota of the sample, see write_record 
+    fn write_named_record_under_quota(writer:&mut Box<dyn Write>, header:&str, seq:&str, min_length:&usize, stop_codon_policy:Option<StopCodonPolicy>, 
+        quota:&mut SampleQuota)->Result<u64,String>
+    {
+        let seq=StopCodonPolicy::apply_optional(stop_codon_policy, seq); 
+        if seq.len() >= *min_length && !quota.reserve((header.len()+seq.len()+3) as u64)
+        {
+            return Ok(0)
+        }
+        PersonalizedGenome::write_named_record(writer, header, &seq, min_length, None)
+    }
+}
+/// A merge of sorted runs of transcript ids, e.g. the ids of the altered transcripts in each haplotype, where each run is sorted on creation 
+/// and the merged ids are yielded in ascending order with duplicates across the runs yielded once
+#[cfg(feature = "writers")]
+struct SortedMerge<'a>
+{
+    runs:Vec<std::vec::IntoIter<&'a String>>,
+    heap:BinaryHeap<Reverse<(&'a String,usize)>>,
+    last:Option<&'a String>
+}
+#[cfg(feature = "writers")]
+impl<'a> SortedMerge<'a>
+{
+    fn new(runs:Vec<Vec<&'a String>>)->Self
+    {
+        let mut runs=runs.into_iter().map(|mut run|{run.sort_unstable(); run.into_iter()}).collect::<Vec<_>>(); 
+        let mut heap=BinaryHeap::with_capacity(runs.len()); 
+        for (index,run) in runs.iter_mut().enumerate()
+        {
+            if let Some(key)=run.next()
+            {
+                heap.push(Reverse((key,index))); 
+            }
+        }
+        SortedMerge{runs,heap,last:None}
+    }
+}
+#[cfg(feature = "writers")]
+impl<'a> Iterator for SortedMerge<'a>
+{
+    type Item=&'a String; 
+    fn next(&mut self)->Option<Self::Item>
+    {
+        while let Some(Reverse((key,index)))=self.heap.pop()
+        {
+            if let Some(next_key)=self.runs[index].next()
+            {
+                self.heap.push(Reverse((next_key,index))); 
+            }
+            if self.last!=Some(key)
+            {
+                self.last=Some(key); 
+                return Some(key)
+            }
+        }
+        None
+    }
+}
+#[cfg(test)]
+mod test_personalized_proteome
+{
+    use super::*; 
+    use std::collections::HashMap;
+    use super::super::coordinates::{RefPos0,Len};
+    #[test]
+    pub fn test_personalized_proteome()->Result<(),String>
+    {
+        let code_string1="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+        let code_string2="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+        let proband_name="code".to_string();
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("1".to_string(), (0,4)); 
+        res_map.insert("2".to_string(), (5,9)); 
+        res_map.insert("3".to_string(), (10,14)); 
+        let mut seq_map=HashMap::new();
+        seq_map.insert("1".to_string(), "AKLMNOPQTRST".to_string());
+        seq_map.insert("2".to_string(), "DEVELEOPMNKO".to_string()); 
+        
+        let seq_tape1=SequenceTape::new(code_string1, res_map.clone()).unwrap(); // this panic incase of length mismatch 
+        let seq_tape2=SequenceTape::new(code_string2, res_map).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new(proband_name, seq_tape1, seq_tape2); 
+        personalized_proteome.write(&"test_data".to_string(),&false,&false,&seq_map,&0,&WriterOptions::default()).map(|_|())
+    }
+    #[test]
+    pub fn test_min_length_filter()
+    {
+        let code_string="SEQ1_SEQ2_LONGSEQ3".to_string(); 
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("1".to_string(), (0,4)); 
+        res_map.insert("2".to_string(), (5,9)); 
+        res_map.insert("3".to_string(), (10,18)); 
+        let seq_tape1=SequenceTape::new(code_string.clone(), res_map.clone()).unwrap();
+        let seq_tape2=SequenceTape::new(code_string, res_map).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new("min_length_test".to_string(), seq_tape1, seq_tape2); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        let mut ref_map=HashMap::new();
+        ref_map.insert("4".to_string(), "MKL".to_string()); 
+        assert_eq!(personalized_proteome.write(&out_dir,&false,&false,&ref_map,&5,&WriterOptions::default()).unwrap(),4);
+        assert_eq!(personalized_proteome.write(&out_dir,&true,&false,&ref_map,&5,&WriterOptions::default()).unwrap(),6);
+        assert_eq!(personalized_proteome.write(&out_dir,&true,&false,&ref_map,&0,&WriterOptions::default()).unwrap(),0);
+        let written=std::fs::read_to_string(format!("{}/min_length_test.fasta",out_dir)).unwrap(); 
+        assert!(written.contains(">4_2\nMKL\n"));
+    }
+    #[test]
+    pub fn test_write_sorted_records()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T3".to_string(), (0,3)); 
+        res_map1.insert("T1".to_string(), (3,6)); 
+        let mut res_map2:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map2.insert("T2".to_string(), (0,3)); 
+        res_map2.insert("T1".to_string(), (3,6)); 
+        let seq_tape1=SequenceTape::new("MKVMKW".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new("MKLMKY".to_string(), res_map2).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("sorted_records_test".to_string(), seq_tape1, seq_tape2); 
+        personalized_proteome.set_record_order(RecordOrder::Sorted); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        personalized_proteome.write(&out_dir,&false,&false,&HashMap::new(),&0,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/sorted_records_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T1_1\nMKW\n>T1_2\nMKY\n>T2_2\nMKL\n>T3_1\nMKV\n"); 
+        let mut ref_seq=HashMap::new(); 
+        for (name,seq) in [("T0","MAA"),("T1","MKA"),("T2","MKA"),("T3","MKA"),("T4","MCC")].iter()
+        {
+            ref_seq.insert(name.to_string(),seq.to_string()); 
+        }
+        personalized_proteome.write(&out_dir,&true,&false,&ref_seq,&0,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/sorted_records_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T0_1\nMAA\n>T0_2\nMAA\n>T1_1\nMKW\n>T1_2\nMKY\n>T2_1\nMKA\n>T2_2\nMKL\n>T3_1\nMKV\n>T3_2\nMKA\n>T4_1\nMCC\n>T4_2\nMCC\n"); 
+    }
+    #[test]
+    pub fn test_write_records_in_processing_order()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T3".to_string(), (0,3)); 
+        res_map1.insert("T1".to_string(), (3,6)); 
+        let seq_tape1=SequenceTape::new("MKVMKW".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new("processing_order_test".to_string(), seq_tape1, seq_tape2); 
+        let mut ref_seq=HashMap::new(); 
+        for (name,seq) in [("T4","MCC"),("T1","MKA"),("T0","MAA"),("T3","MKA")].iter()
+        {
+            ref_seq.insert(name.to_string(),seq.to_string()); 
+        }
+        // the altered records follow the layout of the tape and the unaltered ones the transcript ids, whatever the order of the hash maps 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        personalized_proteome.write(&out_dir,&true,&false,&ref_seq,&0,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/processing_order_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T3_1\nMKV\n>T1_1\nMKW\n>T0_1\nMAA\n>T4_1\nMCC\n>T0_2\nMAA\n>T1_2\nMKA\n>T3_2\nMKA\n>T4_2\nMCC\n"); 
+    }
+    #[test]
+    pub fn test_write_with_length_stats()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T1".to_string(), (0,3)); 
+        res_map1.insert("T2".to_string(), (3,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("length_stats_test".to_string(), seq_tape1, seq_tape2); 
+        personalized_proteome.set_record_order(RecordOrder::Sorted); 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKA".to_string()); 
+        ref_seq.insert("T2".to_string(),"MCC".to_string()); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        let (num_dropped,stats)=personalized_proteome.write_with_length_stats(&out_dir,&true,&false,&ref_seq,&1,&[],&WriterOptions::default()).unwrap(); 
+        assert_eq!((num_dropped,stats.num_sequences,stats.min_length,stats.max_length),(1,2,Some(0),Some(3))); 
+        assert_eq!(stats.anomalies.len(),1); 
+        assert_eq!((stats.anomalies[0].transcript.as_str(),stats.anomalies[0].haplotype),("T2",1)); 
+    }
+    #[test]
+    pub fn test_expected_file_size()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T1".to_string(), (0,3)); 
+        res_map1.insert("T2".to_string(), (3,7)); 
+        let seq_tape1=SequenceTape::new("MKVMCCA".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new("expected_size_test".to_string(), seq_tape1, seq_tape2); 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKA".to_string()); 
+        ref_seq.insert("T3".to_string(),"MCCLV".to_string()); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        // the records are written through a buffer sized by the expected size, which is exact for records without header tags 
+        for (write_all,expected_size) in [(false,21),(true,55)]
+        {
+            personalized_proteome.write_with_length_stats(&out_dir,&write_all,&false,&ref_seq,&0,&[],&WriterOptions::default()).unwrap(); 
+            let written=std::fs::read_to_string(format!("{}/expected_size_test.fasta",out_dir)).unwrap(); 
+            assert_eq!(written.len(),expected_size); 
+            assert_eq!(personalized_proteome.get_expected_file_size(if write_all {Some(&ref_seq)} else {None}),expected_size); 
+        }
+    }
+    #[test]
+    pub fn test_write_unmodified_haplotypes()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T1".to_string(), (0,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("unmodified_haplotypes_test".to_string(), seq_tape1, seq_tape2); 
+        personalized_proteome.set_record_order(RecordOrder::Sorted); 
+        assert_eq!(personalized_proteome.get_num_single_haplotype_transcripts(),1); 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKA".to_string()); 
+        ref_seq.insert("T2".to_string(),"MCC".to_string()); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        for (policy,expected) in [(UnmodifiedHaplotypes::Write,">T1_1\nMKV\n>T1_2\nMKA\n>T2_1\nMCC\n>T2_2\nMCC\n"),
+                    (UnmodifiedHaplotypes::Omit,">T1_1\nMKV\n>T2_1\nMCC\n>T2_2\nMCC\n"),
+                    (UnmodifiedHaplotypes::Mark,">T1_1\nMKV\n>T1_2 haplotype=reference\nMKA\n>T2_1\nMCC\n>T2_2\nMCC\n")].iter()
+        {
+            personalized_proteome.set_unmodified_haplotypes(*policy); 
+            personalized_proteome.write(&out_dir,&true,&false,&ref_seq,&0,&WriterOptions::default()).unwrap(); 
+            let written=std::fs::read_to_string(format!("{}/unmodified_haplotypes_test.fasta",out_dir)).unwrap(); 
+            assert_eq!(&written,expected); 
+        }
+        assert_eq!(UnmodifiedHaplotypes::from_str("omit").unwrap(),UnmodifiedHaplotypes::Omit); 
+        assert!(UnmodifiedHaplotypes::from_str("drop").is_err()); 
+    }
+    #[test]
+    pub fn test_emit_reference()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T1".to_string(), (0,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("emit_reference_test".to_string(), seq_tape1, seq_tape2); 
+        personalized_proteome.set_record_order(RecordOrder::Sorted); 
+        personalized_proteome.set_emit_reference(true); 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKA".to_string()); 
+        ref_seq.insert("T2".to_string(),"MCC".to_string()); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        // the unmodified haplotype of the altered transcript is still handled by the unmodified haplotypes policy 
+        personalized_proteome.write(&out_dir,&true,&false,&ref_seq,&0,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/emit_reference_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T1_1\nMKV\n>T1_2\nMKA\n>T2_1 source=reference\nMCC\n"); 
+    }
+    #[test]
+    pub fn test_write_with_header_tags()
+    {
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("T1".to_string(), (0,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map.clone()).unwrap();
+        let seq_tape2=SequenceTape::new("MKW".to_string(), res_map).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("header_tags_test".to_string(), seq_tape1, seq_tape2); 
+        let mut header_tags=HashMap::new(); 
+        header_tags.insert("T1".to_string(),("p.Leu3Val".to_string(),String::new())); 
+        personalized_proteome.set_header_tags(header_tags); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        personalized_proteome.write(&out_dir,&false,&false,&HashMap::new(),&0,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/header_tags_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T1_1 hgvs=p.Leu3Val\nMKV\n>T1_2\nMKW\n"); 
+    }
+    #[test]
+    pub fn test_transcript_records()
+    {
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("T1".to_string(), (0,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("P1".to_string(), seq_tape1, seq_tape2); 
+        personalized_proteome.set_unmodified_haplotypes(UnmodifiedHaplotypes::Mark); 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKL".to_string()); 
+        ref_seq.insert("T2".to_string(),"MAA".to_string()); 
+        let template=NameTemplate::parse("{proband}|{transcript}|{haplotype}").unwrap(); 
+        let key="T1".to_string(); 
+        assert_eq!(personalized_proteome.get_transcript_records(&key, None, &template, None),vec![("P1|T1|1".to_string(),"MKV")]); 
+        // the second haplotype of T1 is unmodified while T2 is not altered at all 
+        assert_eq!(personalized_proteome.get_transcript_records(&key, Some(&ref_seq), &template, None),
+            vec![("P1|T1|1".to_string(),"MKV"),("P1|T1|2 haplotype=reference".to_string(),"MKL")]); 
+        personalized_proteome.set_record_order(RecordOrder::Sorted); 
+        let records=personalized_proteome.get_fasta_records(Some(&ref_seq), &template, None); 
+        assert_eq!(records.iter().map(|(header,_)|header.as_str()).collect::<Vec<&str>>(),vec!["P1|T1|1","P1|T1|2 haplotype=reference","P1|T2|1","P1|T2|2"]); 
+        // the mapped transcripts are named by their protein ids 
+        let id_map=IdMap::from_tsv_str("T1\tENSP1\n").unwrap(); 
+        assert_eq!(personalized_proteome.get_transcript_records(&key, None, &template, Some(&id_map)),vec![("P1|ENSP1|1".to_string(),"MKV")]); 
+    }
+    #[test]
+    pub fn test_peptide_windows()
+    {
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("T1".to_string(), (0,10)); 
+        let seq_tape1=SequenceTape::new("MKLVEYGAPW".to_string(), res_map.clone()).unwrap();
+        let seq_tape2=SequenceTape::new("MKLVEYGAPW".to_string(), res_map).unwrap(); 
+        let mut personalized_proteome=PersonalizedGenome::new("P1".to_string(), seq_tape1, seq_tape2); 
+        let missense=VariantFeature::new("T1".to_string(),'M',RefPos0::new(5),RefPos0::new(5),Len::new(1)).with_reference_length(Len::new(1)); 
+        personalized_proteome.features1=vec![missense.clone()]; 
+        personalized_proteome.features2=vec![missense]; 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKLVEGGAPW".to_string()); 
+        // the window is shared by both haplotypes, hence, it is only returned once 
+        assert_eq!(personalized_proteome.get_peptide_windows(&ref_seq, 2),
+            vec![("T1_1.w1 proband=P1 code=M ref_position=6 window=4-8 haplotypes=1,2".to_string(),"VEYGA".to_string())]); 
+    }
+    #[test]
+    pub fn test_write_with_custom_records()
+    {
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("T1".to_string(), (0,3)); 
+        let seq_tape1=SequenceTape::new("MKV".to_string(), res_map).unwrap();
+        let seq_tape2=SequenceTape::new(String::new(), HashMap::new()).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new("custom_records_test".to_string(), seq_tape1, seq_tape2); 
+        let custom_records=vec![("CONT_1 trypsin".to_string(),"IVGG".to_string()),("CONT_2".to_string(),"MKV".to_string()),
+            ("T1_1".to_string(),"MSC".to_string()),("CONT_1 duplicated".to_string(),"WWW".to_string())]; 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        personalized_proteome.write_with_custom_records(&out_dir,&false,&false,&HashMap::new(),&0,&custom_records,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/custom_records_test.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T1_1\nMKV\n>CONT_1 trypsin\nIVGG\n"); 
+        // with write_all the reference sequences are written as well and are used for the deduplication, hence, the first CONT_1 record 
+        // is skipped and its id is free for the following record 
+        let mut ref_map=HashMap::new();
+        ref_map.insert("T2".to_string(), "IVGG".to_string()); 
+        personalized_proteome.write_with_custom_records(&out_dir,&true,&false,&ref_map,&0,&custom_records,&WriterOptions::default()).unwrap(); 
+        let written=std::fs::read_to_string(format!("{}/custom_records_test.fasta",out_dir)).unwrap(); 
+        assert!(!written.contains("CONT_1 trypsin")); 
+        assert!(written.contains(">CONT_1 duplicated\nWWW\n")); 
+    }
+    #[test]
+    pub fn test_write_consensus()
+    {
+        let mut res_map1:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map1.insert("T1".to_string(), (0,5)); 
+        res_map1.insert("T2".to_string(), (5,8)); 
+        let mut res_map2:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map2.insert("T1".to_string(), (0,2)); 
+        let seq_tape1=SequenceTape::new("MKLATMEN".to_string(), res_map1).unwrap();
+        let seq_tape2=SequenceTape::new("MK".to_string(), res_map2).unwrap(); 
+        let personalized_proteome=PersonalizedGenome::new("consensus_test".to_string(), seq_tape1, seq_tape2); 
+        let mut ref_map=HashMap::new();
+        ref_map.insert("T1".to_string(), "MKLAV".to_string()); 
+        ref_map.insert("T2".to_string(), "MEK".to_string()); 
+        ref_map.insert("T3".to_string(), "MKV".to_string()); 
+        let out_dir=std::env::temp_dir().to_str().unwrap().to_string(); 
+        let options=WriterOptions{write_all:true,..WriterOptions::default()}; 
+        assert_eq!(personalized_proteome.write_consensus(&out_dir,&ref_map,&0,&ConsensusRule::Ambiguity,&options).unwrap(),0);
+        let written=std::fs::read_to_string(format!("{}/consensus_test.consensus.fasta",out_dir)).unwrap(); 
+        assert_eq!(written,">T1 haplotype=2 rule=ambiguity\nMK\n>T2 haplotype=ambiguous rule=ambiguity\nMEX\n>T3 haplotype=reference rule=ambiguity\nMKV\n"); 
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+// load the modules and crates 
+use std::collections::HashMap;
+use super::{engines::Engine, haplotype_instruction::HaplotypeInstruction, transcript_instructions::{OverlapPolicy,OverlapSummary}, variant_feature::VariantFeature};
+use crate::data_structures::Map::IntMap;
+use crate::functions::diagnostics::DiagnosticOptions;
+use serde::{Deserialize, Serialize};
+/// A generic representation for all instruction in a proband with two haplotypes
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct ProbandInstruction
+{
+    pub proband_name:String, 
+    pub haplotype1_instruction:HaplotypeInstruction, 
+    pub haplotype2_instruction:HaplotypeInstruction
+}
+impl ProbandInstruction
+{
+    /// ## Summary 
+    /// Create a new instance from a proband_name and two haplotypes 
+    pub fn new(proband_name:String, haplotype1_instruction:HaplotypeInstruction, 
+    haplotype2_instruction:HaplotypeInstruction)->Self
+    {
+        ProbandInstruction{proband_name,haplotype1_instruction,haplotype2_instruction}
+    }
+    /// ## Summary 
+    /// Create a new instance from an int map, an engine and a reference sequence
+    pub fn from_intmap(int_map:IntMap, engine:Engine, ref_seq:&HashMap<String,String>)->Self
+    {
+        ProbandInstruction::from_intmap_with_policies(int_map, engine, ref_seq, None, &DiagnosticOptions::default()).0
+    }
+    /// ## Summary 
+    /// Similar to from_intmap, however, the overlapping mutations of the transcripts are resolved with the provided strategy, if any, or 
+    /// checked with the diagnostics, and the resolutions summed over both haplotypes are returned as well, see 
+    /// HaplotypeInstruction::from_vec_t_ins_with_policies 
+    pub fn from_intmap_with_policies(int_map:IntMap, engine:Engine, ref_seq:&HashMap<String,String>, overlap_policy:Option<OverlapPolicy>, 
+        diagnostic_options:&DiagnosticOptions)->(Self,OverlapSummary)
+    {
+        let proband_name=int_map.proband_name.clone();
+        let (haplo1_vec,haplo2_vec)=int_map.consume_and_get_vecs(); 
+        let (h1_t_ins,mut summary)= HaplotypeInstruction::from_vec_t_ins_with_policies(haplo1_vec, engine.clone(),ref_seq,overlap_policy,diagnostic_options); 
+        let (h2_t_ins,summary2)= HaplotypeInstruction::from_vec_t_ins_with_policies(haplo2_vec, engine.clone(),ref_seq,overlap_policy,diagnostic_options);  
+        summary.merge(&summary2); 
+        (ProbandInstruction::new(proband_name, h1_t_ins, h2_t_ins),summary)
+    }
+    /// ## Summary 
+    /// Return the applied variants of the first and the second haplotype projected onto the coordinates of the personalized sequences
+    pub fn get_variant_features(&self, ref_seq:&HashMap<String,String>, engine:Engine)->(Vec<VariantFeature>,Vec<VariantFeature>)
+    {
+        (self.haplotype1_instruction.get_variant_features(ref_seq, engine.clone()),
+        self.haplotype2_instruction.get_variant_features(ref_seq, engine))
+    }
+}
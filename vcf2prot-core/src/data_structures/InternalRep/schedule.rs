@@ -0,0 +1,106 @@
+// load the modules and crates
+use super::engines::Engine;
+
+/// ## Summary
+/// The number of chunks each worker thread should receive on average, more chunks improve the load balancing between the threads
+/// at the cost of a higher scheduling overhead.
+pub const CHUNKS_PER_THREAD:usize=4;
+/// ## Summary
+/// The minimum number of altered transcripts in a cohort to use a parallel execution, smaller workloads are executed with a single thread
+/// as the overhead of scheduling is larger than the gain.
+pub const MIN_PARALLEL_WORKLOAD:usize=64;
+
+/// ## Summary
+/// A two-level execution schedule, the outer level distributes the samples among the worker threads, while the inner level
+/// distributes the transcripts and the tasks of each sample. The schedule is adapted to the workload, i.e. the number of samples
+/// and the number of altered transcripts per sample:
+/// 1. A large cohort, i.e. at least two samples per thread, is only parallelized over the samples and each sample is executed with a single thread.
+/// 2. A small cohort with a large number of transcripts per sample is parallelized over the samples and over the transcripts of each sample.
+/// 3. A small workload is executed with a single thread.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::{engines::Engine,schedule::Schedule};
+/// let schedule=Schedule::adaptive(&[5000;2], 8, &Engine::MT);
+/// assert!(matches!(schedule.inner_engine,Engine::MT));
+/// let schedule=Schedule::adaptive(&[50;2000], 8, &Engine::MT);
+/// assert!(matches!(schedule.inner_engine,Engine::ST));
+/// assert_eq!(schedule.outer_min_len,62);
+///```
+#[derive(Debug,Clone)]
+pub struct Schedule
+{
+    pub outer_engine:Engine,
+    pub outer_min_len:usize,
+    pub inner_engine:Engine
+}
+impl Schedule
+{
+    /// ## Summary
+    /// Create a schedule from the number of altered transcripts of each sample, the number of worker threads and the requested engine,
+    /// a single-threaded engine always results in a single-threaded schedule while a GPU engine keeps the GPU as the inner engine
+    pub fn adaptive(workload_per_sample:&[usize], num_threads:usize, engine:&Engine)->Self
+    {
+        let num_samples=workload_per_sample.len();
+        let total_workload=workload_per_sample.iter().sum::<usize>();
+        let num_threads=std::cmp::max(num_threads,1);
+        match engine
+        {
+            Engine::ST=>Schedule::single_threaded(num_samples),
+            Engine::GPU=>Schedule{outer_engine:Engine::MT,outer_min_len:1,inner_engine:Engine::GPU},
+            Engine::MT | Engine::Auto=>
+            {
+                if total_workload < MIN_PARALLEL_WORKLOAD || num_threads==1
+                {
+                    Schedule::single_threaded(num_samples)
+                }
+                else if num_samples >= 2*num_threads
+                {
+                    // enough samples to keep all threads busy, avoid the nested scheduling overhead
+                    Schedule{outer_engine:Engine::MT,outer_min_len:std::cmp::max(1,num_samples/(num_threads*CHUNKS_PER_THREAD)),inner_engine:Engine::ST}
+                }
+                else
+                {
+                    // few samples, each sample is split among the idle threads
+                    Schedule{outer_engine:Engine::MT,outer_min_len:1,inner_engine:Engine::MT}
+                }
+            }
+        }
+    }
+    /// ## Summary
+    /// A schedule where the samples and their transcripts are processed sequentially
+    fn single_threaded(num_samples:usize)->Self
+    {
+        Schedule{outer_engine:Engine::ST,outer_min_len:std::cmp::max(num_samples,1),inner_engine:Engine::ST}
+    }
+}
+/// ## Summary
+/// Return the minimum number of items per chunk when a collection of num_items items is split among the worker threads of
+/// the current thread pool, this is used to adapt the granularity of the inner level of the schedule to the workload size.
+pub fn inner_min_len(num_items:usize)->usize
+{
+    std::cmp::max(1,num_items/(rayon::current_num_threads()*CHUNKS_PER_THREAD))
+}
+
+#[cfg(test)]
+mod test_schedule
+{
+    use super::*;
+    #[test]
+    fn test_adaptive_schedule()
+    {
+        // a single sample with a large proteome is split over the transcripts
+        let schedule=Schedule::adaptive(&[20_000], 16, &Engine::MT);
+        assert!(matches!(schedule.outer_engine,Engine::MT));
+        assert!(matches!(schedule.inner_engine,Engine::MT));
+        // a large cohort with a small panel is split over the samples only
+        let schedule=Schedule::adaptive(&[100;320], 16, &Engine::MT);
+        assert!(matches!(schedule.inner_engine,Engine::ST));
+        assert_eq!(schedule.outer_min_len,5);
+        // small workloads and single threaded engines are executed sequentially
+        let schedule=Schedule::adaptive(&[10;3], 16, &Engine::MT);
+        assert!(matches!(schedule.outer_engine,Engine::ST));
+        let schedule=Schedule::adaptive(&[20_000;3], 16, &Engine::ST);
+        assert!(matches!(schedule.inner_engine,Engine::ST));
+        assert_eq!(schedule.outer_min_len,3);
+    }
+}
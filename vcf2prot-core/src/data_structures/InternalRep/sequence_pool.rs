@@ -0,0 +1,324 @@
+use std::collections::{HashMap,HashSet};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use rayon::prelude::*;
+use super::engines::Engine;
+use super::personalized_genome::PersonalizedGenome;
+use super::sequence_tape::StopCodonPolicy;
+use crate::writers::{append_custom_records,create_file,WriterOptions};
+
+/// The stem of the fasta file holding the pooled sequences of a cohort
+pub const POOLED_FASTA_FILE_STEM:&str="pooled_sequences";
+/// The name of the sidecar file mapping each pooled sequence to the haplotypes carrying it
+pub const POOL_MEMBERS_FILE_NAME:&str="pooled_sequences.members.tsv";
+/// A unique sequence of the pool, i.e. the transcript id, the sequence and the proband name and haplotype index of each member
+type PooledSequence<'a>=(&'a String,&'a String,Vec<(String,u8)>);
+
+/// ## Summary
+/// Where the members of a pooled sequence, i.e. the proband and the haplotype of each carrier, are listed:
+/// 1. Sidecar => in a tab-separated file, pooled_sequences.members.tsv, with one member per line, while the header only has the number of members, the default.
+/// 2. Header => in the header of the record, e.g. "ENST00000406869_pool1 members=2 ids=HG00096_1,HG00097_2", which grows with the number of members.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum PoolMembers
+{
+    Sidecar,
+    Header
+}
+impl Default for PoolMembers
+{
+    fn default()->Self
+    {
+        PoolMembers::Sidecar
+    }
+}
+impl PoolMembers
+{
+    /// ## Summary
+    /// Return the name of the option as used on the command line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            PoolMembers::Sidecar=>"sidecar",
+            PoolMembers::Header=>"header"
+        }
+    }
+}
+impl FromStr for PoolMembers
+{
+    type Err=String;
+    fn from_str(members:&str)->Result<PoolMembers,String>
+    {
+        match members.to_lowercase().as_str()
+        {
+            "sidecar" | "tsv"=>Ok(PoolMembers::Sidecar),
+            "header"=>Ok(PoolMembers::Header),
+            _=>Err(format!("{} is not a supported way of listing the members of the pooled sequences, supported values are: sidecar and header",members))
+        }
+    }
+}
+/// ## Summary
+/// A pool of the altered sequences of a collection of personalized genomes, where each unique sequence of a transcript is stored once along with
+/// its members, i.e. the proband name and the haplotype index of every haplotype carrying it. In contrast to the cohort aggregate, the members are
+/// kept, hence, the per-sample fasta files can be replaced by a single file without losing the origin of a sequence.
+#[derive(Debug,Clone,Default)]
+pub struct SequencePool
+{
+    num_probands:u64,
+    members_per_sequence:HashMap<String,HashMap<String,Vec<(String,u8)>>>
+}
+/// ## Summary
+/// A summary of the written pool, i.e. the number of probands, the number of haplotype sequences that were pooled, the number of unique
+/// sequences and the number of written sequences, where unique sequences shorter than the minimum length are dropped
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct PoolSummary
+{
+    pub num_probands:u64,
+    pub num_pooled_sequences:u64,
+    pub num_unique_sequences:u64,
+    pub num_written_sequences:u64,
+    pub num_dropped_sequences:u64
+}
+impl SequencePool
+{
+    /// ## Summary
+    /// create a new empty pool
+    pub fn new()->Self
+    {
+        SequencePool::default()
+    }
+    /// ## Summary
+    /// pool the altered sequences of a collection of personalized genomes using the provided engine
+    pub fn from_genomes(vec_genomes:&[PersonalizedGenome], engine:Engine)->Self
+    {
+        match engine
+        {
+            Engine::ST=>
+            {
+                let mut pool=SequencePool::new();
+                vec_genomes.iter().for_each(|genome|pool.add_genome(genome));
+                pool
+            },
+            Engine::MT | Engine::GPU | Engine::Auto=>
+            {
+                vec_genomes.par_iter()
+                .fold(SequencePool::new,|mut pool,genome|{pool.add_genome(genome); pool})
+                .reduce(SequencePool::new,SequencePool::merge)
+            }
+        }
+    }
+    /// ## Summary
+    /// add the altered sequences of both haplotypes of a personalized genome to the pool
+    pub fn add_genome(&mut self, genome:&PersonalizedGenome)
+    {
+        self.num_probands+=1;
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+        for (haplotype,seq_tape) in [(1,seq_tape1),(2,seq_tape2)].iter()
+        {
+            for transcript in seq_tape.get_annotation().keys()
+            {
+                let seq=match seq_tape.get_seq(transcript)
+                {
+                    Ok(seq)=>seq,
+                    Err(_)=>continue
+                };
+                let members=self.members_per_sequence.entry(transcript.clone()).or_default();
+                match members.get_mut(seq)
+                {
+                    Some(members)=>members.push((genome.get_proband_name().clone(),*haplotype)),
+                    None=>{members.insert(seq.to_string(),vec![(genome.get_proband_name().clone(),*haplotype)]);}
+                }
+            }
+        }
+    }
+    /// ## Summary
+    /// merge two pools of disjoint sets of probands
+    pub fn merge(mut self, other:SequencePool)->Self
+    {
+        self.num_probands+=other.num_probands;
+        for (transcript,sequences) in other.members_per_sequence
+        {
+            let members_per_sequence=self.members_per_sequence.entry(transcript).or_default();
+            for (seq,members) in sequences
+            {
+                members_per_sequence.entry(seq).or_default().extend(members);
+            }
+        }
+        self
+    }
+    /// ## Summary
+    /// return the number of pooled probands
+    pub fn get_num_probands(&self)->u64
+    {
+        self.num_probands
+    }
+    /// ## Summary
+    /// return the unique sequences of the pool as a vector of transcript id, sequence and members, sorted by transcript id, then by decreasing
+    /// number of members and then by sequence, where the members of each sequence are sorted by proband name and haplotype
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::InternalRep::{sequence_tape::SequenceTape,personalized_genome::PersonalizedGenome,sequence_pool::SequencePool};
+    /// use std::collections::HashMap;
+    /// let mut annotations=HashMap::new();
+    /// annotations.insert("T1".to_string(),(0,3));
+    /// let genome=|name:&str|PersonalizedGenome::new(name.to_string(),SequenceTape::new("MKV".to_string(),annotations.clone()).unwrap(),
+    ///     SequenceTape::new(String::new(),HashMap::new()).unwrap());
+    /// let mut pool=SequencePool::new();
+    /// pool.add_genome(&genome("proband_2"));
+    /// pool.add_genome(&genome("proband_1"));
+    /// let members=vec![("proband_1".to_string(),1),("proband_2".to_string(),1)];
+    /// assert_eq!(pool.get_unique_sequences(),vec![(&"T1".to_string(),&"MKV".to_string(),members)]);
+    ///```
+    pub fn get_unique_sequences(&self)->Vec<PooledSequence<'_>>
+    {
+        let mut unique_sequences=self.members_per_sequence.iter()
+            .flat_map(|(transcript,sequences)|sequences.iter().map(move |(seq,members)|
+            {
+                let mut members=members.clone();
+                members.sort_unstable();
+                (transcript,seq,members)
+            }))
+            .collect::<Vec<PooledSequence>>();
+        unique_sequences.sort_unstable_by(|(transcript1,seq1,members1),(transcript2,seq2,members2)|
+            transcript1.cmp(transcript2).then(members2.len().cmp(&members1.len())).then(seq1.cmp(seq2)));
+        unique_sequences
+    }
+    /// ## Summary
+    /// write the unique sequences of the pool to a fasta file named pooled_sequences.fasta, the header of each record contains the transcript id
+    /// followed by the rank of the sequence among the sequences of the transcript and the number of members, e.g. "ENST00000406869_pool1 members=12",
+    /// the members are either appended to the header or written to pooled_sequences.members.tsv, see PoolMembers. Sequences shorter than min_length
+    /// are dropped and the custom records, e.g. contaminants, are appended after the pooled sequences, see writers::append_custom_records.
+    pub fn write_pooled_sequences(&self, outdir:&String, write_compressed:&bool, min_length:&usize, members:PoolMembers,
+        custom_records:&[(String,String)], options:&WriterOptions)->Result<PoolSummary,String>
+    {
+        options.output_policy.check_per_sample("the pooled sequences")?;
+        let res_string=format!("{}/{}",outdir,options.compression_policy.get_fasta_name(POOLED_FASTA_FILE_STEM,*write_compressed));
+        let res_path=Path::new(&res_string);
+        let file_handle=match create_file(res_path, &options.retry_policy)
+        {
+            Ok(file)=>file,
+            Err(err_msg)=>return Err(format!("Could not create {} because {}",res_path.display(),err_msg))
+        };
+        let mut writer:Box<dyn Write>=match write_compressed
+        {
+            true=>options.compression_policy.create_encoder(file_handle),
+            false=>Box::new(std::io::BufWriter::new(file_handle))
+        };
+        let unique_sequences=self.get_unique_sequences();
+        let mut member_lines=vec!["record\tproband\thaplotype".to_string()];
+        let mut num_dropped=0;
+        let mut rank=0;
+        let mut written_ids=Vec::with_capacity(unique_sequences.len());
+        let mut written_seqs=HashSet::new();
+        for (index,(transcript,seq,carriers)) in unique_sequences.iter().enumerate()
+        {
+            rank=match index
+            {
+                0=>1,
+                _ if unique_sequences[index-1].0==*transcript=>rank+1,
+                _=>1
+            };
+            let written_seq=StopCodonPolicy::apply_optional(options.stop_codon_policy, seq);
+            if written_seq.len() < *min_length
+            {
+                num_dropped+=1;
+                continue
+            }
+            let record_id=format!("{}_pool{}",transcript,rank);
+            let header=match members
+            {
+                PoolMembers::Header=>format!("{} members={} ids={}",record_id,carriers.len(),
+                    carriers.iter().map(|(proband_name,haplotype)|format!("{}_{}",proband_name,haplotype)).collect::<Vec<String>>().join(",")),
+                PoolMembers::Sidecar=>
+                {
+                    member_lines.extend(carriers.iter().map(|(proband_name,haplotype)|format!("{}\t{}\t{}",record_id,proband_name,haplotype)));
+                    format!("{} members={}",record_id,carriers.len())
+                }
+            };
+            if let Err(err_msg)=write!(writer,">{}\n{}\n",header,written_seq)
+            {
+                return Err(format!("Writing the sequence of: {} failed because {}",record_id,err_msg))
+            }
+            written_ids.push(record_id);
+            written_seqs.insert(seq.as_str());
+        }
+        if !custom_records.is_empty()
+        {
+            let written_ids=written_ids.iter().map(|id|id.as_str()).collect::<HashSet<&str>>();
+            append_custom_records(&mut writer, custom_records, &written_ids, &written_seqs)?;
+        }
+        if let Err(err_msg)=writer.flush()
+        {
+            return Err(format!("Writing the pooled sequences to: {} failed because {}",res_path.display(),err_msg))
+        }
+        if members==PoolMembers::Sidecar
+        {
+            let path2members=Path::new(outdir).join(POOL_MEMBERS_FILE_NAME);
+            let mut file_handle=match create_file(&path2members, &options.retry_policy)
+            {
+                Ok(file)=>std::io::BufWriter::new(file),
+                Err(err_msg)=>return Err(format!("Could not create {} because {}",path2members.display(),err_msg))
+            };
+            if let Err(err_msg)=writeln!(file_handle,"{}",member_lines.join("\n")).and_then(|_|file_handle.flush())
+            {
+                return Err(format!("Writing the members of the pooled sequences to: {} failed because {}",path2members.display(),err_msg))
+            }
+        }
+        Ok(PoolSummary
+        {
+            num_probands:self.num_probands,
+            num_pooled_sequences:unique_sequences.iter().map(|(_,_,carriers)|carriers.len() as u64).sum::<u64>(),
+            num_unique_sequences:unique_sequences.len() as u64,
+            num_written_sequences:unique_sequences.len() as u64-num_dropped,
+            num_dropped_sequences:num_dropped
+        })
+    }
+}
+#[cfg(test)]
+mod test_sequence_pool
+{
+    use super::*;
+    use super::super::sequence_tape::SequenceTape;
+    fn get_genome(name:&str, haplotype1:&[(&str,&str)], haplotype2:&[(&str,&str)])->PersonalizedGenome
+    {
+        let get_tape=|haplotype:&[(&str,&str)]|
+        {
+            let mut seq_str=String::new();
+            let mut annotations=HashMap::new();
+            for (transcript,seq) in haplotype.iter()
+            {
+                annotations.insert(transcript.to_string(),(seq_str.len(),seq_str.len()+seq.len()));
+                seq_str.push_str(seq);
+            }
+            SequenceTape::new(seq_str,annotations).unwrap()
+        };
+        PersonalizedGenome::new(name.to_string(),get_tape(haplotype1),get_tape(haplotype2))
+    }
+    #[test]
+    fn test_write_pooled_sequences()
+    {
+        let cohort=vec![
+            get_genome("proband_1",&[("T1","MKV"),("T2","MEK")],&[("T1","MKV")]),
+            get_genome("proband_2",&[("T1","MKL")],&[("T1","MKV")]),
+        ];
+        let pool=SequencePool::from_genomes(&cohort,Engine::MT);
+        assert_eq!(pool.get_num_probands(),2);
+        let mut out_dir=std::env::temp_dir();
+        out_dir.push("sequence_pool_test");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let out_dir=out_dir.to_str().unwrap().to_string();
+        let summary=pool.write_pooled_sequences(&out_dir,&false,&0,PoolMembers::Header,&[],&WriterOptions::default()).unwrap();
+        let written=std::fs::read_to_string(format!("{}/pooled_sequences.fasta",out_dir)).unwrap();
+        assert_eq!(written,">T1_pool1 members=3 ids=proband_1_1,proband_1_2,proband_2_2\nMKV\n>T1_pool2 members=1 ids=proband_2_1\nMKL\n\
+            >T2_pool1 members=1 ids=proband_1_1\nMEK\n");
+        assert_eq!(summary,PoolSummary{num_probands:2,num_pooled_sequences:5,num_unique_sequences:3,num_written_sequences:3,num_dropped_sequences:0});
+        // with the sidecar the members are listed in a separate file
+        pool.write_pooled_sequences(&out_dir,&false,&0,PoolMembers::Sidecar,&[],&WriterOptions::default()).unwrap();
+        let written=std::fs::read_to_string(format!("{}/pooled_sequences.fasta",out_dir)).unwrap();
+        assert!(written.starts_with(">T1_pool1 members=3\nMKV\n"));
+        let members=std::fs::read_to_string(format!("{}/{}",out_dir,POOL_MEMBERS_FILE_NAME)).unwrap();
+        assert_eq!(members.lines().nth(1),Some("T1_pool1\tproband_1\t1"));
+        assert_eq!(members.lines().count(),6);
+    }
+}
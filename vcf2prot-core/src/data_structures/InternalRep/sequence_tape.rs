@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+use std::collections::HashMap; 
+use std::panic; 
+use std::str::FromStr;
+#[cfg(feature = "writers")]
+use std::path::Path; 
+#[cfg(feature = "writers")]
+use std::io::Write;
+#[cfg(feature = "writers")]
+use crate::writers::{create_output_writer,flush_output,WriterOptions};
+/// ## Summary
+/// The representation of the stop codons in the written sequences, as downstream tools expect different conventions. The stop gained 
+/// instructions, i.e. G and X, truncate the sequence before the stop, while the instructions whose alternative sequence ends with a stop, 
+/// e.g. the frameshifts (F) or the stop losses (L), drop the trailing '*', hence, the altered sequences are produced without a trailing 
+/// '*' while the reference sequences are copied as provided. The policy is applied to every written sequence, so the sequences follow the 
+/// same convention whatever instruction produced them, the policy is passed to the writers through WriterOptions: 
+/// 1. Keep, each sequence ends with a single '*' representing its stop codon, internal stops are kept 
+/// 2. Strip, the trailing '*' of each sequence is removed, internal stops are kept 
+/// 3. Truncate, each sequence is truncated before its first stop, i.e. it holds no '*' 
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::sequence_tape::StopCodonPolicy; 
+/// assert_eq!(StopCodonPolicy::Keep.apply("MK*V"),"MK*V*"); 
+/// assert_eq!(StopCodonPolicy::Strip.apply("MK*V*"),"MK*V"); 
+/// assert_eq!(StopCodonPolicy::Truncate.apply("MK*V*"),"MK"); 
+///```
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum StopCodonPolicy
+{
+    Keep=1,
+    Strip=2,
+    Truncate=3
+}
+impl StopCodonPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            StopCodonPolicy::Keep=>"keep",
+            StopCodonPolicy::Strip=>"strip",
+            StopCodonPolicy::Truncate=>"truncate"
+        }
+    }
+    /// ## Summary
+    /// Return the sequence following the convention of the policy, the sequence is only copied if a stop is appended 
+    pub fn apply<'a>(&self, seq:&'a str)->Cow<'a,str>
+    {
+        match self
+        {
+            StopCodonPolicy::Keep if seq.ends_with('*')=>Cow::Borrowed(seq),
+            StopCodonPolicy::Keep=>Cow::Owned(format!("{}*",seq)),
+            StopCodonPolicy::Strip=>Cow::Borrowed(seq.trim_end_matches('*')),
+            StopCodonPolicy::Truncate=>Cow::Borrowed(seq.split('*').next().unwrap_or(""))
+        }
+    }
+    /// ## Summary
+    /// Return the sequence following the convention of the provided policy, see apply, or the sequence as produced if no policy is provided 
+    pub fn apply_optional(policy:Option<StopCodonPolicy>, seq:&str)->Cow<'_,str>
+    {
+        match policy
+        {
+            Some(policy)=>policy.apply(seq),
+            None=>Cow::Borrowed(seq)
+        }
+    }
+}
+impl FromStr for StopCodonPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<StopCodonPolicy,String>
+    {
+        match policy.to_lowercase().as_str()
+        {
+            "keep"=>Ok(StopCodonPolicy::Keep),
+            "strip"=>Ok(StopCodonPolicy::Strip),
+            "truncate"=>Ok(StopCodonPolicy::Truncate),
+            _=>Err(format!("{} is not a supported stop codon convention, supported conventions are: keep, strip and truncate",policy))
+        }
+    }
+}
+/// An abstraction for a sequence tape, where more than one sequence are annotated in an head to tail fashion 
+/// and a has map that stores the sequence name and the boundries, i.e. the start and the end point in the sequence
+/// are stored. 
+#[derive(Debug,Clone)]
+pub struct SequenceTape
+{
+    seq_str:String,
+    annotations:HashMap<String,(usize,usize)>
+}
+
+impl SequenceTape
+{
+    /// create a new sequence map from a seuqnece tape and an annotation hash map 
+    /// ## Example 
+    ///``` 
+    /// use ppgg_rust::data_structures::InternalRep::sequence_tape::SequenceTape; 
+    /// use ppgg_rust::writers::WriterOptions; 
+    /// use std::collections::HashMap; 
+    /// let code_string="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+    /// let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+    /// res_map.insert("1".to_string(), (0,4)); 
+    /// res_map.insert("2".to_string(), (5,9)); 
+    /// res_map.insert("3".to_string(), (10,14)); 
+    /// let seq_tape=SequenceTape::new(code_string, res_map).unwrap(); // this panic incase of length mismatch 
+    /// //check the correct mapping between the annotations
+    /// assert_eq!("SEQ1",seq_tape.get_seq(&"1".to_string()).unwrap()); 
+    /// assert_eq!("SEQ2",seq_tape.get_seq(&"2".to_string()).unwrap());
+    /// assert_eq!("SEQ3",seq_tape.get_seq(&"3".to_string()).unwrap());
+    ///``` 
+    pub fn new(seq_str:String,annotations:HashMap<String,(usize,usize)>)->Result<Self,String>
+    {
+        let max_index=SequenceTape::get_max_index(&annotations); 
+        if max_index>seq_str.len()
+        {
+            return Err(format!("Bad Tape Encountered, the provided maximum index is {} while tape length is {} ",max_index,seq_str.len())); 
+        }
+        Ok(SequenceTape{seq_str,annotations})
+    }
+    #[cfg(feature = "writers")]
+    /// Write the sequence tap to a fasta file on disk, an error is returned in the aggregate-only mode as a tape holds the haplotype of a single sample. 
+    /// A file whose name ends with the extension of the compression format, e.g. .gz or .zst, is compressed with the compression policy of 
+    /// the writer layer, see writers::CompressionPolicy 
+    /// ## Example 
+    ///``` 
+    /// use std::path::Path;
+    /// use ppgg_rust::data_structures::InternalRep::sequence_tape::SequenceTape; 
+    /// use std::collections::HashMap; 
+    /// let code_string="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+    /// let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+    /// res_map.insert("1".to_string(), (0,4)); 
+    /// res_map.insert("2".to_string(), (5,9)); 
+    /// res_map.insert("3".to_string(), (10,14)); 
+    /// let seq_tape=SequenceTape::new(code_string, res_map).unwrap(); // this panic incase of length mismatch 
+    /// seq_tape.write_to_fasta(Path::new("test_data/test_file.fasta"),&WriterOptions::default()).unwrap();
+    ///``` 
+    pub fn write_to_fasta(&self,output_file_name:&Path,options:&WriterOptions)->Result<(),String>
+    {
+        options.output_policy.check_per_sample("a sequence tape")?; 
+        let write_compressed=output_file_name.extension().map_or(false,|extension|extension==options.compression_policy.format.get_extension()); 
+        let expected_size=self.annotations.iter().map(|(key,(start,end))|key.len()+end.saturating_sub(*start)+3).sum::<usize>(); 
+        let mut writer=create_output_writer(output_file_name, expected_size, write_compressed, options)?; 
+        for key in self.get_ordered_keys()
+        {
+            if let Err(err_msg)=write!(&mut writer,">{}\n{}\n", key, StopCodonPolicy::apply_optional(options.stop_codon_policy, self.get_seq(key).unwrap()))
+            {
+                return Err(format!("Writing the sequence of: {} to {} failed because {}",key,output_file_name.display(),err_msg))
+            }
+        }
+        flush_output(&mut writer, &output_file_name.display().to_string())
+    }
+    /// ## Summary 
+    /// return the hash map containing the annotation hash map 
+    pub fn get_annotation(&self)->&HashMap<String,(usize,usize)>
+    {
+        &self.annotations
+    }
+    /// ## Summary 
+    /// return the names of the sequences in the order of their positions in the tape, i.e. the order in which the execution engine laid 
+    /// them out, where sequences starting at the same position, e.g. empty sequences, are ordered by name, hence, the order does not 
+    /// depend on the iteration order of the annotation hash map and is identical between runs 
+    /// ## Example 
+    ///``` 
+    /// use ppgg::data_structures::InternalRep::sequence_tape::SequenceTape; 
+    /// use std::collections::HashMap; 
+    /// let annotations=vec![("T3".to_string(),(4,4)),("T1".to_string(),(4,6)),("T2".to_string(),(0,4))].into_iter().collect::<HashMap<String,(usize,usize)>>(); 
+    /// let seq_tape=SequenceTape::new("MKLVMA".to_string(), annotations).unwrap(); 
+    /// assert_eq!(seq_tape.get_ordered_keys(),vec!["T2","T1","T3"]); 
+    ///``` 
+    pub fn get_ordered_keys(&self)->Vec<&String>
+    {
+        let mut keys=self.annotations.iter().collect::<Vec<(&String,&(usize,usize))>>(); 
+        keys.sort_unstable_by(|(key1,(start1,_)),(key2,(start2,_))|(start1,key1).cmp(&(start2,key2))); 
+        keys.into_iter().map(|(key,_)|key).collect()
+    }
+    /// ## Summary 
+    /// return the sequence corresponding to the provided sequence name 
+    pub fn get_seq(&self,seq_name:&String)->Result<&str,String>
+    {
+        let res=match self.annotations.get(seq_name) {
+            Some(res)=>res,
+            None=>return Err(format!("The provided sequence name: {}, is not defined in the current table",seq_name))
+        };
+        let res_string=match panic::catch_unwind(||&self.seq_str[res.0..res.1])
+        {
+            Ok(res)=>res,
+            Err(why)=>panic!("The following error was encountered: {:?} \n, while the sequence is: {}\n",why,&self.seq_str), 
+        };
+        Ok(res_string)
+    }
+    /// return the sequence corresponding to the maximum index of the tape
+    /// ## Example 
+    ///``` 
+    /// use std::collections::HashMap;  
+    /// use ppgg_rust::data_structures::InternalRep::sequence_tape::SequenceTape; 
+    /// let code_string="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+    /// let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+    /// res_map.insert("1".to_string(), (0,4)); 
+    /// res_map.insert("2".to_string(), (5,9)); 
+    /// res_map.insert("3".to_string(), (10,14)); 
+    /// res_map.insert("4".to_string(), (15,19)); 
+    /// res_map.insert("5".to_string(), (20,24)); 
+    /// res_map.insert("6".to_string(), (25,29)); 
+    /// let seq_tape=SequenceTape::new(code_string, res_map).unwrap(); 
+    /// assert_eq!(SequenceTape::get_max_index(seq_tape.get_annotation()),29);
+    ///``` 
+    pub fn get_max_index(annotation:&HashMap<String,(usize,usize)>)->usize
+    {
+        let mut max=0; 
+        for (_,value) in annotation.iter()
+        {
+            if value.1 >max
+            {
+                max=value.1
+            }
+        }
+        max
+    }
+}
+#[cfg(test)]
+pub mod test_sequence_tape_module
+{
+    use std::usize;
+    use super::*;
+    #[test]
+    pub fn test_sequence_tape()
+    {
+        let code_string="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("1".to_string(), (0,4)); 
+        res_map.insert("2".to_string(), (5,9)); 
+        res_map.insert("3".to_string(), (10,14)); 
+        res_map.insert("4".to_string(), (15,19)); 
+        res_map.insert("5".to_string(), (20,24)); 
+        res_map.insert("6".to_string(), (25,29)); 
+        let seq_tape=SequenceTape::new(code_string, res_map).unwrap(); 
+        // check the correct mapping between the annotations
+        assert_eq!("SEQ1",seq_tape.get_seq(&"1".to_string()).unwrap()); 
+        assert_eq!("SEQ2",seq_tape.get_seq(&"2".to_string()).unwrap());
+        assert_eq!("SEQ3",seq_tape.get_seq(&"3".to_string()).unwrap());
+        assert_eq!("SEQ4",seq_tape.get_seq(&"4".to_string()).unwrap());
+        assert_eq!("SEQ5",seq_tape.get_seq(&"5".to_string()).unwrap());
+        assert_eq!("SEQ6",seq_tape.get_seq(&"6".to_string()).unwrap());
+    }
+    #[test]
+    #[should_panic]
+    pub fn test_sequence_tape2()
+    {
+        let code_string="SEQ1_SEQ2_SEQ3".to_string(); 
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("1".to_string(), (0,4)); 
+        res_map.insert("2".to_string(), (5,9)); 
+        res_map.insert("3".to_string(), (10,code_string.len()+1)); 
+       
+        SequenceTape::new(code_string, res_map).unwrap(); 
+    }
+    #[test]
+    pub fn test_fasta_write()
+    {
+        let code_string="SEQ1_SEQ2_SEQ3_SEQ4_SEQ5_SEQ6".to_string(); 
+        let mut res_map:HashMap<String,(usize,usize)>=HashMap::new();
+        res_map.insert("1".to_string(), (0,4)); 
+        res_map.insert("2".to_string(), (5,9)); 
+        res_map.insert("3".to_string(), (10,14)); 
+        res_map.insert("4".to_string(), (15,19)); 
+        res_map.insert("5".to_string(), (20,24)); 
+        res_map.insert("6".to_string(), (25,29)); 
+        let seq_tape=SequenceTape::new(code_string, res_map).unwrap(); 
+        seq_tape.write_to_fasta(Path::new("test_data/test_file.fasta"),&WriterOptions::default()).unwrap();
+    }
+    #[test]
+    pub fn test_stop_codon_policy()
+    {
+        // a stop gained at the first residue leaves an empty sequence, which is only written as the stop with keep 
+        for (seq,expected) in [("MKV",["MKV*","MKV","MKV"]),("MK*V*",["MK*V*","MK*V","MK"]),("",["*","",""]),("**",["**","",""])].iter()
+        {
+            let applied=[StopCodonPolicy::Keep,StopCodonPolicy::Strip,StopCodonPolicy::Truncate].iter().map(|policy|policy.apply(seq).to_string()).collect::<Vec<String>>(); 
+            assert_eq!(applied,expected.to_vec()); 
+        }
+        assert_eq!(StopCodonPolicy::from_str("Truncate").unwrap().as_str(),"truncate"); 
+        assert!(StopCodonPolicy::from_str("remove").is_err()); 
+        assert_eq!(StopCodonPolicy::apply_optional(None,"MK*"),"MK*"); 
+        assert_eq!(StopCodonPolicy::apply_optional(Some(StopCodonPolicy::Strip),"MK*"),"MK"); 
+    }
+    
+}
@@ -0,0 +1,210 @@
+// use a caret to load the data 
+use crate::data_structures::InternalRep::coordinates::{RefPos0,Len};
+use crate::error::PpggError;
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct Task
+{
+    exe_code:u8,
+    start_pos:usize,
+    length:usize,
+    start_pos_res:usize,
+}
+impl Task 
+{
+    /// ## Summary 
+    /// Construct a new task 
+    pub fn new(exe_code:u8, start_pos:usize,length:usize,
+        start_pos_res:usize )->Self
+    {
+        Task{exe_code,start_pos,length,start_pos_res}
+    }
+    /// ## Summary 
+    /// Construct a task that copies len amino acids from the reference stream starting at start_pos into the results array at start_pos_res
+    pub fn copy_ref(start_pos:RefPos0, len:Len, start_pos_res:usize)->Self
+    {
+        Task{exe_code:0,start_pos:start_pos.get(),length:len.get(),start_pos_res}
+    }
+    /// ## Summary 
+    /// Construct a task that copies len amino acids from the alternative stream starting at start_pos into the results array at start_pos_res
+    pub fn copy_alt(start_pos:usize, len:Len, start_pos_res:usize)->Self
+    {
+        Task{exe_code:1,start_pos,length:len.get(),start_pos_res}
+    }
+    /// ## Summary 
+    /// Construct a phi task, i.e. an empty task that is dropped before execution 
+    pub fn phi()->Self
+    {
+        Task{exe_code:2,start_pos:0,length:0,start_pos_res:0}
+    }
+    /// ## Summary
+    /// Execute the task of the two input streams ans the resulting vector of chars 
+    /// ## Example  
+    ///```     
+    /// use ppgg_rust::data_structures::InternalRep::task::Task; 
+    /// let test_stream_ref="ABCFEFGH"
+    /// .chars()
+    /// .collect::<Vec<char>>(); 
+    /// let test_stream_alt=test_stream_ref.iter()
+    ///    .rev()
+    ///    .map(|c|c.clone())
+    ///    .collect::<Vec<char>>(); 
+    ///    let mut test_results=vec!['x';10];
+    /// let mut expected_res=vec!['x';10];
+    /// expected_res[8]='B'; 
+    /// let task=Task::new(0,1,1,8); 
+    /// task.execute(&mut test_results, &test_stream_ref, &test_stream_alt).unwrap();
+    /// assert_eq!(*test_results,*expected_res);
+    ///```
+    /// An error is returned if the task reads beyond the end of its input stream or writes beyond the end of the results tape
+    pub fn execute(&self, results_tape:&mut [char], ref_tape:&[char], alt_tape:&[char])->Result<(),PpggError>
+    {
+        let end_bound_res=self.start_pos_res+self.length;
+        let res_len=results_tape.len();
+        match results_tape.get_mut(self.start_pos_res..end_bound_res)
+        {
+            Some(results_slice)=>self.execute_into(results_slice, ref_tape, alt_tape),
+            None=>Err(PpggError::TaskOutOfBounds{start:self.start_pos_res,end:end_bound_res,len:res_len})
+        }
+    }
+    /// ## Summary
+    /// Execute the task into a slice of the results tape that starts at the task's start position in the results array,
+    /// i.e. the slice must have the length of the task, this is used to execute different tasks of the same tape in parallel.
+    /// An error is returned if the task reads beyond the end of its input stream or if the slice does not have the length of the task
+    pub fn execute_into(&self, results_slice:&mut [char], ref_tape:&[char], alt_tape:&[char])->Result<(),PpggError>
+    {
+        let end_bound_stream=self.start_pos+self.length;
+        let stream=match self.exe_code
+        {
+            0=>ref_tape,
+            _=>alt_tape
+        };
+        match stream.get(self.start_pos..end_bound_stream)
+        {
+            Some(source) if source.len()==results_slice.len()=>
+            {
+                results_slice.clone_from_slice(source);
+                Ok(())
+            },
+            Some(_)=>Err(PpggError::TaskOutOfBounds{start:0,end:self.length,len:results_slice.len()}),
+            None=>Err(PpggError::TaskOutOfBounds{start:self.start_pos,end:end_bound_stream,len:stream.len()})
+        }
+    }
+    /// ## Summary
+    /// get a mutable reference to the start position 
+    pub fn get_mut_start_pos(&mut self)->&mut usize
+    {
+        &mut self.start_pos
+    }
+    /// ## Summary
+    /// get a mutable reference to the instance's length 
+    pub fn get_mut_length(&mut self)->&mut usize
+    {
+        &mut self.length
+    }
+    /// ## Summary
+    ///  return the instance's length 
+    pub fn get_length(&self)->usize
+    {
+        self.length
+    }
+    /// ## Summary
+    ///  return the instance's start pos in the results array 
+    pub fn get_start_pos_res(&self)->usize
+    {
+        self.start_pos_res
+    }
+    /// ## Summary
+    ///  return a mutable reference to the start position in the results array 
+    pub fn get_mut_start_pos_res(&mut self)->&mut usize
+    {
+        &mut self.start_pos_res
+    }
+    /// ## Summary
+    ///  return the execution stream 
+    pub fn get_execution_stream(&self)->&u8
+    {
+        &self.exe_code
+    }
+    /// ## Summary
+    ///  return the execution stream 
+    #[inline]
+    pub fn get_stream(&self)->u8
+    {
+        self.exe_code
+    }
+    /// ## Summary
+    ///  return the start position in the input stream 
+    #[inline]
+    pub fn get_start_pos(self)->usize
+    {
+        self.start_pos
+    }
+    /// ## Summary
+    ///  shirt, i.e. change the start position in the stream 
+    pub fn shift_start_pos_stream(&mut self, num:&usize)
+    {
+        self.start_pos+=*num; 
+    }
+    /// ## Summary
+    ///  shirt, i.e. change the start position in the result array  
+    pub fn shift_start_pos_res(&mut self, num:&usize)
+    {
+        self.start_pos_res+=*num; 
+    }
+}
+#[cfg(test)]
+pub mod test_task
+{
+    use super::*; 
+    #[test]
+    fn test_execute()
+    {
+        let test_stream_ref="ABCFEFGH"
+                            .chars()
+                            .collect::<Vec<char>>(); 
+        let test_stream_alt=test_stream_ref.iter()
+                                .rev()
+                                .map(|c|c.clone())
+                                .collect::<Vec<char>>(); 
+        let mut test_results=vec!['x';10];
+        // define the input streams 
+        let task=Task::new(0,1,1,8); 
+        task.execute(&mut test_results, &test_stream_ref, &test_stream_alt).unwrap();
+        let mut expected_res=vec!['x';10];
+        expected_res[8]='B'; 
+        assert_eq!(*test_results,*expected_res);
+        let task2=Task::new(0,4,1,4); 
+        task2.execute(&mut test_results, &test_stream_ref, &test_stream_alt).unwrap();
+        expected_res[4]='E'; 
+        assert_eq!(*test_results,*expected_res);
+        let task3=Task::new(0,6,2,6); 
+        task3.execute(&mut test_results, &test_stream_ref, &test_stream_alt).unwrap();
+        expected_res[6]='G'; 
+        expected_res[7]='H'; 
+        assert_eq!(*test_results,*expected_res);
+    }
+    #[test]
+    fn test_execute_into()
+    {
+        let test_stream_ref="ABCFEFGH".chars().collect::<Vec<char>>(); 
+        let test_stream_alt="XY".chars().collect::<Vec<char>>(); 
+        let mut test_results=vec!['x';3];
+        Task::new(0,1,2,0).execute_into(&mut test_results[0..2], &test_stream_ref, &test_stream_alt).unwrap();
+        Task::new(1,1,1,2).execute_into(&mut test_results[2..3], &test_stream_ref, &test_stream_alt).unwrap();
+        assert_eq!(test_results,vec!['B','C','Y']);
+    }
+    #[test]
+    fn test_execute_out_of_bounds()
+    {
+        let test_stream_ref="ABCFEFGH".chars().collect::<Vec<char>>(); 
+        let test_stream_alt="XY".chars().collect::<Vec<char>>(); 
+        let mut test_results=vec!['x';4];
+        // reading beyond the end of the alternative stream 
+        assert_eq!(Task::new(1,1,2,0).execute(&mut test_results, &test_stream_ref, &test_stream_alt),
+            Err(PpggError::TaskOutOfBounds{start:1,end:3,len:2}));
+        // writing beyond the end of the results tape 
+        assert_eq!(Task::new(0,0,3,2).execute(&mut test_results, &test_stream_ref, &test_stream_alt),
+            Err(PpggError::TaskOutOfBounds{start:2,end:5,len:4}));
+        assert_eq!(test_results,vec!['x';4]);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::panic;
+use std::str::FromStr;
 use std::usize;
 use crate::data_structures::InternalRep::gir; 
 use crate::data_structures::InternalRep::instruction;
@@ -8,14 +8,115 @@ use crate::data_structures::vcf_ds;
 use crate::data_structures::InternalRep::task::Task;
 use serde::{Deserialize, Serialize};
 use std::iter::FromIterator;
+use std::convert::TryFrom;
 use super::instruction::Instruction;
+use super::coordinates::{RefPos0,Len};
+use super::variant_feature::VariantFeature;
+use crate::error::PpggError;
+use crate::functions::diagnostics::DiagnosticOptions;
+
+/// ## Summary
+/// The strategy resolving two mutations of a transcript that overlap or start at the same position, by default, the overlap is reported as 
+/// a failed check, see diagnostics::CheckPolicy, i.e. the run stops with the strict policy and the transcript is skipped otherwise. With a 
+/// strategy, the instructions of the transcript are scanned in the order of their positions and each instruction overlapping a kept one is: 
+/// 1. KeepFirst, dropped, i.e. the first of the overlapping mutations is applied 
+/// 2. KeepMostSevere, kept instead of the overlapped instructions if it is more severe than all of them and dropped otherwise, see get_severity 
+/// 3. MergeIfCompatible, merged with the overlapped instructions if it is identical to them or if it substitutes the residues they write 
+///    with the same residues, the transcript is skipped otherwise 
+/// 4. DropTranscript, the transcript is skipped without reporting a failed check 
+/// 
+/// The strategy is passed to the executions through exec::ExecutionOptions. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum OverlapPolicy
+{
+    KeepFirst=1,
+    KeepMostSevere=2,
+    MergeIfCompatible=3,
+    DropTranscript=4
+}
+impl OverlapPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            OverlapPolicy::KeepFirst=>"keep-first",
+            OverlapPolicy::KeepMostSevere=>"keep-most-severe",
+            OverlapPolicy::MergeIfCompatible=>"merge-if-compatible",
+            OverlapPolicy::DropTranscript=>"drop-transcript"
+        }
+    }
+    /// ## Summary
+    /// Return the severity of an instruction, i.e. 4 for a start lost, 3 for the frameshifts, the stop gains and the stop losses, 2 for 
+    /// the other changes of the length, e.g. in-frame insertions and deletions, and 1 for the substitutions, e.g. missense 
+    pub fn get_severity(instruction:&Instruction)->u8
+    {
+        match instruction.get_code()
+        {
+            '0' | 'U'=>4,
+            'F' | 'R' | 'Q' | 'B' | 'G' | 'X' | 'A' | 'T' | 'L'=>3,
+            'M' | 'N'=>1,
+            _=>2
+        }
+    }
+}
+impl FromStr for OverlapPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<OverlapPolicy,String>
+    {
+        match policy.to_lowercase().replace('_',"-").as_str()
+        {
+            "keep-first"=>Ok(OverlapPolicy::KeepFirst),
+            "keep-most-severe"=>Ok(OverlapPolicy::KeepMostSevere),
+            "merge-if-compatible"=>Ok(OverlapPolicy::MergeIfCompatible),
+            "drop-transcript"=>Ok(OverlapPolicy::DropTranscript),
+            _=>Err(format!("{} is not a supported overlap policy, supported policies are: keep-first, keep-most-severe, merge-if-compatible and drop-transcript",policy))
+        }
+    }
+}
+/// ## Summary
+/// The overlapping mutations resolved with an overlap policy, summed over the transcripts of every haplotype, see OverlapPolicy, the 
+/// summary of each proband is returned along with its personalized genome, see PersonalizedGenome::get_overlap_summary 
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub struct OverlapSummary
+{
+    pub num_conflicts:u64,
+    pub num_dropped_mutations:u64,
+    pub num_merged_mutations:u64,
+    pub num_dropped_transcripts:u64
+}
+impl OverlapSummary
+{
+    /// ## Summary
+    /// Add the resolutions of another summary, e.g. of another transcript or proband 
+    pub fn merge(&mut self, other:&OverlapSummary)
+    {
+        self.num_conflicts+=other.num_conflicts; 
+        self.num_dropped_mutations+=other.num_dropped_mutations; 
+        self.num_merged_mutations+=other.num_merged_mutations; 
+        self.num_dropped_transcripts+=other.num_dropped_transcripts; 
+    }
+    /// ## Summary
+    /// Return a report of the resolutions made with the policy 
+    pub fn to_report(&self, policy:OverlapPolicy)->String
+    {
+        format!("The overlapping mutations were resolved with the policy: {}, {} overlaps were encountered, {} mutations were dropped, {} mutations were merged and {} transcripts were skipped, summed over the haplotypes\n",
+            policy.as_str(),self.num_conflicts,self.num_dropped_mutations,self.num_merged_mutations,self.num_dropped_transcripts)
+    }
+}
+
+/// The tasks generated from the instructions of a transcript, the alternative stream and the interval spanned by each instruction in the results array
+type GeneratedTasks=(Vec<Task>,Vec<char>,Vec<(RefPos0,Len)>);
 
 /// A representation for a collection of mutation in a transcript, where mutations have been already encoded into instructions 
 #[derive(Debug,Clone,Serialize,Deserialize)]
 pub struct TranscriptInstruction
 {
     transcript_name:String,
-    ref_len:usize, 
+    ref_len:Len, 
     instructions:Vec<instruction::Instruction>
 }
 impl TranscriptInstruction
@@ -23,7 +124,7 @@ impl TranscriptInstruction
     /// ## Summary 
     /// Create a new instruction from a transcript name and vector of instruction containing 
     /// the mutation in the transcript and the reference length
-    pub fn new(transcript_name:String, ref_len:usize, instructions:Vec<instruction::Instruction>)->Self
+    pub fn new(transcript_name:String, ref_len:Len, instructions:Vec<instruction::Instruction>)->Self
     {
         TranscriptInstruction{transcript_name,ref_len,instructions}
     }
@@ -33,139 +134,188 @@ impl TranscriptInstruction
     pub fn empty_t_instruction()->Self
     {
         let ins_vec:Vec<instruction::Instruction>=Vec::new(); 
-        TranscriptInstruction{transcript_name:"".to_string(),ref_len:1,instructions:ins_vec}
+        TranscriptInstruction{transcript_name:"".to_string(),ref_len:Len::new(1),instructions:ins_vec}
     }
     /// ## Summary 
     /// Create a new instance from the alt-Transcript instance along with a reference hashmap 
-    /// of sequence names 
-    pub fn from_alt_transcript(mut alt_transcript:vcf_ds::AltTranscript, ref_seqs:&HashMap<String,String>)->Result<Self,String>
+    /// of sequence names, an error is returned if the transcript is not part of the reference, if none of its mutations is supported or 
+    /// if one of its mutations can not be interpreted as an instruction, the overlaps are not checked, see from_alt_transcript_with_policies 
+    pub fn from_alt_transcript(alt_transcript:vcf_ds::AltTranscript, ref_seqs:&HashMap<String,String>)->Result<Self,PpggError>
+    {
+        TranscriptInstruction::from_alt_transcript_with_policies(alt_transcript, ref_seqs, None, &DiagnosticOptions::default(), &mut OverlapSummary::default())
+    }
+    /// ## Summary 
+    /// Similar to from_alt_transcript, however, the overlapping mutations of the transcript are resolved with the provided strategy, if 
+    /// any, and the resolutions are added to the summary, see OverlapPolicy, otherwise, the overlaps are checked with the check policy of 
+    /// the diagnostics, where the translation of the debugged transcripts is logged, see diagnostics::DiagnosticOptions 
+    pub fn from_alt_transcript_with_policies(mut alt_transcript:vcf_ds::AltTranscript, ref_seqs:&HashMap<String,String>, 
+        overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions, summary:&mut OverlapSummary)->Result<Self,PpggError>
     {
         alt_transcript.sort_alterations();// sort alteration 
         let transcript_name=alt_transcript.name.clone(); 
         let ref_len=match ref_seqs.get(&transcript_name)
         {
-            Some(sequence)=>sequence.len(),
-            None=>return Err(format!("The provided transcript name: {} is not in the reference sequence", &transcript_name))
+            Some(sequence)=>Len::new(sequence.len()),
+            None=>return Err(PpggError::MissingTranscript(transcript_name))
         };
         let mut instructions= Vec::with_capacity(alt_transcript.alts.len()); 
-        let mut ins_idx=Vec::with_capacity(alt_transcript.alts.len());
         for mutation in alt_transcript.alts.iter()
         {
-            let instruction=instruction::Instruction::from_mutation(mutation,&alt_transcript.alts);
+            let instruction=instruction::Instruction::from_mutation(mutation,&alt_transcript.alts)?;
+            // a stop re-selection appends residues after the last residue, hence, it must start at the stop codon of the reference 
+            if instruction.get_code()=='V' && instruction.get_position_ref()!=RefPos0::new(ref_len.get())
+            {
+                return Err(PpggError::UninterpretableMutation{transcript:transcript_name,
+                    mutation:format!("{:?} {:?} does not start at the stop codon",mutation.mut_type,mutation.mut_info)})
+            }
             if instruction.get_code()!='E'
             {
-                ins_idx.push(instruction.get_position_ref());
                 instructions.push(instruction)
             }
         }
+        // a start re-selection trims the residues preceding the new start, hence, the instructions located at or before it are dropped 
+        if let Some(index)=instructions.iter().position(|ins|ins.get_code()=='S')
+        {
+            let start=instructions.remove(index);
+            instructions.retain(|ins|ins.get_position_ref()>start.get_position_ref() && ins.get_code()!='S');
+            instructions.insert(0,start);
+        }
+        let ins_idx=instructions.iter().map(|ins|ins.get_position_ref()).collect::<Vec<RefPos0>>();
         if instructions.len()==0
         {
-            return Err(format!("The provided transcript name: {} has {} mutations none of them is supported, skipping this transcript", &transcript_name,alt_transcript.alts.len()))
+            return Err(PpggError::NoSupportedMutations{transcript:transcript_name,num_mutations:alt_transcript.alts.len()})
         }
-        match std::env::var("INSPECT_INS_GEN")
+        // the overlaps are resolved with the requested strategy instead of being reported as a failed check, a start lost drops the transcript anyway 
+        if let Some(policy)=overlap_policy
         {
-            Ok(_)=>
+            if instructions.iter().all(|ins|ins.get_code()!='0')
+            {
+                instructions=TranscriptInstruction::resolve_overlaps(&transcript_name, instructions, policy, summary)?; 
+            }
+            return Ok(TranscriptInstruction::new(transcript_name,ref_len,instructions))
+        }
+        if diagnostic_options.check_policy.is_enabled()
+        {
+            let is_debugged=diagnostic_options.debug_filter.is_debugged(&transcript_name); 
+            if is_debugged
+            {
+                log::debug!("Inspect the generation of instructions from transcript: {}",&transcript_name); 
+                log::debug!("Checking for indexing errors ...."); 
+            }
+            let index = HashSet::<RefPos0>::from_iter(ins_idx);
+            // check that all instructions have unique start points 
+            //-----------------------------------------------------
+            if index.len()!=instructions.len()
             {
-                println!("Inspect the generation of instructions from transcript: {}",&transcript_name); 
-                println!("Checking for indexing errors ...."); 
-                let index = HashSet::<usize>::from_iter(ins_idx.into_iter());
-                // check that all instructions have unique start points 
-                //-----------------------------------------------------
-                if index.len()!=instructions.len()
+                diagnostic_options.check_policy.report_failure(&format!("for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
+                Nevertheless, some mutations at the same position were observed, skipping this mutations ...
+                ",transcript_name,&alt_transcript.alts,&instructions));   
+                return Err(PpggError::OverlappingMutations{transcript:transcript_name}); 
+            }
+            if is_debugged
+            {
+                log::debug!("Checking for mutational overlaps ...");
+            }
+            // generate the pair of adjacent instructions
+            //-------------------------------------------
+            if instructions.len()>1
+            {
+                if instructions.iter().any(|ins|ins.get_code()=='0')
                 {
-                    match std::env::var("PANIC_INSPECT_ERR")
-                    {
-                        Ok(_)=>
-                        {
-                            panic!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                            Nevertheless, some mutations at the same position were observed, skipping this mutations ...
-                            ",transcript_name,&alt_transcript.alts,&instructions);   
-                        },
-                        Err(_)=>
-                        {
-                            println!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                            Nevertheless, some mutations at the same position were observed, skipping this mutations ...
-                            ",transcript_name,&alt_transcript.alts,&instructions);   
-                            return Err("Encountered two mutation at the same time".to_string()); 
-                        }  
-                    }
+                    return Ok(TranscriptInstruction::new(transcript_name,ref_len,instructions))
                 }
-                println!("Checking for mutational overlaps ...");
-                // generate the pair of adjacent instructions
-                //-------------------------------------------
-                if instructions.len()>1
+                let consequent_pairs=instructions[..instructions.len()-1]
+                        .iter()
+                        .enumerate()
+                        .map(|(idx,ins)|(ins,&instructions[idx+1]))
+                        .collect::<Vec<_>>(); 
+                for (ins1,ins2) in consequent_pairs
                 {
-                    if instructions.iter().any(|ins|ins.get_code()=='0')
+                    let is_overlapping=ins2.get_position_res()<ins1.get_position_res()+Len::new(ins1.get_data().len()) // i.e. ins2 starts inside the residues written by ins1
+                        // add a conditional state for handling inframe_deletion, for example a deletion at position 84 with length 5 (ins1) and ins2 is a mutation at site 88 
+                        || ((ins1.get_code()=='C' || ins1.get_code()=='D') && ins2.get_position_ref()<ins1.get_position_res()+ins1.get_length()); 
+                    if is_overlapping
                     {
-                        return Ok(TranscriptInstruction::new(transcript_name,ref_len,instructions))
+                        diagnostic_options.check_policy.report_failure(&format!("for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
+                        Nevertheless, some mutations overlap, skipping this mutations ...
+                        ",transcript_name,&alt_transcript.alts,&instructions));   
+                        return Err(PpggError::OverlappingMutations{transcript:transcript_name}); 
                     }
-                    let consequent_pairs=instructions[..instructions.len()-1]
-                            .iter()
-                            .enumerate()
-                            .map(|(idx,ins)|(ins,&instructions[idx+1]))
-                            .collect::<Vec<_>>(); 
-                    for (ins1,ins2) in consequent_pairs
-                    {
-                        
-                        if ins2.get_position_res()<=(ins1.get_position_res()+ins1.get_data().len()-1) // This is causing us problems and headache 
-                        {
-                            match std::env::var("PANIC_INSPECT_ERR")
-                            {
-                                Ok(_)=>
-                                {
-                                    panic!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                                    Nevertheless, some mutations overlap, skipping this mutations ...
-                                    ",transcript_name,&alt_transcript.alts,&instructions);   
-                                },
-                                Err(_)=>
-                                {
-                                    println!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                                    Nevertheless, some mutations overlap, skipping this mutations ...
-                                    ",transcript_name,&alt_transcript.alts,&instructions);   
-                                    return Err("Encountered two mutation at the same time".to_string()); 
-                                }  
-                            }
-                        }
-                        // add a conditional state for handling inframe_deletion 
-                        if ins1.get_code()=='C' || ins1.get_code()=='D'
-                        {
-                            if ins2.get_position_ref()<=(ins1.get_position_res()+ins1.get_length()-1) // for example a deletion at position 84 with length 5 (ins1) and ins2 is a mutation at site 88 
-                            {
-                                match std::env::var("PANIC_INSPECT_ERR")
-                                {
-                                    Ok(_)=>
-                                    {
-                                        panic!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                                        Nevertheless, some mutations overlap, skipping this mutations ...
-                                        ",transcript_name,&alt_transcript.alts,&instructions);   
-                                    },
-                                    Err(_)=>
-                                    {
-                                        println!("Critical error was encountered: for transcript: {} the following mutations were observed, {:#?} which translate to the following instructions: {:#?}\
-                                        Nevertheless, some mutations overlap, skipping this mutations ...
-                                        ",transcript_name,&alt_transcript.alts,&instructions);   
-                                        return Err("Encountered two mutation at the same time".to_string()); 
-                                    }  
-                                }
-                            }
-                        }                        
-                    }      
-                }
+                }      
             }
-            Err(_)=>()
         }
-        match std::env::var("DEBUG_TXP")
+        Ok(TranscriptInstruction::new(transcript_name,ref_len,instructions))
+    }
+    /// ## Summary 
+    /// Return whether an instruction overlaps a previous instruction of the transcript, i.e. both start at the same position or the 
+    /// instruction starts inside the residues written or deleted by the previous one 
+    pub fn is_overlapping(previous:&Instruction, instruction:&Instruction)->bool
+    {
+        previous.get_position_ref()==instruction.get_position_ref() 
+            || instruction.get_position_res()<previous.get_position_res()+Len::new(previous.get_data().len())
+            || ((previous.get_code()=='C' || previous.get_code()=='D') && instruction.get_position_ref()<previous.get_position_res()+previous.get_length())
+    }
+    /// ## Summary 
+    /// Return whether an instruction overlapping a previous one can be merged into it, i.e. both are identical or the instruction is a 
+    /// substitution of the residues written by the previous one with the same residues 
+    fn is_compatible(previous:&Instruction, instruction:&Instruction)->bool
+    {
+        if previous==instruction
+        {
+            return true
+        }
+        let offset=instruction.get_position_res().get().checked_sub(previous.get_position_res().get()); 
+        matches!(instruction.get_code(),'M' | 'N') && match offset
+        {
+            Some(offset)=>previous.get_data().get(offset..offset+instruction.get_data().len())==Some(instruction.get_data().as_slice()),
+            None=>false
+        }
+    }
+    /// ## Summary 
+    /// Resolve the overlapping instructions of a transcript, sorted by their positions, with a strategy, see OverlapPolicy, and add the 
+    /// resolutions to the summary, an error is returned if the transcript is skipped 
+    pub fn resolve_overlaps(transcript_name:&str, instructions:Vec<Instruction>, policy:OverlapPolicy, summary:&mut OverlapSummary)->Result<Vec<Instruction>,PpggError>
+    {
+        let mut resolved:Vec<Instruction>=Vec::with_capacity(instructions.len()); 
+        for instruction in instructions.into_iter()
         {
-            Ok(txp_id)=>
+            let num_overlapped=resolved.iter().rev().take_while(|previous|TranscriptInstruction::is_overlapping(previous, &instruction)).count(); 
+            if num_overlapped==0
+            {
+                resolved.push(instruction); 
+                continue
+            }
+            summary.num_conflicts+=1; 
+            let overlapped=&resolved[resolved.len()-num_overlapped..]; 
+            match policy
             {
-                if transcript_name==txp_id
+                OverlapPolicy::KeepFirst=>summary.num_dropped_mutations+=1,
+                OverlapPolicy::KeepMostSevere=>
+                {
+                    let severity=OverlapPolicy::get_severity(&instruction); 
+                    if overlapped.iter().all(|previous|OverlapPolicy::get_severity(previous)<severity)
+                    {
+                        resolved.truncate(resolved.len()-num_overlapped); 
+                        resolved.push(instruction); 
+                        summary.num_dropped_mutations+=num_overlapped as u64; 
+                    }
+                    else
+                    {
+                        summary.num_dropped_mutations+=1; 
+                    }
+                },
+                OverlapPolicy::MergeIfCompatible if overlapped.iter().all(|previous|TranscriptInstruction::is_compatible(previous, &instruction))=>
                 {
-                    println!("Current mutations are : {:#?} and it has been translated into the following instruction: {:#?}", &alt_transcript,&instructions)
+                    summary.num_merged_mutations+=1; 
+                },
+                OverlapPolicy::MergeIfCompatible | OverlapPolicy::DropTranscript=>
+                {
+                    summary.num_dropped_transcripts+=1; 
+                    return Err(PpggError::OverlappingMutations{transcript:transcript_name.to_string()})
                 }
-            },
-            Err(_)=>()
+            }
         }
-        Ok(TranscriptInstruction::new(transcript_name,ref_len,instructions))
+        Ok(resolved)
     }
     /// ## Summary 
     /// Return the number of instruction in the transcript 
@@ -180,6 +330,12 @@ impl TranscriptInstruction
         &self.transcript_name
     }
     /// ## Summary 
+    /// Return a reference to the instance vector of instructions 
+    pub fn get_instructions(&self)->&Vec<Instruction>
+    {
+        &self.instructions
+    }
+    /// ## Summary 
     /// Return a mutable reference to the instance vector of instructions 
     pub fn get_mut_instruction(&mut self)->&mut Vec<Instruction>
     {
@@ -207,19 +363,20 @@ impl TranscriptInstruction
     /// let ins=instruction::Instruction::from_mutation(&test_mutation); 
     /// let mut ins_vec=Vec::with_capacity(2);
     /// ins_vec.push(ins);
-    /// let test_alt_transcript=TranscriptInstruction::new("Test1".to_string(), 50, ins_vec);
+    /// let test_alt_transcript=TranscriptInstruction::new("Test1".to_string(), Len::new(50), ins_vec);
     /// println!("{:#?}",test_alt_transcript);
-    /// assert_eq!(test_alt_transcript.compute_expected_results_array_size(),47);
+    /// assert_eq!(test_alt_transcript.compute_expected_results_array_size(),Ok(47));
     ///```  
-    pub fn compute_expected_results_array_size(&self)->usize
+    /// An error is returned if an instruction is not supported or if the instructions remove more residues than the reference has 
+    pub fn compute_expected_results_array_size(&self)->Result<usize,PpggError>
     {
         let mut expected_size=0; 
         for ins in self.instructions.iter()
         {
             match ins.get_code()
             {
-                'U' | '0'=> {expected_size -= self.ref_len as i32;break;},
-                'F' => expected_size += ins.get_data().len()  as i32 - (self.ref_len as i32 - ins.get_position_ref() as i32), 
+                'U' | '0'=> {expected_size -= self.ref_len.get() as i32;break;},
+                'F' => expected_size += ins.get_data().len()  as i32 - (self.ref_len.get() as i32 - ins.get_position_ref().get() as i32), 
                 'R' => 
                 {
                     // get the index of the current instruction
@@ -227,20 +384,20 @@ impl TranscriptInstruction
 
                     if !self.instructions[..index].iter().any(|ins|ins.get_code()=='G' || ins.get_code()=='F')
                     {
-                        expected_size += ins.get_data().len()  as i32 - (self.ref_len as i32 - ins.get_position_ref() as i32)
+                        expected_size += ins.get_data().len()  as i32 - (self.ref_len.get() as i32 - ins.get_position_ref().get() as i32)
                     }
                 },
-                'G' | 'X' => expected_size -= self.ref_len as i32 - ins.get_position_ref() as i32, 
+                'G' | 'X' => expected_size -= self.ref_len.get() as i32 - ins.get_position_ref().get() as i32, 
                 'M' | 'N' | '2' => (), 
                 'L' =>
                 {
-                    if ins.get_position_ref()+1 as usize == self.ref_len ||  ins.get_position_ref() == self.ref_len
+                    if ins.get_position_ref().is_last_residue(self.ref_len) || ins.get_position_ref().is_stop_position(self.ref_len)
                     {
                         expected_size += ins.get_data().len() as i32
                     }
                     else
                     {
-                        expected_size += ins.get_data().len()  as i32 - (self.ref_len as i32 - ins.get_position_ref() as i32)
+                        expected_size += ins.get_data().len()  as i32 - (self.ref_len.get() as i32 - ins.get_position_ref().get() as i32)
                     }
                 },
                 'I' => expected_size += ins.get_data().len() as i32 -1 as i32, // e.g. 125Y>125YRR,
@@ -254,7 +411,7 @@ impl TranscriptInstruction
                         expected_size += ins.get_data().len() as i32 -1 as i32;
                     }
                 }
-                'D' => expected_size -= ins.get_length() as i32, 
+                'D' => expected_size -= ins.get_length().get() as i32, 
                 'C' => 
                 {
                     // get the index of the current instruction
@@ -262,7 +419,7 @@ impl TranscriptInstruction
                     // check if there is other mutation before hand 
                     if !self.instructions[..index].iter().any(|ins|ins.get_code()=='G' || ins.get_code()=='F')
                     {
-                        expected_size -= ins.get_length() as i32;
+                        expected_size -= ins.get_length().get() as i32;
                     }
                 },
                 'K' => 
@@ -272,7 +429,7 @@ impl TranscriptInstruction
                     // check if there is other mutation before hand 
                     if !self.instructions[..index].iter().any(|ins|ins.get_code()=='G' || ins.get_code()=='F')
                     {
-                        expected_size+= ins.get_data().len()  as i32 - (self.ref_len as i32 -ins.get_position_ref() as i32); 
+                        expected_size+= ins.get_data().len()  as i32 - (self.ref_len.get() as i32 -ins.get_position_ref().get() as i32); 
                     }
                 },
                 'Q' => 
@@ -282,7 +439,7 @@ impl TranscriptInstruction
                     // check if there is other mutation before hand 
                     if !self.instructions[..index].iter().any(|ins|ins.get_code()=='G' || ins.get_code()=='F')
                     {
-                        expected_size+= ins.get_data().len()  as i32 - (self.ref_len as i32 -ins.get_position_ref() as i32); 
+                        expected_size+= ins.get_data().len()  as i32 - (self.ref_len.get() as i32 -ins.get_position_ref().get() as i32); 
                     }
                 },
                 'A' =>
@@ -292,34 +449,29 @@ impl TranscriptInstruction
                     // check if there is other mutation before hand 
                     if !self.instructions[..index].iter().any(|ins|ins.get_code()=='G' || ins.get_code()=='F')
                     {
-                        expected_size-= self.ref_len as i32 - ins.get_position_ref() as i32; 
+                        expected_size-= self.ref_len.get() as i32 - ins.get_position_ref().get() as i32; 
                     }
                 },
-                'B' => expected_size-= self.ref_len as i32 - ins.get_position_ref() as i32 - ins.get_length() as i32,
-                'P' => expected_size-= ins.get_length() as i32 ,
+                'B' => expected_size-= self.ref_len.get() as i32 - ins.get_position_ref().get() as i32 - ins.get_length().get() as i32,
+                'P' => expected_size-= ins.get_length().get() as i32 ,
                 'Z' => (),
-                'T' => expected_size-= self.ref_len as i32 - ins.get_position_ref() as i32,
+                'T' => expected_size-= self.ref_len.get() as i32 - ins.get_position_ref().get() as i32,
                 'W' => expected_size+= ins.get_data().len() as i32,
-                'Y' => expected_size+= ins.get_data().len()  as i32 - (self.ref_len as i32 -ins.get_position_ref() as i32)  +1 as i32, 
-                '3' => expected_size+= ins.get_data().len() as i32 - ins.get_length() as i32 ,
-                _=>panic!("instruction: {:#?} is not supported", ins),
+                'Y' => expected_size+= ins.get_data().len()  as i32 - (self.ref_len.get() as i32 -ins.get_position_ref().get() as i32)  +1, 
+                '3' => expected_size+= ins.get_data().len() as i32 - ins.get_length().get() as i32 ,
+                'S' => expected_size+= ins.get_data().len() as i32 - ins.get_position_ref().next().get() as i32, // e.g. 4M>1M trims 3 residues
+                'V' => expected_size+= ins.get_data().len() as i32, 
+                code=>return Err(PpggError::UnsupportedInstruction{transcript:self.transcript_name.clone(),code}),
             }
         }
-        let size = (self.ref_len as i32 + expected_size) as usize;
-        match std::env::var("DEBUG_TXP")
+        match usize::try_from(self.ref_len.get() as i32 + expected_size)
         {
-            Ok(txp_id)=>
-            {
-                if self.transcript_name==txp_id
-                {
-                    println!("Transcript Name is: {}, Input protein length is: {}, mutations are: {:?} and computed results is: {}", self.transcript_name,self.ref_len, self.instructions,size);
-                }
-            },
-            Err(_)=>()
+            Ok(size)=>Ok(size),
+            Err(_)=>Err(PpggError::NegativeSequenceLength{transcript:self.transcript_name.clone(),ref_len:self.ref_len.get(),size_change:expected_size as i64})
         }
-        size
     }
-    /// Return an GIR  of the instances 
+    /// Return an GIR  of the instances, where the results array is initialized with the provided placeholder residue, see gir::PlaceholderPolicy, 
+    /// the tasks are not checked, see get_g_rep_with_checks 
     /// ## Example
     ///```  
     /// let name="ENST00000406869".to_string(); 
@@ -329,101 +481,193 @@ impl TranscriptInstruction
     /// let mut reference=HashMap::new(); 
     /// reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
     /// let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-    /// let test_gir=res.get_g_rep(&reference); 
+    /// let test_gir=res.get_g_rep(&reference,gir::PLACEHOLDER_RESIDUE); 
     /// println!("{:#?}",test_gir); 
     ///```
-    pub fn get_g_rep(&self, ref_seqs:&HashMap<String,String>)->Result<gir::GIR,String>
+    pub fn get_g_rep(&self, ref_seqs:&HashMap<String,String>, placeholder:char)->Result<gir::GIR,PpggError>
+    {
+        self.get_g_rep_with_checks(ref_seqs, placeholder, &DiagnosticOptions::default())
+    }
+    /// ## Summary 
+    /// Similar to get_g_rep, however, the tasks are checked with the check policy of the diagnostics before they are returned and again 
+    /// before they are executed, see gir::GIR::set_check_policy 
+    pub fn get_g_rep_with_checks(&self, ref_seqs:&HashMap<String,String>, placeholder:char, diagnostic_options:&DiagnosticOptions)->Result<gir::GIR,PpggError>
     {        
         // handle the case with start-lost and 'U' code
         if self.instructions.iter().any(|ins| ins.get_code()=='0' || ins.get_code()=='U') || self.instructions.len() ==0
         {
             let mut annotations=HashMap::new();
             annotations.insert(self.transcript_name.clone(), (0 as usize,0 as usize));
-            return Ok(gir::GIR::new(Vec::new(),annotations,Vec::new(),Vec::new(),Vec::new())); 
+            let mut g_rep=gir::GIR::new(Vec::new(),annotations,Vec::new(),Vec::new(),Vec::new()); 
+            g_rep.set_num_instructions(self.instructions.len()); 
+            return Ok(g_rep); 
         }
         // allocate arrays:
         //-----------------
-        let mut vec_tasks=Vec::with_capacity(2*self.instructions.len()); 
-        let mut alt_array=Vec::with_capacity(self.compute_alt_stream_size());
-        let res_array=vec!['.'; self.compute_expected_results_array_size()];
-        let ref_stream=ref_seqs.get(&self.transcript_name).unwrap().chars().collect::<Vec<char>>();
+        let res_size=self.compute_expected_results_array_size()?; 
+        let res_array=vec![placeholder; res_size];
+        let ref_stream=match ref_seqs.get(&self.transcript_name)
+        {
+            Some(sequence)=>sequence.chars().collect::<Vec<char>>(),
+            None=>return Err(PpggError::MissingTranscript(self.transcript_name.clone()))
+        };
         // push the instruction 
         //---------------------
+        let (vec_tasks, alt_array, _)=self.generate_tasks(Len::new(ref_stream.len()))?; 
+        // add the instruction to the array 
+        let mut annotations=HashMap::new();
+        annotations.insert(self.transcript_name.clone(), (0  as usize, res_size)); 
+        if diagnostic_options.check_policy.is_enabled()
+        {
+            if diagnostic_options.debug_filter.is_debugged(&self.transcript_name)
+            {
+                log::debug!("Validating the generated instructions for transcript: {} ....", self.transcript_name);
+            }
+            self.check_tasks(&vec_tasks, res_array.len())?; 
+        }
+        let mut g_rep=gir::GIR::new(vec_tasks, annotations,alt_array,ref_stream,res_array); 
+        g_rep.set_num_instructions(self.instructions.len()); 
+        g_rep.set_placeholder(placeholder); 
+        g_rep.set_check_policy(diagnostic_options.check_policy); 
+        Ok(g_rep)
+    }
+    /// ## Summary 
+    /// Check that the tasks of the instance are contiguous and fill a results array of the expected size, regardless of the CheckPolicy in 
+    /// use, i.e. the invariants get_g_rep checks unless the checks are off, see validation::validate_representations 
+    /// ## Example
+    ///```
+    /// use std::collections::HashMap; 
+    /// use ppgg::data_structures::vcf_ds::AltTranscript; 
+    /// use ppgg::data_structures::InternalRep::transcript_instructions::TranscriptInstruction; 
+    /// let alt_transcript=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|-|5G>5T|1936821C>T".to_string()]);
+    /// let mut reference=HashMap::new(); 
+    /// reference.insert("T1".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
+    /// assert!(TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap().validate_tasks(&reference).is_ok()); 
+    ///```
+    /// An error describing the first violated invariant is returned, or the error generating the tasks 
+    pub fn validate_tasks(&self, ref_seqs:&HashMap<String,String>)->Result<(),PpggError>
+    {
+        if self.instructions.iter().any(|ins| ins.get_code()=='0' || ins.get_code()=='U') || self.instructions.is_empty()
+        {
+            return Ok(())
+        }
+        let res_size=self.compute_expected_results_array_size()?; 
+        let ref_len=match ref_seqs.get(&self.transcript_name)
+        {
+            Some(sequence)=>sequence.chars().count(),
+            None=>return Err(PpggError::MissingTranscript(self.transcript_name.clone()))
+        };
+        let (vec_tasks, _, _)=self.generate_tasks(Len::new(ref_len))?; 
+        self.check_tasks(&vec_tasks, res_size)
+    }
+    /// ## Summary 
+    /// Check that each task starts where the previous task ends in the results array and that the tasks fill the expected size 
+    fn check_tasks(&self, vec_tasks:&[Task], expected_size:usize)->Result<(),PpggError>
+    {
+        let mut counter=0; 
+        for idx in 1..vec_tasks.len()
+        {
+            if vec_tasks[idx].get_start_pos_res()!=vec_tasks[idx-1].get_start_pos_res() + vec_tasks[idx-1].get_length()
+            {
+                log::debug!("{}",gir::format_task_table(vec_tasks));
+                return Err(PpggError::InconsistentTasks{transcript:Some(self.transcript_name.clone()),task_index:idx,
+                    expected_start:vec_tasks[idx-1].get_start_pos_res()+vec_tasks[idx-1].get_length(),actual_start:vec_tasks[idx].get_start_pos_res()});
+            }
+            counter+=vec_tasks[idx].get_length()
+        }
+        counter+=vec_tasks.first().map(|task|task.get_length()).unwrap_or(0); 
+        if expected_size != counter
+        {
+            return Err(PpggError::SizeMismatch{transcript:self.transcript_name.clone(),expected:expected_size,actual:counter}); 
+        }
+        Ok(())
+    }
+    /// ## Summary 
+    /// Return the interval spanned by each instruction in the coordinate system of the personalized sequence, i.e. the sequence
+    /// generated by executing the tasks of the instance. Instructions that do not add residues to the results, 
+    /// e.g. stop-gained, have an empty interval located at the position where the sequence is altered.
+    /// ## Example
+    ///```
+    /// use std::collections::HashMap; 
+    /// use ppgg::data_structures::vcf_ds::AltTranscript; 
+    /// use ppgg::data_structures::InternalRep::transcript_instructions::TranscriptInstruction; 
+    /// let alt_transcript=AltTranscript::new("T1".to_string(),vec!["inframe_insertion|G1|T1|protein_coding|-|5G>5GTEST|1936821C>T".to_string()]);
+    /// let mut reference=HashMap::new(); 
+    /// reference.insert("T1".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
+    /// let features=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap().get_variant_features(&reference).unwrap(); 
+    /// assert_eq!(features.len(),1);
+    /// assert_eq!(features[0].get_start().get(),4); 
+    /// assert_eq!(features[0].get_length().get(),5); 
+    /// assert_eq!(features[0].get_reference_length().get(),1); 
+    ///```
+    pub fn get_variant_features(&self, ref_seqs:&HashMap<String,String>)->Result<Vec<VariantFeature>,PpggError>
+    {
+        // start-lost transcripts are not translated and hence they do not have any features 
+        if self.instructions.iter().any(|ins| ins.get_code()=='0' || ins.get_code()=='U') || self.instructions.is_empty()
+        {
+            return Ok(Vec::new()); 
+        }
+        let ref_len=match ref_seqs.get(&self.transcript_name)
+        {
+            Some(seq)=>Len::new(seq.len()),
+            None=>return Err(PpggError::MissingTranscript(self.transcript_name.clone()))
+        };
+        let (_, _, spans)=self.generate_tasks(ref_len)?; 
+        let num_instructions=self.instructions.len(); 
+        Ok(self.instructions.iter()
+            .zip(spans)
+            .enumerate()
+            .map(|(index,(ins,(start,len)))|VariantFeature::new(self.transcript_name.clone(), ins.get_code(), ins.get_position_ref(), start, len)
+                .with_reference_length(TranscriptInstruction::get_reference_extent(ins, index+1==num_instructions, ref_len)))
+            .collect::<Vec<VariantFeature>>())
+    }
+    /// ## Summary 
+    /// Return the number of reference amino acids replaced by an instruction, i.e. the span between the position of the instruction and 
+    /// the first reference amino acid copied after it, which mirrors the copy tasks of add_till_next_ins and add_last_instruction. 
+    /// The last instruction of a transcript that is not followed by the rest of the reference, e.g. a frameshift, replaces the reference 
+    /// until its end. 
+    fn get_reference_extent(ins:&instruction::Instruction, is_last:bool, ref_len:Len)->Len
+    {
+        let terminal_codes=['K','Y','Q','A','B','P','Z','T','W','G','F','R','L','X','V']; 
+        let extent=match ins.get_code()
+        {
+            code if is_last && terminal_codes.contains(&code)=>return ins.get_position_ref().checked_span_to(RefPos0::new(ref_len.get())).unwrap_or_else(Len::zero),
+            'D' | 'C'=>ins.get_length().get()+1,
+            '2' | '3'=>ins.get_length().get(),
+            _=>1
+        };
+        Len::new(std::cmp::min(extent,ref_len.get().saturating_sub(ins.get_position_ref().get())))
+    }
+    /// ## Summary 
+    /// Translate the instructions of the instance into tasks, returns the vector of tasks, the alternative stream and 
+    /// the interval spanned by each instruction in the results array
+    fn generate_tasks(&self, ref_len:Len)->Result<GeneratedTasks,PpggError>
+    {
+        let mut vec_tasks=Vec::with_capacity(2*self.instructions.len()); 
+        let mut alt_array=Vec::with_capacity(self.compute_alt_stream_size());
+        let mut spans=Vec::with_capacity(self.instructions.len()); 
         // base instruction
         vec_tasks.push(TranscriptInstruction::build_base_instruction(&self.instructions[0],&self.ref_len)); 
         // loop over all instructions
         for ins in self.instructions.iter()
         {
-            let (task1, task2)=match TranscriptInstruction::to_task(ins, &self.instructions,
-                &mut alt_array, &vec_tasks, ref_stream.len())
-            {
-                Ok(res)=>res,
-                Err(err)=> return Err(format!("Translating {} failed with the following error: {:?}",self.transcript_name, err))
-            };
-            if !(*task1.get_execution_stream() == 2 as u8)
+            let (task1, task2)=TranscriptInstruction::to_task(&self.transcript_name, ins, &self.instructions, &mut alt_array, &vec_tasks, ref_len)?;
+            if *task1.get_execution_stream() != 2
             {
+                spans.push((RefPos0::new(task1.get_start_pos_res()),Len::new(task1.get_length()))); 
                 vec_tasks.push(task1);
             }
-            if !(*task2.get_execution_stream() == 2 as u8)
+            else
+            {
+                let last_task=vec_tasks.last().unwrap(); 
+                spans.push((RefPos0::new(last_task.get_start_pos_res()+last_task.get_length()),Len::zero())); 
+            }
+            if *task2.get_execution_stream() != 2
             {
                 vec_tasks.push(task2);
             }           
         }
-        match std::env::var("DEBUG_TXP")
-        {
-            Ok(txp_id)=>
-            {
-                if self.transcript_name==txp_id
-                {
-                    println!("Vector of tasks is: {:#?}",vec_tasks);
-                }
-            },
-            Err(_)=>()
-        }
-        // add the instruction to the array 
-        let mut annotations=HashMap::new();
-        annotations.insert(self.transcript_name.clone(), (0  as usize, self.compute_expected_results_array_size())); 
-        match std::env::var("INSPECT_TXP")
-        {
-            Ok(_)=>
-            {
-                let expected_size= res_array.len();
-                let mut counter=0; 
-                println!("Validating the generated instructions for transcript: {} ....", self.transcript_name);
-                for idx in 1..vec_tasks.len()
-                {
-                    if vec_tasks[idx].get_start_pos_res()!=vec_tasks[idx-1].get_start_pos_res() + vec_tasks[idx-1].get_length()
-                    {
-                        println!("************ CPU Execution Table *********");
-                        println!("index\tstream\tstart_position\tlength\tposition_results\t");
-                        for idx in 0..vec_tasks.len()
-                        {
-                            println!(
-                                "{}\t{}\t{}\t{}\t{}\t",idx,vec_tasks[idx].get_execution_stream(),
-                                vec_tasks[idx].get_start_pos(),
-                                vec_tasks[idx].get_length(),
-                                vec_tasks[idx].get_start_pos_res()
-                                );
-                        }
-                        panic!("Critical failure in the calculations was encountered: position: {} the sum {} does not equal previous inputs: {} and {} \n",
-                        idx,vec_tasks[idx].get_start_pos_res(),vec_tasks[idx-1].get_start_pos_res(),vec_tasks[idx-1].get_length());
-                    }
-                    counter+=vec_tasks[idx].get_length()
-                }
-                counter+=vec_tasks[0].get_length(); 
-                if expected_size != counter
-                {
-                    panic!("In transcript: {}, size mismatched between the expected size: {} and actual size: {}.",
-                    self.transcript_name, expected_size,counter); 
-                }
-            },
-            Err(_)=>()
-        }
-
-
-
-
-        Ok(gir::GIR::new(vec_tasks, annotations,alt_array,ref_stream,res_array))
+        Ok((vec_tasks,alt_array,spans))
     }
     /// Takes an instruction and returns two tasks, the first is the  execution task for the instruction
     /// and the second is the taskdescribe the copying of the reference untill the end of the sequence or
@@ -440,17 +684,17 @@ impl TranscriptInstruction
     /// let mut reference=HashMap::new(); 
     /// reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
     /// let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-    /// let test_gir=res.get_g_rep(&reference); 
+    /// let test_gir=res.get_g_rep(&reference,gir::PLACEHOLDER_RESIDUE); 
     /// println!("{:#?}",test_gir); 
-    /// let (res_array, _)=test_gir.execute(Engine::ST);
+    /// let res_array=test_gir.execute(Engine::ST).unwrap().sequence;
     /// let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
     /// println!("Input Sequence is:  ==>{:#?}",&ref_string);
     /// let res_string=res_array.iter().collect::<String>();
     /// println!("Result sequence is: ==>{:#?}",res_string);
     /// assert_eq!(ref_string.len()+ 7 as usize, res_string.len());
     ///```
-    fn to_task(instruction:&instruction::Instruction, vec_instruction:&Vec<instruction::Instruction>, 
-                    alt_stream: &mut Vec<char>, vec_tasks: &Vec<Task>, ref_len:usize)->Result<(Task,Task),String>
+    fn to_task(transcript_name:&str, instruction:&instruction::Instruction, vec_instruction:&[instruction::Instruction], 
+                    alt_stream: &mut Vec<char>, vec_tasks: &Vec<Task>, ref_len:Len)->Result<(Task,Task),PpggError>
     {
         
         
@@ -468,7 +712,7 @@ impl TranscriptInstruction
             'D' => TranscriptInstruction::get_task_from_inframe_deletion(instruction, alt_stream, vec_tasks),
             'C' => TranscriptInstruction::get_task_from_inframe_deletion(instruction, alt_stream, vec_tasks),
             'K' => TranscriptInstruction::get_task_from_frameshift(instruction,alt_stream, vec_tasks),
-            'Q' | 'Z' | 'P'=> Task::new(2, 0, 0,0), // a phi-instruction ,
+            'Q' | 'Z' | 'P'=> Task::phi(), // a phi-instruction ,
             'A' => TranscriptInstruction::get_task_from_stop_gained(instruction,alt_stream, vec_tasks),
             'B' => TranscriptInstruction::get_task_from_frameshift(instruction,alt_stream, vec_tasks),
             'T' => TranscriptInstruction::get_task_from_stop_gained(instruction,alt_stream, vec_tasks),
@@ -476,178 +720,122 @@ impl TranscriptInstruction
             'Y' => TranscriptInstruction::get_task_from_frameshift(instruction,alt_stream, vec_tasks),
             '2' => TranscriptInstruction::get_task_from_instruction_2(instruction, alt_stream, vec_tasks),
             '3' => TranscriptInstruction::get_task_from_instruction_3(instruction, alt_stream, vec_tasks),
-            _=>panic!("Instruction: {:#?} is not supported",instruction)
+            'S' | 'V' => TranscriptInstruction::get_task_from_stop_lost(instruction, alt_stream, vec_tasks), // copy all the residues of the instruction
+            code=>return Err(PpggError::UnsupportedInstruction{transcript:transcript_name.to_string(),code})
         };
         let last_ins=vec_instruction.last().unwrap() == instruction; 
         let last_ins= match  last_ins
         {
             true =>
             {
-                let last_task_type=['K','Y','Q','A','B','P','Z','T','W','Z','T','W','Z','G','F','R','L','X'].iter().any(|c|*c==instruction.get_code()); 
+                let last_task_type=['K','Y','Q','A','B','P','Z','T','W','Z','T','W','Z','G','F','R','L','X','V'].iter().any(|c|*c==instruction.get_code()); 
                 
                 match last_task_type
                 {
-                    true => Task::new(2, 0,0,0),
-                    false => TranscriptInstruction::add_last_instruction(ref_len, instruction,ins_task.get_start_pos_res()+ins_task.get_length())
+                    true => Task::phi(),
+                    false => TranscriptInstruction::add_last_instruction(transcript_name, ref_len, instruction,ins_task.get_start_pos_res()+ins_task.get_length())?
                 }
             },
             false =>
             {
-                let last_task_type=['K','Q','A','B','P','Z','T','W','Z','T','W','Z','G','F','R','L'].iter().any(|c|*c==instruction.get_code()); 
+                let last_task_type=['K','Q','A','B','P','Z','T','W','Z','T','W','Z','G','F','R','L','V'].iter().any(|c|*c==instruction.get_code()); 
                 match last_task_type
                 {
-                    true => return Err(format!("Instruction: {:?} must be the last mutation in a transcript, current vector of instructions is: {:?}",instruction,vec_instruction)),
-                    false => TranscriptInstruction::add_till_next_ins(instruction, vec_instruction,&ins_task, &ref_len)
+                    true => return Err(PpggError::MisplacedInstruction{transcript:transcript_name.to_string(),code:instruction.get_code()}),
+                    false => TranscriptInstruction::add_till_next_ins(transcript_name, instruction, vec_instruction,&ins_task, &ref_len)?
                 }
             }
         };        
         Ok((ins_task,last_ins))   
     }
-    /// ## Summary 
-    /// add an instruction to copy the data until the next instruction in the vector of mutations
-    fn add_till_next_ins(ins:&instruction::Instruction, instructions:&Vec<instruction::Instruction>, last_task:&Task, ref_len:&usize)->Task
+    /// ## Summary
+    /// add an instruction to copy the data until the next instruction in the vector of mutations, an error is returned if the next 
+    /// instruction starts before the end of the current one 
+    fn add_till_next_ins(transcript_name:&str, ins:&instruction::Instruction, instructions:&[instruction::Instruction], last_task:&Task, ref_len:&Len)->Result<Task,PpggError>
     {
         let position=instructions.iter().position(|inst_cmp|inst_cmp==ins).unwrap();
         let next_ins=&instructions[position+1 as usize];
+        let pos_res=last_task.get_start_pos_res()+last_task.get_length();
         match ins.get_code()
         {
             'D' | 'C'=>
             {
+                // the deleted stretch ends at pos_ref+len, the first amino acid after it is unchanged
+                let end_deletion=ins.get_position_ref()+ins.get_length();
                 if next_ins.get_position_ref() == ins.get_position_ref()
                 {
-                    return Task::new(2, 0, 0,0)
-                }
-                else if (ins.get_position_ref() + ins.get_length())==next_ins.get_position_ref()
-                {
-                    return Task::new(2, 0, 0,0)
-                }
-                else if next_ins.get_code()=='L' && next_ins.get_position_ref()+1 as usize == *ref_len && (ins.get_position_ref()+ins.get_length() +1 as usize)==next_ins.get_position_ref() 
-                {
-                    let stat_pos=ins.get_position_ref()+ins.get_length() +1 as usize; 
-                    return Task::new(0, stat_pos, 1,last_task.get_start_pos_res()+last_task.get_length())
-            
-                }
-                else 
-                {
-                    let stat_pos=ins.get_position_ref()+ins.get_length() +1 as usize; 
-                    let res= panic::catch_unwind(||
-                    {
-                        Task::new(0, stat_pos, 
-                            next_ins.get_position_ref()- stat_pos, 
-                            last_task.get_start_pos_res()+last_task.get_length())
-                    }
-                    );
-                    match res
-                    {
-                        Ok(res)=>res, 
-                        Err(err)=>panic!("This error was encountered: {:#?} while generating a task from: {:#?}, {:#?}, {:#?},{:#?},\
-                        The current instruction is: {:#?} while all instructions are: {:#?}",
-                            err, stat_pos,next_ins.get_position_ref(),last_task.get_start_pos_res(),last_task.get_length(), ins,instructions )
-                    }   
+                    Ok(Task::phi())
                 }
-            }, 
-            '2'  =>
-            {
-                if next_ins.get_position_ref() == ins.get_position_ref()
+                else if end_deletion==next_ins.get_position_ref()
                 {
-                    return Task::new(2, 0, 0,0)
+                    Ok(Task::phi())
                 }
-                else if (ins.get_position_ref() + ins.get_length())==next_ins.get_position_ref()
+                else if next_ins.get_code()=='L' && next_ins.get_position_ref().is_last_residue(*ref_len) && end_deletion.next()==next_ins.get_position_ref()
                 {
-                    return Task::new(2, 0, 0,0)
+                    Ok(Task::copy_ref(end_deletion.next(), Len::new(1),pos_res))
                 }
                 else
                 {
-                    let start_pos=ins.get_position_ref() + ins.get_length();
-                    Task::new(0, 
-                        start_pos, 
-                    next_ins.get_position_ref() - start_pos, 
-                    last_task.get_start_pos_res()+last_task.get_length())
+                    TranscriptInstruction::copy_ref_until(transcript_name, end_deletion.next(), end_deletion.next(), next_ins.get_position_ref(), pos_res)
                 }
             },
-            '3' =>
+            '2' | '3' =>
             {
+                let start_pos=ins.get_position_ref() + ins.get_length();
                 if next_ins.get_position_ref() == ins.get_position_ref()
                 {
-                    return Task::new(2, 0, 0,0)
+                    Ok(Task::phi())
                 }
-                else if (ins.get_position_ref() + ins.get_length())==next_ins.get_position_ref()
+                else if start_pos==next_ins.get_position_ref()
                 {
-                    return Task::new(2, 0, 0,0)
+                    Ok(Task::phi())
                 }
                 else
                 {
-                    let start_pos=ins.get_position_ref() + ins.get_length(); 
-                    Task::new(0, 
-                        start_pos, 
-                    next_ins.get_position_ref() - start_pos, 
-                    last_task.get_start_pos_res()+last_task.get_length())
+                    TranscriptInstruction::copy_ref_until(transcript_name, start_pos, start_pos, next_ins.get_position_ref(), pos_res)
                 }
             },
             _=>
             {
                 if next_ins.get_position_ref() == ins.get_position_ref()
                 {
-                    Task::new(2, 0, 0,0)
+                    Ok(Task::phi())
                 }
                 else
                 {
-                    if next_ins.get_code()=='L'
+                    let start_pos=ins.get_position_ref().next();
+                    if next_ins.get_code()=='L' && next_ins.get_position_ref().is_last_residue(*ref_len)
                     {
-                        if next_ins.get_position_ref()+1 as usize ==*ref_len
-                        {
-                            return Task::new(0, ins.get_position_ref()+1 as usize,
-                        next_ins.get_position_ref() - ins.get_position_ref(),
-                        last_task.get_start_pos_res()+last_task.get_length())
-                        }
-                        else
-                        {
-                            return Task::new(0, ins.get_position_ref()+1 as usize,
-                        next_ins.get_position_ref() - (1 as usize) - ins.get_position_ref(),
-                        last_task.get_start_pos_res()+last_task.get_length())
-                        }
+                        return TranscriptInstruction::copy_ref_until(transcript_name, start_pos, ins.get_position_ref(), next_ins.get_position_ref(), pos_res)
                     }
-                    let res=panic::catch_unwind( ||
-                    {
-                        Task::new(0, ins.get_position_ref()+1 as usize,
-                    next_ins.get_position_ref() - (1 as usize) - ins.get_position_ref(),
-                    last_task.get_start_pos_res() + last_task.get_length())
-                    }); 
-                    match res {
-                        Ok(task)=>task,
-                        Err(err_msg)=>
-                        {
-                            println!("Instructions are : {:#?}",instructions); 
-                            panic!("The following error: {:#?} cause by: {}, {}",
-                        err_msg,next_ins.get_position_ref(), ins.get_position_ref())
-                        }
-                    }
-                    
+                    TranscriptInstruction::copy_ref_until(transcript_name, start_pos, start_pos, next_ins.get_position_ref(), pos_res)
                 }
             }
         }
     }
-    /// ## Summary 
-    /// Add the last instruction, i.e. adds an instruction that describing copying from the last mutation instruction 
-    /// in the transcript until the end of the transcript 
-    fn add_last_instruction(ref_seq:usize, instruction:&instruction::Instruction, pos_res_array:usize)->Task
+    /// ## Summary
+    /// Add the last instruction, i.e. adds an instruction that describing copying from the last mutation instruction
+    /// in the transcript until the end of the transcript, an error is returned if the instruction ends after the end of the transcript
+    fn add_last_instruction(transcript_name:&str, ref_len:Len, instruction:&instruction::Instruction, pos_res_array:usize)->Result<Task,PpggError>
     {
-        match instruction.get_code()
+        let start_pos=match instruction.get_code()
         {
-            'D' | 'C'=>
-            {
-                Task::new(0, instruction.get_position_ref()+instruction.get_length()+ 1 as usize, 
-                ref_seq-instruction.get_position_ref()-instruction.get_length() -1 as usize, 
-                pos_res_array)
-            },
-            '2' | '3' =>
-            {
-                Task::new(0, instruction.get_position_ref()+instruction.get_length(), 
-            ref_seq-instruction.get_position_ref()-instruction.get_length() as usize, pos_res_array)
-            },
-            _=> Task::new(0, instruction.get_position_ref()+1, 
-            ref_seq-instruction.get_position_ref()-1 as usize, pos_res_array)
-        }        
+            'D' | 'C'=> (instruction.get_position_ref()+instruction.get_length()).next(),
+            '2' | '3' => instruction.get_position_ref()+instruction.get_length(),
+            _=> instruction.get_position_ref().next()
+        };
+        TranscriptInstruction::copy_ref_until(transcript_name, start_pos, start_pos, RefPos0::new(ref_len.get()), pos_res_array)
+    }
+    /// ## Summary
+    /// Return a task copying the reference from start_pos, where the length of the task is the span between span_start and end, an error 
+    /// is returned if end is located before span_start 
+    fn copy_ref_until(transcript_name:&str, start_pos:RefPos0, span_start:RefPos0, end:RefPos0, pos_res:usize)->Result<Task,PpggError>
+    {
+        match span_start.checked_span_to(end)
+        {
+            Some(len)=>Ok(Task::copy_ref(start_pos, len, pos_res)),
+            None=>Err(PpggError::InvalidSpan{transcript:transcript_name.to_string(),start:span_start.get(),end:end.get()})
+        }
     }
     /// ## Summary 
     /// returns a Task from a missense mutation encoded as an instruction 
@@ -659,7 +847,7 @@ impl TranscriptInstruction
         alt_stream.extend(instruction.get_data().iter());
         alt_stream.extend(instruction.get_data().iter());
         let pos_altstream=alt_stream.len()-instruction.get_data().len(); 
-        Task::new(1, pos_altstream, 1,pos_result)
+        Task::copy_alt(pos_altstream, Len::new(1),pos_result)
     }
     /// ## Summary 
     /// returns a Task from a frameshift mutation encoded as an instruction 
@@ -675,21 +863,21 @@ impl TranscriptInstruction
         let pos_result=last_task.get_start_pos_res() + last_task.get_length() ;
         alt_stream.extend(instruction.get_data().iter());
         let pos_altstream=alt_stream.len()-instruction.get_data().len(); 
-        Task::new(1, pos_altstream, instruction.get_length(),pos_result)
+        Task::copy_alt(pos_altstream, instruction.get_length(),pos_result)
     }
     /// ## Summary 
     /// returns a Task from a stop-gained mutation encoded as an instruction 
     fn get_task_from_stop_gained(_instruction:&instruction::Instruction, _alt_stream:&mut Vec<char>,
         _vec_tasks:&Vec<Task>)->Task
     {
-        Task::new(2, 0, 0,0)
+        Task::phi()
     }
     /// ## Summary 
     /// returns a Task from an *stop-gained mutation encoded as an instruction 
     fn get_task_from_s_stop_gained(_instruction:&instruction::Instruction, _alt_stream:&mut Vec<char>,
         _vec_tasks:&Vec<Task>)->Task
     {
-        Task::new(2, 0, 0,0)
+        Task::phi()
     }
     /// ## Summary 
     /// returns a Task from a stop_lost mutation encoded as an instruction 
@@ -706,33 +894,34 @@ impl TranscriptInstruction
             0=>0,
             _=>alt_stream.len()-1
         };*/
-        Task::new(1, pos_altstream, instruction.get_data().len(),pos_result)
+        Task::copy_alt(pos_altstream, Len::new(instruction.get_data().len()),pos_result)
     }
     /// ## Summary 
     /// Build the base instruction for a transcript where the reference is copied until the first instruction 
-    fn build_base_instruction(instruction:&instruction::Instruction, ref_len:&usize)->Task
+    fn build_base_instruction(instruction:&instruction::Instruction, ref_len:&Len)->Task
     {
-        match instruction.get_code()
+        let len=match instruction.get_code()
         {
-            'Z' | 'Y'  => Task::new(0, 0, instruction.get_position_ref()+1, 0),
+            'Z' | 'Y'  => instruction.get_position_ref().next().prefix_len(),
+            'S' => Len::zero(), // the residues preceding the new start are trimmed
             'L'=>
             {
-                if instruction.get_position_ref()+1 as usize ==*ref_len
+                if instruction.get_position_ref().is_last_residue(*ref_len)
                 {
-                    Task::new(0, 0, instruction.get_position_ref()+1, 0)
+                    instruction.get_position_ref().next().prefix_len()
                 }
-                // edit here 
-                else if instruction.get_position_ref()==*ref_len
+                else if instruction.get_position_ref().is_stop_position(*ref_len)
                 {
-                    Task::new(0, 0, instruction.get_position_ref(), 0)
+                    instruction.get_position_ref().prefix_len()
                 }
                 else 
                 {
-                    Task::new(0, 0, instruction.get_position_res(), 0)
+                    instruction.get_position_res().prefix_len()
                 }
             },
-            _=> Task::new(0, 0, instruction.get_position_ref(), 0)
-        }
+            _=> instruction.get_position_ref().prefix_len()
+        };
+        Task::copy_ref(RefPos0::start(), len, 0)
     }
     /// ## Summary 
     /// Returns a Task from an inframe_insertion mutation encoded as an instruction 
@@ -743,7 +932,7 @@ impl TranscriptInstruction
         let last_task=vec_tasks.last().unwrap(); 
         let pos_result=last_task.get_start_pos_res() + last_task.get_length() ;
         alt_stream.extend(instruction.get_data().iter());
-        Task::new(1, pos_altstream, instruction.get_length(),pos_result)
+        Task::copy_alt(pos_altstream, instruction.get_length(),pos_result)
     }
     /// ## Summary 
     /// Returns a Task from an inframe_deletion mutation encoded as an instruction 
@@ -754,7 +943,7 @@ impl TranscriptInstruction
         let last_task=vec_tasks.last().unwrap(); 
         let pos_result=last_task.get_start_pos_res() + last_task.get_length();
         alt_stream.extend(instruction.get_data().iter());
-        Task::new(1, pos_altstream,  instruction.get_data().len(),pos_result)
+        Task::copy_alt(pos_altstream, Len::new(instruction.get_data().len()),pos_result)
     }
     /// ## Summary 
     /// Returns a Task from a 2 instruction, see the instruction module for more details   
@@ -765,7 +954,7 @@ impl TranscriptInstruction
         let last_task=vec_tasks.last().unwrap(); 
         let pos_result=last_task.get_start_pos_res() + last_task.get_length();
         alt_stream.extend(instruction.get_data().iter());
-        Task::new(1, pos_altstream, instruction.get_length(),pos_result)
+        Task::copy_alt(pos_altstream, instruction.get_length(),pos_result)
     }
     /// ## Summary 
     /// Returns a Task from a 3 instruction, see the instruction module for more details 
@@ -776,7 +965,7 @@ impl TranscriptInstruction
         let last_task=vec_tasks.last().unwrap(); 
         let pos_result=last_task.get_start_pos_res() + last_task.get_length();
         alt_stream.extend(instruction.get_data().iter());
-        Task::new(1, pos_altstream, instruction.get_data().len(),pos_result)
+        Task::copy_alt(pos_altstream, Len::new(instruction.get_data().len()),pos_result)
     }
 }
 
@@ -785,7 +974,8 @@ pub mod test_transcript_instruction
 {
     use super::*; 
     use crate::data_structures::mutation_ds::Mutation; 
-    use super::super::engines::Engine; 
+    use super::super::engines::Engine;
+    use super::super::gir::PLACEHOLDER_RESIDUE; 
     #[test]
     pub fn test_expected_result_array_length()
     {
@@ -795,12 +985,12 @@ pub mod test_transcript_instruction
         // Define the test case 
         let test_case=vec!["frameshift".to_string(),"ENST00000510017".to_string(), "40VGLHFWTM*>40VDSTFGQC".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec); 
+        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec).unwrap(); 
         let mut ins_vec=Vec::with_capacity(2);
         ins_vec.push(ins);
-        let test_alt_transcript=TranscriptInstruction::new("Test1".to_string(), 50, ins_vec);
+        let test_alt_transcript=TranscriptInstruction::new("Test1".to_string(), Len::new(50), ins_vec);
         println!("{:#?}",test_alt_transcript);
-        assert_eq!(test_alt_transcript.compute_expected_results_array_size(),47);
+        assert_eq!(test_alt_transcript.compute_expected_results_array_size(),Ok(47));
     }
     #[test]
     pub fn test_get_task_from_frameshift()
@@ -811,7 +1001,7 @@ pub mod test_transcript_instruction
         // Define the test case 
         let test_case=vec!["frameshift".to_string(),"ENST00000510017".to_string(), "40VGLHFWTM*>40VDSTFGQC".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec); 
+        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec).unwrap(); 
         let mut vec_tasks=Vec::with_capacity(2);
         vec_tasks.push(Task::new(0, 1, 15, 15));
         let mut alt_stream=Vec::with_capacity(100);
@@ -829,7 +1019,7 @@ pub mod test_transcript_instruction
         // define the test case 
         let test_case=vec!["stop_gained".to_string(),"ENST00000510017".to_string(), "40VGLHFWTM*>40*".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec); 
+        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec).unwrap(); 
         let mut vec_taks=Vec::with_capacity(2);
         vec_taks.push(Task::new(0, 0, 39, 0));
         let mut alt_stream=Vec::with_capacity(100);
@@ -847,7 +1037,7 @@ pub mod test_transcript_instruction
         // define the test case 
         let test_case=vec!["stop_lost".to_string(),"ENST00000650310".to_string(), "489*>489S".to_string()];
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec); 
+        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec).unwrap(); 
         let mut vec_taks=Vec::with_capacity(2);
         vec_taks.push(Task::new(0, 0, 488, 0));
         let mut alt_stream=Vec::with_capacity(100);
@@ -867,7 +1057,7 @@ pub mod test_transcript_instruction
         // define the test case 
         let test_case=vec!["inframe_insertion".to_string(),"ENST00000484547".to_string(), "125Y>125YRR".to_string()]; 
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
-        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec); 
+        let ins=instruction::Instruction::from_mutation(&test_mutation,&prev_mutation_vec).unwrap(); 
         let mut vec_taks=Vec::with_capacity(2);
         vec_taks.push(Task::new(0, 0, 124, 0));
         let mut alt_stream=Vec::with_capacity(10);
@@ -891,9 +1081,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, res_map)=test_gir.unwrap().execute(Engine::ST);
+        let (res_array, _)=test_gir.unwrap().execute(Engine::ST).unwrap().into_parts();
         println!("Res");
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
@@ -925,9 +1115,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, res_map)=test_gir.unwrap().execute(Engine::ST);
+        let (res_array, _)=test_gir.unwrap().execute(Engine::ST).unwrap().into_parts();
         println!("Res");
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
@@ -948,9 +1138,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -973,9 +1163,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -995,9 +1185,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1017,9 +1207,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1040,9 +1230,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1061,9 +1251,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1082,9 +1272,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1103,9 +1293,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1125,9 +1315,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1146,9 +1336,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1167,9 +1357,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1188,9 +1378,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1209,9 +1399,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1230,9 +1420,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1251,9 +1441,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1272,9 +1462,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1293,9 +1483,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1314,9 +1504,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1335,9 +1525,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1356,9 +1546,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1377,9 +1567,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1398,9 +1588,9 @@ pub mod test_transcript_instruction
         let mut reference=HashMap::new(); 
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1429,9 +1619,9 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1464,9 +1654,9 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1499,9 +1689,9 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1534,9 +1724,9 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1561,9 +1751,9 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
@@ -1583,13 +1773,109 @@ pub mod test_transcript_instruction
         let ref_seq_array="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
         reference.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA".to_string());
         let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
-        let test_gir=res.get_g_rep(&reference); 
+        let test_gir=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE); 
         println!("{:#?}",test_gir); 
-        let (res_array, _)=test_gir.unwrap().execute(Engine::ST);
+        let res_array=test_gir.unwrap().execute(Engine::ST).unwrap().sequence;
         let ref_string=ref_seq_array.to_string();
         println!("Input Sequence is:  ==>{:#?}",&ref_string);
         let res_string=res_array.iter().collect::<String>();
         println!("Result sequence is: ==>{:#?}",&res_string);
         assert_eq!(81 as usize, res_string.len());
     }       
-}
\ No newline at end of file
+    #[test]
+    fn test_correct_translation_beyond_u16_positions()
+    {
+        // positions beyond 65,535 must not overflow, e.g. in titin-like giant proteins 
+        let name="ENST00000589042".to_string();
+        let mutations=vec![
+            "*missense|TTN|ENST00000589042|protein_coding|-|66000V>66000H|1936821C>T".to_string(),
+            "inframe_insertion|TTN|ENST00000589042|protein_coding|-|69000G>69000GTEST|1936821C>T".to_string(),
+        ];
+        let alt_transcript= vcf_ds::AltTranscript::new(name, mutations);
+        let mut ref_chars=vec!['A';70000];
+        ref_chars[0]='M'; 
+        ref_chars[65999]='V'; 
+        ref_chars[68999]='G';
+        ref_chars[69999]='W';
+        let ref_string=ref_chars.iter().collect::<String>();
+        let mut reference=HashMap::new(); 
+        reference.insert("ENST00000589042".to_string(),ref_string.clone());
+        let res=TranscriptInstruction::from_alt_transcript(alt_transcript, &reference).unwrap(); 
+        assert_eq!(res.get_instructions()[0].get_position_ref().get(),65999);
+        assert_eq!(res.get_instructions()[1].get_position_ref().get(),68999);
+        let res_array=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE).unwrap().execute(Engine::ST).unwrap().sequence;
+        let res_string=res_array.iter().collect::<String>();
+        assert_eq!(res_string.len(),70004);
+        assert_eq!(&res_string[..65999],&ref_string[..65999]);
+        assert_eq!(&res_string[65999..66000],"H");
+        assert_eq!(&res_string[68999..69004],"GTEST");
+        assert_eq!(&res_string[69004..],&ref_string[69000..]);
+    }    #[test]
+    fn test_errors_instead_of_panics()
+    {
+        let name="ENST00000406869".to_string();
+        let mutations=vec!["*missense|SCN11A|ENST00000406869|protein_coding|-|2V>2H|1936821C>T".to_string()];
+        let mut reference=HashMap::new(); 
+        reference.insert(name.clone(),"MVAW".to_string());
+        let res=TranscriptInstruction::from_alt_transcript(vcf_ds::AltTranscript::new(name.clone(), mutations.clone()), &reference).unwrap(); 
+        // the reference of the transcript is missing while generating the tasks 
+        assert_eq!(res.get_g_rep(&HashMap::new(),PLACEHOLDER_RESIDUE).unwrap_err(),PpggError::MissingTranscript(name.clone()));
+        // and while interpreting the mutations 
+        assert_eq!(TranscriptInstruction::from_alt_transcript(vcf_ds::AltTranscript::new(name.clone(), mutations), &HashMap::new()).unwrap_err(),
+            PpggError::MissingTranscript(name));
+        let res_array=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE).unwrap().execute(Engine::ST).unwrap().sequence;
+        assert_eq!(res_array.iter().collect::<String>(),"MHAW");
+    }
+    #[test]
+    fn test_start_and_stop_reselection()
+    {
+        let name="T1".to_string();
+        // the missense at position 2 is trimmed along with the residues preceding the new start at position 5 
+        let mutations=vec![
+            "missense|G1|T1|protein_coding|-|2K>2R|100A>G".to_string(),
+            "start_reselection|G1|T1|protein_coding|-|5M>1M|110A>G".to_string(),
+            "missense|G1|T1|protein_coding|-|7W>3F|120G>T".to_string(),
+            "stop_reselection|G1|T1|protein_coding|-|9*>5KLQ*|130A>G".to_string(),
+        ];
+        let mut reference=HashMap::new(); 
+        reference.insert(name.clone(),"MKALMPWV".to_string());
+        let res=TranscriptInstruction::from_alt_transcript(vcf_ds::AltTranscript::new(name.clone(), mutations), &reference).unwrap(); 
+        assert_eq!(res.get_instructions().iter().map(|ins|ins.get_code()).collect::<String>(),"SMV");
+        assert_eq!(res.compute_expected_results_array_size(),Ok(7));
+        let res_array=res.get_g_rep(&reference,PLACEHOLDER_RESIDUE).unwrap().execute(Engine::ST).unwrap().sequence;
+        assert_eq!(res_array.iter().collect::<String>(),"MPFVKLQ");
+        // a stop re-selection must start at the stop codon of the reference 
+        let mutations=vec!["stop_reselection|G1|T1|protein_coding|-|6P>6PKLQ*|130A>G".to_string()];
+        assert!(matches!(TranscriptInstruction::from_alt_transcript(vcf_ds::AltTranscript::new(name, mutations), &reference),
+            Err(PpggError::UninterpretableMutation{..})));
+    }
+    #[test]
+    pub fn test_overlap_policies()
+    {
+        // a missense at 10, a frameshift starting at the same position and a compatible missense writing the same residue at 10
+        let missense=Instruction::new('M',false,RefPos0::new(10),RefPos0::new(10),Len::new(1),vec!['N']);
+        let frameshift=Instruction::new('F',false,RefPos0::new(10),RefPos0::new(10),Len::new(3),vec!['N','K','L']);
+        let unrelated=Instruction::new('M',false,RefPos0::new(40),RefPos0::new(40),Len::new(1),vec!['W']);
+        let resolve=|instructions:Vec<Instruction>,policy|
+        {
+            let mut summary=OverlapSummary::default();
+            (TranscriptInstruction::resolve_overlaps("T1",instructions,policy,&mut summary),summary)
+        };
+        let (kept,summary)=resolve(vec![missense.clone(),frameshift.clone(),unrelated.clone()],OverlapPolicy::KeepFirst);
+        assert_eq!((kept.unwrap(),summary.num_conflicts,summary.num_dropped_mutations),(vec![missense.clone(),unrelated.clone()],1,1));
+        let (kept,_)=resolve(vec![missense.clone(),frameshift.clone(),unrelated.clone()],OverlapPolicy::KeepMostSevere);
+        assert_eq!(kept.unwrap(),vec![frameshift.clone(),unrelated.clone()]);
+        let (kept,summary)=resolve(vec![frameshift.clone(),missense.clone()],OverlapPolicy::MergeIfCompatible);
+        assert_eq!((kept.unwrap(),summary.num_merged_mutations),(vec![frameshift.clone()],1));
+        let (kept,summary)=resolve(vec![missense.clone(),frameshift.clone()],OverlapPolicy::MergeIfCompatible);
+        assert!(matches!(kept,Err(PpggError::OverlappingMutations{..})) && summary.num_dropped_transcripts==1);
+        let (kept,_)=resolve(vec![missense.clone(),unrelated.clone()],OverlapPolicy::DropTranscript);
+        assert_eq!(kept.unwrap().len(),2);
+        assert!(matches!(resolve(vec![missense,frameshift],OverlapPolicy::DropTranscript).0,Err(PpggError::OverlappingMutations{..})));
+        let mut total=summary;
+        total.merge(&OverlapSummary{num_conflicts:2,..Default::default()});
+        assert_eq!((total.num_conflicts,total.num_dropped_transcripts),(3,1));
+        assert_eq!(OverlapPolicy::from_str("keep_most_severe").unwrap(),OverlapPolicy::KeepMostSevere);
+        assert!(OverlapPolicy::from_str("ignore").is_err());
+    }
+}
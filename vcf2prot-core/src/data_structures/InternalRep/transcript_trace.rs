@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use super::engines::Engine;
+use super::instruction::Instruction;
+use super::task::Task;
+use super::transcript_instructions::{OverlapPolicy,OverlapSummary,TranscriptInstruction};
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::{Mutation,MutatedString};
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::functions::diagnostics::DiagnosticOptions;
+
+/// ## Summary
+/// The derivation of the sequence of one transcript in one haplotype of a proband, i.e. the sorted mutations observed in the haplotype,
+/// the instructions they were translated into, the execution tasks generated from the instructions, the alternative stream the tasks
+/// read from and the resulting sequence. If any of the steps failed the error is stored and the following steps are left empty.
+#[derive(Debug,Clone)]
+pub struct HaplotypeTrace
+{
+    pub proband_name:String,
+    pub haplotype:u8,
+    pub mutations:Vec<Mutation>,
+    pub instructions:Vec<Instruction>,
+    pub tasks:Vec<Task>,
+    pub alt_stream:Vec<char>,
+    pub sequence:String,
+    pub error:Option<String>
+}
+impl HaplotypeTrace
+{
+    /// ## Summary
+    /// Trace the derivation of the sequence of an altered transcript in one haplotype, where the results array is initialized with the 
+    /// provided placeholder residue, see gir::PlaceholderPolicy, the overlapping mutations are resolved with the provided strategy, if any, 
+    /// and the translation and the tasks are checked with the diagnostics 
+    pub fn new(proband_name:String, haplotype:u8, mut alt_transcript:AltTranscript, ref_seqs:&HashMap<String,String>, placeholder:char, 
+        overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions)->Self
+    {
+        alt_transcript.sort_alterations();
+        let mut trace=HaplotypeTrace{proband_name,haplotype,mutations:alt_transcript.alts.clone(),instructions:Vec::new(),
+                                tasks:Vec::new(),alt_stream:Vec::new(),sequence:String::new(),error:None};
+        let transcript_instruction=match TranscriptInstruction::from_alt_transcript_with_policies(alt_transcript, ref_seqs, overlap_policy, diagnostic_options, &mut OverlapSummary::default())
+        {
+            Ok(res)=>res,
+            Err(err_msg)=>
+            {
+                trace.error=Some(err_msg.to_string());
+                return trace
+            }
+        };
+        trace.instructions=transcript_instruction.get_instructions().clone();
+        let g_rep=match transcript_instruction.get_g_rep_with_checks(ref_seqs,placeholder,diagnostic_options)
+        {
+            Ok(res)=>res,
+            Err(err_msg)=>
+            {
+                trace.error=Some(err_msg.to_string());
+                return trace
+            }
+        };
+        trace.tasks=g_rep.get_tasks().clone();
+        trace.alt_stream=g_rep.clone().consumer_and_get_resources().2;
+        match g_rep.execute(Engine::ST)
+        {
+            Ok(result)=>trace.sequence=result.sequence.into_iter().collect::<String>(),
+            Err(err_msg)=>trace.error=Some(err_msg.to_string())
+        }
+        trace
+    }
+}
+
+/// ## Summary
+/// A step by step report of how the sequence of a single transcript is derived in each haplotype of a collection of probands, it replaces
+/// inspecting the intermediate representations one by one when debugging a specific transcript. Haplotypes that do not carry an alteration
+/// in the transcript are not traced as their sequence is the reference sequence.
+#[derive(Debug,Clone)]
+pub struct TranscriptTrace
+{
+    pub transcript_name:String,
+    pub reference:String,
+    pub haplotypes:Vec<HaplotypeTrace>
+}
+impl TranscriptTrace
+{
+    /// ## Summary
+    /// Trace the transcript across all the probands in the provided slice of IntMaps, returns an error if the transcript is not
+    /// in the reference, the results arrays are initialized with the provided placeholder residue, see gir::PlaceholderPolicy, and the 
+    /// overlapping mutations are resolved with the provided strategy, if any, and checked with the diagnostics otherwise 
+    /// ## Example
+    ///```rust
+    /// use std::collections::HashMap;
+    /// use ppgg::data_structures::Map::IntMap;
+    /// use ppgg::data_structures::vcf_ds::AltTranscript;
+    /// use ppgg::data_structures::InternalRep::transcript_trace::TranscriptTrace;
+    /// use ppgg::data_structures::InternalRep::gir::PLACEHOLDER_RESIDUE;
+    /// use ppgg::functions::diagnostics::DiagnosticOptions;
+    /// let alt_transcript=AltTranscript::new("ENST00000406869".to_string(),
+    ///             vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|5G>5H|1936821C>T".to_string()]);
+    /// let int_map=IntMap::new("sample1".to_string(),vec![alt_transcript],Vec::new());
+    /// let mut ref_seq=HashMap::new();
+    /// ref_seq.insert("ENST00000406869".to_string(),"MEDLGENTMV".to_string());
+    /// let trace=TranscriptTrace::new(&[int_map],"ENST00000406869",&ref_seq,PLACEHOLDER_RESIDUE,None,&DiagnosticOptions::default()).unwrap();
+    /// assert_eq!(trace.haplotypes.len(),1);
+    /// assert_eq!(trace.haplotypes[0].sequence,"MEDLHENTMV");
+    /// println!("{}",trace.to_report());
+    ///```
+    pub fn new(vec_int_repr:&[IntMap], transcript_name:&str, ref_seqs:&HashMap<String,String>, placeholder:char, 
+        overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions)->Result<Self,String>
+    {
+        let reference=match ref_seqs.get(transcript_name)
+        {
+            Some(sequence)=>sequence.clone(),
+            None=>return Err(format!("The provided transcript name: {} is not in the reference sequence", transcript_name))
+        };
+        let mut haplotypes=Vec::new();
+        for int_map in vec_int_repr.iter()
+        {
+            let (mutations1,mutations2)=int_map.get_mutations_ref();
+            for (haplotype,mutations) in [(1_u8,mutations1),(2_u8,mutations2)].iter()
+            {
+                for alt_transcript in mutations.iter().filter(|alt_transcript|alt_transcript.name==transcript_name)
+                {
+                    haplotypes.push(HaplotypeTrace::new(int_map.get_name().clone(), *haplotype, alt_transcript.clone(), ref_seqs, placeholder, overlap_policy, diagnostic_options));
+                }
+            }
+        }
+        Ok(TranscriptTrace{transcript_name:transcript_name.to_string(),reference,haplotypes})
+    }
+    /// ## Summary
+    /// Render the trace as a human readable report, positions are zero-based
+    pub fn to_report(&self)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"=== Trace of transcript: {} ===",self.transcript_name).unwrap();
+        writeln!(report,"Reference sequence ({} aa): {}",self.reference.len(),self.reference).unwrap();
+        writeln!(report,"Number of traced haplotypes: {}",self.haplotypes.len()).unwrap();
+        for trace in self.haplotypes.iter()
+        {
+            writeln!(report).unwrap();
+            writeln!(report,"--- Proband: {}, haplotype: {} ---",trace.proband_name,trace.haplotype).unwrap();
+            writeln!(report,"1. Mutations ({}):",trace.mutations.len()).unwrap();
+            writeln!(report,"index\ttype\tref_position\tref_aa\tmut_position\tmut_aa").unwrap();
+            for (idx,mutation) in trace.mutations.iter().enumerate()
+            {
+                writeln!(report,"{}\t{:?}\t{}\t{}\t{}\t{}",idx,mutation.mut_type,mutation.mut_info.ref_aa_position,
+                            format_mutated_string(&mutation.mut_info.ref_aa),mutation.mut_info.mut_aa_position,
+                            format_mutated_string(&mutation.mut_info.mut_aa)).unwrap();
+            }
+            writeln!(report,"2. Instructions ({}):",trace.instructions.len()).unwrap();
+            writeln!(report,"index\tcode\tstop_state\tref_position\tres_position\tlength\tdata").unwrap();
+            for (idx,instruction) in trace.instructions.iter().enumerate()
+            {
+                writeln!(report,"{}\t{}\t{}\t{}\t{}\t{}\t{}",idx,instruction.get_code(),instruction.get_s_state(),
+                            instruction.get_position_ref(),instruction.get_position_res(),instruction.get_length(),
+                            instruction.get_data().into_iter().collect::<String>()).unwrap();
+            }
+            writeln!(report,"3. Tasks ({}), alternative stream: {}",trace.tasks.len(),trace.alt_stream.iter().collect::<String>()).unwrap();
+            writeln!(report,"index\tstream\tstart_position\tlength\tposition_results\tsegment").unwrap();
+            for (idx,task) in trace.tasks.iter().enumerate()
+            {
+                let stream=match task.get_stream()
+                {
+                    0=>"ref",
+                    _=>"alt"
+                };
+                writeln!(report,"{}\t{}\t{}\t{}\t{}\t{}",idx,stream,task.get_start_pos(),task.get_length(),task.get_start_pos_res(),
+                            self.get_segment(trace,task)).unwrap();
+            }
+            match &trace.error
+            {
+                Some(err_msg)=>writeln!(report,"4. Sequence: not generated, {}",err_msg).unwrap(),
+                None=>writeln!(report,"4. Sequence ({} aa): {}",trace.sequence.len(),trace.sequence).unwrap()
+            }
+        }
+        report
+    }
+    /// ## Summary
+    /// Return the segment of the reference or the alternative stream a task copies into the results array
+    fn get_segment(&self, trace:&HaplotypeTrace, task:&Task)->String
+    {
+        let start=task.get_start_pos();
+        let end=start+task.get_length();
+        match task.get_stream()
+        {
+            0=>self.reference.chars().skip(start).take(end-start).collect::<String>(),
+            _=>trace.alt_stream.iter().skip(start).take(end-start).collect::<String>()
+        }
+    }
+}
+
+fn format_mutated_string(mutated_string:&MutatedString)->String
+{
+    match mutated_string
+    {
+        MutatedString::Sequence(seq) | MutatedString::EndSequence(seq)=>seq.clone(),
+        MutatedString::NotSeq=>"*".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_transcript_trace
+{
+    use super::*;
+    use crate::data_structures::InternalRep::gir::PLACEHOLDER_RESIDUE;
+    fn get_reference()->HashMap<String,String>
+    {
+        let mut ref_seq=HashMap::new();
+        ref_seq.insert("ENST00000406869".to_string(),"MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG".to_string());
+        ref_seq
+    }
+    #[test]
+    fn test_trace_missense()
+    {
+        let alt_transcript=AltTranscript::new("ENST00000406869".to_string(),
+                    vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|5G>5H|1936821C>T".to_string()]);
+        let int_map=IntMap::new("sample1".to_string(),vec![alt_transcript.clone()],vec![alt_transcript]);
+        let trace=TranscriptTrace::new(&[int_map],"ENST00000406869",&get_reference(),PLACEHOLDER_RESIDUE,None,&DiagnosticOptions::default()).unwrap();
+        assert_eq!(trace.haplotypes.len(),2);
+        assert_eq!(trace.haplotypes[0].haplotype,1);
+        assert_eq!(trace.haplotypes[1].haplotype,2);
+        for haplotype in trace.haplotypes.iter()
+        {
+            assert!(haplotype.error.is_none());
+            assert_eq!(haplotype.mutations.len(),1);
+            assert_eq!(haplotype.instructions.len(),1);
+            assert_eq!(haplotype.sequence,"MEDLHENTMVLSTLRSLNNFISQRVEGGSGLEELERGG");
+        }
+        let report=trace.to_report();
+        assert!(report.contains("--- Proband: sample1, haplotype: 2 ---"));
+        assert!(report.contains("4. Sequence (38 aa): MEDLHENTMVLSTLRSLNNFISQRVEGGSGLEELERGG"));
+    }
+    #[test]
+    fn test_trace_only_includes_the_transcript()
+    {
+        let other=AltTranscript::new("ENST00000000001".to_string(),
+                    vec!["*missense|GENE|ENST00000000001|protein_coding|-|2A>2B|100C>T".to_string()]);
+        let int_map=IntMap::new("sample1".to_string(),vec![other],Vec::new());
+        let trace=TranscriptTrace::new(&[int_map],"ENST00000406869",&get_reference(),PLACEHOLDER_RESIDUE,None,&DiagnosticOptions::default()).unwrap();
+        assert!(trace.haplotypes.is_empty());
+    }
+    #[test]
+    fn test_trace_unknown_transcript()
+    {
+        assert!(TranscriptTrace::new(&[],"ENST_UNKNOWN",&get_reference(),PLACEHOLDER_RESIDUE,None,&DiagnosticOptions::default()).is_err());
+    }
+}
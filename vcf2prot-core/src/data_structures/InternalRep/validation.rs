@@ -0,0 +1,229 @@
+/// The module validates the intermediate representations of a cohort before they are executed, i.e. the altered transcripts of every
+/// haplotype are translated into instructions and tasks, which are checked for contiguity and for filling a results array of the expected
+/// size, the violations are collected into a report and the offending transcripts are excluded from the probands instead of stopping the run
+use std::collections::{BTreeMap,HashMap,HashSet};
+use std::fmt::Write;
+use rayon::prelude::*;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::error::PpggError;
+use crate::functions::diagnostics::DiagnosticOptions;
+use super::engines::Engine;
+use super::transcript_instructions::{OverlapPolicy,OverlapSummary,TranscriptInstruction};
+
+/// ## Summary
+/// The kind of a violation:
+/// 1. OverlappingMutations, two mutations of the transcript are located at the same position or overlap, see CheckPolicy
+/// 2. NonContiguousTasks, a task does not start where the previous task ends in the results array
+/// 3. SizeMismatch, the tasks do not fill the results array of the expected size
+/// 4. InvalidTasks, the tasks of the transcript could not be generated, e.g. an invalid span or a negative sequence length
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum ViolationKind
+{
+    OverlappingMutations,
+    NonContiguousTasks,
+    SizeMismatch,
+    InvalidTasks
+}
+impl ViolationKind
+{
+    /// ## Summary
+    /// Return the name of the violation as used in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ViolationKind::OverlappingMutations=>"overlapping-mutations",
+            ViolationKind::NonContiguousTasks=>"non-contiguous-tasks",
+            ViolationKind::SizeMismatch=>"size-mismatch",
+            ViolationKind::InvalidTasks=>"invalid-tasks"
+        }
+    }
+    /// ## Summary
+    /// Return the kind of violation an error of the translation into tasks describes
+    pub fn from_error(error:&PpggError)->Self
+    {
+        match error
+        {
+            PpggError::OverlappingMutations{..}=>ViolationKind::OverlappingMutations,
+            PpggError::InconsistentTasks{..}=>ViolationKind::NonContiguousTasks,
+            PpggError::SizeMismatch{..}=>ViolationKind::SizeMismatch,
+            _=>ViolationKind::InvalidTasks
+        }
+    }
+}
+
+/// ## Summary
+/// A violation found in one haplotype of a transcript of a proband, along with the message of the error describing it
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Violation
+{
+    pub proband:String,
+    pub haplotype:u8,
+    pub transcript:String,
+    pub kind:ViolationKind,
+    pub message:String
+}
+
+/// ## Summary
+/// The violations found in a cohort, along with the number of validated transcripts, i.e. the altered transcripts summed over the
+/// haplotypes of all probands
+#[derive(Debug,Clone,Default,PartialEq,Eq)]
+pub struct ValidationReport
+{
+    pub num_validated:usize,
+    pub violations:Vec<Violation>
+}
+impl ValidationReport
+{
+    /// ## Summary
+    /// Return whether no violation was found
+    pub fn is_empty(&self)->bool
+    {
+        self.violations.is_empty()
+    }
+    /// ## Summary
+    /// Return the number of excluded transcripts, i.e. the pairs of a proband and a transcript with a violation in at least one haplotype
+    pub fn get_num_excluded(&self)->usize
+    {
+        self.violations.iter().map(|violation|(&violation.proband,&violation.transcript)).collect::<HashSet<_>>().len()
+    }
+    /// ## Summary
+    /// Return the violations as a tab-separated table with a header, sorted by proband, transcript and haplotype
+    pub fn to_table(&self)->String
+    {
+        let mut violations=self.violations.iter().collect::<Vec<_>>();
+        violations.sort_by(|v1,v2|(&v1.proband,&v1.transcript,v1.haplotype).cmp(&(&v2.proband,&v2.transcript,v2.haplotype)));
+        let mut table=String::from("Proband\tHaplotype\tTranscript\tViolation\tMessage\n");
+        for violation in violations
+        {
+            writeln!(table,"{}\t{}\t{}\t{}\t{}",violation.proband,violation.haplotype,violation.transcript,violation.kind.as_str(),violation.message).unwrap();
+        }
+        table
+    }
+    /// ## Summary
+    /// Return a summary of the report, i.e. the number of violations per kind and the transcripts with the most violations, at most max_rows
+    pub fn to_summary(&self, max_rows:usize)->String
+    {
+        let mut summary=format!("Validated transcripts: {}, violations: {}, excluded transcripts: {}\n",self.num_validated,self.violations.len(),self.get_num_excluded());
+        let mut per_kind=BTreeMap::new();
+        let mut per_transcript=HashMap::new();
+        for violation in self.violations.iter()
+        {
+            *per_kind.entry(violation.kind).or_insert(0_usize)+=1;
+            *per_transcript.entry(&violation.transcript).or_insert(0_usize)+=1;
+        }
+        for (kind,count) in per_kind
+        {
+            writeln!(summary,"{}\t{}",kind.as_str(),count).unwrap();
+        }
+        let mut per_transcript=per_transcript.into_iter().collect::<Vec<_>>();
+        per_transcript.sort_by(|(t1,c1),(t2,c2)|c2.cmp(c1).then(t1.cmp(t2)));
+        for (transcript,count) in per_transcript.into_iter().take(max_rows)
+        {
+            writeln!(summary,"{}\t{}",transcript,count).unwrap();
+        }
+        summary
+    }
+}
+
+/// ## Summary
+/// Validate an altered transcript, i.e. translate it into instructions and check its tasks, see TranscriptInstruction::validate_tasks.
+/// Transcripts that can not be translated for another reason than overlapping mutations, e.g. a missing reference or unsupported
+/// mutations, are not violations, as they are skipped by the execution engines anyway. The overlapping mutations are resolved with the 
+/// provided strategy, if any, as they are resolved by the execution engines, and are only detected otherwise if the check policy of the 
+/// diagnostics is enabled, see diagnostics::CheckPolicy 
+pub fn validate_transcript(alt_transcript:&AltTranscript, ref_seq:&HashMap<String,String>, overlap_policy:Option<OverlapPolicy>, 
+    diagnostic_options:&DiagnosticOptions)->Result<(),PpggError>
+{
+    match TranscriptInstruction::from_alt_transcript_with_policies(alt_transcript.clone(), ref_seq, overlap_policy, diagnostic_options, &mut OverlapSummary::default())
+    {
+        Ok(instruction)=>instruction.validate_tasks(ref_seq),
+        Err(error @ PpggError::OverlappingMutations{..})=>Err(error),
+        Err(_)=>Ok(())
+    }
+}
+/// ## Summary
+/// Validate the altered transcripts of every haplotype of every proband and exclude the transcripts with a violation in any haplotype
+/// from the proband, the overlapping mutations are resolved with the provided strategy, if any, or checked with the diagnostics, returns 
+/// the report of the violations
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::data_structures::Map::IntMap;
+/// use ppgg::data_structures::vcf_ds::AltTranscript;
+/// use ppgg::data_structures::InternalRep::{engines::Engine,validation};
+/// use ppgg::functions::diagnostics::DiagnosticOptions;
+/// let alt_transcript=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|-|2K>2N|1936821C>T".to_string()]);
+/// let mut vec_int_repr=vec![IntMap::new("P1".to_string(),vec![alt_transcript],Vec::new())];
+/// let ref_seq=vec![("T1".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+/// let report=validation::validate_representations(&mut vec_int_repr,&ref_seq,Engine::ST,None,&DiagnosticOptions::default());
+/// assert!(report.is_empty());
+/// assert_eq!(report.num_validated,1);
+///```
+pub fn validate_representations(vec_int_repr:&mut [IntMap], ref_seq:&HashMap<String,String>, engine:Engine, 
+    overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions)->ValidationReport
+{
+    let validate_proband=|int_map:&mut IntMap|->(usize,Vec<Violation>)
+    {
+        let mut violations=Vec::new();
+        let (mutations1,mutations2)=int_map.get_mutations_ref();
+        let num_validated=mutations1.len()+mutations2.len();
+        for (haplotype,alt_transcripts) in [(1,mutations1),(2,mutations2)]
+        {
+            for alt_transcript in alt_transcripts.iter()
+            {
+                if let Err(error)=validate_transcript(alt_transcript, ref_seq, overlap_policy, diagnostic_options)
+                {
+                    violations.push(Violation{proband:int_map.get_name().clone(),haplotype,transcript:alt_transcript.name.clone(),
+                        kind:ViolationKind::from_error(&error),message:error.to_string()});
+                }
+            }
+        }
+        let excluded=violations.iter().map(|violation|violation.transcript.clone()).collect::<HashSet<String>>();
+        if !excluded.is_empty()
+        {
+            int_map.retain_transcripts(|name|!excluded.contains(name));
+        }
+        (num_validated,violations)
+    };
+    let results=match engine
+    {
+        Engine::ST=>vec_int_repr.iter_mut().map(validate_proband).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_int_repr.par_iter_mut().map(validate_proband).collect::<Vec<_>>()
+    };
+    let mut report=ValidationReport::default();
+    for (num_validated,violations) in results
+    {
+        report.num_validated+=num_validated;
+        report.violations.extend(violations);
+    }
+    report
+}
+
+#[cfg(test)]
+mod test_validation
+{
+    use super::*;
+    #[test]
+    fn test_report_excludes_offending_transcripts()
+    {
+        let violation=|proband:&str,haplotype:u8,transcript:&str,kind:ViolationKind|Violation{proband:proband.to_string(),haplotype,
+            transcript:transcript.to_string(),kind,message:"failed".to_string()};
+        let report=ValidationReport{num_validated:6,violations:vec![violation("P2",1,"T1",ViolationKind::SizeMismatch),
+            violation("P1",2,"T1",ViolationKind::NonContiguousTasks),violation("P1",1,"T1",ViolationKind::NonContiguousTasks)]};
+        assert_eq!(report.get_num_excluded(),2);
+        assert_eq!(report.to_table().lines().nth(1),Some("P1\t1\tT1\tnon-contiguous-tasks\tfailed"));
+        assert!(report.to_summary(5).starts_with("Validated transcripts: 6, violations: 3, excluded transcripts: 2\nnon-contiguous-tasks\t2\nsize-mismatch\t1\nT1\t3"));
+        // a missense beyond the end of the reference can not be translated into tasks, hence, the transcript is excluded from the proband
+        let valid=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|-|2K>2N|1936821C>T".to_string()]);
+        let beyond=AltTranscript::new("T2".to_string(),vec!["missense|G2|T2|protein_coding|-|9K>9N|1936821C>T".to_string()]);
+        let ref_seq=vec![("T1".to_string(),"MKLV".to_string()),("T2".to_string(),"MAAW".to_string())].into_iter().collect::<HashMap<String,String>>();
+        let mut vec_int_repr=vec![IntMap::new("P1".to_string(),vec![valid.clone(),beyond.clone()],vec![valid,beyond])];
+        let report=validate_representations(&mut vec_int_repr,&ref_seq,Engine::MT,None,&DiagnosticOptions::default());
+        assert_eq!((report.num_validated,report.violations.len(),report.get_num_excluded()),(4,2,1));
+        assert_eq!(report.violations[0].kind,ViolationKind::InvalidTasks);
+        assert_eq!(vec_int_repr[0].get_mutations_ref().0.iter().map(|alt|alt.name.as_str()).collect::<Vec<_>>(),vec!["T1"]);
+        assert_eq!(vec_int_repr[0].get_mutations_ref().1.len(),1);
+    }
+}
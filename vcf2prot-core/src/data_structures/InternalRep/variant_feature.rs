@@ -0,0 +1,241 @@
+// load the modules and crates
+use serde::{Deserialize, Serialize};
+use super::coordinates::{RefPos0,Len};
+
+/// ## Summary
+/// An applied variant projected onto the coordinate system of a personalized sequence, i.e. the interval of the personalized sequence
+/// that has been written from the alternative stream. The feature stores the transcript name, the instruction code, the position of the
+/// variant on the reference protein, along with the start and the length of the interval on the personalized sequence.
+/// Variants that do not add residues, e.g. stop-gained, have an empty interval located at the position where the sequence is altered.
+/// Optionally, the number of reference amino acids replaced by the variant is stored, see with_reference_length, which is zero by default.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::variant_feature::VariantFeature;
+/// use ppgg::data_structures::InternalRep::coordinates::{RefPos0,Len};
+/// let feature=VariantFeature::new("ENST00000406869".to_string(),'M',RefPos0::new(4),RefPos0::new(4),Len::new(1));
+/// assert_eq!(feature.get_feature_type(),"missense_variant");
+/// assert_eq!(feature.to_gff3(1,0),
+///     "ENST00000406869_1\tvcf2prot\tmissense_variant\t5\t5\t.\t.\t.\tID=ENST00000406869_1.v1;Name=missense_variant;ref_position=5;instruction_code=M");
+///```
+#[derive(Debug,Clone,PartialEq,Serialize,Deserialize)]
+pub struct VariantFeature
+{
+    transcript_name:String,
+    code:char,
+    pos_ref:RefPos0,
+    start:RefPos0,
+    len:Len,
+    #[serde(default="Len::zero")]
+    ref_len:Len
+}
+impl VariantFeature
+{
+    /// ## Summary
+    /// Create a new feature from a transcript name, an instruction code, the position of the variant on the reference and
+    /// the interval the variant spans on the personalized sequence
+    pub fn new(transcript_name:String, code:char, pos_ref:RefPos0, start:RefPos0, len:Len)->Self
+    {
+        VariantFeature{transcript_name,code,pos_ref,start,len,ref_len:Len::zero()}
+    }
+    /// ## Summary
+    /// Set the number of reference amino acids, starting at the position of the variant on the reference, replaced by the variant
+    pub fn with_reference_length(mut self, ref_len:Len)->Self
+    {
+        self.ref_len=ref_len;
+        self
+    }
+    /// ## Summary
+    /// Return the name of the transcript
+    pub fn get_transcript_name(&self)->&String
+    {
+        &self.transcript_name
+    }
+    /// ## Summary
+    /// Return the instruction code of the variant
+    pub fn get_code(&self)->char
+    {
+        self.code
+    }
+    /// ## Summary
+    /// Return the position of the variant on the reference protein
+    pub fn get_position_ref(&self)->RefPos0
+    {
+        self.pos_ref
+    }
+    /// ## Summary
+    /// Return the start of the variant interval on the personalized sequence
+    pub fn get_start(&self)->RefPos0
+    {
+        self.start
+    }
+    /// ## Summary
+    /// Return the length of the variant interval on the personalized sequence
+    pub fn get_length(&self)->Len
+    {
+        self.len
+    }
+    /// ## Summary
+    /// Return the number of reference amino acids replaced by the variant
+    pub fn get_reference_length(&self)->Len
+    {
+        self.ref_len
+    }
+    /// ## Summary
+    /// Return the sequence ontology term that describes the variant, derived from the instruction code, see the instruction module for
+    /// the meaning of each code
+    pub fn get_feature_type(&self)->&'static str
+    {
+        match self.code
+        {
+            'M' | 'N' =>"missense_variant",
+            'F' | 'R' | 'Q' | 'B' =>"frameshift_variant",
+            'G' | 'X' | 'A' | 'T' =>"stop_gained",
+            'L' | 'W' | 'V' =>"stop_lost",
+            'S' =>"initiator_codon_variant",
+            'I' | 'J' | 'Z' =>"inframe_insertion",
+            'D' | 'C' | 'P' =>"inframe_deletion",
+            _ =>"protein_altering_variant"
+        }
+    }
+    /// ## Summary
+    /// Format the feature as a GFF3 line, the sequence id is the name of the record in the personalized FASTA file, i.e. the transcript name
+    /// followed by the haplotype index, and index is the order of the feature in the transcript which is used to build a unique ID.
+    /// Empty intervals are written as a zero-length feature, i.e. start equals end, located at the residue preceding the altered site.
+    pub fn to_gff3(&self, haplotype:u8, index:usize)->String
+    {
+        let seq_id=format!("{}_{}",self.transcript_name,haplotype);
+        let (start,end)=match self.len.get()
+        {
+            0=>
+            {
+                let site=std::cmp::max(self.start.get(),1);
+                (site,site)
+            },
+            len=>(self.start.to_one_based().get(),self.start.get()+len)
+        };
+        format!("{}\tvcf2prot\t{}\t{}\t{}\t.\t.\t.\tID={}.v{};Name={};ref_position={};instruction_code={}",
+            seq_id,self.get_feature_type(),start,end,seq_id,index+1,self.get_feature_type(),self.pos_ref.to_one_based(),self.code)
+    }
+    /// ## Summary
+    /// Resolve the feature into an applied change using the reference sequence and the personalized sequence of the transcript, the residues
+    /// are read from the intervals of the feature, which are clipped to the length of the sequences
+    pub fn to_applied_change(&self, haplotype:u8, ref_seq:&str, personalized_seq:&str)->AppliedChange
+    {
+        let get_residues=|seq:&str,start:usize,len:usize|seq.chars().skip(start).take(len).collect::<String>();
+        let ref_start=std::cmp::min(self.pos_ref.get(),ref_seq.len());
+        let ref_aa=get_residues(ref_seq,ref_start,self.ref_len.get());
+        let personalized_start=std::cmp::min(self.start.get(),personalized_seq.len());
+        let alt_aa=get_residues(personalized_seq,personalized_start,self.len.get());
+        AppliedChange{transcript_name:self.transcript_name.clone(),haplotype,code:self.code,ref_start,ref_end:ref_start+ref_aa.len(),
+            personalized_start,personalized_end:personalized_start+alt_aa.len(),ref_aa,alt_aa}
+    }
+}
+/// ## Summary
+/// A change applied to a personalized sequence, i.e. the transcript, the haplotype, the instruction code, the replaced reference amino acids
+/// and the alternative amino acids along with their 0-based half-open intervals on the reference and on the personalized sequence.
+/// Changes that do not add residues, e.g. stop-gained, have an empty alternative interval and an empty alternative sequence.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::InternalRep::variant_feature::VariantFeature;
+/// use ppgg::data_structures::InternalRep::coordinates::{RefPos0,Len};
+/// let feature=VariantFeature::new("T1".to_string(),'M',RefPos0::new(1),RefPos0::new(1),Len::new(1)).with_reference_length(Len::new(1));
+/// let change=feature.to_applied_change(2,"MKLV","MQLV");
+/// assert_eq!((change.ref_aa.as_str(),change.alt_aa.as_str()),("K","Q"));
+/// assert_eq!(change.to_tsv(),"T1\t2\tM\tK\tQ\t1\t2\t1\t2");
+///```
+#[derive(Debug,Clone,PartialEq,Serialize,Deserialize)]
+pub struct AppliedChange
+{
+    pub transcript_name:String,
+    pub haplotype:u8,
+    pub code:char,
+    pub ref_aa:String,
+    pub alt_aa:String,
+    pub ref_start:usize,
+    pub ref_end:usize,
+    pub personalized_start:usize,
+    pub personalized_end:usize
+}
+impl AppliedChange
+{
+    /// ## Summary
+    /// The header of the tab-separated table of applied changes, see to_tsv
+    pub const TSV_HEADER:&'static str="transcript\thaplotype\tinstruction_code\tref_aa\talt_aa\tref_start\tref_end\tpersonalized_start\tpersonalized_end";
+    /// ## Summary
+    /// Format the change as a tab-separated line, empty amino acid sequences are written as a dot
+    pub fn to_tsv(&self)->String
+    {
+        let or_dot=|residues:&String|match residues.is_empty()
+        {
+            true=>".".to_string(),
+            false=>residues.clone()
+        };
+        format!("{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",self.transcript_name,self.haplotype,self.code,or_dot(&self.ref_aa),or_dot(&self.alt_aa),
+            self.ref_start,self.ref_end,self.personalized_start,self.personalized_end)
+    }
+    /// ## Summary
+    /// Return the 0-based half-open interval of the peptide window of the change on a personalized sequence of length seq_len, i.e. the
+    /// alternative interval extended by flank residues on both sides and clipped to the sequence, hence, the window of a substituted residue
+    /// is 2*flank+1 residues long. The window of a frameshift spans the whole novel tail until the end of the sequence, as every residue of
+    /// the tail is mutated. Changes without alternative residues, e.g. stop-gained, do not have a window.
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::InternalRep::variant_feature::VariantFeature;
+    /// use ppgg::data_structures::InternalRep::coordinates::{RefPos0,Len};
+    /// let change=VariantFeature::new("T1".to_string(),'M',RefPos0::new(5),RefPos0::new(5),Len::new(1)).to_applied_change(1,"MKLVEGGAPW","MKLVEYGAPW");
+    /// assert_eq!(change.get_peptide_window(10,2),Some((3,8)));
+    ///```
+    pub fn get_peptide_window(&self, seq_len:usize, flank:usize)->Option<(usize,usize)>
+    {
+        if self.personalized_start>=self.personalized_end
+        {
+            return None
+        }
+        let end=match self.code
+        {
+            'F' | 'R' | 'Q' | 'B'=>seq_len,
+            _=>std::cmp::min(self.personalized_end.saturating_add(flank),seq_len)
+        };
+        Some((self.personalized_start.saturating_sub(flank),end))
+    }
+}
+
+#[cfg(test)]
+mod test_variant_feature
+{
+    use super::*;
+    #[test]
+    fn test_to_gff3()
+    {
+        let frameshift=VariantFeature::new("T1".to_string(),'F',RefPos0::new(39),RefPos0::new(39),Len::new(8));
+        assert_eq!(frameshift.to_gff3(2,1),
+            "T1_2\tvcf2prot\tframeshift_variant\t40\t47\t.\t.\t.\tID=T1_2.v2;Name=frameshift_variant;ref_position=40;instruction_code=F");
+        let stop_gained=VariantFeature::new("T1".to_string(),'G',RefPos0::new(9),RefPos0::new(9),Len::zero());
+        assert_eq!(stop_gained.to_gff3(1,0),
+            "T1_1\tvcf2prot\tstop_gained\t9\t9\t.\t.\t.\tID=T1_1.v1;Name=stop_gained;ref_position=10;instruction_code=G");
+    }
+    #[test]
+    fn test_to_applied_change()
+    {
+        let stop_gained=VariantFeature::new("T1".to_string(),'G',RefPos0::new(2),RefPos0::new(2),Len::zero()).with_reference_length(Len::new(3));
+        let change=stop_gained.to_applied_change(1,"MKLVE","MK");
+        assert_eq!((change.ref_aa.as_str(),change.ref_start,change.ref_end),("LVE",2,5));
+        assert_eq!(change.to_tsv(),"T1\t1\tG\tLVE\t.\t2\t5\t2\t2");
+        // the interval is clipped to the reference, e.g. for features missing the reference length
+        let change=VariantFeature::new("T1".to_string(),'M',RefPos0::new(9),RefPos0::new(9),Len::new(1)).to_applied_change(1,"MKLVE","MKLVE");
+        assert_eq!((change.ref_start,change.ref_end,change.personalized_start,change.personalized_end),(5,5,5,5));
+    }
+    #[test]
+    fn test_peptide_window()
+    {
+        // the window of a missense is 2*flank+1 residues long and is clipped at the start of the sequence
+        let missense=VariantFeature::new("T1".to_string(),'M',RefPos0::new(1),RefPos0::new(1),Len::new(1)).to_applied_change(1,"MKLVEG","MQLVEG");
+        assert_eq!(missense.get_peptide_window(6,2),Some((0,4)));
+        assert_eq!(missense.get_peptide_window(6,0),Some((1,2)));
+        // the window of a frameshift covers the whole novel tail
+        let frameshift=VariantFeature::new("T1".to_string(),'F',RefPos0::new(2),RefPos0::new(2),Len::new(3)).to_applied_change(1,"MKLVEG","MKPPW");
+        assert_eq!(frameshift.get_peptide_window(5,1),Some((1,5)));
+        let stop_gained=VariantFeature::new("T1".to_string(),'G',RefPos0::new(2),RefPos0::new(2),Len::zero()).to_applied_change(1,"MKLVEG","MK");
+        assert_eq!(stop_gained.get_peptide_window(2,3),None);
+    }
+}
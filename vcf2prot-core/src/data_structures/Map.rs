@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+use super::vcf_ds::AltTranscript; 
+use serde::{Deserialize, Serialize};
+
+/// An abstraction for an intermediate representation map, i.e. an IntMap, which is the output of io::parse_vcf and the input of exec::execute.
+/// The intermediate representation of a cohort is a vector of IntMap, i.e. one per sample, and each IntMap is a tree of four levels:
+/// 1. a proband name --> Which stores the name of the individuals, it is used as the stem of the generated fasta files 
+/// 2. mutations 1 and mutations 2 --> the two haplotypes of the sample, each is a vector of AltTranscript, one per altered transcript 
+/// 3. AltTranscript --> the name of a transcript, as used in the reference proteome, along with the collection of its mutations 
+/// 4. Mutation --> the type of a mutation, see mutation_ds::MutationType, and its position and amino acids in the reference and the mutated sequence, see mutation_ds::MutationInfo
+///
+/// Every level derives Serialize and Deserialize, hence, the intermediate representation can be stored, e.g. as JSON, and it can be built 
+/// programmatically from sources other than a VCF file, e.g. MAF files, using IntMap::new, AltTranscript::from_mutations and Mutation::from_info. 
+/// Such a representation shall be checked with IntMap::validate before it is passed to exec::execute, which expects the invariants of 
+/// the parsed representation. The order of the mutations of a transcript does not matter as they are sorted before the execution.
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+/// use ppgg::{exec, Engine, IntMap, AltTranscript, Mutation, MutationInfo, MutationType};
+/// // a missense K2N on the first haplotype of a transcript, e.g. read from a MAF file, the positions are one-based 
+/// let transcript_name:Arc<str>=Arc::from("T1");
+/// let mutation=Mutation::from_info(MutationType::MisSense, transcript_name, MutationInfo::new(2,2,"K".to_string(),"N".to_string()));
+/// let int_map=IntMap::new("S1".to_string(),vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation])],Vec::new());
+/// assert!(int_map.validate().is_ok());
+/// let ref_seq=vec![("T1".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+/// let genomes=exec::execute(vec![int_map],Engine::ST,&ref_seq,false,&exec::ExecutionOptions::default());
+/// assert_eq!(genomes[0].get_seq_tapes().0.get_seq(&"T1".to_string()).unwrap(),"MNLV");
+///```
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct IntMap
+{
+    pub proband_name:String,
+    mutations1:Vec<AltTranscript>,
+    mutations2:Vec<AltTranscript>,
+}
+impl IntMap
+{
+    /// ## Summary 
+    /// Create a new intMap 
+    pub fn new(proband_name:String,mutations1:Vec<AltTranscript>,mutations2:Vec<AltTranscript>)->Self
+    {
+        IntMap{proband_name,mutations1,mutations2}
+    }
+    /// ## Summary 
+    /// Returns a tuple of size two, the first contains a reference to the vector of alteration in the first haplotype  
+    /// the second contains a reference to the vector of alteration in the second haplotype  
+    pub fn get_mutations_ref(&self)->(&Vec<AltTranscript>,&Vec<AltTranscript>)
+    {
+        (&self.mutations1,&self.mutations2)
+    }
+    /// ## Summary
+    /// Check the invariants that exec::execute expects from an intermediate representation, i.e. the proband name is not empty, a transcript
+    /// is altered at most once per haplotype and every mutation of an altered transcript carries the name of that transcript. Returns an
+    /// error describing the first violation, the representations returned by io::parse_vcf always satisfy these invariants.
+    pub fn validate(&self)->Result<(),String>
+    {
+        if self.proband_name.is_empty()
+        {
+            return Err("The proband name of an intermediate representation can not be empty".to_string())
+        }
+        for (haplotype,alt_transcripts) in [(1,&self.mutations1),(2,&self.mutations2)]
+        {
+            let mut seen=HashSet::new();
+            for alt_transcript in alt_transcripts.iter()
+            {
+                if !seen.insert(alt_transcript.name.as_str())
+                {
+                    return Err(format!("The transcript: {} is altered more than once in the haplotype: {} of the proband: {}, the mutations of a transcript must be grouped into one AltTranscript",
+                        alt_transcript.name,haplotype,self.proband_name))
+                }
+                if let Some(mutation)=alt_transcript.alts.iter().find(|mutation|*mutation.transcript_name!=*alt_transcript.name)
+                {
+                    return Err(format!("A mutation of the transcript: {} in the haplotype: {} of the proband: {} belongs to the transcript: {}",
+                        alt_transcript.name,haplotype,self.proband_name,mutation.transcript_name))
+                }
+            }
+        }
+        Ok(())
+    }
+    /// ## Summary 
+    /// Return a reference to the instance name 
+    pub fn get_name(&self)->&String
+    {
+        &self.proband_name
+    } 
+    /// ## Summary
+    /// Retain only the altered transcripts whose names satisfy the predicate in both haplotypes
+    pub fn retain_transcripts<F>(&mut self, predicate:F)
+    where F:Fn(&str)->bool
+    {
+        self.mutations1.retain(|alt_transcript|predicate(&alt_transcript.name));
+        self.mutations2.retain(|alt_transcript|predicate(&alt_transcript.name));
+    }
+    /// ## Summary
+    /// Collapse the altered transcripts whose names satisfy the predicate into the first haplotype, e.g. the hemizygous transcripts of an XY
+    /// sample, i.e. the mutations of the second haplotype are added to the first haplotype unless a mutation of the first haplotype is located
+    /// at the same reference position, and the transcripts are removed from the second haplotype. Returns the number of collapsed transcripts.
+    pub fn collapse_into_first_haplotype<F>(&mut self, predicate:F)->usize
+    where F:Fn(&str)->bool
+    {
+        let (collapsed,retained):(Vec<AltTranscript>,Vec<AltTranscript>)=std::mem::take(&mut self.mutations2).into_iter()
+            .partition(|alt_transcript|predicate(&alt_transcript.name));
+        self.mutations2=retained;
+        let mut num_collapsed=self.mutations1.iter().filter(|alt_transcript|predicate(&alt_transcript.name)).count();
+        for alt_transcript2 in collapsed
+        {
+            match self.mutations1.iter_mut().find(|alt_transcript1|alt_transcript1.name==alt_transcript2.name)
+            {
+                Some(alt_transcript1)=>
+                {
+                    for mutation in alt_transcript2.alts
+                    {
+                        if alt_transcript1.alts.iter().all(|mutation1|mutation1.mut_info.ref_aa_position!=mutation.mut_info.ref_aa_position)
+                        {
+                            alt_transcript1.alts.push(mutation);
+                        }
+                    }
+                },
+                None=>
+                {
+                    num_collapsed+=1;
+                    self.mutations1.push(alt_transcript2);
+                }
+            }
+        }
+        num_collapsed
+    }
+    /// ## Summary
+    /// Assign the positions of the mutations in the mutated sequences of both haplotypes, see AltTranscript::assign_mutated_positions
+    pub fn assign_mutated_positions(&mut self)
+    {
+        self.mutations1.iter_mut().chain(self.mutations2.iter_mut()).for_each(|alt_transcript|alt_transcript.assign_mutated_positions());
+    }
+    /// ## Summary
+    /// Consume the reference and returns a tuple containing two vectors, the first is the vector of AltTranscript 
+    /// in the first haplotype and the second is the vector of alteration in the second haplotype, these vectors 
+    /// are moved from the current instance and hence the instance is invalid after this operation  
+    pub fn consume_and_get_vecs(self)->(Vec<AltTranscript>,Vec<AltTranscript>)
+    {
+        (self.mutations1,self.mutations2)
+    }
+}
+
+/// A data structure used to represent the early links between a map its mutations.
+/// the struct owns three data strucutres: a proband_name which hold the name of the proband, 
+/// mutations1 which holds all mutations in the first haplotype 
+/// mutations2 which holds all mutations in the second haplotype 
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct EarlyMap
+{
+    proband_name:String,
+    mutations1:Vec<String>,
+    mutations2:Vec<String>,
+}
+impl EarlyMap
+{
+    /// create a new instance using a proband-name, a vector of string represent the consequences of the first haplotype 
+    /// and a second vector represent the consequences of the second haplotypes 
+    /// ## Examples 
+    ///``` 
+    /// use ppgg_rust::data_structures::Map::EarlyMap;
+    /// let proband_name="Test_name".to_string(); 
+    /// let mutations1="mutation1_1,mutation1_3".split(",").map(|elem| elem.to_string()).collect::<Vec<String>>(); 
+    /// let mutations2="mutation1_2,mutation1_4".split(",").map(|elem| elem.to_string()).collect::<Vec<String>>(); 
+    /// let results=EarlyMap::new(proband_name.clone(),mutations1,mutations2); 
+    /// assert_eq!(*results.get_proband_name(),proband_name); 
+    /// assert_eq!(results.get_mutations_ref(),(&"mutation1_1,mutation1_3".split(",").map(|elem| elem.to_string()).collect::<Vec<String>>(),
+    /// &"mutation1_2,mutation1_4".split(",").map(|elem| elem.to_string()).collect::<Vec<String>>()))
+    ///```
+    pub fn new(proband_name:String, mutations1:Vec<String>, mutations2:Vec<String>)->Self
+    {
+        EarlyMap{proband_name,mutations1,mutations2}
+    }
+    /// create a new instance using a proband-name, and allocate two vectors to hold the generated strings, the expected number of mutations is 
+    /// determined by the parameter, expected_number 
+    /// ## Examples 
+    ///``` 
+    /// use ppgg_rust::data_structures::Map::EarlyMap;
+    /// let proband_name="Test_name".to_string(); 
+    /// let num_mutation=10; 
+    /// let results=EarlyMap::with_capacity(proband_name.clone(),10); 
+    /// assert_eq!(*results.get_proband_name(),proband_name); 
+    /// assert_eq!(results.get_mutations_ref().0.capacity(),num_mutation);
+    /// assert_eq!(results.get_mutations_ref().0.len(),0);
+    /// assert_eq!(results.get_mutations_ref().1.capacity(),num_mutation);
+    /// assert_eq!(results.get_mutations_ref().0.len(),0);
+    ///```
+    pub fn with_capacity(proband_name:String, expected_number:usize)->Self
+    {
+        let mutations1:Vec<String>= Vec::with_capacity(expected_number);
+        let mutations2:Vec<String>= Vec::with_capacity(expected_number);
+        EarlyMap{proband_name,mutations1,mutations2}
+    }
+    /// add a new mutation to the current instance
+    /// ## Examples 
+    ///``` 
+    /// use ppgg_rust::data_structures::Map::EarlyMap;
+    /// let proband_name="Test_name".to_string(); 
+    /// let num_mutation=10; 
+    /// let mut results=EarlyMap::with_capacity(proband_name.clone(),10); 
+    /// results.add_mutation("mutation1".to_string(),1); 
+    /// results.add_mutation("mutation2".to_string(),2);
+    /// assert_eq!(*results.get_proband_name(),proband_name); 
+    /// assert_eq!(results.get_mutations_ref(),(&vec!["mutation1".to_string()], &vec!["mutation2".to_string()]));
+    ///```
+    pub fn add_mutation(&mut self, mutation:String, haplotype:u8)
+    {
+        if haplotype==1
+        {
+            self.mutations1.push(mutation);
+        }
+        else 
+        {
+            self.mutations2.push(mutation);
+        }
+        
+    }
+    /// return a tuple that contain reference to the instances vector of string 
+    /// ## Examples 
+    ///``` 
+    /// use ppgg_rust::data_structures::Map::EarlyMap;
+    /// let proband_name="Test_name".to_string(); 
+    /// let num_mutation=10; 
+    /// let mut results=EarlyMap::with_capacity(proband_name.clone(),10); 
+    /// results.add_mutation("mutation1".to_string(),1); 
+    /// results.add_mutation("mutation2".to_string(),2);
+    /// assert_eq!(*results.get_proband_name(),proband_name); 
+    /// assert_eq!(results.get_mutations_ref(),(&vec!["mutation1".to_string()], &vec!["mutation2".to_string()]));
+    ///```
+    pub fn get_mutations_ref(&self)->(&Vec<String>,&Vec<String>)
+    {
+        (&self.mutations1,&self.mutations2)
+    }
+    /// return a reference to the proband names 
+    /// ## Examples 
+    ///``` 
+    /// use ppgg_rust::data_structures::Map::EarlyMap;
+    /// let proband_name="Test_name".to_string(); 
+    /// let num_mutation=10; 
+    /// let mut results=EarlyMap::with_capacity(proband_name.clone(),10); 
+    /// assert_eq!(*results.get_proband_name(),proband_name); 
+    ///```
+    pub fn get_proband_name(&self)->&String
+    {
+        &self.proband_name
+    }
+}
+#[cfg(test)]
+mod test_int_map
+{
+    use super::*;
+    use std::sync::Arc;
+    use crate::data_structures::mutation_ds::{Mutation,MutationInfo,MutationType};
+    #[test]
+    fn test_validate()
+    {
+        let mutation=|transcript_name:&str,position:u32|Mutation::from_info(MutationType::MisSense,Arc::from(transcript_name),
+            MutationInfo::new(position,position,"K".to_string(),"N".to_string()));
+        let int_map=IntMap::new("S1".to_string(),vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation("T1",2),mutation("T1",5)])],
+            vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation("T1",3)])]);
+        assert!(int_map.validate().is_ok());
+        let duplicated=IntMap::new("S1".to_string(),Vec::new(),vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation("T1",2)]),
+            AltTranscript::from_mutations("T1".to_string(),vec![mutation("T1",3)])]);
+        assert!(duplicated.validate().unwrap_err().contains("altered more than once in the haplotype: 2"));
+        let mismatched=IntMap::new("S1".to_string(),vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation("T2",2)])],Vec::new());
+        assert!(mismatched.validate().unwrap_err().contains("belongs to the transcript: T2"));
+        assert!(IntMap::new(String::new(),Vec::new(),Vec::new()).validate().is_err());
+    }
+    #[test]
+    #[cfg(feature = "writers")]
+    fn test_serde_round_trip()
+    {
+        let mutation=Mutation::from_info(MutationType::FrameShift,Arc::from("T1"),MutationInfo::new(3,3,"L".to_string(),"PQ*".to_string()));
+        let int_map=IntMap::new("S1".to_string(),vec![AltTranscript::from_mutations("T1".to_string(),vec![mutation])],Vec::new());
+        let decoded:IntMap=serde_json::from_str(&serde_json::to_string(&int_map).unwrap()).unwrap();
+        let alts=&decoded.get_mutations_ref().0[0].alts;
+        assert_eq!((decoded.get_name().as_str(),alts[0].mut_type.clone(),alts[0].mut_info.clone()),
+            ("S1",MutationType::FrameShift,MutationInfo::new(3,3,"L".to_string(),"PQ*".to_string())));
+        assert!(decoded.validate().is_ok());
+    }
+}
@@ -1,5 +1,8 @@
 use crate::data_structures::Constants; 
 
+/// The indices of the consequences observed in the first and in the second haplotype 
+pub type HaplotypeIndices=(Vec<usize>,Vec<usize>); 
+
 /// The Bitmask struct is an API for creating and handling bit-masks fields, used to index into the Consequences strings
 /// and extract the consequence of the genomic alteration at a specific genomic location. 
 ///
@@ -49,6 +52,36 @@ impl BitMask
             return BitMask{bitmask_elements:Some(bitmask_vec)}   
         }
     }
+    /// Construct a new bit-mask instance from a string containing the bit-mask, similar to from_string, however, an error is returned 
+    /// if one of the fields of the bit-mask is not a valid non-negative integer instead of panicking. 
+    /// ## Example
+    ///``` 
+    /// use ppgg::data_structures::MaskDecoder::BitMask;
+    /// assert_eq!(BitMask::try_from_string("1024,0,4096").unwrap().bitmask_elements,Some(vec![1024,0,4096])); 
+    /// assert!(BitMask::try_from_string("-1,0,4096").is_err()); 
+    ///```
+    pub fn try_from_string(input_string:&str)->Result<Self,String>
+    {
+        if input_string==Constants::DEF_CONSEQ || input_string=="0$"
+        {
+            return Ok(BitMask{bitmask_elements:None}); 
+        }
+        let fields=match input_string.strip_suffix('$')
+        {
+            Some(field)=>vec![field],
+            None=>input_string.split(',').collect::<Vec<&str>>()
+        };
+        let mut bitmask_vec=Vec::with_capacity(fields.len()); 
+        for field in fields
+        {
+            match field.parse::<u32>()
+            {
+                Ok(elem)=>bitmask_vec.push(elem),
+                Err(err_msg)=>return Err(format!("The bit-mask: {} contains an invalid field: {}, parsing it failed with: {}",input_string,field,err_msg))
+            }
+        }
+        Ok(BitMask{bitmask_elements:Some(bitmask_vec)})
+    }
     /// Parse the u32 integer in the bitmask set and return a tuple of two vectors, the first vector contain the 
     /// index of CSQ observed in the first haplotype and the second contains the CSQ observed in the second haplotype.
     /// ## Example
@@ -92,6 +125,69 @@ impl BitMask
             }
         }
     }
+    /// Similar to get_indices, however, the indices are checked against the number of consequences of the record, i.e. the length of the 
+    /// BCSQ list, and an error describing the first out-of-range index is returned instead of the indices. 
+    /// ## Example
+    ///``` 
+    /// use ppgg::data_structures::MaskDecoder::BitMask; 
+    /// let mut test_bitmask=BitMask::try_from_string("1024$").unwrap(); // references the consequence at index 5 
+    /// assert_eq!(test_bitmask.get_checked_indices(6).unwrap(),Some((vec![5],vec![]))); 
+    /// let mut test_bitmask=BitMask::try_from_string("1024$").unwrap();
+    /// assert!(test_bitmask.get_checked_indices(5).is_err()); 
+    ///```
+    pub fn get_checked_indices(&mut self, num_consequences:usize)->Result<Option<HaplotypeIndices>,String>
+    {
+        let (haplotype_one,haplotype_two)=match self.get_indices()
+        {
+            Some(indices)=>indices,
+            None=>return Ok(None)
+        };
+        for (haplotype,indices) in [(1,&haplotype_one),(2,&haplotype_two)].iter()
+        {
+            if let Some(index)=indices.iter().find(|index|**index>=num_consequences)
+            {
+                return Err(format!("the bit-mask of haplotype {} references the consequence at index {}, while the record has only {} consequences",
+                    haplotype,index,num_consequences))
+            }
+        }
+        Ok(Some((haplotype_one,haplotype_two)))
+    }
+    /// Return the bit-mask of a sample that is homozygous for the major allele of each consequence, given the raw bit-masks of the called 
+    /// samples of a record, i.e. both bits of a consequence are set if the consequence is carried by more than half of the called haplotypes. 
+    /// Trailing zero fields are dropped, hence, an empty vector is returned if no consequence is carried by the majority of the haplotypes. 
+    /// ## Example
+    ///``` 
+    /// use ppgg::data_structures::MaskDecoder::BitMask; 
+    /// // the first consequence is carried by 4 out of 6 haplotypes, while the second one by 2 out of 6 haplotypes
+    /// assert_eq!(BitMask::get_major_allele_mask(&[vec![3],vec![1],vec![14]]),vec![3]); 
+    /// assert_eq!(BitMask::get_major_allele_mask(&[vec![0,3],vec![1,3]]),vec![0,3]); 
+    /// assert!(BitMask::get_major_allele_mask(&[vec![1],vec![0]]).is_empty()); 
+    ///```
+    pub fn get_major_allele_mask(called_masks:&[Vec<u32>])->Vec<u32>
+    {
+        let num_haplotypes=2*called_masks.len(); 
+        let num_fields=called_masks.iter().map(|mask|mask.len()).max().unwrap_or(0); 
+        let mut major_allele_mask=vec![0_u32;num_fields]; 
+        for (field_index,field) in major_allele_mask.iter_mut().enumerate()
+        {
+            for consequence_index in 0..15
+            {
+                let num_carriers=called_masks.iter()
+                    .filter_map(|mask|mask.get(field_index))
+                    .map(|bitmask|((bitmask>>(2*consequence_index))&3).count_ones() as usize)
+                    .sum::<usize>(); 
+                if 2*num_carriers > num_haplotypes
+                {
+                    *field|=3<<(2*consequence_index); 
+                }
+            }
+        }
+        while major_allele_mask.last()==Some(&0)
+        {
+            major_allele_mask.pop(); 
+        }
+        major_allele_mask
+    }
     fn parse_single_field(mut bitmask:u32)->(Vec<usize>,Vec<usize>)
     {
         let mut haplotype_one=Vec::with_capacity(16);
@@ -261,6 +357,27 @@ mod test_bitmask_class
         ()
     }
     #[test]
+    fn test_try_from_string()
+    {
+        assert!(BitMask::try_from_string("").unwrap().bitmask_elements.is_none());
+        assert!(BitMask::try_from_string("0$").unwrap().bitmask_elements.is_none());
+        assert_eq!(BitMask::try_from_string("1024$").unwrap().bitmask_elements,Some(vec![1024]));
+        assert!(BitMask::try_from_string("10a$").is_err());
+        assert!(BitMask::try_from_string("1,,2").is_err());
+    }
+    #[test]
+    fn test_get_checked_indices()
+    {
+        // 3,3 references the consequence at index 0 and 15 in both haplotypes 
+        assert_eq!(BitMask::try_from_string("3,3").unwrap().get_checked_indices(16).unwrap(),Some((vec![0,15],vec![0,15])));
+        let err_msg=BitMask::try_from_string("3,3").unwrap().get_checked_indices(15).unwrap_err();
+        assert!(err_msg.contains("index 15") && err_msg.contains("only 15 consequences")); 
+        // only the second haplotype is out of range 
+        let err_msg=BitMask::try_from_string("9$").unwrap().get_checked_indices(1).unwrap_err();
+        assert!(err_msg.contains("haplotype 2"));
+        assert_eq!(BitMask::try_from_string("0$").unwrap().get_checked_indices(0).unwrap(),None);
+    }
+    #[test]
     fn test_get_indicies1()->Result<(),()>
     {
         let mut test_case="0$".to_string();
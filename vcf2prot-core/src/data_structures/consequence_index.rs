@@ -0,0 +1,175 @@
+/// An index of the consequences of the records of a VCF file, where every consequence is parsed once into a mutation and routed to its
+/// transcript, hence, the consequences decoded from the bit-mask of a sample are assigned to their transcripts through the index instead
+/// of being copied and parsed again for every sample carrying them. A record of overlapping genes or shared exons annotates many
+/// transcripts, which are all parsed once per record and each transcript only receives the consequences addressed to it.
+use std::collections::HashMap;
+use std::sync::Arc;
+use rayon::prelude::*;
+use crate::functions::text_parser;
+use crate::data_structures::Constants;
+use crate::data_structures::mutation_ds::Mutation;
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::data_structures::InternalRep::engines::Engine;
+
+/// ## Summary
+/// A consequence routed to a transcript, i.e. the index of the interned transcript name and the mutation parsed from the consequence,
+/// which is None if the amino acid field of the consequence can not be parsed
+#[derive(Debug,Clone)]
+struct RoutedConsequence
+{
+    transcript_index:usize,
+    mutation:Option<Mutation>
+}
+
+/// ## Summary
+/// The routing table of the consequences of a VCF file, per record the consequences of its BCSQ field in their order, where unsupported
+/// consequences, i.e. consequences whose type is not one of Constants::SUP_TYPE or whose transcript is not protein coding, are not routed.
+/// The transcript names are interned once for the file and shared by all the mutations of a transcript.
+#[derive(Debug,Clone)]
+pub struct ConsequenceIndex
+{
+    transcript_names:Vec<Arc<str>>,
+    records:Vec<Vec<Option<RoutedConsequence>>>
+}
+impl ConsequenceIndex
+{
+    /// ## Summary
+    /// Build the index from the BCSQ field of each record, see VCFRecords::get_consequences_vector, the transcript names are interned
+    /// sequentially in the order of the records, while the mutations are parsed on parallel with a multi-threaded engine
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::consequence_index::ConsequenceIndex;
+    /// use ppgg::data_structures::InternalRep::engines::Engine;
+    /// let consequences=vec!["missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G2|T2|protein_coding|+|2K>2N|10A>T,synonymous|G1|T1|protein_coding|+|3L|30A>T".to_string()];
+    /// let index=ConsequenceIndex::new(&consequences,Engine::ST);
+    /// assert_eq!((index.get_num_transcripts(),index.get_num_routed(0,&[0,1,2])),(2,2));
+    /// let alt_transcripts=index.route(&[(0,vec![1,2])]);
+    /// assert_eq!((alt_transcripts.len(),alt_transcripts[0].name.as_str()),(1,"T2"));
+    ///```
+    pub fn new(consequences:&[String], engine:Engine)->Self
+    {
+        let mut table:HashMap<&str,usize>=HashMap::new();
+        let mut transcript_names:Vec<Arc<str>>=Vec::new();
+        let routes=consequences.iter()
+            .map(|csq|csq.split(',').map(|consequence|
+            {
+                let fields=ConsequenceIndex::split_supported(consequence)?;
+                Some(*table.entry(fields[1]).or_insert_with(||
+                {
+                    transcript_names.push(Arc::from(fields[1]));
+                    transcript_names.len()-1
+                }))
+            }).collect::<Vec<Option<usize>>>())
+            .collect::<Vec<Vec<Option<usize>>>>();
+        let parse_record=|(csq,routes):(&String,&Vec<Option<usize>>)|csq.split(',').zip(routes.iter())
+            .map(|(consequence,route)|route.map(|transcript_index|
+            {
+                let fields=ConsequenceIndex::split_supported(consequence).unwrap();
+                let mutation=Mutation::from_csq_fields(fields[0],transcript_names[transcript_index].clone(),fields[2]).ok();
+                RoutedConsequence{transcript_index,mutation}
+            }))
+            .collect::<Vec<Option<RoutedConsequence>>>();
+        let records=match engine
+        {
+            Engine::ST=>consequences.iter().zip(routes.iter()).map(parse_record).collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>consequences.par_iter().zip(routes.par_iter()).map(parse_record).collect::<Vec<_>>()
+        };
+        ConsequenceIndex{transcript_names,records}
+    }
+    /// ## Summary
+    /// Return the number of distinct transcripts annotated by the consequences of the file
+    pub fn get_num_transcripts(&self)->usize
+    {
+        self.transcript_names.len()
+    }
+    /// ## Summary
+    /// Return the number of consequences in the BCSQ field of a record
+    pub fn get_num_consequences(&self, record_index:usize)->usize
+    {
+        self.records[record_index].len()
+    }
+    /// ## Summary
+    /// Return the number of routed, i.e. supported, consequences among the provided consequences of a record
+    pub fn get_num_routed(&self, record_index:usize, indices:&[usize])->usize
+    {
+        indices.iter().filter(|index|self.records[record_index][**index].is_some()).count()
+    }
+    /// ## Summary
+    /// Route the decoded consequences of a haplotype, i.e. the index of each record carrying at least one consequence along with the indices
+    /// of the carried consequences in its BCSQ field, see VCFRecords::decode_indices, to their transcripts. The mutations of each transcript
+    /// are kept in the order of the records and are collapsed with AltTranscript::drop_replicate, the transcripts are sorted by their name.
+    /// A transcript receives only the consequences addressed to it, while a transcript whose consequences can not be parsed is kept without
+    /// mutations, similar to vcf_tools::group_muts_per_transcript.
+    pub fn route(&self, haplotype:&[(usize,Vec<usize>)])->Vec<AltTranscript>
+    {
+        let mut groups=HashMap::new();
+        self.route_into(haplotype,&mut groups);
+        ConsequenceIndex::to_alt_transcripts(groups)
+    }
+    /// ## Summary
+    /// Similar to route, however, the mutations are appended to the provided groups, i.e. the mutations of each transcript keyed by the
+    /// transcript name, which allows routing the chunks of a streamed file into the same groups, see vcf_tools::IntMapBuilder
+    pub fn route_into(&self, haplotype:&[(usize,Vec<usize>)], groups:&mut HashMap<Arc<str>,Vec<Mutation>>)
+    {
+        for (record_index,indices) in haplotype.iter()
+        {
+            for routed in indices.iter().filter_map(|index|self.records[*record_index][*index].as_ref())
+            {
+                let group=groups.entry(self.transcript_names[routed.transcript_index].clone()).or_default();
+                if let Some(mutation)=routed.mutation.as_ref()
+                {
+                    group.push(mutation.clone());
+                }
+            }
+        }
+    }
+    /// ## Summary
+    /// Collapse the routed mutations of each transcript with AltTranscript::drop_replicate and return the transcripts sorted by their name
+    pub fn to_alt_transcripts(groups:HashMap<Arc<str>,Vec<Mutation>>)->Vec<AltTranscript>
+    {
+        let mut res=groups.into_iter()
+            .map(|(transcript_name,alts)|AltTranscript::from_mutations(transcript_name.to_string(),alts).drop_replicate())
+            .collect::<Vec<AltTranscript>>();
+        res.sort_by(|alt1,alt2|alt1.name.cmp(&alt2.name));
+        res
+    }
+    /// return the type, transcript and amino acid fields of a supported consequence or None otherwise
+    fn split_supported(consequence:&str)->Option<[&str;3]>
+    {
+        match Constants::SUP_TYPE.contains(&consequence.split('|').next().unwrap_or(""))
+        {
+            true=>text_parser::split_csq_str(consequence).ok(),
+            false=>None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_consequence_index
+{
+    use super::*;
+    use crate::functions::vcf_tools;
+    use crate::data_structures::vcf_ds::{Probands,VCFRecords};
+    #[test]
+    fn test_routing_matches_early_maps()
+    {
+        // the first record annotates overlapping genes, i.e. four transcripts including a non-coding one and a replicated consequence
+        let records=["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G2|T2|protein_coding|-|5R>5H|10A>T,\
+            missense|G3|T3|lincRNA|+|2K>2N|10A>T,inframe_deletion|G1|T4|protein_coding|+|3LV>3L|10AT>A,missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t0|1:682\t1|1:15",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G2|T2|protein_coding|-|1M>1V|20A>T,synonymous|G1|T1|protein_coding|+|3L|30A>T\tGT:BCSQ\t1|1:15\t1|0:1",
+            "1\t30\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|4X|30A>T\tGT:BCSQ\t1|0:1\t0|0:0"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let proband_names=vec!["S1".to_string(),"S2".to_string()];
+        for engine in [Engine::ST,Engine::MT]
+        {
+            let early_maps=vcf_tools::get_early_map(Probands::new(proband_names.clone()),VCFRecords::new(records.clone()),engine.clone());
+            let expected=vcf_tools::early_to_intermediate_repr(early_maps,engine.clone());
+            let (routed,_)=vcf_tools::get_int_maps(Probands::new(proband_names.clone()),VCFRecords::new(records.clone()),engine.clone());
+            assert_eq!(format!("{:?}",routed),format!("{:?}",expected));
+        }
+        let index=ConsequenceIndex::new(&VCFRecords::new(records).get_consequences_vector(Engine::ST),Engine::ST);
+        assert_eq!((index.get_num_transcripts(),index.get_num_consequences(0),index.get_num_routed(0,&[0,1,2,3,4])),(3,5,4));
+        // the transcript T1 of the last record can not be parsed and is kept without mutations
+        assert!(index.route(&[(2,vec![0])])[0].get_alts().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+// load the modules and crates
+use std::collections::{BTreeSet,HashSet};
+use rayon::prelude::*;
+use crate::data_structures::Constants;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::readers::vcf_helpers;
+
+/// ## Summary
+/// A user-supplied selection of the consequence types to process among the supported types, see Constants::SUP_TYPE, where each type is
+/// matched by its terms, e.g. *missense&inframe_altering is made of the terms missense and inframe_altering, and the leading * of the
+/// consequences inherited from an upstream change is ignored. A consequence is kept if no type is included or if one of its terms is
+/// included, and if none of its terms is excluded, i.e. the exclusions take precedence. The selection is applied while the records are
+/// read, see apply, hence, the consequences of the other types are never decoded.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::consequence_selection::ConsequenceSelection;
+/// let selection=ConsequenceSelection::new(vec!["missense".to_string()],vec!["stop_retained".to_string()]).unwrap();
+/// assert!(selection.keeps("*missense&inframe_altering"));
+/// assert!(!selection.keeps("frameshift"));
+/// assert!(ConsequenceSelection::new(vec!["synonymous".to_string()],vec![]).is_err());
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct ConsequenceSelection
+{
+    included:HashSet<String>,
+    excluded:HashSet<String>
+}
+impl ConsequenceSelection
+{
+    /// ## Summary
+    /// Create a new selection from the included and the excluded terms, an empty vector leaves the corresponding list unused. An error is
+    /// returned if a term is not part of any supported consequence type, see get_supported_terms
+    pub fn new(included:Vec<String>, excluded:Vec<String>)->Result<Self,String>
+    {
+        let supported_terms=ConsequenceSelection::get_supported_terms();
+        if let Some(term)=included.iter().chain(excluded.iter()).find(|term|!supported_terms.contains(term.as_str()))
+        {
+            return Err(format!("{} is not a supported consequence type, supported types are: {}",term,
+                supported_terms.into_iter().collect::<Vec<&str>>().join(", ")));
+        }
+        Ok(ConsequenceSelection{included:included.into_iter().collect(),excluded:excluded.into_iter().collect()})
+    }
+    /// ## Summary
+    /// Parse a comma-separated list of consequence types, e.g. missense,frameshift
+    pub fn parse_list(list:&str)->Vec<String>
+    {
+        list.split(',').map(|term|term.trim()).filter(|term|!term.is_empty()).map(|term|term.to_string()).collect()
+    }
+    /// ## Summary
+    /// Return the terms of the supported consequence types, in alphabetical order, e.g. frameshift, missense or stop_retained
+    pub fn get_supported_terms()->BTreeSet<&'static str>
+    {
+        Constants::SUP_TYPE.iter().flat_map(|csq_type|csq_type.trim_start_matches('*').split('&')).collect()
+    }
+    /// ## Summary
+    /// Return true if the selection keeps every supported type, i.e. no list is used
+    pub fn is_empty(&self)->bool
+    {
+        self.included.is_empty() && self.excluded.is_empty()
+    }
+    /// ## Summary
+    /// Return whether the consequences of a supported type are kept
+    pub fn keeps(&self, csq_type:&str)->bool
+    {
+        let terms=csq_type.trim_start_matches('*').split('&').collect::<Vec<&str>>();
+        let is_included=self.included.is_empty() || terms.iter().any(|term|self.included.contains(*term));
+        is_included && !terms.iter().any(|term|self.excluded.contains(*term))
+    }
+    /// ## Summary
+    /// Apply the selection to the consequences in the BCSQ field of a VCF record, the supported consequences whose type is not kept are
+    /// marked as filtered, i.e. their type is prefixed with filtered&, so they are treated as unsupported consequences while the indices
+    /// of the bit-masks remain valid, see transcript_filter::TranscriptFilter::apply. Returns None if the record does not carry any kept
+    /// supported consequence.
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::consequence_selection::ConsequenceSelection;
+    /// let selection=ConsequenceSelection::new(vec![],vec!["frameshift".to_string()]).unwrap();
+    /// let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=frameshift|G1|T1|protein_coding|+|5K>5X|10A>AT,missense|G2|T2|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t1|0:5";
+    /// assert_eq!(selection.apply(record).unwrap(),
+    ///     "1\t10\t.\tA\tT\t.\tPASS\tBCSQ=filtered&frameshift|G1|T1|protein_coding|+|5K>5X|10A>AT,missense|G2|T2|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t1|0:5");
+    /// assert_eq!(selection.apply(&record.replace("missense","frameshift")),None);
+    ///```
+    pub fn apply(&self, record:&str)->Option<String>
+    {
+        let mut fields=record.split('\t').collect::<Vec<&str>>();
+        if fields.len()<8
+        {
+            return None;
+        }
+        let mut is_kept=false;
+        let info=fields[7].split(';')
+            .map(|info_field|
+            {
+                match info_field.strip_prefix("BCSQ=")
+                {
+                    Some(bcsq)=>format!("BCSQ={}",bcsq.split(',').map(|csq|
+                    {
+                        if !vcf_helpers::is_supported_csq(csq)
+                        {
+                            return csq.to_string();
+                        }
+                        match self.keeps(csq.split('|').next().unwrap_or(""))
+                        {
+                            true=>{is_kept=true; csq.to_string()},
+                            false=>format!("filtered&{}",csq)
+                        }
+                    }).collect::<Vec<String>>().join(",")),
+                    None=>info_field.to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(";");
+        if !is_kept
+        {
+            return None;
+        }
+        fields[7]=&info;
+        Some(fields.join("\t"))
+    }
+}
+/// ## Summary
+/// Apply the provided selection to the records of a VCF file, see ConsequenceSelection::apply, and return the kept records along with the
+/// number of removed records, the records are returned unchanged if the selection is empty
+pub fn select_records(lines:Vec<String>, selection:&ConsequenceSelection, engine:Engine)->(Vec<String>,usize)
+{
+    if selection.is_empty()
+    {
+        return (lines,0)
+    }
+    let num_records=lines.len();
+    let kept=match engine
+    {
+        Engine::ST=>lines.iter().filter_map(|line|selection.apply(line)).collect::<Vec<String>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().filter_map(|line|selection.apply(line)).collect::<Vec<String>>()
+    };
+    let num_removed=num_records-kept.len();
+    (kept,num_removed)
+}
+
+#[cfg(test)]
+mod test_consequence_selection
+{
+    use super::*;
+    #[test]
+    fn test_unselected_types_are_not_supported()
+    {
+        let terms=ConsequenceSelection::get_supported_terms();
+        assert!(terms.contains("missense") && terms.contains("stop_retained") && !terms.contains("*missense"));
+        let selection=ConsequenceSelection::new(vec!["missense".to_string(),"stop_gained".to_string()],vec!["inframe_altering".to_string()]).unwrap();
+        assert!(selection.keeps("*missense") && selection.keeps("stop_gained"));
+        assert!(!selection.keeps("stop_gained&inframe_altering") && !selection.keeps("inframe_deletion"));
+        let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=intron|G1|T1|protein_coding,missense&inframe_altering|G1|T1|protein_coding|+|2K>2N|10A>T,stop_gained|G2|T2|protein_coding|+|5K>5*|10A>T\tGT:BCSQ\t1|0:17";
+        let selected=selection.apply(record).unwrap();
+        assert!(selected.contains("BCSQ=intron|G1|T1|protein_coding,filtered&missense&inframe_altering|G1|T1"));
+        // the marked consequence is no longer supported, hence, only the stop gained consequence is decoded
+        assert!(vcf_helpers::return_if_supported(&selected));
+        assert!(!vcf_helpers::return_if_supported(&selected.replace("stop_gained|","filtered&stop_gained|")));
+        assert_eq!(ConsequenceSelection::parse_list(" missense, ,frameshift"),vec!["missense".to_string(),"frameshift".to_string()]);
+        assert_eq!(select_records(vec![record.to_string()],&ConsequenceSelection::default(),Engine::ST),(vec![record.to_string()],0));
+        let (kept,num_removed)=select_records(vec![record.to_string(),record.replace("stop_gained|","inframe_deletion|")],&selection,Engine::MT);
+        assert_eq!((kept,num_removed),(vec![selected],1));
+    }
+}
@@ -0,0 +1,174 @@
+// load the modules and crates
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use crate::data_structures::Constants;
+use crate::data_structures::mutation_ds::MutationType;
+
+/// ## Summary
+/// A user-supplied mapping between the consequence type strings emitted by a consequence caller, for example, a different BCFtools
+/// version or fork, and the consequence types supported by the library. The map is consulted before the supported types are filtered,
+/// hence, records with a mapped consequence type are treated exactly as records with the corresponding supported type.
+/// The map is stored as a hashmap with the input type string as a key and the supported type string, i.e. one of SUP_TYPE, as a value.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::csq_map::CsqMap;
+/// let csq_map=CsqMap::from_tsv_str("feature_elongation&frameshift\tframeshift\nmissense_variant\tMisSense\n").unwrap();
+/// assert_eq!(csq_map.translate_type("feature_elongation&frameshift"),Some("frameshift"));
+/// assert_eq!(csq_map.translate_type("missense_variant"),Some("missense"));
+/// assert_eq!(csq_map.translate_type("missense"),None);
+///```
+#[derive(Debug,Clone,PartialEq)]
+pub struct CsqMap
+{
+    map:HashMap<String,String>
+}
+impl CsqMap
+{
+    /// ## Summary
+    /// Read a mapping table from a tab-separated file, see from_tsv_str for the expected layout
+    pub fn from_file(path2load:&Path)->Result<Self,String>
+    {
+        let content=match fs::read_to_string(path2load)
+        {
+            Ok(content)=>content,
+            Err(err_msg)=>return Err(format!("Reading the consequence map: {:?} failed with the following error: {}",path2load,err_msg))
+        };
+        CsqMap::from_tsv_str(&content)
+    }
+    /// ## Summary
+    /// Parse a mapping table from a tab-separated string with two columns, the first column is the input consequence type and
+    /// the second column is the target type, which can be either a supported consequence type string, e.g. frameshift, or the name of
+    /// a MutationType variant, e.g. FrameShift. Empty lines and lines starting with # are ignored.
+    pub fn from_tsv_str(content:&str)->Result<Self,String>
+    {
+        let mut map=HashMap::new();
+        for (line_num,line) in content.lines().enumerate()
+        {
+            let line=line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+            let fields=line.split('\t').collect::<Vec<&str>>();
+            if fields.len()!=2
+            {
+                return Err(format!("Line {} of the consequence map: {} contains {} columns, expected two tab-separated columns",
+                    line_num+1,line,fields.len()));
+            }
+            let target=match CsqMap::resolve_target(fields[1].trim())
+            {
+                Some(target)=>target,
+                None=>return Err(format!("Line {} of the consequence map maps: {} to: {} which is not a supported consequence type, supported types are: {:?}",
+                    line_num+1,fields[0],fields[1],Constants::SUP_TYPE))
+            };
+            if map.insert(fields[0].trim().to_string(),target.to_string()).is_some()
+            {
+                return Err(format!("Line {} of the consequence map contains a duplicated input type: {}",line_num+1,fields[0]));
+            }
+        }
+        Ok(CsqMap{map})
+    }
+    /// ## Summary
+    /// Return the supported consequence type string that corresponds to a supported type string or to the name of a MutationType variant
+    fn resolve_target(target:&str)->Option<&'static str>
+    {
+        Constants::SUP_TYPE.iter()
+            .find(|sup_type|**sup_type==target || format!("{:?}",MutationType::from_str(sup_type).unwrap())==target)
+            .copied()
+    }
+    /// ## Summary
+    /// Return the supported type that an input consequence type is mapped to, or None if the type is not in the map
+    pub fn translate_type(&self, input_type:&str)->Option<&str>
+    {
+        self.map.get(input_type).map(|target|target.as_str())
+    }
+    /// ## Summary
+    /// Return the number of input types in the map
+    pub fn len(&self)->usize
+    {
+        self.map.len()
+    }
+    /// ## Summary
+    /// Return true if the map does not contain any input type
+    pub fn is_empty(&self)->bool
+    {
+        self.map.is_empty()
+    }
+    /// ## Summary
+    /// Rewrite the consequence types in the BCSQ field of a VCF record using the map, the rest of the record is returned unchanged.
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::csq_map::CsqMap;
+    /// let csq_map=CsqMap::from_tsv_str("feature_elongation&frameshift\tframeshift\n").unwrap();
+    /// let record="1\t10\t.\tA\tAT\t.\tPASS\tAC=1;BCSQ=feature_elongation&frameshift|G1|T1|protein_coding|+|5K>5KX|10A>AT\tGT:BCSQ\t1|0:1".to_string();
+    /// assert_eq!(csq_map.apply(&record),
+    ///     "1\t10\t.\tA\tAT\t.\tPASS\tAC=1;BCSQ=frameshift|G1|T1|protein_coding|+|5K>5KX|10A>AT\tGT:BCSQ\t1|0:1".to_string());
+    ///```
+    pub fn apply(&self, record:&str)->String
+    {
+        let mut fields=record.split('\t').collect::<Vec<&str>>();
+        if fields.len()<8
+        {
+            return record.to_string();
+        }
+        let info=fields[7].split(';')
+            .map(|info_field|
+            {
+                match info_field.strip_prefix("BCSQ=")
+                {
+                    Some(bcsq)=>format!("BCSQ={}",bcsq.split(',').map(|csq|self.apply_to_csq(csq)).collect::<Vec<String>>().join(",")),
+                    None=>info_field.to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(";");
+        fields[7]=&info;
+        fields.join("\t")
+    }
+    /// ## Summary
+    /// Rewrite the type of a single consequence string, i.e. the substring before the first |
+    fn apply_to_csq(&self, csq:&str)->String
+    {
+        let mut_type=csq.split('|').next().unwrap();
+        match self.translate_type(mut_type)
+        {
+            Some(target)=>format!("{}{}",target,&csq[mut_type.len()..]),
+            None=>csq.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_csq_map
+{
+    use super::*;
+    use crate::readers::vcf_helpers::return_if_supported;
+    #[test]
+    fn test_parse_map()
+    {
+        let csq_map=CsqMap::from_tsv_str("# input\ttarget\n\nfeature_elongation&frameshift\tframeshift\nstop_gain\tStopGained\r\n").unwrap();
+        assert_eq!(csq_map.len(),2);
+        assert_eq!(csq_map.translate_type("stop_gain"),Some("stop_gained"));
+        assert!(CsqMap::from_tsv_str("missense_variant\tnot_a_type\n").is_err());
+        assert!(CsqMap::from_tsv_str("missense_variant\n").is_err());
+        assert!(CsqMap::from_tsv_str("a\tmissense\na\tframeshift\n").is_err());
+    }
+    #[test]
+    fn test_apply_multiple_csq()
+    {
+        let csq_map=CsqMap::from_tsv_str("missense_variant\tmissense\n").unwrap();
+        let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense_variant|G1|T1|protein_coding|+|5K>5N|10A>T,intron|G2|T2|protein_coding;AC=1\tGT:BCSQ\t1|0:1".to_string();
+        assert_eq!(csq_map.apply(&record),
+            "1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|5K>5N|10A>T,intron|G2|T2|protein_coding;AC=1\tGT:BCSQ\t1|0:1".to_string());
+    }
+    #[test]
+    fn test_apply_before_filter()
+    {
+        let csq_map=CsqMap::from_tsv_str("feature_elongation&frameshift\tFrameShift\n").unwrap();
+        let record="1\t10\t.\tA\tAT\t.\tPASS\tBCSQ=feature_elongation&frameshift|G1|T1|protein_coding|+|5K>5KX|10A>AT\tGT:BCSQ\t1|0:1".to_string();
+        assert!(!return_if_supported(&record));
+        assert!(return_if_supported(&csq_map.apply(&record)));
+    }
+}
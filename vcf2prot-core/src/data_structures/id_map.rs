@@ -0,0 +1,136 @@
+// load the modules and crates
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// ## Summary
+/// A user-supplied mapping between the transcript ids of the consequences, e.g. ENST00000406869, and the ids of the protein sequences, e.g.
+/// ENSP00000384129 or a UniProt accession. The map is applied when the reference sequences are looked up, i.e. a reference keyed by protein
+/// ids is re-keyed by transcript ids, see apply_to_reference, and, if passed to the writers through writers::WriterOptions, when the record
+/// headers are written, i.e. the protein id of a mapped transcript is written instead of its transcript id. A transcript is mapped to at most one protein, while a protein, e.g. a
+/// UniProt accession, can be shared by many transcripts.
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::data_structures::id_map::IdMap;
+/// let id_map=IdMap::from_tsv_str("ENST1\tP12345\nENST2\tP12345\nENST3\tENSP3\n").unwrap();
+/// assert_eq!(id_map.get_protein_id("ENST1"),Some("P12345"));
+/// assert_eq!(id_map.get_transcript_ids("P12345"),vec!["ENST1","ENST2"]);
+/// let mut ref_seq=vec![("P12345".to_string(),"MKLV".to_string()),("ENST4".to_string(),"MAAW".to_string())].into_iter().collect::<HashMap<_,_>>();
+/// assert_eq!(id_map.apply_to_reference(&mut ref_seq),1);
+/// assert_eq!(ref_seq.len(),3);
+/// assert_eq!(ref_seq["ENST2"],"MKLV");
+///```
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct IdMap
+{
+    proteins:HashMap<String,String>,
+    transcripts:HashMap<String,Vec<String>>
+}
+impl IdMap
+{
+    /// ## Summary
+    /// Read a mapping table from a tab-separated file, see from_tsv_str for the expected layout
+    pub fn from_file(path2load:&Path)->Result<Self,String>
+    {
+        let content=match fs::read_to_string(path2load)
+        {
+            Ok(content)=>content,
+            Err(err_msg)=>return Err(format!("Reading the id map: {:?} failed with the following error: {}",path2load,err_msg))
+        };
+        IdMap::from_tsv_str(&content)
+    }
+    /// ## Summary
+    /// Parse a mapping table from a tab-separated string with two columns, the first column is the transcript id and the second column is
+    /// the protein id. Empty lines and lines starting with # are ignored, a transcript that is mapped twice is an error.
+    pub fn from_tsv_str(content:&str)->Result<Self,String>
+    {
+        let mut id_map=IdMap::default();
+        for (line_num,line) in content.lines().enumerate()
+        {
+            let line=line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+            let fields=line.split('\t').map(|field|field.trim()).collect::<Vec<&str>>();
+            if fields.len()!=2 || fields.iter().any(|field|field.is_empty())
+            {
+                return Err(format!("Line {} of the id map: {} contains {} columns, expected two non-empty tab-separated columns",
+                    line_num+1,line,fields.len()));
+            }
+            if id_map.proteins.insert(fields[0].to_string(),fields[1].to_string()).is_some()
+            {
+                return Err(format!("Line {} of the id map contains a duplicated transcript id: {}, a transcript can be mapped to one protein only",
+                    line_num+1,fields[0]));
+            }
+            id_map.transcripts.entry(fields[1].to_string()).or_default().push(fields[0].to_string());
+        }
+        Ok(id_map)
+    }
+    /// ## Summary
+    /// Return the protein id a transcript is mapped to, or None if the transcript is not in the map
+    pub fn get_protein_id(&self, transcript:&str)->Option<&str>
+    {
+        self.proteins.get(transcript).map(|protein|protein.as_str())
+    }
+    /// ## Summary
+    /// Return the transcript ids mapped to a protein in the order of the table, empty if the protein is not in the map
+    pub fn get_transcript_ids(&self, protein:&str)->Vec<&str>
+    {
+        self.transcripts.get(protein).map(|transcripts|transcripts.iter().map(|transcript|transcript.as_str()).collect()).unwrap_or_default()
+    }
+    /// ## Summary
+    /// Return the number of transcripts in the map
+    pub fn len(&self)->usize
+    {
+        self.proteins.len()
+    }
+    /// ## Summary
+    /// Return true if the map does not contain any transcript
+    pub fn is_empty(&self)->bool
+    {
+        self.proteins.is_empty()
+    }
+    /// ## Summary
+    /// Re-key the reference sequences stored under a mapped protein id by the ids of the transcripts mapped to it, sequences stored under
+    /// any other id, e.g. a transcript id, are kept as is and a sequence already stored under a transcript id is not replaced. Returns the
+    /// number of re-keyed protein sequences.
+    pub fn apply_to_reference(&self, ref_seq:&mut HashMap<String,String>)->usize
+    {
+        let mut num_rekeyed=0;
+        for (protein,transcripts) in self.transcripts.iter()
+        {
+            let sequence=match ref_seq.remove(protein)
+            {
+                Some(sequence)=>sequence,
+                None=>continue
+            };
+            for transcript in transcripts.iter()
+            {
+                ref_seq.entry(transcript.clone()).or_insert_with(||sequence.clone());
+            }
+            num_rekeyed+=1;
+        }
+        num_rekeyed
+    }
+}
+
+#[cfg(test)]
+mod test_id_map
+{
+    use super::*;
+    #[test]
+    fn test_parse_and_apply()
+    {
+        assert!(IdMap::from_tsv_str("ENST1\tP1\nENST1\tP2\n").is_err());
+        assert!(IdMap::from_tsv_str("ENST1\n").is_err());
+        let id_map=IdMap::from_tsv_str("# transcript\tprotein\r\nENST1\tENSP1\r\n\nENST2\tENSP2\n").unwrap();
+        assert_eq!((id_map.len(),id_map.get_protein_id("ENST2"),id_map.get_protein_id("ENSP2")),(2,Some("ENSP2"),None));
+        // a sequence already stored under a transcript id is kept
+        let mut ref_seq=vec![("ENSP1".to_string(),"MKLV".to_string()),("ENSP2".to_string(),"MAAW".to_string()),("ENST2".to_string(),"MWWW".to_string())]
+            .into_iter().collect::<HashMap<_,_>>();
+        assert_eq!(id_map.apply_to_reference(&mut ref_seq),2);
+        assert_eq!(ref_seq,vec![("ENST1".to_string(),"MKLV".to_string()),("ENST2".to_string(),"MWWW".to_string())].into_iter().collect::<HashMap<_,_>>());
+    }
+}
@@ -8,10 +8,24 @@
 /// 5. Map ==> contains structures for handling the mapping between probands in the VCF files and there corresponding mutation 
 /// 6. MaskDecoder ==> contains the class bitmask decoder 
 /// 7. Constants ==> contains constant values used throughput the library 
+/// 8. csq_map ==> contains a user-supplied mapping between consequence type strings and the supported consequence types 
+/// 9. consequence_index ==> contains the routing of the consequences of each record to their transcripts 
+/// 10. id_map ==> contains a user-supplied mapping between transcript ids and the ids of their protein sequences 
+/// 11. transcript_filter ==> contains a user-supplied selection of the transcripts and genes whose consequences are processed 
+/// 12. consequence_selection ==> contains a user-supplied selection of the consequence types that are processed 
 pub mod mutation_ds;
 pub mod vcf_ds; 
 pub mod FastaFile;
 pub mod InternalRep; 
 pub mod Map; 
 pub mod MaskDecoder;
-pub mod Constants; 
+pub mod Constants;
+pub mod id_map; 
+#[cfg(feature = "parser")]
+pub mod csq_map; 
+#[cfg(feature = "parser")]
+pub mod consequence_index; 
+#[cfg(feature = "parser")]
+pub mod transcript_filter; 
+#[cfg(feature = "parser")]
+pub mod consequence_selection; 
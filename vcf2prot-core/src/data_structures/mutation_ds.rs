@@ -1,9 +1,12 @@
-use std::{cmp::Ordering, str::FromStr}; 
+use std::{cmp::Ordering, str::FromStr, sync::Arc}; 
 use crate::functions::text_parser; 
+use crate::data_structures::InternalRep::coordinates::RefPos0;
 
 /// an enumerator that contain the supported mutation, namely, MisSense for missense mutations, 
 /// InframeInsertion, i.e. inserions,  InframeDeletion, i.e deletion, FrameShift for frameshifts,
-/// StopGain, i.e. stop gain and StopLost, i.e. stop lost.
+/// StopGain, i.e. stop gain and StopLost, i.e. stop lost. StartReselection and StopReselection describe a change of the codon used
+/// as a start or a stop, e.g. an alternative downstream ATG, where the mutation is written as 4M>1M, i.e. the protein starts at the
+/// methionine at position 4 of the reference, and 120*>120KLQ*, i.e. the residues KLQ are appended after the last residue of the reference.
 /// it derives the Debug, Clone, PartialEq trait and impelement FromStr trait.
 /// ## Example  
 ///``` 
@@ -20,7 +23,7 @@ pub enum MutationType{
                     SFrameShiftAndStopRetained,SStopGainedAndInframeAltering,FrameShiftAndStopRetained,
                     InframeDeletionAndStopRetained,InframeInsertionAndStopRetained,StopGainedAndInframeAltering,
                     StartLost,SStopGained,StopLostAndFrameShift, MissenseAndInframeAltering,
-                    StartLostAndSpliceRegion  
+                    StartLostAndSpliceRegion, StartReselection, StopReselection
                     }
 impl FromStr for MutationType
 {
@@ -51,6 +54,8 @@ impl FromStr for MutationType
             "stop_lost&frameshift"=>Ok(MutationType::StopLostAndFrameShift),
             "missense&inframe_altering"=>Ok(MutationType::MissenseAndInframeAltering),
             "start_lost&splice_region"=>Ok(MutationType::StartLostAndSpliceRegion),
+            "start_reselection"=>Ok(MutationType::StartReselection),
+            "stop_reselection"=>Ok(MutationType::StopReselection),
             _=>Err(())
         }
     }
@@ -98,6 +103,30 @@ impl FromStr for MutatedString
         }       
     }
 }
+impl MutatedString
+{
+    /// ## Summary
+    /// Similar to from_str, however, the provided string is moved into the instance instead of being copied 
+    pub fn from_string(input_str:String)->Result<MutatedString,String>
+    {
+        if input_str.is_empty()
+        {
+            Err("Can not create a mutated string from an empty string".to_string())
+        }
+        else if input_str=="*"
+        {
+            Ok(MutatedString::NotSeq)
+        }
+        else if input_str.contains('*')
+        {
+            Ok(MutatedString::EndSequence(input_str))
+        }
+        else
+        {
+            Ok(MutatedString::Sequence(input_str))
+        }
+    }
+}
 /// A struct to store Information related to an amino acid mutation, the four fields stored in the struct are
 /// 1. **ref_aa_position** which store the starting position of the mutation in the *reference* sequence 
 /// 2. **mut_aa_position** which stores the starting position of the mutation in the *mutated* sequence 
@@ -106,8 +135,8 @@ impl FromStr for MutatedString
 #[derive(Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
 pub struct MutationInfo
 {
-    pub ref_aa_position:u16,
-    pub mut_aa_position:u16,
+    pub ref_aa_position:u32,
+    pub mut_aa_position:u32,
     pub ref_aa:MutatedString,
     pub mut_aa:MutatedString,
 }
@@ -130,19 +159,31 @@ impl MutationInfo
     /// let eg_case= MutationInfo::new(ref_pos,mut_pos,ref_seq,mut_seq); 
     /// println!("The example has the following structure {:#?}",eg_case); // uses pretty print, notice the numbers are 0-indexed
     ///``` 
-    pub fn new(ref_aa_position:u16, mut_aa_position:u16,ref_aa:String,mut_aa:String)->MutationInfo
+    pub fn new(ref_aa_position:u32, mut_aa_position:u32,ref_aa:String,mut_aa:String)->MutationInfo
     {
         MutationInfo
         {
             ref_aa_position:ref_aa_position-1, // rest the index to be 0-indexed
             mut_aa_position:mut_aa_position-1,
-            ref_aa:MutatedString::from_str(&ref_aa).unwrap(),
-            mut_aa:MutatedString::from_str(&mut_aa).unwrap(),
+            ref_aa:MutatedString::from_string(ref_aa).unwrap(),
+            mut_aa:MutatedString::from_string(mut_aa).unwrap(),
         }
     }
+    /// ## Summary
+    /// Return the starting position of the mutation in the *reference* sequence as a zero-based position
+    pub fn get_ref_pos(&self)->RefPos0
+    {
+        RefPos0::new(self.ref_aa_position as usize)
+    }
+    /// ## Summary
+    /// Return the starting position of the mutation in the *mutated* sequence as a zero-based position
+    pub fn get_mut_pos(&self)->RefPos0
+    {
+        RefPos0::new(self.mut_aa_position as usize)
+    }
 }
 /// An abstract representation for a mutation that is composite mainly of 4 components 
-/// 1. transcript_name a *String* containing the transcript name 
+/// 1. transcript_name a shared *str* containing the transcript name, all mutations of a transcript share the same allocation, see from_csq_fields
 /// 2. len an i16 int containing the  length of the mutation
 /// 3. mut_type  a *MutationType* enum coding for the mutational type the mutational type s
 /// 4. mut_info a *MutationInfo* struct summarizing all the mutational info 
@@ -152,7 +193,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug,Clone,Eq,Serialize,Deserialize)]
 pub struct Mutation
 {
-    pub transcript_name:String,
+    pub transcript_name:Arc<str>,
     pub mut_type:MutationType,
     pub mut_info:MutationInfo
 }
@@ -169,23 +210,47 @@ impl Mutation
         {
             return Err(format!("Info_vec must be of size 3, however, your input is of size {}",info_vec.len()));
         }
-        let mut_type=match  MutationType::from_str(&info_vec[0])
+        Mutation::from_csq_fields(&info_vec[0], Arc::from(info_vec[1].as_str()), &info_vec[2])
+    }
+    /// ## Summary
+    /// Create a new mutation from its already parsed components, e.g. when the intermediate representation is built from a source other
+    /// than a VCF file, see Map::IntMap, the transcript name shall be shared by all mutations of a transcript
+    pub fn from_info(mut_type:MutationType, transcript_name:Arc<str>, mut_info:MutationInfo)->Mutation
+    {
+        Mutation{transcript_name,mut_type,mut_info}
+    }
+    /// ## Summary
+    /// Create a new mutation from the mutation type and the amino acid fields of a consequence, e.g. as returned by text_parser::split_csq_str,
+    /// and a shared transcript name, which is used to avoid allocating the transcript name for each mutation when grouping the mutations
+    /// of a transcript, see vcf_tools::group_muts_per_transcript
+    /// ## Example
+    ///```
+    /// use std::sync::Arc;
+    /// use ppgg::data_structures::mutation_ds::Mutation;
+    /// let transcript_name:Arc<str>=Arc::from("ENST00000484547");
+    /// let mutation1=Mutation::from_csq_fields("stop_gained", transcript_name.clone(), "32Q>32*").unwrap();
+    /// let mutation2=Mutation::from_csq_fields("missense", transcript_name.clone(), "40K>40N").unwrap();
+    /// assert!(Arc::ptr_eq(&mutation1.transcript_name,&mutation2.transcript_name));
+    ///```
+    pub fn from_csq_fields(mut_type:&str, transcript_name:Arc<str>, aa_field:&str)->Result<Mutation,String>
+    {
+        let mut_type=match  MutationType::from_str(mut_type)
         {
             Ok(mut_type)=>mut_type,
             Err(_)=>
             {
-                return Err(format!("The provided mutation: {} is not supported",&info_vec[0]));
+                return Err(format!("The provided mutation: {} is not supported",mut_type));
             }
         };
-        let mut_info= match text_parser::parse_amino_acid_field(&info_vec[2])
+        let mut_info= match text_parser::parse_amino_acid_field(aa_field)
         {
             Ok(info_field)=>info_field,
             Err(err_msg)=>
             {
-                return Err(format!("Parsing the provided info field: {} failed with the following error message : {}", &info_vec[2], err_msg));
+                return Err(format!("Parsing the provided info field: {} failed with the following error message : {}", aa_field, err_msg));
             }
         };
-        Ok(Mutation{mut_type:mut_type,mut_info:mut_info,transcript_name:info_vec[1].clone()})
+        Ok(Mutation{mut_type,mut_info,transcript_name})
     }
 }
 impl Ord for Mutation
@@ -254,7 +319,7 @@ mod test_mutationds
         let test_mutation=Mutation::new(Ok(test_case)).unwrap();
         // assert that it produce the correct results 
         assert_eq!(MutationType::StopGained,test_mutation.mut_type);
-        assert_eq!("ENST00000484547".to_string(),test_mutation.transcript_name);
+        assert_eq!("ENST00000484547",&*test_mutation.transcript_name);
         assert_eq!(MutationInfo::new(32, 32, "Q".to_string(), "*".to_string()),test_mutation.mut_info);
     } 
     #[test]
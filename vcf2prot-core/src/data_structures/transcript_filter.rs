@@ -0,0 +1,167 @@
+// load the modules and crates
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::readers::vcf_helpers;
+
+/// ## Summary
+/// A user-supplied selection of the transcripts to process, where each consequence is matched by its transcript id, e.g. ENST00000406869,
+/// and by its gene name, e.g. HOXB3, as written in the BCSQ field. A consequence is kept if no include list is provided or if its
+/// transcript or its gene is included, and if neither its transcript nor its gene is excluded, i.e. the exclusions take precedence.
+/// The filter is applied while the records are read, see apply, hence, the records without a kept consequence are never decoded. The
+/// filter is passed to the readers through readers::ParseOptions.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::transcript_filter::TranscriptFilter;
+/// let filter=TranscriptFilter::new(vec![],vec!["G1".to_string()],vec!["T2".to_string()],vec![]);
+/// assert!(filter.keeps("G1","T1"));
+/// assert!(!filter.keeps("G1","T2"));
+/// assert!(!filter.keeps("G3","T3"));
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct TranscriptFilter
+{
+    include_transcripts:HashSet<String>,
+    include_genes:HashSet<String>,
+    exclude_transcripts:HashSet<String>,
+    exclude_genes:HashSet<String>
+}
+impl TranscriptFilter
+{
+    /// ## Summary
+    /// Create a new filter from the included transcripts and genes and the excluded transcripts and genes, an empty vector leaves the
+    /// corresponding list unused
+    pub fn new(include_transcripts:Vec<String>, include_genes:Vec<String>, exclude_transcripts:Vec<String>, exclude_genes:Vec<String>)->Self
+    {
+        TranscriptFilter{include_transcripts:include_transcripts.into_iter().collect(),include_genes:include_genes.into_iter().collect(),
+            exclude_transcripts:exclude_transcripts.into_iter().collect(),exclude_genes:exclude_genes.into_iter().collect()}
+    }
+    /// ## Summary
+    /// Read a list of ids from a file with one id per line, empty lines and lines starting with # are ignored. An error is returned if
+    /// the file can not be read or does not contain any id.
+    pub fn read_ids(path2load:&Path)->Result<Vec<String>,String>
+    {
+        let content=match fs::read_to_string(path2load)
+        {
+            Ok(content)=>content,
+            Err(err_msg)=>return Err(format!("Reading the id list: {:?} failed with the following error: {}",path2load,err_msg))
+        };
+        let ids=content.lines()
+            .map(|line|line.trim())
+            .filter(|line|!line.is_empty() && !line.starts_with('#'))
+            .map(|line|line.to_string())
+            .collect::<Vec<String>>();
+        if ids.is_empty()
+        {
+            return Err(format!("The id list: {:?} does not contain any id",path2load));
+        }
+        Ok(ids)
+    }
+    /// ## Summary
+    /// Return true if the filter keeps every transcript, i.e. no list is used
+    pub fn is_empty(&self)->bool
+    {
+        self.include_transcripts.is_empty() && self.include_genes.is_empty() && self.exclude_transcripts.is_empty() && self.exclude_genes.is_empty()
+    }
+    /// ## Summary
+    /// Return whether the consequences of a transcript of a gene are kept
+    pub fn keeps(&self, gene:&str, transcript:&str)->bool
+    {
+        let is_included=(self.include_transcripts.is_empty() && self.include_genes.is_empty())
+            || self.include_transcripts.contains(transcript) || self.include_genes.contains(gene);
+        is_included && !self.exclude_transcripts.contains(transcript) && !self.exclude_genes.contains(gene)
+    }
+    /// ## Summary
+    /// Filter the consequences in the BCSQ field of a VCF record, the supported consequences of the transcripts that are not kept are
+    /// marked as filtered, i.e. their type is prefixed with filtered&, so they are treated as unsupported consequences while the indices
+    /// of the bit-masks remain valid. Returns None if the record does not carry any kept supported consequence.
+    /// ## Example
+    ///```rust
+    /// use ppgg::data_structures::transcript_filter::TranscriptFilter;
+    /// let filter=TranscriptFilter::new(vec!["T1".to_string()],vec![],vec![],vec![]);
+    /// let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G2|T2|protein_coding|+|5K>5N|10A>T,missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t1|0:5";
+    /// assert_eq!(filter.apply(record).unwrap(),
+    ///     "1\t10\t.\tA\tT\t.\tPASS\tBCSQ=filtered&missense|G2|T2|protein_coding|+|5K>5N|10A>T,missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t1|0:5");
+    /// assert_eq!(filter.apply(&record.replace("T1","T3")),None);
+    ///```
+    pub fn apply(&self, record:&str)->Option<String>
+    {
+        let mut fields=record.split('\t').collect::<Vec<&str>>();
+        if fields.len()<8
+        {
+            return None;
+        }
+        let mut is_kept=false;
+        let info=fields[7].split(';')
+            .map(|info_field|
+            {
+                match info_field.strip_prefix("BCSQ=")
+                {
+                    Some(bcsq)=>format!("BCSQ={}",bcsq.split(',').map(|csq|
+                    {
+                        if !vcf_helpers::is_supported_csq(csq)
+                        {
+                            return csq.to_string();
+                        }
+                        let mut csq_fields=csq.split('|').skip(1);
+                        match self.keeps(csq_fields.next().unwrap_or(""),csq_fields.next().unwrap_or(""))
+                        {
+                            true=>{is_kept=true; csq.to_string()},
+                            false=>format!("filtered&{}",csq)
+                        }
+                    }).collect::<Vec<String>>().join(",")),
+                    None=>info_field.to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(";");
+        if !is_kept
+        {
+            return None;
+        }
+        fields[7]=&info;
+        Some(fields.join("\t"))
+    }
+}
+/// ## Summary
+/// Apply the filter to the records of a VCF file, see TranscriptFilter::apply, and return the kept records along with the number of
+/// removed records, the records are returned unchanged if the filter is empty
+pub fn filter_records(lines:Vec<String>, filter:&TranscriptFilter, engine:Engine)->(Vec<String>,usize)
+{
+    if filter.is_empty()
+    {
+        return (lines,0)
+    }
+    let num_records=lines.len();
+    let kept=match engine
+    {
+        Engine::ST=>lines.iter().filter_map(|line|filter.apply(line)).collect::<Vec<String>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().filter_map(|line|filter.apply(line)).collect::<Vec<String>>()
+    };
+    let num_removed=num_records-kept.len();
+    (kept,num_removed)
+}
+
+#[cfg(test)]
+mod test_transcript_filter
+{
+    use super::*;
+    use crate::data_structures::vcf_ds::VCFRecords;
+    #[test]
+    fn test_filtered_consequences_are_not_decoded()
+    {
+        let filter=TranscriptFilter::new(vec![],vec!["G1".to_string(),"G2".to_string()],vec![],vec!["G2".to_string()]);
+        assert!(!filter.is_empty() && TranscriptFilter::default().is_empty());
+        let record="1\t10\t.\tA\tT\t.\tPASS\tAC=1;BCSQ=missense|G2|T2|protein_coding|+|5K>5N|10A>T,intron|G1|T1|protein_coding,missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t1|0:17";
+        let filtered=filter.apply(record).unwrap();
+        assert!(filtered.contains("BCSQ=filtered&missense|G2|T2|protein_coding|+|5K>5N|10A>T,intron|G1|T1|protein_coding,missense|G1|T1"));
+        // the bit-mask still selects the first and the last consequence, however, only the kept one is decoded
+        let bcsq=filtered.split('\t').nth(7).unwrap().split("BCSQ=").nth(1).unwrap();
+        assert_eq!(VCFRecords::try_extract_effects(bcsq,"17").unwrap().0.iter().filter(|csq|vcf_helpers::is_supported_csq(csq)).count(),1);
+        assert_eq!(TranscriptFilter::new(vec![],vec![],vec!["T1".to_string()],vec!["G2".to_string()]).apply(record),None);
+        assert_eq!(filter_records(vec![record.to_string()],&TranscriptFilter::default(),Engine::ST),(vec![record.to_string()],0));
+        assert_eq!(filter_records(vec![record.to_string()],&filter,Engine::MT),(vec![filtered.clone()],0));
+    }
+}
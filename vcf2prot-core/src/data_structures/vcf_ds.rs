@@ -0,0 +1,1750 @@
+use std::collections::{HashMap,HashSet};
+use std::str::FromStr;
+use rayon::prelude::*; 
+use crate::functions::text_parser; 
+use crate::functions::diagnostics::CheckPolicy; 
+use crate::data_structures::{MaskDecoder::{BitMask,HaplotypeIndices},
+                            mutation_ds::{Mutation,MutatedString}
+                            };
+use super::Constants;
+use super::InternalRep::engines::Engine; 
+use serde::{Deserialize, Serialize};
+/// The decoded consequences of a proband in one haplotype, i.e. the index of each record carrying at least one consequence along with 
+/// the indices of the carried consequences in the BCSQ field of the record, see VCFRecords::decode_indices 
+pub type RecordIndices=Vec<(usize,Vec<usize>)>; 
+/// The policy for decoding the consequences of records with more than one ALT allele. With Genotype, the default, each consequence is 
+/// assigned to the ALT allele whose change it describes and each haplotype of a proband carries the consequences of the allele its genotype
+/// calls, see AlleleDecomposition, records whose consequences can not all be assigned to exactly one allele, and probands with a missing 
+/// genotype call, are decoded from the bit-mask. With BitMask, the consequences are decoded from the bit-mask as for bi-allelic records 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum MultiAllelicPolicy
+{
+    Genotype,
+    BitMask
+}
+impl Default for MultiAllelicPolicy
+{
+    fn default()->Self
+    {
+        MultiAllelicPolicy::Genotype
+    }
+}
+impl MultiAllelicPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            MultiAllelicPolicy::Genotype=>"genotype",
+            MultiAllelicPolicy::BitMask=>"bitmask"
+        }
+    }
+}
+impl FromStr for MultiAllelicPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<MultiAllelicPolicy,String>
+    {
+        match policy.to_lowercase().replace(['_','-'],"").as_str()
+        {
+            "genotype" | "gt"=>Ok(MultiAllelicPolicy::Genotype),
+            "bitmask" | "mask"=>Ok(MultiAllelicPolicy::BitMask),
+            _=>Err(format!("{} is not a supported policy for multi-allelic records, supported policies are: genotype and bitmask",policy))
+        }
+    }
+}
+/// The policies the consequences of the records are decoded with, including the policy of the checks of the bit-masks, see 
+/// diagnostics::CheckPolicy, the options are carried by the records, see VCFRecords::with_decoding_options, hence, records read with 
+/// different options can be decoded in the same process 
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub struct DecodingOptions
+{
+    pub multi_allelic_policy:MultiAllelicPolicy,
+    pub haplotype_source:HaplotypeSource,
+    pub unphased_policy:UnphasedPolicy,
+    pub check_policy:CheckPolicy
+}
+/// The source the consequences of a proband are assigned to its haplotypes from. With BitMask, the default, the consequences are decoded
+/// from the BCSQ bit-mask in the proband field, except for the multi-allelic records decomposed with MultiAllelicPolicy::Genotype. With 
+/// Phase, the consequences of every record are assigned from the phased genotype call, e.g. 0|1, 1|0 or 1|1, where each haplotype carries 
+/// the consequences of the ALT allele it calls, see AlleleDecomposition::from_phased_record, hence, the bit-mask is not needed. The records
+/// that can not be decomposed, unphased heterozygous calls, e.g. 0/1, and missing calls are decoded from the bit-mask, where records 
+/// without a BCSQ field in their FORMAT are treated as reference. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum HaplotypeSource
+{
+    BitMask,
+    Phase
+}
+impl Default for HaplotypeSource
+{
+    fn default()->Self
+    {
+        HaplotypeSource::BitMask
+    }
+}
+impl HaplotypeSource
+{
+    /// ## Summary
+    /// Return the name of the source as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            HaplotypeSource::BitMask=>"bitmask",
+            HaplotypeSource::Phase=>"phase"
+        }
+    }
+}
+impl FromStr for HaplotypeSource
+{
+    type Err=String;
+    fn from_str(source:&str)->Result<HaplotypeSource,String>
+    {
+        match source.to_lowercase().replace(['_','-'],"").as_str()
+        {
+            "bitmask" | "mask"=>Ok(HaplotypeSource::BitMask),
+            "phase" | "phased" | "gt" | "genotype"=>Ok(HaplotypeSource::Phase),
+            _=>Err(format!("{} is not a supported haplotype source, supported sources are: bitmask and phase",source))
+        }
+    }
+}
+/// The policy for the heterozygous calls whose phase is unknown, e.g. 0/1 or 1/2, as the assignment of their consequences to a haplotype
+/// is arbitrary: 
+/// 1. Assume, the default, the consequences are assigned as decoded from the bit-mask or the genotype, i.e. the call is treated as phased
+/// 2. Collapse, the consequences of both haplotypes are carried by both haplotypes, i.e. a single mutated sequence
+/// 3. Both, the consequences of both haplotypes are carried by the first haplotype while the second carries none, i.e. both possible haplotypes
+/// 4. Skip, the record is skipped for the proband, i.e. neither haplotype carries its consequences
+///
+/// The genotype is read from the first field of the proband field, where the VCF specification requires the GT field to be. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum UnphasedPolicy
+{
+    Assume,
+    Collapse,
+    Both,
+    Skip
+}
+impl Default for UnphasedPolicy
+{
+    fn default()->Self
+    {
+        UnphasedPolicy::Assume
+    }
+}
+impl UnphasedPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            UnphasedPolicy::Assume=>"assume",
+            UnphasedPolicy::Collapse=>"collapse",
+            UnphasedPolicy::Both=>"both",
+            UnphasedPolicy::Skip=>"skip"
+        }
+    }
+    /// ## Summary
+    /// Return whether the genotype of a proband field is heterozygous and unphased, e.g. 0/1, calls with a missing allele, e.g. ./1, are not 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::UnphasedPolicy; 
+    /// assert!(UnphasedPolicy::is_unphased_heterozygous("0/1:3")); 
+    /// assert!(!UnphasedPolicy::is_unphased_heterozygous("0|1:3")); 
+    /// assert!(!UnphasedPolicy::is_unphased_heterozygous("1/1:3")); 
+    /// assert!(!UnphasedPolicy::is_unphased_heterozygous("./1:3")); 
+    ///```
+    pub fn is_unphased_heterozygous(field:&str)->bool
+    {
+        let genotype=field.split(':').next().unwrap_or(""); 
+        if !genotype.contains('/')
+        {
+            return false
+        }
+        match genotype.split(['|','/']).map(|allele|allele.parse::<usize>().ok()).collect::<Option<Vec<usize>>>()
+        {
+            Some(called)=>called.windows(2).any(|alleles|alleles[0]!=alleles[1]),
+            None=>false
+        }
+    }
+    /// ## Summary
+    /// Return whether the policy applies to the consequences decoded for a proband at a record, i.e. whether the call is an unphased 
+    /// heterozygous call carrying at least one consequence, these calls are the unphased calls counted while the records are decoded, 
+    /// see VCFRecords::decode_indices 
+    pub fn is_applied<T>(field:&str, decoded:&(Vec<T>,Vec<T>))->bool
+    {
+        (!decoded.0.is_empty() || !decoded.1.is_empty()) && UnphasedPolicy::is_unphased_heterozygous(field)
+    }
+    /// ## Summary
+    /// Apply the policy to the consequences, or their indices, decoded for each haplotype of a proband at a record, calls that are not 
+    /// unphased heterozygous calls are returned as they are, see is_applied 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::UnphasedPolicy; 
+    /// let decoded=(vec![0],vec![1]); 
+    /// assert_eq!(UnphasedPolicy::Collapse.apply("0/1:6",decoded.clone()),(vec![0,1],vec![0,1])); 
+    /// assert_eq!(UnphasedPolicy::Both.apply("0/1:6",decoded.clone()),(vec![0,1],vec![])); 
+    /// assert_eq!(UnphasedPolicy::Skip.apply("0/1:6",decoded.clone()),(vec![],vec![])); 
+    /// assert_eq!(UnphasedPolicy::Skip.apply("0|1:6",decoded.clone()),decoded); 
+    ///```
+    pub fn apply<T:PartialEq+Clone>(&self, field:&str, decoded:(Vec<T>,Vec<T>))->(Vec<T>,Vec<T>)
+    {
+        if !UnphasedPolicy::is_applied(field, &decoded)
+        {
+            return decoded
+        }
+        let (mut haplotype1,haplotype2)=decoded; 
+        match self
+        {
+            UnphasedPolicy::Assume=>return (haplotype1,haplotype2),
+            UnphasedPolicy::Skip=>return (Vec::new(),Vec::new()),
+            UnphasedPolicy::Collapse | UnphasedPolicy::Both=>()
+        }
+        for elem in haplotype2
+        {
+            if !haplotype1.contains(&elem)
+            {
+                haplotype1.push(elem); 
+            }
+        }
+        match self
+        {
+            UnphasedPolicy::Collapse=>(haplotype1.clone(),haplotype1),
+            _=>(haplotype1,Vec::new())
+        }
+    }
+}
+impl FromStr for UnphasedPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<UnphasedPolicy,String>
+    {
+        match policy.to_lowercase().as_str()
+        {
+            "assume" | "as-is"=>Ok(UnphasedPolicy::Assume),
+            "collapse"=>Ok(UnphasedPolicy::Collapse),
+            "both"=>Ok(UnphasedPolicy::Both),
+            "skip"=>Ok(UnphasedPolicy::Skip),
+            _=>Err(format!("{} is not a supported policy for unphased genotypes, supported policies are: assume, collapse, both and skip",policy))
+        }
+    }
+}
+/// The alleles of a genotype call, i.e. the GT field of a proband field, where a missing allele is None, e.g. .|1 is parsed into 
+/// [None,Some(1)], and a haploid call, e.g. 1 at a hemizygous site of chrX in an XY sample, holds a single allele. The bit-masks of BCFtools/csq 
+/// assign the consequences of a haploid call to the first haplotype, hence, a haploid call holds no second haplotype. 
+/// ## Example
+///```
+/// use ppgg::data_structures::vcf_ds::Genotype; 
+/// let genotype=Genotype::parse(".|1").unwrap(); 
+/// assert!(genotype.is_partially_missing() && !genotype.is_missing()); 
+/// assert_eq!(genotype.get_called_haplotypes(),[false,true]); 
+/// assert_eq!(Genotype::parse("1").unwrap().get_called_haplotypes(),[true,false]); 
+/// assert!(Genotype::parse("./.").unwrap().is_missing()); 
+/// assert_eq!(Genotype::parse("0|x"),None); 
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Genotype
+{
+    alleles:Vec<Option<usize>>
+}
+impl Genotype
+{
+    /// ## Summary
+    /// Parse a genotype call, e.g. 0|1, ./1 or 1, returns None if the call is empty or if an allele is neither . nor an allele index 
+    pub fn parse(genotype:&str)->Option<Self>
+    {
+        if genotype.is_empty()
+        {
+            return None
+        }
+        let alleles=genotype.split(['|','/'])
+            .map(|allele|match allele
+            {
+                "."=>Some(None),
+                allele=>allele.parse::<usize>().ok().map(Some)
+            })
+            .collect::<Option<Vec<Option<usize>>>>()?; 
+        Some(Genotype{alleles})
+    }
+    /// ## Summary
+    /// Return the alleles of the call, in the order of the haplotypes 
+    pub fn get_alleles(&self)->&[Option<usize>]
+    {
+        &self.alleles
+    }
+    /// ## Summary
+    /// Return whether every allele of the call is missing, e.g. ./. or . 
+    pub fn is_missing(&self)->bool
+    {
+        self.alleles.iter().all(|allele|allele.is_none())
+    }
+    /// ## Summary
+    /// Return whether some, but not all, alleles of the call are missing, e.g. .|1 or 0/. 
+    pub fn is_partially_missing(&self)->bool
+    {
+        !self.is_missing() && self.alleles.iter().any(|allele|allele.is_none())
+    }
+    /// ## Summary
+    /// Return whether the call holds a single allele, e.g. 1 at a hemizygous site 
+    pub fn is_haploid(&self)->bool
+    {
+        self.alleles.len()==1
+    }
+    /// ## Summary
+    /// Return whether each haplotype holds a called allele, i.e. the haplotypes whose consequences can be decoded from the bit-mask 
+    pub fn get_called_haplotypes(&self)->[bool;2]
+    {
+        [matches!(self.alleles.first(),Some(Some(_))),matches!(self.alleles.get(1),Some(Some(_)))]
+    }
+    /// ## Summary
+    /// Return a genotype call with every called allele set to the reference, keeping its missing alleles and its separators, e.g. .|1 is 
+    /// returned as .|0 and 1/2 as 0/0 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::Genotype; 
+    /// assert_eq!(Genotype::to_reference_call(".|1"),".|0"); 
+    /// assert_eq!(Genotype::to_reference_call("1/12"),"0/0"); 
+    ///```
+    pub fn to_reference_call(genotype:&str)->String
+    {
+        genotype.split_inclusive(['|','/'].as_ref())
+            .map(|token|match token.trim_end_matches(['|','/'].as_ref())
+            {
+                "."=>token.to_string(),
+                allele=>format!("0{}",&token[allele.len()..])
+            })
+            .collect::<String>()
+    }
+}
+/// The ALT allele of each consequence of a multi-allelic record, i.e. the one-based index of the allele whose change is described by the 
+/// DNA change of the consequence, e.g. 1936821C>T, consequences without a change such as the consequences inherited from another record 
+/// have no allele and are never carried, along with the position of the GT field in the FORMAT layout of the record 
+/// ## Example
+///```
+/// use ppgg::data_structures::vcf_ds::AlleleDecomposition; 
+/// let record="1\t10\t.\tA\tT,G\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G1|T1|protein_coding|+|2K>2D|10A>G\tGT:BCSQ\t1|2:9"; 
+/// let decomposition=AlleleDecomposition::from_record(record).unwrap(); 
+/// assert_eq!(decomposition.get_alleles(),&[Some(1),Some(2)]); 
+/// assert_eq!(decomposition.get_indices("2|0:4"),Some((vec![1],vec![]))); 
+/// assert_eq!(decomposition.get_indices("./.:4"),None); 
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct AlleleDecomposition
+{
+    gt_index:usize,
+    alleles:Vec<Option<usize>>,
+    is_phased:bool,
+    unphased_policy:UnphasedPolicy,
+    span:(u64,u64),
+    changes:Vec<(String,Vec<u64>)>
+}
+impl AlleleDecomposition
+{
+    /// ## Summary
+    /// Create the decomposition of a record, returns None if the record has less than two ALT alleles or no GT field, or if a supported 
+    /// consequence can not be assigned to exactly one ALT allele, e.g. a compound consequence whose change spans several records 
+    pub fn from_record(record:&str)->Option<Self>
+    {
+        match record.split('\t').nth(4)
+        {
+            Some(alts) if alts.contains(',')=>AlleleDecomposition::decompose(record, false),
+            _=>None
+        }
+    }
+    /// ## Summary
+    /// Similar to from_record, however, bi-allelic records are decomposed as well, i.e. each supported consequence belongs to the ALT 
+    /// allele, and only phased heterozygous calls are decoded from the genotype, see get_indices and HaplotypeSource::Phase, unless the 
+    /// unphased calls are resolved by an UnphasedPolicy other than Assume, see for_record  
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::AlleleDecomposition; 
+    /// let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT\t1|0"; 
+    /// let decomposition=AlleleDecomposition::from_phased_record(record).unwrap(); 
+    /// assert_eq!(decomposition.get_indices("0|1"),Some((vec![],vec![0]))); 
+    /// assert_eq!(decomposition.get_indices("1/1"),Some((vec![0],vec![0]))); 
+    /// assert_eq!(decomposition.get_indices("0/1"),None); 
+    ///```
+    pub fn from_phased_record(record:&str)->Option<Self>
+    {
+        AlleleDecomposition::decompose(record, true)
+    }
+    /// ## Summary
+    /// Return the decomposition of a record under the HaplotypeSource and the MultiAllelicPolicy of the options, or None if the record is 
+    /// decoded from the bit-mask, the unphased calls of a phased decomposition are decoded from the genotype unless the UnphasedPolicy of 
+    /// the options is Assume 
+    pub fn for_record(record:&str, options:&DecodingOptions)->Option<Self>
+    {
+        match (options.haplotype_source,options.multi_allelic_policy)
+        {
+            (HaplotypeSource::Phase,_)=>AlleleDecomposition::from_phased_record(record)
+                .map(|decomposition|AlleleDecomposition{unphased_policy:options.unphased_policy,..decomposition}),
+            (HaplotypeSource::BitMask,MultiAllelicPolicy::Genotype)=>AlleleDecomposition::from_record(record),
+            (HaplotypeSource::BitMask,MultiAllelicPolicy::BitMask)=>None
+        }
+    }
+    /// decompose a record into the ALT allele of each consequence, see from_record and from_phased_record 
+    fn decompose(record:&str, is_phased:bool)->Option<Self>
+    {
+        let fields=record.split('\t').collect::<Vec<&str>>(); 
+        if fields.len()<9
+        {
+            return None
+        }
+        let is_bi_allelic=!fields[4].contains(',');
+        let gt_index=fields[8].split(':').position(|field|field=="GT")?; 
+        let csq=fields[7].split(';').find_map(|field|field.strip_prefix("BCSQ="))?; 
+        let pos=fields[1].parse::<u64>().ok()?; 
+        // each allele is matched as written in the record and after trimming the bases it shares with the reference 
+        let changes=fields[4].split(',')
+            .map(|alt|[format!("{}{}>{}",pos,fields[3],alt),AlleleDecomposition::trim_change(pos,fields[3],alt)])
+            .collect::<Vec<[String;2]>>(); 
+        let mut alleles=Vec::new(); 
+        let mut csq_changes=Vec::new(); 
+        for consequence in csq.split(',')
+        {
+            let csq_type=consequence.split('|').next().unwrap_or(""); 
+            if consequence.starts_with('@') || !Constants::SUP_TYPE.contains(&csq_type)
+            {
+                alleles.push(None); 
+                csq_changes.push((String::new(),Vec::new())); 
+                continue; 
+            }
+            let change=consequence.rsplit('|').next().unwrap_or(""); 
+            // the position of each DNA change of the consequence, i.e. more than one for the compound consequences 
+            let positions=change.split('+')
+                .filter_map(|change|change.split(|base:char|!base.is_ascii_digit()).next().and_then(|pos|pos.parse::<u64>().ok()))
+                .collect::<Vec<u64>>(); 
+            csq_changes.push((consequence.split('|').nth(2).unwrap_or("").to_string(),positions)); 
+            if is_bi_allelic
+            {
+                alleles.push(Some(1)); 
+                continue; 
+            }
+            let matches=changes.iter().enumerate().filter(|(_,allele_changes)|allele_changes.iter().any(|allele_change|allele_change==change)).collect::<Vec<_>>(); 
+            match matches.as_slice()
+            {
+                [(allele_index,_)]=>alleles.push(Some(allele_index+1)),
+                _=>return None
+            }
+        }
+        Some(AlleleDecomposition{gt_index,alleles,is_phased,unphased_policy:UnphasedPolicy::default(),span:(pos,pos+fields[3].len().max(1) as u64-1),changes:csq_changes})
+    }
+    /// ## Summary
+    /// Return the DNA change of an allele after removing the bases it shares with the reference, first at the end then at the start, 
+    /// where at least one base is kept, e.g. 10CTT>CT is trimmed into 10CT>C 
+    fn trim_change(pos:u64, reference:&str, alt:&str)->String
+    {
+        let (mut reference,mut alt)=(reference,alt); 
+        while reference.len()>1 && alt.len()>1 && reference.as_bytes()[reference.len()-1]==alt.as_bytes()[alt.len()-1]
+        {
+            reference=&reference[..reference.len()-1]; 
+            alt=&alt[..alt.len()-1]; 
+        }
+        let mut pos=pos; 
+        while reference.len()>1 && alt.len()>1 && reference.as_bytes()[0]==alt.as_bytes()[0]
+        {
+            reference=&reference[1..]; 
+            alt=&alt[1..]; 
+            pos+=1; 
+        }
+        format!("{}{}>{}",pos,reference,alt)
+    }
+    /// ## Summary
+    /// Return the ALT allele of each consequence of the record 
+    pub fn get_alleles(&self)->&[Option<usize>]
+    {
+        &self.alleles
+    }
+    /// ## Summary
+    /// Return the indices of the consequences carried by each haplotype of a proband field, where a haplotype carries the consequences of 
+    /// the allele its genotype calls, returns None if an allele of the genotype is missing, e.g. ./. or 1|., as the bit-mask of such calls 
+    /// may have been imputed, see missing_genotypes, or if the call is heterozygous and unphased, e.g. 0/1, for a decomposition created with 
+    /// from_phased_record. A haploid call is assigned to the first haplotype. 
+    pub fn get_indices(&self, field:&str)->Option<HaplotypeIndices>
+    {
+        let called=self.get_called_alleles(field)?; 
+        Some(self.get_carried_indices(&called))
+    }
+    /// return the alleles called by the genotype of a proband field, or None if the call can not be decoded, see get_indices 
+    fn get_called_alleles(&self, field:&str)->Option<Vec<usize>>
+    {
+        let genotype=field.split(':').nth(self.gt_index)?; 
+        let called=genotype.split(['|','/'])
+            .map(|allele|allele.parse::<usize>().ok())
+            .collect::<Option<Vec<usize>>>()?; 
+        // under an UnphasedPolicy other than Assume, the policy resolves the unphased calls after their decoding as written 
+        if self.is_phased && self.unphased_policy==UnphasedPolicy::Assume && genotype.contains('/') && called.windows(2).any(|alleles|alleles[0]!=alleles[1])
+        {
+            return None
+        }
+        Some(called)
+    }
+    /// return the indices of the consequences of the called alleles in each haplotype 
+    fn get_carried_indices(&self, called:&[usize])->HaplotypeIndices
+    {
+        let get_carried=|called_allele:Option<&usize>|self.alleles.iter().enumerate()
+            .filter(|(_,allele)|allele.is_some() && allele.as_ref()==called_allele)
+            .map(|(index,_)|index)
+            .collect::<Vec<usize>>(); 
+        (get_carried(called.first()),get_carried(called.get(1)))
+    }
+    /// ## Summary
+    /// Return the first and the last position of the reference allele of the record 
+    pub fn get_span(&self)->(u64,u64)
+    {
+        self.span
+    }
+    /// ## Summary
+    /// Return whether the decomposition was created with from_phased_record 
+    pub fn is_phased(&self)->bool
+    {
+        self.is_phased
+    }
+    /// ## Summary
+    /// Return the indices of the consequences carried by each haplotype of a proband at each record decomposed with from_phased_record, 
+    /// i.e. the records of HaplotypeSource::Phase, or None for the other records and the calls that can not be decoded, see get_indices. 
+    /// As the haplotypes are reconstructed across records, the consequences are resolved like bcftools csq resolves them for its bit-mask:
+    /// 1. an allele whose reference allele overlaps the reference allele of a previous allele of the haplotype, e.g. two deletions sharing bases, is dropped
+    /// 2. a compound consequence, e.g. a frameshift whose change is 100TC>T+177C>G, is not carried if the haplotype has the reference allele of a record of its changes
+    /// 3. a compound consequence replaces the other consequences of its transcript at its own record and at the records of its changes
+    ///
+    /// The decompositions, the proband fields and the loci, i.e. CHROM:POS, are aligned with the records, which are sorted by position. 
+    pub fn resolve_phased_indices(decompositions:&[Option<AlleleDecomposition>], proband_fields:&[String], loci:&[String])->Vec<Option<HaplotypeIndices>>
+    {
+        let called=decompositions.iter().zip(proband_fields.iter())
+            .map(|(decomposition,field)|match decomposition
+            {
+                Some(decomposition) if decomposition.is_phased=>decomposition.get_called_alleles(field),
+                _=>None
+            })
+            .collect::<Vec<Option<Vec<usize>>>>(); 
+        let chroms=loci.iter().map(|locus|locus.rsplit_once(':').map(|(chrom,_)|chrom).unwrap_or(locus)).collect::<Vec<&str>>(); 
+        let calls_alt=|record_index:usize,haplotype:usize|called[record_index].as_ref()
+            .and_then(|alleles|alleles.get(haplotype))
+            .map_or(false,|allele|*allele!=0); 
+        // the positions of the records, the changes of a compound consequence without a record, e.g. in a subset of the records, are carried
+        let record_positions=decompositions.iter().zip(chroms.iter())
+            .filter_map(|(decomposition,chrom)|decomposition.as_ref().map(|decomposition|(*chrom,decomposition.span.0)))
+            .collect::<HashSet<(&str,u64)>>(); 
+        let mut resolved=called.iter().zip(decompositions.iter())
+            .map(|(alleles,decomposition)|match (alleles,decomposition)
+            {
+                (Some(alleles),Some(decomposition))=>Some(decomposition.get_carried_indices(alleles)),
+                _=>None
+            })
+            .collect::<Vec<Option<HaplotypeIndices>>>(); 
+        for haplotype in 0..2
+        {
+            // the positions of the ALT alleles of the haplotype 
+            let alt_positions=decompositions.iter().enumerate()
+                .filter(|(record_index,_)|calls_alt(*record_index,haplotype))
+                .filter_map(|(record_index,decomposition)|decomposition.as_ref().map(|decomposition|(chroms[record_index],decomposition.span.0)))
+                .collect::<HashSet<(&str,u64)>>(); 
+            let mut last_allele:Option<(&str,u64)>=None; 
+            let mut covered:HashSet<(&str,&str,u64)>=HashSet::new(); 
+            for (record_index,(decomposition,indices)) in decompositions.iter().zip(resolved.iter_mut()).enumerate()
+            {
+                let (decomposition,indices)=match (decomposition,indices)
+                {
+                    (Some(decomposition),Some(indices)) if calls_alt(record_index,haplotype)=>(decomposition,indices),
+                    _=>continue
+                };
+                let carried=match haplotype
+                {
+                    0=>&mut indices.0,
+                    _=>&mut indices.1
+                };
+                let chrom=chroms[record_index]; 
+                let (start,end)=decomposition.span; 
+                match last_allele
+                {
+                    Some((last_chrom,last_end)) if last_chrom==chrom && start<=last_end=>
+                    {
+                        carried.clear(); 
+                        continue
+                    },
+                    _=>last_allele=Some((chrom,end))
+                }
+                let changes=&decomposition.changes; 
+                carried.retain(|index|changes[*index].1.iter().all(|pos|*pos==start || alt_positions.contains(&(chrom,*pos)) || !record_positions.contains(&(chrom,*pos)))); 
+                let mut num_changes:HashMap<&str,usize>=HashMap::new(); 
+                for index in carried.iter()
+                {
+                    let max_changes=num_changes.entry(changes[*index].0.as_str()).or_insert(0); 
+                    *max_changes=(*max_changes).max(changes[*index].1.len()); 
+                }
+                carried.retain(|index|changes[*index].1.len()==num_changes[changes[*index].0.as_str()]); 
+                for index in carried.iter()
+                {
+                    covered.extend(changes[*index].1.iter().filter(|pos|**pos!=start).map(|pos|(chrom,changes[*index].0.as_str(),*pos))); 
+                }
+            }
+            // the changes of a compound consequence precede it on the reverse strand, hence, they are dropped once all records are resolved 
+            for (record_index,(decomposition,indices)) in decompositions.iter().zip(resolved.iter_mut()).enumerate()
+            {
+                if let (Some(decomposition),Some(indices))=(decomposition,indices)
+                {
+                    let carried=match haplotype
+                    {
+                        0=>&mut indices.0,
+                        _=>&mut indices.1
+                    };
+                    carried.retain(|index|!covered.contains(&(chroms[record_index],decomposition.changes[*index].0.as_str(),decomposition.span.0))); 
+                }
+            }
+        }
+        resolved
+    }
+    /// ## Summary
+    /// Return the consequences carried by each haplotype of a proband field, i.e. the consequences of the record, see get_indices 
+    pub fn get_effects(&self, csq:&str, field:&str)->Option<(Vec<String>,Vec<String>)>
+    {
+        Some(AlleleDecomposition::select_effects(csq, self.get_indices(field)?))
+    }
+    /// return the consequences of the record at the indices of each haplotype 
+    fn select_effects(csq:&str, (indices1,indices2):HaplotypeIndices)->(Vec<String>,Vec<String>)
+    {
+        let splitted_csq=csq.split(',').collect::<Vec<&str>>(); 
+        let get_effects=|indices:Vec<usize>|indices.into_iter().filter_map(|idx|splitted_csq.get(idx).map(|effect|effect.to_string())).collect::<Vec<String>>(); 
+        (get_effects(indices1),get_effects(indices2))
+    }
+}
+/// A summary of the records whose FORMAT layout differs from the layout of the first record in the file, 
+/// drifted records are stored as a tuple of the record locus, i.e. CHROM:POS, and the record FORMAT layout.
+#[derive(Debug,Clone,PartialEq)]
+pub struct FormatDrift
+{
+    pub reference_layout:String,
+    pub drifted_records:Vec<(String,String)>
+}
+/// An abstraction for a collection of VCF Records, the struct owns the provided vector of strings,
+/// where each string is a record from the file.
+#[derive(Debug,Clone)]
+pub struct VCFRecords
+{
+    records:Vec<String>,
+    vep_annotated:bool,
+    decoding_options:DecodingOptions,
+}
+impl VCFRecords
+{
+    /// Create a new VCFRecords from a vector of strings  where each string represent a line in the VCF file 
+    /// ## Example
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::VCFRecords; 
+    /// let test_case1=vec![
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tField1\tField1.1\tField1.2\tField1.3\tField1.4\tField1.5\tField1.6\tField1.7".to_string(),
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tField2\tField2.1\tField2.2\tField2.3\tField2.4\tField2.5\tField2.6\tField2.7".to_string(),
+    ///        ];
+    /// let records=VCFRecords::new(test_case1); 
+    ///``` 
+    pub fn new(records:Vec<String>)->Self
+    {
+        VCFRecords{records,vep_annotated:false,decoding_options:DecodingOptions::default()}
+    }
+    /// ## Summary
+    /// Create a new VCFRecords from records whose BCSQ annotations were converted from the CSQ annotations of Ensembl VEP, see
+    /// functions::vep, where the position of each consequence in the mutated sequence is the reference position, hence, the positions
+    /// are assigned per haplotype once the intermediate representations are built, see AltTranscript::assign_mutated_positions
+    pub fn from_vep_records(records:Vec<String>)->Self
+    {
+        VCFRecords{records,vep_annotated:true,decoding_options:DecodingOptions::default()}
+    }
+    /// ## Summary
+    /// Decode the consequences of the records with the provided options instead of the default ones, see DecodingOptions 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::{VCFRecords,DecodingOptions,MultiAllelicPolicy}; 
+    /// let options=DecodingOptions{multi_allelic_policy:MultiAllelicPolicy::BitMask,..Default::default()}; 
+    /// let records=VCFRecords::new(vec!["1\t100\t.\tAC\tGC,A".to_string()]).with_decoding_options(options); 
+    /// assert_eq!(records.get_decoding_options(),&options); 
+    ///```
+    pub fn with_decoding_options(mut self, decoding_options:DecodingOptions)->Self
+    {
+        self.decoding_options=decoding_options; 
+        self
+    }
+    /// ## Summary
+    /// Return the options the consequences of the records are decoded with 
+    pub fn get_decoding_options(&self)->&DecodingOptions
+    {
+        &self.decoding_options
+    }
+    /// ## Summary
+    /// Return whether the BCSQ annotations of the records were converted from the CSQ annotations of Ensembl VEP
+    pub fn is_vep_annotated(&self)->bool
+    {
+        self.vep_annotated
+    }
+    /// Return a reference 
+    /// ## Example
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::VCFRecords; 
+    /// let test_case1=vec![
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tField1\tField1.1\tField1.2\tField1.3\tField1.4\tField1.5\tField1.6\tField1.7".to_string(),
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tField2\tField2.1\tField2.2\tField2.3\tField2.4\tField2.5\tField2.6\tField2.7".to_string(),
+    ///        ];
+    /// let records=VCFRecords::new(test_case1.clone()); 
+    /// let results=records.get_records(); 
+    /// assert_eq!(results,&test_case1); 
+    ///```
+    pub fn get_records(&self)->&Vec<String>
+    {
+        &self.records
+    }
+    /// Return a vector of String containing the consequences at each genomic position 
+    /// ## Example
+    ///```
+    /// use std::path::Path; 
+    /// use ppgg_rust::readers; 
+    /// use ppgg_rust::data_structures::vcf_ds::VCFRecords; 
+    /// let case_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_case.vcf"); 
+    /// let res_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_res.txt");
+    /// let records=readers::vcf_helpers::read_file(case_path).unwrap(); 
+    /// let results=readers::vcf_helpers::read_file(res_path)
+    ///                                .unwrap()
+    ///                                .iter()
+    ///                                .map(|elem| elem.split("=").map(|elem| elem.to_string()).collect::<Vec<String>>().last().unwrap().clone())
+    ///                                .collect::<Vec<String>>();
+    /// let vcf_records=VCFRecords::new(records);
+    /// assert_eq!(results,vcf_records.get_consequences_vector());
+    ///```
+    pub fn get_consequences_vector(&self,engine:Engine)->Vec<String>
+    {
+        match engine
+        {
+            Engine::ST=>
+            {
+                self.records.iter()
+                    .map(|rec|rec.split("\t").collect::<Vec<&str>>()[7])
+                    .map(|rec| rec.split("BCSQ=").collect::<Vec<&str>>()[1].to_string())
+                    .collect::<Vec<String>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                self.records.par_iter()
+                    .map(|rec|rec.split("\t").collect::<Vec<&str>>()[7])
+                    .map(|rec| rec.split("BCSQ=").collect::<Vec<&str>>()[1].to_string())
+                    .collect::<Vec<String>>()
+            }
+        }
+    }
+    /// Returns a 2D Matrix that is implemented as a vector of vector of strings, where all the mutations observed in a patient is 
+    /// collected into one vector, i.e. the inner vector, the outer vector represent the collection of all mutations.
+    /// ## Example
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::VCFRecords; 
+    /// let test_case1=vec![
+    ///    "1\t1\t1\t1\t1\t1\t1\t1\tField1\tField1.1\tField1.2\tField1.3\tField1.4\tField1.5\tField1.6\tField1.7".to_string(),
+    ///    "1\t1\t1\t1\t1\t1\t1\t1\tField2\tField2.1\tField2.2\tField2.3\tField2.4\tField2.5\tField2.6\tField2.7".to_string(),
+    ///    "1\t1\t1\t1\t1\t1\t1\t1\tField3\tField3.1\tField3.2\tField3.3\tField3.4\tField3.5\tField3.6\tField3.7".to_string(),
+    ///    "1\t1\t1\t1\t1\t1\t1\t1\tField4\tField4.1\tField4.2\tField4.3\tField4.4\tField4.5\tField4.6\tField4.7".to_string(),
+    ///    "1\t1\t1\t1\t1\t1\t1\t1\tField5\tField5.1\tField5.2\tField5.3\tField5.4\tField5.5\tField5.6\tField5.7".to_string(),
+    ///    ];
+    /// let test_result1=vec![
+    ///        "Field1", "Field2", "Field3", "Field4", "Field5"].
+    ///        iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+    /// let test_result2=vec![
+    ///        "Field1.1", "Field2.1", "Field3.1", "Field4.1", "Field5.1"].
+    ///        iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+    /// let test_result3=vec![
+    ///            "Field1.2", "Field2.2", "Field3.2", "Field4.2", "Field5.2"].
+    ///            iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+    /// let test_result4=vec![
+    ///                "Field1.3", "Field2.3", "Field3.3", "Field4.3", "Field5.3"].
+    ///                iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+    /// let test_result5=vec![
+    ///                    "Field1.4", "Field2.4", "Field3.4", "Field4.4", "Field5.4"].
+    ///                    iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+    /// let mut vcf_records=VCFRecords::new(test_case1);
+    /// assert_eq!(test_result1,vcf_records.get_patient_fields(8)[0]);
+    /// assert_eq!(test_result2,vcf_records.get_patient_fields(8)[1]);
+    /// assert_eq!(test_result3,vcf_records.get_patient_fields(8)[2]);
+    /// assert_eq!(test_result4,vcf_records.get_patient_fields(8)[3]);
+    /// assert_eq!(test_result5,vcf_records.get_patient_fields(8)[4]);
+    ///```
+    /// ## Notes
+    /// This function is computational intensive, it ran in two modes:
+    /// first single thread model where work is executed sequentially and a multi-threaded mode
+    /// where work is split or distributed among thread, each thread work on a chunk of the array 
+    /// finally results are concatenated and returned. 
+    pub fn get_patient_fields(&mut self, num_probands:usize,engine:Engine)->Vec<Vec<String>>
+    {
+        let number_probands=&self.records[0].matches('\t').count()-8;
+        let mut res:Vec<Vec<String>>=Vec::with_capacity(number_probands);
+        for _ in 0..num_probands
+        {
+            res.push(Vec::with_capacity(self.records.len())); 
+        }
+        match engine
+        {
+            Engine::ST => 
+            {
+                for record in &self.records
+                {
+                    // split records by \t  
+                    let mut fields=record.split('\t').collect::<Vec<_>>();
+                    // drain the first 8-element in a vector 
+                    fields.drain(0..9);
+                    // add the fields to each patient in the vector of patients 
+                    for idx in 0..fields.len()
+                    {
+                        res[idx].push(fields[idx].to_string())
+                    }
+                }
+                res
+            },
+            Engine::GPU | Engine::MT | Engine::Auto =>
+            {
+                let number_probands=&self.records[0].matches('\t').count()-8;
+                let chunk_size= std::cmp::max(self.records.len()/rayon::current_num_threads(),1); 
+                let temp_res=self.records.chunks(chunk_size)
+                .map(|load|
+                {
+                    let mut res:Vec<Vec<String>>=Vec::with_capacity(number_probands);
+                    for _ in 0..num_probands
+                    {
+                        res.push(Vec::with_capacity(self.records.len())); 
+                    }
+                    for record in load
+                    {
+                        // split records by \t  
+                        let mut fields=record.split('\t').collect::<Vec<_>>();
+                        // drain the first 8-element in a vector 
+                        fields.drain(0..9);
+                        // add the fields to each patient in the vector of patients 
+                        for idx in 0..fields.len()
+                        {
+                            res[idx].push(fields[idx].to_string())
+                        }
+                    }
+                    res
+                })
+                .collect::<Vec<_>>();
+                for vec in temp_res
+                {
+                    let mut idx=0; 
+                    for pat_vec in vec
+                    {
+                        res[idx].extend(pat_vec);   
+                        idx+=1; 
+                    }
+                }
+                res
+            }
+        }        
+    }
+
+    /// Return the index of the BCSQ field in the FORMAT column of each record or None if the record FORMAT does not contain a BCSQ field.
+    /// The FORMAT layout is parsed per record as merged VCF files might change the layout across records. 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::VCFRecords; 
+    /// let test_case=vec![
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tGT:BCSQ\t0|1:1".to_string(),
+    ///        "1\t2\t1\t1\t1\t1\t1\t1\tGT:BCSQ:DP\t0|1:1:30".to_string(),
+    ///        "1\t3\t1\t1\t1\t1\t1\t1\tGT\t0|1".to_string(),
+    ///        ];
+    /// let records=VCFRecords::new(test_case); 
+    /// assert_eq!(records.get_bcsq_indices(),vec![Some(1),Some(1),None]); 
+    ///```
+    pub fn get_bcsq_indices(&self)->Vec<Option<usize>>
+    {
+        self.records.iter()
+            .map(|rec|rec.split('\t').nth(8).and_then(|format|format.split(':').position(|field|field=="BCSQ")))
+            .collect::<Vec<Option<usize>>>()
+    }
+    /// Compare the FORMAT layout of each record to the layout of the first record and return a FormatDrift instance 
+    /// summarizing the records that have a different layout.
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::VCFRecords; 
+    /// let test_case=vec![
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tGT:BCSQ\t0|1:1".to_string(),
+    ///        "1\t2\t1\t1\t1\t1\t1\t1\tGT:DP:BCSQ\t0|1:30:1".to_string(),
+    ///        ];
+    /// let records=VCFRecords::new(test_case); 
+    /// let drift=records.get_format_drift(); 
+    /// assert_eq!(drift.reference_layout,"GT:BCSQ"); 
+    /// assert_eq!(drift.drifted_records,vec![("1:2".to_string(),"GT:DP:BCSQ".to_string())]); 
+    ///```
+    pub fn get_format_drift(&self)->FormatDrift
+    {
+        let get_layout=|rec:&String|rec.split('\t').nth(8).unwrap_or("").to_string(); 
+        let reference_layout=match self.records.first()
+        {
+            Some(rec)=>get_layout(rec),
+            None=>return FormatDrift{reference_layout:"".to_string(),drifted_records:Vec::new()}
+        };
+        let drifted_records=self.records.iter()
+            .map(|rec|(rec,get_layout(rec)))
+            .filter(|(_,layout)|*layout!=reference_layout)
+            .map(|(rec,layout)|
+            {
+                let fields=rec.split('\t').take(2).collect::<Vec<&str>>(); 
+                (fields.join(":"),layout)
+            })
+            .collect::<Vec<(String,String)>>(); 
+        FormatDrift{reference_layout,drifted_records}
+    }
+    /// Return the decomposition of each record into its ALT alleles, see AlleleDecomposition::for_record, or None for the records that are 
+    /// decoded from the bit-mask, i.e. with HaplotypeSource::BitMask bi-allelic records, records that can not be decomposed and every record 
+    /// with MultiAllelicPolicy::BitMask 
+    pub fn get_allele_decompositions(&self)->Vec<Option<AlleleDecomposition>>
+    {
+        match (self.decoding_options.haplotype_source,self.decoding_options.multi_allelic_policy)
+        {
+            (HaplotypeSource::BitMask,MultiAllelicPolicy::BitMask)=>vec![None;self.records.len()],
+            _=>self.records.iter().map(|rec|AlleleDecomposition::for_record(rec,&self.decoding_options)).collect()
+        }
+    }
+    /// Return the locus of each record, i.e. CHROM:POS, which is used to report errors along with the record context 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::VCFRecords; 
+    /// let records=VCFRecords::new(vec!["1\t100\t.\tA\tT".to_string(),"X\t200\t.\tA\tT".to_string()]); 
+    /// assert_eq!(records.get_loci(),vec!["1:100".to_string(),"X:200".to_string()]); 
+    ///```
+    pub fn get_loci(&self)->Vec<String>
+    {
+        self.records.iter()
+            .map(|rec|rec.split('\t').take(2).collect::<Vec<&str>>().join(":"))
+            .collect::<Vec<String>>()
+    }
+    pub fn get_csq_per_patient(&mut self,num_probands:usize,engine:Engine)->Vec<(Vec<String>,Vec<String>)>
+    {
+        let consequences=self.get_consequences_vector(engine.clone()); 
+        let bcsq_indices=self.get_bcsq_indices(); 
+        let decompositions=self.get_allele_decompositions(); 
+        let loci=self.get_loci(); 
+        let probands_table=self.get_patient_fields(num_probands,engine.clone());
+        // we need to get the consequences of each vector 
+        match engine
+        {
+            Engine::ST =>
+            {
+                probands_table.iter()
+                .enumerate()
+                .map(|(sample_index,donor)|VCFRecords::decode_back(&consequences,donor,&bcsq_indices,&decompositions,&loci,sample_index,self.decoding_options,engine.clone()))
+                .collect::<Vec<(Vec<String>,Vec<String>)>>()   
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                probands_table.par_iter()
+                .enumerate()
+                .map(|(sample_index,donor)|VCFRecords::decode_back(&consequences,donor,&bcsq_indices,&decompositions,&loci,sample_index,self.decoding_options,engine.clone()))
+                .collect::<Vec<(Vec<String>,Vec<String>)>>()   
+            }
+        }
+    }
+
+    /// Return the fields of the probands at the provided columns, where columns are zero-based indices relative to the first proband column
+    /// and must be sorted in ascending order, see Probands::select. Each record is only scanned up to the last requested column and only
+    /// the requested columns are copied, which reduces the parsing time considerably when a few probands are selected from a large cohort.
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::VCFRecords; 
+    /// use ppgg::data_structures::InternalRep::engines::Engine; 
+    /// let test_case=vec![
+    ///        "1\t1\t1\t1\t1\t1\t1\t1\tGT:BCSQ\t0|1:1\t1|0:2\t1|1:3".to_string(),
+    ///        "1\t2\t1\t1\t1\t1\t1\t1\tGT:BCSQ\t0|0:0\t0|1:1\t1|0:2".to_string(),
+    ///        ];
+    /// let records=VCFRecords::new(test_case); 
+    /// let fields=records.get_selected_patient_fields(&[0,2],Engine::ST); 
+    /// assert_eq!(fields,vec![vec!["0|1:1".to_string(),"0|0:0".to_string()],vec!["1|1:3".to_string(),"1|0:2".to_string()]]); 
+    ///```
+    pub fn get_selected_patient_fields(&self, columns:&[usize], engine:Engine)->Vec<Vec<String>>
+    {
+        let fields_per_record=match engine
+        {
+            Engine::ST=>self.records.iter().map(|record|VCFRecords::slice_columns(record,columns)).collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>self.records.par_iter().map(|record|VCFRecords::slice_columns(record,columns)).collect::<Vec<_>>()
+        };
+        // transpose the fields from a per-record into a per-proband layout 
+        let mut res=columns.iter().map(|_|Vec::with_capacity(self.records.len())).collect::<Vec<Vec<String>>>(); 
+        for record_fields in fields_per_record
+        {
+            for (idx,field) in record_fields.into_iter().enumerate()
+            {
+                res[idx].push(field); 
+            }
+        }
+        res
+    }
+    /// Copy the proband fields at the provided sorted columns from a record, the record is split lazily and the scan stops after the 
+    /// last requested column, missing fields are returned as empty strings, i.e. they are treated as the reference.
+    fn slice_columns(record:&str, columns:&[usize])->Vec<String>
+    {
+        let mut res=Vec::with_capacity(columns.len()); 
+        let mut fields=record.split('\t').skip(9).enumerate(); 
+        for column in columns.iter()
+        {
+            let field=fields.find(|(idx,_)|idx==column).map(|(_,field)|field).unwrap_or(""); 
+            res.push(field.to_string()); 
+        }
+        res
+    }
+    /// Return the consequences observed in each haplotype of the probands at the provided columns, see get_selected_patient_fields 
+    /// for the layout of the columns and get_csq_per_patient for the layout of the results
+    pub fn get_csq_per_selected_patient(&self, columns:&[usize], engine:Engine)->Vec<(Vec<String>,Vec<String>)>
+    {
+        let consequences=self.get_consequences_vector(engine.clone()); 
+        let bcsq_indices=self.get_bcsq_indices(); 
+        let decompositions=self.get_allele_decompositions(); 
+        let loci=self.get_loci(); 
+        let probands_table=self.get_selected_patient_fields(columns,engine.clone());
+        match engine
+        {
+            Engine::ST =>
+            {
+                probands_table.iter()
+                .zip(columns.iter())
+                .map(|(donor,sample_index)|VCFRecords::decode_back(&consequences,donor,&bcsq_indices,&decompositions,&loci,*sample_index,self.decoding_options,engine.clone()))
+                .collect::<Vec<(Vec<String>,Vec<String>)>>()   
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                probands_table.par_iter()
+                .zip(columns.par_iter())
+                .map(|(donor,sample_index)|VCFRecords::decode_back(&consequences,donor,&bcsq_indices,&decompositions,&loci,*sample_index,self.decoding_options,engine.clone()))
+                .collect::<Vec<(Vec<String>,Vec<String>)>>()   
+            }
+        }
+    }
+
+    /// Decode the bitmask of each record in the proband fields back into the consequences observed in each haplotype, 
+    /// bcsq_indices contain the position of the BCSQ field in the FORMAT layout of each record, see get_bcsq_indices, and decompositions the
+    /// decomposition of the multi-allelic records whose consequences are decoded from the genotypes instead, see get_allele_decompositions,
+    /// while loci and sample_index, i.e. the zero-based index of the proband among the probands of the file, are used to report decoding 
+    /// errors, see decode_effects for the handling of these errors. The unphased heterozygous calls and the decoding errors are handled 
+    /// with the policies of options. 
+    #[allow(clippy::too_many_arguments)]
+    pub fn decode_back(consequences:&Vec<String>,proband_fields:&Vec<String>,bcsq_indices:&Vec<Option<usize>>,decompositions:&[Option<AlleleDecomposition>],
+        loci:&[String],sample_index:usize,options:DecodingOptions,engine:Engine)->(Vec<String>,Vec<String>)
+    {
+        // get index of each consequences 
+        let bitmasks= match engine 
+        {
+            Engine::ST=>
+            {
+                proband_fields
+                            .iter()
+                            .zip(bcsq_indices.iter())
+                            .map(|(field,bcsq_index)| VCFRecords::get_bit_mask(field,bcsq_index))
+                            .collect::<Vec<String>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                (proband_fields,bcsq_indices)
+                            .into_par_iter()
+                            .map(|(field,bcsq_index)| VCFRecords::get_bit_mask(field,bcsq_index))
+                            .collect::<Vec<String>>()
+            }
+        };
+        // get a vector of tuples at each position 
+        let mut results = match engine
+        {
+            Engine::ST => 
+            {
+                consequences.iter()
+                            .zip(bitmasks.iter()) 
+                            .zip(loci.iter())
+                            .zip(proband_fields.iter().zip(decompositions.iter()))
+                            .map(|(((csq,bitmask),locus),(field,decomposition))|VCFRecords::decode_record_effects(csq,bitmask,field,decomposition,locus,sample_index,options.check_policy))
+                            .collect::<Vec<(Vec<String>,Vec<String>)>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                consequences.par_iter()
+                            .zip(bitmasks.par_iter())
+                            .zip(loci.par_iter())
+                            .zip(proband_fields.par_iter().zip(decompositions.par_iter()))
+                            .map(|(((csq,bitmask),locus),(field,decomposition))|VCFRecords::decode_record_effects(csq,bitmask,field,decomposition,locus,sample_index,options.check_policy))
+                            .collect::<Vec<(Vec<String>,Vec<String>)>>()
+            }
+        };        
+        if decompositions.iter().flatten().any(|decomposition|decomposition.is_phased())
+        {
+            for ((result,csq),resolved) in results.iter_mut().zip(consequences.iter())
+                .zip(AlleleDecomposition::resolve_phased_indices(decompositions, proband_fields, loci))
+            {
+                if let Some(indices)=resolved
+                {
+                    *result=AlleleDecomposition::select_effects(csq, indices); 
+                }
+            }
+        }
+        for (result,field) in results.iter_mut().zip(proband_fields.iter())
+        {
+            *result=options.unphased_policy.apply(field, std::mem::take(result)); 
+        }
+        results.retain(|(elem1,elem2)|!elem1.is_empty() || !elem2.is_empty()); 
+        // unroll the mutation into two vectors one for the first haplotype and one for the second 
+        let tuple_1_res=match engine 
+        {
+            Engine::ST=>
+            {
+                results.iter()
+                .map(|elem|elem.0.clone())
+                .flatten()
+                .filter(|csq|Constants::SUP_TYPE.contains(&text_parser::get_type(csq)))
+                .collect::<Vec<String>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                results.par_iter()
+                        .map(|elem|elem.0.clone())
+                        .flatten()
+                        .filter(|csq|Constants::SUP_TYPE.contains(&text_parser::get_type(csq)))
+                        .collect::<Vec<String>>()
+            }
+        }; 
+        let tuple_2_res=match engine 
+        {
+            Engine::ST=>
+            {
+                results.iter()
+                        .map(|elem|elem.1.clone())
+                        .flatten()
+                        .filter(|csq|Constants::SUP_TYPE.contains(&text_parser::get_type(csq)))
+                        .collect::<Vec<String>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto =>
+            {
+                results.par_iter()
+                        .map(|elem|elem.1.clone())
+                        .flatten()
+                        .filter(|csq|Constants::SUP_TYPE.contains(&text_parser::get_type(csq)))
+                        .collect::<Vec<String>>()
+            }
+        };        
+        (tuple_1_res,tuple_2_res)
+    }
+    /// Similar to decode_back, however, the bitmask of each record is decoded into the indices of the consequences observed in each 
+    /// haplotype instead of the consequences, i.e. per haplotype the index of each record carrying at least one consequence along with the 
+    /// indices of its carried consequences, where num_consequences contains the number of consequences in the BCSQ field of each record. 
+    /// Decoding errors are handled as in decode_back, the indices are routed to their transcripts with a ConsequenceIndex. The number of 
+    /// unphased heterozygous calls the UnphasedPolicy of options was applied to, see UnphasedPolicy::is_applied, is returned along with the 
+    /// indices. 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::{VCFRecords,DecodingOptions}; 
+    /// use ppgg::data_structures::InternalRep::engines::Engine; 
+    /// let fields=vec!["0|1:6".to_string(),"0|0:0".to_string()]; 
+    /// let loci=["1:10".to_string(),"1:20".to_string()]; 
+    /// let (haplotype1,haplotype2,num_unphased_calls)=VCFRecords::decode_indices(&[3,1],&fields,&[Some(1),Some(1)],&[None,None],&loci,0,
+    ///     DecodingOptions::default(),Engine::ST); 
+    /// assert_eq!((haplotype1,haplotype2,num_unphased_calls),(vec![(0,vec![1])],vec![(0,vec![0])],0)); 
+    ///```
+    #[allow(clippy::too_many_arguments)]
+    pub fn decode_indices(num_consequences:&[usize],proband_fields:&[String],bcsq_indices:&[Option<usize>],decompositions:&[Option<AlleleDecomposition>],
+        loci:&[String],sample_index:usize,options:DecodingOptions,engine:Engine)->(RecordIndices,RecordIndices,u64)
+    {
+        let mut decoded=match engine
+        {
+            Engine::ST=>
+            {
+                num_consequences.iter().zip(proband_fields.iter()).zip(bcsq_indices.iter().zip(loci.iter())).zip(decompositions.iter())
+                    .map(|(((num_csq,field),(bcsq_index,locus)),decomposition)|
+                        VCFRecords::decode_record_indices(*num_csq,field,bcsq_index,decomposition,locus,sample_index,options.check_policy))
+                    .collect::<Vec<HaplotypeIndices>>()
+            },
+            Engine::MT | Engine::GPU | Engine::Auto=>
+            {
+                num_consequences.par_iter().zip(proband_fields.par_iter()).zip(bcsq_indices.par_iter().zip(loci.par_iter())).zip(decompositions.par_iter())
+                    .map(|(((num_csq,field),(bcsq_index,locus)),decomposition)|
+                        VCFRecords::decode_record_indices(*num_csq,field,bcsq_index,decomposition,locus,sample_index,options.check_policy))
+                    .collect::<Vec<HaplotypeIndices>>()
+            }
+        }; 
+        if decompositions.iter().flatten().any(|decomposition|decomposition.is_phased())
+        {
+            for (indices,resolved) in decoded.iter_mut().zip(AlleleDecomposition::resolve_phased_indices(decompositions, proband_fields, loci))
+            {
+                if let Some(resolved)=resolved
+                {
+                    *indices=resolved; 
+                }
+            }
+        }
+        let mut num_unphased_calls=0; 
+        for (indices,field) in decoded.iter_mut().zip(proband_fields.iter())
+        {
+            num_unphased_calls+=UnphasedPolicy::is_applied(field, indices) as u64; 
+            *indices=options.unphased_policy.apply(field, std::mem::take(indices)); 
+        }
+        let mut haplotype1=Vec::with_capacity(decoded.len()); 
+        let mut haplotype2=Vec::with_capacity(decoded.len()); 
+        for (record_index,(indices1,indices2)) in decoded.into_iter().enumerate()
+        {
+            if !indices1.is_empty()
+            {
+                haplotype1.push((record_index,indices1)); 
+            }
+            if !indices2.is_empty()
+            {
+                haplotype2.push((record_index,indices2)); 
+            }
+        }
+        (haplotype1,haplotype2,num_unphased_calls)
+    }
+    /// Decode the bitmask of a record for a proband into the indices of the consequences observed in each haplotype, see decode_indices,
+    /// the consequences of a decomposed multi-allelic record are decoded from the genotype of the proband unless the call is missing
+    fn decode_record_indices(num_consequences:usize, field:&String, bcsq_index:&Option<usize>, decomposition:&Option<AlleleDecomposition>, locus:&str, 
+        sample_index:usize, check_policy:CheckPolicy)->HaplotypeIndices
+    {
+        if let Some(indices)=decomposition.as_ref().and_then(|decomposition|decomposition.get_indices(field))
+        {
+            return indices
+        }
+        let bitmask=VCFRecords::get_bit_mask(field,bcsq_index); 
+        match BitMask::try_from_string(&bitmask).and_then(|mut mask|mask.get_checked_indices(num_consequences))
+        {
+            Ok(Some(indices))=>indices,
+            Ok(None)=>(Vec::new(),Vec::new()),
+            Err(err_msg)=>VCFRecords::report_decoding_error(&bitmask,locus,sample_index,err_msg,check_policy)
+        }
+    }
+    /// A helper associated function that extract the bitmask from a proband field using the index of the BCSQ field in the record FORMAT,
+    /// records without a BCSQ field in their FORMAT are treated as reference. 
+    fn get_bit_mask(field:&str,bcsq_index:&Option<usize>)->String
+    {
+        match bcsq_index
+        {
+            Some(index)=>text_parser::get_bit_mask_at(field,*index),
+            None=>Constants::DEF_CONSEQ.to_string()
+        }
+    }
+    /// A helper associated function that recieves as an input a CSQ string and a bit mask, results is: Tuple of size two, first element 
+    /// is a vector of strings, while number two is a vector of strings => 
+    /// each of them are the effects as a specifc site.
+    ///```
+    /// use std::path::Path; 
+    /// use ppgg_rust::readers;
+    /// use ppgg_rust::data_structures::vcf_ds::VCFRecords; 
+    /// let case_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_case.vcf"); 
+    /// let res_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_res.txt");
+    /// let records=readers::vcf_helpers::read_file(case_path).unwrap(); 
+    /// let resullts=readers::vcf_helpers::read_file(res_path)
+    ///                                    .unwrap()
+    ///                                    .iter()
+    ///                                    .map(|elem| elem.split("=").map(|elem| elem.to_string()).collect::<Vec<String>>().last().unwrap().clone())
+    ///                                    .collect::<Vec<String>>();
+    //// let vcf_records=VCFRecords::new(records);
+    ///```
+    pub fn extract_effects(csq:&String, bitmask:&mut String)->(Vec<String>,Vec<String>)
+    {
+        match VCFRecords::try_extract_effects(csq,bitmask)
+        {
+            Ok(res)=>res,
+            Err(err_msg)=>panic!("Decoding the bit-mask: {} failed, {}",bitmask,err_msg)
+        }
+    }
+    /// Similar to extract_effects, however, the bitmask is decoded with bounds checks and an error is returned if the bitmask is not a valid 
+    /// bitmask or if it references a consequence beyond the number of consequences in the CSQ string. 
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::VCFRecords; 
+    /// assert_eq!(VCFRecords::try_extract_effects("effect1,effect2","9$").unwrap(),(vec!["effect1".to_string()],vec!["effect2".to_string()])); 
+    /// assert!(VCFRecords::try_extract_effects("effect1","9$").is_err()); 
+    ///```
+    pub fn try_extract_effects(csq:&str, bitmask:&str)->Result<(Vec<String>,Vec<String>),String>
+    {
+        let splitted_csq=csq.split(',').collect::<Vec<&str>>();
+        let (haplotype1,haplotype2)=match BitMask::try_from_string(bitmask)?.get_checked_indices(splitted_csq.len())?
+        {
+            Some(vec)=>vec,
+            None=> return Ok((Vec::new(),Vec::new()))
+        };
+        let index_haplotype_1=haplotype1.into_iter()
+            .map(|idx|splitted_csq[idx].to_string())
+            .collect::<Vec<String>>();
+        let index_haplotype_2=haplotype2.into_iter()
+            .map(|idx|splitted_csq[idx].to_string())
+            .collect::<Vec<String>>();
+        Ok((index_haplotype_1,index_haplotype_2))
+    }
+    /// Decode the effects of a record for a proband, where decoding errors are reported along with the locus of the record and the index of 
+    /// the proband. With a strict CheckPolicy the execution stops at the first error, otherwise, the error is logged and the consequences
+    /// of the record are skipped for this proband. 
+    fn decode_effects(csq:&str, bitmask:&str, locus:&str, sample_index:usize, check_policy:CheckPolicy)->(Vec<String>,Vec<String>)
+    {
+        match VCFRecords::try_extract_effects(csq,bitmask)
+        {
+            Ok(res)=>res,
+            Err(err_msg)=>VCFRecords::report_decoding_error(bitmask,locus,sample_index,err_msg,check_policy)
+        }
+    }
+    /// Similar to decode_effects, however, the consequences of a decomposed multi-allelic record are decoded from the genotype of the proband
+    /// stored in its field unless the call is missing, see AlleleDecomposition::get_indices 
+    fn decode_record_effects(csq:&str, bitmask:&str, field:&str, decomposition:&Option<AlleleDecomposition>, locus:&str, sample_index:usize, 
+        check_policy:CheckPolicy)->(Vec<String>,Vec<String>)
+    {
+        match decomposition.as_ref().and_then(|decomposition|decomposition.get_effects(csq,field))
+        {
+            Some(effects)=>effects,
+            None=>VCFRecords::decode_effects(csq,bitmask,locus,sample_index,check_policy)
+        }
+    }
+    /// Report an error decoding the bit-mask of a record for a proband, the execution stops with a strict CheckPolicy, otherwise, the
+    /// error is logged and empty haplotypes are returned, i.e. the consequences of the record are skipped for this proband 
+    fn report_decoding_error<T:Default>(bitmask:&str, locus:&str, sample_index:usize, err_msg:String, check_policy:CheckPolicy)->(T,T)
+    {
+        let err_msg=format!("decoding the bit-mask: {} of the record at: {} for the sample at index: {} failed, {}",bitmask,locus,sample_index,err_msg); 
+        check_policy.report_failure(&format!("{}, skipping the consequences of this record ...",err_msg)); 
+        (T::default(),T::default())
+    }
+}
+/// a struct that acts as a wrapper for vector of string containing the name of probands in the VCF file
+#[derive(Debug,Clone)]
+pub struct Probands
+{
+    probands:Vec<String>,
+}
+impl Probands
+{
+    /// create a new instance using a vector of strings 
+    pub fn new(probands:Vec<String>)->Self
+    {
+        Probands{probands}
+    }
+    /// return the number of probands in the instance 
+    pub fn get_num_probands(&self)->usize
+    {
+        self.probands.len()
+    }
+    /// return the vector of strings containing the name of all probands 
+    pub fn get_probands(self)->Vec<String>
+    {
+        self.probands
+    }
+    /// Select a subset of the probands, the function returns a new instance containing the selected probands in the order of the header 
+    /// along with their zero-based column indices relative to the first proband column, which can be used with 
+    /// VCFRecords::get_selected_patient_fields. Duplicated names are selected once and an error is returned if a name is not in the file.
+    /// ## Example
+    ///```
+    /// use ppgg::data_structures::vcf_ds::Probands; 
+    /// let probands=Probands::new(vec!["S1".to_string(),"S2".to_string(),"S3".to_string()]); 
+    /// let (selected,columns)=probands.select(&["S3".to_string(),"S1".to_string()]).unwrap(); 
+    /// assert_eq!(columns,vec![0,2]); 
+    /// assert_eq!(selected.get_probands(),vec!["S1".to_string(),"S3".to_string()]); 
+    ///```
+    pub fn select(&self, samples:&[String])->Result<(Probands,Vec<usize>),String>
+    {
+        let column_index=self.probands.iter().enumerate()
+            .map(|(idx,proband)|(proband.as_str(),idx))
+            .collect::<HashMap<&str,usize>>(); 
+        let missing=samples.iter().filter(|sample|!column_index.contains_key(sample.as_str())).collect::<Vec<&String>>(); 
+        if !missing.is_empty()
+        {
+            return Err(format!("The following {} samples are not part of the VCF file: {:?}",missing.len(),missing))
+        }
+        let mut columns=samples.iter().map(|sample|column_index[sample.as_str()]).collect::<Vec<usize>>(); 
+        columns.sort_unstable(); 
+        columns.dedup(); 
+        let selected=columns.iter().map(|column|self.probands[*column].clone()).collect::<Vec<String>>(); 
+        Ok((Probands::new(selected),columns))
+    }
+}
+/// AltTranscript => altered transcript, a transcript that is used as an abstraction for 
+/// a collection of mutation occuring in a transcript.
+#[derive(Debug,Clone,Serialize,Deserialize)]
+pub struct AltTranscript
+{
+    pub name:String,
+    pub alts:Vec<Mutation>
+}
+ impl AltTranscript 
+ {
+    /// Create a new instance from a transcript name and a vector of Consequences string
+    /// ## Example 
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::AltTranscript; 
+    /// let name="ENST00000406869".to_string(); 
+    /// let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+    ///    ];
+    ///    let results=AltTranscript::new(name, mutations); 
+    ///    assert_eq!(results.name,"ENST00000406869".to_string());
+    ///    assert_eq!(results.get_alts().len(),4);
+    ///```    
+    pub fn new(name:String,alts:Vec<String>)->Self
+    {
+        
+        let alts=alts.iter()
+            .filter_map(|field|Mutation::new(text_parser::split_csq_string(&field)).ok())
+            .collect::<Vec<Mutation>>(); 
+        AltTranscript{name,alts}
+    }
+    /// create a new instance for a transcript name and a vector of mutations that will be filled later 
+    pub fn allocate(name:String)->Self
+    {
+        let alts:Vec<Mutation>=Vec::new(); 
+        AltTranscript{name,alts}
+    }
+    /// create a new instance for a transcript name and a vector of mutations that have an expected number of mutations 
+    pub fn with_capacity(name:String, expected_number:usize)->Self
+    {
+        let alts:Vec<Mutation>=Vec::with_capacity(expected_number); 
+        AltTranscript{name,alts}
+    }
+    /// create a new instance from a transcript name and a vector of already parsed mutations 
+    pub fn from_mutations(name:String, alts:Vec<Mutation>)->Self
+    {
+        AltTranscript{name,alts}
+    }
+    /// add an alteration i.e. a genetic mutation, to the current instance of mutations, 
+    /// ## Example 
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::AltTranscript; 
+    /// let name="ENST00000406869".to_string(); 
+    /// let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+    ///    ];
+    ///    let mut results=AltTranscript::new(name, mutations); 
+    ///    assert_eq!(results.get_alts().len(),4);
+    ///    results.add_altes("*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()); 
+    ///    assert_eq!(results.get_alts().len(),5);
+    ///```    
+    pub fn add_altes(&mut self, alt:String)
+    {
+        self.alts.push(Mutation::new(text_parser::split_csq_string(&alt)).unwrap());
+    }
+    /// return a reference to the instance vector of mutations 
+    pub fn get_alts(&self)->&Vec<Mutation>
+    {
+        &self.alts
+    }
+    /// add an alteration i.e. a genetic mutation, to the current instance of mutations, 
+    /// ## Example 
+    ///```
+    /// use ppgg_rust::data_structures::vcf_ds::AltTranscript; 
+    /// let name="ENST00000406869".to_string(); 
+    ///    let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+    ///            "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+    ///    ];
+    ///    let mut results=AltTranscript::new(name, mutations); 
+    ///    results.add_altes("*missense|MAD1L1|ENST00000406869|protein_coding|-|200L>1000H|1936821C>T".to_string()); 
+    ///    results.sort_alterations(); 
+    ///    assert_eq!(results.get_alts()[0].mut_info.ref_aa_position,0);
+    ///    assert_eq!(results.get_alts()[1].mut_info.ref_aa_position,9);
+    ///    assert_eq!(results.get_alts()[2].mut_info.ref_aa_position,99);
+    ///    assert_eq!(results.get_alts()[3].mut_info.ref_aa_position,199);
+    ///    assert_eq!(results.get_alts()[4].mut_info.ref_aa_position,999);
+    ///```    
+    pub fn sort_alterations(&mut self)
+    {
+        self.alts.sort_unstable_by(|mut1, mut2| mut1.mut_info.mut_aa_position.partial_cmp(&mut2.mut_info.mut_aa_position).unwrap()); 
+    }
+    /// Sort the mutations by their reference position and set the position of each mutation in the mutated sequence by shifting its 
+    /// reference position with the length changes of the preceding mutations, this is used for sources that do not provide the mutated 
+    /// positions, e.g. MAF files and VEP annotations 
+    pub fn assign_mutated_positions(&mut self)
+    {
+        let get_length=|aa:&MutatedString|match aa
+        {
+            MutatedString::Sequence(seq)=>seq.len() as i64,
+            MutatedString::EndSequence(seq)=>seq.len() as i64-1,
+            MutatedString::NotSeq=>0
+        };
+        self.alts.sort_by_key(|mutation|mutation.mut_info.ref_aa_position); 
+        let mut shift=0_i64;
+        for mutation in self.alts.iter_mut()
+        {
+            mutation.mut_info.mut_aa_position=(mutation.mut_info.ref_aa_position as i64+shift).max(0) as u32;
+            shift+=get_length(&mutation.mut_info.mut_aa)-get_length(&mutation.mut_info.ref_aa);
+        }
+    }
+
+    pub fn drop_replicate(mut self)->Self
+    {
+        // Sort the vector
+        self.sort_alterations();  
+        // Get unique starting position 
+        let unique_position= self.alts.iter().map(|mutation| mutation.mut_info.ref_aa_position).collect::<HashSet<_>>();
+        // If we have duplicates, then the set will be smaller than the vector as it contains only unique elements 
+        if unique_position.len() < self.alts.len()
+        {
+            // Now, we have a problem that we have two mutations at the same location in the reference, this can be classified into two small problems:
+            // 1. identical mutations at the same position, can be a calling error or parsing error, here the aim is to remove duplicates
+            self.alts.dedup_by(|mut1, mut2|
+                {
+                    mut1.mut_type == mut2.mut_type && 
+                    mut1.mut_info.ref_aa_position==mut2.mut_info.ref_aa_position && 
+                    mut1.mut_info.mut_aa_position==mut2.mut_info.mut_aa_position && 
+                    mut1.mut_info.ref_aa == mut2.mut_info.ref_aa && 
+                    mut1.mut_info.mut_aa == mut2.mut_info.mut_aa
+                }); 
+            
+            // 2. different mutations, here we are going to panic! because the user or the developer has to investigate the source of this problem 
+            if unique_position.len() != self.alts.len()
+            {
+                panic!("Encountered a logical error with analyzing mutations in transcript: {} with the following mutations: {:#?}",self.name, self.alts);
+            }
+            else
+            {
+                return self
+            }
+        }
+        self
+        // Otherwise we are good, we do not have replicates. 
+    }
+ }
+
+#[cfg(test)]
+mod test_alt_transcript
+{
+    use super::*; 
+    #[test]
+    fn test_new()
+    {
+        let name="ENST00000406869".to_string(); 
+        let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+        ];
+        let results=AltTranscript::new(name, mutations); 
+        assert_eq!(results.name,"ENST00000406869".to_string());
+        assert_eq!(results.get_alts().len(),4);
+    }
+    #[test]
+    fn test_add_to_alts()
+    {
+        let name="ENST00000406869".to_string(); 
+        let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+        ];
+        let mut results=AltTranscript::new(name, mutations); 
+        assert_eq!(results.get_alts().len(),4);
+        results.add_altes("*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()); 
+        assert_eq!(results.get_alts().len(),5);
+    }
+    #[test]
+    fn test_sortting_function()
+    {
+        let name="ENST00000406869".to_string(); 
+        let mutations=vec!["*missense|MAD1L1|ENST00000406869|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|ENST00000406869|protein_coding|-|1000R>1000H|1936821C>T".to_string()
+        ];
+        let mut results=AltTranscript::new(name, mutations); 
+        results.add_altes("*missense|MAD1L1|ENST00000406869|protein_coding|-|200L>1000H|1936821C>T".to_string()); 
+        results.sort_alterations(); 
+        assert_eq!(results.get_alts()[0].mut_info.ref_aa_position,0);
+        assert_eq!(results.get_alts()[1].mut_info.ref_aa_position,9);
+        assert_eq!(results.get_alts()[2].mut_info.ref_aa_position,99);
+        assert_eq!(results.get_alts()[3].mut_info.ref_aa_position,199);
+        assert_eq!(results.get_alts()[4].mut_info.ref_aa_position,999);
+    }
+    
+}
+#[cfg(test)]
+mod test_vcf
+{
+    use super::*; 
+    use crate::readers; 
+    use std::path::Path;
+    #[test]
+    fn test_get_consequence_file()
+    {
+        // Prepare input test and results case 
+        //------------------------------------
+        let case_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_case.vcf"); 
+        let res_path=Path::new("/Users/heshamelabd/projects/test_data/test_f1_mod_test_vcf_res.txt");
+        let records=readers::vcf_helpers::read_file(case_path,Engine::MT).unwrap(); 
+        let resullts=readers::vcf_helpers::read_file(res_path,Engine::MT)
+                                        .unwrap()
+                                        .iter()
+                                        .map(|elem| elem.split("=").map(|elem| elem.to_string()).collect::<Vec<String>>().last().unwrap().clone())
+                                        .collect::<Vec<String>>();
+        // Parse the input and check the correctness 
+        //------------------------------------------
+        let vcf_records=VCFRecords::new(records);
+        assert_eq!(resullts,vcf_records.get_consequences_vector(Engine::MT));
+    }
+    #[test]
+    fn test_get_patient_fields1()
+    {
+        let test_case1=vec![
+            "1\t1\t1\t1\t1\t1\t1\t1\t1\tField1\tField1.1\tField1.2\tField1.3\tField1.4\tField1.5\tField1.6\tField1.7".to_string(),
+            "1\t1\t1\t1\t1\t1\t1\t1\t1\tField2\tField2.1\tField2.2\tField2.3\tField2.4\tField2.5\tField2.6\tField2.7".to_string(),
+            "1\t1\t1\t1\t1\t1\t1\t1\t1\tField3\tField3.1\tField3.2\tField3.3\tField3.4\tField3.5\tField3.6\tField3.7".to_string(),
+            "1\t1\t1\t1\t1\t1\t1\t1\t1\tField4\tField4.1\tField4.2\tField4.3\tField4.4\tField4.5\tField4.6\tField4.7".to_string(),
+            "1\t1\t1\t1\t1\t1\t1\t1\t1\tField5\tField5.1\tField5.2\tField5.3\tField5.4\tField5.5\tField5.6\tField5.7".to_string(),
+            ];
+        let test_result1=vec![
+                "Field1", "Field2", "Field3", "Field4", "Field5"].
+                iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+        let test_result2=vec![
+                "Field1.1", "Field2.1", "Field3.1", "Field4.1", "Field5.1"].
+                iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+        let test_result3=vec![
+                    "Field1.2", "Field2.2", "Field3.2", "Field4.2", "Field5.2"].
+                    iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+        let test_result4=vec![
+                        "Field1.3", "Field2.3", "Field3.3", "Field4.3", "Field5.3"].
+                        iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+        let test_result5=vec![
+                            "Field1.4", "Field2.4", "Field3.4", "Field4.4", "Field5.4"].
+                            iter().map(|elem |elem.to_string()).collect::<Vec<String>>();
+        let mut vcf_records=VCFRecords::new(test_case1);
+        assert_eq!(test_result1,vcf_records.get_patient_fields(8,Engine::MT)[0]);
+        assert_eq!(test_result2,vcf_records.get_patient_fields(8,Engine::MT)[1]);
+        assert_eq!(test_result3,vcf_records.get_patient_fields(8,Engine::MT)[2]);
+        assert_eq!(test_result4,vcf_records.get_patient_fields(8,Engine::MT)[3]);
+        assert_eq!(test_result5,vcf_records.get_patient_fields(8,Engine::MT)[4]);
+    }
+    #[test]
+    fn test_extract_effects1()
+    {
+        let mut test_case="effect1,effect2,effect3,effect4,effect5,effect6".to_string(); 
+        let mut bit_mask="1".to_string();
+        let csq_map=VCFRecords::extract_effects(&mut test_case,&mut bit_mask);
+        assert_eq!(csq_map.0[0],"effect1");
+    }
+    #[test]
+    fn test_extract_effects2()
+    {
+        let mut test_case="effect1,effect2,effect3,effect4,effect5,effect6".to_string(); 
+        let mut bit_mask="3".to_string();
+        let csq_map=VCFRecords::extract_effects(&mut test_case,&mut bit_mask);
+        assert_eq!(csq_map.0[0],"effect1");
+        assert_eq!(csq_map.1[0],"effect1");
+    }
+    #[test]
+    fn test_try_extract_effects()
+    {
+        // 1024 references the consequence at index 5 of the first haplotype 
+        assert_eq!(VCFRecords::try_extract_effects("effect1,effect2,effect3,effect4,effect5,effect6","1024$").unwrap().0,vec!["effect6".to_string()]);
+        let err_msg=VCFRecords::try_extract_effects("effect1,effect2","1024$").unwrap_err();
+        assert!(err_msg.contains("index 5"));
+        assert!(VCFRecords::try_extract_effects("effect1,effect2","x$").is_err());
+    }
+    #[test]
+    fn test_decode_out_of_range_bitmask()
+    {
+        // the bitmask of the first record references a second consequence, which does not exist, the record is skipped for the sample  
+        let test_case=vec![
+            "1\t100\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|10K>10R|100A>T\tGT:BCSQ\t0|1:4".to_string(),
+            "1\t200\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|20K>20R|200A>T\tGT:BCSQ\t1|0:1".to_string(),
+        ];
+        let mut records=VCFRecords::new(test_case); 
+        let results=records.get_csq_per_patient(1,Engine::MT); 
+        assert_eq!(results[0].0,vec!["missense|G1|T1|protein_coding|+|20K>20R|200A>T".to_string()]);
+        assert!(results[0].1.is_empty());
+    }
+    #[test]
+    fn test_format_drift()
+    {
+        let test_case=vec![
+            "1\t100\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|10K>10R|100A>T\tGT:BCSQ\t0|1:2".to_string(),
+            "1\t200\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|20K>20R|200A>T\tGT:BCSQ:DP\t1|0:1:30".to_string(),
+            "1\t300\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|30K>30R|300A>T\tGT:DP\t1|1:3".to_string(),
+        ];
+        let mut records=VCFRecords::new(test_case); 
+        let drift=records.get_format_drift(); 
+        assert_eq!(drift.reference_layout,"GT:BCSQ");
+        assert_eq!(drift.drifted_records.len(),2);
+        assert_eq!(drift.drifted_records[0].0,"1:200");
+        // the bitmask of the second record is read from the BCSQ field and not from the last field, while the third record has no bitmask  
+        let results=records.get_csq_per_patient(1,Engine::ST); 
+        assert_eq!(results[0].0,vec!["missense|G1|T1|protein_coding|+|20K>20R|200A>T".to_string()]);
+        assert_eq!(results[0].1,vec!["missense|G1|T1|protein_coding|+|10K>10R|100A>T".to_string()]);
+    }
+    #[test]
+    fn test_multi_allelic_decomposition()
+    {
+        // the consequence of the second ALT allele is written first and the bit-mask of the proband is wrong, i.e. it assigns the 
+        // consequence of the second allele to the first haplotype 
+        let test_case=vec![
+            "1\t100\t.\tAC\tGC,A\t.\tPASS\tBCSQ=frameshift|G1|T1|protein_coding|+|10K|100AC>A,missense|G1|T1|protein_coding|+|10K>10R|100A>G\tGT:BCSQ\t1|2:1".to_string(),
+            "1\t200\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|20K>20R|200A>T\tGT:BCSQ\t0|1:2".to_string(),
+        ];
+        let mut records=VCFRecords::new(test_case); 
+        let decompositions=records.get_allele_decompositions(); 
+        assert_eq!(decompositions[0].as_ref().unwrap().get_alleles(),&[Some(2),Some(1)]); 
+        assert_eq!(decompositions[1],None); 
+        let results=records.get_csq_per_patient(1,Engine::ST); 
+        assert_eq!(results[0].0,vec!["missense|G1|T1|protein_coding|+|10K>10R|100A>G".to_string()]);
+        assert_eq!(results[0].1,vec!["frameshift|G1|T1|protein_coding|+|10K|100AC>A".to_string(),
+            "missense|G1|T1|protein_coding|+|20K>20R|200A>T".to_string()]);
+    }
+    #[test]
+    fn test_phased_haplotypes()
+    {
+        // the records have no BCSQ field in their FORMAT, hence, the haplotypes are reconstructed from the phased genotypes only 
+        let test_case=vec![
+            "1\t100\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|10K>10R|100A>T,intron|G2|T2|protein_coding\tGT\t0|1\t0/1".to_string(),
+            "1\t200\t.\tA\tT,G\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|20K>20R|200A>T,missense|G1|T1|protein_coding|+|20K>20Q|200A>G\tGT\t2|1\t1/1".to_string(),
+        ];
+        let records=VCFRecords::new(test_case); 
+        let decompositions=records.get_records().iter().map(|record|AlleleDecomposition::from_phased_record(record)).collect::<Vec<_>>(); 
+        assert_eq!(decompositions[0].as_ref().unwrap().get_alleles(),&[Some(1),None]); 
+        let consequences=records.get_consequences_vector(Engine::ST); 
+        let (bcsq_indices,loci)=(records.get_bcsq_indices(),records.get_loci()); 
+        let fields=|column:usize|records.get_records().iter().map(|record|record.split('\t').nth(9+column).unwrap().to_string()).collect::<Vec<String>>(); 
+        let (haplotype1,haplotype2)=VCFRecords::decode_back(&consequences,&fields(0),&bcsq_indices,&decompositions,&loci,0,DecodingOptions::default(),Engine::ST); 
+        assert_eq!(haplotype1,vec!["missense|G1|T1|protein_coding|+|20K>20Q|200A>G".to_string()]); 
+        assert_eq!(haplotype2,vec!["missense|G1|T1|protein_coding|+|10K>10R|100A>T".to_string(),"missense|G1|T1|protein_coding|+|20K>20R|200A>T".to_string()]); 
+        // the unphased heterozygous call is decoded from the missing bit-mask, i.e. as reference, while the homozygous call is unambiguous 
+        let (haplotype1,haplotype2)=VCFRecords::decode_back(&consequences,&fields(1),&bcsq_indices,&decompositions,&loci,1,DecodingOptions::default(),Engine::ST); 
+        assert_eq!((haplotype1.len(),haplotype2.len()),(1,1)); 
+        assert_eq!(haplotype1[0],"missense|G1|T1|protein_coding|+|20K>20R|200A>T"); 
+        // the second deletion overlaps the first one, hence, it is dropped from the first haplotype that carries both, while the compound 
+        // frameshift replaces the missense of the third record in the first haplotype and is not carried by the second haplotype 
+        let records=["1\t300\t.\tATCCTAC\tA\t.\tPASS\tBCSQ=inframe_deletion|G1|T1|protein_coding|+|30DPT>30D|300ATCCTAC>A\tGT\t1|0",
+            "1\t301\t.\tTCCTACA\tT\t.\tPASS\tBCSQ=inframe_deletion|G1|T1|protein_coding|+|31PT>31P|301TCCTACA>T\tGT\t1|1",
+            "1\t400\t.\tTC\tT\t.\tPASS\tBCSQ=frameshift|G2|T2|protein_coding|+|5KLV*>5KR*|400TC>T+420C>G,frameshift|G2|T2|protein_coding|+|5KLV*>5KRG*|400TC>T\tGT\t1|1",
+            "1\t420\t.\tC\tG\t.\tPASS\tBCSQ=@400,missense|G2|T2|protein_coding|+|7V>7A|420C>G\tGT\t1|0"].iter().map(|record|record.to_string()).collect::<Vec<String>>(); 
+        let decompositions=records.iter().map(|record|AlleleDecomposition::from_phased_record(record)).collect::<Vec<_>>(); 
+        assert_eq!(decompositions[0].as_ref().unwrap().get_span(),(300,306)); 
+        let fields=records.iter().map(|record|record.rsplit('\t').next().unwrap().to_string()).collect::<Vec<String>>(); 
+        let loci=VCFRecords::new(records).get_loci(); 
+        let resolved=AlleleDecomposition::resolve_phased_indices(&decompositions, &fields, &loci); 
+        assert_eq!(resolved,vec![Some((vec![0],vec![])),Some((vec![],vec![0])),Some((vec![0],vec![1])),Some((vec![],vec![]))]); 
+    }
+    #[test]
+    fn test_unphased_policy()
+    {
+        assert_eq!("Collapse".parse::<UnphasedPolicy>(),Ok(UnphasedPolicy::Collapse)); 
+        assert!("phase".parse::<UnphasedPolicy>().is_err()); 
+        assert_eq!(UnphasedPolicy::default(),UnphasedPolicy::Assume); 
+        let decoded=(vec!["missense|G1|T1|protein_coding|+|10K>10R|100A>T".to_string()],vec!["missense|G1|T1|protein_coding|+|20K>20R|200A>T".to_string()]); 
+        // the decoded consequences are kept as they are under the default, while the call is counted as an unphased call 
+        assert!(UnphasedPolicy::is_applied("0/1:6",&decoded)); 
+        assert!(!UnphasedPolicy::is_applied("0/1:0",&(Vec::<usize>::new(),Vec::new()))); 
+        assert_eq!(UnphasedPolicy::Assume.apply("0/1:6",decoded.clone()),decoded.clone()); 
+        // the shared indices are carried once 
+        assert_eq!(UnphasedPolicy::Collapse.apply("1/2:7",(vec![0,1],vec![1,2])),(vec![0,1,2],vec![0,1,2])); 
+        let (haplotype1,haplotype2)=UnphasedPolicy::Both.apply("0/1:6",decoded.clone()); 
+        assert_eq!((haplotype1.len(),haplotype2.len()),(2,0)); 
+        assert_eq!(UnphasedPolicy::Skip.apply("1/0:6",decoded.clone()),(vec![],vec![])); 
+        // phased, homozygous and partially missing calls are not changed 
+        for field in ["0|1:6","1/1:6","./1:6"].iter()
+        {
+            assert_eq!(UnphasedPolicy::Skip.apply(field,decoded.clone()),decoded.clone()); 
+        }
+    }
+}
+
+
+
+
+
+
+
+
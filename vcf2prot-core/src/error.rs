@@ -0,0 +1,81 @@
+/// The module defines the error type of the crate, i.e. the failures of translating the mutations of a transcript into instructions and
+/// tasks and of executing the tasks, which are returned instead of aborting the process, so a library caller can skip the transcript, or
+/// the haplotype, that failed and continue with the rest of the cohort. The errors are converted into strings where the crate reports its
+/// errors as strings, hence, they can be propagated with ? from the functions returning Result<_,String>.
+use std::fmt;
+
+/// ## Summary
+/// The errors of the crate, each variant carries the context needed to locate the failing transcript or task
+/// ## Example
+///```rust
+/// use ppgg::error::PpggError;
+/// let error=PpggError::InvalidSpan{transcript:"T1".to_string(),start:472,end:470};
+/// assert_eq!(error.to_string(),"Invalid span in transcript: T1, the span starts at: 472 but ends at: 470");
+/// let err_msg:String=error.into();
+/// assert!(err_msg.starts_with("Invalid span"));
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum PpggError
+{
+    /// The transcript is not part of the reference sequences
+    MissingTranscript(String),
+    /// None of the mutations of the transcript is supported
+    NoSupportedMutations{transcript:String,num_mutations:usize},
+    /// Two instructions of the transcript start at the same position or overlap
+    OverlappingMutations{transcript:String},
+    /// The amino acids of a mutation can not be interpreted as an instruction, e.g. a missense without an alternative amino acid
+    UninterpretableMutation{transcript:String,mutation:String},
+    /// The instruction code has no translation into tasks
+    UnsupportedInstruction{transcript:String,code:char},
+    /// The instruction must be the last one of the transcript, e.g. a stop-gained, however, it is followed by other instructions
+    MisplacedInstruction{transcript:String,code:char},
+    /// The span between two consecutive instructions ends before it starts
+    InvalidSpan{transcript:String,start:usize,end:usize},
+    /// The instructions of the transcript remove more residues than the reference has
+    NegativeSequenceLength{transcript:String,ref_len:usize,size_change:i64},
+    /// The task at task_index does not start where the previous task ends in the results array
+    InconsistentTasks{transcript:Option<String>,task_index:usize,expected_start:usize,actual_start:usize},
+    /// The tasks of the transcript do not fill the results array of the transcript
+    SizeMismatch{transcript:String,expected:usize,actual:usize},
+    /// The task reads or writes beyond the end of one of its arrays
+    TaskOutOfBounds{start:usize,end:usize,len:usize},
+    /// The engine is not supported by the build, e.g. the GPU engine without the cuda feature
+    UnsupportedEngine(String),
+    /// The input file can not be read or parsed, e.g. a VCF file without a header
+    ParseFailure(String)
+}
+impl fmt::Display for PpggError
+{
+    fn fmt(&self, f:&mut fmt::Formatter)->fmt::Result
+    {
+        match self
+        {
+            PpggError::MissingTranscript(transcript)=>write!(f,"The provided transcript name: {} is not in the reference sequence",transcript),
+            PpggError::NoSupportedMutations{transcript,num_mutations}=>write!(f,"The provided transcript name: {} has {} mutations none of them is supported, skipping this transcript",
+                transcript,num_mutations),
+            PpggError::OverlappingMutations{transcript}=>write!(f,"Encountered two overlapping mutations or two mutations at the same position in transcript: {}",transcript),
+            PpggError::UninterpretableMutation{transcript,mutation}=>write!(f,"Interpreting the mutation: {} of transcript: {} failed",mutation,transcript),
+            PpggError::UnsupportedInstruction{transcript,code}=>write!(f,"The instruction code: {} of transcript: {} is not supported",code,transcript),
+            PpggError::MisplacedInstruction{transcript,code}=>write!(f,"The instruction: {} must be the last mutation in transcript: {}, however, it is followed by other mutations",
+                code,transcript),
+            PpggError::InvalidSpan{transcript,start,end}=>write!(f,"Invalid span in transcript: {}, the span starts at: {} but ends at: {}",transcript,start,end),
+            PpggError::NegativeSequenceLength{transcript,ref_len,size_change}=>write!(f,"The instructions of transcript: {} change the length of its reference of {} residues by {}",
+                transcript,ref_len,size_change),
+            PpggError::InconsistentTasks{transcript,task_index,expected_start,actual_start}=>write!(f,"The task: {} of transcript: {} starts at: {} in the results array, while the previous task ends at: {}",
+                task_index,transcript.as_deref().unwrap_or("unknown"),actual_start,expected_start),
+            PpggError::SizeMismatch{transcript,expected,actual}=>write!(f,"In transcript: {}, size mismatched between the expected size: {} and actual size: {}",
+                transcript,expected,actual),
+            PpggError::TaskOutOfBounds{start,end,len}=>write!(f,"The task accesses the range: {}..{} of an array of {} residues",start,end,len),
+            PpggError::UnsupportedEngine(engine)=>write!(f,"The engine: {} is not supported by this build",engine),
+            PpggError::ParseFailure(err_msg)=>write!(f,"Parsing the input failed: {}",err_msg)
+        }
+    }
+}
+impl std::error::Error for PpggError {}
+impl From<PpggError> for String
+{
+    fn from(error:PpggError)->String
+    {
+        error.to_string()
+    }
+}
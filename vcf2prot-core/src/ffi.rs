@@ -0,0 +1,561 @@
+/// The module provides the C ABI of the pipeline, i.e. C and C++ pipelines pass the path of a VCF file, the path of the reference proteome
+/// and the options, see Vcf2ProtOptions, or an options handle carrying the other settings as well, see Vcf2ProtOptionsHandle, and receive the generated sequences either through a callback, see vcf2prot_generate, or as a result
+/// buffer that is released by the caller, see vcf2prot_generate_to_buffer. The functions are declared in include/vcf2prot.h, they never
+/// unwind into the caller, a failure is returned as an error code and its message is available through vcf2prot_last_error.
+use std::cell::RefCell;
+use std::ffi::{CStr,CString};
+use std::os::raw::{c_char,c_int,c_void};
+use std::panic::{self,AssertUnwindSafe};
+use std::path::Path;
+use std::ptr;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::functions::contigs::ContigPolicy;
+use crate::functions::missing_genotypes::MissingGenotypePolicy;
+use crate::parts::api::{self,Options};
+use crate::readers;
+
+/// the call succeeded
+pub const VCF2PROT_OK:c_int=0;
+/// a pointer is null, a path is not valid UTF-8 or an option is out of range
+pub const VCF2PROT_INVALID_ARGUMENT:c_int=1;
+/// the VCF or the fasta file could not be read or parsed
+pub const VCF2PROT_PIPELINE_ERROR:c_int=2;
+/// the callback returned a non-zero value, hence, the remaining sequences were not passed to it
+pub const VCF2PROT_ABORTED:c_int=3;
+/// the pipeline panicked, the panic was caught before it reached the caller
+pub const VCF2PROT_PANIC:c_int=4;
+
+thread_local!
+{
+    /// the message of the last failed call on the current thread, see vcf2prot_last_error
+    static LAST_ERROR:RefCell<Option<CString>>=const { RefCell::new(None) };
+}
+
+/// ## Summary
+/// The options of the pipeline as passed by C callers, each field is an integer code:
+/// 1. engine, 0 for the single-threaded engine and 1 for the multi-threaded engine.
+/// 2. contig_policy, 0 skips the records of the non-primary contigs and 1 reads every record, see ContigPolicy.
+/// 3. missing_genotype_policy, 0 for ref, 1 for skip-record, 2 for major-allele and 3 for flag, see MissingGenotypePolicy.
+/// 4. include_reference, if non-zero, the unaltered transcripts of each haplotype are returned with their reference sequence as well.
+#[repr(C)]
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub struct Vcf2ProtOptions
+{
+    pub engine:u8,
+    pub contig_policy:u8,
+    pub missing_genotype_policy:u8,
+    pub include_reference:u8
+}
+impl Vcf2ProtOptions
+{
+    /// translate the integer codes into the options of api::generate, an error is returned if a code is out of range
+    fn to_options(self)->Result<Options,String>
+    {
+        let engine=match self.engine
+        {
+            0=>Engine::ST,
+            1=>Engine::MT,
+            code=>return Err(format!("{} is not a supported engine code, the supported codes are 0 for st and 1 for mt",code))
+        };
+        let contig_policy=match self.contig_policy
+        {
+            0=>ContigPolicy::SkipNonPrimary,
+            1=>ContigPolicy::All,
+            code=>return Err(format!("{} is not a supported contig policy code, the supported codes are 0 for skip-non-primary and 1 for all",code))
+        };
+        let missing_genotype_policy=match self.missing_genotype_policy
+        {
+            0=>MissingGenotypePolicy::Reference,
+            1=>MissingGenotypePolicy::SkipRecord,
+            2=>MissingGenotypePolicy::MajorAllele,
+            3=>MissingGenotypePolicy::Flag,
+            code=>return Err(format!("{} is not a supported missing genotype policy code, the supported codes are 0 for ref, 1 for skip-record, \
+                2 for major-allele and 3 for flag",code))
+        };
+        Ok(Options{engine,contig_policy,missing_genotype_policy,..Options::default()})
+    }
+}
+
+/// ## Summary
+/// The options of the pipeline as an opaque handle, i.e. the options of api::generate along with whether the unaltered transcripts are
+/// returned, which are created from the integer codes, see vcf2prot_options_new, and extended with named settings, see
+/// vcf2prot_options_set. Each call is executed with the options of its handle, hence, threads calling the pipeline with different handles
+/// do not affect each other.
+#[derive(Debug,Clone)]
+pub struct Vcf2ProtOptionsHandle
+{
+    options:Options,
+    include_reference:bool
+}
+impl Vcf2ProtOptionsHandle
+{
+    /// create a handle from the integer codes, an error is returned if a code is out of range
+    fn from_codes(codes:Vcf2ProtOptions)->Result<Self,String>
+    {
+        Ok(Vcf2ProtOptionsHandle{options:codes.to_options()?,include_reference:codes.include_reference!=0})
+    }
+    /// set a named setting, see Options::set
+    fn set(&mut self, name:&str, value:&str)->Result<(),String>
+    {
+        self.options.set(name, value)
+    }
+}
+
+/// ## Summary
+/// A generated sequence as seen by C callers, i.e. the sample, the haplotype, 1 or 2, the transcript and the sequence, which are
+/// NUL-terminated, along with the length of the sequence and whether it is altered or the reference sequence of an unaltered transcript
+#[repr(C)]
+#[derive(Debug,Clone,Copy)]
+pub struct Vcf2ProtRecord
+{
+    pub proband:*const c_char,
+    pub haplotype:u8,
+    pub transcript:*const c_char,
+    pub sequence:*const c_char,
+    pub sequence_len:usize,
+    pub is_altered:u8
+}
+
+/// ## Summary
+/// The callback receiving the generated sequences, the record is only valid during the call, a non-zero return value stops the generation
+pub type Vcf2ProtCallback=Option<unsafe extern "C" fn(record:*const Vcf2ProtRecord, user_data:*mut c_void)->c_int>;
+
+/// ## Summary
+/// The sequences generated by vcf2prot_generate_to_buffer, the strings are owned by the buffer, hence, the records are valid until the
+/// buffer is released with vcf2prot_result_free
+pub struct Vcf2ProtResult
+{
+    strings:Vec<CString>,
+    records:Vec<Vcf2ProtRecord>
+}
+
+/// the error code of a failed call along with its message
+type FfiError=(c_int,String);
+
+/// record the message of a failed call for vcf2prot_last_error and return its code
+fn fail(error:FfiError)->c_int
+{
+    let (code,message)=error;
+    let message=CString::new(message.replace('\0',"")).unwrap_or_default();
+    LAST_ERROR.with(|last_error|*last_error.borrow_mut()=Some(message));
+    code
+}
+
+/// convert a NUL-terminated path into a string, an error is returned if the pointer is null or the path is not valid UTF-8
+/// ## Safety
+/// the pointer must be null or point to a NUL-terminated string
+unsafe fn to_path(path:*const c_char, name:&str)->Result<String,FfiError>
+{
+    if path.is_null()
+    {
+        return Err((VCF2PROT_INVALID_ARGUMENT,format!("The path of the {} is null",name)))
+    }
+    match CStr::from_ptr(path).to_str()
+    {
+        Ok(path)=>Ok(path.to_string()),
+        Err(err_msg)=>Err((VCF2PROT_INVALID_ARGUMENT,format!("The path of the {} is not valid UTF-8: {}",name,err_msg)))
+    }
+}
+
+/// convert a string into a NUL-terminated string
+fn to_c_string(value:&str)->Result<CString,FfiError>
+{
+    CString::new(value).map_err(|err_msg|(VCF2PROT_PIPELINE_ERROR,format!("The string: {} contains a NUL byte: {}",value,err_msg)))
+}
+
+/// ## Summary
+/// Run the pipeline and pass each generated sequence to emit, i.e. the sample, the haplotype, the transcript, the sequence and whether it
+/// is altered, the sequences of each sample are passed haplotype by haplotype, the altered transcripts of a haplotype in the order of its
+/// sequence tape followed by its unaltered transcripts in the order of their names if the reference is included
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings
+unsafe fn generate_records<F>(path2vcf:*const c_char, path2fasta:*const c_char, handle:Result<Vcf2ProtOptionsHandle,FfiError>, mut emit:F)->Result<(),FfiError>
+where F:FnMut(&str,u8,&str,&str,bool)->Result<(),FfiError>
+{
+    let path2vcf=to_path(path2vcf, "VCF file")?;
+    let path2fasta=to_path(path2fasta, "reference fasta file")?;
+    let Vcf2ProtOptionsHandle{options,include_reference}=handle?;
+    let reference=readers::read_fasta_file(Path::new(&path2fasta), options.engine.clone()).map_err(|err_msg|(VCF2PROT_PIPELINE_ERROR,err_msg))?;
+    let vcf=std::fs::File::open(&path2vcf).map_err(|err_msg|(VCF2PROT_PIPELINE_ERROR,format!("Opening the VCF file: {} failed with the following error: {}",path2vcf,err_msg)))?;
+    let proteomes=api::generate(vcf, &reference, options).map_err(|err|(VCF2PROT_PIPELINE_ERROR,err.to_string()))?;
+    for genome in proteomes.genomes.iter()
+    {
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+        for (haplotype,seq_tape) in [(1,seq_tape1),(2,seq_tape2)]
+        {
+            for transcript in seq_tape.get_ordered_keys()
+            {
+                emit(genome.get_proband_name(), haplotype, transcript, seq_tape.get_seq(transcript).unwrap(), true)?;
+            }
+            if include_reference
+            {
+                let mut unaltered=reference.get_records().iter().filter(|(transcript,_)|!seq_tape.get_annotation().contains_key(*transcript)).collect::<Vec<_>>();
+                unaltered.sort_unstable_by_key(|(transcript,_)|*transcript);
+                for (transcript,sequence) in unaltered
+                {
+                    emit(genome.get_proband_name(), haplotype, transcript, sequence, false)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// run the provided function and convert a panic into VCF2PROT_PANIC
+fn catch_panic<F:FnOnce()->Result<(),FfiError>>(function:F)->Result<(),FfiError>
+{
+    match panic::catch_unwind(AssertUnwindSafe(function))
+    {
+        Ok(results)=>results,
+        Err(payload)=>
+        {
+            let message=match (payload.downcast_ref::<&str>(),payload.downcast_ref::<String>())
+            {
+                (Some(message),_)=>message.to_string(),
+                (None,Some(message))=>message.clone(),
+                (None,None)=>"unknown panic payload".to_string()
+            };
+            Err((VCF2PROT_PANIC,format!("The pipeline panicked with the following message: {}",message)))
+        }
+    }
+}
+
+/// the handle of the integer codes, the default options are used if the pointer is null
+/// ## Safety
+/// the options must be null or point to valid options
+unsafe fn get_handle(options:*const Vcf2ProtOptions)->Result<Vcf2ProtOptionsHandle,FfiError>
+{
+    let codes=options.as_ref().copied().unwrap_or_default();
+    Vcf2ProtOptionsHandle::from_codes(codes).map_err(|err_msg|(VCF2PROT_INVALID_ARGUMENT,err_msg))
+}
+
+/// a copy of a handle, the default options are used if the pointer is null
+/// ## Safety
+/// the handle must be null or returned by vcf2prot_options_new and not yet released
+unsafe fn copy_handle(handle:*const Vcf2ProtOptionsHandle)->Result<Vcf2ProtOptionsHandle,FfiError>
+{
+    match handle.as_ref()
+    {
+        Some(handle)=>Ok(handle.clone()),
+        None=>get_handle(ptr::null())
+    }
+}
+
+/// ## Summary
+/// Fill the options with their defaults, i.e. the single-threaded engine, the records of the non-primary contigs are skipped, the missing
+/// genotype calls are treated as the reference and only the altered transcripts are returned
+/// ## Safety
+/// the pointer must be null or point to writable options
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_options_default(options:*mut Vcf2ProtOptions)
+{
+    if let Some(options)=options.as_mut()
+    {
+        *options=Vcf2ProtOptions::default();
+    }
+}
+
+/// ## Summary
+/// Create an options handle from the integer codes of the options, where the default options are used if the options are null, null is
+/// returned if a code is out of range, in which case the message is returned by vcf2prot_last_error
+/// ## Safety
+/// the options must be null or point to valid options, the returned handle must be released with vcf2prot_options_free
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_options_new(options:*const Vcf2ProtOptions)->*mut Vcf2ProtOptionsHandle
+{
+    match get_handle(options)
+    {
+        Ok(handle)=>Box::into_raw(Box::new(handle)),
+        Err(error)=>
+        {
+            fail(error);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// ## Summary
+/// Set a named setting of an options handle, i.e. overlap_policy, checks, placeholder_residue, strict_placeholder_check, unphased,
+/// multi_allelic, haplotype_source or silent_variants, whose values are the ones of the flags of the command line, samples, a comma
+/// separated list of sample names, or transcripts, a comma separated list of the transcripts to personalize. Returns VCF2PROT_OK or
+/// VCF2PROT_INVALID_ARGUMENT, whose message is returned by vcf2prot_last_error.
+/// ## Safety
+/// the handle must be null or returned by vcf2prot_options_new and not yet released, name and value must be null or point to
+/// NUL-terminated strings
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_options_set(handle:*mut Vcf2ProtOptionsHandle, name:*const c_char, value:*const c_char)->c_int
+{
+    let handle=match handle.as_mut()
+    {
+        Some(handle)=>handle,
+        None=>return fail((VCF2PROT_INVALID_ARGUMENT,"The options handle is null".to_string()))
+    };
+    let results=to_path(name, "setting name").and_then(|name|Ok((name,to_path(value, "setting value")?)))
+        .and_then(|(name,value)|handle.set(&name, &value).map_err(|err_msg|(VCF2PROT_INVALID_ARGUMENT,err_msg)));
+    match results
+    {
+        Ok(())=>VCF2PROT_OK,
+        Err(error)=>fail(error)
+    }
+}
+
+/// ## Summary
+/// Release an options handle, a null handle is ignored
+/// ## Safety
+/// the handle must be null or returned by vcf2prot_options_new and not yet released
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_options_free(handle:*mut Vcf2ProtOptionsHandle)
+{
+    if !handle.is_null()
+    {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// ## Summary
+/// Generate the personalized sequences of the samples of a VCF file, plain or gzipped, using the reference proteome and the options, where
+/// the default options are used if the options are null, and pass each sequence to the callback along with user_data. Transcripts that
+/// can not be personalized are skipped. Returns VCF2PROT_OK or an error code, whose message is returned by vcf2prot_last_error.
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings, the options must be null or point to valid options and the callback must be
+/// safe to call with user_data
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_generate(path2vcf:*const c_char, path2fasta:*const c_char, options:*const Vcf2ProtOptions,
+    callback:Vcf2ProtCallback, user_data:*mut c_void)->c_int
+{
+    generate_with_callback(path2vcf, path2fasta, get_handle(options), callback, user_data)
+}
+
+/// ## Summary
+/// Similar to vcf2prot_generate, however, the options are taken from an options handle, where the default options are used if the handle
+/// is null, see vcf2prot_options_new
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings, the handle must be null or returned by vcf2prot_options_new and not yet
+/// released and the callback must be safe to call with user_data
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_generate_with_options(path2vcf:*const c_char, path2fasta:*const c_char, handle:*const Vcf2ProtOptionsHandle,
+    callback:Vcf2ProtCallback, user_data:*mut c_void)->c_int
+{
+    generate_with_callback(path2vcf, path2fasta, copy_handle(handle), callback, user_data)
+}
+
+/// pass the generated sequences to the callback, see vcf2prot_generate
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings and the callback must be safe to call with user_data
+unsafe fn generate_with_callback(path2vcf:*const c_char, path2fasta:*const c_char, handle:Result<Vcf2ProtOptionsHandle,FfiError>,
+    callback:Vcf2ProtCallback, user_data:*mut c_void)->c_int
+{
+    let callback=match callback
+    {
+        Some(callback)=>callback,
+        None=>return fail((VCF2PROT_INVALID_ARGUMENT,"The callback is null".to_string()))
+    };
+    let results=catch_panic(||generate_records(path2vcf, path2fasta, handle, |proband,haplotype,transcript,sequence,is_altered|
+    {
+        let (proband,transcript,c_sequence)=(to_c_string(proband)?,to_c_string(transcript)?,to_c_string(sequence)?);
+        let record=Vcf2ProtRecord{proband:proband.as_ptr(),haplotype,transcript:transcript.as_ptr(),sequence:c_sequence.as_ptr(),
+            sequence_len:sequence.len(),is_altered:is_altered as u8};
+        match callback(&record, user_data)
+        {
+            0=>Ok(()),
+            code=>Err((VCF2PROT_ABORTED,format!("The callback returned {} for the transcript: {} of the sample: {}",code,record_name(&transcript),record_name(&proband))))
+        }
+    }));
+    match results
+    {
+        Ok(())=>VCF2PROT_OK,
+        Err(error)=>fail(error)
+    }
+}
+
+/// the lossy representation of a NUL-terminated string used in the error messages
+fn record_name(name:&CStr)->String
+{
+    name.to_string_lossy().into_owned()
+}
+
+/// ## Summary
+/// Similar to vcf2prot_generate, however, the sequences are collected into a buffer, which is returned, or null on failure, in which case
+/// the error code is written to error_code, if it is not null, and the message is returned by vcf2prot_last_error
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings, the options must be null or point to valid options and error_code must be
+/// null or writable, the returned buffer must be released with vcf2prot_result_free
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_generate_to_buffer(path2vcf:*const c_char, path2fasta:*const c_char, options:*const Vcf2ProtOptions,
+    error_code:*mut c_int)->*mut Vcf2ProtResult
+{
+    generate_to_buffer(path2vcf, path2fasta, get_handle(options), error_code)
+}
+
+/// ## Summary
+/// Similar to vcf2prot_generate_to_buffer, however, the options are taken from an options handle, where the default options are used if
+/// the handle is null, see vcf2prot_options_new
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings, the handle must be null or returned by vcf2prot_options_new and not yet
+/// released and error_code must be null or writable, the returned buffer must be released with vcf2prot_result_free
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_generate_to_buffer_with_options(path2vcf:*const c_char, path2fasta:*const c_char,
+    handle:*const Vcf2ProtOptionsHandle, error_code:*mut c_int)->*mut Vcf2ProtResult
+{
+    generate_to_buffer(path2vcf, path2fasta, copy_handle(handle), error_code)
+}
+
+/// collect the generated sequences into a buffer, see vcf2prot_generate_to_buffer
+/// ## Safety
+/// the paths must be null or point to NUL-terminated strings and error_code must be null or writable
+unsafe fn generate_to_buffer(path2vcf:*const c_char, path2fasta:*const c_char, handle:Result<Vcf2ProtOptionsHandle,FfiError>,
+    error_code:*mut c_int)->*mut Vcf2ProtResult
+{
+    let mut result=Vcf2ProtResult{strings:Vec::new(),records:Vec::new()};
+    let mut entries=Vec::new();
+    let results=catch_panic(||generate_records(path2vcf, path2fasta, handle, |proband,haplotype,transcript,sequence,is_altered|
+    {
+        result.strings.extend([to_c_string(proband)?,to_c_string(transcript)?,to_c_string(sequence)?]);
+        entries.push((haplotype,sequence.len(),is_altered));
+        Ok(())
+    }));
+    let code=match results
+    {
+        Ok(())=>VCF2PROT_OK,
+        Err(error)=>fail(error)
+    };
+    if let Some(error_code)=error_code.as_mut()
+    {
+        *error_code=code;
+    }
+    if code!=VCF2PROT_OK
+    {
+        return ptr::null_mut()
+    }
+    // the strings are not moved by the vector, hence, the records can point into them once all of them are collected
+    result.records=result.strings.chunks(3).zip(entries)
+        .map(|(strings,(haplotype,sequence_len,is_altered))|Vcf2ProtRecord{proband:strings[0].as_ptr(),haplotype,transcript:strings[1].as_ptr(),
+            sequence:strings[2].as_ptr(),sequence_len,is_altered:is_altered as u8})
+        .collect();
+    Box::into_raw(Box::new(result))
+}
+
+/// ## Summary
+/// Return the number of sequences in a result buffer, 0 if the buffer is null
+/// ## Safety
+/// the buffer must be null or returned by vcf2prot_generate_to_buffer and not yet released
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_result_len(result:*const Vcf2ProtResult)->usize
+{
+    result.as_ref().map_or(0,|result|result.records.len())
+}
+
+/// ## Summary
+/// Return the sequence at the provided index of a result buffer, null if the buffer is null or the index is out of range, the record is
+/// valid until the buffer is released
+/// ## Safety
+/// the buffer must be null or returned by vcf2prot_generate_to_buffer and not yet released
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_result_get(result:*const Vcf2ProtResult, index:usize)->*const Vcf2ProtRecord
+{
+    match result.as_ref().and_then(|result|result.records.get(index))
+    {
+        Some(record)=>record,
+        None=>ptr::null()
+    }
+}
+
+/// ## Summary
+/// Release a result buffer, a null buffer is ignored
+/// ## Safety
+/// the buffer must be null or returned by vcf2prot_generate_to_buffer and not yet released
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_result_free(result:*mut Vcf2ProtResult)
+{
+    if !result.is_null()
+    {
+        drop(Box::from_raw(result));
+    }
+}
+
+/// ## Summary
+/// Return the message of the last failed call on the current thread, null if no call has failed, the message is valid until the next
+/// failed call on the same thread
+#[no_mangle]
+pub extern "C" fn vcf2prot_last_error()->*const c_char
+{
+    LAST_ERROR.with(|last_error|last_error.borrow().as_ref().map_or(ptr::null(),|message|message.as_ptr()))
+}
+
+#[cfg(test)]
+mod test_ffi
+{
+    use super::*;
+    use std::collections::HashMap;
+    /// collect the altered sequences passed to the callback into the map behind user_data
+    unsafe extern "C" fn collect(record:*const Vcf2ProtRecord, user_data:*mut c_void)->c_int
+    {
+        let record=&*record;
+        let sequences=&mut *(user_data as *mut HashMap<(String,u8),String>);
+        let sequence=CStr::from_ptr(record.sequence).to_str().unwrap();
+        assert_eq!(sequence.len(),record.sequence_len);
+        sequences.insert((CStr::from_ptr(record.transcript).to_str().unwrap().to_string(),record.haplotype),sequence.to_string());
+        0
+    }
+    unsafe extern "C" fn abort(_:*const Vcf2ProtRecord, _:*mut c_void)->c_int
+    {
+        7
+    }
+    #[test]
+    fn test_generate_through_the_c_abi()
+    {
+        let output_dir=std::env::temp_dir().join("vcf2prot_ffi_test");
+        std::fs::create_dir_all(&output_dir).unwrap();
+        std::fs::write(output_dir.join("demo.vcf"),crate::parts::demo::DEMO_VCF).unwrap();
+        std::fs::write(output_dir.join("demo_reference.fasta"),crate::parts::demo::DEMO_REFERENCE).unwrap();
+        let path2vcf=CString::new(output_dir.join("demo.vcf").to_str().unwrap()).unwrap();
+        let path2fasta=CString::new(output_dir.join("demo_reference.fasta").to_str().unwrap()).unwrap();
+        let mut sequences:HashMap<(String,u8),String>=HashMap::new();
+        unsafe
+        {
+            let code=vcf2prot_generate(path2vcf.as_ptr(),path2fasta.as_ptr(),ptr::null(),Some(collect),&mut sequences as *mut _ as *mut c_void);
+            assert_eq!(code,VCF2PROT_OK);
+            // the sequences match the ones of the library
+            let reference=readers::read_fasta_file(&output_dir.join("demo_reference.fasta"),Engine::ST).unwrap();
+            let proteomes=api::generate(crate::parts::demo::DEMO_VCF.as_bytes(),&reference,Options::default()).unwrap();
+            let (seq_tape1,seq_tape2)=proteomes.genomes[0].get_seq_tapes();
+            assert_eq!(sequences.len(),seq_tape1.get_annotation().len()+seq_tape2.get_annotation().len());
+            for transcript in seq_tape1.get_ordered_keys()
+            {
+                assert_eq!(sequences[&(transcript.clone(),1)],seq_tape1.get_seq(transcript).unwrap());
+            }
+            // the buffer holds the unaltered transcripts of both haplotypes as well
+            let mut options=Vcf2ProtOptions::default();
+            vcf2prot_options_default(&mut options);
+            options.include_reference=1;
+            let mut error_code=-1;
+            let result=vcf2prot_generate_to_buffer(path2vcf.as_ptr(),path2fasta.as_ptr(),&options,&mut error_code);
+            assert_eq!((error_code,vcf2prot_result_len(result)),(VCF2PROT_OK,2*reference.get_records().len()));
+            let record=&*vcf2prot_result_get(result,0);
+            assert_eq!((CStr::from_ptr(record.proband).to_str().unwrap(),record.haplotype,record.is_altered),("HG00096",1,1));
+            assert!(vcf2prot_result_get(result,vcf2prot_result_len(result)).is_null());
+            vcf2prot_result_free(result);
+            // failures are reported through the error codes and the last error
+            assert_eq!(vcf2prot_generate(ptr::null(),path2fasta.as_ptr(),ptr::null(),Some(collect),ptr::null_mut()),VCF2PROT_INVALID_ARGUMENT);
+            assert!(CStr::from_ptr(vcf2prot_last_error()).to_str().unwrap().contains("VCF file"));
+            assert_eq!(vcf2prot_generate(path2vcf.as_ptr(),path2fasta.as_ptr(),ptr::null(),Some(abort),ptr::null_mut()),VCF2PROT_ABORTED);
+            options.engine=5;
+            assert!(vcf2prot_generate_to_buffer(path2vcf.as_ptr(),path2fasta.as_ptr(),&options,&mut error_code).is_null());
+            assert_eq!(error_code,VCF2PROT_INVALID_ARGUMENT);
+            // the settings of a handle apply to the calls with this handle only
+            let handle=vcf2prot_options_new(ptr::null());
+            let (transcripts,unknown)=(CString::new("transcripts").unwrap(),CString::new("unknown").unwrap());
+            let (first,checks)=(CString::new(sequences.keys().next().unwrap().0.as_str()).unwrap(),CString::new("off").unwrap());
+            assert_eq!(vcf2prot_options_set(handle,transcripts.as_ptr(),first.as_ptr()),VCF2PROT_OK);
+            assert_eq!(vcf2prot_options_set(handle,unknown.as_ptr(),checks.as_ptr()),VCF2PROT_INVALID_ARGUMENT);
+            let result=vcf2prot_generate_to_buffer_with_options(path2vcf.as_ptr(),path2fasta.as_ptr(),handle,&mut error_code);
+            assert_eq!(error_code,VCF2PROT_OK);
+            assert!((0..vcf2prot_result_len(result)).all(|index|CStr::from_ptr((*vcf2prot_result_get(result,index)).transcript)==first.as_c_str()));
+            vcf2prot_result_free(result);
+            vcf2prot_options_free(handle);
+            let mut filtered:HashMap<(String,u8),String>=HashMap::new();
+            let code=vcf2prot_generate_with_options(path2vcf.as_ptr(),path2fasta.as_ptr(),ptr::null(),Some(collect),&mut filtered as *mut _ as *mut c_void);
+            assert_eq!((code,filtered),(VCF2PROT_OK,sequences));
+        }
+    }
+}
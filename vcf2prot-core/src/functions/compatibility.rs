@@ -0,0 +1,388 @@
+/// The module contains functions to check whether a reference proteome matches the annotation of a VCF file, i.e. whether both were
+/// generated from the same Ensembl release, which is the most common source of indexing errors
+use std::collections::{BTreeMap,HashMap,HashSet};
+use std::fmt::Write;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::{Mutation,MutatedString};
+
+/// ## Summary
+/// The fraction of checked positions that can mismatch the reference before the reference is considered to belong to a different release
+pub const MAX_MISMATCHED_FRACTION:f64=0.1;
+/// ## Summary
+/// The fraction of transcripts that can be missing from the reference before the reference is considered to belong to a different release
+pub const MAX_MISSING_FRACTION:f64=0.05;
+
+/// ## Summary
+/// A position where the reference residues stated by a consequence of the VCF do not match the reference proteome, the position is one-based
+#[derive(Debug,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct ResidueMismatch
+{
+    pub transcript:String,
+    pub position:usize,
+    pub expected:String,
+    pub observed:String
+}
+
+/// ## Summary
+/// The number of checked and mismatched positions of a transcript referenced by a VCF file, along with its first mismatch 
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct TranscriptMismatches
+{
+    pub transcript:String,
+    pub num_checked:u64,
+    pub num_mismatched:u64,
+    pub first_mismatch:ResidueMismatch
+}
+
+/// ## Summary
+/// The compatibility of the transcripts referenced by a VCF file with a reference proteome, transcripts are grouped into:
+/// 1. exact matches, i.e. the transcript id of the VCF is an id of the reference
+/// 2. version-suffix mismatches, i.e. the ids only match after removing the version suffix, e.g. ENST00000406869 and ENST00000406869.5
+/// 3. version mismatches, i.e. both ids are versioned and the versions differ, e.g. ENST00000406869.4 and ENST00000406869.5
+/// 4. missing transcripts, i.e. no reference id matches the transcript id with or without a version suffix
+///
+/// For every matched transcript, the reference residues of each consequence are compared to the reference proteome.
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct CompatibilityReport
+{
+    pub num_transcripts:u64,
+    pub num_exact:u64,
+    pub version_suffix:Vec<(String,String)>,
+    pub version_mismatch:Vec<(String,String)>,
+    pub missing:Vec<String>,
+    pub num_checked_positions:u64,
+    pub checked_per_transcript:BTreeMap<String,u64>,
+    pub residue_mismatches:Vec<ResidueMismatch>
+}
+impl CompatibilityReport
+{
+    /// ## Summary
+    /// Inspect the transcripts referenced by the IntMaps, e.g. as returned by io::parse_vcf, against the reference proteome
+    /// ## Example
+    ///```rust
+    /// use std::collections::HashMap;
+    /// use ppgg::data_structures::Map::IntMap;
+    /// use ppgg::data_structures::vcf_ds::AltTranscript;
+    /// use ppgg::functions::compatibility::CompatibilityReport;
+    /// let alt_transcript=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|2K>2N|8A>T".to_string()]);
+    /// let int_map=IntMap::new("sample1".to_string(),vec![alt_transcript],Vec::new());
+    /// let mut ref_seq=HashMap::new();
+    /// ref_seq.insert("T1.3".to_string(),"MKV".to_string());
+    /// let report=CompatibilityReport::new(&[int_map],&ref_seq);
+    /// assert_eq!(report.version_suffix,vec![("T1".to_string(),"T1.3".to_string())]);
+    /// assert!(report.residue_mismatches.is_empty());
+    /// assert!(!report.is_compatible());
+    ///```
+    pub fn new(vec_int_repr:&[IntMap], ref_seq:&HashMap<String,String>)->Self
+    {
+        CompatibilityReport::with_sample_size(vec_int_repr, ref_seq, usize::MAX)
+    }
+    /// ## Summary
+    /// Similar to new, however, only the first positions_per_transcript unique positions of each transcript are compared to the reference
+    /// proteome, which bounds the cost of a pre-flight check while every transcript is still checked 
+    /// ## Example
+    ///```rust
+    /// use std::collections::HashMap;
+    /// use ppgg::data_structures::Map::IntMap;
+    /// use ppgg::data_structures::vcf_ds::AltTranscript;
+    /// use ppgg::functions::compatibility::CompatibilityReport;
+    /// let alt_transcript=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|2K>2N|8A>T".to_string(),
+    ///     "missense|G1|T1|protein_coding|+|3W>3N|9A>T".to_string()]);
+    /// let int_map=IntMap::new("sample1".to_string(),vec![alt_transcript],Vec::new());
+    /// let ref_seq=vec![("T1".to_string(),"MKV".to_string())].into_iter().collect::<HashMap<String,String>>();
+    /// assert_eq!(CompatibilityReport::with_sample_size(&[int_map.clone()],&ref_seq,1).residue_mismatches.len(),0);
+    /// let report=CompatibilityReport::with_sample_size(&[int_map],&ref_seq,2);
+    /// assert_eq!(report.get_transcript_mismatches()[0].num_mismatched,1);
+    ///```
+    pub fn with_sample_size(vec_int_repr:&[IntMap], ref_seq:&HashMap<String,String>, positions_per_transcript:usize)->Self
+    {
+        let mut mutations_per_transcript:HashMap<&str,Vec<&Mutation>>=HashMap::new();
+        for int_map in vec_int_repr.iter()
+        {
+            let (mutations1,mutations2)=int_map.get_mutations_ref();
+            for alt_transcript in mutations1.iter().chain(mutations2.iter())
+            {
+                mutations_per_transcript.entry(&alt_transcript.name).or_default().extend(alt_transcript.alts.iter());
+            }
+        }
+        let mut unversioned_ids:HashMap<&str,&String>=HashMap::new();
+        for id in ref_seq.keys()
+        {
+            unversioned_ids.insert(strip_version(id),id);
+        }
+        let mut report=CompatibilityReport{num_transcripts:mutations_per_transcript.len() as u64,..Default::default()};
+        for (transcript,mutations) in mutations_per_transcript.iter()
+        {
+            let reference_id=match ref_seq.get_key_value(*transcript)
+            {
+                Some((id,_))=>
+                {
+                    report.num_exact+=1;
+                    id
+                },
+                None=>match unversioned_ids.get(strip_version(transcript))
+                {
+                    Some(id)=>
+                    {
+                        match (has_version(transcript),has_version(id))
+                        {
+                            (true,true)=>report.version_mismatch.push((transcript.to_string(),id.to_string())),
+                            _=>report.version_suffix.push((transcript.to_string(),id.to_string()))
+                        }
+                        *id
+                    },
+                    None=>
+                    {
+                        report.missing.push(transcript.to_string());
+                        continue
+                    }
+                }
+            };
+            let reference=ref_seq[reference_id].chars().collect::<Vec<char>>();
+            let mut checked=HashSet::new();
+            for mutation in mutations.iter().filter(|mutation|checked.insert((mutation.mut_info.ref_aa_position,format!("{:?}",mutation.mut_info.ref_aa))))
+                .take(positions_per_transcript)
+            {
+                report.num_checked_positions+=1;
+                *report.checked_per_transcript.entry(transcript.to_string()).or_insert(0)+=1;
+                if let Some(mismatch)=check_residues(transcript, mutation, &reference)
+                {
+                    report.residue_mismatches.push(mismatch);
+                }
+            }
+        }
+        report.version_suffix.sort();
+        report.version_mismatch.sort();
+        report.missing.sort();
+        report.residue_mismatches.sort();
+        report
+    }
+    /// ## Summary
+    /// Return the likely causes of indexing errors, an empty vector is returned if the reference matches the annotation of the VCF. Any
+    /// version mismatch is reported, while residue mismatches and missing transcripts are only reported above MAX_MISMATCHED_FRACTION and
+    /// MAX_MISSING_FRACTION respectively, as isolated mismatches are common among curated models and reference proteomes are commonly
+    /// restricted to a subset of the transcripts, e.g. protein-coding transcripts of the canonical contigs.
+    pub fn get_findings(&self)->Vec<String>
+    {
+        let mut findings=Vec::new();
+        if !self.version_suffix.is_empty()
+        {
+            let (vcf_id,ref_id)=&self.version_suffix[0];
+            findings.push(format!("{} transcripts only match after removing the version suffix, e.g. {} in the VCF and {} in the reference, \
+                        the ids of the reference must be formatted like the ids of the VCF",self.version_suffix.len(),vcf_id,ref_id));
+        }
+        if !self.version_mismatch.is_empty()
+        {
+            let (vcf_id,ref_id)=&self.version_mismatch[0];
+            findings.push(format!("{} transcripts have a different version, e.g. {} in the VCF and {} in the reference, \
+                        the VCF and the reference were likely generated from different Ensembl releases",self.version_mismatch.len(),vcf_id,ref_id));
+        }
+        if self.residue_mismatches.len() as f64 > MAX_MISMATCHED_FRACTION*self.num_checked_positions as f64
+        {
+            let mismatched_transcripts=self.residue_mismatches.iter().map(|mismatch|&mismatch.transcript).collect::<HashSet<_>>();
+            findings.push(format!("{} of {} checked positions in {} transcripts do not match the reference residues stated in the VCF, \
+                        the transcript models likely differ between the releases used for the VCF and the reference",self.residue_mismatches.len(),
+                        self.num_checked_positions,mismatched_transcripts.len()));
+        }
+        if self.missing.len() as f64 > MAX_MISSING_FRACTION*self.num_transcripts as f64
+        {
+            findings.push(format!("{} of {} transcripts referenced in the VCF are missing from the reference, e.g. {}, the reference might \
+                        belong to a different Ensembl release or to a different annotation source",self.missing.len(),self.num_transcripts,self.missing[0]));
+        }
+        findings
+    }
+    /// ## Summary
+    /// Return whether the reference matches the annotation of the VCF, i.e. no finding is reported
+    pub fn is_compatible(&self)->bool
+    {
+        self.get_findings().is_empty()
+    }
+    /// ## Summary
+    /// Return the transcripts with at least one residue mismatch, sorted by the number of mismatched positions in a descending order 
+    pub fn get_transcript_mismatches(&self)->Vec<TranscriptMismatches>
+    {
+        let mut per_transcript:BTreeMap<&String,(u64,&ResidueMismatch)>=BTreeMap::new();
+        for mismatch in self.residue_mismatches.iter()
+        {
+            per_transcript.entry(&mismatch.transcript).or_insert((0,mismatch)).0+=1;
+        }
+        let mut mismatches=per_transcript.into_iter()
+            .map(|(transcript,(num_mismatched,first_mismatch))|TranscriptMismatches{transcript:transcript.clone(),
+                num_checked:self.checked_per_transcript.get(transcript).copied().unwrap_or(num_mismatched),num_mismatched,first_mismatch:first_mismatch.clone()})
+            .collect::<Vec<_>>();
+        mismatches.sort_by(|m1,m2|m2.num_mismatched.cmp(&m1.num_mismatched).then(m1.transcript.cmp(&m2.transcript)));
+        mismatches
+    }
+    /// ## Summary
+    /// Render the residue mismatches per transcript in a human readable form, at most max_listed transcripts are listed 
+    pub fn to_mismatch_summary(&self, max_listed:usize)->String
+    {
+        let mismatches=self.get_transcript_mismatches();
+        let mut summary=format!("Transcripts with residue mismatches: {} of {} checked transcripts\n",mismatches.len(),self.checked_per_transcript.len());
+        for mismatch in mismatches.iter().take(max_listed)
+        {
+            writeln!(summary,"    {}: {} of {} checked positions mismatch, e.g. position {}: expected {} found {}",mismatch.transcript,mismatch.num_mismatched,
+                mismatch.num_checked,mismatch.first_mismatch.position,mismatch.first_mismatch.expected,mismatch.first_mismatch.observed).unwrap();
+        }
+        summary
+    }
+    /// ## Summary
+    /// Render the report in a human readable form, at most max_listed entries are listed per category
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"Transcripts referenced in the VCF: {}",self.num_transcripts).unwrap();
+        writeln!(report,"  exact matches: {}",self.num_exact).unwrap();
+        writeln!(report,"  matches after removing the version suffix: {}",self.version_suffix.len()).unwrap();
+        for (vcf_id,ref_id) in self.version_suffix.iter().take(max_listed)
+        {
+            writeln!(report,"    {} => {}",vcf_id,ref_id).unwrap();
+        }
+        writeln!(report,"  version mismatches: {}",self.version_mismatch.len()).unwrap();
+        for (vcf_id,ref_id) in self.version_mismatch.iter().take(max_listed)
+        {
+            writeln!(report,"    {} => {}",vcf_id,ref_id).unwrap();
+        }
+        writeln!(report,"  missing from the reference: {}",self.missing.len()).unwrap();
+        for transcript in self.missing.iter().take(max_listed)
+        {
+            writeln!(report,"    {}",transcript).unwrap();
+        }
+        writeln!(report,"Checked positions: {}, residue mismatches: {}",self.num_checked_positions,self.residue_mismatches.len()).unwrap();
+        for mismatch in self.residue_mismatches.iter().take(max_listed)
+        {
+            writeln!(report,"    {} position {}: expected {} found {}",mismatch.transcript,mismatch.position,mismatch.expected,mismatch.observed).unwrap();
+        }
+        let findings=self.get_findings();
+        match findings.is_empty()
+        {
+            true=>writeln!(report,"Verdict: the reference matches the annotation of the VCF").unwrap(),
+            false=>
+            {
+                writeln!(report,"Verdict: the reference likely does not match the annotation of the VCF").unwrap();
+                for (idx,finding) in findings.iter().enumerate()
+                {
+                    writeln!(report,"  {}. {}",idx+1,finding).unwrap();
+                }
+            }
+        }
+        report
+    }
+}
+/// ## Summary
+/// Remove the version suffix of a transcript id, e.g. ENST00000406869.5 becomes ENST00000406869, ids without a numeric suffix are returned as is
+pub fn strip_version(transcript_id:&str)->&str
+{
+    match transcript_id.rsplit_once('.')
+    {
+        Some((id,version)) if !id.is_empty() && !version.is_empty() && version.chars().all(|c|c.is_ascii_digit())=>id,
+        _=>transcript_id
+    }
+}
+fn has_version(transcript_id:&str)->bool
+{
+    strip_version(transcript_id).len()!=transcript_id.len()
+}
+/// ## Summary
+/// Compare the reference residues of a consequence to the reference protein, a stop codon must be located right after the last residue
+fn check_residues(transcript:&str, mutation:&Mutation, reference:&[char])->Option<ResidueMismatch>
+{
+    let start=mutation.mut_info.ref_aa_position as usize;
+    let expected=match &mutation.mut_info.ref_aa
+    {
+        MutatedString::Sequence(seq) | MutatedString::EndSequence(seq)=>seq.chars().take_while(|res|*res!='*').collect::<Vec<char>>(),
+        MutatedString::NotSeq=>Vec::new()
+    };
+    let observed=reference.iter().skip(start).take(expected.len().max(1)).collect::<String>();
+    let is_match=match expected.is_empty()
+    {
+        true=>start==reference.len() || (start+1==reference.len() && reference[start]=='*'),
+        false=>start+expected.len()<=reference.len() && reference[start..start+expected.len()]==expected[..]
+    };
+    match is_match
+    {
+        true=>None,
+        false=>Some(ResidueMismatch{
+            transcript:transcript.to_string(),
+            position:start+1,
+            expected:match expected.is_empty()
+            {
+                true=>"*".to_string(),
+                false=>expected.iter().collect::<String>()
+            },
+            observed:match observed.is_empty()
+            {
+                true=>"end of the protein".to_string(),
+                false=>observed
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_compatibility
+{
+    use super::*;
+    use crate::parts::{demo,io};
+    use crate::data_structures::InternalRep::engines::Engine;
+    const VERSIONED_REFERENCE:&str=include_str!("../../data/compatibility/versioned_reference.fasta");
+    const OTHER_RELEASE_REFERENCE:&str=include_str!("../../data/compatibility/other_release_reference.fasta");
+    fn check_pair(name:&str, reference:&str)->CompatibilityReport
+    {
+        let output_dir=std::env::temp_dir().join(name);
+        std::fs::create_dir_all(&output_dir).unwrap();
+        let path2vcf=output_dir.join("demo.vcf");
+        let path2fasta=output_dir.join("reference.fasta");
+        std::fs::write(&path2vcf,demo::DEMO_VCF).unwrap();
+        std::fs::write(&path2fasta,reference).unwrap();
+        CompatibilityReport::new(&io::parse_vcf(&path2vcf,Engine::ST,None).unwrap(),&io::read_fasta(&path2fasta,Engine::ST))
+    }
+    #[test]
+    fn test_strip_version()
+    {
+        assert_eq!(strip_version("ENST00000406869.5"),"ENST00000406869");
+        assert_eq!(strip_version("ENST00000406869"),"ENST00000406869");
+        assert_eq!(strip_version("ENST00000406869.a"),"ENST00000406869.a");
+        assert!(has_version("ENST00000406869.12"));
+        assert!(!has_version("ENST00000406869"));
+    }
+    #[test]
+    fn test_matching_pair()
+    {
+        let report=check_pair("vcf2prot_compatibility_matching",demo::DEMO_REFERENCE);
+        assert_eq!(report.num_transcripts,35);
+        assert_eq!(report.num_exact,35);
+        assert!(report.is_compatible());
+    }
+    #[test]
+    fn test_versioned_reference()
+    {
+        let report=check_pair("vcf2prot_compatibility_versioned",VERSIONED_REFERENCE);
+        assert_eq!(report.num_exact,0);
+        assert_eq!(report.version_suffix.len(),35);
+        assert_eq!(report.get_findings().len(),1);
+    }
+    #[test]
+    fn test_other_release_reference()
+    {
+        let report=check_pair("vcf2prot_compatibility_other_release",OTHER_RELEASE_REFERENCE);
+        assert_eq!(report.missing.len(),7);
+        assert!(!report.residue_mismatches.is_empty());
+        assert_eq!(report.get_findings().len(),2);
+        assert!(report.to_report(5).contains("Verdict: the reference likely does not match the annotation of the VCF"));
+    }
+    #[test]
+    fn test_sampled_mismatches_per_transcript()
+    {
+        let full=check_pair("vcf2prot_compatibility_sampled",OTHER_RELEASE_REFERENCE);
+        let output_dir=std::env::temp_dir().join("vcf2prot_compatibility_sampled");
+        let sampled=CompatibilityReport::with_sample_size(&io::parse_vcf(&output_dir.join("demo.vcf"),Engine::ST,None).unwrap(),
+            &io::read_fasta(&output_dir.join("reference.fasta"),Engine::ST),1);
+        assert_eq!(sampled.num_checked_positions,sampled.checked_per_transcript.len() as u64);
+        assert!(sampled.num_checked_positions<=full.num_checked_positions);
+        let mismatches=full.get_transcript_mismatches();
+        assert_eq!(mismatches.iter().map(|mismatch|mismatch.num_mismatched).sum::<u64>(),full.residue_mismatches.len() as u64);
+        assert!(mismatches.iter().all(|mismatch|mismatch.num_mismatched<=mismatch.num_checked));
+        assert!(full.to_mismatch_summary(3).starts_with(&format!("Transcripts with residue mismatches: {} of",mismatches.len())));
+    }
+}
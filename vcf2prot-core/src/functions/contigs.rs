@@ -0,0 +1,232 @@
+/// The module classifies the contigs of a VCF file, e.g. as primary assembly chromosomes or as ALT, HLA and decoy contigs of the GRCh38 full
+/// analysis set, whose transcripts are mostly not part of the reference proteome, in order to skip the records of non-primary contigs
+use std::collections::HashMap;
+use std::fmt::Write;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+
+/// ## Summary
+/// The class of a contig, derived from the naming conventions of GRCh37, GRCh38 and their analysis sets, with or without the chr prefix:
+/// 1. Primary, the autosomes, the sex chromosomes and the mitochondrial genome, e.g. 1, chrX or chrM
+/// 2. Alt, the alternate loci and the patches, e.g. chr6_GL000251v2_alt or chr1_KN196472v1_fix
+/// 3. Hla, the HLA allele contigs, e.g. HLA-A*01:01:01:01
+/// 4. Decoy, the decoy sequences, e.g. chrUn_JTFH01000001v1_decoy, hs37d5 or chrEBV
+/// 5. Unlocalized, the sequences of a known chromosome with unknown location, e.g. chr1_KI270706v1_random
+/// 6. Unplaced, the sequences of unknown chromosome, e.g. chrUn_KI270302v1 or GL000192.1
+/// 7. Other, every other contig, e.g. the chromosomes of other species, which are not considered non-primary as they can not be told apart
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub enum ContigClass
+{
+    Primary,
+    Alt,
+    Hla,
+    Decoy,
+    Unlocalized,
+    Unplaced,
+    Other
+}
+impl ContigClass
+{
+    /// ## Summary
+    /// Return whether the class is one of the recognized classes of non-primary contigs, i.e. neither Primary nor Other
+    pub fn is_non_primary(&self)->bool
+    {
+        !matches!(self,ContigClass::Primary | ContigClass::Other)
+    }
+    /// ## Summary
+    /// Return the name of the class as used in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ContigClass::Primary=>"primary",
+            ContigClass::Alt=>"ALT",
+            ContigClass::Hla=>"HLA",
+            ContigClass::Decoy=>"decoy",
+            ContigClass::Unlocalized=>"unlocalized",
+            ContigClass::Unplaced=>"unplaced",
+            ContigClass::Other=>"other"
+        }
+    }
+}
+
+/// ## Summary
+/// Classify a contig by its name, see ContigClass for the recognized naming conventions
+/// ## Example
+///```rust
+/// use ppgg::functions::contigs::{classify_contig,ContigClass};
+/// assert_eq!(classify_contig("chr7"),ContigClass::Primary);
+/// assert_eq!(classify_contig("MT"),ContigClass::Primary);
+/// assert_eq!(classify_contig("chr6_GL000251v2_alt"),ContigClass::Alt);
+/// assert_eq!(classify_contig("HLA-DRB1*15:01:01:01"),ContigClass::Hla);
+/// assert_eq!(classify_contig("chrUn_KI270302v1"),ContigClass::Unplaced);
+///```
+pub fn classify_contig(name:&str)->ContigClass
+{
+    let stripped=name.strip_prefix("chr").unwrap_or(name);
+    if is_primary(stripped)
+    {
+        return ContigClass::Primary
+    }
+    if name.starts_with("HLA-")
+    {
+        return ContigClass::Hla
+    }
+    if name.ends_with("_alt") || name.ends_with("_fix")
+    {
+        return ContigClass::Alt
+    }
+    if name.ends_with("_decoy") || name=="hs37d5" || stripped=="EBV"
+    {
+        return ContigClass::Decoy
+    }
+    if name.ends_with("_random")
+    {
+        return ContigClass::Unlocalized
+    }
+    if stripped.starts_with("Un") || ["GL","KI","JH","KN","KQ","KV","KZ","ML","MU"].iter().any(|prefix|name.starts_with(prefix))
+    {
+        return ContigClass::Unplaced
+    }
+    ContigClass::Other
+}
+
+/// ## Summary
+/// Return whether a contig name, without the chr prefix, is a chromosome of the primary assembly
+fn is_primary(stripped:&str)->bool
+{
+    match stripped
+    {
+        "X" | "Y" | "M" | "MT"=>true,
+        _=>matches!(stripped.parse::<u8>(),Ok(1..=22)) && !stripped.starts_with('0')
+    }
+}
+
+/// ## Summary
+/// The policy for records on non-primary contigs, with SkipNonPrimary, which is the default, the records of non-primary contigs, see 
+/// ContigClass::is_non_primary, are skipped and counted, while with All, the records of every contig are parsed
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ContigPolicy
+{
+    SkipNonPrimary,
+    All
+}
+impl Default for ContigPolicy
+{
+    fn default()->Self
+    {
+        ContigPolicy::SkipNonPrimary
+    }
+}
+
+/// ## Summary
+/// The records that were skipped because of the contig they are located on, i.e. the number of skipped records per contig
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct SkippedContigs
+{
+    pub num_records_per_contig:HashMap<String,usize>
+}
+impl SkippedContigs
+{
+    /// ## Summary
+    /// Return the total number of skipped records
+    pub fn get_num_records(&self)->usize
+    {
+        self.num_records_per_contig.values().sum()
+    }
+    /// ## Summary
+    /// Add the skipped records of another part of the same file, e.g. a chunk of a streamed file, see readers::stream_vcf
+    pub fn merge(&mut self, other:SkippedContigs)
+    {
+        for (contig,num_records) in other.num_records_per_contig
+        {
+            *self.num_records_per_contig.entry(contig).or_insert(0)+=num_records;
+        }
+    }
+    /// ## Summary
+    /// Return the number of skipped records per contig class, sorted by class
+    pub fn get_num_records_per_class(&self)->Vec<(ContigClass,usize)>
+    {
+        let mut per_class=HashMap::new();
+        for (contig,num_records) in self.num_records_per_contig.iter()
+        {
+            *per_class.entry(classify_contig(contig)).or_insert(0)+=num_records;
+        }
+        let mut per_class=per_class.into_iter().collect::<Vec<(ContigClass,usize)>>();
+        per_class.sort();
+        per_class
+    }
+    /// ## Summary
+    /// Return a human readable report of the skipped records, with the number of records per class followed by the max_listed contigs
+    /// with the largest number of skipped records
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} records on {} non-primary contigs were skipped: {}",self.get_num_records(),
+            self.num_records_per_contig.len(),self.get_num_records_per_class().iter()
+                .map(|(class,num_records)|format!("{} {}",num_records,class.as_str())).collect::<Vec<String>>().join(", ")).unwrap();
+        let mut per_contig=self.num_records_per_contig.iter().collect::<Vec<(&String,&usize)>>();
+        per_contig.sort_by(|(contig1,num1),(contig2,num2)|num2.cmp(num1).then(contig1.cmp(contig2)));
+        for (contig,num_records) in per_contig.into_iter().take(max_listed)
+        {
+            writeln!(report,"\t{}\t{}\t{}",contig,classify_contig(contig).as_str(),num_records).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Apply the contig policy to the records of a VCF file, i.e. the lines of its body, and return the retained records, in their order,
+/// along with the skipped records per contig, nothing is skipped with ContigPolicy::All
+pub fn filter_records_by_contig(lines:Vec<String>, policy:ContigPolicy, engine:Engine)->(Vec<String>,SkippedContigs)
+{
+    if policy==ContigPolicy::All
+    {
+        return (lines,SkippedContigs::default())
+    }
+    let is_retained=|line:&String|!classify_contig(line.split('\t').next().unwrap()).is_non_primary();
+    let (retained,skipped):(Vec<String>,Vec<String>)=match engine
+    {
+        Engine::ST=>lines.into_iter().partition(is_retained),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.into_par_iter().partition(is_retained)
+    };
+    let mut skipped_contigs=SkippedContigs::default();
+    for line in skipped.iter()
+    {
+        *skipped_contigs.num_records_per_contig.entry(line.split('\t').next().unwrap().to_string()).or_insert(0)+=1;
+    }
+    (retained,skipped_contigs)
+}
+
+#[cfg(test)]
+mod test_contigs
+{
+    use super::*;
+    #[test]
+    fn test_classify_contig()
+    {
+        for name in ["1","22","chr22","X","chrY","chrM","MT"]
+        {
+            assert_eq!(classify_contig(name),ContigClass::Primary,"{}",name);
+        }
+        for (name,class) in [("23",ContigClass::Other),("01",ContigClass::Other),("chr1_KN196472v1_fix",ContigClass::Alt),("chrEBV",ContigClass::Decoy),
+            ("hs37d5",ContigClass::Decoy),("chrUn_JTFH01000001v1_decoy",ContigClass::Decoy),("chr1_KI270706v1_random",ContigClass::Unlocalized),
+            ("GL000192.1",ContigClass::Unplaced),("scaffold_12",ContigClass::Other)]
+        {
+            assert_eq!(classify_contig(name),class,"{}",name);
+        }
+    }
+    #[test]
+    fn test_filter_records_by_contig()
+    {
+        let lines=["chr1\t10","chr6_GL000251v2_alt\t20","HLA-A*01:01:01:01\t30","chr6_GL000251v2_alt\t40","chr38\t50"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let (retained,skipped)=filter_records_by_contig(lines.clone(),ContigPolicy::SkipNonPrimary,Engine::MT);
+        assert_eq!(retained,vec![lines[0].clone(),lines[4].clone()]);
+        assert_eq!(skipped.get_num_records(),3);
+        assert_eq!(skipped.get_num_records_per_class(),vec![(ContigClass::Alt,2),(ContigClass::Hla,1)]);
+        assert!(skipped.to_report(1).lines().nth(1).unwrap().starts_with("\tchr6_GL000251v2_alt\tALT\t2"));
+        let (retained,skipped)=filter_records_by_contig(lines.clone(),ContigPolicy::All,Engine::ST);
+        assert_eq!((retained,skipped.get_num_records()),(lines,0));
+    }
+}
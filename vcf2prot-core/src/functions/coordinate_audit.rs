@@ -0,0 +1,256 @@
+/// The module audits the coordinates and the lengths of the executed tasks across the whole cohort, i.e. every clamped task, off-by-one
+/// correction, size mismatch and unwritten residue encountered while generating and executing the tasks is collected per transcript and
+/// haplotype, and the issues of all probands are grouped by transcript, kind, size and instruction codes, so systematic patterns point to
+/// the instruction code whose translation into tasks is wrong
+use std::collections::{BTreeMap,HashMap,HashSet};
+use std::fmt::Write;
+use crate::data_structures::InternalRep::gir::ExecutionReport;
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome;
+use crate::error::PpggError;
+
+/// ## Summary
+/// The kind of a coordinate or length issue:
+/// 1. Clamp, a task reads beyond the end of its input stream or writes beyond the end of the results array by more than one residue
+/// 2. OffByOne, a task that was clamped by exactly one residue
+/// 3. SizeMismatch, the tasks of a transcript do not fill the results array of the transcript and the transcript was skipped
+/// 4. InvalidSpan, the span between two consecutive instructions ends before it starts and the transcript was skipped
+/// 5. NegativeLength, the instructions remove more residues than the reference has and the transcript was skipped
+/// 6. UnfilledResidues, residues of the transcript were not written by any task
+/// 7. Skipped, the tasks of the transcript could not be generated for any other reason
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum IssueKind
+{
+    Clamp,
+    OffByOne,
+    SizeMismatch,
+    InvalidSpan,
+    NegativeLength,
+    UnfilledResidues,
+    Skipped
+}
+impl IssueKind
+{
+    /// ## Summary
+    /// Return the name of the issue as used in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            IssueKind::Clamp=>"clamp",
+            IssueKind::OffByOne=>"off-by-one",
+            IssueKind::SizeMismatch=>"size-mismatch",
+            IssueKind::InvalidSpan=>"invalid-span",
+            IssueKind::NegativeLength=>"negative-length",
+            IssueKind::UnfilledResidues=>"unfilled-residues",
+            IssueKind::Skipped=>"skipped"
+        }
+    }
+}
+
+/// ## Summary
+/// An issue encountered in one haplotype of a transcript, the delta is the size of the issue in residues, i.e. the number of residues a task
+/// was clamped by, the actual minus the expected size of a mismatch, the end minus the start of an invalid span, the length the instructions
+/// would leave the reference with, or the number of unwritten residues, along with the codes of the instructions of the transcript
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct CoordinateIssue
+{
+    pub transcript:String,
+    pub haplotype:u8,
+    pub kind:IssueKind,
+    pub delta:i64,
+    pub instruction_codes:String
+}
+
+/// ## Summary
+/// Collect the issues of an executed haplotype from its execution report and the number of unwritten residues per transcript, the instruction
+/// codes of a transcript are only looked up for the transcripts with an issue
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::data_structures::InternalRep::gir::{Clamp,ExecutionReport};
+/// use ppgg::functions::coordinate_audit::{get_coordinate_issues,IssueKind};
+/// let report=ExecutionReport{clamps:vec![Clamp{task_index:3,transcript:Some("T1".to_string()),requested_length:4,performed_length:3}],..Default::default()};
+/// let issues=get_coordinate_issues(1,&report,&HashMap::new(),|_|"ML".to_string());
+/// assert_eq!((issues[0].kind,issues[0].delta,issues[0].instruction_codes.as_str()),(IssueKind::OffByOne,1,"ML"));
+///```
+pub fn get_coordinate_issues<F:Fn(&str)->String>(haplotype:u8, report:&ExecutionReport, unfilled_per_transcript:&HashMap<String,usize>, get_codes:F)->Vec<CoordinateIssue>
+{
+    let mut issues=Vec::new();
+    let mut push=|transcript:&str,kind:IssueKind,delta:i64|issues.push(CoordinateIssue{transcript:transcript.to_string(),haplotype,kind,delta,
+        instruction_codes:get_codes(transcript)});
+    for clamp in report.clamps.iter()
+    {
+        let delta=(clamp.requested_length-clamp.performed_length) as i64;
+        let kind=match delta
+        {
+            1=>IssueKind::OffByOne,
+            _=>IssueKind::Clamp
+        };
+        push(clamp.transcript.as_deref().unwrap_or("-"),kind,delta);
+    }
+    for (transcript,reason) in report.skipped_transcripts.iter().zip(report.skip_reasons.iter())
+    {
+        let (kind,delta)=match reason
+        {
+            PpggError::SizeMismatch{expected,actual,..}=>(IssueKind::SizeMismatch,*actual as i64-*expected as i64),
+            PpggError::InvalidSpan{start,end,..}=>(IssueKind::InvalidSpan,*end as i64-*start as i64),
+            PpggError::NegativeSequenceLength{ref_len,size_change,..}=>(IssueKind::NegativeLength,*ref_len as i64+size_change),
+            _=>(IssueKind::Skipped,0)
+        };
+        push(transcript,kind,delta);
+    }
+    let mut unfilled=unfilled_per_transcript.iter().filter(|(_,num_unfilled)|**num_unfilled!=0).collect::<Vec<_>>();
+    unfilled.sort();
+    for (transcript,num_unfilled) in unfilled
+    {
+        push(transcript,IssueKind::UnfilledResidues,*num_unfilled as i64);
+    }
+    issues
+}
+
+/// ## Summary
+/// A group of identical issues, i.e. the same kind and delta at the same transcript with the same instruction codes, along with the number
+/// of haplotypes and of probands that encountered it
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct AuditPattern
+{
+    pub transcript:String,
+    pub kind:IssueKind,
+    pub delta:i64,
+    pub instruction_codes:String,
+    pub num_haplotypes:u64,
+    pub num_probands:u64
+}
+
+/// ## Summary
+/// The key of a pattern, i.e. the transcript, the kind, the delta and the instruction codes of its issues
+type PatternKey=(String,IssueKind,i64,String);
+/// ## Summary
+/// The issues of the whole cohort grouped by transcript, kind, delta and instruction codes
+#[derive(Debug,Clone,Default)]
+pub struct CoordinateAudit
+{
+    patterns:BTreeMap<PatternKey,(u64,HashSet<String>)>
+}
+impl CoordinateAudit
+{
+    /// ## Summary
+    /// Create an empty audit
+    pub fn new()->Self
+    {
+        CoordinateAudit::default()
+    }
+    /// ## Summary
+    /// Create the audit of the issues encountered while executing the personalized genomes of a cohort
+    pub fn from_genomes(genomes:&[PersonalizedGenome])->Self
+    {
+        let mut audit=CoordinateAudit::new();
+        for genome in genomes.iter()
+        {
+            audit.add(genome.get_proband_name(), genome.get_coordinate_issues());
+        }
+        audit
+    }
+    /// ## Summary
+    /// Add the issues of a proband to the audit
+    pub fn add(&mut self, proband_name:&str, issues:&[CoordinateIssue])
+    {
+        for issue in issues.iter()
+        {
+            let entry=self.patterns.entry((issue.transcript.clone(),issue.kind,issue.delta,issue.instruction_codes.clone())).or_default();
+            entry.0+=1;
+            entry.1.insert(proband_name.to_string());
+        }
+    }
+    /// ## Summary
+    /// Return whether no issue was encountered
+    pub fn is_empty(&self)->bool
+    {
+        self.patterns.is_empty()
+    }
+    /// ## Summary
+    /// Return the number of haplotypes that encountered an issue of each kind, summed over all transcripts
+    pub fn get_num_issues_per_kind(&self)->BTreeMap<IssueKind,u64>
+    {
+        let mut counts=BTreeMap::new();
+        for ((_,kind,_,_),(num_haplotypes,_)) in self.patterns.iter()
+        {
+            *counts.entry(*kind).or_insert(0)+=num_haplotypes;
+        }
+        counts
+    }
+    /// ## Summary
+    /// Return the patterns grouped by transcript, i.e. ordered by transcript name and, within a transcript, by the number of haplotypes
+    /// that encountered the pattern in descending order
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::coordinate_audit::{CoordinateAudit,CoordinateIssue,IssueKind};
+    /// let issue=CoordinateIssue{transcript:"T1".to_string(),haplotype:1,kind:IssueKind::SizeMismatch,delta:-2,instruction_codes:"ML".to_string()};
+    /// let mut audit=CoordinateAudit::new();
+    /// audit.add("P1",&[issue.clone(),CoordinateIssue{haplotype:2,..issue.clone()}]);
+    /// audit.add("P2",&[issue]);
+    /// let patterns=audit.get_patterns();
+    /// assert_eq!((patterns.len(),patterns[0].num_haplotypes,patterns[0].num_probands),(1,3,2));
+    ///```
+    pub fn get_patterns(&self)->Vec<AuditPattern>
+    {
+        let mut patterns=self.patterns.iter()
+            .map(|((transcript,kind,delta,instruction_codes),(num_haplotypes,probands))|AuditPattern{transcript:transcript.clone(),kind:*kind,delta:*delta,
+                instruction_codes:instruction_codes.clone(),num_haplotypes:*num_haplotypes,num_probands:probands.len() as u64})
+            .collect::<Vec<AuditPattern>>();
+        patterns.sort_by(|first,second|first.transcript.cmp(&second.transcript).then(second.num_haplotypes.cmp(&first.num_haplotypes)));
+        patterns
+    }
+    /// ## Summary
+    /// Return a short report of the number of issues per kind, followed by the most frequent patterns across all transcripts, i.e. the same
+    /// kind, delta and instruction codes, which are the patterns that point to a bug in the translation of an instruction code
+    pub fn to_summary(&self, max_patterns:usize)->String
+    {
+        let mut summary=String::new();
+        for (kind,num_haplotypes) in self.get_num_issues_per_kind()
+        {
+            writeln!(summary,"{}\t{}",kind.as_str(),num_haplotypes).unwrap();
+        }
+        let mut code_patterns:HashMap<(IssueKind,i64,&str),(u64,HashSet<&str>)>=HashMap::new();
+        for ((transcript,kind,delta,instruction_codes),(num_haplotypes,_)) in self.patterns.iter()
+        {
+            let entry=code_patterns.entry((*kind,*delta,instruction_codes.as_str())).or_default();
+            entry.0+=num_haplotypes;
+            entry.1.insert(transcript.as_str());
+        }
+        let mut code_patterns=code_patterns.into_iter().collect::<Vec<_>>();
+        code_patterns.sort_by(|(first_key,first),(second_key,second)|second.0.cmp(&first.0).then(first_key.cmp(second_key)));
+        for ((kind,delta,instruction_codes),(num_haplotypes,transcripts)) in code_patterns.into_iter().take(max_patterns)
+        {
+            writeln!(summary,"{} of {} residues with the instruction codes: {} in {} haplotypes of {} transcripts",kind.as_str(),delta,
+                instruction_codes,num_haplotypes,transcripts.len()).unwrap();
+        }
+        summary
+    }
+}
+#[cfg(test)]
+mod test_coordinate_audit
+{
+    use super::*;
+    use crate::data_structures::InternalRep::gir::Clamp;
+    #[test]
+    fn test_issues_are_grouped_across_probands()
+    {
+        let report=ExecutionReport{clamps:vec![Clamp{task_index:0,transcript:Some("T1".to_string()),requested_length:5,performed_length:3}],
+            skipped_transcripts:vec!["T2".to_string(),"T3".to_string()],
+            skip_reasons:vec![PpggError::SizeMismatch{transcript:"T2".to_string(),expected:10,actual:8},PpggError::MissingTranscript("T3".to_string())],
+            ..Default::default()};
+        let unfilled=vec![("T1".to_string(),2)].into_iter().collect::<HashMap<String,usize>>();
+        let issues=get_coordinate_issues(2,&report,&unfilled,|transcript|format!("{}L",transcript.len()));
+        assert_eq!(issues.iter().map(|issue|(issue.transcript.as_str(),issue.kind,issue.delta)).collect::<Vec<_>>(),
+            vec![("T1",IssueKind::Clamp,2),("T2",IssueKind::SizeMismatch,-2),("T3",IssueKind::Skipped,0),("T1",IssueKind::UnfilledResidues,2)]);
+        let mut audit=CoordinateAudit::new();
+        audit.add("P1",&issues);
+        audit.add("P2",&issues[1..2]);
+        let patterns=audit.get_patterns();
+        assert_eq!(patterns.len(),4);
+        assert_eq!((patterns[2].transcript.as_str(),patterns[2].num_haplotypes,patterns[2].num_probands),("T2",2,2));
+        assert_eq!(audit.get_num_issues_per_kind()[&IssueKind::SizeMismatch],2);
+        assert!(audit.to_summary(1).ends_with("size-mismatch of -2 residues with the instruction codes: 2L in 2 haplotypes of 1 transcripts\n"));
+    }
+}
@@ -0,0 +1,280 @@
+/// The module controls the diagnostics of a run, i.e. the quality-control checks of the translation from mutations to instructions and of
+/// the tasks handed to the execution engines, the transcripts whose translation is logged in detail, and a logger that prints the records
+/// of the log facade on the standard error, either as text or as a JSON object per line.
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::io::Write as IoWrite;
+use std::str::FromStr;
+use std::time::{SystemTime,UNIX_EPOCH};
+use log::{Level,LevelFilter,Log,Metadata,Record};
+
+/// ## Summary
+/// The policy of the quality-control checks:
+/// 1. Strict => the instructions of each transcript are checked for overlaps and the tasks of each representation are checked for gaps
+///    before they are executed, a failed overlap check or an undecodable bit-mask stops the run, the default of the command line tool.
+/// 2. Warn => the same checks are run, however, a failure is logged as an error and the affected transcript or record is skipped.
+/// 3. Off => the checks are skipped, which avoids their runtime cost, an undecodable bit-mask is still logged and skipped, the default of
+///    the library.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum CheckPolicy
+{
+    Strict,
+    Warn,
+    Off
+}
+impl Default for CheckPolicy
+{
+    fn default()->Self
+    {
+        CheckPolicy::Off
+    }
+}
+impl CheckPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            CheckPolicy::Strict=>"strict",
+            CheckPolicy::Warn=>"warn",
+            CheckPolicy::Off=>"off"
+        }
+    }
+    /// ## Summary
+    /// Return whether the quality-control checks are run
+    pub fn is_enabled(&self)->bool
+    {
+        *self!=CheckPolicy::Off
+    }
+    /// ## Summary
+    /// Report a failed quality-control check, the run stops with the message if the policy is strict, otherwise, the message is logged
+    /// as an error and the caller skips the affected transcript or record
+    pub fn report_failure(&self, message:&str)
+    {
+        match self
+        {
+            CheckPolicy::Strict=>panic!("Critical error was encountered: {}",message),
+            _=>log::error!("Critical error was encountered: {}",message)
+        }
+    }
+}
+impl FromStr for CheckPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<CheckPolicy,String>
+    {
+        match policy.to_lowercase().as_str()
+        {
+            "strict"=>Ok(CheckPolicy::Strict),
+            "warn"=>Ok(CheckPolicy::Warn),
+            "off" | "none"=>Ok(CheckPolicy::Off),
+            _=>Err(format!("{} is not a supported check policy, supported policies are: strict, warn and off",policy))
+        }
+    }
+}
+/// ## Summary
+/// The transcripts whose translation into instructions and tasks is logged at the debug level, an empty filter logs every transcript
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct DebugFilter
+{
+    transcripts:HashSet<String>
+}
+impl DebugFilter
+{
+    /// ## Summary
+    /// Create a new filter from the IDs of the debugged transcripts
+    pub fn new(transcripts:Vec<String>)->Self
+    {
+        DebugFilter{transcripts:transcripts.into_iter().collect()}
+    }
+    /// ## Summary
+    /// Return the IDs of the debugged transcripts, empty if all transcripts are debugged
+    pub fn get_transcripts(&self)->Vec<&String>
+    {
+        let mut transcripts=self.transcripts.iter().collect::<Vec<_>>();
+        transcripts.sort();
+        transcripts
+    }
+    /// ## Summary
+    /// Return whether the translation of a transcript is logged, i.e. the debug level is enabled and the transcript passes the filter
+    pub fn is_debugged(&self, transcript:&str)->bool
+    {
+        log::log_enabled!(Level::Debug) && (self.transcripts.is_empty() || self.transcripts.contains(transcript))
+    }
+}
+/// ## Summary
+/// The diagnostics of translating the mutations of the transcripts into instructions and tasks and of executing the tasks, i.e. the policy 
+/// of the quality-control checks and the transcripts whose translation is logged in detail, the options are passed to the executions 
+/// through exec::ExecutionOptions, hence, cohorts can be executed with different diagnostics in the same process 
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct DiagnosticOptions
+{
+    pub check_policy:CheckPolicy,
+    pub debug_filter:DebugFilter
+}
+
+/// ## Summary
+/// The format of the records printed by the Logger:
+/// 1. Text => a line per record of the form LEVEL:: message, the default.
+/// 2. Json => a JSON object per line with the timestamp, the level, the module and the message of the record.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum LogFormat
+{
+    Text,
+    Json
+}
+impl Default for LogFormat
+{
+    fn default()->Self
+    {
+        LogFormat::Text
+    }
+}
+impl LogFormat
+{
+    /// ## Summary
+    /// Return the name of the format as used on the command line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            LogFormat::Text=>"text",
+            LogFormat::Json=>"json"
+        }
+    }
+}
+impl FromStr for LogFormat
+{
+    type Err=String;
+    fn from_str(format:&str)->Result<LogFormat,String>
+    {
+        match format.to_lowercase().as_str()
+        {
+            "text"=>Ok(LogFormat::Text),
+            "json"=>Ok(LogFormat::Json),
+            _=>Err(format!("{} is not a supported log format, supported formats are: text and json",format))
+        }
+    }
+}
+/// ## Summary
+/// A logger of the log facade that prints the records up to a maximum level on the standard error
+#[derive(Debug,Clone,Copy)]
+pub struct Logger
+{
+    level:LevelFilter,
+    format:LogFormat
+}
+impl Logger
+{
+    /// ## Summary
+    /// Create a new logger printing the records up to the provided level in the provided format
+    pub fn new(level:LevelFilter, format:LogFormat)->Self
+    {
+        Logger{level,format}
+    }
+    /// ## Summary
+    /// Install the logger as the logger of the process, an error is returned if another logger has already been installed
+    pub fn init(self)->Result<(),String>
+    {
+        let level=self.level;
+        match log::set_logger(Box::leak(Box::new(self)))
+        {
+            Ok(_)=>{log::set_max_level(level); Ok(())},
+            Err(err_msg)=>Err(format!("Installing the logger failed with the following error: {}",err_msg))
+        }
+    }
+    /// ## Summary
+    /// Format a record as a line without the trailing newline
+    /// ## Example
+    ///```rust
+    /// use log::{Level,LevelFilter,Record};
+    /// use ppgg::functions::diagnostics::{Logger,LogFormat};
+    /// let record=Record::builder().level(Level::Warn).target("ppgg::readers").args(format_args!("a \"quoted\" record")).build();
+    /// assert_eq!(Logger::new(LevelFilter::Info,LogFormat::Text).format_record(&record),"WARNING:: a \"quoted\" record");
+    /// assert!(Logger::new(LevelFilter::Info,LogFormat::Json).format_record(&record)
+    ///     .ends_with(",\"level\":\"warn\",\"target\":\"ppgg::readers\",\"message\":\"a \\\"quoted\\\" record\"}"));
+    ///```
+    pub fn format_record(&self, record:&Record)->String
+    {
+        match self.format
+        {
+            LogFormat::Text=>
+            {
+                let level=match record.level()
+                {
+                    Level::Warn=>"WARNING",
+                    level=>level.as_str()
+                };
+                format!("{}:: {}",level,record.args())
+            },
+            LogFormat::Json=>
+            {
+                let timestamp=SystemTime::now().duration_since(UNIX_EPOCH).map(|time|time.as_secs_f64()).unwrap_or(0.0);
+                format!("{{\"timestamp\":{:.3},\"level\":\"{}\",\"target\":\"{}\",\"message\":\"{}\"}}",timestamp,
+                    record.level().as_str().to_lowercase(),escape_json(record.target()),escape_json(&record.args().to_string()))
+            }
+        }
+    }
+}
+impl Log for Logger
+{
+    fn enabled(&self, metadata:&Metadata)->bool
+    {
+        metadata.level()<=self.level
+    }
+    fn log(&self, record:&Record)
+    {
+        if self.enabled(record.metadata())
+        {
+            let _=writeln!(std::io::stderr().lock(),"{}",self.format_record(record));
+        }
+    }
+    fn flush(&self)
+    {
+        let _=std::io::stderr().flush();
+    }
+}
+/// ## Summary
+/// Escape a string as the content of a JSON string
+fn escape_json(text:&str)->String
+{
+    let mut escaped=String::with_capacity(text.len());
+    for chr in text.chars()
+    {
+        match chr
+        {
+            '"'=>escaped.push_str("\\\""),
+            '\\'=>escaped.push_str("\\\\"),
+            '\n'=>escaped.push_str("\\n"),
+            '\t'=>escaped.push_str("\\t"),
+            '\r'=>escaped.push_str("\\r"),
+            chr if (chr as u32)<0x20=>{let _=write!(escaped,"\\u{:04x}",chr as u32);},
+            chr=>escaped.push(chr)
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test_diagnostics
+{
+    use super::*;
+    #[test]
+    fn test_check_policy_and_filter()
+    {
+        assert_eq!(DiagnosticOptions::default().check_policy,CheckPolicy::Off);
+        assert_eq!(CheckPolicy::from_str("WARN").unwrap(),CheckPolicy::Warn);
+        assert!(!CheckPolicy::from_str("off").unwrap().is_enabled());
+        assert!(CheckPolicy::from_str("lenient").is_err());
+        // a failed check is only logged with the warn policy 
+        CheckPolicy::Warn.report_failure("the check failed");
+        assert_eq!(LogFormat::from_str("json").unwrap(),LogFormat::Json);
+        assert_eq!(escape_json("a\tb\u{1}"),"a\\tb\\u0001");
+        let filter=DebugFilter::new(vec!["ENST2".to_string(),"ENST1".to_string(),"ENST2".to_string()]);
+        assert_eq!(filter.get_transcripts(),vec!["ENST1","ENST2"]);
+        // no logger is installed, hence, the debug level is disabled and no transcript is debugged
+        assert!(!filter.is_debugged("ENST1"));
+    }
+}
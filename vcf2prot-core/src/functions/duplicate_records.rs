@@ -0,0 +1,202 @@
+/// The module collapses the duplicated records of merged VCF files, i.e. records emitted more than once with the same CHROM, POS, REF and
+/// ALT, which would otherwise add the same mutations twice to the haplotypes of a sample
+use std::collections::{HashMap,HashSet};
+use std::fmt::Write;
+
+/// ## Summary
+/// The duplicated records of a VCF file, i.e. the number of records whose CHROM, POS, REF and ALT were already seen, the number of these
+/// records that were removed, the number of collapsed calls per sample, in the order of the VCF header, and the loci of the duplicated records
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct DuplicateRecordSummary
+{
+    pub proband_names:Vec<String>,
+    pub num_duplicate_records:usize,
+    pub num_removed_records:usize,
+    pub num_collapsed_per_sample:Vec<usize>,
+    pub duplicated_loci:Vec<String>
+}
+impl DuplicateRecordSummary
+{
+    /// ## Summary
+    /// Return the total number of collapsed genotype calls
+    pub fn get_num_collapsed(&self)->usize
+    {
+        self.num_collapsed_per_sample.iter().sum()
+    }
+    /// ## Summary
+    /// Add the duplicates of another part of the same file, e.g. a chunk of a streamed file, see readers::stream_vcf
+    pub fn merge(&mut self, other:DuplicateRecordSummary)
+    {
+        self.num_duplicate_records+=other.num_duplicate_records;
+        self.num_removed_records+=other.num_removed_records;
+        self.num_collapsed_per_sample.resize(self.num_collapsed_per_sample.len().max(other.num_collapsed_per_sample.len()),0);
+        self.num_collapsed_per_sample.iter_mut().zip(other.num_collapsed_per_sample).for_each(|(num,other_num)|*num+=other_num);
+        self.duplicated_loci.extend(other.duplicated_loci);
+    }
+    /// ## Summary
+    /// Return a human readable report of the duplicated records, i.e. the totals followed by the loci of the first max_listed duplicated records
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} duplicated records were found, {} of them were removed and {} genotype calls in {} samples were collapsed into their first record, \
+            the first duplicated records are:",self.num_duplicate_records,self.num_removed_records,self.get_num_collapsed(),
+            self.num_collapsed_per_sample.iter().filter(|num_collapsed|**num_collapsed!=0).count()).unwrap();
+        for locus in self.duplicated_loci.iter().take(max_listed)
+        {
+            writeln!(report,"\t{}",locus).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Collapse the duplicated records of a VCF file, i.e. the lines of its body, and return the retained records, in their order, along with a
+/// summary of the duplicates, the proband names of the summary are left empty. Records are keyed by CHROM, POS, REF and ALT, and the
+/// duplicates are resolved per sample using the genotype, which makes the collapse haplotype-aware:
+/// 1. A call of a duplicated record whose genotype equals the genotype of the sample in an earlier record with the same key is collapsed, i.e. its bit-mask is set to zero, as its consequences are already encoded by the earlier record.
+/// 2. A call with a different genotype, e.g. 1|0 after 0|1, is kept as it places the variant on another haplotype.
+/// 3. A duplicated record where every call was collapsed is removed.
+///
+/// Only the collapsed calls carrying an alternative allele are counted in the summary.
+/// The genotype and the bit-mask are located using the FORMAT layout of each record, the whole sample field is compared for records without
+/// a GT field.
+pub fn collapse_duplicate_records(lines:Vec<String>, num_probands:usize)->(Vec<String>,DuplicateRecordSummary)
+{
+    let mut summary=DuplicateRecordSummary{num_collapsed_per_sample:vec![0;num_probands],..Default::default()};
+    // count the records of each key first, so the genotypes are only kept for the duplicated keys, which are rare
+    let mut num_records_per_key=HashMap::new();
+    for line in lines.iter()
+    {
+        if let Some(key)=get_record_key(line)
+        {
+            *num_records_per_key.entry(key).or_insert(0_usize)+=1;
+        }
+    }
+    if num_records_per_key.values().all(|num_records|*num_records==1)
+    {
+        return (lines,summary)
+    }
+    let duplicated_keys=num_records_per_key.into_iter().filter(|(_,num_records)|*num_records>1)
+        .map(|(key,_)|key).collect::<HashSet<String>>();
+    let mut seen_genotypes:HashMap<String,Vec<Vec<String>>>=HashMap::new();
+    let mut retained=Vec::with_capacity(lines.len());
+    for line in lines.into_iter()
+    {
+        let key=match get_record_key(&line)
+        {
+            Some(key) if duplicated_keys.contains(&key)=>key,
+            _=>
+            {
+                retained.push(line);
+                continue
+            }
+        };
+        let genotypes=get_genotypes(&line);
+        let previous=seen_genotypes.entry(key.clone()).or_default();
+        if previous.is_empty()
+        {
+            previous.push(genotypes);
+            retained.push(line);
+            continue
+        }
+        summary.num_duplicate_records+=1;
+        summary.duplicated_loci.push(key.replace('\t',":"));
+        let collapsed=genotypes.iter().enumerate()
+            .filter(|(sample_index,genotype)|previous.iter().any(|earlier|earlier.get(*sample_index)==Some(*genotype)))
+            .map(|(sample_index,_)|sample_index)
+            .collect::<Vec<usize>>();
+        // only the calls carrying an alternative allele count as collapsed, reference calls add no mutation in the first place
+        for sample_index in collapsed.iter().filter(|sample_index|**sample_index<num_probands && is_carrier(&genotypes[**sample_index]))
+        {
+            summary.num_collapsed_per_sample[*sample_index]+=1;
+        }
+        previous.push(genotypes);
+        let num_samples=line.split('\t').skip(9).count();
+        if collapsed.len()==num_samples
+        {
+            summary.num_removed_records+=1;
+            continue
+        }
+        retained.push(reset_bit_masks(line,&collapsed));
+    }
+    (retained,summary)
+}
+
+/// ## Summary
+/// Return the key of a record, i.e. its CHROM, POS, REF and ALT columns, the ID is not part of the key as merged files may emit the same
+/// variant with and without an identifier, None is returned for truncated records
+fn get_record_key(line:&str)->Option<String>
+{
+    let mut columns=line.split('\t');
+    let (chrom,pos,_,reference,alt)=(columns.next()?,columns.next()?,columns.next()?,columns.next()?,columns.next()?);
+    Some(format!("{}\t{}\t{}\t{}",chrom,pos,reference,alt))
+}
+
+/// ## Summary
+/// Return the genotype of each sample of a record, the whole field is used if the FORMAT column has no GT field
+fn get_genotypes(line:&str)->Vec<String>
+{
+    let gt_index=line.split('\t').nth(8).and_then(|format|format.split(':').position(|field|field=="GT"));
+    line.split('\t').skip(9)
+        .map(|field|match gt_index
+        {
+            Some(index)=>field.split(':').nth(index).unwrap_or(".").to_string(),
+            None=>field.to_string()
+        })
+        .collect()
+}
+
+/// ## Summary
+/// Return whether a genotype carries at least one alternative allele, e.g. 0|1 or 1/1
+fn is_carrier(genotype:&str)->bool
+{
+    genotype.split(['/','|'].as_ref()).any(|allele|allele!="0" && allele!=".")
+}
+
+/// ## Summary
+/// Set the bit-mask of the provided samples to zero, the record is returned unchanged if its FORMAT column has no BCSQ field
+fn reset_bit_masks(line:String, sample_indices:&[usize])->String
+{
+    let bcsq_index=match line.split('\t').nth(8).and_then(|format|format.split(':').position(|field|field=="BCSQ"))
+    {
+        Some(index)=>index,
+        None=>return line
+    };
+    let mut fields=line.split('\t').map(|field|field.to_string()).collect::<Vec<String>>();
+    for sample_index in sample_indices.iter()
+    {
+        let field=&mut fields[9+sample_index];
+        let mut sub_fields=field.split(':').map(|sub_field|sub_field.to_string()).collect::<Vec<String>>();
+        while sub_fields.len()<=bcsq_index
+        {
+            sub_fields.push(".".to_string());
+        }
+        sub_fields[bcsq_index]="0".to_string();
+        *field=sub_fields.join(":");
+    }
+    fields.join("\t")
+}
+
+#[cfg(test)]
+mod test_duplicate_records
+{
+    use super::*;
+    #[test]
+    fn test_collapse_duplicate_records()
+    {
+        let lines=["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t0|1:2\t1|1:3",
+            "1\t10\trs1\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t0|1:2\t1|1:3",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t0|1:2\t0|0:0",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t1|0:1\t0|0:0",
+            "1\t20\t.\tA\tG\t.\tPASS\tBCSQ=y\tGT:BCSQ\t0|1:2\t0|0:0"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let (retained,summary)=collapse_duplicate_records(lines.clone(),2);
+        // the second record only differs by its ID, while the first sample of the fourth record carries the variant on the other haplotype
+        assert_eq!(retained,vec![lines[0].clone(),lines[2].clone(),"1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t1|0:1\t0|0:0".to_string(),lines[4].clone()]);
+        assert_eq!((summary.num_duplicate_records,summary.num_removed_records),(2,1));
+        assert_eq!(summary.num_collapsed_per_sample,vec![1,1]);
+        assert_eq!(summary.duplicated_loci,vec!["1:10:A:T".to_string(),"1:20:A:T".to_string()]);
+        let (retained,summary)=collapse_duplicate_records(vec![lines[0].clone()],2);
+        assert_eq!((retained.len(),summary.get_num_collapsed()),(1,0));
+    }
+}
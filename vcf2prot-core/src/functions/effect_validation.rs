@@ -0,0 +1,310 @@
+/// The module contains functions to cross-check the protein change of each consequence against its genomic change and a genome sequence
+use std::collections::HashMap;
+use rayon::prelude::*;
+use crate::data_structures::vcf_ds::VCFRecords;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::functions::text_parser;
+use crate::readers::vcf_helpers::is_supported_csq;
+
+/// ## Summary
+/// The standard genetic code, where codons are indexed using the base order T, C, A and G, i.e. TTT is 0 and GGG is 63
+const GENETIC_CODE:&[u8;64]=b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+
+/// ## Summary
+/// A consequence whose genomic change is not consistent with the genome or with the stated protein change, the struct stores the locus
+/// of the record, i.e. CHROM:POS, the transcript, the consequence type, the protein and the genomic change along with the reason.
+#[derive(Debug,Clone,PartialEq)]
+pub struct InconsistentAnnotation
+{
+    pub locus:String,
+    pub transcript:String,
+    pub consequence:String,
+    pub protein_change:String,
+    pub genomic_change:String,
+    pub reason:String
+}
+
+/// ## Summary
+/// Cross-check the supported consequences of each record against the genome, where the genome is a hashmap with contig names as keys and
+/// sequences as values, contigs are matched by the first word of the name with or without the chr prefix. The following checks are performed:
+/// 1. The reference allele of the genomic change matches the genome sequence at the stated position.
+/// 2. The length difference between the alleles shifts the reading frame for frameshifts and preserves it for inframe insertions and deletions.
+/// 3. For single nucleotide missense and stop-gained changes, one of the codons overlapping the position encodes the stated amino acid change.
+///
+/// The codons are translated before and after the change taking the strand of the transcript into account.
+///
+/// Consequences with a compound genomic change, e.g. 100A>T+102G>C, and consequences inherited from another record, i.e. starting with *,
+/// are only checked for the reference alleles. Codons split by an intron can not be reconstructed from the genome and hence changes at the
+/// first or the last bases of an exon might be flagged by the third check.
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::functions::effect_validation::validate_csq;
+/// let mut genome=HashMap::new();
+/// genome.insert("chr7".to_string(),"GGATGAAAGCC".to_string());
+/// // the AAA codon, i.e. K, at positions 6-8 becomes AAT, i.e. N
+/// assert!(validate_csq("7","missense|G1|T1|protein_coding|+|2K>2N|8A>T",&genome).is_ok());
+/// assert!(validate_csq("7","missense|G1|T1|protein_coding|+|2K>2E|8A>T",&genome).is_err());
+///```
+pub fn validate_records(records:&VCFRecords, genome:&HashMap<String,String>, engine:Engine)->Vec<InconsistentAnnotation>
+{
+    let genome=index_genome(genome);
+    match engine
+    {
+        Engine::ST=>records.get_records().iter().flat_map(|record|validate_record(record,&genome)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>records.get_records().par_iter().flat_map(|record|validate_record(record,&genome)).collect::<Vec<_>>()
+    }
+}
+/// ## Summary
+/// Cross-check a single consequence string, e.g. missense|FAM20C|ENST00000313766|protein_coding|+|13I>13F|193236A>T, of a record located
+/// on the provided chromosome, returns the reason as an error if the consequence is inconsistent, see validate_records for the checks
+pub fn validate_csq(chrom:&str, csq:&str, genome:&HashMap<String,String>)->Result<(),String>
+{
+    validate_csq_indexed(chrom, csq, &index_genome(genome))
+}
+
+/// ## Summary
+/// Map the first word of each contig name, e.g. 1 from '1 dna:chromosome chromosome:GRCh38:1', to the sequence of the contig
+fn index_genome(genome:&HashMap<String,String>)->HashMap<&str,&[u8]>
+{
+    genome.iter()
+        .map(|(name,seq)|(name.split_whitespace().next().unwrap_or(""),seq.as_bytes()))
+        .collect::<HashMap<_,_>>()
+}
+/// ## Summary
+/// Return the sequence of a contig, where the contig is looked up with and without the chr prefix
+fn get_contig<'a>(chrom:&str, genome:&HashMap<&str,&'a [u8]>)->Option<&'a [u8]>
+{
+    let alternative=match chrom.strip_prefix("chr")
+    {
+        Some(name)=>name.to_string(),
+        None=>format!("chr{}",chrom)
+    };
+    genome.get(chrom).or_else(||genome.get(alternative.as_str())).copied()
+}
+/// ## Summary
+/// Cross-check all the supported consequences of a record
+fn validate_record(record:&str, genome:&HashMap<&str,&[u8]>)->Vec<InconsistentAnnotation>
+{
+    let fields=record.split('\t').collect::<Vec<&str>>();
+    if fields.len()<8
+    {
+        return Vec::new()
+    }
+    let bcsq=match fields[7].split(';').find_map(|info_field|info_field.strip_prefix("BCSQ="))
+    {
+        Some(bcsq)=>bcsq,
+        None=>return Vec::new()
+    };
+    bcsq.split(',')
+        .filter(|csq|is_supported_csq(csq))
+        .filter_map(|csq|
+        {
+            match validate_csq_indexed(fields[0], csq, genome)
+            {
+                Ok(_)=>None,
+                Err(reason)=>
+                {
+                    let csq_fields=csq.split('|').collect::<Vec<&str>>();
+                    Some(InconsistentAnnotation{locus:format!("{}:{}",fields[0],fields[1]),transcript:csq_fields[2].to_string(),
+                        consequence:csq_fields[0].to_string(),protein_change:csq_fields[5].to_string(),genomic_change:csq_fields[6].to_string(),reason})
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+}
+/// ## Summary
+/// Cross-check a single consequence string against an indexed genome
+fn validate_csq_indexed(chrom:&str, csq:&str, genome:&HashMap<&str,&[u8]>)->Result<(),String>
+{
+    let csq_fields=csq.split('|').collect::<Vec<&str>>();
+    if csq_fields.len()!=7
+    {
+        return Err(format!("The consequence: {} does not contain the seven expected fields",csq))
+    }
+    let contig=match get_contig(chrom, genome)
+    {
+        Some(contig)=>contig,
+        None=>return Err(format!("The contig: {} is not part of the genome",chrom))
+    };
+    // check the reference allele of each change
+    let changes=csq_fields[6].split('+').map(parse_genomic_change).collect::<Result<Vec<_>,String>>()?;
+    for (position,ref_allele,_) in changes.iter()
+    {
+        let start=position-1;
+        let observed=contig.get(start..start+ref_allele.len()).map(|seq|seq.to_ascii_uppercase());
+        if observed.as_deref()!=Some(ref_allele.as_bytes())
+        {
+            return Err(format!("The reference allele: {} at position: {} does not match the genome sequence: {}",ref_allele,position,
+                observed.map(|seq|String::from_utf8_lossy(&seq).to_string()).unwrap_or_else(||"out of range".to_string())))
+        }
+    }
+    let mut_type=csq_fields[0];
+    if mut_type.starts_with('*') || changes.len()!=1
+    {
+        return Ok(())
+    }
+    let (position,ref_allele,alt_allele)=&changes[0];
+    let diff=alt_allele.len() as i64-ref_allele.len() as i64;
+    match mut_type
+    {
+        "frameshift" if diff%3==0 =>Err(format!("A length difference of {} nucleotides does not shift the reading frame",diff)),
+        "inframe_insertion" if diff<=0 || diff%3!=0 =>Err(format!("A length difference of {} nucleotides is not an inframe insertion",diff)),
+        "inframe_deletion" if diff>=0 || diff%3!=0 =>Err(format!("A length difference of {} nucleotides is not an inframe deletion",diff)),
+        "missense" | "stop_gained" if ref_allele.len()==1 && alt_allele.len()==1 =>
+        {
+            let (ref_aa,alt_aa)=parse_protein_change(csq_fields[5])?;
+            if ref_aa.len()!=1 || alt_aa.len()!=1
+            {
+                return Ok(())
+            }
+            let is_reverse=csq_fields[4]=="-";
+            let (ref_aa,alt_aa)=(ref_aa.as_bytes()[0],alt_aa.as_bytes()[0]);
+            match is_codon_consistent(contig, position-1, alt_allele.as_bytes()[0], is_reverse, ref_aa, alt_aa)
+            {
+                true=>Ok(()),
+                false=>Err(format!("None of the codons overlapping position: {} translates to {} before and to {} after the change",position,
+                    ref_aa as char,alt_aa as char))
+            }
+        },
+        _=>Ok(())
+    }
+}
+/// ## Summary
+/// Parse a genomic change, e.g. 193236A>T, into a one-based position, a reference and an alternative allele
+fn parse_genomic_change(change:&str)->Result<(usize,String,String),String>
+{
+    let (lhs,alt_allele)=match change.split_once('>')
+    {
+        Some(res)=>res,
+        None=>return Err(format!("The genomic change: {} could not be parsed",change))
+    };
+    let num_digits=lhs.chars().take_while(|c|c.is_ascii_digit()).count();
+    let position=match lhs[..num_digits].parse::<usize>()
+    {
+        Ok(position) if position>0 =>position,
+        _=>return Err(format!("The genomic change: {} could not be parsed",change))
+    };
+    Ok((position,lhs[num_digits..].to_ascii_uppercase(),alt_allele.to_ascii_uppercase()))
+}
+/// ## Summary
+/// Parse a protein change, e.g. 13I>13F, into the reference and the alternative amino acids
+fn parse_protein_change(change:&str)->Result<(String,String),String>
+{
+    match change.split_once('>')
+    {
+        Some((lhs,rhs))=>
+        {
+            let (_,ref_aa)=text_parser::parse_amino_acid_seq_position(lhs)?;
+            let (_,alt_aa)=text_parser::parse_amino_acid_seq_position(rhs)?;
+            Ok((ref_aa,alt_aa))
+        },
+        None=>Err(format!("The protein change: {} could not be parsed",change))
+    }
+}
+/// ## Summary
+/// Return true if one of the three codons overlapping the zero-based position translates to ref_aa and, after replacing the base at the
+/// position with alt_base, to alt_aa, codons on the reverse strand are reverse complemented before translation
+fn is_codon_consistent(contig:&[u8], position:usize, alt_base:u8, is_reverse:bool, ref_aa:u8, alt_aa:u8)->bool
+{
+    (0..3).filter(|offset|*offset<=position && position-offset+3<=contig.len())
+        .any(|offset|
+        {
+            let start=position-offset;
+            let mut ref_codon=[0;3];
+            ref_codon.copy_from_slice(&contig[start..start+3]);
+            let mut alt_codon=ref_codon;
+            alt_codon[offset]=alt_base;
+            translate_codon(&ref_codon,is_reverse)==Some(ref_aa) && translate_codon(&alt_codon,is_reverse)==Some(alt_aa)
+        })
+}
+/// ## Summary
+/// Translate a codon from the forward strand into an amino acid, where the codon is reverse complemented first if is_reverse is set,
+/// returns None if the codon contains a base other than A, C, G or T
+pub fn translate_codon(codon:&[u8;3], is_reverse:bool)->Option<u8>
+{
+    let bases=match is_reverse
+    {
+        true=>[complement(codon[2])?,complement(codon[1])?,complement(codon[0])?],
+        false=>[codon[0].to_ascii_uppercase(),codon[1].to_ascii_uppercase(),codon[2].to_ascii_uppercase()]
+    };
+    let mut index=0;
+    for base in bases.iter()
+    {
+        index=index*4+match base
+        {
+            b'T'=>0,
+            b'C'=>1,
+            b'A'=>2,
+            b'G'=>3,
+            _=>return None
+        };
+    }
+    Some(GENETIC_CODE[index])
+}
+/// ## Summary
+/// Return the complement of a base
+fn complement(base:u8)->Option<u8>
+{
+    match base.to_ascii_uppercase()
+    {
+        b'A'=>Some(b'T'),
+        b'C'=>Some(b'G'),
+        b'G'=>Some(b'C'),
+        b'T'=>Some(b'A'),
+        _=>None
+    }
+}
+
+#[cfg(test)]
+mod test_effect_validation
+{
+    use super::*;
+    fn get_genome()->HashMap<String,String>
+    {
+        let mut genome=HashMap::new();
+        // ATG AAA TGG GCC CTT ... on the forward strand
+        genome.insert("1 dna:chromosome".to_string(),"ATGAAATGGGCCCTTACGGAT".to_string());
+        genome
+    }
+    #[test]
+    fn test_translate_codon()
+    {
+        assert_eq!(translate_codon(b"ATG",false),Some(b'M'));
+        assert_eq!(translate_codon(b"TGA",false),Some(b'*'));
+        // the reverse complement of CAT is ATG
+        assert_eq!(translate_codon(b"cat",true),Some(b'M'));
+        assert_eq!(translate_codon(b"ANG",false),None);
+    }
+    #[test]
+    fn test_validate_csq()
+    {
+        let genome=get_genome();
+        // TGG (W) to TGA (*) at position 9
+        assert!(validate_csq("chr1","stop_gained|G1|T1|protein_coding|+|3W>3*|9G>A",&genome).is_ok());
+        // the reference allele does not match the genome
+        let reason=validate_csq("1","missense|G1|T1|protein_coding|+|3W>3C|9C>T",&genome).unwrap_err();
+        assert!(reason.contains("does not match the genome sequence: G"));
+        // the last codon GAT is ATC, i.e. I, on the reverse strand and changes to GAA, i.e. TTC or F, on the reverse strand
+        assert!(validate_csq("1","missense|G1|T1|protein_coding|-|1I>1F|21T>A",&genome).is_ok());
+        assert!(validate_csq("1","missense|G1|T1|protein_coding|-|1D>1Y|21T>A",&genome).is_err());
+        // frame checks
+        assert!(validate_csq("1","frameshift|G1|T1|protein_coding|+|2K>2KX|4A>AT",&genome).is_ok());
+        assert!(validate_csq("1","frameshift|G1|T1|protein_coding|+|2K>2KX|4A>ATTT",&genome).is_err());
+        assert!(validate_csq("1","inframe_deletion|G1|T1|protein_coding|+|2KW>2K|5AATG>A",&genome).is_ok());
+        assert!(validate_csq("1","inframe_insertion|G1|T1|protein_coding|+|2K>2KW|6A>AT",&genome).is_err());
+        assert!(validate_csq("2","missense|G1|T1|protein_coding|+|3W>3C|9G>T",&genome).is_err());
+    }
+    #[test]
+    fn test_validate_records()
+    {
+        let records=VCFRecords::new(vec![
+            "1\t9\t.\tG\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|3W>3C|9G>T,missense|G2|T2|protein_coding|+|3W>3L|9G>T\tGT\t1|0".to_string(),
+            "1\t9\t.\tG\tA\t.\tPASS\tAC=1;BCSQ=stop_gained|G1|T1|protein_coding|+|3W>3*|9G>A\tGT\t0|1".to_string()]);
+        let flagged=validate_records(&records, &get_genome(), Engine::ST);
+        assert_eq!(flagged.len(),1);
+        assert_eq!(flagged[0].transcript,"T2");
+        assert_eq!(flagged[0].locus,"1:9");
+        assert_eq!(validate_records(&records, &get_genome(), Engine::MT),flagged);
+    }
+}
@@ -0,0 +1,132 @@
+/// The module rolls up the protein changes of a cohort by gene symbol, i.e. for each gene the number of samples carrying at least one
+/// protein-altering change in one of its transcripts along with the distinct protein changes, providing a high-level view of a run
+use std::collections::{BTreeSet,HashMap};
+use rayon::prelude::*;
+use crate::data_structures::vcf_ds::VCFRecords;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::functions::hgvs::HgvsChange;
+
+/// ## Summary
+/// The roll-up of a gene, where carriers are the distinct samples with at least one protein-altering change in one of the transcripts of
+/// the gene, and protein changes are the distinct changes formatted as transcript:HGVS.p, e.g. ENST00000288602:p.Val600Glu
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct GeneSummary
+{
+    pub gene:String,
+    pub transcripts:Vec<String>,
+    pub carriers:Vec<String>,
+    pub protein_changes:Vec<String>
+}
+impl GeneSummary
+{
+    /// ## Summary
+    /// Return the number of samples carrying at least one protein-altering change in the gene
+    pub fn num_carriers(&self)->usize
+    {
+        self.carriers.len()
+    }
+}
+
+/// ## Summary
+/// Extract the gene symbol of each transcript from the BCSQ consequences of the records, i.e. the second and the third field of each
+/// consequence, consequences inherited from another record, e.g. @12345, and consequences with an empty gene symbol are ignored
+/// ## Example
+///```rust
+/// use ppgg::data_structures::vcf_ds::VCFRecords;
+/// use ppgg::functions::gene_report::get_transcript_genes;
+/// use ppgg::Engine;
+/// let records=VCFRecords::new(vec!["7\t140753336\t.\tA\tT\t.\tPASS\tBCSQ=missense|BRAF|ENST00000646891|protein_coding|-|640V>640E|140753336A>T,@140753340\tGT\t1|0".to_string()]);
+/// let transcript_genes=get_transcript_genes(&records,Engine::ST);
+/// assert_eq!(transcript_genes.get("ENST00000646891").unwrap(),"BRAF");
+/// assert_eq!(transcript_genes.len(),1);
+///```
+pub fn get_transcript_genes(records:&VCFRecords, engine:Engine)->HashMap<String,String>
+{
+    let pairs=match engine
+    {
+        Engine::ST=>records.get_records().iter().flat_map(|record|get_record_genes(record)).collect::<Vec<(String,String)>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>records.get_records().par_iter().flat_map(|record|get_record_genes(record)).collect::<Vec<(String,String)>>()
+    };
+    pairs.into_iter().collect::<HashMap<String,String>>()
+}
+
+/// ## Summary
+/// Return the transcript and gene symbol pairs of the BCSQ consequences of a single record
+fn get_record_genes(record:&str)->Vec<(String,String)>
+{
+    let info=match record.split('\t').nth(7)
+    {
+        Some(info)=>info,
+        None=>return Vec::new()
+    };
+    let bcsq=match info.split(';').find_map(|field|field.strip_prefix("BCSQ="))
+    {
+        Some(bcsq)=>bcsq,
+        None=>return Vec::new()
+    };
+    bcsq.split(',')
+        .filter_map(|csq|
+        {
+            let mut fields=csq.split('|').skip(1);
+            match (fields.next(),fields.next())
+            {
+                (Some(gene),Some(transcript)) if !gene.is_empty() && !transcript.is_empty()=>Some((transcript.to_string(),gene.to_string())),
+                _=>None
+            }
+        })
+        .collect::<Vec<(String,String)>>()
+}
+
+/// ## Summary
+/// The transcripts, the carriers and the protein changes collected for a gene while rolling up the changes
+type GeneChanges<'a>=(BTreeSet<&'a str>,BTreeSet<&'a str>,BTreeSet<String>);
+
+/// ## Summary
+/// Roll up the protein changes, e.g. as returned by hgvs::get_hgvs_changes, by gene symbol, where transcripts without a gene symbol are
+/// reported under their transcript id. The genes are sorted by the number of carriers, in descending order, and then by their symbol.
+pub fn compute_gene_report(changes:&[HgvsChange], transcript_genes:&HashMap<String,String>)->Vec<GeneSummary>
+{
+    let mut per_gene:HashMap<&str,GeneChanges>=HashMap::new();
+    for change in changes.iter()
+    {
+        let gene=transcript_genes.get(&change.transcript_name).map(|gene|gene.as_str()).unwrap_or(change.transcript_name.as_str());
+        let (transcripts,carriers,protein_changes)=per_gene.entry(gene).or_default();
+        transcripts.insert(&change.transcript_name);
+        carriers.insert(&change.proband_name);
+        protein_changes.insert(format!("{}:{}",change.transcript_name,change.hgvs_p));
+    }
+    let mut report=per_gene.into_iter()
+        .map(|(gene,(transcripts,carriers,protein_changes))|GeneSummary{
+            gene:gene.to_string(),
+            transcripts:transcripts.into_iter().map(|transcript|transcript.to_string()).collect(),
+            carriers:carriers.into_iter().map(|carrier|carrier.to_string()).collect(),
+            protein_changes:protein_changes.into_iter().collect()
+        })
+        .collect::<Vec<GeneSummary>>();
+    report.sort_by(|summary1,summary2|summary2.num_carriers().cmp(&summary1.num_carriers()).then(summary1.gene.cmp(&summary2.gene)));
+    report
+}
+
+#[cfg(test)]
+mod test_gene_report
+{
+    use super::*;
+    fn get_change(proband_name:&str, haplotype:u8, transcript_name:&str, hgvs_p:&str)->HgvsChange
+    {
+        HgvsChange{proband_name:proband_name.to_string(),haplotype,transcript_name:transcript_name.to_string(),consequence:"Missense".to_string(),
+            ref_position:1,hgvs_p:hgvs_p.to_string()}
+    }
+    #[test]
+    fn test_compute_gene_report()
+    {
+        let transcript_genes=[("T1","G1"),("T2","G1"),("T3","G2")].iter()
+            .map(|(transcript,gene)|(transcript.to_string(),gene.to_string())).collect::<HashMap<String,String>>();
+        let changes=vec![get_change("S1",1,"T1","p.Val600Glu"),get_change("S1",2,"T1","p.Val600Glu"),get_change("S2",1,"T2","p.Lys5Arg"),
+            get_change("S2",1,"T3","p.Arg3Ter"),get_change("S3",2,"T4","p.Ala2Gly")];
+        let report=compute_gene_report(&changes,&transcript_genes);
+        assert_eq!(report.iter().map(|summary|(summary.gene.as_str(),summary.num_carriers())).collect::<Vec<_>>(),vec![("G1",2),("G2",1),("T4",1)]);
+        assert_eq!(report[0].transcripts,vec!["T1","T2"]);
+        assert_eq!(report[0].carriers,vec!["S1","S2"]);
+        assert_eq!(report[0].protein_changes,vec!["T1:p.Val600Glu","T2:p.Lys5Arg"]);
+    }
+}
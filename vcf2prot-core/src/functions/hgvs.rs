@@ -0,0 +1,678 @@
+/// The module contains functions to format the applied mutations as HGVS protein-level (HGVS.p) descriptions, e.g. p.Arg263Gln,
+/// for clinical reports, along with the collection of these descriptions across the haplotypes of a cohort, and to parse HGVS.p
+/// descriptions back into mutations, so sources that describe protein changes in HGVS can be translated into instructions
+use std::collections::HashMap;
+use std::sync::Arc;
+use rayon::prelude::*;
+use crate::data_structures::mutation_ds::{Mutation,MutationInfo,MutationType,MutatedString};
+use crate::data_structures::InternalRep::instruction::Instruction;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::Map::IntMap;
+use crate::functions::text_parser;
+
+/// ## Summary
+/// The location of an HGVS.p description, i.e. the first residue and its position, the last residue of a range and its position, if any,
+/// and the rest of the description
+type HgvsLocation<'a>=((char,usize),Option<(char,usize)>,&'a str);
+/// ## Summary
+/// The HGVS.p descriptions of the alterations of each transcript of a proband, with transcript ids as keys and the comma separated
+/// descriptions of the first and the second haplotype as values, an empty string is used for an unaltered haplotype
+pub type HgvsHeaderTags=HashMap<String,(String,String)>;
+
+/// ## Summary
+/// An applied mutation along with its HGVS.p description, positions are one-based as in HGVS
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct HgvsChange
+{
+    pub proband_name:String,
+    pub haplotype:u8,
+    pub transcript_name:String,
+    pub consequence:String,
+    pub ref_position:usize,
+    pub hgvs_p:String
+}
+
+/// ## Summary
+/// Return the three letter code of an amino acid, the stop codon, i.e. *, is encoded as Ter
+/// ## Example
+///```rust
+/// use ppgg::functions::hgvs::aa_to_three_letter;
+/// assert_eq!(aa_to_three_letter('R').unwrap(),"Arg");
+/// assert_eq!(aa_to_three_letter('*').unwrap(),"Ter");
+/// assert!(aa_to_three_letter('1').is_err());
+///```
+pub fn aa_to_three_letter(amino_acid:char)->Result<&'static str,String>
+{
+    match amino_acid
+    {
+        'A'=>Ok("Ala"), 'R'=>Ok("Arg"), 'N'=>Ok("Asn"), 'D'=>Ok("Asp"), 'C'=>Ok("Cys"),
+        'Q'=>Ok("Gln"), 'E'=>Ok("Glu"), 'G'=>Ok("Gly"), 'H'=>Ok("His"), 'I'=>Ok("Ile"),
+        'L'=>Ok("Leu"), 'K'=>Ok("Lys"), 'M'=>Ok("Met"), 'F'=>Ok("Phe"), 'P'=>Ok("Pro"),
+        'S'=>Ok("Ser"), 'T'=>Ok("Thr"), 'W'=>Ok("Trp"), 'Y'=>Ok("Tyr"), 'V'=>Ok("Val"),
+        'U'=>Ok("Sec"), 'O'=>Ok("Pyl"), 'B'=>Ok("Asx"), 'Z'=>Ok("Glx"), 'J'=>Ok("Xle"),
+        'X'=>Ok("Xaa"), '*'=>Ok("Ter"),
+        _=>Err(format!("{} is not a valid amino acid code",amino_acid))
+    }
+}
+
+/// ## Summary
+/// Return the one letter code of a three letter amino acid code, i.e. the inverse of aa_to_three_letter, Ter is decoded as *
+/// ## Example
+///```rust
+/// use ppgg::functions::hgvs::three_letter_to_aa;
+/// assert_eq!(three_letter_to_aa("Arg").unwrap(),'R');
+/// assert_eq!(three_letter_to_aa("Ter").unwrap(),'*');
+/// assert!(three_letter_to_aa("Foo").is_err());
+///```
+pub fn three_letter_to_aa(code:&str)->Result<char,String>
+{
+    "ARNDCQEGHILKMFPSTWYVUOBZJX*".chars()
+        .find(|amino_acid|aa_to_three_letter(*amino_acid)==Ok(code))
+        .ok_or(format!("{} is not a valid three letter amino acid code",code))
+}
+
+/// ## Summary
+/// Format a mutation as an HGVS.p description, the reference protein of the transcript is optional, however, it is needed to name the
+/// residues flanking an insertion when they are not part of the protein change, and it is used to normalize deletions, insertions and
+/// duplications by shifting them to the most C-terminal position as required by HGVS. The following descriptions are generated:
+/// 1. substitutions, e.g. p.Arg263Gln, and nonsense changes, e.g. p.Arg263Ter, unchanged residues are described as p.Arg263=
+/// 2. deletions, e.g. p.Val600del or p.Val600_Lys601del, and deletion-insertions, e.g. p.Val600_Lys601delinsGlu
+/// 3. insertions, e.g. p.Val600_Lys601insSer, and duplications, e.g. p.Lys601dup
+/// 4. frameshifts, e.g. p.Arg97ProfsTer23, where the new stop codon is counted from the first changed residue, or p.Arg97ProfsTer? if
+///    the new stop codon is not part of the protein change
+/// 5. stop-loss extensions, e.g. p.Ter110GlnextTer17, where the new stop codon is counted from the former stop codon
+/// 6. start-loss, e.g. p.Met1?
+/// ## Example
+///```rust
+/// use ppgg::data_structures::mutation_ds::{MutationInfo,MutationType};
+/// use ppgg::functions::hgvs::format_hgvs_p;
+/// let missense=MutationInfo::new(263,263,"R".to_string(),"Q".to_string());
+/// assert_eq!(format_hgvs_p(&MutationType::MisSense,&missense,None).unwrap(),"p.Arg263Gln");
+/// let insertion=MutationInfo::new(600,600,"VK".to_string(),"VSK".to_string());
+/// assert_eq!(format_hgvs_p(&MutationType::InframeInsertion,&insertion,None).unwrap(),"p.Val600_Lys601insSer");
+///```
+pub fn format_hgvs_p(mut_type:&MutationType, mut_info:&MutationInfo, reference:Option<&str>)->Result<String,String>
+{
+    let position=mut_info.ref_aa_position as usize+1; // HGVS position are one-based
+    let ref_aa=get_residues(&mut_info.ref_aa);
+    let mut_aa=get_residues(&mut_info.mut_aa);
+    if ref_aa.is_empty()
+    {
+        return Err("The reference amino acids of the mutation are empty".to_string())
+    }
+    match mut_type
+    {
+        MutationType::StartLost | MutationType::StartLostAndSpliceRegion=>
+        {
+            Ok(format!("p.{}{}?",aa_to_three_letter(ref_aa[0])?,position))
+        },
+        MutationType::FrameShift | MutationType::SFrameShift | MutationType::FrameShiftAndStopRetained |
+        MutationType::SFrameShiftAndStopRetained | MutationType::StopLostAndFrameShift=>
+        {
+            format_frameshift(position, &ref_aa, &mut_aa)
+        },
+        // the alternative start codon of a start re-selection is not described by HGVS.p, which uses p.Met1? instead
+        MutationType::StartReselection=>Ok("p.Met1?".to_string()),
+        MutationType::StopLost | MutationType::StopReselection if ref_aa[0]=='*' =>
+        {
+            format_extension(position, &mut_aa)
+        },
+        _=>format_inframe(position, &ref_aa, &mut_aa, reference)
+    }
+}
+/// ## Summary
+/// Format a mutation as an HGVS.p description, see format_hgvs_p for more details
+pub fn mutation_to_hgvs_p(mutation:&Mutation, reference:Option<&str>)->Result<String,String>
+{
+    format_hgvs_p(&mutation.mut_type, &mutation.mut_info, reference)
+}
+/// ## Summary
+/// Collect the HGVS.p descriptions of the applied mutations in each haplotype of each proband, i.e. mutations that are translated into an
+/// instruction, of transcripts that are part of the reference. Mutations that can not be formatted are described as p.?, i.e. an unknown
+/// protein change. The changes are ordered by proband, haplotype, transcript and position.
+pub fn get_hgvs_changes(vec_int_repr:&[IntMap], ref_seq:&HashMap<String,String>, engine:Engine)->Vec<HgvsChange>
+{
+    match engine
+    {
+        Engine::ST=>vec_int_repr.iter().flat_map(|int_map|get_proband_changes(int_map,ref_seq)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_int_repr.par_iter().flat_map(|int_map|get_proband_changes(int_map,ref_seq)).collect::<Vec<_>>()
+    }
+}
+/// ## Summary
+/// Group the HGVS.p descriptions of a collection of changes into header tags per proband
+pub fn get_header_tags(changes:&[HgvsChange])->HashMap<String,HgvsHeaderTags>
+{
+    let mut header_tags:HashMap<String,HgvsHeaderTags>=HashMap::new();
+    for change in changes.iter()
+    {
+        let tags=header_tags.entry(change.proband_name.clone()).or_default()
+                    .entry(change.transcript_name.clone()).or_insert_with(||(String::new(),String::new()));
+        let tag=match change.haplotype
+        {
+            1=>&mut tags.0,
+            _=>&mut tags.1
+        };
+        if !tag.is_empty()
+        {
+            tag.push(',');
+        }
+        tag.push_str(&change.hgvs_p);
+    }
+    header_tags
+}
+fn get_proband_changes(int_map:&IntMap, ref_seq:&HashMap<String,String>)->Vec<HgvsChange>
+{
+    let mut changes=Vec::new();
+    let (mutations1,mutations2)=int_map.get_mutations_ref();
+    for (haplotype,mutations) in [(1_u8,mutations1),(2_u8,mutations2)].iter()
+    {
+        for alt_transcript in mutations.iter()
+        {
+            let reference=match ref_seq.get(&alt_transcript.name)
+            {
+                Some(sequence)=>sequence,
+                None=>continue
+            };
+            let mut alts=alt_transcript.alts.clone();
+            alts.sort_unstable_by_key(|mutation|mutation.mut_info.ref_aa_position);
+            for mutation in alts.iter().filter(|mutation|Instruction::from_mutation(mutation,&alts).map_or(false,|ins|ins.get_code()!='E'))
+            {
+                changes.push(HgvsChange{
+                    proband_name:int_map.get_name().clone(),
+                    haplotype:*haplotype,
+                    transcript_name:alt_transcript.name.clone(),
+                    consequence:format!("{:?}",mutation.mut_type),
+                    ref_position:mutation.mut_info.ref_aa_position as usize+1,
+                    hgvs_p:mutation_to_hgvs_p(mutation,Some(reference)).unwrap_or_else(|_|"p.?".to_string())
+                })
+            }
+        }
+    }
+    changes.sort_by(|change1,change2|(change1.haplotype,&change1.transcript_name,change1.ref_position)
+                                .cmp(&(change2.haplotype,&change2.transcript_name,change2.ref_position)));
+    changes
+}
+fn get_residues(mutated_string:&MutatedString)->Vec<char>
+{
+    match mutated_string
+    {
+        MutatedString::Sequence(seq) | MutatedString::EndSequence(seq)=>seq.chars().collect::<Vec<char>>(),
+        MutatedString::NotSeq=>vec!['*']
+    }
+}
+fn to_three_letters(residues:&[char])->Result<String,String>
+{
+    let mut res=String::with_capacity(residues.len()*3);
+    for amino_acid in residues.iter()
+    {
+        res.push_str(aa_to_three_letter(*amino_acid)?);
+    }
+    Ok(res)
+}
+/// ## Summary
+/// Format a range of residues, e.g. Val600_Lys601, or a single residue, e.g. Val600, where start is one-based
+fn format_range(residues:&[char], start:usize)->Result<String,String>
+{
+    match residues.len()
+    {
+        0=>Err("Can not format an empty range of residues".to_string()),
+        1=>Ok(format!("{}{}",aa_to_three_letter(residues[0])?,start)),
+        num=>Ok(format!("{}{}_{}{}",aa_to_three_letter(residues[0])?,start,aa_to_three_letter(residues[num-1])?,start+num-1))
+    }
+}
+fn format_frameshift(position:usize, ref_aa:&[char], mut_aa:&[char])->Result<String,String>
+{
+    let shift=ref_aa.iter().zip(mut_aa.iter()).take_while(|(ref_res,mut_res)|ref_res==mut_res).count();
+    if shift>=ref_aa.len()
+    {
+        return Err("The frameshift does not alter any reference amino acid".to_string())
+    }
+    let first_ref=aa_to_three_letter(ref_aa[shift])?;
+    if shift>=mut_aa.len()
+    {
+        return Ok(format!("p.{}{}fsTer?",first_ref,position+shift))
+    }
+    if mut_aa[shift]=='*'
+    {
+        return Ok(format!("p.{}{}Ter",first_ref,position+shift))
+    }
+    let new_stop=match mut_aa[shift..].iter().position(|res|*res=='*')
+    {
+        Some(idx)=>(idx+1).to_string(),
+        None=>"?".to_string()
+    };
+    Ok(format!("p.{}{}{}fsTer{}",first_ref,position+shift,aa_to_three_letter(mut_aa[shift])?,new_stop))
+}
+fn format_extension(position:usize, mut_aa:&[char])->Result<String,String>
+{
+    if mut_aa.is_empty() || mut_aa[0]=='*'
+    {
+        return Ok(format!("p.Ter{}=",position))
+    }
+    let new_stop=match mut_aa.iter().position(|res|*res=='*')
+    {
+        Some(idx)=>(idx+1).to_string(),
+        None=>"?".to_string()
+    };
+    Ok(format!("p.Ter{}{}extTer{}",position,aa_to_three_letter(mut_aa[0])?,new_stop))
+}
+fn format_inframe(position:usize, ref_aa:&[char], mut_aa:&[char], reference:Option<&str>)->Result<String,String>
+{
+    // residues following a new stop codon are not translated
+    let mut_aa=match mut_aa.iter().position(|res|*res=='*')
+    {
+        Some(idx)=>&mut_aa[..idx+1],
+        None=>mut_aa
+    };
+    let prefix=ref_aa.iter().zip(mut_aa.iter()).take_while(|(ref_res,mut_res)|ref_res==mut_res).count();
+    let max_suffix=ref_aa.len().min(mut_aa.len())-prefix;
+    let suffix=ref_aa.iter().rev().zip(mut_aa.iter().rev()).take(max_suffix).take_while(|(ref_res,mut_res)|ref_res==mut_res).count();
+    let deleted=&ref_aa[prefix..ref_aa.len()-suffix];
+    let inserted=&mut_aa[prefix..mut_aa.len()-suffix];
+    let start=position+prefix;
+    // the reference protein is only used if it agrees with the reference amino acids of the mutation
+    let reference=reference.map(|sequence|sequence.chars().collect::<Vec<char>>())
+                    .filter(|sequence|position+ref_aa.len()-1<=sequence.len() &&
+                        sequence[position-1..position-1+ref_aa.len()].iter().zip(ref_aa.iter()).all(|(res1,res2)|res1==res2 || *res2=='*'));
+    match (deleted.len(),inserted.len())
+    {
+        (0,0)=>Ok(format!("p.{}{}=",aa_to_three_letter(ref_aa[0])?,position)),
+        (1,1)=>Ok(format!("p.{}{}{}",aa_to_three_letter(deleted[0])?,start,aa_to_three_letter(inserted[0])?)),
+        (_,0)=>
+        {
+            let (deleted,start)=match reference.as_ref()
+            {
+                Some(sequence)=>shift_deletion(sequence, start-1, deleted.len()),
+                None=>(deleted.to_vec(),start)
+            };
+            Ok(format!("p.{}del",format_range(&deleted,start)?))
+        },
+        (_,_) if inserted[0]=='*' =>Ok(format!("p.{}{}Ter",aa_to_three_letter(deleted[0])?,start)),
+        (0,_)=>
+        {
+            match reference.as_ref()
+            {
+                Some(sequence)=>format_insertion(sequence, start-1, inserted),
+                None=>
+                {
+                    // without the reference the flanking residues must be part of the protein change
+                    if prefix==0 || suffix==0
+                    {
+                        return Err("The reference protein is needed to name the residues flanking the insertion".to_string())
+                    }
+                    Ok(format!("p.{}{}_{}{}ins{}",aa_to_three_letter(ref_aa[prefix-1])?,start-1,aa_to_three_letter(ref_aa[prefix])?,start,
+                                    to_three_letters(inserted)?))
+                }
+            }
+        },
+        (_,_)=>Ok(format!("p.{}delins{}",format_range(deleted,start)?,to_three_letters(inserted)?))
+    }
+}
+/// ## Summary
+/// Shift a deletion of len residues starting at the zero-based index start to the most C-terminal position, returns the deleted residues
+/// along with the one-based start of the shifted deletion
+fn shift_deletion(reference:&[char], mut start:usize, len:usize)->(Vec<char>,usize)
+{
+    while start+len<reference.len() && reference[start+len]==reference[start]
+    {
+        start+=1;
+    }
+    (reference[start..start+len].to_vec(),start+1)
+}
+/// ## Summary
+/// Format an insertion of residues before the zero-based index insert_pos of the reference, the insertion is shifted to the most C-terminal
+/// position and described as a duplication if it repeats the preceding residues
+fn format_insertion(reference:&[char], mut insert_pos:usize, inserted:&[char])->Result<String,String>
+{
+    let mut inserted=inserted.to_vec();
+    while insert_pos<reference.len() && reference[insert_pos]==inserted[0]
+    {
+        inserted.rotate_left(1);
+        insert_pos+=1;
+    }
+    if insert_pos>=inserted.len() && reference[insert_pos-inserted.len()..insert_pos]==inserted[..]
+    {
+        return Ok(format!("p.{}dup",format_range(&inserted,insert_pos-inserted.len()+1)?))
+    }
+    if insert_pos==0 || insert_pos>=reference.len()
+    {
+        return Err("The insertion is not flanked by two reference residues".to_string())
+    }
+    Ok(format!("p.{}{}_{}{}ins{}",aa_to_three_letter(reference[insert_pos-1])?,insert_pos,aa_to_three_letter(reference[insert_pos])?,
+                    insert_pos+1,to_three_letters(&inserted)?))
+}
+
+/// ## Summary
+/// Parse an HGVS.p description into the type and the amino acid change of a mutation, i.e. the inverse of format_hgvs_p, where the change
+/// is expressed like a consequence of bcftools csq, e.g. 263R>263Q, hence, the mutation can be translated into an instruction. Three and
+/// one letter amino acid codes are supported, e.g. p.Arg263Gln or p.R263Q, along with predicted changes, e.g. p.(Arg263Gln), and a
+/// leading protein accession, e.g. NP_004324.2:p.Val600Glu. The notations are parsed as follows:
+/// 1. substitutions, e.g. p.Arg263Gln, are missense mutations and nonsense changes, e.g. p.Arg263Ter or p.Arg263*, are stop-gained mutations
+/// 2. deletions, e.g. p.Val600_Lys601del, are anchored to the preceding residue of the reference protein, which is hence required
+/// 3. insertions, e.g. p.Lys2_Leu3insAla, are anchored to the first flanking residue and duplications, e.g. p.Lys601dup, are insertions of the duplicated residues
+/// 4. deletion-insertions, e.g. p.Cys28_Lys29delinsTrp, are inframe deletions, inframe insertions or missense mutations depending on their length change
+/// 5. frameshifts, e.g. p.Arg97ProfsTer23, and stop-loss extensions, e.g. p.Ter110GlnextTer17, whose residues following the first changed residue are unknown and encoded as X up to the new stop codon
+/// 6. start-loss, e.g. p.Met1?
+///
+/// The residues of a range beyond its first and last residue are read from the reference protein, which is optional otherwise, and the
+/// residues of the description are checked against it. The position of the change in the mutated sequence is set to its reference
+/// position, see AltTranscript::assign_mutated_positions to derive the positions of the mutations of a haplotype. Unchanged residues, e.g.
+/// p.Arg263=, unknown changes, e.g. p.?, frameshifts and extensions without a new stop codon, e.g. p.Arg97fs, and N-terminal extensions
+/// return an error.
+/// ## Example
+///```rust
+/// use ppgg::data_structures::mutation_ds::MutationType;
+/// use ppgg::functions::hgvs::parse_hgvs_p;
+/// let (mut_type,mut_info)=parse_hgvs_p("p.Arg263Gln",None).unwrap();
+/// assert_eq!((mut_type,mut_info.ref_aa_position),(MutationType::MisSense,262));
+/// // the deletion of K2 and L3 is anchored to M1 of the reference protein
+/// let (mut_type,mut_info)=parse_hgvs_p("p.Lys2_Leu3del",Some("MKLV")).unwrap();
+/// assert_eq!((mut_type,mut_info.ref_aa_position),(MutationType::InframeDeletion,0));
+/// assert!(parse_hgvs_p("p.Arg263=",None).is_err());
+///```
+pub fn parse_hgvs_p(hgvs:&str, reference:Option<&str>)->Result<(MutationType,MutationInfo),String>
+{
+    let fail=|reason:&str|Err(format!("The HGVS.p description: {} {}",hgvs,reason));
+    let description=hgvs.rsplit(':').next().unwrap().trim();
+    let description=match description.strip_prefix("p.")
+    {
+        Some(description)=>description,
+        None=>return fail("does not start with p.")
+    };
+    let description=description.strip_prefix('(').and_then(|description|description.strip_suffix(')')).unwrap_or(description);
+    let reference=reference.map(|sequence|sequence.chars().collect::<Vec<char>>());
+    let (start,end,change)=match parse_location(description)
+    {
+        Ok(location)=>location,
+        Err(err_msg)=>return fail(&err_msg)
+    };
+    // check the residues of the location against the reference protein
+    if let Some(sequence)=reference.as_ref()
+    {
+        for (residue,position) in [Some(start),end].iter().flatten()
+        {
+            let matches=match sequence.get(position-1)
+            {
+                Some(ref_residue)=>ref_residue==residue,
+                // the stop codon follows the last residue of the reference protein
+                None=>*residue=='*' && *position==sequence.len()+1
+            };
+            if !matches
+            {
+                return fail(&format!("does not match the reference protein at position {}",position))
+            }
+        }
+    }
+    let (end_residue,end_position)=end.unwrap_or(start);
+    if end_position<start.1
+    {
+        return fail("has a range whose end precedes its start")
+    }
+    let range=match (reference.as_ref(),end_position-start.1)
+    {
+        (_,0)=>vec![start.0],
+        (_,1)=>vec![start.0,end_residue],
+        (Some(sequence),_) if end_position<=sequence.len()=>sequence[start.1-1..end_position].to_vec(),
+        _=>return fail("has a range of more than two residues, whose residues need the reference protein")
+    };
+    let range=range.into_iter().collect::<String>();
+    let position=start.1;
+    let (mut_type,aa_change)=if change=="="
+    {
+        return fail("does not alter the protein")
+    }
+    else if change=="?"
+    {
+        match (start,end)
+        {
+            (('M',1),None)=>(MutationType::StartLost,"1M>1M".to_string()),
+            _=>return fail("describes an unknown consequence, only p.Met1? is supported")
+        }
+    }
+    else if change=="del"
+    {
+        let anchor=match reference.as_ref().and_then(|sequence|sequence.get(position.wrapping_sub(2)))
+        {
+            Some(anchor) if position>1=>*anchor,
+            _=>return fail("is a deletion, which needs the preceding residue of the reference protein as an anchor")
+        };
+        (MutationType::InframeDeletion,format!("{}{}{}>{}{}",position-1,anchor,range,position-1,anchor))
+    }
+    else if change=="dup"
+    {
+        (MutationType::InframeInsertion,format!("{}{}>{}{}{}",end_position,end_residue,end_position,end_residue,range))
+    }
+    else if let Some(inserted)=change.strip_prefix("delins")
+    {
+        let inserted=match parse_residues(inserted)
+        {
+            Ok(inserted) if !inserted.is_empty()=>inserted,
+            _=>return fail("does not describe valid inserted residues")
+        };
+        let mut_type=match (inserted.starts_with('*'),inserted.len().cmp(&range.len()))
+        {
+            (true,_)=>MutationType::StopGained,
+            (false,std::cmp::Ordering::Less)=>MutationType::InframeDeletion,
+            (false,std::cmp::Ordering::Greater)=>MutationType::InframeInsertion,
+            (false,std::cmp::Ordering::Equal)=>MutationType::MisSense
+        };
+        let inserted=match mut_type
+        {
+            MutationType::StopGained=>"*".to_string(),
+            _=>inserted
+        };
+        (mut_type,format!("{}{}>{}{}",position,range,position,inserted))
+    }
+    else if let Some(inserted)=change.strip_prefix("ins")
+    {
+        match (end,parse_residues(inserted))
+        {
+            (Some(_),Ok(inserted)) if end_position==position+1 && !inserted.is_empty()=>
+                (MutationType::InframeInsertion,format!("{}{}>{}{}{}",position,start.0,position,start.0,inserted)),
+            _=>return fail("is not an insertion of valid residues between two adjacent residues")
+        }
+    }
+    else if end.is_some()
+    {
+        return fail("has a range, which is only supported for deletions, insertions, deletion-insertions and duplications")
+    }
+    else if let Some((new_residue,new_stop))=split_new_stop(change,"fs")
+    {
+        match (parse_residues(new_residue).as_deref(),new_stop)
+        {
+            (Ok(new_residue),Some(new_stop)) if new_residue.len()==1 && new_stop>=2=>
+                (MutationType::FrameShift,format!("{}{}>{}{}{}*",position,start.0,position,new_residue,"X".repeat(new_stop-2))),
+            _=>return fail("is not a frameshift with a new residue and a new stop codon, e.g. p.Arg97ProfsTer23")
+        }
+    }
+    else if let Some((new_residue,new_stop))=split_new_stop(change,"ext")
+    {
+        match (start.0,parse_residues(new_residue).as_deref(),new_stop)
+        {
+            ('*',Ok(new_residue),Some(new_stop)) if new_residue.len()==1 && new_stop>=2=>
+                (MutationType::StopLost,format!("{}*>{}{}{}*",position,position,new_residue,"X".repeat(new_stop-2))),
+            _=>return fail("is not a stop-loss extension with a new residue and a new stop codon, e.g. p.Ter110GlnextTer17")
+        }
+    }
+    else
+    {
+        match (start.0,parse_residues(change).as_deref())
+        {
+            ('*',_)=>return fail("alters the stop codon without describing its extension"),
+            (_,Ok("*"))=>(MutationType::StopGained,format!("{}{}>{}*",position,start.0,position)),
+            (_,Ok(residue)) if residue.len()==1=>(MutationType::MisSense,format!("{}{}>{}{}",position,start.0,position,residue)),
+            _=>return fail("is not a supported protein change")
+        }
+    };
+    match text_parser::parse_amino_acid_field(&aa_change)
+    {
+        Ok(mut_info)=>Ok((mut_type,mut_info)),
+        Err(err_msg)=>fail(&format!("could not be converted into the amino acid change: {}, {}",aa_change,err_msg))
+    }
+}
+/// ## Summary
+/// Parse an HGVS.p description into a mutation of the provided transcript, see parse_hgvs_p for more details
+pub fn hgvs_p_to_mutation(hgvs:&str, transcript_name:Arc<str>, reference:Option<&str>)->Result<Mutation,String>
+{
+    let (mut_type,mut_info)=parse_hgvs_p(hgvs, reference)?;
+    Ok(Mutation::from_info(mut_type, transcript_name, mut_info))
+}
+/// ## Summary
+/// Parse the first residue of a description, either as a three letter code or as a one letter code, along with the length of its code
+fn parse_residue(description:&str)->Option<(char,usize)>
+{
+    if let Some(residue)=description.get(..3).and_then(|code|three_letter_to_aa(code).ok())
+    {
+        return Some((residue,3))
+    }
+    match description.chars().next()
+    {
+        Some(residue) if residue=='*' || (residue.is_ascii_uppercase() && aa_to_three_letter(residue).is_ok())=>Some((residue,1)),
+        _=>None
+    }
+}
+/// ## Summary
+/// Parse a sequence of residues, e.g. GlnLysTer or QK*, into their one letter codes
+fn parse_residues(description:&str)->Result<String,String>
+{
+    let mut residues=String::with_capacity(description.len());
+    let mut rest=description;
+    while !rest.is_empty()
+    {
+        match parse_residue(rest)
+        {
+            Some((residue,len))=>
+            {
+                residues.push(residue);
+                rest=&rest[len..];
+            },
+            None=>return Err(format!("{} is not a valid sequence of amino acids",description))
+        }
+    }
+    Ok(residues)
+}
+/// ## Summary
+/// Parse the location of a description, i.e. a residue and its one-based position, e.g. Arg263, optionally followed by the end of a range,
+/// e.g. Arg263_Lys265, and return the location along with the rest of the description, i.e. the change
+fn parse_location(description:&str)->Result<HgvsLocation<'_>,String>
+{
+    let parse_position=|description:&str|->Result<((char,usize),usize),String>
+    {
+        let (residue,len)=parse_residue(description).ok_or("does not start with a valid amino acid".to_string())?;
+        let num_digits=description[len..].chars().take_while(|digit|digit.is_ascii_digit()).count();
+        match description[len..len+num_digits].parse::<usize>()
+        {
+            Ok(position) if position!=0=>Ok(((residue,position),len+num_digits)),
+            _=>Err("does not have a valid one-based position".to_string())
+        }
+    };
+    let (start,len)=parse_position(description)?;
+    match description[len..].strip_prefix('_')
+    {
+        Some(rest)=>
+        {
+            let (end,end_len)=parse_position(rest)?;
+            Ok((start,Some(end),&rest[end_len..]))
+        },
+        None=>Ok((start,None,&description[len..]))
+    }
+}
+/// ## Summary
+/// Split a frameshift or an extension change, e.g. ProfsTer23 or Glnext*17, at the provided keyword into the new residue and the
+/// position of the new stop codon, which is None if it is unknown, e.g. ProfsTer?, or None if the change does not contain the keyword
+fn split_new_stop<'a>(change:&'a str, keyword:&str)->Option<(&'a str,Option<usize>)>
+{
+    let (new_residue,new_stop)=change.split_once(keyword)?;
+    let new_stop=new_stop.strip_prefix("Ter").or_else(||new_stop.strip_prefix('*')).and_then(|new_stop|new_stop.parse::<usize>().ok());
+    Some((new_residue,new_stop))
+}
+
+#[cfg(test)]
+mod test_hgvs
+{
+    use super::*;
+    fn format(mut_type:MutationType, change:&str, reference:Option<&str>)->String
+    {
+        let mut_info=crate::functions::text_parser::parse_amino_acid_field(change).unwrap();
+        format_hgvs_p(&mut_type,&mut_info,reference).unwrap()
+    }
+    #[test]
+    fn test_substitutions()
+    {
+        assert_eq!(format(MutationType::MisSense,"263R>263Q",None),"p.Arg263Gln");
+        assert_eq!(format(MutationType::SMisSense,"5G>5H",None),"p.Gly5His");
+        assert_eq!(format(MutationType::StopGained,"263R>263*",None),"p.Arg263Ter");
+        assert_eq!(format(MutationType::StopGained,"263RL>263*",None),"p.Arg263Ter");
+        assert_eq!(format(MutationType::MisSense,"10VL>10HK",None),"p.Val10_Leu11delinsHisLys");
+    }
+    #[test]
+    fn test_deletions_and_insertions()
+    {
+        let reference="MEDLGENTMVLSTLRSLNN";
+        assert_eq!(format(MutationType::InframeDeletion,"10VLSTLR>10V",None),"p.Leu11_Arg15del");
+        assert_eq!(format(MutationType::InframeDeletion,"10VLSTLR>10R",None),"p.Val10_Leu14del");
+        assert_eq!(format(MutationType::InframeInsertion,"600VK>600VSK",None),"p.Val600_Lys601insSer");
+        assert_eq!(format(MutationType::InframeInsertion,"5G>5GTEST",Some(reference)),"p.Gly5_Glu6insThrGluSerThr");
+        // inserting another E after E6 is a duplication
+        assert_eq!(format(MutationType::InframeInsertion,"6E>6EE",Some(reference)),"p.Glu6dup");
+        // deleting one N of N18 and N19 is shifted to the most C-terminal position, i.e. N19
+        assert_eq!(format(MutationType::InframeDeletion,"17LN>17L",Some(reference)),"p.Asn19del");
+        assert_eq!(format(MutationType::InframeDeletion,"17LN>17L",None),"p.Asn18del");
+        assert!(format_hgvs_p(&MutationType::InframeInsertion,&MutationInfo::new(5,5,"G".to_string(),"GTEST".to_string()),None).is_err());
+    }
+    #[test]
+    fn test_frameshifts_and_extensions()
+    {
+        assert_eq!(format(MutationType::FrameShift,"97R>97PKLMS*",None),"p.Arg97ProfsTer6");
+        assert_eq!(format(MutationType::FrameShift,"97RL>97RKL",None),"p.Leu98LysfsTer?");
+        assert_eq!(format(MutationType::FrameShift,"97R>97*",None),"p.Arg97Ter");
+        assert_eq!(format(MutationType::StopLost,"110*>110QLMK*",None),"p.Ter110GlnextTer5");
+        assert_eq!(format(MutationType::StartLost,"1M>1T",None),"p.Met1?");
+    }
+    #[test]
+    fn test_parse_hgvs_p()
+    {
+        let reference="MEDLGENTMVLSTLRSLNN";
+        let parse=|hgvs:&str,reference:Option<&str>|
+        {
+            let (mut_type,mut_info)=parse_hgvs_p(hgvs,reference).unwrap();
+            let hgvs=format_hgvs_p(&mut_type,&mut_info,reference).unwrap();
+            (mut_type,hgvs)
+        };
+        // the parsed mutations are formatted back into the same descriptions
+        assert_eq!(parse("p.Arg263Gln",None),(MutationType::MisSense,"p.Arg263Gln".to_string()));
+        assert_eq!(parse("ENSP00000288602:p.(R263*)",None),(MutationType::StopGained,"p.Arg263Ter".to_string()));
+        assert_eq!(parse("p.Leu11_Arg15del",Some(reference)),(MutationType::InframeDeletion,"p.Leu11_Arg15del".to_string()));
+        assert_eq!(parse("p.Gly5_Glu6insThrGluSerThr",Some(reference)),(MutationType::InframeInsertion,"p.Gly5_Glu6insThrGluSerThr".to_string()));
+        assert_eq!(parse("p.Glu6dup",Some(reference)),(MutationType::InframeInsertion,"p.Glu6dup".to_string()));
+        assert_eq!(parse("p.Val10_Leu11delinsHisLys",None),(MutationType::MisSense,"p.Val10_Leu11delinsHisLys".to_string()));
+        assert_eq!(parse("p.Val10_Thr13delinsHis",Some(reference)),(MutationType::InframeDeletion,"p.Val10_Thr13delinsHis".to_string()));
+        assert_eq!(parse("p.Arg97ProfsTer6",None),(MutationType::FrameShift,"p.Arg97ProfsTer6".to_string()));
+        assert_eq!(parse("p.*110Glnext*5",None),(MutationType::StopLost,"p.Ter110GlnextTer5".to_string()));
+        assert_eq!(parse("p.Met1?",None),(MutationType::StartLost,"p.Met1?".to_string()));
+        // the residues of the frameshifted sequence are unknown
+        assert_eq!(parse_hgvs_p("p.Arg97ProfsTer6",None).unwrap().1.mut_aa,MutatedString::EndSequence("PXXXX*".to_string()));
+        for unsupported in ["p.Arg263=","p.?","p.Arg97fs","p.Val10del","p.Val10_Thr13delinsHis","p.Met1ext-5","c.263G>A"].iter()
+        {
+            assert!(parse_hgvs_p(unsupported,None).is_err(),"{}",unsupported);
+        }
+        // the residues must match the reference protein
+        assert!(parse_hgvs_p("p.Arg5Gln",Some(reference)).is_err());
+    }
+    #[test]
+    fn test_get_hgvs_changes()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript;
+        let alt_transcript=AltTranscript::new("T1".to_string(),vec![
+                "*missense|G1|T1|protein_coding|-|10V>10H|1936821C>T".to_string(),
+                "*missense|G1|T1|protein_coding|-|5G>5H|1936821C>T".to_string()]);
+        let unknown=AltTranscript::new("T2".to_string(),vec!["*missense|G2|T2|protein_coding|-|5G>5H|1936821C>T".to_string()]);
+        let int_map=IntMap::new("sample1".to_string(),vec![alt_transcript,unknown],Vec::new());
+        let mut ref_seq=HashMap::new();
+        ref_seq.insert("T1".to_string(),"MEDLGENTMVLSTLRSLNN".to_string());
+        let changes=get_hgvs_changes(&[int_map],&ref_seq,Engine::ST);
+        assert_eq!(changes.iter().map(|change|change.hgvs_p.as_str()).collect::<Vec<_>>(),vec!["p.Gly5His","p.Val10His"]);
+        let header_tags=get_header_tags(&changes);
+        assert_eq!(header_tags["sample1"]["T1"],("p.Gly5His,p.Val10His".to_string(),String::new()));
+    }
+}
@@ -0,0 +1,479 @@
+/// The module supports incremental runs on re-called cohorts, a digest of the variant set of each sample is stored in a run manifest
+/// next to the generated files, and in the next run, only the samples whose digest changed are regenerated while the files of the other
+/// samples are reused. The progress of a run is recorded as well, i.e. every sample is added to a progress file once its files are written,
+/// so a run that is interrupted can be resumed without writing the completed samples again
+use std::collections::{HashMap,HashSet};
+use std::fmt::Write;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::Map::IntMap;
+use crate::writers::sanitize_file_stems;
+
+/// ## Summary
+/// The offset basis and the prime of the 64-bit FNV-1a hash, which, unlike the default hasher of the standard library, is stable across
+/// platforms and compiler versions, hence, digests of different runs can be compared
+const FNV_OFFSET_BASIS:u64=0xcbf2_9ce4_8422_2325;
+const FNV_PRIME:u64=0x0000_0100_0000_01b3;
+/// ## Summary
+/// The prefix of the manifest line holding the digest of the run settings
+const SETTINGS_DIGEST_PREFIX:&str="#settings_digest=";
+
+/// ## Summary
+/// Update a FNV-1a digest with the provided bytes
+fn update_digest(mut digest:u64, bytes:&[u8])->u64
+{
+    for byte in bytes.iter()
+    {
+        digest^=*byte as u64;
+        digest=digest.wrapping_mul(FNV_PRIME);
+    }
+    digest
+}
+
+/// ## Summary
+/// Return the digest of the provided bytes, e.g. the content of a generated file, as a hexadecimal string
+pub fn get_bytes_digest(bytes:&[u8])->String
+{
+    format!("{:016x}",update_digest(FNV_OFFSET_BASIS,bytes))
+}
+
+/// ## Summary
+/// Return the size in bytes and the digest of the raw content of a file, e.g. an input of the run, as returned by get_bytes_digest for the
+/// whole content, the file is read in chunks, hence, large VCF files are not loaded into memory
+/// ## Example
+///```rust
+/// use ppgg::functions::incremental::{get_bytes_digest,get_file_digest};
+/// let path2file=std::env::temp_dir().join("doc_file_digest.txt");
+/// std::fs::write(&path2file,"MKLV").unwrap();
+/// assert_eq!(get_file_digest(&path2file).unwrap(),(4,get_bytes_digest(b"MKLV")));
+///```
+pub fn get_file_digest(path2file:&Path)->Result<(u64,String),String>
+{
+    let mut file=match File::open(path2file)
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Opening the file: {} for computing its digest failed with the following error: {}",path2file.display(),err_msg))
+    };
+    let (mut digest,mut num_bytes)=(FNV_OFFSET_BASIS,0); 
+    let mut buffer=vec![0_u8;1<<16];
+    loop
+    {
+        match file.read(&mut buffer)
+        {
+            Ok(0)=>return Ok((num_bytes,format!("{:016x}",digest))),
+            Ok(num_read)=>
+            {
+                digest=update_digest(digest,&buffer[..num_read]);
+                num_bytes+=num_read as u64;
+            },
+            Err(err_msg) if err_msg.kind()==std::io::ErrorKind::Interrupted=>(),
+            Err(err_msg)=>return Err(format!("Reading the file: {} for computing its digest failed with the following error: {}",path2file.display(),err_msg))
+        }
+    }
+}
+
+/// ## Summary
+/// Return the digest of the variant set of a sample, i.e. of the altered transcripts of each haplotype along with their mutations, as a
+/// hexadecimal string, the digest does not depend on the order of the transcripts
+pub fn get_variant_digest(int_map:&IntMap)->String
+{
+    let (mutations1,mutations2)=int_map.get_mutations_ref();
+    let mut digest=FNV_OFFSET_BASIS;
+    for (haplotype,mutations) in [(1_u8,mutations1),(2_u8,mutations2)].iter()
+    {
+        let mut transcripts=mutations.iter().map(|transcript|serde_json::to_string(transcript).unwrap()).collect::<Vec<String>>();
+        transcripts.sort();
+        digest=update_digest(digest,&[*haplotype]);
+        for transcript in transcripts.iter()
+        {
+            digest=update_digest(digest,transcript.as_bytes());
+            digest=update_digest(digest,b"\n");
+        }
+    }
+    format!("{:016x}",digest)
+}
+
+/// ## Summary
+/// Return the digest of the settings of a run, i.e. the provided description of the options that change the generated files, the reference
+/// sequences, the custom records and the version of the library, a sample can only be reused if the settings of both runs are identical
+pub fn get_settings_digest(settings:&str, ref_seq:&HashMap<String,String>, custom_records:&[(String,String)])->String
+{
+    let mut digest=update_digest(FNV_OFFSET_BASIS,env!("CARGO_PKG_VERSION").as_bytes());
+    digest=update_digest(digest,settings.as_bytes());
+    let mut ids=ref_seq.keys().collect::<Vec<&String>>();
+    ids.sort();
+    for id in ids
+    {
+        digest=update_digest(digest,id.as_bytes());
+        digest=update_digest(digest,ref_seq[id].as_bytes());
+    }
+    for (id,seq) in custom_records.iter()
+    {
+        digest=update_digest(digest,id.as_bytes());
+        digest=update_digest(digest,seq.as_bytes());
+    }
+    format!("{:016x}",digest)
+}
+
+/// ## Summary
+/// The manifest entry of a sample, i.e. its name, the stem of its files, see writers::sanitize_file_stems, the digest of its variants,
+/// whether writing its files failed even after retrying, see writers::RetryPolicy, and whether its fasta file was truncated because it 
+/// exceeded the output quota, see writers::OutputQuota
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct ManifestEntry
+{
+    pub proband_name:String,
+    pub file_stem:String,
+    pub variant_digest:String,
+    pub is_failed:bool,
+    pub is_truncated:bool
+}
+
+/// ## Summary
+/// The write status of a manifest entry as used in the manifest table
+const WRITTEN_STATUS:&str="written";
+const FAILED_STATUS:&str="failed";
+const TRUNCATED_STATUS:&str="truncated";
+
+/// ## Summary
+/// The manifest of a run, i.e. the digest of its settings and an entry per sample, in the order of the VCF header
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct RunManifest
+{
+    pub settings_digest:String,
+    pub entries:Vec<ManifestEntry>
+}
+impl RunManifest
+{
+    /// ## Summary
+    /// Create the manifest of a run from the intermediate representation of each sample, the file stems are unique within the cohort
+    pub fn new(settings_digest:String, int_maps:&[IntMap], engine:Engine)->Self
+    {
+        let digests=match engine
+        {
+            Engine::ST=>int_maps.iter().map(get_variant_digest).collect::<Vec<String>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>int_maps.par_iter().map(get_variant_digest).collect::<Vec<String>>()
+        };
+        let file_stems=sanitize_file_stems(&int_maps.iter().map(|int_map|int_map.get_name().as_str()).collect::<Vec<&str>>());
+        let entries=int_maps.iter().zip(file_stems).zip(digests)
+            .map(|((int_map,file_stem),variant_digest)|ManifestEntry{proband_name:int_map.get_name().clone(),file_stem,variant_digest,
+                is_failed:false,is_truncated:false})
+            .collect::<Vec<ManifestEntry>>();
+        RunManifest{settings_digest,entries}
+    }
+    /// ## Summary
+    /// Parse a manifest from its tab-separated representation, see to_table, an error is returned if the settings digest is missing or
+    /// if a line does not have three or four columns, where the write status in the fourth column is optional and defaults to written, 
+    /// the other write statuses are failed and truncated
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::incremental::RunManifest;
+    /// let manifest=RunManifest::from_table("#settings_digest=00ff\nProband Name\tFile Name\tVariant Digest\nS1\tS1\t0a0b\n").unwrap();
+    /// assert_eq!((manifest.settings_digest.as_str(),manifest.entries[0].variant_digest.as_str()),("00ff","0a0b"));
+    /// assert_eq!(RunManifest::from_table(&manifest.to_table()).unwrap(),manifest);
+    /// assert!(RunManifest::from_table("S1\tS1\t0a0b\n").is_err());
+    /// let manifest=RunManifest::from_table("#settings_digest=00ff\nProband Name\tFile Name\tVariant Digest\tWrite Status\nS1\tS1\t0a0b\tfailed\n").unwrap();
+    /// assert_eq!(manifest.get_failed_samples(),vec!["S1".to_string()]);
+    /// let manifest=RunManifest::from_table("#settings_digest=00ff\nProband Name\tFile Name\tVariant Digest\tWrite Status\nS1\tS1\t0a0b\ttruncated\n").unwrap();
+    /// assert_eq!((manifest.get_truncated_samples(),manifest.get_failed_samples().len()),(vec!["S1".to_string()],0));
+    ///```
+    pub fn from_table(table:&str)->Result<Self,String>
+    {
+        let mut lines=table.lines();
+        let settings_digest=match lines.next().and_then(|line|line.strip_prefix(SETTINGS_DIGEST_PREFIX))
+        {
+            Some(digest)=>digest.to_string(),
+            None=>return Err(format!("The run manifest does not start with the digest of the run settings, i.e. a line starting with {}",SETTINGS_DIGEST_PREFIX))
+        };
+        let mut entries=Vec::new();
+        for line in lines.skip(1).filter(|line|!line.is_empty())
+        {
+            let (proband_name,file_stem,variant_digest,is_failed,is_truncated)=match line.split('\t').collect::<Vec<&str>>()[..]
+            {
+                [proband_name,file_stem,variant_digest]=>(proband_name,file_stem,variant_digest,false,false),
+                [proband_name,file_stem,variant_digest,WRITTEN_STATUS]=>(proband_name,file_stem,variant_digest,false,false),
+                [proband_name,file_stem,variant_digest,FAILED_STATUS]=>(proband_name,file_stem,variant_digest,true,false),
+                [proband_name,file_stem,variant_digest,TRUNCATED_STATUS]=>(proband_name,file_stem,variant_digest,false,true),
+                _=>return Err(format!("The line: {} of the run manifest does not have three tab-separated columns followed by an optional write status, \
+                    i.e. {}, {} or {}",line,WRITTEN_STATUS,FAILED_STATUS,TRUNCATED_STATUS))
+            };
+            entries.push(ManifestEntry{proband_name:proband_name.to_string(),file_stem:file_stem.to_string(),
+                variant_digest:variant_digest.to_string(),is_failed,is_truncated});
+        }
+        Ok(RunManifest{settings_digest,entries})
+    }
+    /// ## Summary
+    /// Return the tab-separated representation of the manifest, i.e. the settings digest followed by a header and a line per sample
+    pub fn to_table(&self)->String
+    {
+        let mut table=String::new();
+        writeln!(table,"{}{}",SETTINGS_DIGEST_PREFIX,self.settings_digest).unwrap();
+        writeln!(table,"Proband Name\tFile Name\tVariant Digest\tWrite Status").unwrap();
+        for entry in self.entries.iter()
+        {
+            let status=match (entry.is_failed,entry.is_truncated)
+            {
+                (true,_)=>FAILED_STATUS,
+                (false,true)=>TRUNCATED_STATUS,
+                (false,false)=>WRITTEN_STATUS
+            };
+            writeln!(table,"{}\t{}\t{}\t{}",entry.proband_name,entry.file_stem,entry.variant_digest,status).unwrap();
+        }
+        table
+    }
+    /// ## Summary
+    /// Return the names of the samples whose files could not be written, in the order of the manifest
+    pub fn get_failed_samples(&self)->Vec<String>
+    {
+        self.entries.iter().filter(|entry|entry.is_failed).map(|entry|entry.proband_name.clone()).collect()
+    }
+    /// ## Summary
+    /// Set the write status of every sample, the provided samples are marked as failed and every other sample as written
+    pub fn set_failed_samples(&mut self, failed_samples:&HashSet<String>)
+    {
+        self.entries.iter_mut().for_each(|entry|entry.is_failed=failed_samples.contains(&entry.proband_name));
+    }
+    /// ## Summary
+    /// Return the names of the samples whose fasta files were truncated by the output quota, in the order of the manifest
+    pub fn get_truncated_samples(&self)->Vec<String>
+    {
+        self.entries.iter().filter(|entry|entry.is_truncated).map(|entry|entry.proband_name.clone()).collect()
+    }
+    /// ## Summary
+    /// Mark the provided samples as truncated, the other samples keep their status, e.g. the truncated samples of a previous run that are not
+    /// written again by --resume, a sample that is marked as failed as well is listed as failed 
+    pub fn set_truncated_samples(&mut self, truncated_samples:&HashSet<String>)
+    {
+        self.entries.iter_mut().filter(|entry|truncated_samples.contains(&entry.proband_name)).for_each(|entry|entry.is_truncated=true);
+    }
+    /// ## Summary
+    /// Replace the entries of the provided samples with their entries in the current manifest, which is used when the failed samples of a
+    /// previous run are written again, i.e. by --resume, samples that are not part of the current manifest are kept as they are
+    pub fn update_entries(&mut self, current:&RunManifest, proband_names:&HashSet<String>)
+    {
+        let current_entries=current.entries.iter().map(|entry|(&entry.proband_name,entry)).collect::<HashMap<&String,&ManifestEntry>>();
+        for entry in self.entries.iter_mut().filter(|entry|proband_names.contains(&entry.proband_name))
+        {
+            if let Some(current_entry)=current_entries.get(&entry.proband_name)
+            {
+                *entry=(*current_entry).clone();
+            }
+        }
+    }
+    /// ## Summary
+    /// Return the names of the samples whose files from the previous run can be reused, i.e. the settings of both runs are identical and
+    /// the sample has the same file stem and variant digest in both manifests and its files were completely written by the previous run, i.e.
+    /// they neither failed nor were truncated, while 
+    /// is_output_present, which is called with the file stem, returns whether the files of the sample are still present
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::incremental::{RunManifest,ManifestEntry};
+    /// let entry=|name:&str,digest:&str|ManifestEntry{proband_name:name.to_string(),file_stem:name.to_string(),variant_digest:digest.to_string(),is_failed:false,is_truncated:false};
+    /// let previous=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1","aa"),entry("S2","bb"),entry("S3","cc")]};
+    /// let current=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1","aa"),entry("S2","b2"),entry("S3","cc"),entry("S4","dd")]};
+    /// let reusable=current.get_reusable_samples(&previous,|file_stem|file_stem!="S3");
+    /// assert_eq!(reusable.into_iter().collect::<Vec<String>>(),vec!["S1".to_string()]);
+    /// let current=RunManifest{settings_digest:"02".to_string(),..current};
+    /// assert!(current.get_reusable_samples(&previous,|_|true).is_empty());
+    ///```
+    pub fn get_reusable_samples<F:Fn(&str)->bool>(&self, previous:&RunManifest, is_output_present:F)->HashSet<String>
+    {
+        if self.settings_digest!=previous.settings_digest
+        {
+            return HashSet::new()
+        }
+        let previous_entries=previous.entries.iter().map(|entry|(&entry.proband_name,entry)).collect::<HashMap<&String,&ManifestEntry>>();
+        self.entries.iter()
+            .filter(|entry|matches!(previous_entries.get(&entry.proband_name),
+                Some(previous_entry) if !previous_entry.is_failed && !previous_entry.is_truncated && previous_entry.file_stem==entry.file_stem && previous_entry.variant_digest==entry.variant_digest))
+            .filter(|entry|is_output_present(&entry.file_stem))
+            .map(|entry|entry.proband_name.clone())
+            .collect::<HashSet<String>>()
+    }
+    /// ## Summary
+    /// Return the progress of a run whose provided samples are already complete, e.g. the samples reused by --incremental, which is the
+    /// starting point of the progress file the writers extend with every written sample, see writers::start_run_progress
+    pub fn get_progress(&self, completed_samples:&HashSet<String>)->RunProgress
+    {
+        let entries=self.entries.iter().filter(|entry|completed_samples.contains(&entry.proband_name))
+            .map(|entry|ProgressEntry{proband_name:entry.proband_name.clone(),file_stem:entry.file_stem.clone(),is_truncated:entry.is_truncated})
+            .collect::<Vec<ProgressEntry>>();
+        RunProgress{settings_digest:self.settings_digest.clone(),entries}
+    }
+}
+
+/// ## Summary
+/// A sample whose files were completely written by a run, i.e. its name, the stem of its files and whether its fasta file was truncated by
+/// the output quota, see RunProgress
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct ProgressEntry
+{
+    pub proband_name:String,
+    pub file_stem:String,
+    pub is_truncated:bool
+}
+impl ProgressEntry
+{
+    /// ## Summary
+    /// Return the line of the entry in the progress file, see RunProgress::from_table
+    pub fn to_line(&self)->String
+    {
+        format!("{}\t{}\t{}\n",self.proband_name,self.file_stem,if self.is_truncated {TRUNCATED_STATUS} else {WRITTEN_STATUS})
+    }
+}
+
+/// ## Summary
+/// The progress of a run that is still writing or was interrupted, i.e. the digest of its settings and the samples whose files were
+/// completely written so far, in the order they were written. Unlike the run manifest, which is written once all samples are done, the
+/// progress is extended after every sample, hence, a run that dies midway can be resumed with the samples that are still missing
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct RunProgress
+{
+    pub settings_digest:String,
+    pub entries:Vec<ProgressEntry>
+}
+impl RunProgress
+{
+    /// ## Summary
+    /// Parse the progress from its tab-separated representation, see to_table, a last line without a line break, i.e. a line whose writing
+    /// was interrupted, is ignored, while an error is returned if the settings digest is missing or if a complete line does not have three
+    /// columns whose write status is written or truncated
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::incremental::RunProgress;
+    /// let progress=RunProgress::from_table("#settings_digest=00ff\nProband Name\tFile Name\tWrite Status\nS1\tS1\twritten\nS2\tS2").unwrap();
+    /// assert_eq!((progress.entries.len(),progress.entries[0].file_stem.as_str()),(1,"S1"));
+    /// assert_eq!(RunProgress::from_table(&progress.to_table()).unwrap(),progress);
+    /// assert!(RunProgress::from_table("#settings_digest=00ff\nProband Name\tFile Name\tWrite Status\nS1\tS1\tfailed\n").is_err());
+    ///```
+    pub fn from_table(table:&str)->Result<Self,String>
+    {
+        let mut lines=table.split_inclusive('\n');
+        let settings_digest=match lines.next().and_then(|line|line.trim_end().strip_prefix(SETTINGS_DIGEST_PREFIX))
+        {
+            Some(digest)=>digest.to_string(),
+            None=>return Err(format!("The run progress does not start with the digest of the run settings, i.e. a line starting with {}",SETTINGS_DIGEST_PREFIX))
+        };
+        let mut entries=Vec::new();
+        for line in lines.skip(1).filter_map(|line|line.strip_suffix('\n')).filter(|line|!line.is_empty())
+        {
+            let (proband_name,file_stem,is_truncated)=match line.split('\t').collect::<Vec<&str>>()[..]
+            {
+                [proband_name,file_stem,WRITTEN_STATUS]=>(proband_name,file_stem,false),
+                [proband_name,file_stem,TRUNCATED_STATUS]=>(proband_name,file_stem,true),
+                _=>return Err(format!("The line: {} of the run progress does not have three tab-separated columns ending with the write status, i.e. {} or {}",
+                    line,WRITTEN_STATUS,TRUNCATED_STATUS))
+            };
+            entries.push(ProgressEntry{proband_name:proband_name.to_string(),file_stem:file_stem.to_string(),is_truncated});
+        }
+        Ok(RunProgress{settings_digest,entries})
+    }
+    /// ## Summary
+    /// Return the tab-separated representation of the progress, i.e. the settings digest followed by a header and a line per sample
+    pub fn to_table(&self)->String
+    {
+        let mut table=String::new();
+        writeln!(table,"{}{}",SETTINGS_DIGEST_PREFIX,self.settings_digest).unwrap();
+        writeln!(table,"Proband Name\tFile Name\tWrite Status").unwrap();
+        self.entries.iter().for_each(|entry|table.push_str(&entry.to_line()));
+        table
+    }
+    /// ## Summary
+    /// Return the names of the samples of the current run that were completed by the interrupted run, i.e. the settings of both runs are
+    /// identical and the sample has the same file stem in the progress and in the current manifest, while is_output_present, which is
+    /// called with the file stem, returns whether the files of the sample are still present
+    pub fn get_completed_samples<F:Fn(&str)->bool>(&self, current:&RunManifest, is_output_present:F)->HashSet<String>
+    {
+        if self.settings_digest!=current.settings_digest
+        {
+            return HashSet::new()
+        }
+        let current_stems=current.entries.iter().map(|entry|(&entry.proband_name,&entry.file_stem)).collect::<HashMap<&String,&String>>();
+        self.entries.iter()
+            .filter(|entry|current_stems.get(&entry.proband_name)==Some(&&entry.file_stem))
+            .filter(|entry|is_output_present(&entry.file_stem))
+            .map(|entry|entry.proband_name.clone())
+            .collect::<HashSet<String>>()
+    }
+    /// ## Summary
+    /// Return the names of the samples whose fasta files were truncated by the output quota, in the order of the progress
+    pub fn get_truncated_samples(&self)->Vec<String>
+    {
+        self.entries.iter().filter(|entry|entry.is_truncated).map(|entry|entry.proband_name.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test_incremental
+{
+    use super::*;
+    use crate::data_structures::vcf_ds::AltTranscript;
+    #[test]
+    fn test_get_variant_digest()
+    {
+        let transcript=|name:&str,change:&str|AltTranscript::new(name.to_string(),vec![format!("missense|G1|{}|protein_coding|+|{}|100A>T",name,change)]);
+        let int_map=IntMap::new("S1".to_string(),vec![transcript("T1","10K>10R"),transcript("T2","20K>20R")],vec![]);
+        let reordered=IntMap::new("S2".to_string(),vec![transcript("T2","20K>20R"),transcript("T1","10K>10R")],vec![]);
+        let swapped=IntMap::new("S1".to_string(),vec![],vec![transcript("T1","10K>10R"),transcript("T2","20K>20R")]);
+        let changed=IntMap::new("S1".to_string(),vec![transcript("T1","10K>10W"),transcript("T2","20K>20R")],vec![]);
+        assert_eq!(get_variant_digest(&int_map),get_variant_digest(&reordered));
+        assert_ne!(get_variant_digest(&int_map),get_variant_digest(&swapped));
+        assert_ne!(get_variant_digest(&int_map),get_variant_digest(&changed));
+        let mut ref_seq=HashMap::new();
+        ref_seq.insert("T1".to_string(),"MKK".to_string());
+        assert_ne!(get_settings_digest("a",&ref_seq,&[]),get_settings_digest("b",&ref_seq,&[]));
+        assert_ne!(get_settings_digest("a",&ref_seq,&[]),get_settings_digest("a",&HashMap::new(),&[]));
+    }
+    #[test]
+    fn test_failed_samples()
+    {
+        let entry=|name:&str,digest:&str|ManifestEntry{proband_name:name.to_string(),file_stem:name.to_string(),variant_digest:digest.to_string(),is_failed:false,is_truncated:false};
+        let mut previous=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1","aa"),entry("S2","bb")]};
+        previous.set_failed_samples(&["S2".to_string()].iter().cloned().collect::<HashSet<String>>());
+        assert_eq!(RunManifest::from_table(&previous.to_table()).unwrap().get_failed_samples(),vec!["S2".to_string()]);
+        // a failed sample is never reused, even if its variants did not change
+        let current=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1","aa"),entry("S2","b2")]};
+        assert_eq!(current.get_reusable_samples(&previous,|_|true).into_iter().collect::<Vec<String>>(),vec!["S1".to_string()]);
+        let resumed=["S2".to_string()].iter().cloned().collect::<HashSet<String>>();
+        previous.update_entries(&current,&resumed);
+        assert_eq!((previous.entries[1].variant_digest.as_str(),previous.entries[1].is_failed),("b2",false));
+    }
+    #[test]
+    fn test_truncated_samples()
+    {
+        let entry=|name:&str|ManifestEntry{proband_name:name.to_string(),file_stem:name.to_string(),variant_digest:"aa".to_string(),is_failed:false,is_truncated:false};
+        let mut previous=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1"),entry("S2")]};
+        previous.set_truncated_samples(&["S2".to_string()].iter().cloned().collect::<HashSet<String>>());
+        let parsed=RunManifest::from_table(&previous.to_table()).unwrap();
+        assert_eq!(parsed,previous);
+        assert_eq!(parsed.get_truncated_samples(),vec!["S2".to_string()]);
+        // the incomplete files of a truncated sample are never reused 
+        let current=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1"),entry("S2")]};
+        assert_eq!(current.get_reusable_samples(&previous,|_|true).into_iter().collect::<Vec<String>>(),vec!["S1".to_string()]);
+    }
+    #[test]
+    fn test_run_progress()
+    {
+        let entry=|name:&str|ManifestEntry{proband_name:name.to_string(),file_stem:name.to_string(),variant_digest:"aa".to_string(),is_failed:false,is_truncated:false};
+        let mut current=RunManifest{settings_digest:"01".to_string(),entries:vec![entry("S1"),entry("S2"),entry("S3"),entry("S4")]};
+        current.set_truncated_samples(&["S2".to_string()].iter().cloned().collect::<HashSet<String>>());
+        let mut progress=current.get_progress(&["S1".to_string(),"S2".to_string()].iter().cloned().collect::<HashSet<String>>());
+        assert_eq!(progress.get_truncated_samples(),vec!["S2".to_string()]);
+        // the writers append a line per completed sample, the interrupted line of S4 is ignored
+        progress.entries.push(ProgressEntry{proband_name:"S3".to_string(),file_stem:"S3".to_string(),is_truncated:false});
+        let interrupted=format!("{}S4\tS",progress.to_table());
+        let parsed=RunProgress::from_table(&interrupted).unwrap();
+        assert_eq!(parsed,progress);
+        let mut completed=parsed.get_completed_samples(&current,|file_stem|file_stem!="S1").into_iter().collect::<Vec<String>>();
+        completed.sort();
+        assert_eq!(completed,vec!["S2".to_string(),"S3".to_string()]);
+        // a sample whose file stem changed is written again
+        current.entries[2].file_stem="S3_1".to_string();
+        assert_eq!(parsed.get_completed_samples(&current,|_|true).len(),2);
+        let changed=RunManifest{settings_digest:"02".to_string(),..current};
+        assert!(parsed.get_completed_samples(&changed,|_|true).is_empty());
+    }
+}
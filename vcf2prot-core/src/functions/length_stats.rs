@@ -0,0 +1,206 @@
+/// The module collects the length statistics of the personalized sequences written for a sample, i.e. a histogram of the sequence lengths,
+/// and flags sequences whose length points to an execution bug, e.g. empty sequences or sequences much longer than their reference
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// ## Summary
+/// The lower bounds of the bins of the length histogram in amino acids, the last bin holds every sequence of at least 10,000 amino acids
+pub const LENGTH_BIN_BOUNDS:[usize;10]=[0,1,50,100,200,500,1_000,2_000,5_000,10_000];
+/// ## Summary
+/// A sequence that is more than this factor longer than its reference sequence is flagged as an anomaly
+pub const ANOMALY_LENGTH_FACTOR:usize=10;
+/// ## Summary
+/// The code of a start-lost instruction, which generates an empty sequence by design
+const START_LOST_CODE:char='0';
+
+/// ## Summary
+/// The kind of a length anomaly:
+/// 1. Empty, the sequence has no residue, e.g. because of a task that was not executed
+/// 2. Overlong, the sequence is more than ANOMALY_LENGTH_FACTOR times longer than its reference sequence
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum AnomalyKind
+{
+    Empty,
+    Overlong
+}
+impl AnomalyKind
+{
+    /// ## Summary
+    /// Return the name of the anomaly as used in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            AnomalyKind::Empty=>"empty",
+            AnomalyKind::Overlong=>"overlong"
+        }
+    }
+}
+
+/// ## Summary
+/// Return the kind of anomaly of a sequence given its length and the length of its reference sequence, if known, or None if the length is plausible
+/// ## Example
+///```rust
+/// use ppgg::functions::length_stats::{get_anomaly_kind,AnomalyKind};
+/// assert_eq!(get_anomaly_kind(0,Some(120)),Some(AnomalyKind::Empty));
+/// assert_eq!(get_anomaly_kind(1201,Some(120)),Some(AnomalyKind::Overlong));
+/// assert_eq!(get_anomaly_kind(1200,Some(120)),None);
+/// assert_eq!(get_anomaly_kind(1201,None),None);
+///```
+pub fn get_anomaly_kind(length:usize, reference_length:Option<usize>)->Option<AnomalyKind>
+{
+    match (length,reference_length)
+    {
+        (0,_)=>Some(AnomalyKind::Empty),
+        (length,Some(reference_length)) if length > ANOMALY_LENGTH_FACTOR*std::cmp::max(reference_length,1)=>Some(AnomalyKind::Overlong),
+        _=>None
+    }
+}
+
+/// ## Summary
+/// A sequence with an implausible length along with the codes of the instructions that generated it, e.g. "MF" for a missense and a
+/// frameshift, see instruction::Instruction for the meaning of each code
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct LengthAnomaly
+{
+    pub transcript:String,
+    pub haplotype:u8,
+    pub kind:AnomalyKind,
+    pub length:usize,
+    pub reference_length:Option<usize>,
+    pub instruction_codes:String
+}
+
+/// ## Summary
+/// The length statistics of the altered sequences written for a sample, the reference sequences that are written with --write_all_proteins
+/// are not part of the statistics
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct SampleLengthStats
+{
+    pub proband_name:String,
+    pub num_sequences:usize,
+    pub total_length:usize,
+    pub min_length:Option<usize>,
+    pub max_length:Option<usize>,
+    pub histogram:[usize;LENGTH_BIN_BOUNDS.len()],
+    pub anomalies:Vec<LengthAnomaly>
+}
+impl SampleLengthStats
+{
+    /// ## Summary
+    /// Create empty statistics for a sample
+    pub fn new(proband_name:String)->Self
+    {
+        SampleLengthStats{proband_name,..Default::default()}
+    }
+    /// ## Summary
+    /// Add a written sequence to the statistics, where instruction_codes is only called for anomalous sequences, empty sequences generated
+    /// by a start-lost instruction are not flagged
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::length_stats::{SampleLengthStats,AnomalyKind};
+    /// let mut stats=SampleLengthStats::new("proband_1".to_string());
+    /// stats.add("T1",1,120,Some(118),||"M".to_string());
+    /// stats.add("T2",2,0,Some(300),||"G".to_string());
+    /// assert_eq!((stats.num_sequences,stats.histogram[0],stats.histogram[3]),(2,1,1));
+    /// assert_eq!(stats.anomalies[0].kind,AnomalyKind::Empty);
+    /// assert_eq!(stats.anomalies[0].instruction_codes,"G");
+    /// stats.add("T3",1,0,Some(300),||"0".to_string());
+    /// assert_eq!((stats.num_sequences,stats.anomalies.len()),(3,1));
+    ///```
+    pub fn add<F:FnOnce()->String>(&mut self, transcript:&str, haplotype:u8, length:usize, reference_length:Option<usize>, instruction_codes:F)
+    {
+        self.num_sequences+=1;
+        self.total_length+=length;
+        self.min_length=Some(self.min_length.map_or(length,|min_length|std::cmp::min(min_length,length)));
+        self.max_length=Some(self.max_length.map_or(length,|max_length|std::cmp::max(max_length,length)));
+        let bin=LENGTH_BIN_BOUNDS.iter().rposition(|bound|length>=*bound).unwrap();
+        self.histogram[bin]+=1;
+        if let Some(kind)=get_anomaly_kind(length,reference_length)
+        {
+            let instruction_codes=instruction_codes();
+            // an empty sequence is the expected outcome of a lost start codon, i.e. the instruction code 0
+            if kind==AnomalyKind::Empty && instruction_codes.contains(START_LOST_CODE)
+            {
+                return
+            }
+            self.anomalies.push(LengthAnomaly{transcript:transcript.to_string(),haplotype,kind,length,reference_length,instruction_codes});
+        }
+    }
+    /// ## Summary
+    /// Return the mean length of the sequences, or zero if no sequence was added
+    pub fn get_mean_length(&self)->f64
+    {
+        match self.num_sequences
+        {
+            0=>0.0,
+            num_sequences=>self.total_length as f64/num_sequences as f64
+        }
+    }
+}
+
+/// ## Summary
+/// Return the label of each bin of the length histogram, e.g. 50-99 or >=10000
+pub fn get_bin_labels()->Vec<String>
+{
+    LENGTH_BIN_BOUNDS.iter().enumerate()
+        .map(|(index,bound)|match LENGTH_BIN_BOUNDS.get(index+1)
+        {
+            Some(next_bound) if *next_bound==bound+1=>bound.to_string(),
+            Some(next_bound)=>format!("{}-{}",bound,next_bound-1),
+            None=>format!(">={}",bound)
+        })
+        .collect()
+}
+
+/// ## Summary
+/// Return a human readable report of the length anomalies of a cohort, i.e. the number of anomalies of each kind followed by the
+/// max_listed first anomalies with their sample, transcript, haplotype, lengths and instruction codes, an empty string is returned if
+/// no anomaly was detected
+pub fn anomalies_to_report(stats:&[SampleLengthStats], max_listed:usize)->String
+{
+    let anomalies=stats.iter().flat_map(|sample|sample.anomalies.iter().map(move |anomaly|(&sample.proband_name,anomaly))).collect::<Vec<_>>();
+    if anomalies.is_empty()
+    {
+        return String::new()
+    }
+    let mut per_kind=HashMap::new();
+    for (_,anomaly) in anomalies.iter()
+    {
+        *per_kind.entry(anomaly.kind.as_str()).or_insert(0)+=1;
+    }
+    let mut per_kind=per_kind.into_iter().collect::<Vec<(&str,usize)>>();
+    per_kind.sort();
+    let mut report=String::new();
+    writeln!(report,"WARNING: {} sequences with an implausible length were written, which may point to an execution bug: {}",anomalies.len(),
+        per_kind.iter().map(|(kind,num)|format!("{} {}",num,kind)).collect::<Vec<String>>().join(", ")).unwrap();
+    for (proband_name,anomaly) in anomalies.into_iter().take(max_listed)
+    {
+        writeln!(report,"\t{}\t{}_{}\t{}\tlength={}\treference_length={}\tinstructions={}",proband_name,anomaly.transcript,anomaly.haplotype,
+            anomaly.kind.as_str(),anomaly.length,anomaly.reference_length.map_or("NA".to_string(),|length|length.to_string()),anomaly.instruction_codes).unwrap();
+    }
+    report
+}
+
+#[cfg(test)]
+mod test_length_stats
+{
+    use super::*;
+    #[test]
+    fn test_sample_length_stats()
+    {
+        let mut stats=SampleLengthStats::new("S1".to_string());
+        for (transcript,length,reference_length) in [("T1",350,Some(340)),("T2",49,Some(400)),("T3",12_000,Some(800)),("T4",9_000,Some(1_000)),("T5",3,None)]
+        {
+            stats.add(transcript,1,length,reference_length,||"F".to_string());
+        }
+        assert_eq!(stats.histogram,[0,2,0,0,1,0,0,0,1,1]);
+        assert_eq!((stats.min_length,stats.max_length),(Some(3),Some(12_000)));
+        assert_eq!(stats.anomalies.iter().map(|anomaly|anomaly.transcript.as_str()).collect::<Vec<_>>(),vec!["T3"]);
+        let report=anomalies_to_report(&[stats],10);
+        assert!(report.starts_with("WARNING: 1 sequences"));
+        assert!(report.contains("\tS1\tT3_1\toverlong\tlength=12000\treference_length=800\tinstructions=F"));
+        assert_eq!(get_bin_labels()[..3],["0".to_string(),"1-49".to_string(),"50-99".to_string()]);
+        assert_eq!(anomalies_to_report(&[SampleLengthStats::new("S2".to_string())],10),"");
+    }
+}
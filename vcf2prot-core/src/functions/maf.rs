@@ -0,0 +1,356 @@
+/// The module converts the records of a MAF (Mutation Annotation Format) file, e.g. of a TCGA cohort, into the intermediate representation,
+/// i.e. one IntMap per tumor sample, so tumor cohorts can be personalized without generating a VCF file first
+use std::collections::{BTreeMap,HashMap};
+use std::fmt::Write;
+use std::sync::Arc;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::data_structures::mutation_ds::{Mutation,MutationType};
+use crate::functions::text_parser;
+
+/// ## Summary
+/// The indices of the MAF columns used for the conversion, the protein change is read from the Protein_position and the Amino_acids
+/// columns, e.g. 746-750/1210 and ELREA/-, written by VEP-annotated MAF files such as the GDC ones, and from HGVSp_Short, e.g. p.K2N,
+/// for the records where these columns are empty. The tumor alleles are used for the zygosity, if present.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct MafColumns
+{
+    pub sample:usize,
+    pub classification:usize,
+    pub transcript:usize,
+    pub protein_position:Option<usize>,
+    pub amino_acids:Option<usize>,
+    pub hgvsp_short:Option<usize>,
+    pub reference_allele:Option<usize>,
+    pub tumor_allele1:Option<usize>,
+    pub tumor_allele2:Option<usize>
+}
+impl MafColumns
+{
+    /// ## Summary
+    /// Locate the columns in the header line of a MAF file, an error is returned if Tumor_Sample_Barcode, Variant_Classification or
+    /// Transcript_ID is missing, or if the header has neither the Protein_position and Amino_acids columns nor the HGVSp_Short column
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::maf::MafColumns;
+    /// let columns=MafColumns::from_header("Hugo_Symbol\tVariant_Classification\tTumor_Sample_Barcode\tHGVSp_Short\tTranscript_ID").unwrap();
+    /// assert_eq!((columns.sample,columns.classification,columns.transcript,columns.hgvsp_short),(2,1,4,Some(3)));
+    /// assert!(MafColumns::from_header("Hugo_Symbol\tVariant_Classification\tTumor_Sample_Barcode\tTranscript_ID").is_err());
+    ///```
+    pub fn from_header(header:&str)->Result<MafColumns,String>
+    {
+        let names=header.split('\t').map(|name|name.trim()).collect::<Vec<&str>>();
+        let find=|name:&str|names.iter().position(|column|*column==name);
+        let require=|name:&str|find(name).ok_or(format!("The header of the MAF file does not contain the required column: {}",name));
+        let columns=MafColumns{sample:require("Tumor_Sample_Barcode")?,classification:require("Variant_Classification")?,
+            transcript:require("Transcript_ID")?,protein_position:find("Protein_position"),amino_acids:find("Amino_acids"),
+            hgvsp_short:find("HGVSp_Short"),reference_allele:find("Reference_Allele"),tumor_allele1:find("Tumor_Seq_Allele1"),
+            tumor_allele2:find("Tumor_Seq_Allele2")};
+        if (columns.protein_position.is_none() || columns.amino_acids.is_none()) && columns.hgvsp_short.is_none()
+        {
+            return Err("The header of the MAF file must contain either the Protein_position and the Amino_acids columns or the HGVSp_Short column".to_string())
+        }
+        Ok(columns)
+    }
+}
+
+/// ## Summary
+/// Return the consequence type of a MAF variant classification, or None for the classifications that do not alter the protein sequence,
+/// e.g. Silent, Intron or Splice_Site, which are ignored
+/// ## Example
+///```rust
+/// use ppgg::functions::maf::get_mutation_type;
+/// use ppgg::MutationType;
+/// assert_eq!(get_mutation_type("Missense_Mutation"),Some(MutationType::MisSense));
+/// assert_eq!(get_mutation_type("Frame_Shift_Del"),Some(MutationType::FrameShift));
+/// assert_eq!(get_mutation_type("Silent"),None);
+///```
+pub fn get_mutation_type(classification:&str)->Option<MutationType>
+{
+    match classification
+    {
+        "Missense_Mutation"=>Some(MutationType::MisSense),
+        "Nonsense_Mutation"=>Some(MutationType::StopGained),
+        "In_Frame_Del"=>Some(MutationType::InframeDeletion),
+        "In_Frame_Ins"=>Some(MutationType::InframeInsertion),
+        "Frame_Shift_Del" | "Frame_Shift_Ins"=>Some(MutationType::FrameShift),
+        "Nonstop_Mutation"=>Some(MutationType::StopLost),
+        "Translation_Start_Site"=>Some(MutationType::StartLost),
+        _=>None
+    }
+}
+
+/// ## Summary
+/// The mutations of the two haplotypes of a tumor sample, grouped by transcript
+type HaplotypeMutations=(BTreeMap<String,Vec<Mutation>>,BTreeMap<String,Vec<Mutation>>);
+
+/// ## Summary
+/// The outcome of converting a MAF file, i.e. the tumor samples in the order of the file, the number of records, the number of converted
+/// records, the number of records whose classification does not alter the protein and the records that alter the protein but could not be
+/// converted, counted per classification and listed with their sample, transcript and reason
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct MafSummary
+{
+    pub proband_names:Vec<String>,
+    pub num_records:usize,
+    pub num_converted:usize,
+    pub num_ignored:usize,
+    pub num_skipped_per_classification:BTreeMap<String,usize>,
+    pub skipped_records:Vec<(String,String,String)>
+}
+impl MafSummary
+{
+    /// ## Summary
+    /// Return the number of protein-altering records that could not be converted
+    pub fn get_num_skipped(&self)->usize
+    {
+        self.skipped_records.len()
+    }
+    /// ## Summary
+    /// Return a human readable report of the conversion, i.e. the totals, the skipped records per classification and the first max_listed
+    /// skipped records
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} MAF records of {} samples were read, {} of them were converted, {} do not alter the protein and {} could not be converted",
+            self.num_records,self.proband_names.len(),self.num_converted,self.num_ignored,self.get_num_skipped()).unwrap();
+        if self.skipped_records.is_empty()
+        {
+            return report
+        }
+        writeln!(report,"The skipped records per classification are: {}",self.num_skipped_per_classification.iter()
+            .map(|(classification,num_skipped)|format!("{} {}",num_skipped,classification)).collect::<Vec<String>>().join(", ")).unwrap();
+        for (sample,transcript,reason) in self.skipped_records.iter().take(max_listed)
+        {
+            writeln!(report,"\t{}\t{}\t{}",sample,transcript,reason).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Convert the lines of a MAF file into one intermediate representation per tumor sample, in the order of the file, along with a summary
+/// of the conversion, see MafSummary. Comment lines, i.e. starting with #, are skipped and the first remaining line is the header.
+/// The records are converted as follows:
+/// 1. Missense_Mutation, Nonsense_Mutation, In_Frame_Del, In_Frame_Ins and Translation_Start_Site records are converted into the corresponding consequence, see get_mutation_type.
+/// 2. Pure deletions and insertions, e.g. ELREA/- or -/A, are anchored to the preceding, respectively the first flanking, residue of the reference sequence, as expected by the execution engines.
+/// 3. Frame_Shift_Del, Frame_Shift_Ins and Nonstop_Mutation records are skipped, as a MAF record has neither the frameshifted sequence nor the extension of a lost stop codon.
+/// 4. The records of the other classifications are ignored as they do not alter the protein sequence.
+///
+/// MAF records are not phased, hence, a record is placed on the first haplotype unless both tumor alleles differ from the reference
+/// allele, in which case it is placed on both haplotypes. The positions of the mutations in the mutated sequences are derived from the
+/// length changes of the preceding mutations of each haplotype. An error is returned if the header lacks a required column, see
+/// MafColumns::from_header, or if a record has fewer columns than the header.
+pub fn maf_to_int_maps(lines:&[String], ref_seqs:&HashMap<String,String>)->Result<(Vec<IntMap>,MafSummary),String>
+{
+    let mut lines=lines.iter().filter(|line|!line.starts_with('#') && !line.trim().is_empty());
+    let columns=match lines.next()
+    {
+        Some(header)=>MafColumns::from_header(header)?,
+        None=>return Err("The MAF file does not contain a header line".to_string())
+    };
+    let num_columns=[Some(columns.sample),Some(columns.classification),Some(columns.transcript),columns.protein_position,
+        columns.amino_acids,columns.hgvsp_short,columns.reference_allele,columns.tumor_allele1,columns.tumor_allele2]
+        .iter().flatten().max().unwrap()+1;
+    let mut summary=MafSummary::default();
+    let mut sample_index=HashMap::new();
+    let mut haplotypes:Vec<HaplotypeMutations>=Vec::new();
+    let mut transcript_names:HashMap<String,Arc<str>>=HashMap::new();
+    for line in lines
+    {
+        let fields=line.split('\t').map(|field|field.trim()).collect::<Vec<&str>>();
+        if fields.len()<num_columns
+        {
+            return Err(format!("The MAF record: {} has {} columns, while the header requires at least {} columns",line,fields.len(),num_columns))
+        }
+        summary.num_records+=1;
+        let sample=fields[columns.sample];
+        let index=*sample_index.entry(sample.to_string()).or_insert_with(||
+        {
+            summary.proband_names.push(sample.to_string());
+            haplotypes.push((BTreeMap::new(),BTreeMap::new()));
+            haplotypes.len()-1
+        });
+        let mut_type=match get_mutation_type(fields[columns.classification])
+        {
+            Some(mut_type)=>mut_type,
+            None=>
+            {
+                summary.num_ignored+=1;
+                continue
+            }
+        };
+        let transcript=fields[columns.transcript];
+        let transcript_name=transcript_names.entry(transcript.to_string()).or_insert_with(||Arc::from(transcript)).clone();
+        let mutation=match get_protein_change(&fields, &columns, &mut_type, ref_seqs.get(transcript))
+            .and_then(|aa_field|text_parser::parse_amino_acid_field(&aa_field))
+        {
+            Ok(mut_info)=>Mutation::from_info(mut_type,transcript_name,mut_info),
+            Err(err_msg)=>
+            {
+                *summary.num_skipped_per_classification.entry(fields[columns.classification].to_string()).or_insert(0)+=1;
+                summary.skipped_records.push((sample.to_string(),transcript.to_string(),err_msg));
+                continue
+            }
+        };
+        summary.num_converted+=1;
+        let (haplotype1,haplotype2)=&mut haplotypes[index];
+        if is_homozygous(&fields, &columns)
+        {
+            haplotype2.entry(transcript.to_string()).or_default().push(mutation.clone());
+        }
+        haplotype1.entry(transcript.to_string()).or_default().push(mutation);
+    }
+    let int_maps=summary.proband_names.iter().zip(haplotypes)
+        .map(|(proband_name,(haplotype1,haplotype2))|IntMap::new(proband_name.clone(),to_alt_transcripts(haplotype1),to_alt_transcripts(haplotype2)))
+        .collect::<Vec<IntMap>>();
+    Ok((int_maps,summary))
+}
+
+/// ## Summary
+/// Return the protein change of a record as an amino acid field, e.g. 640V>640E, where the position of the mutated sequence is set to
+/// the reference position and is updated once the mutations of a haplotype are known, see AltTranscript::assign_mutated_positions, or the reason why
+/// the record can not be converted
+fn get_protein_change(fields:&[&str], columns:&MafColumns, mut_type:&MutationType, ref_seq:Option<&String>)->Result<String,String>
+{
+    match mut_type
+    {
+        MutationType::FrameShift=>return Err("the frameshifted sequence is not part of a MAF record".to_string()),
+        MutationType::StopLost=>return Err("the extension of a lost stop codon is not part of a MAF record".to_string()),
+        _=>()
+    }
+    let (position,ref_aa,mut_aa)=match (columns.protein_position.map(|index|fields[index]),columns.amino_acids.map(|index|fields[index]))
+    {
+        (Some(position),Some(amino_acids)) if !position.is_empty() && position!="." && amino_acids.contains('/')=>
+        {
+            let start=position.split('/').next().unwrap().split('-').next().unwrap();
+            let position=match start.parse::<usize>()
+            {
+                Ok(position) if position!=0=>position,
+                _=>return Err(format!("the protein position: {} is not a valid position",position))
+            };
+            let (ref_aa,mut_aa)=amino_acids.split_once('/').unwrap();
+            (position,ref_aa.to_string(),mut_aa.to_string())
+        },
+        _=>match columns.hgvsp_short.map(|index|fields[index])
+        {
+            Some(hgvsp)=>parse_hgvsp_substitution(hgvsp)?,
+            None=>return Err("the record has neither a protein position nor a protein change".to_string())
+        }
+    };
+    let anchor=|position:usize|match ref_seq.and_then(|seq|seq.chars().nth(position-1))
+    {
+        Some(residue)=>Ok(residue),
+        None=>Err(format!("the residue at position {} of the reference sequence, which anchors the indel, is not available",position))
+    };
+    let (position,ref_aa,mut_aa)=match (mut_type,ref_aa.as_str(),mut_aa.as_str())
+    {
+        (_,"-","-")=>return Err("the record has neither a reference nor an alternative amino acid".to_string()),
+        (MutationType::InframeDeletion,_,"-") if position>1=>
+        {
+            let residue=anchor(position-1)?;
+            (position-1,format!("{}{}",residue,ref_aa),residue.to_string())
+        },
+        (MutationType::InframeDeletion,_,"-")=>return Err("a deletion of the first residue has no preceding residue to anchor it".to_string()),
+        (MutationType::InframeInsertion,"-",_)=>
+        {
+            let residue=anchor(position)?;
+            (position,residue.to_string(),format!("{}{}",residue,mut_aa))
+        },
+        (MutationType::StartLost,_,"-")=>(position,ref_aa.clone(),ref_aa),
+        (_,"-",_) | (_,_,"-")=>return Err(format!("the amino acids: {}/{} do not match the classification of the record",ref_aa,mut_aa)),
+        _=>(position,ref_aa,mut_aa)
+    };
+    Ok(format!("{}{}>{}{}",position,ref_aa,position,mut_aa))
+}
+
+/// ## Summary
+/// Parse a HGVSp_Short substitution, e.g. p.K2N or p.Q32*, into its one-based position and its reference and alternative amino acids,
+/// other changes, e.g. p.E746_A750del, need the Protein_position and the Amino_acids columns
+fn parse_hgvsp_substitution(hgvsp:&str)->Result<(usize,String,String),String>
+{
+    let change=hgvsp.strip_prefix("p.").unwrap_or(hgvsp);
+    let start=change.find(|residue:char|residue.is_ascii_digit());
+    let end=change.rfind(|residue:char|residue.is_ascii_digit());
+    match (start,end)
+    {
+        (Some(start),Some(end)) if start==1 && end+2==change.len()=>
+        {
+            let position=change[start..=end].parse::<usize>().map_err(|_|format!("the protein change: {} is not a valid substitution",hgvsp))?;
+            let (ref_aa,mut_aa)=(&change[..start],&change[end+1..]);
+            match position!=0 && ref_aa!="=" && mut_aa!="=" && mut_aa!="?"
+            {
+                true=>Ok((position,ref_aa.to_string(),mut_aa.to_string())),
+                false=>Err(format!("the protein change: {} does not alter the protein",hgvsp))
+            }
+        },
+        _=>Err(format!("the protein change: {} is not a substitution, indels need the Protein_position and the Amino_acids columns",hgvsp))
+    }
+}
+
+/// ## Summary
+/// Return whether both tumor alleles of a record differ from the reference allele, records without the allele columns are heterozygous
+fn is_homozygous(fields:&[&str], columns:&MafColumns)->bool
+{
+    match (columns.reference_allele,columns.tumor_allele1,columns.tumor_allele2)
+    {
+        (Some(reference),Some(allele1),Some(allele2))=>fields[allele1]!=fields[reference] && fields[allele2]!=fields[reference],
+        _=>false
+    }
+}
+
+/// ## Summary
+/// Group the mutations of a haplotype into altered transcripts, in the order of the transcript names, the repeated records of a mutation
+/// are dropped and the positions of the mutated sequences are assigned, see AltTranscript::assign_mutated_positions
+fn to_alt_transcripts(haplotype:BTreeMap<String,Vec<Mutation>>)->Vec<AltTranscript>
+{
+    haplotype.into_iter()
+        .map(|(transcript,mut mutations)|
+        {
+            mutations.sort_by_key(|mutation|mutation.mut_info.ref_aa_position);
+            mutations.dedup_by(|mutation2,mutation1|mutation1.mut_info==mutation2.mut_info && mutation1.mut_type==mutation2.mut_type);
+            let mut alt_transcript=AltTranscript::from_mutations(transcript,mutations);
+            alt_transcript.assign_mutated_positions();
+            alt_transcript
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_maf
+{
+    use super::*;
+    use crate::data_structures::mutation_ds::MutatedString;
+    #[test]
+    fn test_maf_to_int_maps()
+    {
+        let lines=["#version 2.4",
+            "Hugo_Symbol\tVariant_Classification\tReference_Allele\tTumor_Seq_Allele1\tTumor_Seq_Allele2\tTumor_Sample_Barcode\tHGVSp_Short\tTranscript_ID\tProtein_position\tAmino_acids",
+            "G1\tMissense_Mutation\tA\tA\tT\tTUMOR_1\tp.K2N\tT1\t2/8\tK/N",
+            "G1\tIn_Frame_Del\tGAA\tGAA\t-\tTUMOR_1\tp.E4_L5del\tT1\t4-5/8\tEL/-",
+            "G1\tNonsense_Mutation\tC\tT\tT\tTUMOR_1\tp.Q7*\tT1\t7/8\t",
+            "G2\tIn_Frame_Ins\t-\t-\tGCC\tTUMOR_2\tp.M1_K2insA\tT2\t1-2/4\t-/A",
+            "G2\tSilent\tA\tA\tG\tTUMOR_2\tp.K2=\tT2\t2/4\tK",
+            "G2\tFrame_Shift_Del\tA\tA\t-\tTUMOR_2\tp.K2fs\tT2\t2/4\tK/X"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let ref_seqs=vec![("T1".to_string(),"MKAELVQW".to_string()),("T2".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+        let (int_maps,summary)=maf_to_int_maps(&lines,&ref_seqs).unwrap();
+        assert_eq!(summary.proband_names,vec!["TUMOR_1".to_string(),"TUMOR_2".to_string()]);
+        assert_eq!((summary.num_records,summary.num_converted,summary.num_ignored,summary.get_num_skipped()),(6,4,1,1));
+        assert_eq!(summary.num_skipped_per_classification.get("Frame_Shift_Del"),Some(&1));
+        // the deletion is anchored to the preceding residue, A3, and shifts the nonsense mutation by two residues in the mutated sequence
+        let (haplotype1,haplotype2)=int_maps[0].get_mutations_ref();
+        let fields=haplotype1[0].alts.iter()
+            .map(|mutation|(mutation.mut_info.ref_aa_position,mutation.mut_info.mut_aa_position,mutation.mut_info.ref_aa.clone(),mutation.mut_info.mut_aa.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(fields,vec![(1,1,MutatedString::Sequence("K".to_string()),MutatedString::Sequence("N".to_string())),
+            (2,2,MutatedString::Sequence("AEL".to_string()),MutatedString::Sequence("A".to_string())),
+            (6,4,MutatedString::Sequence("Q".to_string()),MutatedString::NotSeq)]);
+        // only the nonsense mutation is carried by both tumor alleles
+        assert_eq!((haplotype2.len(),haplotype2[0].alts.len(),haplotype2[0].alts[0].mut_type.clone()),(1,1,MutationType::StopGained));
+        let insertion=&int_maps[1].get_mutations_ref().0[0].alts[0];
+        assert_eq!((insertion.mut_info.ref_aa.clone(),insertion.mut_info.mut_aa.clone()),(MutatedString::Sequence("M".to_string()),MutatedString::Sequence("MA".to_string())));
+        assert!(int_maps.iter().all(|int_map|int_map.validate().is_ok()));
+        assert!(maf_to_int_maps(&lines[..1],&ref_seqs).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+/// The module accumulates the statistics of a run while the representations are parsed and executed, i.e. the consequences decoded per
+/// proband and the alterations, instructions, tasks, clamps and skipped transcripts of each executed proband, so the summary tables reflect
+/// what was executed without an extra pass over the intermediate representations. The accumulator is sharded by the proband name, where
+/// every shard is guarded by its own lock, hence, the probands executed on parallel rarely contend for the same lock. An accumulator is 
+/// created by the caller for each run and passed to the executions through exec::ExecutionOptions, hence, runs in the same process do not 
+/// share their metrics.
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash,Hasher};
+use std::str::FromStr;
+use std::sync::Mutex;
+use crate::data_structures::Constants;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::MutationType;
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome;
+#[cfg(feature = "parser")]
+use crate::functions::vcf_tools::DecodingSummary;
+use crate::parts::exec::StatSummary;
+
+/// ## Summary
+/// The default number of shards of an accumulator
+pub const NUM_SHARDS:usize=16;
+
+/// ## Summary
+/// The metrics of a proband, the parsing metrics are recorded from the representation and the summary of decoding the consequences, see
+/// MetricsAccumulator::record_parse, and the execution metrics when its personalized genome is generated, see exec::execute.
+/// The alterations of transcripts missing from the reference or skipped by the execution are not counted as executed.
+#[derive(Debug,Clone,Default,PartialEq,Eq)]
+pub struct ProbandMetrics
+{
+    pub num_decoded_consequences:u64,
+    pub num_parsed_mutations:u64,
+    pub is_executed:bool,
+    pub num_altered_transcripts:u64,
+    pub num_mutations_per_type:Vec<u64>,
+    pub num_haplotypes_per_transcript:HashMap<String,u64>,
+    pub num_applied_instructions:u64,
+    pub num_executed_tasks:u64,
+    pub num_clamped_tasks:u64,
+    pub num_skipped_transcripts:u64,
+    pub num_rejected_transcripts:u64
+}
+impl ProbandMetrics
+{
+    /// ## Summary
+    /// Return the number of decoded consequences that are not part of the representation, i.e. unsupported, unparsable or duplicated
+    /// consequences
+    pub fn get_num_dropped_consequences(&self)->u64
+    {
+        self.num_decoded_consequences.saturating_sub(self.num_parsed_mutations)
+    }
+    /// ## Summary
+    /// Return the number of executed mutations, summed over both haplotypes
+    pub fn get_num_executed_mutations(&self)->u64
+    {
+        self.num_mutations_per_type.iter().sum()
+    }
+}
+
+/// ## Summary
+/// The alterations of a proband that are part of the reference, i.e. per haplotype the transcripts and the index of the type of each of
+/// their mutations in Constants::SUP_TYPE, extracted before the representation is consumed by the execution
+#[derive(Debug,Clone)]
+pub struct ExecutedAlterations
+{
+    proband_name:String,
+    haplotypes:[Vec<(String,Vec<usize>)>;2]
+}
+impl ExecutedAlterations
+{
+    /// ## Summary
+    /// Extract the alterations of the transcripts of the reference from a representation
+    pub fn new(int_map:&IntMap, ref_seq:&HashMap<String,String>)->Self
+    {
+        let sup_types=Constants::SUP_TYPE.iter().map(|mut_type|MutationType::from_str(mut_type).unwrap()).collect::<Vec<MutationType>>();
+        let (mutations1,mutations2)=int_map.get_mutations_ref();
+        let get_alterations=|alt_transcripts:&[crate::data_structures::vcf_ds::AltTranscript]|alt_transcripts.iter()
+            .filter(|alt_transcript|ref_seq.contains_key(&alt_transcript.name))
+            .map(|alt_transcript|(alt_transcript.name.clone(),alt_transcript.get_alts().iter()
+                .filter_map(|mutation|sup_types.iter().position(|mut_type|*mut_type==mutation.mut_type))
+                .collect::<Vec<usize>>()))
+            .collect::<Vec<(String,Vec<usize>)>>();
+        ExecutedAlterations{proband_name:int_map.get_name().clone(),haplotypes:[get_alterations(mutations1),get_alterations(mutations2)]}
+    }
+}
+
+/// ## Summary
+/// A snapshot of the metrics of all the recorded probands
+#[derive(Debug,Clone,Default,PartialEq,Eq)]
+pub struct MetricsSnapshot
+{
+    pub probands:HashMap<String,ProbandMetrics>
+}
+impl MetricsSnapshot
+{
+    /// ## Summary
+    /// Return the summary of the executed probands, i.e. the number of executed altered transcripts and the number of executed mutations of
+    /// each type per proband, along with the number of executed haplotype alterations per transcript, probands that were only parsed are
+    /// not part of the summary
+    pub fn to_stat_summary(&self)->StatSummary
+    {
+        let mut summary=StatSummary{num_mutation_per_proband:HashMap::new(),type_mutation_per_proband:HashMap::new(),
+            number_of_mutations_per_transcript:HashMap::new()};
+        for (proband_name,metrics) in self.probands.iter().filter(|(_,metrics)|metrics.is_executed)
+        {
+            summary.num_mutation_per_proband.insert(proband_name.clone(),metrics.num_altered_transcripts);
+            summary.type_mutation_per_proband.insert(proband_name.clone(),metrics.num_mutations_per_type.clone());
+            for (transcript_name,count) in metrics.num_haplotypes_per_transcript.iter()
+            {
+                *summary.number_of_mutations_per_transcript.entry(transcript_name.clone()).or_insert(0)+=count;
+            }
+        }
+        summary
+    }
+}
+
+/// ## Summary
+/// A thread-safe accumulator of the metrics per proband, the metrics of a proband are stored in a single shard and are replaced, rather
+/// than added, when the proband is recorded again, e.g. when the representations are parsed twice, hence, the accumulator always holds
+/// the metrics of the latest parsing and execution of each proband
+#[derive(Debug)]
+pub struct MetricsAccumulator
+{
+    shards:Vec<Mutex<HashMap<String,ProbandMetrics>>>
+}
+impl MetricsAccumulator
+{
+    /// ## Summary
+    /// Create an empty accumulator with the provided number of shards, at least one shard is created
+    pub fn new(num_shards:usize)->Self
+    {
+        MetricsAccumulator{shards:(0..num_shards.max(1)).map(|_|Mutex::new(HashMap::new())).collect()}
+    }
+    /// ## Summary
+    /// Record the parsing metrics of a representation built from num_decoded_consequences consequences
+    pub fn record_parsed(&self, int_map:&IntMap, num_decoded_consequences:u64)
+    {
+        let (mutations1,mutations2)=int_map.get_mutations_ref();
+        let num_parsed_mutations=mutations1.iter().chain(mutations2.iter()).map(|alt_transcript|alt_transcript.get_alts().len() as u64).sum();
+        self.update(int_map.get_name(),|metrics|
+        {
+            metrics.num_decoded_consequences=num_decoded_consequences;
+            metrics.num_parsed_mutations=num_parsed_mutations;
+        });
+    }
+    /// ## Summary
+    /// Record the parsing metrics of the representations of a parse, where the number of decoded consequences of each proband is taken 
+    /// from the summary of the decoding, see vcf_tools::DecodingSummary, which requires the parser feature
+    #[cfg(feature = "parser")]
+    pub fn record_parse(&self, int_maps:&[IntMap], decoding_summary:&DecodingSummary)
+    {
+        int_maps.iter().for_each(|int_map|self.record_parsed(int_map, decoding_summary.get_num_decoded(int_map.get_name())));
+    }
+    /// ## Summary
+    /// Record the execution metrics of a proband from the alterations extracted before the execution and its personalized genome, the
+    /// transcripts skipped in a haplotype are not counted as executed in that haplotype
+    pub fn record_executed(&self, alterations:ExecutedAlterations, genome:&PersonalizedGenome)
+    {
+        let (report1,report2)=genome.get_execution_reports();
+        let mut executed=ProbandMetrics{is_executed:true,num_mutations_per_type:vec![0;Constants::SUP_TYPE.len()],..Default::default()};
+        for (alterations,report) in alterations.haplotypes.iter().zip([report1,report2])
+        {
+            for (transcript_name,type_indices) in alterations.iter().filter(|(name,_)|!report.skipped_transcripts.contains(name))
+            {
+                executed.num_altered_transcripts+=1;
+                type_indices.iter().for_each(|index|executed.num_mutations_per_type[*index]+=1);
+                *executed.num_haplotypes_per_transcript.entry(transcript_name.clone()).or_insert(0)+=1;
+            }
+            executed.num_applied_instructions+=report.num_applied_instructions as u64;
+            executed.num_executed_tasks+=report.num_executed_tasks as u64;
+            executed.num_clamped_tasks+=report.clamps.len() as u64;
+            executed.num_skipped_transcripts+=report.skipped_transcripts.len() as u64;
+            executed.num_rejected_transcripts+=report.rejected_transcripts.len() as u64;
+        }
+        self.update(&alterations.proband_name,|metrics|
+        {
+            executed.num_decoded_consequences=metrics.num_decoded_consequences;
+            executed.num_parsed_mutations=metrics.num_parsed_mutations;
+            *metrics=executed;
+        });
+    }
+    /// ## Summary
+    /// Return a snapshot of the metrics of all the recorded probands
+    pub fn snapshot(&self)->MetricsSnapshot
+    {
+        let mut probands=HashMap::new();
+        for shard in self.shards.iter()
+        {
+            probands.extend(shard.lock().unwrap().iter().map(|(name,metrics)|(name.clone(),metrics.clone())));
+        }
+        MetricsSnapshot{probands}
+    }
+    /// ## Summary
+    /// Remove the metrics of all the recorded probands
+    pub fn reset(&self)
+    {
+        self.shards.iter().for_each(|shard|shard.lock().unwrap().clear());
+    }
+    fn update<F:FnOnce(&mut ProbandMetrics)>(&self, proband_name:&String, update_fn:F)
+    {
+        let mut hasher=DefaultHasher::new();
+        proband_name.hash(&mut hasher);
+        let shard=&self.shards[hasher.finish() as usize % self.shards.len()];
+        update_fn(shard.lock().unwrap().entry(proband_name.clone()).or_default());
+    }
+}
+
+#[cfg(test)]
+mod test_metrics
+{
+    use super::*;
+    use rayon::prelude::*;
+    use crate::data_structures::InternalRep::engines::Engine;
+    use crate::data_structures::InternalRep::proband_instructions::ProbandInstruction;
+    use crate::data_structures::InternalRep::gir::PlaceholderPolicy;
+    use crate::functions::diagnostics::DiagnosticOptions;
+    use crate::data_structures::vcf_ds::AltTranscript;
+    #[test]
+    fn test_metrics_accumulator()
+    {
+        let mut ref_seq=HashMap::new();
+        ref_seq.insert("T1".to_string(),"MKLVT".to_string());
+        let int_maps=(0..32).map(|index|IntMap::new(format!("S{}",index),
+            vec![AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|2K>2N|10A>T".to_string()])],
+            vec![AltTranscript::new("T2".to_string(),vec!["missense|G2|T2|protein_coding|+|1M>1V|20A>T".to_string()])]))
+            .collect::<Vec<IntMap>>();
+        let accumulator=MetricsAccumulator::new(4);
+        int_maps.par_iter().for_each(|int_map|accumulator.record_parsed(int_map,3));
+        int_maps.into_par_iter().for_each(|int_map|
+        {
+            let alterations=ExecutedAlterations::new(&int_map,&ref_seq);
+            let instruction=ProbandInstruction::from_intmap(int_map,Engine::ST,&ref_seq);
+            let genome=PersonalizedGenome::from_proband_instruction(instruction,Engine::ST,&ref_seq,&PlaceholderPolicy::default(),&DiagnosticOptions::default());
+            accumulator.record_executed(alterations,&genome);
+        });
+        // parsing a proband again replaces its parsing metrics
+        accumulator.record_parsed(&IntMap::new("S0".to_string(),Vec::new(),Vec::new()),3);
+        let snapshot=accumulator.snapshot();
+        assert_eq!(snapshot.probands.len(),32);
+        let metrics=&snapshot.probands["S1"];
+        assert_eq!((metrics.num_decoded_consequences,metrics.num_parsed_mutations,metrics.get_num_dropped_consequences()),(3,2,1));
+        // the transcript T2 is missing from the reference, hence, only one mutation is executed
+        assert_eq!((metrics.num_altered_transcripts,metrics.get_num_executed_mutations()),(1,1));
+        assert_eq!(snapshot.probands["S0"].get_num_dropped_consequences(),3);
+        let summary=snapshot.to_stat_summary();
+        assert_eq!((summary.num_mutation_per_proband.len(),summary.number_of_mutations_per_transcript["T1"]),(32,32));
+        accumulator.reset();
+        assert!(accumulator.snapshot().probands.is_empty());
+    }
+    #[cfg(feature = "parser")]
+    #[test]
+    fn test_record_parse()
+    {
+        // the decoded consequences of a parse are taken from its decoding summary 
+        let accumulator=MetricsAccumulator::new(4);
+        let decoding_summary=DecodingSummary{num_decoded_consequences:vec![("S0".to_string(),5)].into_iter().collect(),..Default::default()};
+        accumulator.record_parse(&[IntMap::new("S0".to_string(),Vec::new(),Vec::new()),IntMap::new("S1".to_string(),Vec::new(),Vec::new())],&decoding_summary);
+        let snapshot=accumulator.snapshot();
+        assert_eq!((snapshot.probands["S0"].num_decoded_consequences,snapshot.probands["S1"].num_decoded_consequences),(5,0));
+    }
+}
@@ -0,0 +1,413 @@
+/// The module makes the handling of missing genotype calls, e.g. ./. or .|., explicit, the bit-mask of a sample with a missing call is either
+/// treated as the reference, the whole record is skipped or the call is imputed with the major allele of the called samples. Independent of
+/// the policy, the bits of the haplotypes without a called allele, e.g. the first haplotype of .|1 or the second one of a haploid call at a
+/// hemizygous site, are cleared from the bit-mask of each sample before it is decoded
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::str::FromStr;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::MaskDecoder::BitMask;
+use crate::data_structures::vcf_ds::Genotype;
+use crate::readers::vcf_helpers;
+
+/// ## Summary
+/// The policy for the samples with a missing genotype call at a record:
+/// 1. Reference, the default, the bit-mask of the sample is set to zero, i.e. the sample carries none of the consequences of the record.
+/// 2. SkipRecord, records with a missing call in at least one sample are skipped for all samples.
+/// 3. MajorAllele, the sample is imputed as homozygous for the major allele of each consequence among the called samples, see BitMask::get_major_allele_mask.
+/// 4. Flag, the sample is treated as the reference, however, the transcripts of the record are flagged in the headers of the sample, i.e. the
+///    sequence of a flagged transcript is not known to be the reference, the partially missing calls, e.g. .|1, are flagged as well.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum MissingGenotypePolicy
+{
+    Reference,
+    SkipRecord,
+    MajorAllele,
+    Flag
+}
+impl Default for MissingGenotypePolicy
+{
+    fn default()->Self
+    {
+        MissingGenotypePolicy::Reference
+    }
+}
+impl MissingGenotypePolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line and in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            MissingGenotypePolicy::Reference=>"ref",
+            MissingGenotypePolicy::SkipRecord=>"skip-record",
+            MissingGenotypePolicy::MajorAllele=>"major-allele",
+            MissingGenotypePolicy::Flag=>"flag"
+        }
+    }
+}
+impl FromStr for MissingGenotypePolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<MissingGenotypePolicy,String>
+    {
+        match policy.to_lowercase().replace('_',"-").as_str()
+        {
+            "ref" | "reference"=>Ok(MissingGenotypePolicy::Reference),
+            "skip-record" | "skip"=>Ok(MissingGenotypePolicy::SkipRecord),
+            "major-allele" | "major"=>Ok(MissingGenotypePolicy::MajorAllele),
+            "flag"=>Ok(MissingGenotypePolicy::Flag),
+            _=>Err(format!("{} is not a supported policy for missing genotypes, supported policies are: ref, skip-record, major-allele and flag",policy))
+        }
+    }
+}
+
+/// ## Summary
+/// Return whether a genotype call is missing, i.e. every allele is missing, e.g. ., ./. or .|., partially missing calls such as ./1 are
+/// not missing as the called allele is encoded in the bit-mask
+/// ## Example
+///```rust
+/// use ppgg::functions::missing_genotypes::is_missing_genotype;
+/// assert!(is_missing_genotype("./."));
+/// assert!(is_missing_genotype(".|."));
+/// assert!(is_missing_genotype("."));
+/// assert!(!is_missing_genotype("./1"));
+/// assert!(!is_missing_genotype("0|0"));
+///```
+pub fn is_missing_genotype(genotype:&str)->bool
+{
+    !genotype.is_empty() && genotype.split(['/','|'].as_ref()).all(|allele|allele==".")
+}
+
+/// ## Summary
+/// The missing genotype calls of a VCF file, i.e. the number of missing calls per sample, in the order of the VCF header, the number of
+/// missing calls that were imputed as carrying at least one consequence, the number of records that were skipped and, with the Flag
+/// policy, the transcripts flagged in each sample
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct MissingGenotypeSummary
+{
+    pub policy:MissingGenotypePolicy,
+    pub proband_names:Vec<String>,
+    pub num_missing_per_sample:Vec<usize>,
+    pub num_imputed_per_sample:Vec<usize>,
+    pub num_skipped_records:usize,
+    pub flagged_transcripts_per_sample:Vec<HashSet<String>>
+}
+impl MissingGenotypeSummary
+{
+    /// ## Summary
+    /// Return the total number of missing genotype calls
+    pub fn get_num_missing(&self)->usize
+    {
+        self.num_missing_per_sample.iter().sum()
+    }
+    /// ## Summary
+    /// Add the missing calls of another part of the same file, e.g. a chunk of a streamed file, see readers::stream_vcf
+    pub fn merge(&mut self, other:MissingGenotypeSummary)
+    {
+        for (counts,other_counts) in [(&mut self.num_missing_per_sample,other.num_missing_per_sample),(&mut self.num_imputed_per_sample,other.num_imputed_per_sample)]
+        {
+            counts.resize(counts.len().max(other_counts.len()),0);
+            counts.iter_mut().zip(other_counts).for_each(|(num,other_num)|*num+=other_num);
+        }
+        self.num_skipped_records+=other.num_skipped_records;
+        let flagged=&mut self.flagged_transcripts_per_sample;
+        flagged.resize(flagged.len().max(other.flagged_transcripts_per_sample.len()),HashSet::new());
+        flagged.iter_mut().zip(other.flagged_transcripts_per_sample).for_each(|(transcripts,other_transcripts)|transcripts.extend(other_transcripts));
+    }
+    /// ## Summary
+    /// Return the transcripts flagged in a sample, i.e. the transcripts of the records with a missing or a partially missing call in the
+    /// sample, empty if the policy is not Flag or the sample is unknown
+    pub fn get_flagged_transcripts(&self, sample_name:&str)->HashSet<String>
+    {
+        match self.proband_names.iter().position(|name|name==sample_name)
+        {
+            Some(sample_index)=>self.flagged_transcripts_per_sample.get(sample_index).cloned().unwrap_or_default(),
+            None=>HashSet::new()
+        }
+    }
+    /// ## Summary
+    /// Return a human readable report of the missing calls, i.e. the totals followed by the max_listed samples with the largest number
+    /// of missing calls
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} missing genotype calls in {} samples were handled with the {} policy, {} records were skipped and {} calls were imputed as carriers",
+            self.get_num_missing(),self.num_missing_per_sample.iter().filter(|num_missing|**num_missing!=0).count(),self.policy.as_str(),
+            self.num_skipped_records,self.num_imputed_per_sample.iter().sum::<usize>()).unwrap();
+        if self.policy==MissingGenotypePolicy::Flag
+        {
+            writeln!(report,"{} transcripts were flagged over all samples",self.flagged_transcripts_per_sample.iter().map(|transcripts|transcripts.len()).sum::<usize>()).unwrap();
+        }
+        let mut per_sample=self.proband_names.iter().zip(self.num_missing_per_sample.iter())
+            .filter(|(_,num_missing)|**num_missing!=0).collect::<Vec<(&String,&usize)>>();
+        per_sample.sort_by(|(name1,num1),(name2,num2)|num2.cmp(num1).then(name1.cmp(name2)));
+        for (name,num_missing) in per_sample.into_iter().take(max_listed)
+        {
+            writeln!(report,"\t{}\t{}",name,num_missing).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Apply the missing genotype policy to the records of a VCF file, i.e. the lines of its body, and return the retained records, in their
+/// order, along with a summary of the missing calls, see MissingGenotypePolicy, the proband names of the summary are left empty. The
+/// genotype and the bit-mask are located using the FORMAT layout of each record, records without a GT field have no missing calls.
+/// The bits of the uncalled haplotypes are cleared from the bit-masks of the called samples, see mask_uncalled_haplotypes.
+pub fn apply_missing_genotype_policy(lines:Vec<String>, num_probands:usize, policy:MissingGenotypePolicy, engine:Engine)->(Vec<String>,MissingGenotypeSummary)
+{
+    let results=match engine
+    {
+        Engine::ST=>lines.into_iter().map(|line|apply_to_record(line,policy)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.into_par_iter().map(|line|apply_to_record(line,policy)).collect::<Vec<_>>()
+    };
+    let mut summary=MissingGenotypeSummary{policy,num_missing_per_sample:vec![0;num_probands],num_imputed_per_sample:vec![0;num_probands],
+        ..Default::default()};
+    if policy==MissingGenotypePolicy::Flag
+    {
+        summary.flagged_transcripts_per_sample=vec![HashSet::new();num_probands];
+    }
+    let mut retained=Vec::with_capacity(results.len());
+    for (line,missing_samples,partial_samples) in results
+    {
+        if let (MissingGenotypePolicy::Flag,Some(line))=(policy,line.as_ref())
+        {
+            let transcripts=get_record_transcripts(line);
+            for sample_index in missing_samples.iter().map(|(sample_index,_)|sample_index).chain(partial_samples.iter())
+            {
+                if let Some(flagged)=summary.flagged_transcripts_per_sample.get_mut(*sample_index)
+                {
+                    flagged.extend(transcripts.iter().cloned());
+                }
+            }
+        }
+        for (sample_index,is_imputed) in missing_samples
+        {
+            if sample_index < num_probands
+            {
+                summary.num_missing_per_sample[sample_index]+=1;
+                summary.num_imputed_per_sample[sample_index]+=is_imputed as usize;
+            }
+        }
+        match line
+        {
+            Some(line)=>retained.push(line),
+            None=>summary.num_skipped_records+=1
+        }
+    }
+    (retained,summary)
+}
+
+/// ## Summary
+/// Apply the policy to a record and return the record, or None if it is skipped, along with the index of each sample with a missing call
+/// and whether the sample was imputed as carrying at least one consequence, and the index of each sample with a partially missing call
+fn apply_to_record(line:String, policy:MissingGenotypePolicy)->(Option<String>,Vec<(usize,bool)>,Vec<usize>)
+{
+    let (gt_index,bcsq_index)=match line.split('\t').nth(8)
+    {
+        Some(format)=>(format.split(':').position(|field|field=="GT"),format.split(':').position(|field|field=="BCSQ")),
+        None=>return (Some(line),Vec::new(),Vec::new())
+    };
+    let gt_index=match gt_index
+    {
+        Some(index)=>index,
+        None=>return (Some(line),Vec::new(),Vec::new())
+    };
+    let (line,partial_samples)=match bcsq_index
+    {
+        Some(bcsq_index)=>mask_uncalled_haplotypes(line, gt_index, bcsq_index),
+        None=>(line,Vec::new())
+    };
+    let missing_samples=line.split('\t').skip(9).enumerate()
+        .filter(|(_,field)|is_missing_genotype(field.split(':').nth(gt_index).unwrap_or("")))
+        .map(|(sample_index,_)|sample_index)
+        .collect::<Vec<usize>>();
+    if missing_samples.is_empty()
+    {
+        return (Some(line),Vec::new(),partial_samples)
+    }
+    let bcsq_index=match (policy,bcsq_index)
+    {
+        (MissingGenotypePolicy::SkipRecord,_)=>return (None,missing_samples.into_iter().map(|sample_index|(sample_index,false)).collect(),partial_samples),
+        (_,Some(index))=>index,
+        // without a bit-mask, the record has no consequences to impute
+        (_,None)=>return (Some(line),missing_samples.into_iter().map(|sample_index|(sample_index,false)).collect(),partial_samples)
+    };
+    let imputed_mask=match policy
+    {
+        MissingGenotypePolicy::MajorAllele=>
+        {
+            let called_masks=line.split('\t').skip(9).enumerate()
+                .filter(|(sample_index,_)|missing_samples.binary_search(sample_index).is_err())
+                .filter_map(|(_,field)|parse_raw_bit_mask(field.split(':').nth(bcsq_index).unwrap_or(".")))
+                .collect::<Vec<Vec<u32>>>();
+            BitMask::get_major_allele_mask(&called_masks)
+        },
+        _=>Vec::new()
+    };
+    let imputed_field=match imputed_mask.is_empty()
+    {
+        true=>"0".to_string(),
+        false=>imputed_mask.iter().map(|field|field.to_string()).collect::<Vec<String>>().join(",")
+    };
+    let mut fields=line.split('\t').map(|field|field.to_string()).collect::<Vec<String>>();
+    for sample_index in missing_samples.iter()
+    {
+        let field=&mut fields[9+sample_index];
+        let mut sub_fields=field.split(':').map(|sub_field|sub_field.to_string()).collect::<Vec<String>>();
+        while sub_fields.len()<=bcsq_index
+        {
+            sub_fields.push(".".to_string());
+        }
+        sub_fields[bcsq_index]=imputed_field.clone();
+        *field=sub_fields.join(":");
+    }
+    (Some(fields.join("\t")),missing_samples.into_iter().map(|sample_index|(sample_index,!imputed_mask.is_empty())).collect(),partial_samples)
+}
+
+/// ## Summary
+/// Clear the bits of the haplotypes without a called allele from the bit-masks of the samples with at least one called allele, e.g. the bits
+/// of the first haplotype of .|1 or the bits of the second haplotype of a haploid call, and replace a missing bit-mask of a called sample by
+/// zero. Returns the record, which is rewritten only if a bit-mask changed, along with the index of each sample with a partially missing call.
+/// ## Example
+///```rust
+/// use ppgg::functions::missing_genotypes::mask_uncalled_haplotypes;
+/// let record="X\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t.|1:3\t1:3\t0/0:.".to_string();
+/// assert_eq!(mask_uncalled_haplotypes(record,0,1),("X\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t.|1:2\t1:1\t0/0:0".to_string(),vec![0]));
+///```
+pub fn mask_uncalled_haplotypes(line:String, gt_index:usize, bcsq_index:usize)->(String,Vec<usize>)
+{
+    let mut partial_samples=Vec::new();
+    let mut masked=Vec::new();
+    for (sample_index,field) in line.split('\t').skip(9).enumerate()
+    {
+        let genotype=match Genotype::parse(field.split(':').nth(gt_index).unwrap_or(""))
+        {
+            Some(genotype) if !genotype.is_missing()=>genotype,
+            _=>continue
+        };
+        if genotype.is_partially_missing()
+        {
+            partial_samples.push(sample_index);
+        }
+        let bitmask=field.split(':').nth(bcsq_index).unwrap_or(".");
+        let called=genotype.get_called_haplotypes();
+        if called!=[true,true] || bitmask=="." || bitmask.is_empty()
+        {
+            let masked_bitmask=mask_bit_mask(bitmask, called);
+            if masked_bitmask!=bitmask
+            {
+                masked.push((sample_index,masked_bitmask));
+            }
+        }
+    }
+    if masked.is_empty()
+    {
+        return (line,partial_samples)
+    }
+    let mut fields=line.split('\t').map(|field|field.to_string()).collect::<Vec<String>>();
+    for (sample_index,masked_bitmask) in masked
+    {
+        let field=&mut fields[9+sample_index];
+        let mut sub_fields=field.split(':').map(|sub_field|sub_field.to_string()).collect::<Vec<String>>();
+        while sub_fields.len()<=bcsq_index
+        {
+            sub_fields.push(".".to_string());
+        }
+        sub_fields[bcsq_index]=masked_bitmask;
+        *field=sub_fields.join(":");
+    }
+    (fields.join("\t"),partial_samples)
+}
+
+/// keep the bits of the called haplotypes of a raw bit-mask, i.e. the even bits for the first and the odd bits for the second haplotype,
+/// a missing bit-mask or a bit-mask without any kept bit is zero, while an invalid bit-mask is returned unchanged for the decoder to report it
+fn mask_bit_mask(bitmask:&str, called:[bool;2])->String
+{
+    let (fields,suffix)=match bitmask.strip_suffix('$')
+    {
+        Some(fields)=>(fields,"$"),
+        None=>(bitmask,"")
+    };
+    let kept_bits=(if called[0] {0x5555_5555_u32} else {0})|(if called[1] {0xAAAA_AAAA_u32} else {0});
+    match parse_raw_bit_mask(fields)
+    {
+        Some(values) if values.iter().any(|value|value&kept_bits!=0)=>
+        {
+            format!("{}{}",values.iter().map(|value|(value&kept_bits).to_string()).collect::<Vec<String>>().join(","),suffix)
+        },
+        Some(_)=>"0".to_string(),
+        None if bitmask=="." || bitmask.is_empty()=>"0".to_string(),
+        None=>bitmask.to_string()
+    }
+}
+
+/// return the transcripts of the supported consequences of a record
+fn get_record_transcripts(line:&str)->Vec<String>
+{
+    let info=line.split('\t').nth(7).unwrap_or("");
+    info.split(';')
+        .filter_map(|info_field|info_field.strip_prefix("BCSQ="))
+        .flat_map(|bcsq|bcsq.split(','))
+        .filter(|csq|vcf_helpers::is_supported_csq(csq))
+        .filter_map(|csq|csq.split('|').nth(2).map(|transcript|transcript.to_string()))
+        .collect()
+}
+
+/// ## Summary
+/// Parse the raw bit-mask of a called sample, e.g. 10922,14, into its fields, None is returned for missing or invalid bit-masks, which
+/// do not contribute to the major allele
+fn parse_raw_bit_mask(bitmask:&str)->Option<Vec<u32>>
+{
+    bitmask.split(',').map(|field|field.parse::<u32>().ok()).collect::<Option<Vec<u32>>>()
+}
+
+#[cfg(test)]
+mod test_missing_genotypes
+{
+    use super::*;
+    #[test]
+    fn test_apply_missing_genotype_policy()
+    {
+        let lines=["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t1|1:3\t./.:5\t0|1:2\t1|0:1",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:DP:BCSQ\t0|1:30:2\t1|1:30:3\t.|.\t0|0:30:0",
+            "1\t30\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t0|1:2\t0|1:2\t0|0:0\t0|0:0"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let (retained,summary)=apply_missing_genotype_policy(lines.clone(),4,MissingGenotypePolicy::Reference,Engine::ST);
+        assert_eq!(retained[0],"1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t1|1:3\t./.:0\t0|1:2\t1|0:1");
+        assert_eq!(retained[1],"1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:DP:BCSQ\t0|1:30:2\t1|1:30:3\t.|.:.:0\t0|0:30:0");
+        assert_eq!(retained[2],lines[2]);
+        assert_eq!((summary.num_missing_per_sample.clone(),summary.num_skipped_records),(vec![0,1,1,0],0));
+        let (retained,summary)=apply_missing_genotype_policy(lines.clone(),4,MissingGenotypePolicy::SkipRecord,Engine::MT);
+        assert_eq!((retained,summary.num_skipped_records,summary.get_num_missing()),(vec![lines[2].clone()],2,2));
+        let (retained,summary)=apply_missing_genotype_policy(lines.clone(),4,MissingGenotypePolicy::MajorAllele,Engine::ST);
+        // the consequence of the first record is carried by 4 out of 6 called haplotypes, the one of the second by 3 out of 6
+        assert_eq!(retained[0],"1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:BCSQ\t1|1:3\t./.:3\t0|1:2\t1|0:1");
+        assert_eq!(retained[1],"1\t20\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:DP:BCSQ\t0|1:30:2\t1|1:30:3\t.|.:.:0\t0|0:30:0");
+        assert_eq!(summary.num_imputed_per_sample,vec![0,1,0,0]);
+        assert_eq!(MissingGenotypePolicy::from_str("skip_record").unwrap(),MissingGenotypePolicy::SkipRecord);
+        assert!(MissingGenotypePolicy::from_str("drop").is_err());
+    }
+    #[test]
+    fn test_uncalled_haplotypes_and_flags()
+    {
+        // the bit-mask 15 selects the first and the second consequence on both haplotypes
+        let lines=["X\t10\t.\tA\tT,C\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G2|T2|protein_coding|+|5K>5N|10A>C\tGT:BCSQ\t.|1:15\t1:15\t./.:15\t1/2:15$",
+            "X\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G3|T3|protein_coding|+|8K>8N|20A>T\tGT:BCSQ\t0|0:.\t1:1\t0|0:0\t0|0:0"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let (retained,summary)=apply_missing_genotype_policy(lines.clone(),4,MissingGenotypePolicy::Flag,Engine::ST);
+        assert_eq!(retained[0],"X\t10\t.\tA\tT,C\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G2|T2|protein_coding|+|5K>5N|10A>C\tGT:BCSQ\t.|1:10\t1:5\t./.:0\t1/2:15$");
+        assert_eq!(retained[1],"X\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G3|T3|protein_coding|+|8K>8N|20A>T\tGT:BCSQ\t0|0:0\t1:1\t0|0:0\t0|0:0");
+        assert_eq!(summary.num_missing_per_sample,vec![0,0,1,0]);
+        let expected=vec!["T1".to_string(),"T2".to_string()].into_iter().collect::<HashSet<String>>();
+        assert_eq!(summary.flagged_transcripts_per_sample,vec![expected.clone(),HashSet::new(),expected,HashSet::new()]);
+        assert!(summary.to_report(2).contains("4 transcripts were flagged"));
+        // the uncalled haplotypes are cleared with every policy, whereas the transcripts are flagged with Flag only
+        let (retained_ref,summary)=apply_missing_genotype_policy(lines,4,MissingGenotypePolicy::Reference,Engine::MT);
+        assert_eq!((retained_ref,summary.flagged_transcripts_per_sample.len()),(retained,0));
+        assert_eq!(MissingGenotypePolicy::from_str("flag").unwrap().as_str(),"flag");
+    }
+}
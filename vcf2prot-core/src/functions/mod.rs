@@ -0,0 +1,63 @@
+/// A module for managing and handling text parsing functions, the current functions are used for
+/// parsing and analyzing different aspects of the VCF text files  
+///``` 
+///``` 
+pub mod text_parser; 
+pub mod progress; 
+pub mod diagnostics; 
+#[cfg(feature = "parser")]
+pub mod vcf_tools; 
+#[cfg(feature = "stats")]
+pub mod summary;
+#[cfg(feature = "parser")]
+pub mod effect_validation;
+#[cfg(feature = "engine")]
+pub mod hgvs;
+#[cfg(feature = "stats")]
+pub mod compatibility;
+#[cfg(feature = "stats")]
+pub mod run_status;
+#[cfg(feature = "engine")]
+pub mod verify;
+#[cfg(feature = "parser")]
+pub mod contigs;
+#[cfg(feature = "stats")]
+pub mod gene_report;
+#[cfg(feature = "parser")]
+pub mod sex_chromosomes;
+#[cfg(feature = "engine")]
+pub mod length_stats;
+#[cfg(feature = "parser")]
+pub mod missing_genotypes;
+#[cfg(feature = "writers")]
+pub mod incremental;
+#[cfg(feature = "parser")]
+pub mod duplicate_records;
+#[cfg(feature = "parser")]
+pub mod maf;
+#[cfg(feature = "parser")]
+pub mod mutation_tsv;
+#[cfg(feature = "parser")]
+pub mod vep;
+#[cfg(feature = "engine")]
+pub mod self_test;
+#[cfg(feature = "parser")]
+pub mod upstream_tools;
+#[cfg(feature = "parser")]
+pub mod sample_vcfs;
+#[cfg(feature = "parser")]
+pub mod sample_selection;
+#[cfg(feature = "parser")]
+pub mod somatic;
+#[cfg(feature = "parser")]
+pub mod quality_filters;
+#[cfg(feature = "parser")]
+pub mod silent_variants;
+#[cfg(feature = "stats")]
+pub mod metrics;
+#[cfg(feature = "engine")]
+pub mod coordinate_audit;
+#[cfg(feature = "writers")]
+pub mod smoke;
+#[cfg(feature = "writers")]
+pub mod provenance;
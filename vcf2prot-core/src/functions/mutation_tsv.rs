@@ -0,0 +1,162 @@
+/// The module converts mutation lists that were prepared per sample and grouped per transcript, i.e. tab-separated files with the
+/// transcript, the consequence type and the protein change of each mutation, into the intermediate representation, so the instructions
+/// and the execution engines can be used without a VCF file
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::data_structures::mutation_ds::Mutation;
+
+/// ## Summary
+/// The haplotypes a mutation of a mutation list is placed on, read from the optional fourth column, where 1 and 2 place the mutation on
+/// the first, respectively the second, haplotype and 1|2 on both of them
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum MutationHaplotype
+{
+    First,
+    Second,
+    Both
+}
+impl MutationHaplotype
+{
+    /// ## Summary
+    /// Parse the haplotype column of a mutation list, an empty column places the mutation on the first haplotype
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::mutation_tsv::MutationHaplotype;
+    /// assert_eq!(MutationHaplotype::from_field("").unwrap(),MutationHaplotype::First);
+    /// assert_eq!(MutationHaplotype::from_field("1|2").unwrap(),MutationHaplotype::Both);
+    /// assert!(MutationHaplotype::from_field("3").is_err());
+    ///```
+    pub fn from_field(field:&str)->Result<Self,String>
+    {
+        match field
+        {
+            "" | "1"=>Ok(MutationHaplotype::First),
+            "2"=>Ok(MutationHaplotype::Second),
+            "1|2" | "2|1"=>Ok(MutationHaplotype::Both),
+            _=>Err(format!("the haplotype: {} is not supported, the supported haplotypes are 1, 2 and 1|2",field))
+        }
+    }
+}
+
+/// ## Summary
+/// Convert the lines of the mutation list of a sample into its intermediate representation. Comment lines, i.e. starting with #, and empty
+/// lines are skipped, as is a header line starting with the transcript column, e.g. transcript, consequence and protein_change. Each remaining
+/// line describes one mutation with the following tab-separated columns:
+/// 1. The transcript id, e.g. ENST00000406869, as named in the reference proteome.
+/// 2. The consequence type as written by BCFtools/csq, e.g. missense, *frameshift or inframe_deletion, see MutationType.
+/// 3. The protein change as written in the amino acid field of a BCSQ annotation, e.g. 640V>640E or 34ELREA>34E.
+/// 4. Optionally, the haplotype carrying the mutation, see MutationHaplotype, the first haplotype is used if the column is missing.
+///
+/// The positions of the mutations in the mutated sequences are derived from the length changes of the preceding mutations of each
+/// haplotype, see AltTranscript::assign_mutated_positions, so the lists can be prepared with reference positions only. The repeated lines
+/// of a mutation are dropped. An error is returned for the first line with fewer than three columns, an unsupported consequence type, an
+/// invalid protein change or an invalid haplotype, along with its line number.
+/// ## Example
+///```rust
+/// use ppgg::functions::mutation_tsv::mutation_tsv_to_int_map;
+/// let lines=["transcript\tconsequence\tprotein_change","T1\tmissense\t2K>2N","T1\tinframe_deletion\t4AEL>4A\t1|2"].iter()
+///     .map(|line|line.to_string()).collect::<Vec<String>>();
+/// let int_map=mutation_tsv_to_int_map("S1",&lines).unwrap();
+/// let (haplotype1,haplotype2)=int_map.get_mutations_ref();
+/// assert_eq!((haplotype1[0].alts.len(),haplotype2[0].alts.len()),(2,1));
+///```
+pub fn mutation_tsv_to_int_map(proband_name:&str, lines:&[String])->Result<IntMap,String>
+{
+    let mut haplotype1:BTreeMap<String,Vec<Mutation>>=BTreeMap::new();
+    let mut haplotype2:BTreeMap<String,Vec<Mutation>>=BTreeMap::new();
+    let mut transcript_names:BTreeMap<String,Arc<str>>=BTreeMap::new();
+    let mut is_first=true;
+    for (line_number,line) in lines.iter().enumerate().map(|(index,line)|(index+1,line))
+    {
+        if line.starts_with('#') || line.trim().is_empty()
+        {
+            continue
+        }
+        let fields=line.split('\t').map(|field|field.trim()).collect::<Vec<&str>>();
+        if is_first && fields[0].eq_ignore_ascii_case("transcript")
+        {
+            is_first=false;
+            continue
+        }
+        is_first=false;
+        if fields.len()<3
+        {
+            return Err(format!("Line {} of the mutation list of sample: {} has {} columns, while at least 3 columns, i.e. the transcript, the consequence type and the protein change, are required",
+                line_number,proband_name,fields.len()))
+        }
+        let transcript_name=transcript_names.entry(fields[0].to_string()).or_insert_with(||Arc::from(fields[0])).clone();
+        let mutation=match Mutation::from_csq_fields(fields[1], transcript_name, fields[2])
+        {
+            Ok(mutation)=>mutation,
+            Err(err_msg)=>return Err(format!("Line {} of the mutation list of sample: {} is not valid: {}",line_number,proband_name,err_msg))
+        };
+        let haplotype=match MutationHaplotype::from_field(fields.get(3).copied().unwrap_or(""))
+        {
+            Ok(haplotype)=>haplotype,
+            Err(err_msg)=>return Err(format!("Line {} of the mutation list of sample: {} is not valid: {}",line_number,proband_name,err_msg))
+        };
+        if haplotype!=MutationHaplotype::First
+        {
+            haplotype2.entry(fields[0].to_string()).or_default().push(mutation.clone());
+        }
+        if haplotype!=MutationHaplotype::Second
+        {
+            haplotype1.entry(fields[0].to_string()).or_default().push(mutation);
+        }
+    }
+    Ok(IntMap::new(proband_name.to_string(),to_alt_transcripts(haplotype1),to_alt_transcripts(haplotype2)))
+}
+
+/// ## Summary
+/// Group the mutations of a haplotype into altered transcripts, in the order of the transcript names, the repeated lines of a mutation
+/// are dropped and the positions of the mutated sequences are assigned, see AltTranscript::assign_mutated_positions
+fn to_alt_transcripts(haplotype:BTreeMap<String,Vec<Mutation>>)->Vec<AltTranscript>
+{
+    haplotype.into_iter()
+        .map(|(transcript,mut mutations)|
+        {
+            mutations.sort_by_key(|mutation|mutation.mut_info.ref_aa_position);
+            mutations.dedup_by(|mutation2,mutation1|mutation1.mut_info.ref_aa_position==mutation2.mut_info.ref_aa_position &&
+                mutation1.mut_info.ref_aa==mutation2.mut_info.ref_aa && mutation1.mut_info.mut_aa==mutation2.mut_info.mut_aa && mutation1.mut_type==mutation2.mut_type);
+            let mut alt_transcript=AltTranscript::from_mutations(transcript,mutations);
+            alt_transcript.assign_mutated_positions();
+            alt_transcript
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_mutation_tsv
+{
+    use super::*;
+    use crate::data_structures::mutation_ds::{MutatedString,MutationType};
+    #[test]
+    fn test_mutation_tsv_to_int_map()
+    {
+        let lines=["# prepared for S1","transcript\tconsequence\tprotein_change\thaplotype",
+            "T2\tmissense\t3L>3P","",
+            "T1\tinframe_deletion\t2KAE>2K\t1|2",
+            "T1\t*missense\t7W>7C",
+            "T1\tstop_gained\t6Q>6*\t2",
+            "T1\t*missense\t7W>7C"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let int_map=mutation_tsv_to_int_map("S1",&lines).unwrap();
+        assert!(int_map.validate().is_ok());
+        let (haplotype1,haplotype2)=int_map.get_mutations_ref();
+        assert_eq!(haplotype1.iter().map(|alt|alt.name.clone()).collect::<Vec<String>>(),vec!["T1".to_string(),"T2".to_string()]);
+        // the deletion shifts the missense mutation by two residues in the mutated sequence and the repeated line is dropped
+        let fields=haplotype1[0].alts.iter()
+            .map(|mutation|(mutation.mut_info.ref_aa_position,mutation.mut_info.mut_aa_position,mutation.mut_type.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(fields,vec![(1,1,MutationType::InframeDeletion),(6,4,MutationType::SMisSense)]);
+        assert_eq!((haplotype2.len(),haplotype2[0].alts.len()),(1,2));
+        assert_eq!((haplotype2[0].alts[1].mut_info.mut_aa_position,haplotype2[0].alts[1].mut_info.mut_aa.clone()),(3,MutatedString::NotSeq));
+        // the invalid lines are reported with their line number
+        let invalid=|line:&str|mutation_tsv_to_int_map("S1",&[line.to_string()]).unwrap_err();
+        assert!(invalid("T1\tmissense").contains("Line 1"));
+        assert!(invalid("T1\tsynonymous\t2K>2K").contains("not supported"));
+        assert!(invalid("T1\tmissense\t2K>2N\t3").contains("haplotype"));
+    }
+}
@@ -0,0 +1,411 @@
+/// The module reports the progress of a run, the parser, the executioner and the writers count the parsed records, the translated
+/// transcripts and the written probands in the tracker of the run that is passed to them through their options, which is sampled by a
+/// reporter thread that redraws a progress bar or prints a JSON line per interval, along with the stage of the run and the estimated
+/// remaining time of the stage.
+use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::{Arc,Mutex};
+use std::sync::atomic::{AtomicBool,AtomicU8,AtomicU64,Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration,Instant};
+
+/// The interval between two redraws of the progress bar
+pub const BAR_INTERVAL:Duration=Duration::from_millis(500);
+/// The interval between two JSON progress lines, a line is printed on every stage change as well
+pub const JSON_INTERVAL:Duration=Duration::from_secs(5);
+/// The number of characters of the progress bar
+const BAR_WIDTH:usize=20;
+
+/// ## Summary
+/// The way the progress of a run is reported:
+/// 1. Log => the start and the end of each stage are printed with a timestamp when the run is verbose, the default.
+/// 2. Bar => a progress bar is redrawn on the standard error, which is meant for interactive terminals.
+/// 3. Json => a JSON object per line is printed on the standard error, which is meant for workflow managers and log collectors.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ProgressMode
+{
+    Log,
+    Bar,
+    Json
+}
+impl Default for ProgressMode
+{
+    fn default()->Self
+    {
+        ProgressMode::Log
+    }
+}
+impl ProgressMode
+{
+    /// ## Summary
+    /// Return the name of the mode as used on the command line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ProgressMode::Log=>"log",
+            ProgressMode::Bar=>"bar",
+            ProgressMode::Json=>"json"
+        }
+    }
+}
+impl FromStr for ProgressMode
+{
+    type Err=String;
+    fn from_str(mode:&str)->Result<ProgressMode,String>
+    {
+        match mode.to_lowercase().as_str()
+        {
+            "log"=>Ok(ProgressMode::Log),
+            "bar"=>Ok(ProgressMode::Bar),
+            "json"=>Ok(ProgressMode::Json),
+            _=>Err(format!("{} is not a supported progress mode, supported modes are: log, bar and json",mode))
+        }
+    }
+}
+
+/// ## Summary
+/// The stages of a run, in the order they are passed
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ProgressStage
+{
+    Parsing,
+    Loading,
+    Executing,
+    Writing,
+    Finished
+}
+impl Default for ProgressStage
+{
+    fn default()->Self
+    {
+        ProgressStage::Parsing
+    }
+}
+impl ProgressStage
+{
+    /// ## Summary
+    /// Return the name of the stage as used in the progress reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ProgressStage::Parsing=>"parsing",
+            ProgressStage::Loading=>"loading",
+            ProgressStage::Executing=>"executing",
+            ProgressStage::Writing=>"writing",
+            ProgressStage::Finished=>"finished"
+        }
+    }
+    fn from_u8(stage:u8)->Self
+    {
+        match stage
+        {
+            1=>ProgressStage::Loading,
+            2=>ProgressStage::Executing,
+            3=>ProgressStage::Writing,
+            4=>ProgressStage::Finished,
+            _=>ProgressStage::Parsing
+        }
+    }
+}
+
+/// ## Summary
+/// A snapshot of the progress of the run, see snapshot
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct ProgressSnapshot
+{
+    pub stage:ProgressStage,
+    /// the time since the start of the first stage
+    pub elapsed:Duration,
+    /// the time since the start of the current stage
+    pub stage_elapsed:Duration,
+    pub num_records_parsed:u64,
+    pub num_transcripts_translated:u64,
+    pub num_probands_translated:u64,
+    pub num_probands_written:u64,
+    /// the number of probands of the run, None while the VCF file is parsed
+    pub num_probands:Option<u64>
+}
+impl ProgressSnapshot
+{
+    /// ## Summary
+    /// Return the probands completed by the current stage and the number of probands of the run, i.e. the translated probands while
+    /// executing and the written probands while writing, None if the stage has no known end, e.g. while the VCF file is parsed
+    pub fn get_stage_progress(&self)->Option<(u64,u64)>
+    {
+        let num_probands=self.num_probands?;
+        match self.stage
+        {
+            ProgressStage::Executing=>Some((self.num_probands_translated.min(num_probands),num_probands)),
+            ProgressStage::Writing=>Some((self.num_probands_written.min(num_probands),num_probands)),
+            _=>None
+        }
+    }
+    /// ## Summary
+    /// Return the estimated remaining time of the current stage, assuming the remaining probands take as long as the completed ones, None
+    /// if the stage has no known end or no proband was completed yet
+    /// ## Example
+    ///```rust
+    /// use std::time::Duration;
+    /// use ppgg::functions::progress::{ProgressSnapshot,ProgressStage};
+    /// let snapshot=ProgressSnapshot{stage:ProgressStage::Writing,stage_elapsed:Duration::from_secs(30),num_probands_written:10,num_probands:Some(40),
+    ///     ..Default::default()};
+    /// assert_eq!(snapshot.get_eta(),Some(Duration::from_secs(90)));
+    /// assert_eq!(ProgressSnapshot{stage:ProgressStage::Parsing,..snapshot}.get_eta(),None);
+    ///```
+    pub fn get_eta(&self)->Option<Duration>
+    {
+        match self.get_stage_progress()?
+        {
+            (0,_)=>None,
+            (num_done,num_probands)=>Some(Duration::from_secs_f64(self.stage_elapsed.as_secs_f64()*(num_probands-num_done) as f64/num_done as f64))
+        }
+    }
+    /// ## Summary
+    /// Return the snapshot as a single line of the progress bar, e.g.
+    /// [writing] 1200 records parsed | 340 transcripts translated | 10/40 probands written [#####---------------] 25% ETA 0:01:30
+    pub fn to_bar_line(&self)->String
+    {
+        let mut line=format!("[{}] {} records parsed | {} transcripts translated",self.stage.as_str(),self.num_records_parsed,self.num_transcripts_translated);
+        if let Some((num_done,num_probands))=self.get_stage_progress()
+        {
+            let verb=if self.stage==ProgressStage::Writing {"written"} else {"translated"};
+            let num_filled=match num_probands
+            {
+                0=>BAR_WIDTH,
+                _=>(num_done as usize*BAR_WIDTH)/num_probands as usize
+            };
+            write!(line," | {}/{} probands {} [{}{}] {}%",num_done,num_probands,verb,"#".repeat(num_filled),"-".repeat(BAR_WIDTH-num_filled),
+                num_done*100/num_probands.max(1)).unwrap();
+        }
+        if let Some(eta)=self.get_eta()
+        {
+            let seconds=eta.as_secs();
+            write!(line," ETA {}:{:02}:{:02}",seconds/3600,(seconds/60)%60,seconds%60).unwrap();
+        }
+        line
+    }
+    /// ## Summary
+    /// Return the snapshot as a JSON object on a single line, where the number of probands and the remaining time are null while unknown
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::progress::{ProgressSnapshot,ProgressStage};
+    /// let snapshot=ProgressSnapshot{stage:ProgressStage::Parsing,num_records_parsed:12,..Default::default()};
+    /// assert_eq!(snapshot.to_json_line(),"{\"stage\":\"parsing\",\"elapsed_seconds\":0.0,\"records_parsed\":12,\"transcripts_translated\":0,\
+    /// \"probands_translated\":0,\"probands_written\":0,\"num_probands\":null,\"eta_seconds\":null}");
+    ///```
+    pub fn to_json_line(&self)->String
+    {
+        let or_null=|value:Option<u64>|value.map_or("null".to_string(),|value|value.to_string());
+        format!("{{\"stage\":\"{}\",\"elapsed_seconds\":{:.1},\"records_parsed\":{},\"transcripts_translated\":{},\"probands_translated\":{},\
+            \"probands_written\":{},\"num_probands\":{},\"eta_seconds\":{}}}",self.stage.as_str(),self.elapsed.as_secs_f64(),self.num_records_parsed,
+            self.num_transcripts_translated,self.num_probands_translated,self.num_probands_written,or_null(self.num_probands),
+            or_null(self.get_eta().map(|eta|eta.as_secs())))
+    }
+}
+/// ## Summary
+/// The progress of a run, i.e. its stage and the counts of the parsed records, the translated transcripts and the written probands, a
+/// tracker is shared by the parser, the executioner and the writers of a run through their options, hence, two runs of the library in
+/// the same process, e.g. a run and a request of a server, are tracked apart
+#[derive(Debug)]
+pub struct ProgressTracker
+{
+    num_records_parsed:AtomicU64,
+    num_transcripts_translated:AtomicU64,
+    num_probands_translated:AtomicU64,
+    num_probands_written:AtomicU64,
+    /// the number of probands of the run, u64::MAX while it is unknown, i.e. before the VCF file is parsed
+    num_probands:AtomicU64,
+    stage:AtomicU8,
+    /// the start of the run and of the current stage, None before the first stage is started
+    stage_start:Mutex<Option<(Instant,Instant)>>,
+    /// the time spent in each completed stage, in the order the stages were passed
+    stage_durations:Mutex<Vec<(ProgressStage,Duration)>>
+}
+impl Default for ProgressTracker
+{
+    fn default()->Self
+    {
+        ProgressTracker::new()
+    }
+}
+impl ProgressTracker
+{
+    /// ## Summary
+    /// Create the tracker of a run that has not started its first stage yet
+    pub fn new()->Self
+    {
+        ProgressTracker{num_records_parsed:AtomicU64::new(0),num_transcripts_translated:AtomicU64::new(0),num_probands_translated:AtomicU64::new(0),
+            num_probands_written:AtomicU64::new(0),num_probands:AtomicU64::new(u64::MAX),stage:AtomicU8::new(0),stage_start:Mutex::new(None),
+            stage_durations:Mutex::new(Vec::new())}
+    }
+    /// ## Summary
+    /// Start a stage of the run, the remaining time is estimated from the progress made since the start of the current stage, and the time
+    /// spent in the previous stage is recorded, see get_stage_durations
+    pub fn start_stage(&self, stage:ProgressStage)
+    {
+        let now=Instant::now();
+        let mut stage_start=self.stage_start.lock().unwrap();
+        if let Some((_,previous_start))=*stage_start
+        {
+            self.stage_durations.lock().unwrap().push((ProgressStage::from_u8(self.stage.load(Ordering::SeqCst)),now-previous_start));
+        }
+        *stage_start=Some((stage_start.map_or(now,|(run_start,_)|run_start),now));
+        self.stage.store(stage as u8, Ordering::SeqCst);
+    }
+    /// ## Summary
+    /// Set the number of probands that are executed and written by the run, e.g. the samples left after an incremental run reused the others
+    pub fn set_num_probands(&self, num_probands:usize)
+    {
+        self.num_probands.store(num_probands as u64, Ordering::SeqCst);
+    }
+    /// ## Summary
+    /// Count the supported records of the VCF file that were parsed
+    pub fn add_parsed_records(&self, num_records:usize)
+    {
+        self.num_records_parsed.fetch_add(num_records as u64, Ordering::Relaxed);
+    }
+    /// ## Summary
+    /// Count an executed proband along with the number of transcripts that were translated for it, summed over both haplotypes
+    pub fn add_translated_proband(&self, num_transcripts:usize)
+    {
+        self.num_transcripts_translated.fetch_add(num_transcripts as u64, Ordering::Relaxed);
+        self.num_probands_translated.fetch_add(1, Ordering::Relaxed);
+    }
+    /// ## Summary
+    /// Count a proband whose fasta file was written
+    pub fn add_written_proband(&self)
+    {
+        self.num_probands_written.fetch_add(1, Ordering::Relaxed);
+    }
+    /// ## Summary
+    /// Return the time spent in each completed stage of the run, in the order the stages were passed, the current stage is not part of the
+    /// durations until the next stage is started, e.g. the writing stage is recorded once the finished stage is started
+    pub fn get_stage_durations(&self)->Vec<(ProgressStage,Duration)>
+    {
+        self.stage_durations.lock().unwrap().clone()
+    }
+    /// ## Summary
+    /// Return a snapshot of the progress of the run
+    pub fn snapshot(&self)->ProgressSnapshot
+    {
+        let now=Instant::now();
+        let (run_start,stage_start)=self.stage_start.lock().unwrap().unwrap_or((now,now));
+        ProgressSnapshot{stage:ProgressStage::from_u8(self.stage.load(Ordering::SeqCst)),elapsed:now-run_start,stage_elapsed:now-stage_start,
+            num_records_parsed:self.num_records_parsed.load(Ordering::Relaxed),num_transcripts_translated:self.num_transcripts_translated.load(Ordering::Relaxed),
+            num_probands_translated:self.num_probands_translated.load(Ordering::Relaxed),num_probands_written:self.num_probands_written.load(Ordering::Relaxed),
+            num_probands:Some(self.num_probands.load(Ordering::SeqCst)).filter(|num_probands|*num_probands!=u64::MAX)}
+    }
+}
+
+/// ## Summary
+/// A thread that reports the progress of the run on the standard error with a progress bar or JSON lines, see ProgressMode, the last
+/// report is printed when the reporter is finished or dropped
+#[derive(Debug)]
+pub struct ProgressReporter
+{
+    is_stopped:Arc<AtomicBool>,
+    handle:Option<JoinHandle<()>>
+}
+impl ProgressReporter
+{
+    /// ## Summary
+    /// Start reporting the progress of the provided tracker with the provided mode, None is returned for ProgressMode::Log, as its lines are
+    /// printed by the caller
+    pub fn start(mode:ProgressMode, tracker:Arc<ProgressTracker>)->Option<Self>
+    {
+        let interval=match mode
+        {
+            ProgressMode::Log=>return None,
+            ProgressMode::Bar=>BAR_INTERVAL,
+            ProgressMode::Json=>JSON_INTERVAL
+        };
+        let is_stopped=Arc::new(AtomicBool::new(false));
+        let stop_signal=is_stopped.clone();
+        let handle=std::thread::spawn(move ||
+        {
+            let report=|snapshot:&ProgressSnapshot|match mode
+            {
+                ProgressMode::Json=>eprintln!("{}",snapshot.to_json_line()),
+                _=>eprint!("\r\x1b[K{}",snapshot.to_bar_line())
+            };
+            let (mut last_report,mut last_stage)=(Instant::now(),None);
+            while !stop_signal.load(Ordering::SeqCst)
+            {
+                let snapshot=tracker.snapshot();
+                if last_stage!=Some(snapshot.stage) || last_report.elapsed()>=interval
+                {
+                    report(&snapshot);
+                    last_report=Instant::now();
+                    last_stage=Some(snapshot.stage);
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            report(&tracker.snapshot());
+            if mode==ProgressMode::Bar
+            {
+                eprintln!();
+            }
+        });
+        Some(ProgressReporter{is_stopped,handle:Some(handle)})
+    }
+    /// ## Summary
+    /// Stop the reporter after printing the last report
+    pub fn finish(mut self)
+    {
+        self.stop();
+    }
+    fn stop(&mut self)
+    {
+        self.is_stopped.store(true, Ordering::SeqCst);
+        if let Some(handle)=self.handle.take()
+        {
+            handle.join().unwrap();
+        }
+    }
+}
+impl Drop for ProgressReporter
+{
+    fn drop(&mut self)
+    {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod test_progress
+{
+    use super::*;
+    #[test]
+    fn test_stage_progress()
+    {
+        let snapshot=ProgressSnapshot{stage:ProgressStage::Executing,num_probands_translated:5,num_probands_written:1,num_probands:Some(20),..Default::default()};
+        assert_eq!(snapshot.get_stage_progress(),Some((5,20)));
+        assert!(snapshot.to_bar_line().contains("5/20 probands translated [#####---------------] 25%"));
+        let writing=ProgressSnapshot{stage:ProgressStage::Writing,..snapshot.clone()};
+        assert_eq!(writing.get_stage_progress(),Some((1,20)));
+        // nothing is completed yet, hence, no remaining time can be estimated
+        let starting=ProgressSnapshot{num_probands_written:0,..writing};
+        assert_eq!((starting.get_eta(),starting.to_bar_line().contains("ETA")),(None,false));
+        assert_eq!(ProgressSnapshot{num_probands:None,..snapshot}.get_stage_progress(),None);
+        assert_eq!(ProgressMode::from_str("JSON").unwrap(),ProgressMode::Json);
+        assert!(ProgressMode::from_str("spinner").is_err());
+    }
+    #[test]
+    fn test_trackers_are_kept_apart()
+    {
+        let (tracker,other)=(ProgressTracker::new(),ProgressTracker::new());
+        tracker.start_stage(ProgressStage::Parsing);
+        tracker.add_parsed_records(12);
+        tracker.start_stage(ProgressStage::Executing);
+        tracker.set_num_probands(2);
+        tracker.add_translated_proband(7);
+        other.add_written_proband();
+        let snapshot=tracker.snapshot();
+        assert_eq!((snapshot.stage,snapshot.num_records_parsed,snapshot.num_transcripts_translated,snapshot.num_probands_written,snapshot.num_probands),
+            (ProgressStage::Executing,12,7,0,Some(2)));
+        assert_eq!(tracker.get_stage_durations().iter().map(|(stage,_)|*stage).collect::<Vec<_>>(),vec![ProgressStage::Parsing]);
+        assert_eq!((other.snapshot().num_records_parsed,other.snapshot().num_probands,other.get_stage_durations().len()),(0,None,0));
+    }
+}
@@ -0,0 +1,141 @@
+/// The module records the provenance of a run for workflow managers, i.e. the version of the tool, the command line, the size and the digest
+/// of every input file, the number of probands, the number of processed and skipped transcripts and the time spent in each stage of the
+/// run, which are written as a single JSON document to the output directory, see writers::write_provenance_manifest. In contrast to the
+/// run manifest of incremental runs, see incremental::RunManifest, the provenance manifest is not read back by vcf2prot.
+use std::path::Path;
+use std::time::Duration;
+use serde::Serialize;
+use crate::functions::incremental;
+use crate::functions::metrics::MetricsSnapshot;
+use crate::functions::progress::ProgressStage;
+
+/// ## Summary
+/// The name of the digest algorithm used for the input files, see incremental::get_file_digest
+pub const DIGEST_ALGORITHM:&str="fnv1a-64";
+
+/// ## Summary
+/// An input file of the run, i.e. its role, e.g. vcf or reference, the path as provided on the command line, its size in bytes and the
+/// digest of its raw content, i.e. of the compressed bytes for compressed files
+#[derive(Debug,Clone,PartialEq,Eq,Serialize)]
+pub struct InputFile
+{
+    pub role:String,
+    pub path:String,
+    pub num_bytes:u64,
+    pub digest:String
+}
+impl InputFile
+{
+    /// ## Summary
+    /// Read an input file and compute its size and digest, an error is returned if the file can not be read
+    pub fn new(role:&str, path2file:&Path)->Result<Self,String>
+    {
+        let (num_bytes,digest)=incremental::get_file_digest(path2file)?;
+        Ok(InputFile{role:role.to_string(),path:path2file.display().to_string(),num_bytes,digest})
+    }
+}
+
+/// ## Summary
+/// The time spent in a stage of the run in seconds, see progress::ProgressTracker::get_stage_durations
+#[derive(Debug,Clone,PartialEq,Serialize)]
+pub struct StageTiming
+{
+    pub stage:String,
+    pub seconds:f64
+}
+
+/// ## Summary
+/// The provenance of a run, where the reference fasta file is listed along with the other inputs under the reference role, the processed
+/// transcripts are the transcripts executed per haplotype, summed over all probands, while the skipped and rejected transcripts are the
+/// transcripts that were skipped by the execution or not written because of placeholder residues, see metrics::ProbandMetrics
+#[derive(Debug,Clone,PartialEq,Serialize)]
+pub struct ProvenanceManifest
+{
+    pub tool:String,
+    pub version:String,
+    pub command_line:Vec<String>,
+    pub digest_algorithm:String,
+    pub inputs:Vec<InputFile>,
+    pub num_probands:u64,
+    pub num_processed_transcripts:u64,
+    pub num_skipped_transcripts:u64,
+    pub num_rejected_transcripts:u64,
+    pub stages:Vec<StageTiming>,
+    pub total_seconds:f64
+}
+impl ProvenanceManifest
+{
+    /// ## Summary
+    /// Create the manifest of a run of the provided tool and version started with the provided command line, without inputs, counts and
+    /// timings
+    pub fn new(tool:&str, version:&str, command_line:Vec<String>)->Self
+    {
+        ProvenanceManifest{tool:tool.to_string(),version:version.to_string(),command_line,digest_algorithm:DIGEST_ALGORITHM.to_string(),
+            inputs:Vec::new(),num_probands:0,num_processed_transcripts:0,num_skipped_transcripts:0,num_rejected_transcripts:0,stages:Vec::new(),total_seconds:0.0}
+    }
+    /// ## Summary
+    /// Add an input file of the run with the provided role, see InputFile::new
+    pub fn add_input(&mut self, role:&str, path2file:&Path)->Result<(),String>
+    {
+        self.inputs.push(InputFile::new(role, path2file)?);
+        Ok(())
+    }
+    /// ## Summary
+    /// Return the input file with the provided role, None is returned if the run has no input with this role
+    pub fn get_input(&self, role:&str)->Option<&InputFile>
+    {
+        self.inputs.iter().find(|input|input.role==role)
+    }
+    /// ## Summary
+    /// Set the number of probands of the run and the number of processed, skipped and rejected transcripts of its executed probands
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::provenance::ProvenanceManifest;
+    /// use ppgg::functions::metrics::{MetricsSnapshot,ProbandMetrics};
+    /// let mut snapshot=MetricsSnapshot::default();
+    /// snapshot.probands.insert("S1".to_string(),ProbandMetrics{is_executed:true,num_altered_transcripts:4,num_skipped_transcripts:1,..Default::default()});
+    /// snapshot.probands.insert("S2".to_string(),ProbandMetrics{num_altered_transcripts:3,..Default::default()});
+    /// let mut manifest=ProvenanceManifest::new("vcf2prot","0.1.5",vec!["vcf2prot".to_string()]);
+    /// manifest.set_counts(2,&snapshot);
+    /// assert_eq!((manifest.num_probands,manifest.num_processed_transcripts,manifest.num_skipped_transcripts),(2,4,1));
+    ///```
+    pub fn set_counts(&mut self, num_probands:usize, snapshot:&MetricsSnapshot)
+    {
+        self.num_probands=num_probands as u64;
+        let executed=snapshot.probands.values().filter(|metrics|metrics.is_executed);
+        let (num_processed,num_skipped,num_rejected)=executed.fold((0,0,0),|(num_processed,num_skipped,num_rejected),metrics|
+            (num_processed+metrics.num_altered_transcripts,num_skipped+metrics.num_skipped_transcripts,num_rejected+metrics.num_rejected_transcripts));
+        self.num_processed_transcripts=num_processed;
+        self.num_skipped_transcripts=num_skipped;
+        self.num_rejected_transcripts=num_rejected;
+    }
+    /// ## Summary
+    /// Set the time spent in each stage of the run, where the total time is the sum over all stages
+    pub fn set_stage_durations(&mut self, durations:&[(ProgressStage,Duration)])
+    {
+        self.stages=durations.iter().map(|(stage,duration)|StageTiming{stage:stage.as_str().to_string(),seconds:duration.as_secs_f64()}).collect();
+        self.total_seconds=self.stages.iter().map(|stage|stage.seconds).sum();
+    }
+}
+
+#[cfg(test)]
+mod test_provenance
+{
+    use super::*;
+    #[test]
+    fn test_provenance_manifest()
+    {
+        let path2vcf=std::env::temp_dir().join("test_provenance_manifest.vcf");
+        std::fs::write(&path2vcf,crate::parts::demo::DEMO_VCF).unwrap();
+        let mut manifest=ProvenanceManifest::new("vcf2prot","0.1.5",vec!["vcf2prot".to_string(),"-f".to_string(),path2vcf.display().to_string()]);
+        manifest.add_input("vcf",&path2vcf).unwrap();
+        assert!(manifest.add_input("reference",&std::env::temp_dir().join("test_provenance_manifest_missing.fasta")).is_err());
+        let vcf=manifest.get_input("vcf").unwrap();
+        assert_eq!((vcf.num_bytes,vcf.digest.as_str()),(crate::parts::demo::DEMO_VCF.len() as u64,incremental::get_bytes_digest(crate::parts::demo::DEMO_VCF.as_bytes()).as_str()));
+        assert!(manifest.get_input("reference").is_none());
+        manifest.set_stage_durations(&[(ProgressStage::Parsing,Duration::from_millis(1500)),(ProgressStage::Writing,Duration::from_millis(500))]);
+        assert_eq!((manifest.stages[0].stage.as_str(),manifest.total_seconds),("parsing",2.0));
+        let json=serde_json::to_value(&manifest).unwrap();
+        assert_eq!((json["inputs"][0]["role"].as_str(),json["stages"][1]["seconds"].as_f64()),(Some("vcf"),Some(0.5)));
+    }
+}
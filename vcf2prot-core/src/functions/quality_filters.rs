@@ -0,0 +1,229 @@
+/// The module filters the low-quality records and genotype calls of a VCF file before their consequences are decoded, i.e. the records
+/// that did not pass the FILTER column or whose population allele frequency is out of range are skipped and the calls with a read depth
+/// or a genotype quality below a minimum are set to the reference, so they do not alter the personalized proteomes
+use std::fmt::Write;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::vcf_ds::Genotype;
+
+/// ## Summary
+/// The record-level and genotype-level quality filters:
+/// 1. pass_only, records whose FILTER column is neither PASS nor . are skipped for all samples.
+/// 2. min_dp, calls whose DP FORMAT field is below the minimum are set to the reference.
+/// 3. min_gq, calls whose GQ FORMAT field is below the minimum are set to the reference.
+/// 4. min_af and max_af, records where no ALT allele has a population allele frequency within the range are skipped for all samples, the
+///    frequencies are read from the INFO key af_key, AF by default, e.g. AF_nfe for gnomAD, or computed from the matching AC and AN keys,
+///    e.g. AC_nfe and AN_nfe, if the key is missing.
+///
+/// Calls without a DP or a GQ value, e.g. records without the field or a . value, and records without a frequency are not filtered.
+/// ## Example
+///```rust
+/// use ppgg::functions::quality_filters::QualityFilters;
+/// let filters=QualityFilters{min_dp:Some(10),pass_only:true,..Default::default()};
+/// let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:DP:BCSQ\t0|1:5:2\t1|1:30:3";
+/// assert_eq!(filters.apply(record).unwrap(),("1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:DP:BCSQ\t0|0:5:0\t1|1:30:3".to_string(),1));
+/// assert_eq!(filters.apply(&record.replace("PASS","LowQual")),None);
+/// let rare=QualityFilters{max_af:Some(0.01),..Default::default()};
+/// assert_eq!(rare.apply(&record.replace("BCSQ=x","AC=30;AN=1000;BCSQ=x")),None);
+///```
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct QualityFilters
+{
+    pub min_dp:Option<u32>,
+    pub min_gq:Option<u32>,
+    pub pass_only:bool,
+    pub min_af:Option<f64>,
+    pub max_af:Option<f64>,
+    pub af_key:Option<String>
+}
+impl QualityFilters
+{
+    /// ## Summary
+    /// Return true if no filter is used
+    pub fn is_empty(&self)->bool
+    {
+        self.min_dp.is_none() && self.min_gq.is_none() && !self.pass_only && self.min_af.is_none() && self.max_af.is_none()
+    }
+    /// ## Summary
+    /// Return the population allele frequencies of the ALT alleles of a record from its INFO column, i.e. the values of the af_key, or the
+    /// ratios of the values of the AC key to the value of the AN key, e.g. AC_nfe and AN_nfe for AF_nfe, None if the frequencies are missing
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::quality_filters::QualityFilters;
+    /// let filters=QualityFilters{af_key:Some("AF_nfe".to_string()),..Default::default()};
+    /// assert_eq!(filters.get_allele_frequencies("DP=20;AF_nfe=0.1,0.01"),Some(vec![0.1,0.01]));
+    /// assert_eq!(filters.get_allele_frequencies("AC_nfe=5;AN_nfe=100"),Some(vec![0.05]));
+    /// assert_eq!(filters.get_allele_frequencies("AF=0.2"),None);
+    ///```
+    pub fn get_allele_frequencies(&self, info:&str)->Option<Vec<f64>>
+    {
+        let af_key=self.af_key.as_deref().unwrap_or("AF");
+        let get_values=|key:&str|info.split(';')
+            .find_map(|info_field|info_field.strip_prefix(key).and_then(|value|value.strip_prefix('=')))
+            .and_then(|values|values.split(',').map(|value|value.parse::<f64>().ok()).collect::<Option<Vec<f64>>>());
+        if let Some(frequencies)=get_values(af_key)
+        {
+            return Some(frequencies)
+        }
+        let (allele_counts,allele_number)=(get_values(&af_key.replacen("AF","AC",1))?,get_values(&af_key.replacen("AF","AN",1))?);
+        match allele_number.first()
+        {
+            Some(allele_number) if *allele_number>0.0=>Some(allele_counts.iter().map(|allele_count|allele_count/allele_number).collect()),
+            _=>None
+        }
+    }
+    /// ## Summary
+    /// Return whether a record is kept by the frequency range, i.e. at least one ALT allele has a frequency within the range or the record
+    /// has no frequency
+    fn is_in_frequency_range(&self, info:&str)->bool
+    {
+        if self.min_af.is_none() && self.max_af.is_none()
+        {
+            return true
+        }
+        match self.get_allele_frequencies(info)
+        {
+            Some(frequencies)=>frequencies.iter().any(|frequency|*frequency>=self.min_af.unwrap_or(0.0) && *frequency<=self.max_af.unwrap_or(1.0)),
+            None=>true
+        }
+    }
+    /// ## Summary
+    /// Apply the filters to a VCF record, returns None if the record is skipped, otherwise, the record, where the genotype alleles of the
+    /// filtered calls are set to 0 and their bit-masks to zero, along with the number of filtered calls
+    pub fn apply(&self, record:&str)->Option<(String,usize)>
+    {
+        let fields=record.split('\t').collect::<Vec<&str>>();
+        if self.pass_only && !matches!(fields.get(6),Some(&"PASS") | Some(&"."))
+        {
+            return None
+        }
+        if !self.is_in_frequency_range(fields.get(7).unwrap_or(&""))
+        {
+            return None
+        }
+        let format=match fields.get(8)
+        {
+            Some(format)=>format.split(':').collect::<Vec<&str>>(),
+            None=>return Some((record.to_string(),0))
+        };
+        let get_index=|key:&str|format.iter().position(|field|*field==key);
+        let (gt_index,bcsq_index)=(get_index("GT"),get_index("BCSQ"));
+        let thresholds=[(get_index("DP"),self.min_dp),(get_index("GQ"),self.min_gq)].iter()
+            .filter_map(|(index,minimum)|match (index,minimum)
+            {
+                (Some(index),Some(minimum))=>Some((*index,*minimum)),
+                _=>None
+            })
+            .collect::<Vec<(usize,u32)>>();
+        if thresholds.is_empty() || (gt_index.is_none() && bcsq_index.is_none())
+        {
+            return Some((record.to_string(),0))
+        }
+        let mut num_filtered=0;
+        let mut filtered_fields=fields[..9.min(fields.len())].iter().map(|field|field.to_string()).collect::<Vec<String>>();
+        for field in fields.iter().skip(9)
+        {
+            let mut sub_fields=field.split(':').map(|sub_field|sub_field.to_string()).collect::<Vec<String>>();
+            let is_filtered=thresholds.iter()
+                .any(|(index,minimum)|matches!(sub_fields.get(*index).and_then(|value|value.parse::<u32>().ok()),Some(value) if value<*minimum));
+            if is_filtered
+            {
+                if let Some(gt)=gt_index.and_then(|gt_index|sub_fields.get_mut(gt_index))
+                {
+                    *gt=Genotype::to_reference_call(gt);
+                }
+                if let Some(bitmask)=bcsq_index.and_then(|bcsq_index|sub_fields.get_mut(bcsq_index))
+                {
+                    *bitmask="0".to_string();
+                }
+                num_filtered+=1;
+            }
+            filtered_fields.push(sub_fields.join(":"));
+        }
+        match num_filtered
+        {
+            0=>Some((record.to_string(),0)),
+            _=>Some((filtered_fields.join("\t"),num_filtered))
+        }
+    }
+}
+
+/// ## Summary
+/// The number of records skipped by the FILTER column or by the frequency range and the number of calls set to the reference by the depth
+/// and quality minimums
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub struct QualityFilterSummary
+{
+    pub num_skipped_records:usize,
+    pub num_filtered_calls:usize
+}
+impl QualityFilterSummary
+{
+    /// ## Summary
+    /// Return a human readable report of the filtered records and calls
+    pub fn to_report(&self)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} records that did not pass the FILTER column or the allele frequency range were skipped and {} calls below the minimum depth or genotype quality were set to the reference",
+            self.num_skipped_records,self.num_filtered_calls).unwrap();
+        report
+    }
+}
+
+/// ## Summary
+/// Apply the provided quality filters to the records of a VCF file, see QualityFilters::apply, and return the retained records along with
+/// a summary of the filtered records and calls, the records are returned unchanged if no filter is used
+pub fn filter_records(lines:Vec<String>, filters:&QualityFilters, engine:Engine)->(Vec<String>,QualityFilterSummary)
+{
+    if filters.is_empty()
+    {
+        return (lines,QualityFilterSummary::default())
+    }
+    let num_records=lines.len();
+    let retained=match engine
+    {
+        Engine::ST=>lines.iter().filter_map(|line|filters.apply(line)).collect::<Vec<(String,usize)>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().filter_map(|line|filters.apply(line)).collect::<Vec<(String,usize)>>()
+    };
+    let summary=QualityFilterSummary{num_skipped_records:num_records-retained.len(),num_filtered_calls:retained.iter().map(|(_,num_filtered)|num_filtered).sum()};
+    (retained.into_iter().map(|(line,_)|line).collect(),summary)
+}
+
+#[cfg(test)]
+mod test_quality_filters
+{
+    use super::*;
+    #[test]
+    fn test_low_quality_calls_are_set_to_reference()
+    {
+        let filters=QualityFilters{min_dp:Some(10),min_gq:Some(20),..Default::default()};
+        assert!(!filters.is_empty() && QualityFilters::default().is_empty());
+        let record="1\t10\t.\tA\tT,C\t.\tLowQual\tBCSQ=x\tGT:GQ:DP:BCSQ\t1/2:30:40:15\t.|1:10:40:2\t0|1:.:.:2\t1|1:99:5";
+        // the second call fails the genotype quality and the last call the depth, while the missing values of the third call pass
+        let (filtered,num_filtered)=filters.apply(record).unwrap();
+        assert_eq!(filtered,"1\t10\t.\tA\tT,C\t.\tLowQual\tBCSQ=x\tGT:GQ:DP:BCSQ\t1/2:30:40:15\t.|0:10:40:0\t0|1:.:.:2\t0|0:99:5");
+        assert_eq!(num_filtered,2);
+        assert_eq!(QualityFilters{pass_only:true,..filters.clone()}.apply(record),None);
+        let record="1\t10\t.\tA\tT\t.\t.\tBCSQ=x\tGT:BCSQ\t1|1:3";
+        assert_eq!(QualityFilters{pass_only:true,..filters}.apply(record),Some((record.to_string(),0)));
+        assert_eq!(filter_records(vec![record.to_string()],&QualityFilters::default(),Engine::ST),(vec![record.to_string()],QualityFilterSummary::default()));
+        let records=vec![record.to_string(),record.replace("\t.\tBCSQ","\tLowQual\tBCSQ")];
+        let (retained,summary)=filter_records(records,&QualityFilters{pass_only:true,..Default::default()},Engine::MT);
+        assert_eq!((retained,summary),(vec![record.to_string()],QualityFilterSummary{num_skipped_records:1,num_filtered_calls:0}));
+    }
+    #[test]
+    fn test_allele_frequency_range()
+    {
+        let rare=QualityFilters{max_af:Some(0.01),..Default::default()};
+        assert!(!rare.is_empty());
+        // a record is kept if any of its ALT alleles is within the range or if it has no frequency
+        let record="1\t10\t.\tA\tT,C\t.\tPASS\tAF=0.2,0.001;BCSQ=x\tGT:BCSQ\t1|2:6";
+        assert_eq!(rare.apply(record),Some((record.to_string(),0)));
+        assert_eq!(rare.apply(&record.replace("AF=0.2,0.001;","")),Some((record.replace("AF=0.2,0.001;",""),0)));
+        assert_eq!(QualityFilters{min_af:Some(0.5),..rare.clone()}.apply(record),None);
+        let gnomad=QualityFilters{min_af:Some(0.05),af_key:Some("gnomAD_AF".to_string()),..Default::default()};
+        assert_eq!(gnomad.get_allele_frequencies("gnomAD_AC=10,1;gnomAD_AN=100"),Some(vec![0.1,0.01]));
+        assert!(gnomad.apply(&record.replace("AF=0.2,0.001","gnomAD_AF=0.01,0.02")).is_none());
+        assert_eq!(gnomad.get_allele_frequencies("gnomAD_AC=10;gnomAD_AN=0"),None);
+    }
+}
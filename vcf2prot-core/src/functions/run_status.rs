@@ -0,0 +1,146 @@
+/// The module contains the warning and error budget of a run, i.e. the problems that did not abort the run but affect its results,
+/// along with the exit status the run should finish with so workflow engines can branch on partially successful runs
+use crate::functions::summary::TranscriptCoverage;
+use crate::functions::compatibility::CompatibilityReport;
+
+/// ## Summary
+/// The outcome of a run that completed, each outcome has a distinct exit code. Exit code 1 is used by runs that were aborted, e.g. by
+/// --fail_on_missing_transcripts_threshold, and exit code 2 by invalid command line arguments, hence, the codes of the partially
+/// successful outcomes start at 3. If a run has more than one kind of problem, the outcome with the highest code is reported:
+/// 1. Clean => 0, the run completed without any problem
+/// 2. SkippedTranscripts => 3, transcripts referenced in the VCF are missing from the reference or were skipped due to translation errors
+/// 3. ReferenceMismatches => 4, the reference residues stated by consequences of the VCF do not match the reference proteome
+/// 4. InconsistentAnnotations => 5, consequences of the VCF are inconsistent with the genome, only checked if a genome is provided
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord)]
+pub enum RunStatus
+{
+    Clean,
+    SkippedTranscripts,
+    ReferenceMismatches,
+    InconsistentAnnotations
+}
+impl RunStatus
+{
+    /// ## Summary
+    /// Return the exit code of the status
+    pub fn exit_code(&self)->i32
+    {
+        match self
+        {
+            RunStatus::Clean=>0,
+            RunStatus::SkippedTranscripts=>3,
+            RunStatus::ReferenceMismatches=>4,
+            RunStatus::InconsistentAnnotations=>5
+        }
+    }
+    /// ## Summary
+    /// Return the name of the status as used in the status line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            RunStatus::Clean=>"clean",
+            RunStatus::SkippedTranscripts=>"skipped_transcripts",
+            RunStatus::ReferenceMismatches=>"reference_mismatches",
+            RunStatus::InconsistentAnnotations=>"inconsistent_annotations"
+        }
+    }
+}
+
+/// ## Summary
+/// The number of problems of each kind encountered during a run, the budget is filled by the checks that are executed and is then used to
+/// derive the status of the run
+/// ## Example
+///```rust
+/// use ppgg::functions::run_status::{RunBudget,RunStatus};
+/// let mut budget=RunBudget::default();
+/// assert_eq!(budget.get_status(),RunStatus::Clean);
+/// budget.add_inconsistent_annotations(2);
+/// assert_eq!(budget.get_status().exit_code(),5);
+/// println!("{}",budget.to_status_line());
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct RunBudget
+{
+    pub num_missing_transcripts:u64,
+    pub num_skipped_transcripts:u64,
+    pub num_reference_mismatches:u64,
+    pub num_inconsistent_annotations:u64
+}
+impl RunBudget
+{
+    /// ## Summary
+    /// Add the missing and the skipped transcripts of a transcript coverage to the budget
+    pub fn add_coverage(&mut self, coverage:&TranscriptCoverage)
+    {
+        self.num_missing_transcripts+=coverage.missing.len() as u64;
+        self.num_skipped_transcripts+=coverage.skipped.len() as u64;
+    }
+    /// ## Summary
+    /// Add the residue mismatches of a compatibility report to the budget, the missing transcripts of the report are not added as they are
+    /// already accounted for by the transcript coverage
+    pub fn add_compatibility(&mut self, report:&CompatibilityReport)
+    {
+        self.num_reference_mismatches+=report.residue_mismatches.len() as u64;
+    }
+    /// ## Summary
+    /// Add the number of consequences that are inconsistent with the genome to the budget
+    pub fn add_inconsistent_annotations(&mut self, num_inconsistent:usize)
+    {
+        self.num_inconsistent_annotations+=num_inconsistent as u64;
+    }
+    /// ## Summary
+    /// Return the status of the run, i.e. the most severe kind of problem in the budget
+    pub fn get_status(&self)->RunStatus
+    {
+        if self.num_inconsistent_annotations!=0
+        {
+            return RunStatus::InconsistentAnnotations
+        }
+        if self.num_reference_mismatches!=0
+        {
+            return RunStatus::ReferenceMismatches
+        }
+        if self.num_missing_transcripts!=0 || self.num_skipped_transcripts!=0
+        {
+            return RunStatus::SkippedTranscripts
+        }
+        RunStatus::Clean
+    }
+    /// ## Summary
+    /// Return a single machine-parsable line summarizing the run, made of the VCF2PROT_STATUS tag followed by space-separated key=value pairs,
+    /// e.g. VCF2PROT_STATUS status=skipped_transcripts exit_code=3 missing_transcripts=2 skipped_transcripts=0 reference_mismatches=0 inconsistent_annotations=0
+    pub fn to_status_line(&self)->String
+    {
+        let status=self.get_status();
+        format!("VCF2PROT_STATUS status={} exit_code={} missing_transcripts={} skipped_transcripts={} reference_mismatches={} inconsistent_annotations={}",
+            status.as_str(),status.exit_code(),self.num_missing_transcripts,self.num_skipped_transcripts,self.num_reference_mismatches,
+            self.num_inconsistent_annotations)
+    }
+}
+
+#[cfg(test)]
+mod test_run_status
+{
+    use super::*;
+    #[test]
+    fn test_status_precedence()
+    {
+        let mut budget=RunBudget::default();
+        budget.add_coverage(&TranscriptCoverage{num_referenced:10,missing:vec!["T1".to_string()],skipped:Vec::new()});
+        assert_eq!(budget.get_status(),RunStatus::SkippedTranscripts);
+        budget.num_reference_mismatches=1;
+        assert_eq!(budget.get_status(),RunStatus::ReferenceMismatches);
+        budget.add_inconsistent_annotations(3);
+        assert_eq!(budget.get_status(),RunStatus::InconsistentAnnotations);
+        assert_eq!(budget.get_status().exit_code(),5);
+    }
+    #[test]
+    fn test_status_line()
+    {
+        let budget=RunBudget{num_missing_transcripts:2,num_skipped_transcripts:1,..Default::default()};
+        assert_eq!(budget.to_status_line(),"VCF2PROT_STATUS status=skipped_transcripts exit_code=3 missing_transcripts=2 skipped_transcripts=1 \
+                    reference_mismatches=0 inconsistent_annotations=0");
+        assert_eq!(RunBudget::default().to_status_line().split(' ').nth(2).unwrap(),"exit_code=0");
+    }
+}
@@ -0,0 +1,150 @@
+/// The module restricts the parsing of a VCF file to a subset of its probands, i.e. the columns of the other probands are removed from each
+/// record as soon as the record is read, hence, their genotypes are neither checked nor decoded and no representation is built for them
+use std::fs;
+use std::path::Path;
+use rayon::prelude::*;
+use crate::data_structures::vcf_ds::Probands;
+use crate::data_structures::InternalRep::engines::Engine;
+
+/// ## Summary
+/// The names of the selected samples, in the order they were provided, where a duplicated name is kept once, the selection is passed to
+/// the readers through readers::ParseOptions
+/// ## Example
+///```rust
+/// use ppgg::functions::sample_selection::SampleSelection;
+/// let selection=SampleSelection::from_list("HG00097, HG00096,HG00097").unwrap();
+/// assert_eq!(selection.get_samples(),&["HG00097".to_string(),"HG00096".to_string()]);
+/// assert!(SampleSelection::from_list(" , ").is_err());
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct SampleSelection
+{
+    samples:Vec<String>
+}
+impl SampleSelection
+{
+    /// ## Summary
+    /// Create a new selection from the names of the samples
+    pub fn new(samples:Vec<String>)->Self
+    {
+        let mut selected=Vec::with_capacity(samples.len());
+        for sample in samples
+        {
+            if !selected.contains(&sample)
+            {
+                selected.push(sample);
+            }
+        }
+        SampleSelection{samples:selected}
+    }
+    /// ## Summary
+    /// Parse a comma-separated list of sample names, an error is returned if the list does not contain any name
+    pub fn from_list(list:&str)->Result<Self,String>
+    {
+        let samples=list.split(',').map(|sample|sample.trim()).filter(|sample|!sample.is_empty()).map(|sample|sample.to_string()).collect::<Vec<String>>();
+        if samples.is_empty()
+        {
+            return Err(format!("The list of samples: {} does not contain any sample name",list));
+        }
+        Ok(SampleSelection::new(samples))
+    }
+    /// ## Summary
+    /// Read the sample names from a file with one name per line, empty lines and lines starting with # are ignored. An error is returned if
+    /// the file can not be read or does not contain any name.
+    pub fn from_file(path2load:&Path)->Result<Self,String>
+    {
+        let content=match fs::read_to_string(path2load)
+        {
+            Ok(content)=>content,
+            Err(err_msg)=>return Err(format!("Reading the samples file: {:?} failed with the following error: {}",path2load,err_msg))
+        };
+        let samples=content.lines().map(|line|line.trim()).filter(|line|!line.is_empty() && !line.starts_with('#'))
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        if samples.is_empty()
+        {
+            return Err(format!("The samples file: {:?} does not contain any sample name",path2load));
+        }
+        Ok(SampleSelection::new(samples))
+    }
+    /// ## Summary
+    /// Return the names of the selected samples
+    pub fn get_samples(&self)->&[String]
+    {
+        &self.samples
+    }
+}
+/// ## Summary
+/// Select the probands of a VCF file using the provided selection, returns the names of the selected probands in the order of the VCF
+/// header along with their zero-based columns among the probands of the file, or the probands unchanged and None if no selection is
+/// provided. An error is returned if a selected sample is not part of the file.
+pub fn select_probands(proband_names:Vec<String>, selection:Option<&SampleSelection>)->Result<(Vec<String>,Option<Vec<usize>>),String>
+{
+    let selection=match selection
+    {
+        Some(selection)=>selection,
+        None=>return Ok((proband_names,None))
+    };
+    let (selected,columns)=Probands::new(proband_names).select(selection.get_samples())?;
+    Ok((selected.get_probands(),Some(columns)))
+}
+/// ## Summary
+/// Remove the fields of the probands that are not at the provided columns from each record, the eight fixed fields and the FORMAT field
+/// are kept, where the columns are sorted zero-based indices among the probands of the file, see select_probands
+/// ## Example
+///```rust
+/// use ppgg::functions::sample_selection::slice_records;
+/// use ppgg::data_structures::InternalRep::engines::Engine;
+/// let records=vec!["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=.\tGT:BCSQ\t0|1:2\t1|0:1\t0|0:0".to_string()];
+/// assert_eq!(slice_records(records,&[0,2],Engine::ST),vec!["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=.\tGT:BCSQ\t0|1:2\t0|0:0".to_string()]);
+///```
+pub fn slice_records(lines:Vec<String>, columns:&[usize], engine:Engine)->Vec<String>
+{
+    match engine
+    {
+        Engine::ST=>lines.iter().map(|line|slice_record(line, columns)).collect::<Vec<String>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().map(|line|slice_record(line, columns)).collect::<Vec<String>>()
+    }
+}
+/// keep the fixed fields of a record and the fields of the probands at the provided columns
+fn slice_record(record:&str, columns:&[usize])->String
+{
+    let mut sliced=String::with_capacity(record.len());
+    let mut selected=columns.iter().peekable();
+    for (idx,field) in record.split('\t').enumerate()
+    {
+        if idx>=9
+        {
+            match selected.peek()
+            {
+                Some(column) if **column==idx-9=>{selected.next();},
+                Some(_)=>continue,
+                None=>break
+            }
+        }
+        if idx!=0
+        {
+            sliced.push('\t');
+        }
+        sliced.push_str(field);
+    }
+    sliced
+}
+
+#[cfg(test)]
+mod test_sample_selection
+{
+    use super::*;
+    #[test]
+    fn test_select_and_slice()
+    {
+        let probands=vec!["S1".to_string(),"S2".to_string(),"S3".to_string()];
+        assert_eq!(select_probands(probands.clone(),None).unwrap(),(probands.clone(),None));
+        assert_eq!(select_probands(probands.clone(),Some(&SampleSelection::new(vec!["S3".to_string()]))).unwrap(),(vec!["S3".to_string()],Some(vec![2])));
+        let (selected,columns)=Probands::new(probands).select(SampleSelection::new(vec!["S3".to_string(),"S2".to_string()]).get_samples()).unwrap();
+        assert_eq!((selected.get_probands(),columns.clone()),(vec!["S2".to_string(),"S3".to_string()],vec![1,2]));
+        let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=.\tGT:BCSQ\t0|1:2\t1|0:1\t0|0:0";
+        assert_eq!(slice_record(record,&columns),"1\t10\t.\tA\tT\t.\tPASS\tBCSQ=.\tGT:BCSQ\t1|0:1\t0|0:0");
+        assert_eq!(slice_record(record,&[0]),"1\t10\t.\tA\tT\t.\tPASS\tBCSQ=.\tGT:BCSQ\t0|1:2");
+        assert!(Probands::new(vec!["S1".to_string()]).select(&["S4".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+/// The module extracts per-sample subsets of a VCF file, i.e. for each sample the original records whose consequences were applied to its
+/// personalized proteome, so the protein sequences can be cross-referenced with a minimal variant file instead of the cohort VCF
+use std::collections::HashMap;
+use rayon::prelude::*;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::Mutation;
+use crate::data_structures::vcf_ds::{AlleleDecomposition,AltTranscript,DecodingOptions,VCFRecords};
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::functions::text_parser;
+
+/// ## Summary
+/// The records applied to a sample, i.e. the indices of the original records, in their order, carrying at least one consequence that was
+/// applied to one of the haplotypes of the sample, along with the column of the sample relative to the first sample column
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SampleRecords
+{
+    pub proband_name:String,
+    pub column:usize,
+    pub record_indices:Vec<usize>
+}
+
+/// ## Summary
+/// The per-sample subsets of a VCF file, i.e. the header and the original records of the file along with the records applied to each sample
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SampleVcfSubsets
+{
+    pub header:Vec<String>,
+    pub records:Vec<String>,
+    pub samples:Vec<SampleRecords>
+}
+impl SampleVcfSubsets
+{
+    /// ## Summary
+    /// Return the lines of the VCF subset of a sample, i.e. the meta-information lines of the header, the column header restricted to the
+    /// sample and its applied records, where the first nine columns, including the INFO column, are copied unchanged from the original record
+    /// and followed by the field of the sample
+    pub fn get_sample_lines(&self, sample:&SampleRecords)->Vec<String>
+    {
+        let mut lines=self.header.iter().filter(|line|line.starts_with("##")).cloned().collect::<Vec<String>>();
+        lines.push(format!("#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\t{}",sample.proband_name));
+        for index in sample.record_indices.iter()
+        {
+            let record=&self.records[*index];
+            let mut line=record.split('\t').take(9).collect::<Vec<&str>>().join("\t");
+            line.push('\t');
+            line.push_str(record.split('\t').nth(9+sample.column).unwrap_or("."));
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// ## Summary
+/// Return the index of the original record of each processed record, where the processed records are the records of the file after the
+/// contig filter, the consequence map, the collapse of duplicated records and the missing genotype policy were applied, see
+/// readers::read_vcf_with_policies. These steps keep the order of the records and only drop records or edit their INFO and sample fields,
+/// hence, the records are aligned by their CHROM, POS, ID, REF and ALT columns. An error is returned if a processed record can not be aligned.
+/// ## Example
+///```rust
+/// use ppgg::functions::sample_vcfs::align_records;
+/// let original=["1\t10\t.\tA\tT\tx","1\t20\t.\tA\tT\tx","2\t30\t.\tC\tG\tx"].iter().map(|line|line.to_string()).collect::<Vec<String>>();
+/// let processed=["1\t10\t.\tA\tT\ty","2\t30\t.\tC\tG\ty"].iter().map(|line|line.to_string()).collect::<Vec<String>>();
+/// assert_eq!(align_records(&original,&processed).unwrap(),vec![0,2]);
+/// assert!(align_records(&processed,&original).is_err());
+///```
+pub fn align_records(original:&[String], processed:&[String])->Result<Vec<usize>,String>
+{
+    let mut indices=Vec::with_capacity(processed.len());
+    let mut next_index=0;
+    for record in processed.iter()
+    {
+        let key=get_record_key(record);
+        match original[next_index..].iter().position(|candidate|get_record_key(candidate)==key)
+        {
+            Some(offset)=>
+            {
+                indices.push(next_index+offset);
+                next_index+=offset+1;
+            },
+            None=>return Err(format!("The record at: {} could not be aligned to a record of the original VCF file",key.replace('\t',":")))
+        }
+    }
+    Ok(indices)
+}
+
+/// ## Summary
+/// Return the records applied to each intermediate representation, where the processed records are the records the representations were
+/// built from and record_indices the index of the original record of each processed record, see align_records. A record is applied to a
+/// sample if at least one of the consequences its bit-mask assigns to a haplotype is part of the same haplotype of the representation
+/// and the transcript of the consequence is part of the reference, i.e. unsupported consequences, consequences of missing transcripts and
+/// consequences removed from the representation, e.g. by --only_transcript, are not applied. The records are decoded with the options the
+/// representations were built with. An error is returned if a representation does not belong to a sample of proband_names, i.e. the 
+/// samples of the VCF header.
+pub fn get_applied_records(proband_names:&[String], processed:&[String], record_indices:&[usize], vec_maps:&[IntMap],
+    ref_seqs:&HashMap<String,String>, decoding_options:&DecodingOptions, engine:Engine)->Result<Vec<SampleRecords>,String>
+{
+    let columns=proband_names.iter().enumerate().map(|(column,name)|(name.as_str(),column)).collect::<HashMap<&str,usize>>();
+    let mut samples=Vec::with_capacity(vec_maps.len());
+    for int_map in vec_maps.iter()
+    {
+        match columns.get(int_map.get_name().as_str())
+        {
+            Some(column)=>samples.push((int_map,*column)),
+            None=>return Err(format!("The sample: {} is not part of the VCF file",int_map.get_name()))
+        }
+    }
+    let get_sample_records=|(int_map,column):&(&IntMap,usize)|
+    {
+        let (haplotype1,haplotype2)=int_map.get_mutations_ref();
+        let haplotypes=[get_applied_alts(haplotype1,ref_seqs),get_applied_alts(haplotype2,ref_seqs)];
+        let record_indices=processed.iter().zip(record_indices.iter())
+            .filter(|(record,_)|is_applied(record,*column,&haplotypes,decoding_options))
+            .map(|(_,index)|*index)
+            .collect::<Vec<usize>>();
+        SampleRecords{proband_name:int_map.get_name().clone(),column:*column,record_indices}
+    };
+    Ok(match engine
+    {
+        Engine::ST=>samples.iter().map(get_sample_records).collect::<Vec<SampleRecords>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>samples.par_iter().map(get_sample_records).collect::<Vec<SampleRecords>>()
+    })
+}
+
+/// ## Summary
+/// Return the key a record is aligned by, i.e. its CHROM, POS, ID, REF and ALT columns
+fn get_record_key(record:&str)->String
+{
+    record.split('\t').take(5).collect::<Vec<&str>>().join("\t")
+}
+
+/// ## Summary
+/// Return the mutations of the transcripts of a haplotype that are part of the reference, keyed by the transcript name
+fn get_applied_alts<'a>(alt_transcripts:&'a [AltTranscript], ref_seqs:&HashMap<String,String>)->HashMap<&'a str,&'a Vec<Mutation>>
+{
+    alt_transcripts.iter()
+        .filter(|alt_transcript|ref_seqs.contains_key(&alt_transcript.name))
+        .map(|alt_transcript|(alt_transcript.name.as_str(),alt_transcript.get_alts()))
+        .collect()
+}
+
+/// ## Summary
+/// Return whether at least one consequence the bit-mask, or the genotype of a decomposed record, of a sample assigns to a haplotype of a record is an applied mutation of the same
+/// haplotype, records without a BCSQ field or with an invalid bit-mask that are not decomposed are never applied
+fn is_applied(record:&str, column:usize, haplotypes:&[HashMap<&str,&Vec<Mutation>>;2], decoding_options:&DecodingOptions)->bool
+{
+    let fields=record.split('\t').collect::<Vec<&str>>();
+    if fields.len()<=9+column
+    {
+        return false
+    }
+    let csq=match fields[7].split(';').find_map(|field|field.strip_prefix("BCSQ="))
+    {
+        Some(csq)=>csq,
+        None=>return false
+    };
+    let decomposition=AlleleDecomposition::for_record(record,decoding_options);
+    let (consequences1,consequences2)=match decomposition.and_then(|decomposition|decomposition.get_effects(csq,fields[9+column]))
+    {
+        Some(res)=>res,
+        None=>
+        {
+            let bcsq_index=match fields[8].split(':').position(|field|field=="BCSQ")
+            {
+                Some(bcsq_index)=>bcsq_index,
+                None=>return false
+            };
+            let bitmask=text_parser::get_bit_mask_at(fields[9+column],bcsq_index);
+            match VCFRecords::try_extract_effects(csq,&bitmask)
+            {
+                Ok(res)=>res,
+                Err(_)=>return false
+            }
+        }
+    };
+    [consequences1,consequences2].iter().zip(haplotypes.iter())
+        .any(|(consequences,alts)|consequences.iter().any(|consequence|is_applied_consequence(consequence,alts)))
+}
+
+/// ## Summary
+/// Return whether a consequence is one of the applied mutations of its transcript, consequences that can not be parsed, e.g. the
+/// consequences inherited from another record, are never applied
+fn is_applied_consequence(consequence:&String, alts:&HashMap<&str,&Vec<Mutation>>)->bool
+{
+    let mutation=match Mutation::new(text_parser::split_csq_string(consequence))
+    {
+        Ok(mutation)=>mutation,
+        Err(_)=>return false
+    };
+    match alts.get(&*mutation.transcript_name)
+    {
+        Some(mutations)=>mutations.iter().any(|applied|applied.mut_type==mutation.mut_type && applied.mut_info==mutation.mut_info),
+        None=>false
+    }
+}
+
+#[cfg(test)]
+mod test_sample_vcfs
+{
+    use super::*;
+    use crate::functions::vcf_tools;
+    use crate::data_structures::vcf_ds::Probands;
+    #[test]
+    fn test_get_applied_records()
+    {
+        let header=["##fileformat=VCFv4.2","#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1\tS2"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let records=["1\t10\t.\tA\tT\t.\tPASS\tDP=3;BCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t0|1:2\t0|0:0",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G2|T2|protein_coding|+|1M>1V|20A>T\tGT:BCSQ\t1|1:3\t1|0:1",
+            "1\t30\t.\tA\tT\t.\tPASS\tBCSQ=synonymous|G1|T1|protein_coding|+|3L|30A>T\tGT:BCSQ\t1|0:1\t1|0:1"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let proband_names=vec!["S1".to_string(),"S2".to_string()];
+        let early_maps=vcf_tools::get_early_map(Probands::new(proband_names.clone()),VCFRecords::new(records.clone()),Engine::ST);
+        let vec_maps=vcf_tools::early_to_intermediate_repr(early_maps,Engine::ST);
+        // the transcript T2 is missing from the reference and synonymous consequences are not supported
+        let mut ref_seqs=HashMap::new();
+        ref_seqs.insert("T1".to_string(),"MKLV".to_string());
+        let record_indices=align_records(&records,&records).unwrap();
+        let samples=get_applied_records(&proband_names,&records,&record_indices,&vec_maps,&ref_seqs,&DecodingOptions::default(),Engine::MT).unwrap();
+        assert_eq!(samples.iter().map(|sample|sample.record_indices.clone()).collect::<Vec<Vec<usize>>>(),vec![vec![0],vec![]]);
+        let subsets=SampleVcfSubsets{header,records:records.clone(),samples};
+        assert_eq!(subsets.get_sample_lines(&subsets.samples[0])[1..],
+            ["#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1".to_string(),
+            "1\t10\t.\tA\tT\t.\tPASS\tDP=3;BCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t0|1:2".to_string()]);
+        ref_seqs.insert("T2".to_string(),"MKLV".to_string());
+        let samples=get_applied_records(&proband_names,&records,&record_indices,&vec_maps,&ref_seqs,&DecodingOptions::default(),Engine::ST).unwrap();
+        assert_eq!((samples[0].record_indices.clone(),samples[1].record_indices.clone()),(vec![0,1],vec![1]));
+        assert!(get_applied_records(&proband_names[..1],&records,&record_indices,&vec_maps,&ref_seqs,&DecodingOptions::default(),Engine::ST).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+/// The module provides the built-in translation vectors of the self-test, i.e. the translation scenarios of the test suite along with
+/// their expected personalized sequences, which are run through the selected engine at runtime to confirm that a binary behaves correctly
+/// on the platform it is installed on before its outputs are trusted
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::panic::{self,AssertUnwindSafe};
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::InternalRep::transcript_instructions::TranscriptInstruction;
+use crate::data_structures::InternalRep::gir::PLACEHOLDER_RESIDUE;
+
+/// ## Summary
+/// The transcript all the built-in vectors are personalized against
+pub const TRANSCRIPT_NAME:&str="ENST00000406869";
+
+/// ## Summary
+/// The short reference of the vectors covering the individual mutation types
+pub const SHORT_REFERENCE:&str="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGG";
+
+/// ## Summary
+/// The complete reference of MAD1L1, used by the vectors combining mutations of multiple transcripts and long frameshifts
+pub const LONG_REFERENCE:&str="MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA";
+
+/// ## Summary
+/// A built-in translation vector, i.e. the consequences of a transcript, the reference they are applied to and the expected personalized
+/// sequence, the consequences of other transcripts are part of some vectors as they must be ignored. The expected sequence is the raw
+/// results array of the engine, i.e. residues that were not written hold the placeholder residue, and None if the transcript is expected
+/// to be rejected as none of its consequences is supported
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct SelfTestCase
+{
+    pub name:&'static str,
+    pub consequences:&'static [&'static str],
+    pub reference:&'static str,
+    pub expected:Option<&'static str>
+}
+
+/// ## Summary
+/// The built-in translation vectors, one for each translation scenario of the transcript instructions test suite
+pub const SELF_TEST_CASES:[SelfTestCase;30]=[
+    SelfTestCase{name:"translation_1",consequences:&[
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|5G>5H|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLHENTMVLSTLRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_2",consequences:&[
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|10V>10H|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMHLSTLRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_3",consequences:&[
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|10V>10H|1936821C>T",
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|20F>20K|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMHLSTLRSLNNKISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_4",consequences:&[
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|5G>5T|1936821C>T",
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|10V>10E|1936821C>T",
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|15R>15S|1936821C>T",
+        "*missense|MAD1L1|ENST00000406869|protein_coding|-|20F>20T|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLTENTMELSTLSSLNNTISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_5",consequences:&[
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|5G>5GTEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGTESTENTMVLSTLRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_6",consequences:&[
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|5G>5GTEST|1936821C>T",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|10V>10VECT|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGTESTENTMVECTLSTLRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_7",consequences:&[
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|5G>5GTEST|1936821C>T",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|10V>10VECT|1936821C>T",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|14R>14RAPID|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGTESTENTMVECTLSTRAPIDRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_8",consequences:&[
+        "frameshift|MAD1L1|ENST00000406869|protein_coding|-|10V>10VTESTFRAMESHIFT|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVTESTFRAMESHIFT")},
+    SelfTestCase{name:"translation_9",consequences:&[
+        "inframe_deletion|MAD1L1|ENST00000406869|protein_coding|-|10VLSTLR>10V|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_10",consequences:&[
+        "inframe_deletion|MAD1L1|ENST00000406869|protein_coding|-|10VLSTLR>10R|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMRSLNNFISQRVEGGSGLEELERGG")},
+    SelfTestCase{name:"translation_11",consequences:&[
+        "inframe_deletion|MAD1L1|ENST00000406869|protein_coding|-|10VLSTLR>10R|1936821C>T",
+        "inframe_deletion|MAD1L1|ENST00000406869|protein_coding|-|28GSGLE>28E|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMRSLNNFISQRVEGEELERGG")},
+    SelfTestCase{name:"translation_12",consequences:&[
+        "stop_gained|MAD1L1|ENST00000406869|protein_coding|-|37G>37*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELER")},
+    SelfTestCase{name:"translation_13",consequences:&[
+        "stop_lost|MAD1L1|ENST00000406869|protein_coding|-|39*>39TEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGGTEST")},
+    SelfTestCase{name:"translation_14",consequences:&[
+        "stop_lost|MAD1L1|ENST00000406869|protein_coding|-|38G*>39TEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGGTEST")},
+    SelfTestCase{name:"translation_15",consequences:&[
+        "*missense&inframe_altering|MAD1L1|ENST00000406869|protein_coding|-|34LERGG>34LTEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELTEST")},
+    SelfTestCase{name:"translation_16",consequences:&[
+        "*frameshift&stop_retained|MAD1L1|ENST00000406869|protein_coding|-|20FISQRVEGGSGLEELERGG*>20LTEST*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNLTEST")},
+    SelfTestCase{name:"translation_17",consequences:&[
+        "*frameshift&stop_retained|MAD1L1|ENST00000406869|protein_coding|-|20FISQRVEGGSGLEELERGG*>20TEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNTEST")},
+    SelfTestCase{name:"translation_18",consequences:&[
+        "*stop_gained&inframe_altering|MAD1L1|ENST00000406869|protein_coding|-|20FISQRVEGGSGLEELERGG*>20|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNN")},
+    SelfTestCase{name:"translation_19",consequences:&[
+        "frameshift&stop_retained|MAD1L1|ENST00000406869|protein_coding|-|20FISQRVEGGSGLEELERGG*>20FLTESTTWO*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFLTESTTWO")},
+    SelfTestCase{name:"translation_20",consequences:&[
+        "inframe_deletion&stop_retained|MAD1L1|ENST00000406869|protein_coding|-|38*>38*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERG.")},
+    SelfTestCase{name:"translation_21",consequences:&[
+        "inframe_insertion&stop_retained|MAD1L1|ENST00000406869|protein_coding|-|38*>38*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:None},
+    SelfTestCase{name:"translation_22",consequences:&[
+        "stop_gained&inframe_altering|MAD1L1|ENST00000406869|protein_coding|-|20FISQRVEGGSGLEELERGG*>20*|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNN")},
+    SelfTestCase{name:"translation_23",consequences:&[
+        "stop_lost&frameshift|MAD1L1|ENST00000406869|protein_coding|-|39*>39TEST|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEELERGGTEST")},
+    SelfTestCase{name:"translation_24",consequences:&[
+        "missense&inframe_altering|MAD1L1|ENST00000406869|protein_coding|-|34ERGG>34YEAP|1936821C>T"],
+        reference:SHORT_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLEEYEAPG")},
+    SelfTestCase{name:"translation_25",consequences:&[
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|710E>710K|1816099C>T",
+        "missense|MAD1L1|ENST00000399654|protein_coding|-|706S>706L|1816110G>A",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|696R>696L|1816140C>A",
+        "missense|MAD1L1|ENST00000406869|protein_coding|-|666K>666N|1898200C>G",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|650R>650Q|1898249C>T",
+        "inframe_insertion|MAD1L1|ENST00000455998|protein_coding|-|143L>143KQEL|2219360G>GCTCCTGCTT",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|190L>190KQEL|2219360G>GCTCCTGCT",
+        "inframe_insertion|MAD1L1|ENST00000402746|protein_coding|-|98L>98KQEL|2219360G>GCTCCTGCTT",
+        "inframe_deletion|MAD1L1|ENST00000399654|protein_coding|-|661DCL>661L|1898211GGCAGTC>G",
+        "inframe_deletion|MAD1L1|ENST00000265854|protein_coding|-|661DCL>661L|1898211GGCAGTC>G"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEKQELEVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKQELRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYQLTSLYAEHPGLLNATSPSGSKMQLLETEFSHTVGELIEVHLRLQDSIPAFLSLLTLKLFSRQTV")},
+    SelfTestCase{name:"translation_26",consequences:&[
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|710E>710K|1816099C>T",
+        "missense|MAD1L1|ENST00000399654|protein_coding|-|706S>706L|1816110G>A",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|696R>696L|1816140C>A",
+        "missense|MAD1L1|ENST00000406869|protein_coding|-|666K>666N|1898200C>G",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|650R>650Q|1898249C>T",
+        "inframe_insertion|MAD1L1|ENST00000455998|protein_coding|-|143L>143KQEL|2219360G>GCTCCTGCTT",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|190L>190KQEL|2219360G>GCTCCTGCT",
+        "inframe_deletion|MAD1L1|ENST00000399654|protein_coding|-|661DCL>661L|1898211GGCAGTC>G",
+        "inframe_deletion|MAD1L1|ENST00000265854|protein_coding|-|661DCL>661L|1898211GGCAGTC>G"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKQELRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYQLTSLYAEHPGLLNATSPSGSKMQLLETEFSHTVGELIEVHLRLQDSIPAFLSLLTLKLFSRQTV")},
+    SelfTestCase{name:"translation_27",consequences:&[
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|710E>710K|1816099C>T",
+        "missense|MAD1L1|ENST00000399654|protein_coding|-|706S>706L|1816110G>A",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|696R>696L|1816140C>A",
+        "missense|MAD1L1|ENST00000406869|protein_coding|-|666K>666N|1898200C>G",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|650R>650Q|1898249C>T",
+        "inframe_insertion|MAD1L1|ENST00000455998|protein_coding|-|143L>143KQEL|2219360G>GCTCCTGCTT",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|190L>190KQEL|2219360G>GCTCCTGCT",
+        "inframe_deletion|MAD1L1|ENST00000399654|protein_coding|-|661DCL>661L|1898211GGCAGTC>G",
+        "inframe_deletion|MAD1L1|ENST00000265854|protein_coding|-|661DCL>661L|1898211GGCAGTC>G"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKQELRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYQLTSLYAEHPGLLNATSPSGSKMQLLETEFSHTVGELIEVHLRLQDSIPAFLSLLTLKLFSRQTV")},
+    SelfTestCase{name:"translation_28",consequences:&[
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|710E>710K|1816099C>T",
+        "missense|MAD1L1|ENST00000399654|protein_coding|-|706S>706L|1816110G>A",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|696R>696L|1816140C>A",
+        "missense|MAD1L1|ENST00000406869|protein_coding|-|666K>666N|1898200C>G",
+        "missense|MAD1L1|ENST00000265854|protein_coding|-|650R>650Q|1898249C>T",
+        "inframe_insertion|MAD1L1|ENST00000455998|protein_coding|-|143L>143KQEL|2219360G>GCTCCTGCTT",
+        "inframe_insertion|MAD1L1|ENST00000406869|protein_coding|-|190L>190KQEL|2219360G>GCTCCTGCT",
+        "inframe_deletion|MAD1L1|ENST00000437877|protein_coding|-|117DCL>117L|1898211GGCAGTC>G",
+        "inframe_deletion|MAD1L1|ENST00000399654|protein_coding|-|661DCL>661L|1898211GGCAGTC>G"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQERLAEEKMQEQLERNRQCQQNLDAASKQELRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWERLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYQLTSLYAEHPGLIFNATSPSGSKMQLLETEFSHTVGELIEVHLRLQDSIPAFLSLLTLKLFSRQTVA")},
+    SelfTestCase{name:"translation_29",consequences:&[
+        "frameshift|MAD1L1|ENST00000406869|protein_coding|-|319RLDQTMGLSIRTPEDLSRFVVELQQRELALKDKNSAVTSSARGLEKARQQLQEELRQVSGQLLEERKKRETHEALARRLQKRVLLLTKERDGMRAILGSYDSELTPAEYSPQLTRRMREAEDMVQKVHSHSAEMEAQLSQALEELGGQKQRADMLEMELKMLKSQSSSAEQSFLFSREEADTLRLKVEELEGERSRLEEEKRMLEAQLERRALQGDYDQSRTKVLHMSLNPTSVARQRLREDHSQLQAECERLRGLLRAMERGGTVPADLEAAAASLPSSKEVAELKKQVESAELKNQRLKEVFQTKIQEFRKACYTLTGYQIDITTENQYRLTSLYAEHPGDCLIFKATSPSGSKMQLLETEFSHTVGELIEVHLRRQDSIPAFLSSLTLELFSRQTVA*>319GETGPDHGPEHQDSRRPFQIRG*|1936821C>T+2213243T>TCTCC"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRARVELERAASTSARNYEREVDRNQELLTRIRQLQEREAGAEEKMQEQLERNRQCQQNLDAASKRLREKEDSLAQAGETINALKGRISELQWSVMDQEMRVKRLESEKQELQEQLDLQHKKCQEANQKIQELQASQEARADHEQQIKDLEQKLSLQEQDAAIVKNMKSELVRLPRLERELKQLREESAHLREMRETNGLLQEELEGLQRKLGRQEKMQETLVGLELENERLLAKLQSWEGETGPDHGPEHQDSRRPFQIRG")},
+    SelfTestCase{name:"translation_30",consequences:&[
+        "stop_gained|MAD1L1|ENST00000406869|protein_coding|-|82R>82*|2225457G>A"],
+        reference:LONG_REFERENCE,
+        expected:Some("MEDLGENTMVLSTLRSLNNFISQRVEGGSGLDISTSAPGSLQMQYQQSMQLEERAEQIRSKSHLIQVEREKMQMELSHKRA")}];
+
+/// ## Summary
+/// The outcome of a vector, i.e. the observed sequence or the error raised while personalizing it
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SelfTestOutcome
+{
+    pub case:SelfTestCase,
+    pub observed:Result<String,String>
+}
+impl SelfTestOutcome
+{
+    /// ## Summary
+    /// Return whether the observed sequence equals the expected sequence, or whether the transcript was rejected if it is expected to be
+    pub fn is_passed(&self)->bool
+    {
+        match (&self.observed,self.case.expected)
+        {
+            (Ok(sequence),Some(expected))=>sequence==expected,
+            (Err(_),None)=>true,
+            _=>false
+        }
+    }
+}
+
+/// ## Summary
+/// The outcomes of the self-test on an engine, in the order of the built-in vectors
+#[derive(Debug,Clone)]
+pub struct SelfTestReport
+{
+    pub engine:Engine,
+    pub outcomes:Vec<SelfTestOutcome>
+}
+impl SelfTestReport
+{
+    /// ## Summary
+    /// Return the number of vectors that passed
+    pub fn get_num_passed(&self)->usize
+    {
+        self.outcomes.iter().filter(|outcome|outcome.is_passed()).count()
+    }
+    /// ## Summary
+    /// Return whether every vector passed
+    pub fn is_passed(&self)->bool
+    {
+        self.get_num_passed()==self.outcomes.len()
+    }
+    /// ## Summary
+    /// Return a human readable report, i.e. a PASS or FAIL line per vector followed by the expected and the observed sequence of the
+    /// failed vectors and the totals
+    pub fn to_report(&self)->String
+    {
+        let mut report=String::new();
+        for outcome in self.outcomes.iter()
+        {
+            match (outcome.is_passed(),&outcome.observed)
+            {
+                (true,_)=>writeln!(report,"PASS\t{}",outcome.case.name).unwrap(),
+                (false,observed)=>writeln!(report,"FAIL\t{}\n\texpected: {}\n\tobserved: {}",outcome.case.name,
+                    outcome.case.expected.unwrap_or("a rejected transcript"),
+                    match observed
+                    {
+                        Ok(sequence)=>sequence.clone(),
+                        Err(err_msg)=>format!("an error, {}",err_msg)
+                    }).unwrap()
+            }
+        }
+        writeln!(report,"{} out of {} built-in translation vectors passed on the {:?} engine",self.get_num_passed(),self.outcomes.len(),self.engine).unwrap();
+        report
+    }
+}
+
+/// ## Summary
+/// Personalize the reference of a vector with its consequences using the provided engine and return the resulting sequence, a panic of the
+/// engine is caught and returned as an error, so a defective binary is reported instead of aborting the self-test, the same holds for an error
+/// returned by the engine, e.g. an engine that is not supported by the build
+pub fn run_case(case:&SelfTestCase, engine:Engine)->Result<String,String>
+{
+    let mut ref_seqs=HashMap::new();
+    ref_seqs.insert(TRANSCRIPT_NAME.to_string(),case.reference.to_string());
+    let consequences=case.consequences.iter().map(|consequence|consequence.to_string()).collect::<Vec<String>>();
+    let alt_transcript=AltTranscript::new(TRANSCRIPT_NAME.to_string(),consequences);
+    let g_rep=TranscriptInstruction::from_alt_transcript(alt_transcript,&ref_seqs)?.get_g_rep(&ref_seqs,PLACEHOLDER_RESIDUE)?;
+    match panic::catch_unwind(AssertUnwindSafe(||g_rep.execute(engine)))
+    {
+        Ok(Ok(result))=>Ok(result.sequence.iter().collect::<String>()),
+        Ok(Err(err_msg))=>Err(format!("the engine failed: {}",err_msg)),
+        Err(payload)=>match (payload.downcast_ref::<&str>(),payload.downcast_ref::<String>())
+        {
+            (Some(err_msg),_)=>Err(format!("the engine panicked: {}",err_msg)),
+            (None,Some(err_msg))=>Err(format!("the engine panicked: {}",err_msg)),
+            (None,None)=>Err("the engine panicked".to_string())
+        }
+    }
+}
+
+/// ## Summary
+/// Run every built-in vector through the provided engine and return the outcomes, see SELF_TEST_CASES
+pub fn run_self_test(engine:Engine)->SelfTestReport
+{
+    let outcomes=SELF_TEST_CASES.iter()
+        .map(|case|SelfTestOutcome{case:*case,observed:run_case(case,engine.clone())})
+        .collect::<Vec<SelfTestOutcome>>();
+    SelfTestReport{engine,outcomes}
+}
+
+#[cfg(test)]
+mod test_self_test
+{
+    use super::*;
+    #[test]
+    fn test_run_self_test()
+    {
+        for engine in [Engine::ST,Engine::MT]
+        {
+            let report=run_self_test(engine);
+            assert!(report.is_passed(),"{}",report.to_report());
+        }
+        let mut case=SELF_TEST_CASES[0];
+        case.expected=Some(SHORT_REFERENCE);
+        let outcome=SelfTestOutcome{case,observed:run_case(&case,Engine::ST)};
+        assert!(!outcome.is_passed());
+        assert!(run_case(&case,Engine::GPU).unwrap_err().contains("not supported"));
+    }
+}
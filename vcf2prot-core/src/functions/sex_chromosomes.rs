@@ -0,0 +1,304 @@
+/// The module handles the sex chromosomes of XY samples, i.e. the transcripts located outside the pseudoautosomal regions (PARs) of chrX and
+/// chrY have a single copy in XY samples, hence, their variants are collapsed into a single haplotype instead of generating two haplotypes
+use std::collections::{HashMap,HashSet};
+use std::fmt::Write;
+use std::str::FromStr;
+use rayon::prelude::*;
+use crate::data_structures::vcf_ds::VCFRecords;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::Map::IntMap;
+
+/// ## Summary
+/// The sex chromosome complement of a sample, XX samples are handled like autosomes, i.e. with two haplotypes per transcript, while the
+/// transcripts outside the PARs of XY samples are emitted as a single haplotype
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum SampleSex
+{
+    XX,
+    XY
+}
+impl SampleSex
+{
+    /// ## Summary
+    /// Return the name of the sex as used in the reports
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            SampleSex::XX=>"XX",
+            SampleSex::XY=>"XY"
+        }
+    }
+}
+impl FromStr for SampleSex
+{
+    type Err=String;
+    /// ## Summary
+    /// Parse the sex of a sample, both the karyotype, i.e. XX or XY, and the common codes of sample sheets and PLINK files are supported,
+    /// i.e. F, female and 2 for XX and M, male and 1 for XY, case-insensitive
+    fn from_str(sex:&str)->Result<SampleSex,String>
+    {
+        match sex.to_lowercase().as_str()
+        {
+            "xx" | "f" | "female" | "2"=>Ok(SampleSex::XX),
+            "xy" | "m" | "male" | "1"=>Ok(SampleSex::XY),
+            _=>Err(format!("{} is not a supported sex, supported values are: XX, F, female or 2 and XY, M, male or 1",sex))
+        }
+    }
+}
+
+/// ## Summary
+/// The one-based, inclusive intervals of the PAR1 and the PAR2 of a sex chromosome
+pub type ParRegions=[(u64,u64);2];
+
+/// ## Summary
+/// The genome build that defines the coordinates of the pseudoautosomal regions, GRCh38 is the default
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ParBuild
+{
+    GRCh38,
+    GRCh37
+}
+impl Default for ParBuild
+{
+    fn default()->Self
+    {
+        ParBuild::GRCh38
+    }
+}
+impl ParBuild
+{
+    /// ## Summary
+    /// Return the one-based, inclusive intervals of the PAR1 and the PAR2 on chrX and on chrY of the build
+    pub fn get_regions(&self)->(ParRegions,ParRegions)
+    {
+        match self
+        {
+            ParBuild::GRCh38=>([(10_001,2_781_479),(155_701_383,156_030_895)],[(10_001,2_781_479),(56_887_903,57_217_415)]),
+            ParBuild::GRCh37=>([(60_001,2_699_520),(154_931_044,155_260_560)],[(10_001,2_649_520),(59_034_050,59_363_566)])
+        }
+    }
+}
+impl FromStr for ParBuild
+{
+    type Err=String;
+    fn from_str(build:&str)->Result<ParBuild,String>
+    {
+        match build.to_lowercase().as_str()
+        {
+            "grch38" | "hg38"=>Ok(ParBuild::GRCh38),
+            "grch37" | "hg19"=>Ok(ParBuild::GRCh37),
+            _=>Err(format!("{} is not a supported genome build, supported builds are: GRCh38 and GRCh37",build))
+        }
+    }
+}
+
+/// ## Summary
+/// Parse a sample-sex table, i.e. a tab-separated table with the sample name in the first column and its sex in the second column, see
+/// SampleSex for the supported values. Empty lines and lines starting with # are ignored, as is a header line whose second column is sex,
+/// an error is returned for an unsupported sex or a sample that is listed twice with different sexes.
+/// ## Example
+///```rust
+/// use ppgg::functions::sex_chromosomes::{parse_sample_sex_table,SampleSex};
+/// let sexes=parse_sample_sex_table("sample\tsex\nS1\tXY\nS2\tF\n").unwrap();
+/// assert_eq!(sexes["S1"],SampleSex::XY);
+/// assert_eq!(sexes["S2"],SampleSex::XX);
+/// assert!(parse_sample_sex_table("S1\tunknown\n").is_err());
+///```
+pub fn parse_sample_sex_table(table:&str)->Result<HashMap<String,SampleSex>,String>
+{
+    let mut sexes=HashMap::new();
+    for (line_index,line) in table.lines().enumerate()
+    {
+        if line.trim().is_empty() || line.starts_with('#')
+        {
+            continue
+        }
+        let fields=line.split('\t').map(|field|field.trim()).collect::<Vec<&str>>();
+        if fields.len()<2
+        {
+            return Err(format!("Line {} of the sample-sex table: {} has {} columns, expected the sample name and its sex",line_index+1,line,fields.len()))
+        }
+        if line_index==0 && fields[1].eq_ignore_ascii_case("sex")
+        {
+            continue
+        }
+        let sex=match SampleSex::from_str(fields[1])
+        {
+            Ok(sex)=>sex,
+            Err(err_msg)=>return Err(format!("Parsing line {} of the sample-sex table failed: {}",line_index+1,err_msg))
+        };
+        if let Some(previous)=sexes.insert(fields[0].to_string(),sex)
+        {
+            if previous!=sex
+            {
+                return Err(format!("The sample: {} is listed with the sexes {} and {} in the sample-sex table",fields[0],previous.as_str(),sex.as_str()))
+            }
+        }
+    }
+    Ok(sexes)
+}
+
+/// ## Summary
+/// Return whether a locus is located on chrX or chrY outside the pseudoautosomal regions, i.e. has a single copy in XY samples, contig names
+/// are matched with or without the chr prefix
+/// ## Example
+///```rust
+/// use ppgg::functions::sex_chromosomes::{is_hemizygous_locus,ParBuild};
+/// assert!(is_hemizygous_locus("chrX",48_000_000,ParBuild::GRCh38));
+/// assert!(!is_hemizygous_locus("chrX",1_000_000,ParBuild::GRCh38));
+/// assert!(!is_hemizygous_locus("7",48_000_000,ParBuild::GRCh38));
+///```
+pub fn is_hemizygous_locus(chrom:&str, position:u64, build:ParBuild)->bool
+{
+    let (regions_x,regions_y)=build.get_regions();
+    let regions=match chrom.strip_prefix("chr").unwrap_or(chrom)
+    {
+        "X"=>regions_x,
+        "Y"=>regions_y,
+        _=>return false
+    };
+    !regions.iter().any(|(start,end)|*start<=position && position<=*end)
+}
+
+/// ## Summary
+/// Return the transcripts that are hemizygous in XY samples, i.e. the transcripts whose consequences are only stated by records located
+/// outside the pseudoautosomal regions of chrX and chrY, see is_hemizygous_locus, transcripts with at least one record in a PAR or on
+/// another contig are handled as diploid
+pub fn get_hemizygous_transcripts(records:&VCFRecords, build:ParBuild, engine:Engine)->HashSet<String>
+{
+    let pairs=match engine
+    {
+        Engine::ST=>records.get_records().iter().flat_map(|record|get_record_transcripts(record,build)).collect::<Vec<(String,bool)>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>records.get_records().par_iter().flat_map(|record|get_record_transcripts(record,build)).collect::<Vec<(String,bool)>>()
+    };
+    let mut is_hemizygous:HashMap<String,bool>=HashMap::new();
+    for (transcript,hemizygous) in pairs
+    {
+        *is_hemizygous.entry(transcript).or_insert(true)&=hemizygous;
+    }
+    is_hemizygous.into_iter().filter(|(_,hemizygous)|*hemizygous).map(|(transcript,_)|transcript).collect::<HashSet<String>>()
+}
+
+/// ## Summary
+/// Return the transcripts of the BCSQ consequences of a record along with whether the record is located at a hemizygous locus
+fn get_record_transcripts(record:&str, build:ParBuild)->Vec<(String,bool)>
+{
+    let fields=record.split('\t').take(8).collect::<Vec<&str>>();
+    if fields.len()<8
+    {
+        return Vec::new()
+    }
+    let hemizygous=match fields[1].parse::<u64>()
+    {
+        Ok(position)=>is_hemizygous_locus(fields[0],position,build),
+        Err(_)=>false
+    };
+    match fields[7].split(';').find_map(|field|field.strip_prefix("BCSQ="))
+    {
+        Some(bcsq)=>bcsq.split(',').filter_map(|csq|csq.split('|').nth(2)).filter(|transcript|!transcript.is_empty())
+                        .map(|transcript|(transcript.to_string(),hemizygous)).collect::<Vec<(String,bool)>>(),
+        None=>Vec::new()
+    }
+}
+
+/// ## Summary
+/// The outcome of applying the sample sexes to a cohort, i.e. the sex of each sample found in the table, the number of hemizygous transcripts
+/// collapsed into a single haplotype per XY sample, the hemizygous transcripts of the cohort and the samples missing from the table, which are
+/// handled as diploid
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct SexChromosomeSummary
+{
+    pub sexes:HashMap<String,SampleSex>,
+    pub num_collapsed_per_sample:HashMap<String,usize>,
+    pub hemizygous_transcripts:HashSet<String>,
+    pub missing_samples:Vec<String>
+}
+impl SexChromosomeSummary
+{
+    /// ## Summary
+    /// Return the transcripts that are emitted as a single haplotype for a sample, i.e. the hemizygous transcripts for XY samples and none otherwise
+    pub fn get_single_copy_transcripts(&self, sample:&str)->HashSet<String>
+    {
+        match self.sexes.get(sample)
+        {
+            Some(SampleSex::XY)=>self.hemizygous_transcripts.clone(),
+            _=>HashSet::new()
+        }
+    }
+    /// ## Summary
+    /// Return a human readable report of the summary, listing at most max_listed samples missing from the table
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        let num_xy=self.sexes.values().filter(|sex|**sex==SampleSex::XY).count();
+        writeln!(report,"{} XX and {} XY samples, {} transcripts are outside the pseudoautosomal regions of chrX and chrY, {} altered transcripts were collapsed into a single haplotype",
+            self.sexes.len()-num_xy,num_xy,self.hemizygous_transcripts.len(),self.num_collapsed_per_sample.values().sum::<usize>()).unwrap();
+        if !self.missing_samples.is_empty()
+        {
+            writeln!(report,"{} samples are not listed in the sample-sex table and are handled as diploid: {}",self.missing_samples.len(),
+                self.missing_samples.iter().take(max_listed).cloned().collect::<Vec<String>>().join(", ")).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Collapse the hemizygous transcripts of the XY samples into the first haplotype, see IntMap::collapse_into_first_haplotype, samples of
+/// the IntMaps that are not listed in sexes are handled as diploid and are listed in the returned summary
+pub fn apply_sample_sexes(vec_maps:&mut [IntMap], sexes:&HashMap<String,SampleSex>, hemizygous_transcripts:HashSet<String>)->SexChromosomeSummary
+{
+    let mut summary=SexChromosomeSummary{hemizygous_transcripts,..Default::default()};
+    for int_map in vec_maps.iter_mut()
+    {
+        match sexes.get(int_map.get_name())
+        {
+            Some(sex)=>
+            {
+                summary.sexes.insert(int_map.get_name().clone(),*sex);
+                if *sex==SampleSex::XY
+                {
+                    let num_collapsed=int_map.collapse_into_first_haplotype(|transcript|summary.hemizygous_transcripts.contains(transcript));
+                    summary.num_collapsed_per_sample.insert(int_map.get_name().clone(),num_collapsed);
+                }
+            },
+            None=>summary.missing_samples.push(int_map.get_name().clone())
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod test_sex_chromosomes
+{
+    use super::*;
+    use crate::data_structures::vcf_ds::AltTranscript;
+    #[test]
+    fn test_get_hemizygous_transcripts()
+    {
+        let records=VCFRecords::new(["chrX\t48000000\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|48000000A>T",
+            "chrX\t1000000\t.\tA\tT\t.\tPASS\tBCSQ=missense|G2|T2|protein_coding|+|2K>2N|1000000A>T",
+            "chrX\t48000100\t.\tA\tT\t.\tPASS\tBCSQ=missense|G3|T3|protein_coding|+|2K>2N|48000100A>T",
+            "chrX\t2000000\t.\tA\tT\t.\tPASS\tBCSQ=missense|G3|T3|protein_coding|+|8K>8N|2000000A>T",
+            "7\t48000000\t.\tA\tT\t.\tPASS\tBCSQ=missense|G4|T4|protein_coding|+|2K>2N|48000000A>T"].iter().map(|record|record.to_string()).collect());
+        assert_eq!(get_hemizygous_transcripts(&records,ParBuild::GRCh38,Engine::MT),vec!["T1".to_string()].into_iter().collect::<HashSet<String>>());
+    }
+    #[test]
+    fn test_apply_sample_sexes()
+    {
+        let get_alt_transcript=|name:&str,aa_fields:&[&str]|AltTranscript::new(name.to_string(),aa_fields.iter()
+            .map(|aa_field|format!("missense|G|{}|protein_coding|+|{}|1A>T",name,aa_field)).collect());
+        let get_map=|name:&str|IntMap::new(name.to_string(),vec![get_alt_transcript("T1",&["2K>2N"]),get_alt_transcript("T4",&["3K>3N"])],
+            vec![get_alt_transcript("T1",&["2K>2N","5L>5P"]),get_alt_transcript("T4",&["3K>3N"])]);
+        let mut vec_maps=vec![get_map("S1"),get_map("S2"),get_map("S3")];
+        let sexes=[("S1",SampleSex::XY),("S2",SampleSex::XX)].iter().map(|(name,sex)|(name.to_string(),*sex)).collect::<HashMap<_,_>>();
+        let summary=apply_sample_sexes(&mut vec_maps,&sexes,vec!["T1".to_string()].into_iter().collect());
+        let (mutations1,mutations2)=vec_maps[0].get_mutations_ref();
+        assert_eq!(mutations1.iter().find(|alt|alt.name=="T1").unwrap().alts.iter().map(|alt|alt.mut_info.ref_aa_position).collect::<Vec<_>>(),vec![1,4]);
+        assert!(mutations2.iter().all(|alt|alt.name!="T1"));
+        assert_eq!(vec_maps[1].get_mutations_ref().1.len(),2);
+        assert_eq!((summary.num_collapsed_per_sample["S1"],summary.missing_samples.clone()),(1,vec!["S3".to_string()]));
+        assert_eq!(summary.get_single_copy_transcripts("S1").len(),1);
+        assert!(summary.get_single_copy_transcripts("S2").is_empty());
+    }
+}
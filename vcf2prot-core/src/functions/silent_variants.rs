@@ -0,0 +1,184 @@
+/// The module records the silent variants of the probands, i.e. the synonymous and the start_retained consequences, which do not change
+/// the protein sequence and are otherwise dropped with the unsupported consequences. The consequences carried by each proband are decoded
+/// from its bit-mask while the records are read and reported as zero-change annotations, optionally along with the fasta headers.
+use std::collections::HashMap;
+use std::str::FromStr;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::vcf_ds::VCFRecords;
+
+/// the types of the silent consequences, the types inherited from an upstream change are prefixed with *
+pub const SILENT_TYPES:[&str;2]=["synonymous","start_retained"];
+
+/// ## Summary
+/// The handling of the silent variants:
+/// 1. Skip, the default, the silent consequences are dropped with the unsupported consequences.
+/// 2. Report, the silent consequences carried by each proband are written to silent_variants.tsv.
+/// 3. Headers, as Report, moreover, the written records of the transcripts are tagged with their silent changes, e.g. silent=3L,377H.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum SilentVariantPolicy
+{
+    Skip,
+    Report,
+    Headers
+}
+impl Default for SilentVariantPolicy
+{
+    fn default()->Self
+    {
+        SilentVariantPolicy::Skip
+    }
+}
+impl SilentVariantPolicy
+{
+    /// ## Summary
+    /// Return the name of the policy as used on the command line
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            SilentVariantPolicy::Skip=>"skip",
+            SilentVariantPolicy::Report=>"report",
+            SilentVariantPolicy::Headers=>"headers"
+        }
+    }
+}
+impl FromStr for SilentVariantPolicy
+{
+    type Err=String;
+    fn from_str(policy:&str)->Result<SilentVariantPolicy,String>
+    {
+        match policy.to_lowercase().as_str()
+        {
+            "skip"=>Ok(SilentVariantPolicy::Skip),
+            "report"=>Ok(SilentVariantPolicy::Report),
+            "headers"=>Ok(SilentVariantPolicy::Headers),
+            _=>Err(format!("{} is not a supported policy for silent variants, supported policies are: skip, report and headers",policy))
+        }
+    }
+}
+
+/// ## Summary
+/// A silent consequence carried by a haplotype of a proband, i.e. its type, its gene and transcript and the unchanged residue along with
+/// the DNA change as written in the BCSQ field, e.g. 3L and 30A>T
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SilentVariant
+{
+    pub proband_name:String,
+    pub haplotype:u8,
+    pub csq_type:String,
+    pub gene:String,
+    pub transcript:String,
+    pub protein_change:String,
+    pub dna_change:String
+}
+impl SilentVariant
+{
+    /// ## Summary
+    /// Parse a silent consequence carried by a haplotype of a proband, None is returned if the consequence is not silent
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::silent_variants::SilentVariant;
+    /// let variant=SilentVariant::from_csq("P1",2,"*synonymous|G1|T1|protein_coding|+|3L|30A>T").unwrap();
+    /// assert_eq!((variant.transcript.as_str(),variant.protein_change.as_str()),("T1","3L"));
+    /// assert!(SilentVariant::from_csq("P1",1,"missense|G1|T1|protein_coding|+|2K>2N|10A>T").is_none());
+    ///```
+    pub fn from_csq(proband_name:&str, haplotype:u8, csq:&str)->Option<Self>
+    {
+        let fields=csq.split('|').collect::<Vec<&str>>();
+        if fields.len()!=7 || !SILENT_TYPES.contains(&fields[0].trim_start_matches('*'))
+        {
+            return None
+        }
+        Some(SilentVariant{proband_name:proband_name.to_string(),haplotype,csq_type:fields[0].to_string(),gene:fields[1].to_string(),
+            transcript:fields[2].to_string(),protein_change:fields[5].to_string(),dna_change:fields[6].to_string()})
+    }
+}
+
+/// ## Summary
+/// Return the silent consequences carried by the probands at a record, in the order of the probands and their haplotypes, where the
+/// consequences are decoded from the bit-mask of each proband, records without a BCSQ field or bit-masks that can not be decoded have none
+/// ## Example
+///```rust
+/// use ppgg::functions::silent_variants::get_silent_variants;
+/// let record="1\t30\t.\tA\tT\t.\tPASS\tBCSQ=synonymous|G1|T1|protein_coding|+|3L|30A>T\tGT:BCSQ\t1|0:1\t0|1:2\t0|0:0";
+/// let probands=vec!["P1".to_string(),"P2".to_string(),"P3".to_string()];
+/// let variants=get_silent_variants(record,&probands);
+/// assert_eq!(variants.iter().map(|variant|(variant.proband_name.as_str(),variant.haplotype)).collect::<Vec<_>>(),vec![("P1",1),("P2",2)]);
+///```
+pub fn get_silent_variants(line:&str, proband_names:&[String])->Vec<SilentVariant>
+{
+    let fields=line.split('\t').collect::<Vec<&str>>();
+    let bcsq=match fields.get(7).and_then(|info|info.split(';').find_map(|info_field|info_field.strip_prefix("BCSQ=")))
+    {
+        Some(bcsq) if SILENT_TYPES.iter().any(|csq_type|bcsq.contains(csq_type))=>bcsq,
+        _=>return Vec::new()
+    };
+    let bcsq_index=match fields.get(8).and_then(|format|format.split(':').position(|key|key=="BCSQ"))
+    {
+        Some(bcsq_index)=>bcsq_index,
+        None=>return Vec::new()
+    };
+    let mut variants=Vec::new();
+    for (proband_name,field) in proband_names.iter().zip(fields.iter().skip(9))
+    {
+        let (haplotype1,haplotype2)=match field.split(':').nth(bcsq_index).map(|bitmask|VCFRecords::try_extract_effects(bcsq,bitmask))
+        {
+            Some(Ok(effects))=>effects,
+            _=>continue
+        };
+        for (haplotype,effects) in [(1,haplotype1),(2,haplotype2)]
+        {
+            variants.extend(effects.iter().filter_map(|csq|SilentVariant::from_csq(proband_name,haplotype,csq)));
+        }
+    }
+    variants
+}
+
+/// ## Summary
+/// Collect the silent consequences carried by the probands from the records of a VCF file, see get_silent_variants, the variants are 
+/// returned in the order of the records
+pub fn collect_silent_variants(lines:&[String], proband_names:&[String], engine:Engine)->Vec<SilentVariant>
+{
+    match engine
+    {
+        Engine::ST=>lines.iter().flat_map(|line|get_silent_variants(line,proband_names)).collect::<Vec<SilentVariant>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().flat_map_iter(|line|get_silent_variants(line,proband_names)).collect::<Vec<SilentVariant>>()
+    }
+}
+
+/// ## Summary
+/// Return the header tags of the silent variants of a proband per transcript, e.g. silent=3L,377H, along with the haplotype carrying them
+pub fn get_header_tags(variants:&[SilentVariant], proband_name:&str)->HashMap<(String,u8),String>
+{
+    let mut grouped:HashMap<(String,u8),Vec<&str>>=HashMap::new();
+    for variant in variants.iter().filter(|variant|variant.proband_name==proband_name)
+    {
+        grouped.entry((variant.transcript.clone(),variant.haplotype)).or_default().push(&variant.protein_change);
+    }
+    grouped.into_iter().map(|(key,changes)|(key,format!("silent={}",changes.join(",")))).collect()
+}
+
+#[cfg(test)]
+mod test_silent_variants
+{
+    use super::*;
+    #[test]
+    fn test_silent_variants_are_decoded_per_haplotype()
+    {
+        // the second consequence is carried by both haplotypes of the first proband, the fourth one by the second haplotype of the second
+        let record="1\t30\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,synonymous|G1|T2|protein_coding|+|3L|30A>T,\
+            @25,*start_retained|G2|T3|protein_coding|+|1M|30A>T\tGT:BCSQ\t1|1:15\t0|1:128\t.|.:.";
+        let probands=vec!["P1".to_string(),"P2".to_string(),"P3".to_string()];
+        let variants=get_silent_variants(record,&probands);
+        assert_eq!(variants.iter().map(|variant|(variant.proband_name.as_str(),variant.haplotype,variant.transcript.as_str())).collect::<Vec<_>>(),
+            vec![("P1",1,"T2"),("P1",2,"T2"),("P2",2,"T3")]);
+        assert_eq!(variants[2].csq_type,"*start_retained");
+        let tags=get_header_tags(&variants,"P1");
+        assert_eq!((tags.len(),tags[&("T2".to_string(),2)].as_str()),(2,"silent=3L"));
+        assert!(get_silent_variants(&record.replace("synonymous","missense").replace("start_retained","missense"),&probands).is_empty());
+        assert_eq!(collect_silent_variants(&[record.to_string(),record.to_string()],&probands,Engine::MT).len(),2*variants.len());
+        assert_eq!(SilentVariantPolicy::from_str("Headers").unwrap(),SilentVariantPolicy::Headers);
+        assert!(SilentVariantPolicy::from_str("all").is_err());
+    }
+}
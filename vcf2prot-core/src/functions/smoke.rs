@@ -0,0 +1,105 @@
+/// The module supports a smoke mode for testing the integration of Vcf2prot into downstream pipelines, only the first records and the first 
+/// samples of a VCF file are processed, hence, a run completes in seconds regardless of the size of the input, and a fixed set of files, i.e. 
+/// a fasta file per sample and the run manifest, is written and listed along with the size and the digest of each file in a smoke manifest
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use crate::functions::incremental::{self,RunManifest};
+use crate::writers::RUN_MANIFEST_FILE_NAME;
+
+/// ## Summary
+/// The default number of records and samples processed in the smoke mode 
+pub const DEFAULT_SMOKE_RECORDS:usize=100;
+pub const DEFAULT_SMOKE_SAMPLES:usize=2;
+
+/// ## Summary
+/// The size of the input processed in the smoke mode, i.e. the number of supported records, taken from the start of the VCF file, and the 
+/// number of samples, taken in the order of the VCF header, see io::parse_vcf_smoke 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct SmokeConfig
+{
+    pub num_records:usize,
+    pub num_samples:usize
+}
+impl Default for SmokeConfig
+{
+    fn default()->Self
+    {
+        SmokeConfig{num_records:DEFAULT_SMOKE_RECORDS,num_samples:DEFAULT_SMOKE_SAMPLES}
+    }
+}
+
+/// ## Summary
+/// A file written by a smoke run, i.e. its name in the output directory, its size in bytes and the digest of its content, see 
+/// incremental::get_bytes_digest 
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SmokeFile
+{
+    pub file_name:String,
+    pub num_bytes:u64,
+    pub digest:String
+}
+
+/// ## Summary
+/// Return the files written by a smoke run, i.e. the fasta file of each sample of the manifest that was not marked as failed, in the order of 
+/// the manifest, followed by the run manifest itself, an error is returned if one of the files can not be read 
+pub fn get_smoke_files(output_dir:&Path, manifest:&RunManifest)->Result<Vec<SmokeFile>,String>
+{
+    manifest.entries.iter()
+        .filter(|entry|!entry.is_failed)
+        .map(|entry|format!("{}.fasta",entry.file_stem))
+        .chain(std::iter::once(RUN_MANIFEST_FILE_NAME.to_string()))
+        .map(|file_name|match fs::read(output_dir.join(&file_name))
+        {
+            Ok(content)=>Ok(SmokeFile{num_bytes:content.len() as u64,digest:incremental::get_bytes_digest(&content),file_name}),
+            Err(err_msg)=>Err(format!("Reading the file: {} written by the smoke run failed with the following error: {}",file_name,err_msg))
+        })
+        .collect()
+}
+
+/// ## Summary
+/// Return the tab-separated representation of the files of a smoke run, i.e. a header and a line per file 
+/// ## Example
+///```rust
+/// use ppgg::functions::smoke::{SmokeFile,smoke_files_to_table};
+/// let files=vec![SmokeFile{file_name:"S1.fasta".to_string(),num_bytes:12,digest:"00ff".to_string()}];
+/// assert_eq!(smoke_files_to_table(&files),"File Name\tBytes\tDigest\nS1.fasta\t12\t00ff\n");
+///```
+pub fn smoke_files_to_table(files:&[SmokeFile])->String
+{
+    let mut table=String::new();
+    writeln!(table,"File Name\tBytes\tDigest").unwrap();
+    for file in files.iter()
+    {
+        writeln!(table,"{}\t{}\t{}",file.file_name,file.num_bytes,file.digest).unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod test_smoke
+{
+    use super::*;
+    use crate::parts::io;
+    use crate::data_structures::InternalRep::engines::Engine;
+    use crate::functions::contigs::ContigPolicy;
+    use crate::functions::missing_genotypes::MissingGenotypePolicy;
+    #[test]
+    fn test_parse_vcf_smoke()
+    {
+        let header="##fileformat=VCFv4.2\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1\tS2\tS3\n";
+        let records=(1..=5).map(|index|format!("1\t{}0\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|{}K>{}N|{}0A>T\tGT:BCSQ\t0|1:2\t1|0:1\t1|1:3\n",
+            index,index,index,index)).collect::<String>();
+        let path2vcf=std::env::temp_dir().join("vcf2prot_test_parse_vcf_smoke.vcf");
+        fs::write(&path2vcf,format!("{}{}",header,records)).unwrap();
+        let config=SmokeConfig{num_records:3,num_samples:2};
+        let int_maps=io::parse_vcf_smoke(&path2vcf,Engine::ST,None,ContigPolicy::default(),MissingGenotypePolicy::default(),&config,
+            &crate::readers::ParseOptions::default()).unwrap().vec_int_map;
+        // only the first two samples are parsed and they only carry the mutations of the first three records 
+        assert_eq!(int_maps.iter().map(|int_map|int_map.get_name().as_str()).collect::<Vec<&str>>(),vec!["S1","S2"]);
+        let (mutations1,mutations2)=int_maps[0].get_mutations_ref();
+        assert!(mutations1.is_empty());
+        assert_eq!(mutations2.iter().map(|transcript|transcript.get_alts().len()).sum::<usize>(),3);
+        fs::remove_file(&path2vcf).unwrap();
+    }
+}
@@ -0,0 +1,289 @@
+/// The module implements the somatic mode, i.e. a tumor sample is designated among the probands of a VCF file, optionally along with its
+/// matched normal sample, only the variants of the tumor that are absent from the normal are applied and the variant allele fraction and
+/// the read depth of the tumor are collected from its FORMAT fields for the headers of the altered records
+use std::collections::HashMap;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::vcf_ds::{Genotype,VCFRecords};
+use crate::readers::vcf_helpers;
+
+/// ## Summary
+/// The tumor sample of a somatic run along with its matched normal sample, if any
+/// ## Example
+///```rust
+/// use ppgg::functions::somatic::SomaticMode;
+/// let mode=SomaticMode::new("TUMOR".to_string(),Some("NORMAL".to_string())).unwrap();
+/// let columns=mode.resolve_columns(&["NORMAL".to_string(),"TUMOR".to_string()]).unwrap();
+/// assert_eq!((columns.tumor,columns.normal),(1,Some(0)));
+/// assert!(SomaticMode::new("TUMOR".to_string(),Some("TUMOR".to_string())).is_err());
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SomaticMode
+{
+    tumor:String,
+    normal:Option<String>
+}
+impl SomaticMode
+{
+    /// ## Summary
+    /// Create a new somatic mode from the names of the tumor and the normal samples, an error is returned if both names are the same
+    pub fn new(tumor:String, normal:Option<String>)->Result<Self,String>
+    {
+        if normal.as_ref()==Some(&tumor)
+        {
+            return Err(format!("The tumor and the normal sample must differ, both are: {}",tumor));
+        }
+        Ok(SomaticMode{tumor,normal})
+    }
+    /// ## Summary
+    /// Return the name of the tumor sample
+    pub fn get_tumor(&self)->&str
+    {
+        &self.tumor
+    }
+    /// ## Summary
+    /// Return the name of the normal sample, if any
+    pub fn get_normal(&self)->Option<&str>
+    {
+        self.normal.as_deref()
+    }
+    /// ## Summary
+    /// Return the zero-based columns of the tumor and the normal sample among the probands of a VCF file, an error is returned if a sample
+    /// is not part of the file
+    pub fn resolve_columns(&self, proband_names:&[String])->Result<SomaticColumns,String>
+    {
+        let get_column=|sample:&str|match proband_names.iter().position(|name|name==sample)
+        {
+            Some(column)=>Ok(column),
+            None=>Err(format!("The sample: {} of the somatic mode is not part of the VCF file",sample))
+        };
+        Ok(SomaticColumns{tumor:get_column(&self.tumor)?,normal:self.normal.as_deref().map(get_column).transpose()?})
+    }
+}
+
+/// ## Summary
+/// The zero-based columns of the tumor and of the normal sample among the probands of a VCF file
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct SomaticColumns
+{
+    pub tumor:usize,
+    pub normal:Option<usize>
+}
+
+/// ## Summary
+/// The support of a variant in the tumor, i.e. its variant allele fraction, taken from the AF field or computed from the AD field, and its
+/// read depth, taken from the DP field or computed from the AD field, None if the fields are missing
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct VariantSupport
+{
+    pub vaf:Option<f64>,
+    pub depth:Option<u32>
+}
+impl VariantSupport
+{
+    /// ## Summary
+    /// Parse the support of a variant from the FORMAT layout of a record, e.g. GT:AD:DP:BCSQ, and the field of the tumor sample, the fraction
+    /// of a multi-allelic record is the sum of the fractions of its ALT alleles
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::somatic::VariantSupport;
+    /// assert_eq!(VariantSupport::from_field("GT:AD:BCSQ","0|1:30,10:2"),VariantSupport{vaf:Some(0.25),depth:Some(40)});
+    /// assert_eq!(VariantSupport::from_field("GT:AF:DP","0|1:0.4:50"),VariantSupport{vaf:Some(0.4),depth:Some(50)});
+    /// assert_eq!(VariantSupport::from_field("GT","0|1"),VariantSupport::default());
+    ///```
+    pub fn from_field(format:&str, field:&str)->Self
+    {
+        let values=format.split(':').zip(field.split(':')).collect::<HashMap<&str,&str>>();
+        let allelic_depths=values.get("AD").and_then(|ad|ad.split(',').map(|depth|depth.parse::<u32>().ok()).collect::<Option<Vec<u32>>>());
+        let vaf=match values.get("AF").and_then(|af|af.split(',').map(|fraction|fraction.parse::<f64>().ok()).sum::<Option<f64>>())
+        {
+            Some(vaf)=>Some(vaf),
+            None=>allelic_depths.as_ref().and_then(|depths|
+            {
+                let total=depths.iter().sum::<u32>();
+                match (total,depths.first())
+                {
+                    (0,_) | (_,None)=>None,
+                    (total,Some(ref_depth))=>Some((total-ref_depth) as f64/total as f64)
+                }
+            })
+        };
+        let depth=match values.get("DP").and_then(|dp|dp.parse::<u32>().ok())
+        {
+            Some(depth)=>Some(depth),
+            None=>allelic_depths.map(|depths|depths.iter().sum::<u32>())
+        };
+        VariantSupport{vaf,depth}
+    }
+}
+
+/// ## Summary
+/// Return the columns of the samples of the provided somatic mode among the probands of a VCF file, or None if the somatic mode is not used
+pub fn get_somatic_columns(proband_names:&[String], mode:Option<&SomaticMode>)->Result<Option<SomaticColumns>,String>
+{
+    match mode
+    {
+        Some(mode)=>mode.resolve_columns(proband_names).map(Some),
+        None=>Ok(None)
+    }
+}
+
+/// ## Summary
+/// Remove the germline variants from the tumor sample, i.e. the records where every ALT allele called in the tumor is called in the normal
+/// sample as well are set to the reference in the tumor, its genotype alleles are set to 0 and its bit-mask to zero. Returns the records
+/// along with the number of removed variants, the records are returned unchanged if no normal sample is provided.
+pub fn remove_germline_variants(lines:Vec<String>, columns:SomaticColumns, engine:Engine)->(Vec<String>,usize)
+{
+    let normal=match columns.normal
+    {
+        Some(normal)=>normal,
+        None=>return (lines,0)
+    };
+    let results=match engine
+    {
+        Engine::ST=>lines.into_iter().map(|line|remove_germline_variant(line,columns.tumor,normal)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.into_par_iter().map(|line|remove_germline_variant(line,columns.tumor,normal)).collect::<Vec<_>>()
+    };
+    let num_removed=results.iter().filter(|(_,is_removed)|*is_removed).count();
+    (results.into_iter().map(|(line,_)|line).collect(),num_removed)
+}
+
+/// set the tumor field of a record to the reference if the variants of the tumor are carried by the normal, returns whether it was set
+fn remove_germline_variant(line:String, tumor:usize, normal:usize)->(String,bool)
+{
+    let fields=line.split('\t').collect::<Vec<&str>>();
+    let (gt_index,bcsq_index)=match fields.get(8)
+    {
+        Some(format)=>(format.split(':').position(|field|field=="GT"),format.split(':').position(|field|field=="BCSQ")),
+        None=>return (line,false)
+    };
+    let (gt_index,tumor_field,normal_field)=match (gt_index,fields.get(9+tumor),fields.get(9+normal))
+    {
+        (Some(gt_index),Some(tumor_field),Some(normal_field))=>(gt_index,*tumor_field,*normal_field),
+        _=>return (line,false)
+    };
+    let get_alt_alleles=|field:&str|match Genotype::parse(field.split(':').nth(gt_index).unwrap_or(""))
+    {
+        Some(genotype)=>genotype.get_alleles().iter().filter_map(|allele|*allele).filter(|allele|*allele!=0).collect::<Vec<usize>>(),
+        None=>Vec::new()
+    };
+    let (tumor_alleles,normal_alleles)=(get_alt_alleles(tumor_field),get_alt_alleles(normal_field));
+    if tumor_alleles.is_empty() || !tumor_alleles.iter().all(|allele|normal_alleles.contains(allele))
+    {
+        return (line,false)
+    }
+    let mut sub_fields=tumor_field.split(':').map(|sub_field|sub_field.to_string()).collect::<Vec<String>>();
+    sub_fields[gt_index]=Genotype::to_reference_call(&sub_fields[gt_index]);
+    if let Some(bcsq_index)=bcsq_index.filter(|bcsq_index|*bcsq_index<sub_fields.len())
+    {
+        sub_fields[bcsq_index]="0".to_string();
+    }
+    let tumor_field=sub_fields.join(":");
+    let mut fields=fields;
+    fields[9+tumor]=&tumor_field;
+    (fields.join("\t"),true)
+}
+
+/// ## Summary
+/// Return the support of the variant of a record in its first sample for each transcript with a supported consequence carried by the
+/// sample, i.e. decoded from its bit-mask, in the order of the consequences
+/// ## Example
+///```rust
+/// use ppgg::functions::somatic::{get_variant_support,VariantSupport};
+/// let record="1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T,missense|G2|T2|protein_coding|+|5K>5N|10A>T\tGT:AD:BCSQ\t0|1:30,10:8";
+/// assert_eq!(get_variant_support(record),vec![("T2".to_string(),VariantSupport{vaf:Some(0.25),depth:Some(40)})]);
+///```
+pub fn get_variant_support(line:&str)->Vec<(String,VariantSupport)>
+{
+    let fields=line.split('\t').collect::<Vec<&str>>();
+    let (format,field)=match (fields.get(8),fields.get(9))
+    {
+        (Some(format),Some(field))=>(*format,*field),
+        _=>return Vec::new()
+    };
+    let bitmask=match format.split(':').position(|key|key=="BCSQ").and_then(|bcsq_index|field.split(':').nth(bcsq_index))
+    {
+        Some(bitmask)=>bitmask,
+        None=>return Vec::new()
+    };
+    let bcsq=match fields[7].split(';').find_map(|info_field|info_field.strip_prefix("BCSQ="))
+    {
+        Some(bcsq)=>bcsq,
+        None=>return Vec::new()
+    };
+    let (haplotype1,haplotype2)=match VCFRecords::try_extract_effects(bcsq,bitmask)
+    {
+        Ok(effects)=>effects,
+        Err(_)=>return Vec::new()
+    };
+    let support=VariantSupport::from_field(format, field);
+    let mut transcripts:Vec<String>=Vec::new();
+    for csq in haplotype1.iter().chain(haplotype2.iter()).filter(|csq|vcf_helpers::is_supported_csq(csq))
+    {
+        if let Some(transcript)=csq.split('|').nth(2).filter(|transcript|!transcripts.iter().any(|known|known==transcript))
+        {
+            transcripts.push(transcript.to_string());
+        }
+    }
+    transcripts.into_iter().map(|transcript|(transcript,support)).collect()
+}
+
+/// ## Summary
+/// Collect the support of the variants of the tumor from the records of a VCF file, where the tumor is the only remaining proband, see
+/// get_variant_support, the support of each transcript is returned in the order of the records
+pub fn collect_variant_support(lines:&[String], engine:Engine)->Vec<(String,VariantSupport)>
+{
+    match engine
+    {
+        Engine::ST=>lines.iter().flat_map(|line|get_variant_support(line)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.par_iter().flat_map_iter(|line|get_variant_support(line)).collect::<Vec<_>>()
+    }
+}
+
+/// ## Summary
+/// Return the header tags of the collected support per transcript, e.g. vaf=0.250,0.400 dp=40,50 for a transcript altered by two variants,
+/// where a missing value is written as ., see collect_variant_support
+pub fn get_support_tags(variant_support:&[(String,VariantSupport)])->HashMap<String,String>
+{
+    let mut grouped:HashMap<String,Vec<VariantSupport>>=HashMap::new();
+    for (transcript,support) in variant_support.iter()
+    {
+        grouped.entry(transcript.clone()).or_default().push(*support);
+    }
+    grouped.into_iter().map(|(transcript,supports)|
+    {
+        let vaf=supports.iter().map(|support|support.vaf.map(|vaf|format!("{:.3}",vaf)).unwrap_or_else(||".".to_string())).collect::<Vec<String>>();
+        let depth=supports.iter().map(|support|support.depth.map(|depth|depth.to_string()).unwrap_or_else(||".".to_string())).collect::<Vec<String>>();
+        (transcript,format!("vaf={} dp={}",vaf.join(","),depth.join(",")))
+    }).collect()
+}
+
+#[cfg(test)]
+mod test_somatic
+{
+    use super::*;
+    #[test]
+    fn test_remove_germline_variants()
+    {
+        let lines=["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:AD:BCSQ\t0|1:20,20:2\t0/1:30,10:2",
+            "1\t20\t.\tA\tT,C\t.\tPASS\tBCSQ=x\tGT:AD:BCSQ\t0|1:20,0,20:2\t1|2:20,10,10:6",
+            "1\t30\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:AD:BCSQ\t0|0:40,0:0\t.|1:20,20:2"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let columns=SomaticMode::new("T".to_string(),Some("N".to_string())).unwrap().resolve_columns(&["N".to_string(),"T".to_string()]).unwrap();
+        let (retained,num_removed)=remove_germline_variants(lines.clone(),columns,Engine::ST);
+        // the first variant is carried by the normal, while the second ALT allele of the second record is somatic
+        assert_eq!(num_removed,1);
+        assert_eq!(retained[0],"1\t10\t.\tA\tT\t.\tPASS\tBCSQ=x\tGT:AD:BCSQ\t0|1:20,20:2\t0/0:30,10:0");
+        assert_eq!(retained[1..],lines[1..]);
+        assert_eq!(remove_germline_variants(lines.clone(),SomaticColumns{tumor:1,normal:None},Engine::MT),(lines,0));
+        assert!(SomaticMode::new("T".to_string(),None).unwrap().resolve_columns(&["N".to_string()]).is_err());
+        assert_eq!(VariantSupport::from_field("GT:AD:DP","0|1:0,0:0"),VariantSupport{vaf:None,depth:Some(0)});
+        let records=["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:AD:BCSQ\t0|1:30,10:2",
+            "1\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|5K>5N|20A>T\tGT:AF:BCSQ\t1|0:0.4:1"].iter()
+            .map(|line|line.to_string()).collect::<Vec<String>>();
+        let variant_support=collect_variant_support(&records,Engine::MT);
+        assert_eq!(variant_support,collect_variant_support(&records,Engine::ST));
+        assert_eq!(get_support_tags(&variant_support)["T1"],"vaf=0.250,0.400 dp=40,.");
+        assert!(get_support_tags(&[]).is_empty());
+    }
+}
@@ -0,0 +1,859 @@
+/// The module contains function to compute statistical summaries 
+use std::collections::{HashMap,HashSet}; 
+use rayon::prelude::*; 
+use crate::data_structures::Map; 
+use crate::data_structures::mutation_ds::MutationType; 
+use crate::data_structures::InternalRep::engines::Engine; 
+use crate::data_structures::InternalRep::transcript_instructions::{OverlapPolicy,OverlapSummary,TranscriptInstruction}; 
+use crate::data_structures::InternalRep::gir::PLACEHOLDER_RESIDUE; 
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome; 
+use crate::data_structures::Constants;
+use crate::functions::metrics::MetricsSnapshot;
+use crate::functions::diagnostics::DiagnosticOptions;
+use std::str::FromStr;
+use std::fmt::Write;
+use serde::Serialize;
+
+/// ## Summary
+/// Compute the mutational load per patient from an input vector of intermediate representation 
+pub fn compute_number_mutation_per_proband(vec_intmaps:&Vec<Map::IntMap>)->HashMap<String,u64>
+{
+    vec_intmaps.par_iter()
+    .map(|int_map|stat_helper::number_mutations_per_proband(int_map))
+    .collect::<HashMap<String,u64>>()
+}
+/// ## Summary
+/// Compute the number of each mutational type in the patient from an input vector of intermediate representations 
+pub fn compute_type_mutations_per_patient(vec_intmaps:&Vec<Map::IntMap>)->HashMap<String,Vec<u64>>
+{
+    vec_intmaps.par_iter()
+    .map(|int_map|stat_helper::compute_mutation_frequency(int_map))
+    .collect::<HashMap<String,Vec<u64>>>()
+}
+/// ## Summary
+/// Compute the number of alterations or mutations per transcript from an input vector of intermediate representations 
+pub fn compute_number_of_mutations_per_transcript(vec_intmaps:&Vec<Map::IntMap>)->HashMap<String,u64>
+{
+    let transcript_names=stat_helper::get_uniuqe_transcript(vec_intmaps);
+    transcript_names.into_par_iter()
+    .map(|transcript_name| stat_helper::count_in_all_individual(transcript_name,&vec_intmaps))
+    .collect::<HashMap<String,u64>>()
+}
+/// ## Summary
+/// The altered transcripts of a proband grouped by the haplotypes carrying their variants, a transcript that is altered in only one of the
+/// haplotypes has an unmodified haplotype whose sequence is the reference sequence, see personalized_genome::UnmodifiedHaplotypes
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct HaplotypeSpread
+{
+    pub num_haplotype1_only:u64,
+    pub num_haplotype2_only:u64,
+    pub num_both:u64
+}
+impl HaplotypeSpread
+{
+    /// ## Summary
+    /// Return the number of transcripts altered in exactly one haplotype
+    pub fn num_single_haplotype(&self)->u64
+    {
+        self.num_haplotype1_only+self.num_haplotype2_only
+    }
+}
+/// ## Summary
+/// Compute per proband the number of transcripts with variants on the first haplotype only, on the second haplotype only and on both haplotypes,
+/// transcripts without a supported mutation are not counted
+pub fn compute_haplotype_spread(vec_intmaps:&[Map::IntMap])->HashMap<String,HaplotypeSpread>
+{
+    vec_intmaps.par_iter()
+    .map(|int_map|(int_map.get_name().clone(),stat_helper::haplotype_spread_per_proband(int_map)))
+    .collect::<HashMap<String,HaplotypeSpread>>()
+}
+/// ## Summary
+/// The mutational load of a proband normalized by the size of the callable proteome, i.e. the transcripts in the reference proteome.
+/// Edits located on transcripts that are not part of the reference are not counted as they can not be applied.
+/// The edits per kb are computed relative to the diploid proteome length as edits from both haplotypes are counted.
+#[derive(Debug,Clone,PartialEq)]
+pub struct MutationLoad
+{
+    pub num_edits:u64,
+    pub num_altered_transcripts:u64,
+    pub num_callable_transcripts:u64,
+    pub proteome_length:u64,
+    pub edits_per_transcript:f64,
+    pub edits_per_kb:f64
+}
+/// ## Summary
+/// Compute the mutational load per proband normalized by the number of callable transcripts and by the length of the proteome,
+/// the results can be used directly for burden analyses across cohorts that have been generated with different references. 
+pub fn compute_normalized_mutation_load(vec_intmaps:&Vec<Map::IntMap>, ref_seq:&HashMap<String,String>)->HashMap<String,MutationLoad>
+{
+    let num_callable_transcripts=ref_seq.len() as u64; 
+    let proteome_length=ref_seq.par_iter().map(|(_,seq)|seq.len() as u64).sum::<u64>(); 
+    vec_intmaps.par_iter()
+    .map(|int_map|stat_helper::normalized_load_per_proband(int_map,ref_seq,num_callable_transcripts,proteome_length))
+    .collect::<HashMap<String,MutationLoad>>()
+}
+/// ## Summary
+/// The variants of a reference transcript across the cohort, i.e. the number of samples with at least one variant on either haplotype of the
+/// transcript and the distinct one-based positions of these variants in the reference sequence, sorted in ascending order
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct TranscriptDecoration
+{
+    pub transcript:String,
+    pub length:u64,
+    pub num_samples:u64,
+    pub positions:Vec<u32>
+}
+/// ## Summary
+/// Decorate every transcript of the reference with its variants across the cohort, see TranscriptDecoration, the transcripts are sorted by the 
+/// number of samples carrying them, from the most to the least variable, then by name, hence, hypervariable proteins are listed first. 
+/// Variants of transcripts that are not part of the reference are not counted as they can not be applied. 
+pub fn compute_transcript_decoration(vec_intmaps:&[Map::IntMap], ref_seq:&HashMap<String,String>)->Vec<TranscriptDecoration>
+{
+    let positions_per_sample=vec_intmaps.par_iter()
+        .map(|int_map|stat_helper::get_positions_per_transcript(int_map,ref_seq))
+        .collect::<Vec<HashMap<&str,Vec<u32>>>>(); 
+    let mut decoration=ref_seq.iter()
+        .map(|(transcript,seq)|(transcript.as_str(),TranscriptDecoration{transcript:transcript.clone(),length:seq.len() as u64,num_samples:0,positions:Vec::new()}))
+        .collect::<HashMap<&str,TranscriptDecoration>>(); 
+    for positions_per_transcript in positions_per_sample.into_iter()
+    {
+        for (transcript,positions) in positions_per_transcript.into_iter()
+        {
+            let entry=decoration.get_mut(transcript).unwrap(); 
+            entry.num_samples+=1; 
+            entry.positions.extend(positions); 
+        }
+    }
+    let mut decoration=decoration.into_values().collect::<Vec<TranscriptDecoration>>(); 
+    decoration.par_iter_mut().for_each(|entry|{entry.positions.sort_unstable(); entry.positions.dedup()}); 
+    decoration.sort_by(|entry1,entry2|entry2.num_samples.cmp(&entry1.num_samples).then_with(||entry1.transcript.cmp(&entry2.transcript))); 
+    decoration
+}
+/// ## Summary
+/// The transcripts referenced in the VCF, i.e. the transcripts with at least one supported consequence in any haplotype, that could not be
+/// personalized, either because they are missing from the reference or because they were skipped due to errors while translating their
+/// mutations into instructions and tasks. The missing transcripts are sorted by name and the skipped ones are stored along with the first
+/// error that was encountered. This is used as a quality gate to catch reference proteomes that do not match the annotation of the VCF.
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct TranscriptCoverage
+{
+    pub num_referenced:u64,
+    pub missing:Vec<String>,
+    pub skipped:Vec<(String,String)>
+}
+impl TranscriptCoverage
+{
+    /// ## Summary
+    /// Return the number of transcripts that could not be personalized, i.e. missing along with skipped transcripts
+    pub fn num_failed(&self)->u64
+    {
+        (self.missing.len()+self.skipped.len()) as u64
+    }
+    /// ## Summary
+    /// Return the percentage of referenced transcripts that could not be personalized, zero is returned if no transcript is referenced
+    pub fn percent_failed(&self)->f64
+    {
+        match self.num_referenced
+        {
+            0=>0.0,
+            num=>self.num_failed() as f64*100.0/num as f64
+        }
+    }
+    /// ## Summary
+    /// Return a human readable breakdown of the coverage, at most max_listed transcripts are listed per category
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"{} of {} transcripts referenced in the VCF could not be personalized ({:.2}%)",self.num_failed(),
+                    self.num_referenced,self.percent_failed()).unwrap();
+        writeln!(report,"  missing from the reference: {}",self.missing.len()).unwrap();
+        for transcript in self.missing.iter().take(max_listed)
+        {
+            writeln!(report,"    {}",transcript).unwrap();
+        }
+        if self.missing.len()>max_listed
+        {
+            writeln!(report,"    ... and {} more",self.missing.len()-max_listed).unwrap();
+        }
+        writeln!(report,"  skipped due to errors: {}",self.skipped.len()).unwrap();
+        for (transcript,err_msg) in self.skipped.iter().take(max_listed)
+        {
+            writeln!(report,"    {}: {}",transcript,err_msg).unwrap();
+        }
+        if self.skipped.len()>max_listed
+        {
+            writeln!(report,"    ... and {} more",self.skipped.len()-max_listed).unwrap();
+        }
+        report
+    }
+}
+/// ## Summary
+/// Compute the transcripts referenced in the VCF that can not be personalized using the provided reference, a transcript is skipped if
+/// translating the alterations of any haplotype into instructions or tasks fails, where the overlapping mutations are resolved with the 
+/// provided strategy, if any, or checked with the diagnostics, see TranscriptCoverage for more details
+pub fn compute_transcript_coverage(vec_intmaps:&[Map::IntMap], ref_seq:&HashMap<String,String>, engine:Engine, 
+    overlap_policy:Option<OverlapPolicy>, diagnostic_options:&DiagnosticOptions)->TranscriptCoverage
+{
+    let errors=match engine
+    {
+        Engine::ST=>vec_intmaps.iter().flat_map(|int_map|stat_helper::get_translation_errors(int_map,ref_seq,overlap_policy,diagnostic_options)).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_intmaps.par_iter().flat_map(|int_map|stat_helper::get_translation_errors(int_map,ref_seq,overlap_policy,diagnostic_options)).collect::<Vec<_>>()
+    };
+    let referenced=stat_helper::get_uniuqe_transcript(vec_intmaps);
+    let mut coverage=TranscriptCoverage{num_referenced:referenced.len() as u64,missing:Vec::new(),skipped:Vec::new()};
+    coverage.missing=referenced.into_iter().filter(|transcript|!ref_seq.contains_key(transcript)).collect::<Vec<_>>();
+    let mut first_errors:HashMap<String,String>=HashMap::new();
+    for (transcript,err_msg) in errors.into_iter()
+    {
+        first_errors.entry(transcript).or_insert(err_msg);
+    }
+    coverage.skipped=first_errors.into_iter().collect::<Vec<_>>();
+    coverage.skipped.sort();
+    coverage
+}
+
+/// ## Summary
+/// The counts of a proband in the cohort statistics, i.e. the altered transcripts and the mutations of its representation, where the
+/// transcripts missing from the reference and their mutations are skipped as they can not be applied. The consequences that were dropped
+/// while parsing, e.g. unsupported or unparsable consequences, and the transcripts skipped by the execution are only known once the
+/// proband is executed, see CohortStatistics::add_execution_metrics
+#[derive(Debug,Clone,PartialEq,Eq,Default,Serialize)]
+pub struct ProbandStatistics
+{
+    pub proband_name:String,
+    pub num_altered_transcripts:u64,
+    pub num_mutations:u64,
+    pub num_missing_transcripts:u64,
+    pub num_missing_mutations:u64,
+    pub num_dropped_consequences:u64,
+    pub num_skipped_transcripts:u64
+}
+/// ## Summary
+/// The counts of a gene across the cohort, i.e. the number of its altered transcripts, the number of samples carrying a mutation in at
+/// least one of these transcripts and the number of mutations summed over these transcripts
+#[derive(Debug,Clone,PartialEq,Eq,Serialize)]
+pub struct GeneStatistics
+{
+    pub gene:String,
+    pub num_transcripts:u64,
+    pub num_carriers:u64,
+    pub num_mutations:u64
+}
+/// ## Summary
+/// The counts of a transcript across the cohort, i.e. the number of samples and haplotypes carrying a mutation in the transcript and
+/// the number of these mutations, the gene is None if the gene symbol of the transcript is unknown
+#[derive(Debug,Clone,PartialEq,Eq,Serialize)]
+pub struct TranscriptStatistics
+{
+    pub transcript:String,
+    pub gene:Option<String>,
+    pub is_in_reference:bool,
+    pub num_carriers:u64,
+    pub num_altered_haplotypes:u64,
+    pub num_mutations:u64
+}
+/// ## Summary
+/// The counts of a consequence type across the cohort, i.e. the number of samples carrying the consequence, the number of mutations and
+/// the number of these mutations that were skipped as their transcript is missing from the reference
+#[derive(Debug,Clone,PartialEq,Eq,Serialize)]
+pub struct ConsequenceStatistics
+{
+    pub consequence:String,
+    pub num_carriers:u64,
+    pub num_mutations:u64,
+    pub num_missing_mutations:u64
+}
+/// ## Summary
+/// The statistics of a cohort run per proband, per gene, per transcript and per consequence type, used for checking a run at a glance.
+/// The probands are sorted by name, while the genes and the transcripts are sorted by the number of carriers, from the most to the least
+/// frequently altered, then by name, and the consequences are sorted by the number of mutations then by name.
+#[derive(Debug,Clone,PartialEq,Eq,Default,Serialize)]
+pub struct CohortStatistics
+{
+    pub probands:Vec<ProbandStatistics>,
+    pub genes:Vec<GeneStatistics>,
+    pub transcripts:Vec<TranscriptStatistics>,
+    pub consequences:Vec<ConsequenceStatistics>
+}
+impl CohortStatistics
+{
+    /// ## Summary
+    /// Add the number of dropped consequences and skipped transcripts of each executed proband from the accumulated metrics of the run,
+    /// the probands that are not part of the snapshot keep their counts
+    pub fn add_execution_metrics(&mut self, snapshot:&MetricsSnapshot)
+    {
+        for proband in self.probands.iter_mut()
+        {
+            if let Some(metrics)=snapshot.probands.get(&proband.proband_name)
+            {
+                proband.num_dropped_consequences=metrics.get_num_dropped_consequences();
+                proband.num_skipped_transcripts=metrics.num_skipped_transcripts;
+            }
+        }
+    }
+    /// ## Summary
+    /// Return the total number of mutations of the cohort along with the number of them that were skipped, i.e. located on transcripts
+    /// missing from the reference, and the number of dropped consequences
+    pub fn get_totals(&self)->(u64,u64,u64)
+    {
+        self.probands.iter().fold((0,0,0),|(num_mutations,num_missing,num_dropped),proband|(num_mutations+proband.num_mutations,
+            num_missing+proband.num_missing_mutations,num_dropped+proband.num_dropped_consequences))
+    }
+}
+/// ## Summary
+/// Compute the statistics of a cohort from its intermediate representations, see CohortStatistics, the gene symbols of the transcripts
+/// are taken from transcript_genes, e.g. as returned by gene_report::get_transcript_genes, transcripts without a gene symbol are not
+/// part of the gene statistics.
+pub fn compute_cohort_statistics(vec_intmaps:&[Map::IntMap], ref_seq:&HashMap<String,String>, transcript_genes:&HashMap<String,String>)->CohortStatistics
+{
+    let sup_types=Constants::SUP_TYPE.iter().map(|mut_type|MutationType::from_str(mut_type).unwrap()).collect::<Vec<MutationType>>();
+    let counts=vec_intmaps.par_iter()
+        .map(|int_map|stat_helper::get_proband_counts(int_map,ref_seq,&sup_types))
+        .collect::<Vec<stat_helper::ProbandCounts>>();
+    let mut transcripts:HashMap<String,TranscriptStatistics>=HashMap::new();
+    let mut genes:HashMap<&str,(HashSet<&str>,u64,u64)>=HashMap::new();
+    let mut consequences=vec![(0,0,0);sup_types.len()];
+    let mut probands=Vec::with_capacity(counts.len());
+    for proband_counts in counts.iter()
+    {
+        let mut carried_genes=HashSet::new();
+        for (transcript,(num_haplotypes,num_mutations)) in proband_counts.transcripts.iter()
+        {
+            let entry=transcripts.entry(transcript.clone()).or_insert_with(||TranscriptStatistics{transcript:transcript.clone(),
+                gene:transcript_genes.get(transcript).cloned(),is_in_reference:ref_seq.contains_key(transcript),num_carriers:0,num_altered_haplotypes:0,num_mutations:0});
+            entry.num_carriers+=1;
+            entry.num_altered_haplotypes+=num_haplotypes;
+            entry.num_mutations+=num_mutations;
+            if let Some(gene)=transcript_genes.get(transcript)
+            {
+                let entry=genes.entry(gene.as_str()).or_default();
+                entry.0.insert(transcript.as_str());
+                entry.2+=num_mutations;
+                if carried_genes.insert(gene.as_str())
+                {
+                    entry.1+=1;
+                }
+            }
+        }
+        for (index,(num_mutations,num_missing)) in proband_counts.consequences.iter().enumerate().filter(|(_,(num_mutations,_))|*num_mutations!=0)
+        {
+            consequences[index].0+=1;
+            consequences[index].1+=num_mutations;
+            consequences[index].2+=num_missing;
+        }
+        probands.push(proband_counts.statistics.clone());
+    }
+    probands.sort_by(|proband1,proband2|proband1.proband_name.cmp(&proband2.proband_name));
+    let mut genes=genes.into_iter()
+        .map(|(gene,(gene_transcripts,num_carriers,num_mutations))|GeneStatistics{gene:gene.to_string(),num_transcripts:gene_transcripts.len() as u64,num_carriers,num_mutations})
+        .collect::<Vec<GeneStatistics>>();
+    genes.sort_by(|gene1,gene2|gene2.num_carriers.cmp(&gene1.num_carriers).then_with(||gene1.gene.cmp(&gene2.gene)));
+    let mut transcripts=transcripts.into_values().collect::<Vec<TranscriptStatistics>>();
+    transcripts.sort_by(|transcript1,transcript2|transcript2.num_carriers.cmp(&transcript1.num_carriers).then_with(||transcript1.transcript.cmp(&transcript2.transcript)));
+    let mut consequences=Constants::SUP_TYPE.iter().zip(consequences)
+        .filter(|(_,(_,num_mutations,_))|*num_mutations!=0)
+        .map(|(consequence,(num_carriers,num_mutations,num_missing_mutations))|ConsequenceStatistics{consequence:consequence.to_string(),num_carriers,num_mutations,num_missing_mutations})
+        .collect::<Vec<ConsequenceStatistics>>();
+    consequences.sort_by(|consequence1,consequence2|consequence2.num_mutations.cmp(&consequence1.num_mutations).then_with(||consequence1.consequence.cmp(&consequence2.consequence)));
+    CohortStatistics{probands,genes,transcripts,consequences}
+}
+
+/// ## Summary
+/// The lower bounds of the bins of the length delta histograms in amino acids, i.e. the length of an altered sequence minus the length of
+/// its reference sequence, the first bin holds every sequence shortened by at least 500 amino acids and the last bin every sequence
+/// lengthened by at least 500 amino acids
+pub const LENGTH_DELTA_BIN_BOUNDS:[i64;11]=[i64::MIN,-499,-99,-49,-9,0,1,10,50,100,500];
+/// ## Summary
+/// Return the labels of the bins of the length delta histograms, e.g. <=-500, -499..-100, 0 or >=500
+/// ## Example
+///```rust
+/// use ppgg::functions::summary::get_delta_bin_labels;
+/// let labels=get_delta_bin_labels();
+/// assert_eq!((labels[0].as_str(),labels[1].as_str(),labels[5].as_str(),labels[10].as_str()),("<=-500","-499..-100","0",">=500"));
+///```
+pub fn get_delta_bin_labels()->Vec<String>
+{
+    LENGTH_DELTA_BIN_BOUNDS.iter().enumerate()
+        .map(|(index,bound)|match LENGTH_DELTA_BIN_BOUNDS.get(index+1)
+        {
+            Some(next_bound) if index==0=>format!("<={}",next_bound-1),
+            Some(next_bound) if *next_bound==bound+1=>bound.to_string(),
+            Some(next_bound)=>format!("{}..{}",bound,next_bound-1),
+            None=>format!(">={}",bound)
+        })
+        .collect()
+}
+/// ## Summary
+/// The length deltas of the altered sequences of a proband or of a transcript, i.e. the number of shortened, unchanged and lengthened 
+/// sequences, the extreme and the summed deltas and a histogram of the deltas, see LENGTH_DELTA_BIN_BOUNDS. Deletions, stop gains and
+/// frameshifts that truncate the protein shorten the sequence, while insertions and stop losses lengthen it.
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct LengthDeltaHistogram
+{
+    pub name:String,
+    pub num_shortened:u64,
+    pub num_unchanged:u64,
+    pub num_lengthened:u64,
+    pub min_delta:Option<i64>,
+    pub max_delta:Option<i64>,
+    pub total_delta:i64,
+    pub histogram:[u64;LENGTH_DELTA_BIN_BOUNDS.len()]
+}
+impl LengthDeltaHistogram
+{
+    /// ## Summary
+    /// Create an empty histogram for a proband or a transcript
+    pub fn new(name:String)->Self
+    {
+        LengthDeltaHistogram{name,..Default::default()}
+    }
+    /// ## Summary
+    /// Add the length delta of an altered sequence to the histogram
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::summary::LengthDeltaHistogram;
+    /// let mut histogram=LengthDeltaHistogram::new("T1".to_string());
+    /// histogram.add(-120);
+    /// histogram.add(0);
+    /// histogram.add(3);
+    /// assert_eq!((histogram.num_shortened,histogram.num_unchanged,histogram.num_lengthened),(1,1,1));
+    /// assert_eq!((histogram.histogram[1],histogram.histogram[5],histogram.histogram[6]),(1,1,1));
+    /// assert_eq!((histogram.min_delta,histogram.max_delta,histogram.get_mean_delta()),(Some(-120),Some(3),-39.0));
+    ///```
+    pub fn add(&mut self, delta:i64)
+    {
+        match delta.cmp(&0)
+        {
+            std::cmp::Ordering::Less=>self.num_shortened+=1,
+            std::cmp::Ordering::Equal=>self.num_unchanged+=1,
+            std::cmp::Ordering::Greater=>self.num_lengthened+=1
+        }
+        self.min_delta=Some(self.min_delta.map_or(delta,|min_delta|std::cmp::min(min_delta,delta)));
+        self.max_delta=Some(self.max_delta.map_or(delta,|max_delta|std::cmp::max(max_delta,delta)));
+        self.total_delta+=delta;
+        let bin=LENGTH_DELTA_BIN_BOUNDS.iter().rposition(|bound|delta>=*bound).unwrap();
+        self.histogram[bin]+=1;
+    }
+    /// ## Summary
+    /// Return the number of sequences in the histogram
+    pub fn get_num_sequences(&self)->u64
+    {
+        self.num_shortened+self.num_unchanged+self.num_lengthened
+    }
+    /// ## Summary
+    /// Return the mean length delta, zero is returned for an empty histogram
+    pub fn get_mean_delta(&self)->f64
+    {
+        match self.get_num_sequences()
+        {
+            0=>0.0,
+            num=>self.total_delta as f64/num as f64
+        }
+    }
+}
+/// ## Summary
+/// The length deltas of a cohort per proband, sorted by the name of the proband, and per transcript, sorted by the number of shortened
+/// sequences, from the most to the least frequently shortened, then by name, hence, transcripts that are systematically truncated, e.g.
+/// because the annotation of the VCF does not match the reference, are listed first
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct LengthDeltas
+{
+    pub probands:Vec<LengthDeltaHistogram>,
+    pub transcripts:Vec<LengthDeltaHistogram>
+}
+/// ## Summary
+/// Compute the length deltas of the altered sequences of the personalized genomes relative to their reference sequences, the length of
+/// a sequence is taken from the annotation of its sequence tape, i.e. before the stop codon policy is applied, and the sequences of
+/// transcripts missing from the reference are not counted
+pub fn compute_length_deltas(genomes:&[PersonalizedGenome], ref_seq:&HashMap<String,String>)->LengthDeltas
+{
+    let deltas_per_proband=genomes.par_iter()
+        .map(|genome|stat_helper::get_length_deltas(genome,ref_seq))
+        .collect::<Vec<(String,Vec<(&str,i64)>)>>();
+    let mut transcripts:HashMap<&str,LengthDeltaHistogram>=HashMap::new();
+    let mut probands=Vec::with_capacity(deltas_per_proband.len());
+    for (proband_name,deltas) in deltas_per_proband.into_iter()
+    {
+        let mut proband=LengthDeltaHistogram::new(proband_name);
+        for (transcript,delta) in deltas.into_iter()
+        {
+            proband.add(delta);
+            transcripts.entry(transcript).or_insert_with(||LengthDeltaHistogram::new(transcript.to_string())).add(delta);
+        }
+        probands.push(proband);
+    }
+    probands.sort_by(|proband1,proband2|proband1.name.cmp(&proband2.name));
+    let mut transcripts=transcripts.into_values().collect::<Vec<LengthDeltaHistogram>>();
+    transcripts.sort_by(|transcript1,transcript2|transcript2.num_shortened.cmp(&transcript1.num_shortened).then_with(||transcript1.name.cmp(&transcript2.name)));
+    LengthDeltas{probands,transcripts}
+}
+
+mod stat_helper
+{
+    use super::*; 
+    pub fn number_mutations_per_proband(int_map:&Map::IntMap)->(String,u64)
+    {
+        let (mut1,mut2)=int_map.get_mutations_ref();
+        let num_mut=mut1.len()+mut2.len(); 
+        (int_map.get_name().clone(),num_mut as u64)
+    }
+
+    pub fn haplotype_spread_per_proband(int_map:&Map::IntMap)->HaplotypeSpread
+    {
+        let (mutations1,mutations2)=int_map.get_mutations_ref(); 
+        let altered1=mutations1.iter().filter(|alt_transcript|!alt_transcript.alts.is_empty()).map(|alt_transcript|alt_transcript.name.as_str()).collect::<HashSet<&str>>(); 
+        let altered2=mutations2.iter().filter(|alt_transcript|!alt_transcript.alts.is_empty()).map(|alt_transcript|alt_transcript.name.as_str()).collect::<HashSet<&str>>(); 
+        let num_both=altered1.intersection(&altered2).count() as u64; 
+        HaplotypeSpread{num_haplotype1_only:altered1.len() as u64-num_both,num_haplotype2_only:altered2.len() as u64-num_both,num_both}
+    }
+
+    pub fn compute_mutation_frequency(int_map:&Map::IntMap)->(String,Vec<u64>)
+    {
+        let counts=Constants::SUP_TYPE.par_iter()
+        .map(|&mut_type|get_count_per_proband(mut_type,int_map))
+        .collect::<Vec<u64>>();        
+        (int_map.proband_name.clone(),counts)
+    }
+
+    fn get_count_per_proband(mut_type:&str, int_map:&Map::IntMap)->u64
+    {
+        let mut sum=0; 
+        let (mut_h1, mut_h2)= int_map.get_mutations_ref();
+        let mut_type=MutationType::from_str(mut_type).unwrap(); 
+        for alt in mut_h1.iter()
+        {
+            for mutation in alt.get_alts().iter()
+            {
+                if mutation.mut_type==mut_type
+                {
+                    sum+=1
+                }
+            }
+        }
+        for alt in mut_h2.iter()
+        {
+            for mutation in alt.get_alts().iter()
+            {
+                if mutation.mut_type==mut_type
+                {
+                    sum+=1
+                }
+            }
+        }
+        sum
+    }
+    /// return the transcripts in the reference whose alterations can not be translated into instructions or tasks along with the error 
+    pub fn get_translation_errors(int_map:&Map::IntMap, ref_seq:&HashMap<String,String>, overlap_policy:Option<OverlapPolicy>, 
+        diagnostic_options:&DiagnosticOptions)->Vec<(String,String)>
+    {
+        let (mut_h1,mut_h2)=int_map.get_mutations_ref();
+        mut_h1.iter().chain(mut_h2.iter())
+        .filter(|alt|ref_seq.contains_key(&alt.name))
+        .filter_map(|alt|
+        {
+            let res=TranscriptInstruction::from_alt_transcript_with_policies(alt.clone(), ref_seq, overlap_policy, diagnostic_options, &mut OverlapSummary::default())
+                        .and_then(|transcript_instruction|transcript_instruction.get_g_rep_with_checks(ref_seq,PLACEHOLDER_RESIDUE,diagnostic_options));
+            match res
+            {
+                Ok(_)=>None,
+                Err(err_msg)=>Some((alt.name.clone(),err_msg.to_string()))
+            }
+        })
+        .collect::<Vec<_>>()
+    }
+    pub fn get_uniuqe_transcript(vec_intmaps:&[Map::IntMap])->Vec<String>
+    {
+        let mut results=vec_intmaps.par_iter()
+        .map(|map|extract_transcript_from_map(map))
+        .flatten()
+        .collect::<Vec<String>>(); 
+        results.sort();
+        results.dedup();
+        results
+    }
+    fn extract_transcript_from_map(intmap:&Map::IntMap)->Vec<String>
+    {
+        let (mut_h1,mut_h2)=intmap.get_mutations_ref();
+        let mut res1=mut_h1.par_iter()
+        .map(|alt|alt.name.clone())
+        .collect::<Vec<String>>(); 
+        let mut res2=mut_h2.par_iter()
+        .map(|alt|alt.name.clone())
+        .collect::<Vec<String>>(); 
+        res1.append(&mut res2); 
+        res1
+    }
+   pub fn count_in_all_individual(transcript_name:String,vec_intmaps:&Vec<Map::IntMap>)->(String,u64)
+   {
+        let results=vec_intmaps.par_iter()
+        .map(|intmap|get_count_in_a_proband(&transcript_name,intmap))
+        .collect::<Vec<u64>>(); 
+    (transcript_name,results.iter().sum())
+   }
+   pub fn normalized_load_per_proband(int_map:&Map::IntMap, ref_seq:&HashMap<String,String>, 
+        num_callable_transcripts:u64, proteome_length:u64)->(String,MutationLoad)
+   {
+        let (mut_h1,mut_h2)=int_map.get_mutations_ref(); 
+        let callable=mut_h1.iter().chain(mut_h2.iter())
+            .filter(|alt|ref_seq.contains_key(&alt.name))
+            .collect::<Vec<_>>(); 
+        let num_edits=callable.iter().map(|alt|alt.get_alts().len() as u64).sum::<u64>(); 
+        let mut altered_transcripts=callable.iter().map(|alt|&alt.name).collect::<Vec<&String>>(); 
+        altered_transcripts.sort(); 
+        altered_transcripts.dedup(); 
+        let edits_per_transcript= match num_callable_transcripts
+        {
+            0=>0.0,
+            _=>num_edits as f64/num_callable_transcripts as f64
+        }; 
+        let edits_per_kb= match proteome_length
+        {
+            0=>0.0,
+            _=>num_edits as f64/(2.0*proteome_length as f64/1000.0)
+        }; 
+        (int_map.get_name().clone(),MutationLoad{num_edits,num_altered_transcripts:altered_transcripts.len() as u64,
+            num_callable_transcripts,proteome_length,edits_per_transcript,edits_per_kb})
+   }
+   /// return the one-based reference positions of the variants of each reference transcript altered in either haplotype of a proband 
+   pub fn get_positions_per_transcript<'a>(int_map:&Map::IntMap, ref_seq:&'a HashMap<String,String>)->HashMap<&'a str,Vec<u32>>
+   {
+        let (mut_h1,mut_h2)=int_map.get_mutations_ref(); 
+        let mut positions:HashMap<&str,Vec<u32>>=HashMap::new(); 
+        for alt in mut_h1.iter().chain(mut_h2.iter()).filter(|alt|!alt.get_alts().is_empty())
+        {
+            if let Some((transcript,_))=ref_seq.get_key_value(&alt.name)
+            {
+                positions.entry(transcript.as_str()).or_default()
+                    .extend(alt.get_alts().iter().map(|mutation|mutation.mut_info.ref_aa_position+1)); 
+            }
+        }
+        positions
+   }
+   /// the counts of a proband used for computing the cohort statistics, i.e. the statistics of the proband, the number of altered haplotypes
+   /// and of mutations per transcript, and the number of mutations along with the number of missing mutations per consequence type,
+   /// indexed as Constants::SUP_TYPE
+   pub struct ProbandCounts
+   {
+        pub statistics:ProbandStatistics,
+        pub transcripts:HashMap<String,(u64,u64)>,
+        pub consequences:Vec<(u64,u64)>
+   }
+   pub fn get_proband_counts(int_map:&Map::IntMap, ref_seq:&HashMap<String,String>, sup_types:&[MutationType])->ProbandCounts
+   {
+        let (mut_h1,mut_h2)=int_map.get_mutations_ref(); 
+        let mut statistics=ProbandStatistics{proband_name:int_map.get_name().clone(),..Default::default()}; 
+        let mut transcripts:HashMap<String,(u64,u64)>=HashMap::new(); 
+        let mut consequences=vec![(0,0);sup_types.len()]; 
+        for alt in mut_h1.iter().chain(mut_h2.iter()).filter(|alt|!alt.get_alts().is_empty())
+        {
+            let is_in_reference=ref_seq.contains_key(&alt.name); 
+            let entry=transcripts.entry(alt.name.clone()).or_insert((0,0)); 
+            entry.0+=1; 
+            entry.1+=alt.get_alts().len() as u64; 
+            statistics.num_mutations+=alt.get_alts().len() as u64; 
+            if !is_in_reference
+            {
+                statistics.num_missing_mutations+=alt.get_alts().len() as u64; 
+            }
+            for index in alt.get_alts().iter().filter_map(|mutation|sup_types.iter().position(|mut_type|*mut_type==mutation.mut_type))
+            {
+                consequences[index].0+=1; 
+                if !is_in_reference
+                {
+                    consequences[index].1+=1; 
+                }
+            }
+        }
+        statistics.num_altered_transcripts=transcripts.len() as u64; 
+        statistics.num_missing_transcripts=transcripts.keys().filter(|transcript|!ref_seq.contains_key(*transcript)).count() as u64; 
+        ProbandCounts{statistics,transcripts,consequences}
+   }
+   /// return the name of a proband along with the transcript and the length delta of each altered sequence of both haplotypes, where
+   /// transcripts missing from the reference are skipped
+   pub fn get_length_deltas<'a>(genome:&PersonalizedGenome, ref_seq:&'a HashMap<String,String>)->(String,Vec<(&'a str,i64)>)
+   {
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes(); 
+        let deltas=seq_tape1.get_annotation().iter().chain(seq_tape2.get_annotation().iter())
+            .filter_map(|(transcript,(start,end))|ref_seq.get_key_value(transcript)
+                .map(|(transcript,seq)|(transcript.as_str(),end.saturating_sub(*start) as i64-seq.len() as i64)))
+            .collect::<Vec<(&str,i64)>>(); 
+        (genome.get_proband_name().clone(),deltas)
+   }
+   fn get_count_in_a_proband(transcript_name:&String, intmap:&Map::IntMap)->u64
+   {
+        let (mut_h1,mut_h2)=intmap.get_mutations_ref(); 
+        let mut sum=0;
+        if mut_h1.iter().any(|alt|alt.name==*transcript_name){sum+=1}
+        if mut_h2.iter().any(|alt|alt.name==*transcript_name){sum+=1}
+        sum
+   }
+}
+#[cfg(test)]
+pub mod test_summary_function
+{
+    use super::*; 
+    use crate::{parts::io::parse_vcf, data_structures::InternalRep::engines::Engine}; 
+    fn generate_default_internal_representation()->Vec<Map::IntMap>
+    {      
+        use std::path::Path; 
+        match parse_vcf(Path::new("/Users/heshamelabd/projects/test_data/test_case_int1.vcf"),Engine::MT,None)
+        {
+            Ok(res)=>res,
+            Err(err_msg)=>panic!("{}",err_msg)
+        }
+    }
+    #[test]
+    fn test_compute_number_mutation_per_proband()
+    {
+        let num_mut_per_pat=compute_number_mutation_per_proband(&generate_default_internal_representation());
+        println!("{:#?}",num_mut_per_pat); 
+
+    }
+    #[test]
+    fn test_compute_type_mutations_per_patient()
+    {
+        let type_mutation_per_proband=compute_type_mutations_per_patient(&generate_default_internal_representation());
+        println!("{:#?}",type_mutation_per_proband); 
+    }
+    #[test]
+    fn test_compute_transcript_coverage()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"M".repeat(300)); 
+        ref_seq.insert("T2".to_string(),"M".repeat(200)); 
+        let alt_1=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|5M>5K|10A>T".to_string()]); 
+        // a frameshift without an altered sequence is not supported and hence T2 is skipped 
+        let alt_2=AltTranscript::new("T2".to_string(),vec!["frameshift|G2|T2|protein_coding|+|5M>5*|10A>T".to_string()]); 
+        let alt_3=AltTranscript::new("T3".to_string(),vec!["missense|G3|T3|protein_coding|+|5M>5K|10A>T".to_string()]); 
+        let int_map=Map::IntMap::new("proband_1".to_string(),vec![alt_1,alt_3],vec![alt_2]); 
+        for engine in [Engine::ST,Engine::MT].iter()
+        {
+            let coverage=compute_transcript_coverage(std::slice::from_ref(&int_map), &ref_seq, engine.clone(), None, &DiagnosticOptions::default()); 
+            assert_eq!(coverage.num_referenced,3); 
+            assert_eq!(coverage.missing,vec!["T3".to_string()]); 
+            assert_eq!(coverage.skipped.iter().map(|(name,_)|name.as_str()).collect::<Vec<_>>(),vec!["T2"]); 
+            assert_eq!(coverage.num_failed(),2); 
+            assert!((coverage.percent_failed()-200.0/3.0).abs()<1e-9); 
+            let report=coverage.to_report(10); 
+            assert!(report.contains("missing from the reference: 1")); 
+            assert!(report.contains("skipped due to errors: 1")); 
+        }
+        assert_eq!(TranscriptCoverage::default().percent_failed(),0.0); 
+    }
+    #[test]
+    fn test_compute_haplotype_spread()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        let alt=|name:&str|AltTranscript::new(name.to_string(),vec![format!("missense|G1|{}|protein_coding|+|5M>5K|10A>T",name)]); 
+        // T4 has no supported mutation and hence it is not counted 
+        let int_map=Map::IntMap::new("proband_1".to_string(),vec![alt("T1"),alt("T2"),AltTranscript::allocate("T4".to_string())],vec![alt("T2"),alt("T3")]); 
+        let spread=compute_haplotype_spread(std::slice::from_ref(&int_map)); 
+        assert_eq!(spread["proband_1"],HaplotypeSpread{num_haplotype1_only:1,num_haplotype2_only:1,num_both:1}); 
+        assert_eq!(spread["proband_1"].num_single_haplotype(),2); 
+    }
+    #[test]
+    fn test_compute_normalized_mutation_load()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"M".repeat(300)); 
+        ref_seq.insert("T2".to_string(),"M".repeat(200)); 
+        let alt_1=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|5M>5K|10A>T".to_string(),
+            "missense|G1|T1|protein_coding|+|9M>9K|22A>T".to_string()]); 
+        let alt_2=AltTranscript::new("T1".to_string(),vec!["missense|G1|T1|protein_coding|+|5M>5K|10A>T".to_string()]); 
+        // T3 is not part of the reference and hence it is not callable 
+        let alt_3=AltTranscript::new("T3".to_string(),vec!["missense|G3|T3|protein_coding|+|5M>5K|10A>T".to_string()]); 
+        let int_map=Map::IntMap::new("proband_1".to_string(),vec![alt_1,alt_3],vec![alt_2]); 
+        let results=compute_normalized_mutation_load(&vec![int_map], &ref_seq); 
+        let load=results.get("proband_1").unwrap(); 
+        assert_eq!(load.num_edits,3); 
+        assert_eq!(load.num_altered_transcripts,1); 
+        assert_eq!(load.num_callable_transcripts,2); 
+        assert_eq!(load.proteome_length,500); 
+        assert_eq!(load.edits_per_transcript,1.5); 
+        assert_eq!(load.edits_per_kb,3.0); 
+    }
+    #[test]
+    fn test_compute_transcript_decoration()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        let mut ref_seq=HashMap::new(); 
+        for transcript in ["T1","T2","T3"].iter()
+        {
+            ref_seq.insert(transcript.to_string(),"M".repeat(100)); 
+        }
+        let alt=|name:&str,positions:&[u32]|AltTranscript::new(name.to_string(),positions.iter()
+            .map(|pos|format!("missense|G1|{}|protein_coding|+|{}M>{}K|10A>T",name,pos,pos)).collect()); 
+        // T4 is not part of the reference, and the variant shared by both haplotypes of proband_1 is a single position 
+        let int_map1=Map::IntMap::new("proband_1".to_string(),vec![alt("T1",&[5,9]),alt("T4",&[2])],vec![alt("T1",&[5])]); 
+        let int_map2=Map::IntMap::new("proband_2".to_string(),vec![alt("T2",&[7])],vec![alt("T1",&[3])]); 
+        let decoration=compute_transcript_decoration(&[int_map1,int_map2], &ref_seq); 
+        assert_eq!(decoration.iter().map(|entry|(entry.transcript.as_str(),entry.num_samples)).collect::<Vec<_>>(),vec![("T1",2),("T2",1),("T3",0)]); 
+        assert_eq!(decoration[0].positions,vec![3,5,9]); 
+        assert_eq!(decoration[0].length,100); 
+        assert!(decoration[2].positions.is_empty()); 
+    }
+    #[test]
+    fn test_compute_cohort_statistics()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        use crate::functions::metrics::ProbandMetrics; 
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"M".repeat(100)); 
+        ref_seq.insert("T2".to_string(),"M".repeat(100)); 
+        let transcript_genes=[("T1","G1"),("T2","G1"),("T3","G3")].iter()
+            .map(|(transcript,gene)|(transcript.to_string(),gene.to_string())).collect::<HashMap<String,String>>(); 
+        let alt=|name:&str,consequences:&[&str]|AltTranscript::new(name.to_string(),consequences.iter()
+            .map(|consequence|format!("{}|G1|{}|protein_coding|+|{}|10A>T",consequence.split(':').next().unwrap(),name,consequence.split(':').nth(1).unwrap())).collect()); 
+        // T3 is not part of the reference, hence, its mutation is counted as missing 
+        let int_map1=Map::IntMap::new("proband_2".to_string(),vec![alt("T1",&["missense:5M>5K","missense:9M>9K"]),alt("T3",&["missense:2M>2K"])],
+            vec![alt("T1",&["missense:5M>5K"])]); 
+        let int_map2=Map::IntMap::new("proband_1".to_string(),vec![alt("T2",&["stop_gained:7M>7*"])],Vec::new()); 
+        let mut statistics=compute_cohort_statistics(&[int_map1,int_map2], &ref_seq, &transcript_genes); 
+        assert_eq!(statistics.probands.iter().map(|proband|proband.proband_name.as_str()).collect::<Vec<_>>(),vec!["proband_1","proband_2"]); 
+        let proband=&statistics.probands[1]; 
+        assert_eq!((proband.num_altered_transcripts,proband.num_mutations,proband.num_missing_transcripts,proband.num_missing_mutations),(2,4,1,1)); 
+        assert_eq!(statistics.genes,vec![GeneStatistics{gene:"G1".to_string(),num_transcripts:2,num_carriers:2,num_mutations:4},
+            GeneStatistics{gene:"G3".to_string(),num_transcripts:1,num_carriers:1,num_mutations:1}]); 
+        assert_eq!((statistics.transcripts[0].transcript.as_str(),statistics.transcripts[0].num_altered_haplotypes,statistics.transcripts[0].num_mutations),("T1",2,3)); 
+        assert!(!statistics.transcripts.iter().find(|transcript|transcript.transcript=="T3").unwrap().is_in_reference); 
+        assert_eq!(statistics.consequences,vec![ConsequenceStatistics{consequence:"missense".to_string(),num_carriers:1,num_mutations:4,num_missing_mutations:1},
+            ConsequenceStatistics{consequence:"stop_gained".to_string(),num_carriers:1,num_mutations:1,num_missing_mutations:0}]); 
+        let mut snapshot=MetricsSnapshot::default(); 
+        snapshot.probands.insert("proband_1".to_string(),ProbandMetrics{num_decoded_consequences:3,num_parsed_mutations:1,num_skipped_transcripts:1,..Default::default()}); 
+        statistics.add_execution_metrics(&snapshot); 
+        assert_eq!((statistics.probands[0].num_dropped_consequences,statistics.probands[0].num_skipped_transcripts),(2,1)); 
+        assert_eq!(statistics.get_totals(),(5,1,2)); 
+    }
+    #[test]
+    fn test_compute_length_deltas()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript; 
+        use crate::data_structures::InternalRep::proband_instructions::ProbandInstruction; 
+        use crate::data_structures::InternalRep::gir::PlaceholderPolicy;
+        let mut ref_seq=HashMap::new(); 
+        ref_seq.insert("T1".to_string(),"MKLVTAMKLV".to_string()); 
+        ref_seq.insert("T2".to_string(),"MKLVT".to_string()); 
+        let alt=|name:&str,consequence:&str|AltTranscript::new(name.to_string(),vec![format!("{}|G1|{}|protein_coding|+|{}|10A>T",
+            consequence.split(':').next().unwrap(),name,consequence.split(':').nth(1).unwrap())]); 
+        // the stop gained truncates T1 after its fourth residue, while the missense keeps the length of T2 
+        let int_maps=vec![Map::IntMap::new("proband_2".to_string(),vec![alt("T1","stop_gained:5T>5*")],vec![alt("T2","missense:2K>2N")]),
+            Map::IntMap::new("proband_1".to_string(),vec![alt("T1","stop_gained:5T>5*")],vec![alt("T3","missense:2K>2N")])]; 
+        let genomes=int_maps.into_iter()
+            .map(|int_map|PersonalizedGenome::from_proband_instruction(ProbandInstruction::from_intmap(int_map,Engine::ST,&ref_seq),Engine::ST,&ref_seq,&PlaceholderPolicy::default(),&DiagnosticOptions::default()))
+            .collect::<Vec<PersonalizedGenome>>(); 
+        let deltas=compute_length_deltas(&genomes, &ref_seq); 
+        assert_eq!(deltas.probands.iter().map(|proband|(proband.name.as_str(),proband.get_num_sequences())).collect::<Vec<_>>(),vec![("proband_1",1),("proband_2",2)]); 
+        assert_eq!(deltas.transcripts.iter().map(|transcript|transcript.name.as_str()).collect::<Vec<_>>(),vec!["T1","T2"]); 
+        let delta=deltas.transcripts[0].min_delta.unwrap(); 
+        assert!(delta<0 && deltas.transcripts[0].max_delta==Some(delta)); 
+        assert_eq!((deltas.transcripts[0].num_shortened,deltas.transcripts[1].num_unchanged),(2,1)); 
+    }
+    #[test]
+    fn test_number_of_mutations_per_transcript()
+    {
+        let num_mut_per_transcript=compute_number_of_mutations_per_transcript(&generate_default_internal_representation());
+        println!("{:#?}",num_mut_per_transcript); 
+    }
+}
\ No newline at end of file
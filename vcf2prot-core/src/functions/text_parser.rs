@@ -1,4 +1,5 @@
-use crate::data_structures::mutation_ds::MutationInfo; 
+use std::sync::Arc;
+use crate::data_structures::mutation_ds::{Mutation,MutationInfo}; 
 use crate::data_structures::Constants; 
 /// The function takes the consequence string and returned a Result enum either containing an Ok or Err type.
 /// # Ok
@@ -19,38 +20,38 @@ use crate::data_structures::Constants;
 /// }
 ///```
 pub fn split_csq_string(input_string:&String)->Result<Vec<String>,String>
+{
+    split_csq_str(input_string).map(|fields|fields.iter().map(|field|field.to_string()).collect::<Vec<String>>())
+}
+/// Similar to split_csq_string, however, the three fields are borrowed from the consequence string instead of being copied into new strings,
+/// the start_lost field is the only exception as its amino acid field is missing from the consequence string and is set to 1M>1*. 
+///``` 
+/// use ppgg::functions::text_parser::split_csq_str;
+/// let fields=split_csq_str("stop_gained|RABGEF1|ENST00000484547|NMD|+|32Q>32*|66771993C>T").unwrap();
+/// assert_eq!(fields,["stop_gained","ENST00000484547","32Q>32*"]);
+///```
+pub fn split_csq_str(input_string:&str)->Result<[&str;3],String>
 {
     let num_match=input_string.matches('|').count();
-    let res = input_string.split('|').map(|elem| elem.into()).collect::<Vec<String>>(); 
+    let res = input_string.split('|').collect::<Vec<&str>>(); 
     match num_match
     {
         6=>
         {
-            match res[3].as_str()
+            match res[3]
             {
-                "protein_coding" | "NMD"=>
-                {
-                    let index:Vec<usize>=vec![0,2,5];
-                    Ok(index.iter().map(|i| res[*i].clone()).collect::<Vec<String>>())
-                },
+                "protein_coding" | "NMD"=>Ok([res[0],res[2],res[5]]),
                 _=>Err("Skipping this transcript as it is not a protein coding transcript".to_string())
             }
         }, 
         _=>
         {
-            match res[0].as_str()
+            match (res[0],res.get(2))
             {
-                "start_lost"=>
-                {
-                    let mut results=Vec::with_capacity(3); 
-                    results.push(res[0].clone());
-                    results.push(res[2].clone());
-                    results.push("1M>1*".to_string()); 
-                    Ok(results)
-                },
+                ("start_lost",Some(transcript))=>Ok([res[0],transcript,"1M>1*"]),
                 _=>
                 {
-                    println!("In correct number of fields, expected 6, received {} and the input string is: {}, skipping this mutation ...",num_match,input_string); 
+                    log::warn!("In correct number of fields, expected 6, received {} and the input string is: {}, skipping this mutation ...",num_match,input_string); 
                     Err(format!("In correct number of fields, expected 6, received {} and the input string is: {}",num_match,input_string))
                 }
             }
@@ -80,7 +81,7 @@ pub fn split_csq_string(input_string:&String)->Result<Vec<String>,String>
 /// assert_eq!(MutatedString::NotSeq,res.mut_aa); 
 /// assert_eq!(MutatedString::Sequence("Q".to_string()),res.ref_aa);
 ///```
-pub fn parse_amino_acid_field(input_string: &String)->Result<MutationInfo,String>
+pub fn parse_amino_acid_field(input_string: &str)->Result<MutationInfo,String>
 {
     // split the field into two amino acids 
     let parsed_strings=input_string.split('>').collect::<Vec<&str>>();
@@ -106,10 +107,33 @@ pub fn parse_amino_acid_field(input_string: &String)->Result<MutationInfo,String
             return Err(format!("\n while extracting the sequence and the position of the mutation the following error was encounterred {}",err_msg));
         }
     };
+    // positions are one-based, a position of zero can not be converted into a zero-based position 
+    if ref_pos==0 || mut_pos==0
+    {
+        return Err(format!("The parsed string: {} contains a position of zero, while amino acid positions are one-based",input_string));
+    }
     Ok(MutationInfo::new(ref_pos,mut_pos,ref_seq,mut_seq))
 }
+/// ## Summary
+/// The hardened entry point of the consequence parser, the consequence string, e.g. "missense|BRAF|ENST00000646891|protein_coding|-|640V>640E|140753336A>T",
+/// is split using split_csq_str and parsed into a mutation, an error is returned for any malformed or unsupported consequence, i.e. the 
+/// function does not panic on arbitrary input, which is checked by the fuzz target in fuzz/fuzz_targets/csq_parser.rs.  
+/// ## Example
+///```rust
+/// use ppgg::functions::text_parser::try_parse_consequence;
+/// let mutation=try_parse_consequence("missense|BRAF|ENST00000646891|protein_coding|-|640V>640E|140753336A>T").unwrap();
+/// assert_eq!(&*mutation.transcript_name,"ENST00000646891");
+/// assert_eq!(mutation.mut_info.ref_aa_position,639);
+/// assert!(try_parse_consequence("missense|BRAF|ENST00000646891|protein_coding|-|0V>0E|140753336A>T").is_err());
+/// assert!(try_parse_consequence("start_lost|").is_err());
+///```
+pub fn try_parse_consequence(csq:&str)->Result<Mutation,String>
+{
+    let [mut_type,transcript_name,aa_field]=split_csq_str(csq)?;
+    Mutation::from_csq_fields(mut_type, Arc::from(transcript_name), aa_field)
+}
 /// The function takes an input string composite of an aminoacid position concatinated with a stirng object ,e.g 35KTEST and returns 
-/// the amino acid position as u16 int, in this case it 35, and the string containg the mutation, here it is KTEST.
+/// the amino acid position as u32 int, in this case it 35, and the string containg the mutation, here it is KTEST.
 /// ## Ok
 /// a tuple containg the amino acid position as an int and the sequence as a stirng,
 /// ## Errors 
@@ -124,15 +148,14 @@ pub fn parse_amino_acid_field(input_string: &String)->Result<MutationInfo,String
 ///       Err(seq)=>()
 /// }
 ///```
-pub fn parse_amino_acid_seq_position(input_seq: &str)->Result<(u16,String),String>
+pub fn parse_amino_acid_seq_position(input_seq: &str)->Result<(u32,String),String>
 {
     if input_seq.matches('-').count() !=0
     {
         return Err(format!("Input string: {} is invalid, it contains a '-' sign which is not valid for indexing amino acid positions, also it is not avalid amino acid",input_seq));
     }
-    let input_as_vec=input_seq.chars().collect::<Vec<char>>();// split the input string into a vector of chars, for example, 32Q -> 3,2,Q;
     let nums=['0','1','2','3','4','5','6','7','8','9']; // valid numbers 
-    let position = match input_as_vec.iter().clone().filter(|c|  nums.contains(&c)).collect::<String>().parse() // extract the numbers from the stream 
+    let position = match input_seq.chars().filter(|c|  nums.contains(c)).collect::<String>().parse() // extract the numbers from the stream, for example, 32Q -> 32
     {
         Ok(num)=>num,
         Err(err_msg)=>
@@ -140,7 +163,7 @@ pub fn parse_amino_acid_seq_position(input_seq: &str)->Result<(u16,String),Strin
             return Err(format!("Parsing the input sequence {}, failed with the following error message {}",input_seq,err_msg ));
         }
     };
-    let mut sequence = input_as_vec.iter().clone().filter(|c| !nums.contains(&c)).collect::<String>();
+    let mut sequence = input_seq.chars().filter(|c| !nums.contains(c)).collect::<String>();
     if sequence.is_empty()
     {
         sequence="*".to_string(); 
@@ -160,17 +183,79 @@ pub fn parse_amino_acid_seq_position(input_seq: &str)->Result<(u16,String),Strin
 /// let mut results=text_parser::get_bit_mask(&test_case.to_string());
 /// assert_eq!(results,"10922,14"); 
 ///```
-pub fn get_bit_mask(input_string:&String)->String
+pub fn get_bit_mask(input_string:&str)->String
 {
     // check there is at least one semicolon in the patient fields  
     if input_string.matches(":").count()==0
     {
         return Constants::DEF_CONSEQ.to_string();
     }
+    // the bitmask is the last field of the patient string 
+    get_bit_mask_at(input_string, input_string.matches(":").count())
+}
+/// Similar to get_bit_mask, however, an error is returned instead of panicking if the bitmask is invalid, i.e. negative, which is 
+/// generated by outdated versions of csq. 
+/// # Example
+///``` 
+/// use ppgg::functions::text_parser::try_get_bit_mask;
+/// assert_eq!(try_get_bit_mask("0|1:10922,14,0,0,0").unwrap(),"10922,14"); 
+/// assert!(try_get_bit_mask("0|1:-3").is_err()); 
+///```
+pub fn try_get_bit_mask(input_string:&str)->Result<String,String>
+{
+    // check there is at least one semicolon in the patient fields  
+    if input_string.matches(":").count()==0
+    {
+        return Ok(Constants::DEF_CONSEQ.to_string());
+    }
+    // the bitmask is the last field of the patient string 
+    try_get_bit_mask_at(input_string, input_string.matches(":").count())
+}
+/// takes an input patient field along with the index of the BCSQ field in the record FORMAT layout and extract the bitmask from it,
+/// this is used when the FORMAT layout changes across records, for example, in merged VCF files, and the bitmask is not guaranteed 
+/// to be the last field. It returns "" an empty string, representing the reference, if the field is missing from the patient string.  
+/// # Example
+///``` 
+/// use ppgg::functions::text_parser;
+/// let mut test_case="0|1:10922:37"; 
+/// let mut results=text_parser::get_bit_mask_at(test_case,1);
+/// assert_eq!(results,"10922$"); 
+/// test_case="0|1";
+/// let mut results=text_parser::get_bit_mask_at(test_case,1);
+/// assert_eq!(results,""); 
+///```
+pub fn get_bit_mask_at(input_string:&str, bcsq_index:usize)->String
+{
+    match try_get_bit_mask_at(input_string, bcsq_index)
+    {
+        Ok(bitmask)=>bitmask,
+        Err(err_msg)=>panic!("{}",err_msg)
+    }
+}
+/// Similar to get_bit_mask_at, however, an error is returned instead of panicking if the bitmask is invalid, i.e. negative. 
+pub fn try_get_bit_mask_at(input_string:&str, bcsq_index:usize)->Result<String,String>
+{
     // define the bitmask field 
-    let bitmask_field=input_string.split(":")
-                        .collect::<Vec<&str>>()[input_string.matches(":").count()]// gets the las field of the patient string 
-                        .to_string();
+    let bitmask_field=match input_string.split(':').nth(bcsq_index)
+    {
+        Some(field)=>field.to_string(),
+        None=>return Ok(Constants::DEF_CONSEQ.to_string())
+    };
+    // negative bitmasks are generated by outdated versions of csq, they are rejected before parse_fields and remove_leading_zeros panic on them 
+    let is_negative=match bitmask_field.contains(',')
+    {
+        true=>bitmask_field.contains('-'),
+        false=>matches!(bitmask_field.parse::<i32>(),Ok(value) if value < 0)
+    };
+    if is_negative
+    {
+        return Err(format!("An invalid bit mask was encountered: {} .  Most likely an outdated version of csq has been used. Check this commit @ Github for more details: https://github.com/samtools/bcftools/commit/1f1e7667ffc1235f31a82e2093f037338acbb4e7",bitmask_field));
+    }
+    Ok(decode_bit_mask_field(bitmask_field))
+}
+/// Trim and normalize a bitmask field that does not contain a negative number, see get_bit_mask_at 
+fn decode_bit_mask_field(bitmask_field:String)->String
+{
     // get the strings 
     if bitmask_field==".".to_string()
     {
@@ -381,7 +466,7 @@ mod test_text_parser
         {
             Ok((pos,seq))=>
             {
-                assert_eq!(pos,32u16);
+                assert_eq!(pos,32u32);
                 assert_eq!(seq,"Q".to_string());
             }
             Err(err_msg)=>
@@ -396,7 +481,7 @@ mod test_text_parser
         let amino_acids_chars="ABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect::<Vec<char>>();
         for i in 0..100 // simulate different amino acids senario
         {
-            let pos:u16=i;
+            let pos:u32=i;
             for j in 1..24 // simulate postential length
             {
                 let random_seq=amino_acids_chars.iter().take(j).collect::<String>(); // get a test sequence
@@ -418,6 +503,18 @@ mod test_text_parser
         }
     }
     #[test]
+    fn test_parse_amino_acid_position_beyond_u16()
+    {
+        // titin has more than 34,000 amino acids, while positions in larger proteins exceed 65,535 
+        let (pos,seq)=parse_amino_acid_seq_position("70000Q").unwrap();
+        assert_eq!(pos,70000);
+        assert_eq!(seq,"Q".to_string());
+        let res=parse_amino_acid_field("65536Q>65537KT").unwrap();
+        assert_eq!(res.ref_aa_position,65535);
+        assert_eq!(res.mut_aa_position,65536);
+        assert_eq!(res.get_ref_pos().get(),65535);
+    }
+    #[test]
     fn test_parse_amino_acid_seq_position_bad_input_1()->Result<(),String>
     {
         let test_case="Test"; // here test case should fail because there is no position  
@@ -479,7 +576,7 @@ mod test_text_parser
         {
             Ok((pos,seq))=>
             {
-                assert_eq!(pos,32u16);
+                assert_eq!(pos,32u32);
                 assert_eq!(seq,"*".to_string());
             }
             Err(err_msg)=>
@@ -496,7 +593,7 @@ mod test_text_parser
         {
             Ok((pos,seq))=>
             {
-                assert_eq!(pos,32u16);
+                assert_eq!(pos,32u32);
                 assert_eq!(seq,"KMNOPQQQ*".to_string());
             }
             Err(err_msg)=>
@@ -554,73 +651,106 @@ mod test_text_parser
     fn test_get_bit_mask1()
     {
         let test_case="0|0"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"");
     }
     #[test]
     fn test_get_bit_mask2()
     {
         let test_case="0|0:.:79,0:79:99:.:.:.:0"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"0$");
     }
     #[test]
     fn test_get_bit_mask3()
     {
         let test_case="0|0:.:37,0:37:72:.:.:.:0"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"0$");
     }
     #[test]
     fn test_get_bit_mask4()
     {
         let test_case="0|0:0"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"0$");
     }
     #[test]
     fn test_get_bit_mask5()
     {
         let test_case="0|1:0.541667:26,22:48:PASS:99:577,0,683:..:0.3336:2"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"2$");
     }
     #[test]
     fn test_get_bit_mask6()
     {
         let test_case="0|1:10"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"10$");
     } 
     #[test]
     fn test_get_bit_mask7()
     {
         let test_case="0|1:0.432432:16,21:37:PASS:99:634,0,417:..:0.1989:10922"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"10922$"); 
     }
     #[test]
     fn test_get_bit_mask8()
     {
         let test_case="1|1:.:4,87:91:99:3000,249,0:..:0.4777:15"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"15$"); 
     }
     #[test]
     fn test_get_bit_mask9()
     {
         let test_case="1|1:.:4,87:91:99:3000,249,0:..:0.4777:15,32,14"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"15,32,14"); 
     }
     #[test]
     fn test_get_bit_mask10()
     {
         let test_case="1|1:.:4,87:91:99:3000,249,0:..:0.4777:15,32,14,0,0,0"; 
-        let results=get_bit_mask(&test_case.to_string());
+        let results=get_bit_mask(test_case);
         assert_eq!(results,"15,32,14"); 
     }
     #[test]
+    fn test_get_bit_mask_at()
+    {
+        let test_case="0|1:10922:37"; 
+        assert_eq!(get_bit_mask_at(test_case,1),"10922$"); 
+        assert_eq!(get_bit_mask_at(test_case,3),""); 
+        assert_eq!(get_bit_mask_at("0|1:.:15,32,14,0",2),"15,32,14"); 
+    }
+    #[test]
+    fn test_malformed_consequences_are_errors()
+    {
+        assert!(split_csq_str("start_lost|").is_err());
+        assert!(parse_amino_acid_field("0V>0E").is_err());
+        assert!(try_get_bit_mask("0|1:15,-32,0").is_err());
+        assert_eq!(try_get_bit_mask("0|1:1-2").unwrap(),"");
+    }
+    #[test]
+    /// Run the parsers on the shipped fuzz corpus, which is checked without cargo fuzz so the corpus stays panic-free
+    fn test_fuzz_corpus_does_not_panic()
+    {
+        let corpus=std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus/csq_parser");
+        let mut num_inputs=0;
+        for entry in std::fs::read_dir(&corpus).unwrap()
+        {
+            let input=String::from_utf8_lossy(&std::fs::read(entry.unwrap().path()).unwrap()).to_string();
+            let _=split_csq_string(&input);
+            let _=parse_amino_acid_field(&input);
+            let _=try_get_bit_mask(&input);
+            let _=try_parse_consequence(&input);
+            num_inputs+=1;
+        }
+        assert!(num_inputs!=0);
+    }
+    #[test]
     fn test_get_types()
     {
         let test_case="*missense|ITPRID1|ENST00000409210|protein_coding|+|717C>717Y|31643796G>A".to_string(); 
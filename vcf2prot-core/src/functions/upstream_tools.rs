@@ -0,0 +1,144 @@
+/// The module detects the upstream tools that produced a VCF file, i.e. the ##<tool>Version and ##<tool>Command lines bcftools adds to the
+/// header, and diagnoses outdated versions of bcftools/csq before the records are parsed, as these encode invalid, i.e. negative, bit-masks
+/// that otherwise only surface while the genotypes are decoded
+use std::fmt::Write;
+
+/// ## Summary
+/// The bcftools commit that fixed the encoding of the bit-masks, referenced by the errors raised on invalid bit-masks
+pub const CSQ_BITMASK_FIX_COMMIT:&str="https://github.com/samtools/bcftools/commit/1f1e7667ffc1235f31a82e2093f037338acbb4e7";
+
+/// ## Summary
+/// The first bcftools release, as major and minor version, whose csq command encodes the bit-masks as unsigned integers
+pub const MIN_CSQ_RELEASE:(u32,u32)=(1,13);
+
+/// ## Summary
+/// The default value of the --ncsq parameter of bcftools csq, with up to 15 consequences per haplotype, the 30 bits of a bit-mask never
+/// reach the sign bit, hence, outdated versions only produce invalid bit-masks with a larger --ncsq
+pub const DEFAULT_NCSQ:usize=15;
+
+/// ## Summary
+/// An upstream tool recorded in the header of a VCF file, i.e. its name, e.g. bcftools_csq, its version string, e.g. 1.9+htslib-1.9, and
+/// the command it was run with, if recorded
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct UpstreamTool
+{
+    pub name:String,
+    pub version:String,
+    pub command:Option<String>
+}
+impl UpstreamTool
+{
+    /// ## Summary
+    /// Return the release of the tool as major and minor version, e.g. (1,9) for 1.9+htslib-1.9, None is returned if the version string
+    /// does not start with a release, e.g. for development builds without a tag
+    pub fn get_release(&self)->Option<(u32,u32)>
+    {
+        let release=self.version.split(|c:char|!(c.is_ascii_digit() || c=='.')).next()?;
+        let mut fields=release.split('.');
+        let major=fields.next()?.parse::<u32>().ok()?;
+        let minor=fields.next().and_then(|minor|minor.parse::<u32>().ok()).unwrap_or(0);
+        Some((major,minor))
+    }
+    /// ## Summary
+    /// Return the maximum number of consequences per haplotype of a csq command, i.e. the value of -n or --ncsq, or DEFAULT_NCSQ if it is
+    /// not part of the command, None is returned if the command was not recorded
+    pub fn get_ncsq(&self)->Option<usize>
+    {
+        let command=self.command.as_ref()?;
+        let mut args=command.split_whitespace();
+        while let Some(arg)=args.next()
+        {
+            let value=match (arg,arg.strip_prefix("--ncsq="))
+            {
+                ("-n",_) | ("--ncsq",_)=>args.next(),
+                (_,Some(value))=>Some(value),
+                _=>None
+            };
+            if let Some(ncsq)=value.and_then(|value|value.parse::<usize>().ok())
+            {
+                return Some(ncsq)
+            }
+        }
+        Some(DEFAULT_NCSQ)
+    }
+}
+
+/// ## Summary
+/// Return the upstream tools recorded in the header of a VCF file, in the order of their version lines, both the name separators used by
+/// bcftools over the releases are supported, i.e. ##bcftools/csqVersion and ##bcftools_csqVersion, which are reported as bcftools_csq.
+/// The command of a tool is the last command line recorded for it, as the header keeps the lines of every run.
+pub fn detect_upstream_tools(header:&[String])->Vec<UpstreamTool>
+{
+    let mut tools:Vec<UpstreamTool>=Vec::new();
+    for line in header.iter().take_while(|line|line.starts_with("##"))
+    {
+        let (key,value)=match line[2..].split_once('=')
+        {
+            Some(res)=>res,
+            None=>continue
+        };
+        let value=value.trim_end().to_string();
+        if let Some(name)=key.strip_suffix("Version")
+        {
+            let name=name.replace('/',"_");
+            match tools.iter_mut().find(|tool|tool.name==name)
+            {
+                Some(tool)=>tool.version=value,
+                None=>tools.push(UpstreamTool{name,version:value,command:None})
+            }
+        }
+        else if let Some(name)=key.strip_suffix("Command")
+        {
+            let name=name.replace('/',"_");
+            if let Some(tool)=tools.iter_mut().find(|tool|tool.name==name)
+            {
+                tool.command=Some(value);
+            }
+        }
+    }
+    tools
+}
+
+/// ## Summary
+/// Return a warning if the VCF file was annotated by a bcftools/csq release older than MIN_CSQ_RELEASE with more than DEFAULT_NCSQ
+/// consequences per haplotype, which is the setting where outdated releases overflow the bit-masks into negative numbers, None is returned
+/// otherwise, including files without a csq version line
+pub fn diagnose_csq_version(tools:&[UpstreamTool])->Option<String>
+{
+    let csq=tools.iter().find(|tool|tool.name=="bcftools_csq")?;
+    let release=csq.get_release()?;
+    let ncsq=csq.get_ncsq().unwrap_or(DEFAULT_NCSQ);
+    if release>=MIN_CSQ_RELEASE || ncsq<=DEFAULT_NCSQ
+    {
+        return None
+    }
+    let mut warning=String::new();
+    write!(warning,"The VCF file was annotated with bcftools/csq version {} using {} consequences per haplotype, releases older than {}.{} \
+        encode the bit-masks of more than {} consequences as negative numbers, which are rejected as invalid bit-masks while the genotypes are \
+        decoded, see: {}. Re-annotate the file using bcftools csq {}.{} or later, or with -n {} or less.",csq.version,ncsq,MIN_CSQ_RELEASE.0,
+        MIN_CSQ_RELEASE.1,DEFAULT_NCSQ,CSQ_BITMASK_FIX_COMMIT,MIN_CSQ_RELEASE.0,MIN_CSQ_RELEASE.1,DEFAULT_NCSQ).unwrap();
+    Some(warning)
+}
+
+#[cfg(test)]
+mod test_upstream_tools
+{
+    use super::*;
+    #[test]
+    fn test_diagnose_csq_version()
+    {
+        let header=["##fileformat=VCFv4.2","##bcftools_viewVersion=1.9+htslib-1.9","##bcftools_viewCommand=view -c1 -O v in.vcf",
+            "##bcftools/csqVersion=1.9+htslib-1.9","##bcftools/csqCommand=csq -g a.gff3 -f a.fa -O v -o out.vcf -n 64 in.vcf",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1"].iter().map(|line|line.to_string()).collect::<Vec<String>>();
+        let mut tools=detect_upstream_tools(&header);
+        assert_eq!(tools.iter().map(|tool|tool.name.as_str()).collect::<Vec<&str>>(),vec!["bcftools_view","bcftools_csq"]);
+        assert_eq!((tools[1].get_release(),tools[1].get_ncsq()),(Some((1,9)),Some(64)));
+        assert!(diagnose_csq_version(&tools).unwrap().contains(CSQ_BITMASK_FIX_COMMIT));
+        // the default number of consequences never overflows, and recent releases are fixed
+        tools[1].command=Some("csq -g a.gff3 -f a.fa in.vcf".to_string());
+        assert!(diagnose_csq_version(&tools).is_none());
+        tools[1]=UpstreamTool{name:"bcftools_csq".to_string(),version:"1.15.1+htslib-1.15.1".to_string(),command:Some("csq --ncsq=64 in.vcf".to_string())};
+        assert_eq!(tools[1].get_ncsq(),Some(64));
+        assert!(diagnose_csq_version(&tools).is_none());
+    }
+}
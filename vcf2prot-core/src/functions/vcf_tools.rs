@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::data_structures::{InternalRep::engines::Engine, Map::{EarlyMap, IntMap}, mutation_ds::Mutation, vcf_ds::{AltTranscript, Probands, VCFRecords}}; 
+use crate::functions::text_parser; 
+use crate::data_structures::consequence_index::ConsequenceIndex; 
+use rayon::prelude::*;
+
+
+/// ## Summary 
+/// create a vector of early maps on parallele using probands name and a mutable a collection 
+/// of VCF records 
+pub fn get_early_map(probands:Probands, mut records:VCFRecords, engine:Engine)->Vec<EarlyMap>
+{
+    // the consequence vector per patient 
+    let mutation_per_proband=records.get_csq_per_patient(probands.get_num_probands(),engine.clone()); 
+
+    (probands.get_probands(),mutation_per_proband).into_par_iter()
+    .map(|(proband,(vec_mut_one,vec_mut_two))|{
+        EarlyMap::new(proband,vec_mut_one,vec_mut_two)})
+    .collect::<Vec<EarlyMap>>()
+}
+/// ## Summary 
+/// create a vector of early maps for a subset of the probands, only the columns of the selected probands are parsed from each record,
+/// see Probands::select, an error is returned if a sample is not part of the provided probands 
+pub fn get_early_map_for_samples(probands:Probands, records:VCFRecords, samples:&[String], engine:Engine)->Result<Vec<EarlyMap>,String>
+{
+    let (selected,columns)=probands.select(samples)?; 
+    let mutation_per_proband=records.get_csq_per_selected_patient(&columns,engine.clone()); 
+    Ok((selected.get_probands(),mutation_per_proband).into_par_iter()
+    .map(|(proband,(vec_mut_one,vec_mut_two))|{
+        EarlyMap::new(proband,vec_mut_one,vec_mut_two)})
+    .collect::<Vec<EarlyMap>>())
+}
+/// ## Summary 
+/// A summary of decoding the records into the intermediate representations, i.e. the number of unphased heterozygous calls the 
+/// UnphasedPolicy of the records was applied to, see VCFRecords::decode_indices, and the number of supported consequences decoded for 
+/// each proband, which are recorded with the parsing metrics of the proband, see metrics::MetricsAccumulator::record_parse 
+#[derive(Debug,Clone,Default,PartialEq,Eq)]
+pub struct DecodingSummary
+{
+    pub num_unphased_calls:u64,
+    pub num_decoded_consequences:HashMap<String,u64>
+}
+impl DecodingSummary
+{
+    /// ## Summary 
+    /// Add the summary of another part of the same file, e.g. a chunk of a streamed file 
+    pub fn merge(&mut self, other:DecodingSummary)
+    {
+        self.num_unphased_calls+=other.num_unphased_calls; 
+        for (proband_name,num_decoded) in other.num_decoded_consequences
+        {
+            *self.num_decoded_consequences.entry(proband_name).or_insert(0)+=num_decoded; 
+        }
+    }
+    /// ## Summary 
+    /// Return the number of supported consequences decoded for a proband, zero if the proband was not decoded 
+    pub fn get_num_decoded(&self, proband_name:&str)->u64
+    {
+        self.num_decoded_consequences.get(proband_name).copied().unwrap_or(0)
+    }
+}
+/// ## Summary 
+/// Create the intermediate representation of each proband directly from the records, i.e. without the early maps, the consequences of each 
+/// record are parsed once and routed to their transcripts, see ConsequenceIndex, and the bit-mask of each proband is decoded into the 
+/// indices of its consequences, hence, a consequence carried by many probands is not copied and parsed again for each of them. 
+/// The representations are identical to the ones built with get_early_map and early_to_intermediate_repr and are returned along with the
+/// summary of the decoding. 
+pub fn get_int_maps(probands:Probands, mut records:VCFRecords, engine:Engine)->(Vec<IntMap>,DecodingSummary)
+{
+    let num_probands=probands.get_num_probands(); 
+    let probands_table=records.get_patient_fields(num_probands,engine.clone()); 
+    build_routed_int_maps(probands.get_probands(), &records, probands_table, (0..num_probands).collect(), engine)
+}
+/// ## Summary 
+/// Similar to get_int_maps, however, only the columns of the selected probands are parsed, see get_early_map_for_samples, an error is 
+/// returned if a sample is not part of the provided probands 
+pub fn get_int_maps_for_samples(probands:Probands, records:VCFRecords, samples:&[String], engine:Engine)->Result<(Vec<IntMap>,DecodingSummary),String>
+{
+    let (selected,columns)=probands.select(samples)?; 
+    let probands_table=records.get_selected_patient_fields(&columns,engine.clone()); 
+    Ok(build_routed_int_maps(selected.get_probands(), &records, probands_table, columns, engine))
+}
+/// ## Summary 
+/// Build the intermediate representations of the probands from their fields, where columns contain the index of each proband among the 
+/// probands of the file, which is used to report decoding errors 
+fn build_routed_int_maps(proband_names:Vec<String>, records:&VCFRecords, probands_table:Vec<Vec<String>>, columns:Vec<usize>, engine:Engine)->(Vec<IntMap>,DecodingSummary)
+{
+    let index=ConsequenceIndex::new(&records.get_consequences_vector(engine.clone()), engine.clone()); 
+    let num_consequences=(0..records.get_records().len()).map(|record_index|index.get_num_consequences(record_index)).collect::<Vec<usize>>(); 
+    let bcsq_indices=records.get_bcsq_indices(); 
+    let decompositions=records.get_allele_decompositions(); 
+    let loci=records.get_loci(); 
+    let decoding_options=records.get_decoding_options(); 
+    let build_int_map=|((proband_name,donor),sample_index):((String,Vec<String>),usize)|
+    {
+        let (haplotype1,haplotype2,num_unphased_calls)=VCFRecords::decode_indices(&num_consequences,&donor,&bcsq_indices,&decompositions,&loci,
+            sample_index,*decoding_options,engine.clone()); 
+        let num_decoded=haplotype1.iter().chain(haplotype2.iter())
+            .map(|(record_index,indices)|index.get_num_routed(*record_index,indices) as u64)
+            .sum::<u64>(); 
+        let mut int_map=IntMap::new(proband_name,index.route(&haplotype1),index.route(&haplotype2)); 
+        if records.is_vep_annotated()
+        {
+            int_map.assign_mutated_positions(); 
+        }
+        (int_map,(num_unphased_calls,num_decoded))
+    }; 
+    let (int_maps,counts):(Vec<IntMap>,Vec<(u64,u64)>)=match engine
+    {
+        Engine::ST=>
+        {
+            proband_names.into_iter().zip(probands_table).zip(columns)
+                .map(build_int_map)
+                .unzip()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto=>
+        {
+            proband_names.into_par_iter().zip(probands_table).zip(columns)
+                .map(build_int_map)
+                .unzip()
+        }
+    }; 
+    let num_unphased_calls=counts.iter().map(|(num_unphased_calls,_)|num_unphased_calls).sum(); 
+    let num_decoded_consequences=int_maps.iter().zip(counts.iter())
+        .map(|(int_map,(_,num_decoded))|(int_map.get_name().clone(),*num_decoded))
+        .collect::<HashMap<String,u64>>(); 
+    (int_maps,DecodingSummary{num_unphased_calls,num_decoded_consequences})
+}
+/// the routed mutations of each transcript of a haplotype keyed by the transcript name 
+type TranscriptGroups=HashMap<Arc<str>,Vec<Mutation>>; 
+/// the routed mutations of both haplotypes of a proband along with the number of decoded supported consequences and of unphased calls 
+#[derive(Default)]
+struct ProbandGroups
+{
+    haplotypes:[TranscriptGroups;2],
+    num_decoded:u64,
+    num_unphased_calls:u64
+}
+/// ## Summary 
+/// An incremental builder of the intermediate representations, the chunks of the records of a file, e.g. of a streamed file, see 
+/// readers::stream_vcf, are added in their order and the consequences of each chunk are routed to the transcripts of each proband, see 
+/// ConsequenceIndex, hence, only the routed mutations are kept between two chunks. The built representations are identical to the 
+/// representations built from all the records at once with get_int_maps. 
+/// ## Example
+///```rust
+/// use ppgg::functions::vcf_tools::IntMapBuilder;
+/// use ppgg::data_structures::vcf_ds::{Probands,VCFRecords};
+/// use ppgg::data_structures::InternalRep::engines::Engine;
+/// let mut builder=IntMapBuilder::new(Probands::new(vec!["S1".to_string()]));
+/// builder.add_records(VCFRecords::new(vec!["1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|2K>2N|10A>T\tGT:BCSQ\t0|1:2".to_string()]),Engine::ST);
+/// builder.add_records(VCFRecords::new(vec!["1\t20\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|3L>3V|20A>T\tGT:BCSQ\t0|1:2".to_string()]),Engine::ST);
+/// assert_eq!(builder.get_num_records(),2);
+/// let int_maps=builder.build(Engine::ST);
+/// assert_eq!(int_maps[0].get_mutations_ref().1[0].get_alts().len(),2);
+///```
+pub struct IntMapBuilder
+{
+    proband_names:Vec<String>,
+    groups:Vec<ProbandGroups>,
+    num_records:usize,
+    vep_annotated:bool
+}
+impl IntMapBuilder
+{
+    /// ## Summary 
+    /// Create a builder without records for the provided probands 
+    pub fn new(probands:Probands)->Self
+    {
+        let proband_names=probands.get_probands(); 
+        let num_probands=proband_names.len(); 
+        IntMapBuilder{proband_names,groups:(0..num_probands).map(|_|ProbandGroups::default()).collect(),num_records:0,vep_annotated:false}
+    }
+    /// ## Summary 
+    /// Return the number of records added to the builder 
+    pub fn get_num_records(&self)->usize
+    {
+        self.num_records
+    }
+    /// ## Summary 
+    /// Return the summary of decoding the records added to the builder 
+    pub fn get_decoding_summary(&self)->DecodingSummary
+    {
+        DecodingSummary{num_unphased_calls:self.groups.iter().map(|groups|groups.num_unphased_calls).sum(),
+            num_decoded_consequences:self.proband_names.iter().zip(self.groups.iter()).map(|(proband_name,groups)|(proband_name.clone(),groups.num_decoded)).collect()}
+    }
+    /// ## Summary 
+    /// Decode and route the consequences of the next chunk of records for every proband, with the decoding options of the records 
+    pub fn add_records(&mut self, mut records:VCFRecords, engine:Engine)
+    {
+        let probands_table=records.get_patient_fields(self.proband_names.len(),engine.clone()); 
+        let index=ConsequenceIndex::new(&records.get_consequences_vector(engine.clone()), engine.clone()); 
+        let num_consequences=(0..records.get_records().len()).map(|record_index|index.get_num_consequences(record_index)).collect::<Vec<usize>>(); 
+        let bcsq_indices=records.get_bcsq_indices(); 
+        let decompositions=records.get_allele_decompositions(); 
+        let loci=records.get_loci(); 
+        let decoding_options=records.get_decoding_options(); 
+        let add_proband=|(sample_index,(groups,donor)):(usize,(&mut ProbandGroups,Vec<String>))|
+        {
+            let (haplotype1,haplotype2,num_unphased_calls)=VCFRecords::decode_indices(&num_consequences,&donor,&bcsq_indices,&decompositions,&loci,
+                sample_index,*decoding_options,engine.clone()); 
+            groups.num_unphased_calls+=num_unphased_calls; 
+            groups.num_decoded+=haplotype1.iter().chain(haplotype2.iter())
+                .map(|(record_index,indices)|index.get_num_routed(*record_index,indices) as u64)
+                .sum::<u64>(); 
+            index.route_into(&haplotype1,&mut groups.haplotypes[0]); 
+            index.route_into(&haplotype2,&mut groups.haplotypes[1]); 
+        }; 
+        match engine
+        {
+            Engine::ST=>self.groups.iter_mut().zip(probands_table).enumerate().for_each(add_proband),
+            Engine::MT | Engine::GPU | Engine::Auto=>self.groups.par_iter_mut().zip(probands_table).enumerate().for_each(add_proband)
+        }
+        self.num_records+=records.get_records().len(); 
+        self.vep_annotated|=records.is_vep_annotated(); 
+    }
+    /// ## Summary 
+    /// Build the intermediate representation of each proband from the routed mutations, in the order of the probands 
+    pub fn build(self, engine:Engine)->Vec<IntMap>
+    {
+        let vep_annotated=self.vep_annotated; 
+        let build_int_map=|(proband_name,groups):(String,ProbandGroups)|
+        {
+            let [groups1,groups2]=groups.haplotypes; 
+            let mut int_map=IntMap::new(proband_name,ConsequenceIndex::to_alt_transcripts(groups1),ConsequenceIndex::to_alt_transcripts(groups2)); 
+            if vep_annotated
+            {
+                int_map.assign_mutated_positions(); 
+            }
+            int_map
+        }; 
+        match engine
+        {
+            Engine::ST=>self.proband_names.into_iter().zip(self.groups).map(build_int_map).collect::<Vec<IntMap>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>self.proband_names.into_par_iter().zip(self.groups).map(build_int_map).collect::<Vec<IntMap>>()
+        }
+    }
+}
+/// ## Summary 
+/// Process a collection of early maps to a collection of Intermediate maps on Parallel.
+pub fn early_to_intermediate_repr(mut vec_of_early_maps:Vec<EarlyMap>,engine:Engine)->Vec<IntMap>
+{
+    match engine 
+    {
+        Engine::ST=>
+        {
+            vec_of_early_maps.iter_mut()
+                    .map(|early_map| build_int_map_from_early(early_map))
+                    .collect::<Vec<IntMap>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto =>
+        {
+            vec_of_early_maps.par_iter_mut()
+                    .map(|early_map| build_int_map_from_early(early_map))
+                    .collect::<Vec<IntMap>>()
+        }
+    }
+}
+/// ## Summary 
+/// Build an intermediate map instance, IntMap from an early map instance 
+pub fn build_int_map_from_early(early_map:&EarlyMap)->IntMap
+{
+    // get the map of each mutations in the file 
+    let (mutations1,mutations2)=early_map.get_mutations_ref();
+    // get the map of each mutations in the file 
+    let alt_transcripts1=group_muts_per_transcript(mutations1); 
+    let alt_transcripts2=group_muts_per_transcript(mutations2); 
+    IntMap::new(early_map.get_proband_name().clone(),alt_transcripts1,alt_transcripts2)
+}
+/// ## Summary 
+/// Group all mutations in each transcript to a vector of AltTranscript, where each element in the generated transcript
+/// contain all the mutations observed in a single transcript.
+/// ## Example
+///```
+/// use ppgg_rust::functions::vcf_tools::group_muts_per_transcript; 
+/// let mutations=vec!["*missense|MAD1L1|Transcript1|protein_coding|-|1R>1H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript1|protein_coding|-|10R>10H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript2|protein_coding|-|100R>100H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript2|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript3|protein_coding|-|18R>18H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript3|protein_coding|-|1993R>1993H|1936821C>T".to_string(),
+///        ];
+///        let results=group_muts_per_transcript(&mutations);
+///        // check correct length 
+///        assert_eq!(results.len(),3);
+///        // check the correct name 
+///        assert_eq!(results[0].name,"Transcript1");
+///        assert_eq!(results[1].name,"Transcript2");
+///        assert_eq!(results[2].name,"Transcript3");
+///        // check the correct mapping 
+///        // 1. results: Transcript 1
+///        assert_eq!(results[0].get_alts()[0].mut_info.ref_aa_position,0);
+///        assert_eq!(results[0].get_alts()[1].mut_info.ref_aa_position,9);
+///        // 2. results: Transcripts 2
+///        assert_eq!(results[1].get_alts()[0].mut_info.ref_aa_position,99);
+///        assert_eq!(results[1].get_alts()[1].mut_info.ref_aa_position,999);
+///        // 3. results: Transcripts 3
+///        assert_eq!(results[2].get_alts()[0].mut_info.ref_aa_position,17);
+///        assert_eq!(results[2].get_alts()[1].mut_info.ref_aa_position,1992);
+///
+///```
+pub fn group_muts_per_transcript(vec_mut:&[String])->Vec<AltTranscript>
+{
+    // intern the transcript names, each consequence is split once, borrowing its fields, and is assigned to its transcript through  
+    // the index of the transcript in the table, all the mutations of a transcript share the interned name 
+    //------------------------------
+    let mut table:HashMap<&str,usize>=HashMap::new(); 
+    let mut names:Vec<Arc<str>>=Vec::new(); 
+    let mut groups:Vec<Vec<Mutation>>=Vec::new(); 
+    for consequence in vec_mut.iter()
+    {
+        let fields=match text_parser::split_csq_str(consequence)
+        {
+            Ok(fields)=>fields,
+            Err(_)=>continue
+        };
+        let index=*table.entry(fields[1]).or_insert_with(||
+            {
+                names.push(Arc::from(fields[1])); 
+                groups.push(Vec::new()); 
+                names.len()-1
+            }); 
+        if let Ok(mutation)=Mutation::from_csq_fields(fields[0], names[index].clone(), fields[2])
+        {
+            groups[index].push(mutation); 
+        }
+    }
+    let mut res=names.into_iter().zip(groups)
+        .map(|(name,alts)|AltTranscript::from_mutations(name.to_string(), alts).drop_replicate())
+        .collect::<Vec<AltTranscript>>(); 
+    res.sort_by(|alt1,alt2|alt1.name.cmp(&alt2.name)); 
+    res
+}
+/// ## Summary 
+/// Extract the set of uniuqe transcripts in a collection of mutations 
+/// ##Example 
+///```
+/// use ppgg_rust::functions::vcf_tools::get_unique_transcript; 
+/// let mutations=vec!["*missense|MAD1L1|Transcript1|protein_coding|-|1R>1H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript1|protein_coding|-|10R>10H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript2|protein_coding|-|100R>100H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript2|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript3|protein_coding|-|100R>100H|1936821C>T".to_string(),
+///                "*missense|MAD1L1|Transcript3|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+///        ];
+///        let results=get_unique_transcript(&mutations);
+///        assert_eq!(results.len(),3);
+///        assert_eq!(results[0],"Transcript1");
+///        assert_eq!(results[1],"Transcript2");
+///        assert_eq!(results[2],"Transcript3");
+///
+///```
+pub fn get_unique_transcript(vec_mut:&Vec<String>)->Vec<String>
+{
+    let mut uniuqe_muts=vec_mut
+                                .iter()
+                                .filter_map(|field|
+                                    {
+                                        match text_parser::split_csq_string(field)
+                                        {
+                                            Ok(res)=>Some(res[1].clone()),
+                                            Err(_)=>None,
+                                        }
+                                    })
+                                .collect::<Vec<String>>(); 
+    uniuqe_muts.sort(); 
+    uniuqe_muts.dedup(); 
+    uniuqe_muts
+
+}
+ 
+#[cfg(test)]
+mod test_vcf_tools_function
+{
+    use super::*; 
+    #[test]
+    pub fn test_get_early_map()
+    {
+        let probands=vec![
+                    "Proband1".to_string(),
+                    "Proband2".to_string(),
+                    "Proband3".to_string(),
+                    "Proband4".to_string()]; 
+        let mutations=vec![
+            ("mutation1_1,mutation1_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),"mutation1_2,mutation1_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()),
+            ("mutation2_1,mutation2_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),"mutation2_2,mutation2_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()),
+            ("mutation3_1,mutation3_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),"mutation3_2,mutation3_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()),
+            ("mutation4_1,mutation4_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),"mutation4_2,mutation4_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>())
+            ]; 
+        let results=(probands,mutations).into_par_iter()
+        .map(|(proband,(vec_mut_one,vec_mut_two))|{
+            EarlyMap::new(proband,vec_mut_one,vec_mut_two)})
+        .collect::<Vec<EarlyMap>>(); 
+        // write the assert statment 
+        assert_eq!(results[0].get_proband_name(),"Proband1");
+        assert_eq!(results[1].get_proband_name(),"Proband2");
+        assert_eq!(results[2].get_proband_name(),"Proband3");
+        assert_eq!(results[3].get_proband_name(),"Proband4");
+        // check that the mutations have been inserted 
+        assert_eq!(results[0].get_mutations_ref(),(&"mutation1_1,mutation1_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),
+                                                &"mutation1_2,mutation1_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()));
+        assert_eq!(results[1].get_mutations_ref(),(&"mutation2_1,mutation2_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),
+                                                &"mutation2_2,mutation2_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()));
+        assert_eq!(results[2].get_mutations_ref(),(&"mutation3_1,mutation3_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),
+                                                &"mutation3_2,mutation3_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()));
+        assert_eq!(results[3].get_mutations_ref(),(&"mutation4_1,mutation4_3".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>(),
+                                                &"mutation4_2,mutation4_4".split(",").map(|elem|elem.to_string()).collect::<Vec<String>>()));
+    }
+    #[test]
+    pub fn test_get_early_map_for_samples()
+    {
+        let probands=Probands::new(vec!["S1".to_string(),"S2".to_string(),"S3".to_string()]); 
+        let records=VCFRecords::new(vec![
+            "1\t10\t.\tA\tT\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|5K>5N|10A>T\tGT:BCSQ\t1|0:1\t0|1:2\t1|1:3".to_string(),
+            "1\t20\t.\tC\tG\t.\tPASS\tBCSQ=missense|G1|T1|protein_coding|+|9R>9G|20C>G\tGT:BCSQ\t0|1:2\t0|0:0\t1|0:1".to_string()]); 
+        let all_samples=get_early_map(probands.clone(), records.clone(), Engine::ST); 
+        let selected=get_early_map_for_samples(probands.clone(), records.clone(), &["S3".to_string(),"S1".to_string()], Engine::ST).unwrap(); 
+        assert_eq!(selected.len(),2); 
+        for (early_map,expected) in selected.iter().zip([&all_samples[0],&all_samples[2]].iter())
+        {
+            assert_eq!(early_map.get_proband_name(),expected.get_proband_name()); 
+            assert_eq!(early_map.get_mutations_ref(),expected.get_mutations_ref()); 
+        }
+        assert!(get_early_map_for_samples(probands, records, &["S4".to_string()], Engine::ST).is_err()); 
+    }
+    #[test]
+    pub fn test_get_unique_transcript()
+    {
+        let mutations=vec!["*missense|MAD1L1|Transcript1|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript1|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript2|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript2|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript3|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript3|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+        ];
+        let results=get_unique_transcript(&mutations);
+        assert_eq!(results.len(),3);
+        assert_eq!(results[0],"Transcript1");
+        assert_eq!(results[1],"Transcript2");
+        assert_eq!(results[2],"Transcript3");
+    }
+    #[test]
+    fn test_group_muts_per_transcript()
+    {
+        let mutations=vec!["*missense|MAD1L1|Transcript1|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript1|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript2|protein_coding|-|100R>100H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript2|protein_coding|-|1000R>1000H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript3|protein_coding|-|18R>18H|1936821C>T".to_string(),
+                "*missense|MAD1L1|Transcript3|protein_coding|-|1993R>1993H|1936821C>T".to_string(),
+        ];
+        let results=group_muts_per_transcript(&mutations);
+        // check correct length 
+        assert_eq!(results.len(),3);
+        // check the correct name 
+        assert_eq!(results[0].name,"Transcript1");
+        assert_eq!(results[1].name,"Transcript2");
+        assert_eq!(results[2].name,"Transcript3");
+        // check the correct mapping 
+        // 1. results: Transcript 1
+        assert_eq!(results[0].get_alts()[0].mut_info.ref_aa_position,0);
+        assert_eq!(results[0].get_alts()[1].mut_info.ref_aa_position,9);
+        // 2. results: Transcripts 2
+        assert_eq!(results[1].get_alts()[0].mut_info.ref_aa_position,99);
+        assert_eq!(results[1].get_alts()[1].mut_info.ref_aa_position,999);
+        // 3. results: Transcripts 3
+        assert_eq!(results[2].get_alts()[0].mut_info.ref_aa_position,17);
+        assert_eq!(results[2].get_alts()[1].mut_info.ref_aa_position,1992);
+    }
+    #[test]
+    pub fn test_group_muts_per_transcript_shares_names()
+    {
+        let mutations=vec!["*missense|MAD1L1|Transcript10|protein_coding|-|5R>5H|1936821C>T".to_string(),
+                    "*missense|MAD1L1|Transcript1|protein_coding|-|10R>10H|1936821C>T".to_string(),
+                    "*missense|MAD1L1|Transcript10|protein_coding|-|1R>1H|1936821C>T".to_string(),
+                    "*missense|MAD1L1|Transcript2|lncRNA|-|1R>1H|1936821C>T".to_string()];
+        let results=group_muts_per_transcript(&mutations);
+        // transcripts are matched by name, i.e. Transcript1 does not collect the mutations of Transcript10, and non-coding transcripts are skipped
+        assert_eq!(results.len(),2);
+        assert_eq!(results[0].name,"Transcript1");
+        assert_eq!(results[0].get_alts().len(),1);
+        assert_eq!(results[1].name,"Transcript10");
+        assert_eq!(results[1].get_alts().iter().map(|mutation|mutation.mut_info.ref_aa_position).collect::<Vec<u32>>(),vec![0,4]);
+        assert!(Arc::ptr_eq(&results[1].get_alts()[0].transcript_name,&results[1].get_alts()[1].transcript_name));
+    }
+}
@@ -0,0 +1,446 @@
+/// The module converts the CSQ annotations of Ensembl VEP into the BCSQ annotations of bcftools csq, i.e. the consequences of each record
+/// are rewritten into the BCSQ layout and the bit-mask of each sample is derived from its genotype, so VEP-annotated VCF files can be
+/// consumed directly without annotating them again with bcftools csq
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use rayon::prelude::*;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::functions::text_parser;
+
+/// ## Summary
+/// The maximum number of converted consequences per record, i.e. the number of consequences encoded by one bit-mask field as written by
+/// bcftools csq with its default --ncsq, the remaining consequences of a record are skipped and counted, see VepSummary
+pub const MAX_VEP_CONSEQUENCES:usize=15;
+
+/// ## Summary
+/// The indices of the fields of a VEP CSQ entry used for the conversion, the field order is read from the Format of the CSQ description in
+/// the VCF header, e.g. Allele|Consequence|IMPACT|SYMBOL|Gene|Feature_type|Feature|BIOTYPE|...|Protein_position|Amino_acids|...|STRAND.
+/// The gene is read from SYMBOL, or from Gene if SYMBOL is not part of the layout, and the ALT allele of an entry is read from
+/// ALLELE_NUM, if present, or matched against the Allele field otherwise.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct VepLayout
+{
+    pub allele:usize,
+    pub consequence:usize,
+    pub feature:usize,
+    pub protein_position:usize,
+    pub amino_acids:usize,
+    pub gene:Option<usize>,
+    pub biotype:Option<usize>,
+    pub strand:Option<usize>,
+    pub allele_num:Option<usize>
+}
+impl VepLayout
+{
+    /// ## Summary
+    /// Locate the fields in the Format of a VEP CSQ description, i.e. the field names separated by |, an error is returned if Allele,
+    /// Consequence, Feature, Protein_position or Amino_acids is missing
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::vep::VepLayout;
+    /// let layout=VepLayout::from_format("Allele|Consequence|IMPACT|SYMBOL|Feature|BIOTYPE|Protein_position|Amino_acids|STRAND").unwrap();
+    /// assert_eq!((layout.allele,layout.feature,layout.protein_position,layout.gene),(0,4,6,Some(3)));
+    /// assert!(VepLayout::from_format("Allele|Consequence|Feature").is_err());
+    ///```
+    pub fn from_format(format:&str)->Result<VepLayout,String>
+    {
+        let names=format.split('|').map(|name|name.trim()).collect::<Vec<&str>>();
+        let find=|name:&str|names.iter().position(|field|*field==name);
+        let require=|name:&str|find(name).ok_or(format!("The Format of the VEP CSQ annotations does not contain the required field: {}",name));
+        Ok(VepLayout{allele:require("Allele")?,consequence:require("Consequence")?,feature:require("Feature")?,
+            protein_position:require("Protein_position")?,amino_acids:require("Amino_acids")?,gene:find("SYMBOL").or_else(||find("Gene")),
+            biotype:find("BIOTYPE"),strand:find("STRAND"),allele_num:find("ALLELE_NUM")})
+    }
+    /// ## Summary
+    /// Read the layout from the CSQ description of the header lines of a VCF file, None is returned if the header has no CSQ description
+    /// or if the CSQ annotations are not written by VEP, e.g. written by bcftools csq with a custom tag, which lack the Allele field
+    /// ## Example
+    ///```rust
+    /// use ppgg::functions::vep::VepLayout;
+    /// let header=vec!["##INFO=<ID=CSQ,Number=.,Type=String,Description=\"Consequence annotations from Ensembl VEP. Format: Allele|Consequence|Feature|Protein_position|Amino_acids\">".to_string()];
+    /// assert_eq!(VepLayout::from_header(&header).unwrap().unwrap().amino_acids,4);
+    /// assert!(VepLayout::from_header(&["##INFO=<ID=BCSQ,Number=.,Type=String,Description=\"Format: Consequence|gene|transcript\">".to_string()]).is_none());
+    ///```
+    pub fn from_header(header:&[String])->Option<Result<VepLayout,String>>
+    {
+        let line=header.iter().take_while(|line|line.starts_with("##")).find(|line|line.starts_with("##INFO=<ID=CSQ,"))?;
+        let format=line.split_once("Format:")?.1.split('"').next().unwrap_or("").trim();
+        match format.split('|').any(|name|name.trim()=="Allele")
+        {
+            true=>Some(VepLayout::from_format(format)),
+            false=>None
+        }
+    }
+    fn get_num_fields(&self)->usize
+    {
+        [Some(self.allele),Some(self.consequence),Some(self.feature),Some(self.protein_position),Some(self.amino_acids),self.gene,
+            self.biotype,self.strand,self.allele_num].iter().flatten().max().unwrap()+1
+    }
+}
+
+/// ## Summary
+/// A parsed VEP CSQ entry, i.e. the consequence of one ALT allele on one feature, where the consequence terms are the Sequence Ontology
+/// terms of the entry, e.g. missense_variant and splice_region_variant
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct VepConsequence
+{
+    pub allele:String,
+    pub allele_num:Option<usize>,
+    pub terms:Vec<String>,
+    pub gene:String,
+    pub feature:String,
+    pub biotype:String,
+    pub strand:String,
+    pub protein_position:String,
+    pub amino_acids:String
+}
+
+/// ## Summary
+/// Parse one entry of a VEP CSQ INFO field using the layout of the header, an error is returned if the entry has fewer fields than the
+/// layout requires
+/// ## Example
+///```rust
+/// use ppgg::functions::vep::{VepLayout,parse_vep};
+/// let layout=VepLayout::from_format("Allele|Consequence|SYMBOL|Feature|BIOTYPE|Protein_position|Amino_acids|STRAND").unwrap();
+/// let csq=parse_vep("T|missense_variant&splice_region_variant|BRAF|ENST00000646891|protein_coding|640|V/E|-1",&layout).unwrap();
+/// assert_eq!(csq.terms,vec!["missense_variant".to_string(),"splice_region_variant".to_string()]);
+/// assert_eq!((csq.feature.as_str(),csq.strand.as_str(),csq.amino_acids.as_str()),("ENST00000646891","-","V/E"));
+/// assert!(parse_vep("T|missense_variant",&layout).is_err());
+///```
+pub fn parse_vep(entry:&str, layout:&VepLayout)->Result<VepConsequence,String>
+{
+    let fields=entry.split('|').collect::<Vec<&str>>();
+    if fields.len()<layout.get_num_fields()
+    {
+        return Err(format!("The VEP CSQ entry: {} has {} fields, while the layout of the header requires at least {} fields",entry,fields.len(),layout.get_num_fields()))
+    }
+    let optional=|index:Option<usize>|index.map(|index|fields[index].to_string()).unwrap_or_default();
+    let strand=match optional(layout.strand).as_str()
+    {
+        "-1"=>"-".to_string(),
+        _=>"+".to_string()
+    };
+    Ok(VepConsequence{allele:fields[layout.allele].to_string(),allele_num:layout.allele_num.and_then(|index|fields[index].parse::<usize>().ok()),
+        terms:fields[layout.consequence].split('&').map(|term|term.to_string()).collect(),gene:optional(layout.gene),
+        feature:fields[layout.feature].to_string(),biotype:optional(layout.biotype),strand,
+        protein_position:fields[layout.protein_position].to_string(),amino_acids:fields[layout.amino_acids].to_string()})
+}
+
+/// ## Summary
+/// Return the BCSQ consequence type of the Sequence Ontology terms of a VEP entry along with the term it is derived from, or None if none
+/// of the terms alters the protein sequence, where the term that alters the protein the most is used, e.g. stop_gained for
+/// stop_gained&inframe_deletion
+/// ## Example
+///```rust
+/// use ppgg::functions::vep::get_bcsq_type;
+/// assert_eq!(get_bcsq_type(&["missense_variant".to_string(),"splice_region_variant".to_string()]),Some(("missense_variant","missense")));
+/// assert_eq!(get_bcsq_type(&["synonymous_variant".to_string()]),None);
+///```
+pub fn get_bcsq_type(terms:&[String])->Option<(&'static str,&'static str)>
+{
+    ["frameshift_variant","stop_lost","stop_gained","start_lost","inframe_insertion","inframe_deletion","missense_variant"].iter()
+        .find(|term|terms.iter().any(|vep_term|vep_term==*term))
+        .map(|term|(*term,term.strip_suffix("_variant").unwrap_or(term)))
+}
+
+/// ## Summary
+/// Return the consequence of a VEP entry in the BCSQ layout, i.e. type|gene|transcript|biotype|strand|amino acid change|DNA change, Ok(None)
+/// if it does not alter the protein, or the term and the reason why it can not be converted. Frameshifts and lost stop codons are skipped
+/// as VEP reports neither the frameshifted sequence nor the extension of the protein, and so are pure insertions and deletions, e.g. -/A or
+/// EL/-, as the flanking residue that anchors them is not part of the entry.
+fn to_bcsq(csq:&VepConsequence, dna_change:&str)->Result<Option<String>,(String,String)>
+{
+    let (term,mut_type)=match get_bcsq_type(&csq.terms)
+    {
+        Some(bcsq_type)=>bcsq_type,
+        None=>return Ok(None)
+    };
+    let skip=|reason:String|Err((term.to_string(),reason));
+    match mut_type
+    {
+        "frameshift"=>return skip("the frameshifted sequence is not part of a VEP CSQ entry".to_string()),
+        "stop_lost"=>return skip("the extension of a lost stop codon is not part of a VEP CSQ entry".to_string()),
+        _=>()
+    }
+    let position=match csq.protein_position.split('/').next().unwrap().split('-').next().unwrap().parse::<usize>()
+    {
+        Ok(position) if position!=0=>position,
+        _=>return skip(format!("the protein position: {} is not a valid position",csq.protein_position))
+    };
+    let (ref_aa,mut_aa)=match csq.amino_acids.split_once('/')
+    {
+        Some((ref_aa,mut_aa))=>(ref_aa,mut_aa),
+        None=>return skip(format!("the amino acids: {} do not describe a change of the protein",csq.amino_acids))
+    };
+    let mut_aa=match (mut_type,ref_aa,mut_aa)
+    {
+        ("start_lost",_,"-")=>ref_aa,
+        (_,"-",_) | (_,_,"-")=>return skip(format!("the amino acids: {} describe a pure indel that lacks its anchoring residue",csq.amino_acids)),
+        _=>mut_aa
+    };
+    let aa_change=format!("{}{}>{}{}",position,ref_aa,position,mut_aa);
+    if let Err(err_msg)=text_parser::parse_amino_acid_field(&aa_change)
+    {
+        return skip(err_msg)
+    }
+    Ok(Some(format!("{}|{}|{}|{}|{}|{}|{}",mut_type,csq.gene,csq.feature,csq.biotype,csq.strand,aa_change,dna_change)))
+}
+
+/// ## Summary
+/// Return the alleles of the ALT alleles of a record as written by VEP to the Allele field, i.e. the first base is removed if it is shared
+/// by the REF and all the ALT alleles and removed alleles are written as -, e.g. the ALT allele A of the REF allele AT is written as -
+/// ## Example
+///```rust
+/// use ppgg::functions::vep::get_vep_alleles;
+/// assert_eq!(get_vep_alleles("A",&["T","G"]),vec!["T".to_string(),"G".to_string()]);
+/// assert_eq!(get_vep_alleles("AT",&["A","ATT"]),vec!["-".to_string(),"TT".to_string()]);
+///```
+pub fn get_vep_alleles(ref_allele:&str, alt_alleles:&[&str])->Vec<String>
+{
+    let first_base=ref_allele.chars().next();
+    let is_trimmed=first_base.is_some() && alt_alleles.iter().all(|alt|alt.chars().next()==first_base && !alt.starts_with('<'));
+    alt_alleles.iter().map(|alt|match (is_trimmed,&alt[alt.len().min(1)..])
+    {
+        (true,"")=>"-".to_string(),
+        (true,trimmed)=>trimmed.to_string(),
+        (false,_)=>alt.to_string()
+    })
+    .collect()
+}
+
+/// ## Summary
+/// The outcome of converting the VEP annotations of a VCF file, i.e. the number of records with CSQ annotations, the number of CSQ
+/// entries, the number of converted entries, the number of entries that do not alter the protein, the entries whose allele does not match
+/// an ALT allele of their record, the entries beyond MAX_VEP_CONSEQUENCES protein-altering entries of a record and the protein-altering
+/// entries that could not be converted, counted per consequence term along with the first reason of each term
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct VepSummary
+{
+    pub num_records:usize,
+    pub num_consequences:usize,
+    pub num_converted:usize,
+    pub num_ignored:usize,
+    pub num_unmatched:usize,
+    pub num_truncated:usize,
+    pub num_skipped_per_term:BTreeMap<String,(usize,String)>
+}
+impl VepSummary
+{
+    /// ## Summary
+    /// Return the number of protein-altering entries that could not be converted
+    pub fn get_num_skipped(&self)->usize
+    {
+        self.num_skipped_per_term.values().map(|(num_skipped,_)|num_skipped).sum()
+    }
+    /// ## Summary
+    /// Return whether entries were dropped by the conversion, i.e. skipped, unmatched or truncated entries
+    pub fn has_dropped_entries(&self)->bool
+    {
+        self.get_num_skipped()+self.num_unmatched+self.num_truncated!=0
+    }
+    /// ## Summary
+    /// Add the counts of another summary, e.g. of the next chunk of a streamed file, to the current summary
+    pub fn merge(&mut self, other:VepSummary)
+    {
+        self.num_records+=other.num_records;
+        self.num_consequences+=other.num_consequences;
+        self.num_converted+=other.num_converted;
+        self.num_ignored+=other.num_ignored;
+        self.num_unmatched+=other.num_unmatched;
+        self.num_truncated+=other.num_truncated;
+        for (term,(num_skipped,reason)) in other.num_skipped_per_term
+        {
+            self.num_skipped_per_term.entry(term).or_insert((0,reason)).0+=num_skipped;
+        }
+    }
+    /// ## Summary
+    /// Return a human readable report of the conversion, i.e. the totals and the skipped entries per consequence term
+    pub fn to_report(&self)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"The VEP CSQ annotations of {} records were converted into BCSQ annotations, {} out of {} entries were converted, {} do not alter the protein, \
+            {} do not match an ALT allele of their record, {} exceed the {} consequences per record and {} could not be converted",
+            self.num_records,self.num_converted,self.num_consequences,self.num_ignored,self.num_unmatched,self.num_truncated,MAX_VEP_CONSEQUENCES,
+            self.get_num_skipped()).unwrap();
+        for (term,(num_skipped,reason)) in self.num_skipped_per_term.iter()
+        {
+            writeln!(report,"\t{}\t{}\t{}",term,num_skipped,reason).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Convert a record annotated by VEP into a record annotated by bcftools csq, i.e. the CSQ INFO field is replaced by a BCSQ INFO field with
+/// the protein-altering entries, see get_bcsq_type, BCSQ is appended to the FORMAT field and each sample is given the bit-mask of the
+/// converted consequences carried by its genotype, where the first haplotype carries the first allele of the genotype, i.e. unphased
+/// genotypes are read like phased ones, haploid calls are carried by the first haplotype and missing alleles carry no consequence.
+/// Records without a CSQ INFO field are returned unchanged, while a record without a converted entry loses its CSQ INFO field.
+/// ## Example
+///```rust
+/// use ppgg::functions::vep::{VepLayout,VepSummary,vep_to_bcsq};
+/// let layout=VepLayout::from_format("Allele|Consequence|SYMBOL|Feature|BIOTYPE|Protein_position|Amino_acids|STRAND").unwrap();
+/// let mut summary=VepSummary::default();
+/// let record="7\t140753336\t.\tA\tT\t.\tPASS\tDP=10;CSQ=T|missense_variant|BRAF|ENST00000646891|protein_coding|640|V/E|-1\tGT\t0|1\t1|1";
+/// assert_eq!(vep_to_bcsq(record,&layout,&mut summary),
+///     "7\t140753336\t.\tA\tT\t.\tPASS\tDP=10;BCSQ=missense|BRAF|ENST00000646891|protein_coding|-|640V>640E|140753336A>T\tGT:BCSQ\t0|1:2\t1|1:3");
+/// assert_eq!((summary.num_records,summary.num_converted),(1,1));
+///```
+pub fn vep_to_bcsq(record:&str, layout:&VepLayout, summary:&mut VepSummary)->String
+{
+    let fields=record.split('\t').collect::<Vec<&str>>();
+    if fields.len()<8 || !fields[7].split(';').any(|info|info.starts_with("CSQ="))
+    {
+        return record.to_string()
+    }
+    summary.num_records+=1;
+    let alt_alleles=fields[4].split(',').collect::<Vec<&str>>();
+    let vep_alleles=get_vep_alleles(fields[3], &alt_alleles);
+    let mut converted:Vec<(String,usize)>=Vec::new();
+    let csq_field=fields[7].split(';').find_map(|info|info.strip_prefix("CSQ=")).unwrap();
+    for entry in csq_field.split(',')
+    {
+        summary.num_consequences+=1;
+        let csq=match parse_vep(entry, layout)
+        {
+            Ok(csq)=>csq,
+            Err(err_msg)=>
+            {
+                summary.num_skipped_per_term.entry("malformed_entry".to_string()).or_insert((0,err_msg)).0+=1;
+                continue
+            }
+        };
+        let allele_index=match csq.allele_num
+        {
+            Some(allele_num) if allele_num<=alt_alleles.len()=>allele_num,
+            Some(_)=>0,
+            None=>vep_alleles.iter().position(|allele|*allele==csq.allele).map(|index|index+1).unwrap_or(0)
+        };
+        if allele_index==0
+        {
+            summary.num_unmatched+=1;
+            continue
+        }
+        let dna_change=format!("{}{}>{}",fields[1],fields[3],alt_alleles[allele_index-1]);
+        match to_bcsq(&csq, &dna_change)
+        {
+            Ok(Some(_)) if converted.len()==MAX_VEP_CONSEQUENCES=>summary.num_truncated+=1,
+            Ok(Some(bcsq))=>
+            {
+                summary.num_converted+=1;
+                converted.push((bcsq,allele_index));
+            },
+            Ok(None)=>summary.num_ignored+=1,
+            Err((term,reason))=>summary.num_skipped_per_term.entry(term).or_insert((0,reason)).0+=1
+        }
+    }
+    let info=fields[7].split(';')
+        .filter_map(|info|match info.starts_with("CSQ=")
+        {
+            true if converted.is_empty()=>None,
+            true=>Some(format!("BCSQ={}",converted.iter().map(|(bcsq,_)|bcsq.as_str()).collect::<Vec<&str>>().join(","))),
+            false=>Some(info.to_string())
+        })
+        .collect::<Vec<String>>();
+    let info=match info.is_empty()
+    {
+        true=>".".to_string(),
+        false=>info.join(";")
+    };
+    let mut record=fields[..7].join("\t");
+    write!(record,"\t{}",info).unwrap();
+    if fields.len()==8
+    {
+        return record
+    }
+    let format=fields[8].split(':').collect::<Vec<&str>>();
+    let gt_index=format.iter().position(|key|*key=="GT");
+    write!(record,"\t{}:BCSQ",fields[8]).unwrap();
+    for sample in fields[9..].iter()
+    {
+        let mut bitmask=0_u32;
+        if let Some(genotype)=gt_index.and_then(|gt_index|sample.split(':').nth(gt_index))
+        {
+            for (haplotype,allele) in genotype.split(['|','/']).take(2).enumerate()
+            {
+                if let Ok(allele)=allele.parse::<usize>()
+                {
+                    for (csq_index,_) in converted.iter().enumerate().filter(|(_,(_,allele_index))|*allele_index==allele)
+                    {
+                        bitmask|=1<<(2*csq_index+haplotype);
+                    }
+                }
+            }
+        }
+        // pad the samples whose trailing fields are dropped, so the bit-mask is located at the BCSQ index of the FORMAT field
+        let num_missing=format.len().saturating_sub(sample.split(':').count());
+        write!(record,"\t{}{}:{}",sample,":.".repeat(num_missing),bitmask).unwrap();
+    }
+    record
+}
+
+/// ## Summary
+/// Convert the records of a VCF file annotated by VEP using the layout of its header, see vep_to_bcsq, and return the converted records,
+/// in their order, along with the summary of the conversion
+pub fn convert_records(lines:Vec<String>, layout:&VepLayout, engine:Engine)->(Vec<String>,VepSummary)
+{
+    let convert=|line:String|
+    {
+        let mut summary=VepSummary::default();
+        let record=vep_to_bcsq(&line, layout, &mut summary);
+        (record,summary)
+    };
+    let (records,summaries):(Vec<String>,Vec<VepSummary>)=match engine
+    {
+        Engine::ST=>lines.into_iter().map(convert).unzip(),
+        Engine::MT | Engine::GPU | Engine::Auto=>lines.into_par_iter().map(convert).unzip()
+    };
+    let mut summary=VepSummary::default();
+    summaries.into_iter().for_each(|other|summary.merge(other));
+    (records,summary)
+}
+
+#[cfg(test)]
+mod test_vep
+{
+    use super::*;
+    use crate::data_structures::vcf_ds::{Probands,VCFRecords};
+    use crate::data_structures::mutation_ds::Mutation;
+    use crate::functions::vcf_tools;
+    #[test]
+    fn test_vep_to_bcsq_multi_allelic()
+    {
+        let layout=VepLayout::from_format("Allele|Consequence|IMPACT|SYMBOL|Gene|Feature_type|Feature|BIOTYPE|Protein_position|Amino_acids|STRAND").unwrap();
+        let csq=["-|inframe_deletion|MODERATE|G1|E1|Transcript|T1|protein_coding|4-5|EL/-|1",
+            "TT|inframe_insertion&splice_region_variant|MODERATE|G1|E1|Transcript|T1|protein_coding|4|E/EL|1",
+            "TT|stop_gained|HIGH|G1|E1|Transcript|T2|protein_coding|7|Q/*|-1",
+            "TT|synonymous_variant|LOW|G1|E1|Transcript|T3|protein_coding|2|K|1",
+            "G|missense_variant|MODERATE|G1|E1|Transcript|T1|protein_coding|4|E/K|1"].join(",");
+        let record=format!("1\t100\t.\tAT\tA,ATT\t.\tPASS\tCSQ={}\tGT:DP\t0|1:5\t2/1\t.|2\t2",csq);
+        let mut summary=VepSummary::default();
+        let converted=vep_to_bcsq(&record, &layout, &mut summary);
+        let fields=converted.split('\t').collect::<Vec<&str>>();
+        assert_eq!(fields[7],"BCSQ=inframe_insertion|G1|T1|protein_coding|+|4E>4EL|100AT>ATT,stop_gained|G1|T2|protein_coding|-|7Q>7*|100AT>ATT");
+        assert_eq!(fields[8],"GT:DP:BCSQ");
+        // the second ALT allele carries both converted consequences, the haploid call is carried by the first haplotype
+        assert_eq!(fields[9..].to_vec(),vec!["0|1:5:0","2/1:.:5",".|2:.:10","2:.:5"]);
+        assert_eq!((summary.num_consequences,summary.num_converted,summary.num_ignored,summary.num_unmatched),(5,2,1,1));
+        assert_eq!(summary.num_skipped_per_term.get("inframe_deletion").map(|(num_skipped,_)|*num_skipped),Some(1));
+        assert!(summary.has_dropped_entries());
+    }
+    #[test]
+    fn test_vep_mutated_positions()
+    {
+        let layout=VepLayout::from_format("Allele|Consequence|SYMBOL|Feature|BIOTYPE|Protein_position|Amino_acids|STRAND").unwrap();
+        let records=["1\t100\t.\tA\tAGCC\t.\tPASS\tCSQ=GCC|inframe_insertion|G1|T1|protein_coding|2|K/KA|1\tGT\t1|0",
+            "1\t110\t.\tA\tT\t.\tPASS\tCSQ=T|missense_variant|G1|T1|protein_coding|5|L/V|1\tGT\t1|1"].iter()
+            .map(|record|record.to_string()).collect::<Vec<String>>();
+        let (records,_)=convert_records(records, &layout, Engine::ST);
+        let (int_maps,_)=vcf_tools::get_int_maps(Probands::new(vec!["S1".to_string()]), VCFRecords::from_vep_records(records), Engine::ST);
+        let (haplotype1,haplotype2)=int_maps[0].get_mutations_ref();
+        // the insertion shifts the missense mutation by one residue in the first haplotype only
+        let get_positions=|alts:&[Mutation]|alts.iter().map(|mutation|(mutation.mut_info.ref_aa_position,mutation.mut_info.mut_aa_position)).collect::<Vec<(u32,u32)>>();
+        assert_eq!(get_positions(haplotype1[0].get_alts()),vec![(1,1),(4,5)]);
+        assert_eq!(get_positions(haplotype2[0].get_alts()),vec![(4,4)]);
+    }
+}
@@ -0,0 +1,237 @@
+/// The module provides a simple and independent model of how the mutations of a transcript alter its sequence, which can be used as a
+/// test oracle to verify the personalized sequences generated by the engines for specific transcripts, e.g. in the tests of downstream
+/// tools, without relying on the instructions and the tasks used by the engines
+use std::collections::HashMap;
+use std::fmt::Write;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::{Mutation,MutationType,MutatedString};
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome;
+
+/// ## Summary
+/// Apply the mutations of a transcript to its reference sequence by splicing, i.e. the reference residues of each mutation are replaced
+/// by its mutated residues, which is the simplest model of the personalized sequence and is slow but easy to reason about. The model is:
+/// 1. the mutations are applied in the order of their reference position and the positions of the reference are used throughout
+/// 2. a mutated string that contains a stop codon, e.g. of a frameshift or a stop-gained mutation, truncates the sequence after the residues preceding the stop codon
+/// 3. the mutated residues of a mutation whose reference residues contain the stop codon, e.g. a frameshift or a stop-lost mutation, replace the remainder of the reference
+/// 4. a start-lost mutation results in an empty sequence
+/// 5. mutations downstream of a truncation, including the asterisk-prefixed consequences of compound variants, are ignored
+/// 6. mutations that overlap a previous mutation or that are outside the reference are reported as an error
+/// ## Example
+///```rust
+/// use std::sync::Arc;
+/// use ppgg::verify::apply_mutations_naive;
+/// use ppgg::data_structures::mutation_ds::Mutation;
+/// let name:Arc<str>=Arc::from("T1");
+/// let mutations=vec![Mutation::from_csq_fields("missense",name.clone(),"2K>2R").unwrap(),
+///     Mutation::from_csq_fields("inframe_deletion",name.clone(),"4LL>4L").unwrap(),
+///     Mutation::from_csq_fields("stop_gained",name.clone(),"7W>6*").unwrap()];
+/// assert_eq!(apply_mutations_naive("MKALLPWV",&mutations).unwrap(),"MRALP");
+///```
+pub fn apply_mutations_naive(ref_seq:&str, mutations:&[Mutation])->Result<String,String>
+{
+    let mut sorted_mutations=mutations.iter().collect::<Vec<&Mutation>>();
+    sorted_mutations.sort_by_key(|mutation|mutation.mut_info.ref_aa_position);
+    let mut sequence=String::with_capacity(ref_seq.len());
+    let mut cursor=0;
+    for mutation in sorted_mutations
+    {
+        if mutation.mut_type==MutationType::StartLost || mutation.mut_type==MutationType::StartLostAndSpliceRegion
+        {
+            return Ok(String::new())
+        }
+        let position=mutation.mut_info.ref_aa_position as usize;
+        // a start re-selection trims the residues preceding the new start, including the mutations applied to them
+        if mutation.mut_type==MutationType::StartReselection
+        {
+            sequence.clear();
+            sequence.push_str(strip_stop_codon(&mutation.mut_info.mut_aa));
+            cursor=position+1;
+            continue
+        }
+        if position<cursor
+        {
+            return Err(format!("The mutation at position: {} overlaps a previous mutation that ends at position: {}",position+1,cursor))
+        }
+        let ref_residues=strip_stop_codon(&mutation.mut_info.ref_aa);
+        if position+ref_residues.len()>ref_seq.len()
+        {
+            return Err(format!("The mutation at position: {} with the reference residues: {} is outside the reference, which has a length of: {}",
+                position+1,ref_residues,ref_seq.len()))
+        }
+        sequence.push_str(&ref_seq[cursor..position]);
+        sequence.push_str(strip_stop_codon(&mutation.mut_info.mut_aa));
+        if has_stop_codon(&mutation.mut_info.mut_aa) || has_stop_codon(&mutation.mut_info.ref_aa)
+        {
+            return Ok(sequence)
+        }
+        cursor=position+ref_residues.len();
+    }
+    sequence.push_str(&ref_seq[cursor..]);
+    Ok(sequence)
+}
+
+/// ## Summary
+/// Return the residues of a mutated string that precede its stop codon, if any
+fn strip_stop_codon(mutated_string:&MutatedString)->&str
+{
+    match mutated_string
+    {
+        MutatedString::Sequence(seq_str)=>seq_str,
+        MutatedString::EndSequence(seq_str)=>seq_str.split('*').next().unwrap(),
+        MutatedString::NotSeq=>""
+    }
+}
+
+/// ## Summary
+/// Return whether a mutated string contains a stop codon
+fn has_stop_codon(mutated_string:&MutatedString)->bool
+{
+    !matches!(mutated_string,MutatedString::Sequence(_))
+}
+
+/// ## Summary
+/// A personalized sequence that does not match the sequence of the naive model, see apply_mutations_naive, the haplotype is 1 or 2
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SequenceMismatch
+{
+    pub proband_name:String,
+    pub haplotype:u8,
+    pub transcript:String,
+    pub expected:String,
+    pub observed:String
+}
+
+/// ## Summary
+/// The result of comparing the personalized sequences of a run with the sequences of the naive model, haplotypes whose mutations can not be
+/// applied by the naive model, e.g. overlapping mutations, are counted as skipped and are listed along with the reason
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct VerificationReport
+{
+    pub num_compared:usize,
+    pub mismatches:Vec<SequenceMismatch>,
+    pub skipped:Vec<(String,u8,String,String)>
+}
+impl VerificationReport
+{
+    /// ## Summary
+    /// Return whether all compared sequences match the naive model
+    pub fn is_consistent(&self)->bool
+    {
+        self.mismatches.is_empty()
+    }
+    /// ## Summary
+    /// Return a human readable report of the comparison, listing at most max_listed mismatches and skipped haplotypes
+    pub fn to_report(&self, max_listed:usize)->String
+    {
+        let mut report=String::new();
+        writeln!(report,"Compared {} personalized sequences with the naive model: {} match, {} mismatch and {} haplotypes were skipped",
+            self.num_compared,self.num_compared-self.mismatches.len(),self.mismatches.len(),self.skipped.len()).unwrap();
+        for mismatch in self.mismatches.iter().take(max_listed)
+        {
+            writeln!(report,"\t{} haplotype {} transcript {}: expected {}, observed {}",mismatch.proband_name,mismatch.haplotype,mismatch.transcript,
+                mismatch.expected,mismatch.observed).unwrap();
+        }
+        for (proband_name,haplotype,transcript,reason) in self.skipped.iter().take(max_listed)
+        {
+            writeln!(report,"\t{} haplotype {} transcript {} was skipped: {}",proband_name,haplotype,transcript,reason).unwrap();
+        }
+        report
+    }
+}
+
+/// ## Summary
+/// Compare the personalized sequences of a run with the sequences of the naive model, see apply_mutations_naive, where the IntMaps are the
+/// input of the run, e.g. as returned by io::parse_vcf, and the genomes are its results, e.g. as returned by exec::execute. If transcripts
+/// is provided, only these transcripts are compared, otherwise, all transcripts with mutations are compared. Transcripts that are not in the
+/// reference are ignored, as they are not personalized by the engines.
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, verify, Engine};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_compare_run_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let genomes=exec::execute(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&exec::ExecutionOptions::default());
+/// let report=verify::compare_run(&vec_int_repr,&genomes,&ref_seq,None);
+/// print!("{}",report.to_report(10));
+/// // compare a single transcript only
+/// let transcript=vec_int_repr[0].get_mutations_ref().0[0].name.clone();
+/// let report=verify::compare_run(&vec_int_repr,&genomes,&ref_seq,Some(&[transcript.as_str()]));
+/// assert!(report.num_compared>=1);
+///```
+pub fn compare_run(vec_int_repr:&[IntMap], genomes:&[PersonalizedGenome], ref_seq:&HashMap<String,String>, transcripts:Option<&[&str]>)->VerificationReport
+{
+    let genomes=genomes.iter().map(|genome|(genome.get_proband_name(),genome)).collect::<HashMap<_,_>>();
+    let mut report=VerificationReport::default();
+    for int_map in vec_int_repr.iter()
+    {
+        let genome=match genomes.get(int_map.get_name())
+        {
+            Some(genome)=>genome,
+            None=>continue
+        };
+        let (mutations1,mutations2)=int_map.get_mutations_ref();
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+        for (haplotype,mutations,seq_tape) in [(1,mutations1,seq_tape1),(2,mutations2,seq_tape2)]
+        {
+            for alt_transcript in mutations.iter()
+            {
+                if alt_transcript.alts.is_empty() || transcripts.map_or(false,|transcripts|!transcripts.contains(&alt_transcript.name.as_str()))
+                {
+                    continue
+                }
+                let reference=match ref_seq.get(&alt_transcript.name)
+                {
+                    Some(reference)=>reference,
+                    None=>continue
+                };
+                let expected=match apply_mutations_naive(reference,&alt_transcript.alts)
+                {
+                    Ok(expected)=>expected,
+                    Err(err_msg)=>
+                    {
+                        report.skipped.push((int_map.get_name().clone(),haplotype,alt_transcript.name.clone(),err_msg));
+                        continue
+                    }
+                };
+                let observed=seq_tape.get_seq(&alt_transcript.name).unwrap_or(reference.as_str());
+                report.num_compared+=1;
+                if observed!=expected
+                {
+                    report.mismatches.push(SequenceMismatch{proband_name:int_map.get_name().clone(),haplotype,transcript:alt_transcript.name.clone(),
+                        expected,observed:observed.to_string()});
+                }
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod test_verify
+{
+    use super::*;
+    use std::sync::Arc;
+    fn get_mutation(mut_type:&str, aa_field:&str)->Mutation
+    {
+        Mutation::from_csq_fields(mut_type,Arc::from("T1"),aa_field).unwrap()
+    }
+    #[test]
+    fn test_apply_mutations_naive()
+    {
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[]).unwrap(),"MKALLPWV");
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("inframe_insertion","3A>3AGG")]).unwrap(),"MKAGGLLPWV");
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("frameshift","5LPWV>5RS*")]).unwrap(),"MKALRS");
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("stop_lost","9*>9KLQ*")]).unwrap(),"MKALLPWVKLQ");
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("frameshift","6PWV*>6RSTQ")]).unwrap(),"MKALLRSTQ");
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("missense","2K>2R"),get_mutation("start_lost","1M>1I")]).unwrap(),"");
+        // mutations downstream of a truncation are ignored
+        assert_eq!(apply_mutations_naive("MKALLPWV",&[get_mutation("*missense","7W>5R"),get_mutation("stop_gained","3A>3*")]).unwrap(),"MK");
+        assert!(apply_mutations_naive("MKALLPWV",&[get_mutation("inframe_deletion","4LL>4L"),get_mutation("missense","5L>5R")]).is_err());
+        assert!(apply_mutations_naive("MKA",&[get_mutation("missense","5L>5R")]).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+/// # Project Description 
+///  The crate contains all the function and modules that were utilized to build PPGG (https://github.com/ikmb/ppg)
+/// The crate is composite of 5 main modules:
+/// 1. Readers which provide a collection of function for reading Fasta and VCF files 
+/// 2. Data_structures which is the major engine of the crate, the different data structures provides 
+///    a Wide array of struct to abstract and simplify the analysis of genetic data
+/// 3. Parts provides a high-level constructs that are build ontop of other parts of the library 
+/// 4. Functions which provide text parsing, summary statistics and validation functions used by the other modules 
+/// 5. Writers which provide a collection of function for writing the intermediate representations and the stats to the disk 
+///
+/// The failures of translating and executing the mutations of a transcript are returned as an error::PpggError instead of a panic.
+/// The crate is a pure library that does not depend on any command line parsing crate, the executable is provided by the vcf2prot-cli crate.
+/// The modules are gated by cargo features, i.e. parser, engine, stats, writers and cuda, which are all enabled by default, see the README
+/// for the minimal feature set of each use case.
+/// The types and the modules that are needed for running the pipeline are re-exported at the root of the crate, i.e. the following is the 
+/// public API of the crate, while the modules above give access to the building blocks of the pipeline. The personalized proteomes can be 
+/// generated in memory from any reader with generate, see parts::api, or step by step as below, while C and C++ callers use the C ABI of
+/// the ffi module, which is gated by the opt-in ffi feature, and web pages use the wasm module, which is gated by the opt-in wasm feature.
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, Engine};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_api_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let genomes=exec::execute(vec_int_repr,Engine::ST,&ref_seq,false,&exec::ExecutionOptions::default());
+/// assert_eq!(genomes[0].get_proband_name(),"HG00096");
+///```
+#[cfg(feature = "parser")]
+pub mod readers;
+pub mod data_structures;
+#[cfg(feature = "engine")]
+pub mod parts; 
+pub mod functions;
+pub mod error;
+#[cfg(feature = "writers")]
+pub mod writers; 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "writers")]
+pub use parts::{io, demo};
+#[cfg(feature = "engine")]
+pub use parts::exec;
+#[cfg(all(feature = "engine", feature = "parser"))]
+pub use parts::api::{generate,Options,PersonalizedProteomes};
+pub use data_structures::FastaFile::FastaFile;
+#[cfg(feature = "engine")]
+pub use functions::verify;
+pub use data_structures::InternalRep::engines::Engine;
+pub use error::PpggError;
+#[cfg(feature = "engine")]
+pub use data_structures::InternalRep::personalized_genome::{PersonalizedGenome,HaplotypeSequences};
+#[cfg(feature = "engine")]
+pub use data_structures::InternalRep::consensus::ConsensusRule;
+#[cfg(feature = "engine")]
+pub use data_structures::InternalRep::gpu_config::GpuConfig;
+pub use data_structures::Map::IntMap;
+pub use data_structures::vcf_ds::AltTranscript;
+pub use data_structures::mutation_ds::{Mutation,MutationInfo,MutationType,MutatedString};
+#[cfg(feature = "parser")]
+pub use data_structures::csq_map::CsqMap;
+
+
@@ -0,0 +1,211 @@
+/// The module provides the high-level API of the library, i.e. a VCF file is read from any reader, e.g. an in-memory buffer, and the
+/// personalized proteomes of its samples are returned as in-memory structures, hence, other tools and language bindings can embed the
+/// pipeline without shelling out to the executable and without reading back fasta files, see generate.
+use std::collections::HashMap;
+use std::io::Read;
+use std::str::FromStr;
+#[cfg(feature = "stats")]
+use std::sync::Arc;
+use crate::data_structures::FastaFile::FastaFile;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::InternalRep::gir::PlaceholderPolicy;
+use crate::data_structures::InternalRep::transcript_instructions::OverlapPolicy;
+use crate::data_structures::transcript_filter::TranscriptFilter;
+use crate::data_structures::vcf_ds::{HaplotypeSource,MultiAllelicPolicy,UnphasedPolicy};
+use crate::data_structures::InternalRep::personalized_genome::{PersonalizedGenome,HaplotypeSequences};
+use crate::data_structures::csq_map::CsqMap;
+use crate::error::PpggError;
+use crate::functions::contigs::ContigPolicy;
+use crate::functions::diagnostics::CheckPolicy;
+use crate::functions::duplicate_records::DuplicateRecordSummary;
+use crate::functions::missing_genotypes::{MissingGenotypePolicy,MissingGenotypeSummary};
+use crate::functions::sample_selection::SampleSelection;
+use crate::functions::silent_variants::SilentVariantPolicy;
+use crate::functions::vcf_tools::{self,DecodingSummary};
+#[cfg(feature = "stats")]
+use crate::functions::metrics::{MetricsAccumulator,MetricsSnapshot,NUM_SHARDS};
+use crate::parts::exec::{self,ExecutionFailure,ExecutionOptions};
+use crate::readers;
+
+/// ## Summary
+/// The options of generate, i.e. the execution engine, Engine::ST by default so the calling process keeps control of its threads, an
+/// optional map translating the consequence types, see CsqMap, the policies for the records of non-primary contigs and for the missing
+/// genotype calls, the remaining options of the parse, see readers::ParseOptions, whether the applied variants are projected onto the 
+/// personalized sequences, see PersonalizedGenome::get_applied_changes, and the options of the execution, see exec::ExecutionOptions.
+/// Every other setting of the command line, e.g. the transcript filters or the policies of the checks, is carried by the parse or the 
+/// execution options, hence, two calls in the same process can use different settings, while the options of the writers do not apply 
+/// as nothing is written.
+#[derive(Debug,Clone)]
+pub struct Options
+{
+    pub engine:Engine,
+    pub csq_map:Option<CsqMap>,
+    pub contig_policy:ContigPolicy,
+    pub missing_genotype_policy:MissingGenotypePolicy,
+    pub parse_options:readers::ParseOptions,
+    pub compute_features:bool,
+    pub execution_options:ExecutionOptions
+}
+impl Default for Options
+{
+    fn default()->Self
+    {
+        Options{engine:Engine::ST,csq_map:None,contig_policy:ContigPolicy::default(),missing_genotype_policy:MissingGenotypePolicy::default(),
+            parse_options:readers::ParseOptions::default(),compute_features:false,execution_options:ExecutionOptions::default()}
+    }
+}
+impl Options
+{
+    /// ## Summary
+    /// Set a named setting, i.e. overlap_policy, checks, placeholder_residue, strict_placeholder_check, unphased, multi_allelic,
+    /// haplotype_source or silent_variants, whose values are the ones of the flags of the command line, samples, a comma separated list of
+    /// sample names, or transcripts, a comma separated list of the transcripts to personalize. An error is returned if the name is not
+    /// supported or the value is not valid. Used by the C and the wasm entry points, which receive the settings as strings.
+    /// ## Example
+    ///```rust
+    /// let mut options=ppgg::Options::default();
+    /// options.set("overlap_policy","drop-transcript").unwrap();
+    /// assert!(options.set("checks","sometimes").is_err());
+    ///```
+    pub fn set(&mut self, name:&str, value:&str)->Result<(),String>
+    {
+        let parse_options=&mut self.parse_options;
+        let execution_options=&mut self.execution_options;
+        match name
+        {
+            "overlap_policy"=>execution_options.overlap_policy=Some(OverlapPolicy::from_str(value)?),
+            "checks"=>execution_options.diagnostic_options.check_policy=CheckPolicy::from_str(value)?,
+            "placeholder_residue"=>
+            {
+                let mut residues=value.chars();
+                let residue=match (residues.next(),residues.next())
+                {
+                    (Some(residue),None)=>residue,
+                    _=>return Err(format!("The provided placeholder residue: {} is not a single character",value))
+                };
+                execution_options.placeholder_policy=PlaceholderPolicy::new(residue, execution_options.placeholder_policy.is_strict)?;
+            },
+            "strict_placeholder_check"=>execution_options.placeholder_policy.is_strict=match value
+            {
+                "1" | "true"=>true,
+                "0" | "false"=>false,
+                _=>return Err(format!("{} is not a valid value of strict_placeholder_check, the supported values are 1, true, 0 and false",value))
+            },
+            "unphased"=>parse_options.decoding_options.unphased_policy=UnphasedPolicy::from_str(value)?,
+            "multi_allelic"=>parse_options.decoding_options.multi_allelic_policy=MultiAllelicPolicy::from_str(value)?,
+            "haplotype_source"=>parse_options.decoding_options.haplotype_source=HaplotypeSource::from_str(value)?,
+            "silent_variants"=>parse_options.silent_variant_policy=SilentVariantPolicy::from_str(value)?,
+            "samples"=>parse_options.sample_selection=Some(SampleSelection::from_list(value)?),
+            "transcripts"=>parse_options.transcript_filter=TranscriptFilter::new(value.split(',').map(|transcript|transcript.trim().to_string())
+                .filter(|transcript|!transcript.is_empty()).collect(),Vec::new(),Vec::new(),Vec::new()),
+            _=>return Err(format!("{} is not a supported setting, the supported settings are: overlap_policy, checks, placeholder_residue, \
+                strict_placeholder_check, unphased, multi_allelic, haplotype_source, silent_variants, samples and transcripts",name))
+        }
+        Ok(())
+    }
+}
+
+/// ## Summary
+/// The personalized proteomes of the samples of a VCF file, i.e. a personalized genome per sample, in the order of the VCF header, along
+/// with the transcripts and the samples that could not be personalized, see exec::execute_with_recovery, the summaries of the missing
+/// genotype calls, of the collapsed duplicated records and of the decoding, the annotations collected from the records, e.g. the silent 
+/// variants, see readers::RecordAnnotations, and with the stats feature the metrics of the call, see metrics::MetricsSnapshot. All of them 
+/// are collected for the call only, i.e. nothing is carried over from a previous call 
+#[derive(Debug,Clone)]
+pub struct PersonalizedProteomes
+{
+    pub genomes:Vec<PersonalizedGenome>,
+    pub failures:Vec<ExecutionFailure>,
+    pub missing_genotypes:MissingGenotypeSummary,
+    pub duplicate_records:DuplicateRecordSummary,
+    pub decoding_summary:DecodingSummary,
+    pub annotations:readers::RecordAnnotations,
+    #[cfg(feature = "stats")]
+    pub metrics:MetricsSnapshot
+}
+impl PersonalizedProteomes
+{
+    /// ## Summary
+    /// Return the names of the personalized samples, in the order of the VCF header
+    pub fn get_proband_names(&self)->Vec<&str>
+    {
+        self.genomes.iter().map(|genome|genome.get_proband_name().as_str()).collect()
+    }
+    /// ## Summary
+    /// Return the personalized genome of a sample, None if the sample is not part of the file or could not be personalized
+    pub fn get_genome(&self, proband_name:&str)->Option<&PersonalizedGenome>
+    {
+        self.genomes.iter().find(|genome|genome.get_proband_name()==proband_name)
+    }
+    /// ## Summary
+    /// Return the sequences of both haplotypes of the altered transcripts of each sample keyed by the sample name, where an unaltered
+    /// haplotype has the reference sequence, see PersonalizedGenome::to_haplotype_sequences
+    pub fn to_haplotype_sequences(&self, reference:&FastaFile)->HashMap<String,HaplotypeSequences>
+    {
+        self.genomes.iter()
+            .map(|genome|(genome.get_proband_name().clone(),genome.to_haplotype_sequences(reference.get_records())))
+            .collect()
+    }
+}
+
+/// ## Summary
+/// Generate the personalized proteomes of the samples of a VCF file annotated with bcftools csq or Ensembl VEP, which is read from the
+/// provided reader, a gzipped or bgzipped stream is decompressed while it is read, using the reference proteome and the options. The
+/// transcripts whose mutations can not be executed are skipped and reported instead of stopping the generation, see PersonalizedProteomes,
+/// while an error is returned if the file can not be read or parsed. Nothing is written to the disk. With the stats feature the metrics are 
+/// recorded in the accumulator of the execution options, or in an accumulator created for the call if none is provided. 
+/// ## Example
+///```rust
+/// use ppgg::{generate,Options,Engine};
+/// use ppgg::readers::read_fasta_from_reader;
+/// let reference=read_fasta_from_reader(ppgg::demo::DEMO_REFERENCE.as_bytes(),Engine::ST).unwrap();
+/// let proteomes=generate(ppgg::demo::DEMO_VCF.as_bytes(),&reference,Options::default()).unwrap();
+/// assert_eq!(proteomes.get_proband_names(),vec!["HG00096"]);
+/// let sequences=proteomes.to_haplotype_sequences(&reference);
+/// assert!(!sequences["HG00096"].is_empty());
+///```
+pub fn generate<R:Read>(vcf:R, reference:&FastaFile, mut options:Options)->Result<PersonalizedProteomes,PpggError>
+{
+    let (probands,records,missing_genotypes,duplicate_records,annotations)=readers::read_vcf_from_reader(vcf, options.engine.clone(), 
+        options.csq_map.as_ref(), options.contig_policy, options.missing_genotype_policy, &options.parse_options).map_err(PpggError::ParseFailure)?;
+    let (vec_int_repr,decoding_summary)=vcf_tools::get_int_maps(probands, records, options.engine.clone());
+    #[cfg(feature = "stats")]
+    let metrics=options.execution_options.metrics.get_or_insert_with(||Arc::new(MetricsAccumulator::new(NUM_SHARDS))).clone();
+    #[cfg(feature = "stats")]
+    metrics.record_parse(&vec_int_repr, &decoding_summary);
+    let (genomes,failures)=exec::execute_with_recovery(vec_int_repr, options.engine, reference.get_records(), options.compute_features, 
+        &options.execution_options);
+    Ok(PersonalizedProteomes{genomes,failures,missing_genotypes,duplicate_records,decoding_summary,annotations,
+        #[cfg(feature = "stats")]
+        metrics:metrics.snapshot()})
+}
+
+#[cfg(test)]
+mod test_api
+{
+    use super::*;
+    #[test]
+    fn test_generate_matches_parse_and_execute()
+    {
+        let reference=readers::read_fasta_from_reader(crate::parts::demo::DEMO_REFERENCE.as_bytes(),Engine::ST).unwrap();
+        let options=Options{compute_features:true,..Options::default()};
+        let proteomes=generate(crate::parts::demo::DEMO_VCF.as_bytes(),&reference,options).unwrap();
+        let path2vcf=std::env::temp_dir().join("vcf2prot_api_test.vcf");
+        std::fs::write(&path2vcf,crate::parts::demo::DEMO_VCF).unwrap();
+        let vec_int_repr=crate::parts::io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+        let expected=exec::execute(vec_int_repr,Engine::ST,reference.get_records(),true,&ExecutionOptions::default());
+        assert_eq!(proteomes.genomes.len(),expected.len());
+        let genome=proteomes.get_genome("HG00096").unwrap();
+        assert_eq!(genome.to_haplotype_sequences(reference.get_records()),expected[0].to_haplotype_sequences(reference.get_records()));
+        assert!(!genome.get_applied_changes(reference.get_records()).is_empty());
+        assert!(proteomes.get_genome("HG00097").is_none());
+        assert!(matches!(generate(std::io::empty(),&reference,Options::default()),Err(PpggError::ParseFailure(_))));
+        // the metrics of a call are not carried over to the next one 
+        #[cfg(feature = "stats")]
+        {
+            let again=generate(crate::parts::demo::DEMO_VCF.as_bytes(),&reference,Options::default()).unwrap();
+            assert_eq!(again.metrics,proteomes.metrics);
+            assert_eq!(again.metrics.probands.len(),1);
+        }
+    }
+}
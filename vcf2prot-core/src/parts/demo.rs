@@ -0,0 +1,163 @@
+// load the libraries and crates
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::parts::{io, exec};
+use crate::writers::{self,WriterOptions};
+
+/// ## Summary
+/// A small VCF file that is bundled with the crate, it contains a subset of the consequence-called records of sample HG00096
+/// from the examples directory covering the different mutation types supported by the tool
+pub const DEMO_VCF:&str=include_str!("../../data/demo/demo.vcf");
+/// ## Summary
+/// The reference sequences of all transcripts that are referenced by the records of the demo VCF file
+pub const DEMO_REFERENCE:&str=include_str!("../../data/demo/demo_reference.fasta");
+
+/// ## Summary
+/// Run the complete pipeline on the bundled demo data, i.e. parsing the VCF, computing the stats, generating the personalized
+/// proteomes and writing the results. If no output directory is provided a new directory is created inside the system temp directory.
+/// The demo is always executed using the single-thread engine as the multi-threaded engines are designed for full-sized VCF files.
+/// Returns the path to the directory containing the demo input and the generated results.
+/// ## Example
+///```rust
+/// use ppgg::parts::demo::{run_demo, print_walkthrough};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_demo_doc_test");
+/// let res_path=run_demo(Some(&output_dir)).unwrap();
+/// assert!(res_path.join("HG00096.fasta").exists());
+/// print_walkthrough(&res_path).unwrap();
+///```
+pub fn run_demo(output_dir:Option<&Path>)->Result<PathBuf,String>
+{
+    let res_path=match output_dir
+    {
+        Some(path)=>path.to_path_buf(),
+        None=>
+        {
+            let timestamp=SystemTime::now().duration_since(UNIX_EPOCH).map(|duration|duration.as_nanos()).unwrap_or(0); 
+            std::env::temp_dir().join(format!("vcf2prot_demo_{}",timestamp))
+        }
+    };
+    let input_path=res_path.join("input");
+    match fs::create_dir_all(&input_path)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Creating the demo directory: {:?} failed with the following error: {}",input_path,err_msg))
+    };
+    // write the bundled data to the input directory
+    let path2vcf=input_path.join("demo.vcf");
+    let path2fasta=input_path.join("demo_reference.fasta");
+    match fs::write(&path2vcf,DEMO_VCF)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Writing the demo VCF file to: {:?} failed with the following error: {}",path2vcf,err_msg))
+    };
+    match fs::write(&path2fasta,DEMO_REFERENCE)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Writing the demo reference file to: {:?} failed with the following error: {}",path2fasta,err_msg))
+    };
+    // run the pipeline
+    let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None)?;
+    let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+    let options=WriterOptions::default();
+    io::compute_and_write_summary(&res_path, &vec_int_repr, &options);
+    io::compute_and_write_normalized_load(&res_path, &vec_int_repr, &ref_seq, &options);
+    let vec_per_genomes=exec::execute(vec_int_repr, Engine::ST, &ref_seq, true, &exec::ExecutionOptions::default());
+    let output_dir=match res_path.to_str()
+    {
+        Some(path)=>path.to_string(),
+        None=>return Err(format!("The demo directory: {:?} is not a valid UTF-8 path",res_path))
+    };
+    io::write_variant_features(&vec_per_genomes, Engine::ST, &output_dir, &options);
+    let (num_dropped,_)=io::write_personalized_genomes(vec_per_genomes, Engine::ST, output_dir, true, false, false, &ref_seq, 0, &[], &options);
+    match writers::write_number_of_dropped_sequences_per_proband(&res_path, num_dropped, &options)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Writing the number of dropped sequences failed with the following error: {}",err_msg))
+    };
+    Ok(res_path)
+}
+
+/// ## Summary
+/// Print a walkthrough of the files produced by the demo run, i.e. the name, the size and a short description of each file
+/// followed by a preview of its content
+pub fn print_walkthrough(res_path:&Path)->Result<(),String>
+{
+    let mut files=match fs::read_dir(res_path)
+    {
+        Ok(entries)=>entries.filter_map(|entry|entry.ok())
+                    .map(|entry|entry.path())
+                    .filter(|path|path.is_file())
+                    .collect::<Vec<PathBuf>>(),
+        Err(err_msg)=>return Err(format!("Reading the demo directory: {:?} failed with the following error: {}",res_path,err_msg))
+    };
+    files.sort();
+    println!("The demo input and results have been written to: {:?}",res_path);
+    println!("The input VCF and reference files are located at: {:?}",res_path.join("input"));
+    println!("The following {} files have been generated:",files.len());
+    for (index,path) in files.iter().enumerate()
+    {
+        let file_name=path.file_name().unwrap().to_str().unwrap_or("").to_string();
+        let content=match fs::read_to_string(path)
+        {
+            Ok(content)=>content,
+            Err(err_msg)=>return Err(format!("Reading the generated file: {:?} failed with the following error: {}",path,err_msg))
+        };
+        println!("\n{}. {} ({} bytes)",index+1,file_name,content.len());
+        println!("   {}",describe_file(&file_name));
+        if file_name.ends_with(".fasta")
+        {
+            let num_seq=content.lines().filter(|line|line.starts_with('>')).count();
+            println!("   The file contains {} sequences, the first record is:",num_seq);
+            for line in content.lines().take(2)
+            {
+                let end=line.char_indices().nth(80).map(|(idx,_)|idx).unwrap_or(line.len());
+                println!("   {}{}",&line[..end],if end<line.len() {"..."} else {""});
+            }
+        }
+        else
+        {
+            println!("   The first lines of the file are:");
+            for line in content.lines().take(4)
+            {
+                println!("   {}",line);
+            }
+        }
+    }
+    println!("\nTo run Vcf2prot on your own data, check: vcf2prot --help");
+    Ok(())
+}
+
+/// ## Summary
+/// Return a short description for one of the files written by the demo run
+fn describe_file(file_name:&str)->&'static str
+{
+    match file_name
+    {
+        "number_of_mutations_per_proband.tsv"=>"The total number of mutations observed in each sample.",
+        "type_of_mutations_per_patient.tsv"=>"The number of mutations per sample stratified by the type of the mutation, e.g. missense or frameshift.",
+        "number_of_mutations_per_transcript.tsv"=>"The number of mutations observed in each transcript across all samples.",
+        "normalized_mutation_load_per_proband.tsv"=>"The number of edits per sample normalized by the number of callable transcripts and by the proteome length.",
+        "number_of_dropped_sequences_per_proband.tsv"=>"The number of sequences per sample that were shorter than the minimum length and were not written.",
+        name if name.ends_with(".gff3")=>"The applied variants of a sample marked on the personalized sequences, it can be loaded along with the fasta file into protein viewers.",
+        name if name.ends_with(".fasta")=>"The personalized proteome of a sample, each altered transcript is written once per haplotype, e.g. ID_1 and ID_2.",
+        _=>"An auxiliary file generated by the demo run."
+    }
+}
+
+#[cfg(test)]
+mod test_demo
+{
+    use super::*;
+    #[test]
+    fn test_run_demo()
+    {
+        let output_dir=std::env::temp_dir().join("vcf2prot_demo_unit_test");
+        let res_path=run_demo(Some(&output_dir)).unwrap();
+        let fasta=fs::read_to_string(res_path.join("HG00096.fasta")).unwrap();
+        assert!(fasta.lines().filter(|line|line.starts_with('>')).count()>0);
+        assert!(res_path.join("number_of_mutations_per_proband.tsv").exists());
+        assert!(print_walkthrough(&res_path).is_ok());
+    }
+}
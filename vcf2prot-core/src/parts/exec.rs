@@ -0,0 +1,702 @@
+use std::collections::HashMap;
+use std::any::Any; 
+use std::panic::{self, AssertUnwindSafe}; 
+use std::sync::Arc; 
+#[cfg(feature = "stats")]
+use crate::functions::summary::*; 
+#[cfg(feature = "stats")]
+use crate::functions::metrics::{ExecutedAlterations,MetricsAccumulator}; 
+use crate::data_structures::InternalRep::engines::Engine; 
+use crate::data_structures::Map::IntMap; 
+use crate::data_structures::InternalRep::proband_instructions::ProbandInstruction; 
+use crate::data_structures::InternalRep::haplotype_instruction::HaplotypeInstruction; 
+use crate::data_structures::InternalRep::transcript_instructions::{OverlapPolicy,OverlapSummary,TranscriptInstruction}; 
+use crate::data_structures::InternalRep::schedule::inner_min_len; 
+use crate::data_structures::vcf_ds::AltTranscript; 
+use crate::error::PpggError; 
+use crate::functions::progress::ProgressTracker; 
+use crate::functions::diagnostics::DiagnosticOptions; 
+use crate::data_structures::InternalRep::personalized_genome::{PersonalizedGenome,HaplotypeSequences}; 
+use crate::data_structures::InternalRep::schedule::Schedule; 
+use crate::data_structures::InternalRep::transcript_trace::TranscriptTrace; 
+use crate::data_structures::InternalRep::gir::PlaceholderPolicy; 
+use rayon::prelude::*; 
+#[cfg(feature = "stats")]
+use crossbeam::thread; 
+#[cfg(feature = "engine")]
+use crossbeam::channel::Sender; 
+
+// drive the public functions 
+//---------------------------
+/// ## Summary 
+/// The options of the execution layer, which are passed to every execution instead of being set for the whole process, hence, two runs 
+/// of the library in the same process, e.g. two requests of a server, can personalize their proteomes with different options. The 
+/// overlapping mutations of a transcript are resolved with the overlap policy, if any, and the resolutions of each proband are returned 
+/// with its personalized genome, see PersonalizedGenome::get_overlap_summary. The translations and the tasks are checked with the 
+/// diagnostics, see diagnostics::DiagnosticOptions, the executed probands are counted in the tracker of the run, if any, see 
+/// progress::ProgressTracker, and with the stats feature the metrics of each executed proband are recorded in the accumulator of the run, 
+/// if any, see metrics::MetricsAccumulator 
+/// ## Example
+///```rust
+/// use ppgg::exec::ExecutionOptions;
+/// use ppgg::data_structures::InternalRep::gir::PlaceholderPolicy;
+/// let options=ExecutionOptions{placeholder_policy:PlaceholderPolicy::new('#',false).unwrap(),..ExecutionOptions::default()};
+/// assert!(!options.placeholder_policy.is_strict && options.overlap_policy.is_none());
+///```
+#[derive(Debug,Clone,Default)]
+pub struct ExecutionOptions
+{
+    pub placeholder_policy:PlaceholderPolicy,
+    pub overlap_policy:Option<OverlapPolicy>,
+    pub diagnostic_options:DiagnosticOptions,
+    pub progress:Option<Arc<ProgressTracker>>,
+    #[cfg(feature = "stats")]
+    pub metrics:Option<Arc<MetricsAccumulator>>
+}
+/// ##Summary 
+/// A summary for the generated results. It contains three fields: 
+#[cfg(feature = "stats")]
+#[derive(Debug,Clone)]
+pub struct StatSummary
+{
+    pub num_mutation_per_proband:HashMap<String,u64>,
+    pub type_mutation_per_proband:HashMap<String,Vec<u64>>,
+    pub number_of_mutations_per_transcript:HashMap<String,u64>,
+}
+/// ## Summary 
+/// The executioner for computing and generating a personalized proteome per patient, if compute_features is set, the applied variants are 
+/// projected onto the personalized sequences and are stored with each personalized genome so they can be written as GFF3 files.
+/// With a multi-threaded engine the work is scheduled on two levels, i.e. over the samples and over the transcripts of each sample, 
+/// where the schedule is adapted to the number of samples and the number of altered transcripts per sample, see Schedule for more details. 
+/// The representations can be parsed from a VCF file, see io::parse_vcf, or built programmatically, see IntMap::validate. 
+/// With the stats feature the metrics of each executed proband are recorded in the accumulator of the options, see ExecutionOptions. 
+pub fn execute(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    options:&ExecutionOptions)->Vec<PersonalizedGenome>
+{
+    let workload=vec_int_repr.iter()
+        .map(|int_map|{let (mut1,mut2)=int_map.get_mutations_ref(); mut1.len()+mut2.len()})
+        .collect::<Vec<usize>>(); 
+    let schedule=Schedule::adaptive(&workload, rayon::current_num_threads(), &exec_engine); 
+    let inner_engine=schedule.inner_engine.clone(); 
+    match schedule.outer_engine
+    {
+        Engine::ST=>
+        {
+            vec_int_repr.into_iter()
+            .map(|proband_map|execute_proband(proband_map,inner_engine.clone(),ref_seq,compute_features,options))
+            .collect::<Vec<PersonalizedGenome>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto =>
+        {
+            vec_int_repr.into_par_iter()
+            .with_min_len(schedule.outer_min_len)
+            .map(|proband_map|execute_proband(proband_map,inner_engine.clone(),ref_seq,compute_features,options))
+            .collect::<Vec<PersonalizedGenome>>()
+        }
+    }
+}
+/// ## Summary 
+/// Similar to execute, however, each personalized genome is sent through the provided channel, along with the index of its representation, 
+/// as soon as it has been generated instead of being collected. With a bounded channel the execution is hence throttled by the receivers, 
+/// i.e. it blocks once the channel is full until a receiver takes a genome, see io::execute_and_write_pipelined. The sender is dropped once 
+/// all representations have been executed, which disconnects the channel, and the execution stops early if all receivers have been dropped. 
+#[cfg(feature = "engine")]
+pub fn execute_to_channel(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    sender:Sender<(usize,PersonalizedGenome)>, options:&ExecutionOptions)
+{
+    let workload=vec_int_repr.iter()
+        .map(|int_map|{let (mut1,mut2)=int_map.get_mutations_ref(); mut1.len()+mut2.len()})
+        .collect::<Vec<usize>>(); 
+    let schedule=Schedule::adaptive(&workload, rayon::current_num_threads(), &exec_engine); 
+    let inner_engine=schedule.inner_engine.clone(); 
+    // a failed send means that all receivers are gone, hence, the rest of the representations is not executed 
+    let _=match schedule.outer_engine
+    {
+        Engine::ST=>
+        {
+            vec_int_repr.into_iter()
+            .enumerate()
+            .try_for_each(|(index,proband_map)|sender.send((index,execute_proband(proband_map,inner_engine.clone(),ref_seq,compute_features,options))).ok())
+        },
+        Engine::MT | Engine::GPU | Engine::Auto =>
+        {
+            vec_int_repr.into_par_iter()
+            .enumerate()
+            .with_min_len(schedule.outer_min_len)
+            .try_for_each_with(sender,|sender,(index,proband_map)|sender.send((index,execute_proband(proband_map,inner_engine.clone(),ref_seq,compute_features,options))).ok())
+        }
+    }; 
+}
+/// ## Summary 
+/// A transcript, or a whole proband, that was skipped while executing in the recovery mode, see execute_with_recovery, where the haplotype 
+/// and the transcript name are None if the whole proband failed 
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct ExecutionFailure
+{
+    pub proband_name:String,
+    pub haplotype:Option<u8>,
+    pub transcript_name:Option<String>,
+    pub reason:String
+}
+/// ## Summary 
+/// Similar to execute, however, a failure while generating the instructions of a transcript, or while executing its tasks, is caught, i.e. 
+/// errors and panics, the failing transcript is dropped from its haplotype and the rest of the proteome is generated. The failures, along 
+/// with the transcripts that were skipped while generating the tasks, are returned next to the personalized genomes in the order of the 
+/// IntMaps, a proband that still fails without its failing transcripts is dropped and reported as a whole. 
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, Engine};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_execute_with_recovery_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let (genomes,failures)=exec::execute_with_recovery(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&exec::ExecutionOptions::default());
+/// assert_eq!(genomes.len()+failures.iter().filter(|failure|failure.haplotype.is_none()).count(),vec_int_repr.len());
+///```
+pub fn execute_with_recovery(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    options:&ExecutionOptions)->(Vec<PersonalizedGenome>,Vec<ExecutionFailure>)
+{
+    let workload=vec_int_repr.iter()
+        .map(|int_map|{let (mut1,mut2)=int_map.get_mutations_ref(); mut1.len()+mut2.len()})
+        .collect::<Vec<usize>>(); 
+    let schedule=Schedule::adaptive(&workload, rayon::current_num_threads(), &exec_engine); 
+    let inner_engine=schedule.inner_engine.clone(); 
+    let results=match schedule.outer_engine
+    {
+        Engine::ST=>
+        {
+            vec_int_repr.into_iter()
+            .map(|proband_map|execute_proband_with_recovery(proband_map,inner_engine.clone(),ref_seq,compute_features,options))
+            .collect::<Vec<_>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto =>
+        {
+            vec_int_repr.into_par_iter()
+            .with_min_len(schedule.outer_min_len)
+            .map(|proband_map|execute_proband_with_recovery(proband_map,inner_engine.clone(),ref_seq,compute_features,options))
+            .collect::<Vec<_>>()
+        }
+    }; 
+    let mut genomes=Vec::with_capacity(results.len()); 
+    let mut failures=Vec::new(); 
+    for (genome,proband_failures) in results
+    {
+        genomes.extend(genome); 
+        failures.extend(proband_failures); 
+    }
+    (genomes,failures)
+}
+/// ## Summary 
+/// The number of sequences the instructions of a proband generate in each haplotype, i.e. the number of records of its personalized fasta 
+/// file without the reference sequences 
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SequenceCount
+{
+    pub proband_name:String,
+    pub num_haplotype1:usize,
+    pub num_haplotype2:usize
+}
+impl SequenceCount
+{
+    /// ## Summary 
+    /// Return the number of sequences generated in both haplotypes 
+    pub fn total(&self)->usize
+    {
+        self.num_haplotype1+self.num_haplotype2
+    }
+}
+/// ## Summary 
+/// Generate the instructions of each proband, similar to execute, and count the sequences they would generate without executing them, 
+/// which is considerably faster than execute and does not allocate the personalized sequences, the counts are returned in the order of the IntMaps. 
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, Engine};
+/// use ppgg::exec::ExecutionOptions;
+/// let output_dir=std::env::temp_dir().join("vcf2prot_count_sequences_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let counts=exec::count_sequences(vec_int_repr,Engine::ST,&ref_seq,&ExecutionOptions::default());
+/// print!("{}",exec::sequence_counts_to_report(&counts));
+///```
+pub fn count_sequences(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, options:&ExecutionOptions)->Vec<SequenceCount>
+{
+    let workload=vec_int_repr.iter()
+        .map(|int_map|{let (mut1,mut2)=int_map.get_mutations_ref(); mut1.len()+mut2.len()})
+        .collect::<Vec<usize>>(); 
+    let schedule=Schedule::adaptive(&workload, rayon::current_num_threads(), &exec_engine); 
+    let inner_engine=schedule.inner_engine.clone(); 
+    let count=|proband_map:ProbandInstruction|SequenceCount{num_haplotype1:proband_map.haplotype1_instruction.get_num_transcripts(),
+        num_haplotype2:proband_map.haplotype2_instruction.get_num_transcripts(),proband_name:proband_map.proband_name}; 
+    match schedule.outer_engine
+    {
+        Engine::ST=>
+        {
+            vec_int_repr.into_iter()
+            .map(|proband_map|ProbandInstruction::from_intmap_with_policies(proband_map, inner_engine.clone(),ref_seq,options.overlap_policy,&options.diagnostic_options).0)
+            .map(count)
+            .collect::<Vec<SequenceCount>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto =>
+        {
+            vec_int_repr.into_par_iter()
+            .with_min_len(schedule.outer_min_len)
+            .map(|proband_map|ProbandInstruction::from_intmap_with_policies(proband_map, inner_engine.clone(),ref_seq,options.overlap_policy,&options.diagnostic_options).0)
+            .map(count)
+            .collect::<Vec<SequenceCount>>()
+        }
+    }
+}
+/// ## Summary 
+/// Return a tab-separated report of the sequence counts, i.e. one line per proband with the number of sequences in each haplotype and 
+/// in total, followed by a line with the totals of the cohort 
+pub fn sequence_counts_to_report(counts:&[SequenceCount])->String
+{
+    let mut report=String::from("Proband Name\tHaplotype 1\tHaplotype 2\tTotal\n"); 
+    for count in counts.iter()
+    {
+        report.push_str(&format!("{}\t{}\t{}\t{}\n",count.proband_name,count.num_haplotype1,count.num_haplotype2,count.total())); 
+    }
+    let (num_haplotype1,num_haplotype2)=counts.iter().fold((0,0),|(acc1,acc2),count|(acc1+count.num_haplotype1,acc2+count.num_haplotype2)); 
+    report.push_str(&format!("Total\t{}\t{}\t{}\n",num_haplotype1,num_haplotype2,num_haplotype1+num_haplotype2)); 
+    report
+}
+/// ## Summary 
+/// Similar to execute, however, the personalized proteomes are returned as a nested map with proband names as keys and the sequences of 
+/// the two haplotypes of each altered transcript as values, i.e. sample -> transcript -> (haplotype 1 sequence, haplotype 2 sequence), 
+/// where the sequence of a transcript that is only altered in one haplotype is the reference sequence for the other haplotype. 
+/// Non-altered transcripts are not part of the map, see PersonalizedGenome::to_haplotype_sequences for more details. 
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, Engine};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_execute_to_map_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let sequences=exec::execute_to_map(vec_int_repr,Engine::ST,&ref_seq,&exec::ExecutionOptions::default());
+/// for (transcript,(hap1_seq,hap2_seq)) in sequences["HG00096"].iter()
+/// {
+///     assert!(ref_seq.contains_key(transcript));
+///     assert!(hap1_seq!=&ref_seq[transcript] || hap2_seq!=&ref_seq[transcript]);
+/// }
+///```
+pub fn execute_to_map(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, options:&ExecutionOptions)->HashMap<String,HaplotypeSequences>
+{
+    let vec_genomes=execute(vec_int_repr, exec_engine.clone(), ref_seq, false, options); 
+    match exec_engine
+    {
+        Engine::ST=>
+        {
+            vec_genomes.iter()
+            .map(|genome|(genome.get_proband_name().clone(),genome.to_haplotype_sequences(ref_seq)))
+            .collect::<HashMap<_,_>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto=>
+        {
+            vec_genomes.par_iter()
+            .map(|genome|(genome.get_proband_name().clone(),genome.to_haplotype_sequences(ref_seq)))
+            .collect::<HashMap<_,_>>()
+        }
+    }
+}
+/// ## Summary 
+/// Restrict the execution to a single transcript, i.e. drop the alterations of all other transcripts from the IntMaps and all other
+/// sequences from the reference, returns an error if the transcript is not in the reference 
+pub fn restrict_to_transcript(vec_int_repr:&mut [IntMap], ref_seq:&mut HashMap<String,String>, transcript_name:&str)->Result<(),String>
+{
+    if !ref_seq.contains_key(transcript_name)
+    {
+        return Err(format!("The provided transcript name: {} is not in the reference sequence", transcript_name));
+    }
+    ref_seq.retain(|name,_|name==transcript_name);
+    vec_int_repr.iter_mut().for_each(|int_map|int_map.retain_transcripts(|name|name==transcript_name));
+    Ok(())
+}
+/// ## Summary 
+/// Trace the derivation of a single transcript, i.e. its mutations, instructions, tasks and sequences, across all the probands 
+/// ## Example
+///```rust
+/// use ppgg::{io, exec, Engine};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_trace_transcript_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let transcript=vec_int_repr[0].get_mutations_ref().0[0].name.clone();
+/// let trace=exec::trace_transcript(&vec_int_repr,&transcript,&ref_seq,&exec::ExecutionOptions::default()).unwrap();
+/// println!("{}",trace.to_report());
+///```
+pub fn trace_transcript(vec_int_repr:&[IntMap], transcript_name:&str, ref_seq:&HashMap<String,String>, options:&ExecutionOptions)->Result<TranscriptTrace,String>
+{
+    TranscriptTrace::new(vec_int_repr, transcript_name, ref_seq, options.placeholder_policy.residue, options.overlap_policy, &options.diagnostic_options)
+}
+/// ## Summary 
+/// Generate the personalized genome of a proband, with the stats feature the executed alterations, i.e. the alterations of the transcripts 
+/// of the reference that were not skipped, and the execution report are recorded in the metrics accumulator of the options, if any 
+fn execute_proband(proband_map:IntMap, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, options:&ExecutionOptions)->PersonalizedGenome
+{
+    #[cfg(feature = "stats")]
+    let alterations=ExecutedAlterations::new(&proband_map, ref_seq); 
+    let (proband_instruction,overlap_summary)=ProbandInstruction::from_intmap_with_policies(proband_map, exec_engine.clone(), ref_seq, options.overlap_policy, &options.diagnostic_options); 
+    let mut genome=build_personalized_genome(proband_instruction, exec_engine, ref_seq, compute_features, options); 
+    genome.set_overlap_summary(overlap_summary); 
+    #[cfg(feature = "stats")]
+    if let Some(metrics)=options.metrics.as_ref()
+    {
+        metrics.record_executed(alterations, &genome); 
+    }
+    record_progress(&genome, options); 
+    genome
+}
+/// ## Summary 
+/// Count an executed proband and its translated transcripts in the progress tracker of the options, if any, see progress::ProgressTracker 
+fn record_progress(genome:&PersonalizedGenome, options:&ExecutionOptions)
+{
+    if let Some(progress)=options.progress.as_ref()
+    {
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes(); 
+        progress.add_translated_proband(seq_tape1.get_annotation().len()+seq_tape2.get_annotation().len()); 
+    }
+}
+/// ## Summary 
+/// Execute the instructions of a proband with or without projecting the applied variants onto the personalized sequences
+fn build_personalized_genome(proband_instruction:ProbandInstruction, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    options:&ExecutionOptions)->PersonalizedGenome
+{
+    match compute_features
+    {
+        true=>PersonalizedGenome::from_proband_instruction_with_features(proband_instruction, exec_engine, ref_seq, &options.placeholder_policy, &options.diagnostic_options),
+        false=>PersonalizedGenome::from_proband_instruction(proband_instruction, exec_engine, ref_seq, &options.placeholder_policy, &options.diagnostic_options)
+    }
+}
+/// ## Summary 
+/// Generate the personalized genome of a proband in the recovery mode, see execute_with_recovery, the proband is executed once with all 
+/// of its transcripts, only if this fails each transcript is executed on its own to find and drop the failing ones 
+fn execute_proband_with_recovery(proband_map:IntMap, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    options:&ExecutionOptions)->(Option<PersonalizedGenome>,Vec<ExecutionFailure>)
+{
+    #[cfg(feature = "stats")]
+    let alterations=ExecutedAlterations::new(&proband_map, ref_seq); 
+    let proband_name=proband_map.proband_name.clone(); 
+    let mut failures=Vec::new(); 
+    let (haplo1_vec,haplo2_vec)=proband_map.consume_and_get_vecs(); 
+    let (h1_ins,mut overlap_summary)=get_recoverable_instructions(haplo1_vec, &exec_engine, ref_seq, options); 
+    let (h2_ins,overlap_summary2)=get_recoverable_instructions(haplo2_vec, &exec_engine, ref_seq, options); 
+    overlap_summary.merge(&overlap_summary2); 
+    for &(haplotype,instructions) in [(1,&h1_ins),(2,&h2_ins)].iter()
+    {
+        for (transcript_name,reason) in instructions.iter().filter_map(|(name,res)|res.as_ref().err().map(|reason|(name,reason)))
+        {
+            failures.push(ExecutionFailure{proband_name:proband_name.clone(),haplotype:Some(haplotype),transcript_name:Some(transcript_name.clone()),reason:reason.clone()}); 
+        }
+    }
+    let proband_instruction=ProbandInstruction::new(proband_name.clone(), 
+        HaplotypeInstruction::new(h1_ins.into_iter().filter_map(|(_,res)|res.ok()).collect()),
+        HaplotypeInstruction::new(h2_ins.into_iter().filter_map(|(_,res)|res.ok()).collect())); 
+    let backup=proband_instruction.clone(); 
+    let genome=match catch_failure(||try_build_personalized_genome(proband_instruction, exec_engine.clone(), ref_seq, compute_features, options))
+    {
+        Ok(genome)=>Ok(genome),
+        Err(_)=>
+        {
+            // isolate the failing transcripts and retry without them 
+            let h1_ins=drop_failing_transcripts(backup.haplotype1_instruction.clone(), 1, &proband_name, ref_seq, options, &mut failures); 
+            let h2_ins=drop_failing_transcripts(backup.haplotype2_instruction.clone(), 2, &proband_name, ref_seq, options, &mut failures); 
+            catch_failure(||try_build_personalized_genome(ProbandInstruction::new(proband_name.clone(), h1_ins, h2_ins), exec_engine, ref_seq, compute_features, options))
+        }
+    }; 
+    match genome
+    {
+        Ok(mut genome)=>
+        {
+            genome.set_overlap_summary(overlap_summary); 
+            // the reasons of the transcripts that were skipped while generating the tasks 
+            let (report1,report2)=genome.get_execution_reports(); 
+            for &(haplotype,report) in [(1,report1),(2,report2)].iter()
+            {
+                for (transcript_name,reason) in report.skipped_transcripts.iter().zip(report.skip_reasons.iter())
+                {
+                    failures.push(ExecutionFailure{proband_name:proband_name.clone(),haplotype:Some(haplotype),transcript_name:Some(transcript_name.clone()),
+                        reason:reason.to_string()}); 
+                }
+            }
+            #[cfg(feature = "stats")]
+            if let Some(metrics)=options.metrics.as_ref()
+            {
+                metrics.record_executed(alterations, &genome); 
+            }
+            record_progress(&genome, options); 
+            (Some(genome),failures)
+        },
+        Err(reason)=>
+        {
+            failures.push(ExecutionFailure{proband_name,haplotype:None,transcript_name:None,reason}); 
+            (None,failures)
+        }
+    }
+}
+/// ## Summary
+/// The instruction of each altered transcript of a haplotype or the reason it could not be generated 
+type RecoverableInstructions=Vec<(String,Result<TranscriptInstruction,String>)>; 
+/// ## Summary 
+/// Generate the instructions of each altered transcript of a haplotype, catching errors and panics, transcripts that are not in the 
+/// reference or have no supported mutations are dropped without a failure, as they are dropped by HaplotypeInstruction::from_vec_t_ins, 
+/// the overlapping mutations are resolved with the overlap policy of the options, if any, and the resolutions are returned summed over 
+/// the transcripts 
+fn get_recoverable_instructions(alt_trans_vec:Vec<AltTranscript>, engine:&Engine, ref_seq:&HashMap<String,String>, 
+    options:&ExecutionOptions)->(RecoverableInstructions,OverlapSummary)
+{
+    let get_instruction=|alt_transcript:AltTranscript|
+    {
+        let transcript_name=alt_transcript.name.clone(); 
+        let mut summary=OverlapSummary::default(); 
+        let instruction=match catch_failure(||Ok(TranscriptInstruction::from_alt_transcript_with_policies(alt_transcript, ref_seq, options.overlap_policy, &options.diagnostic_options, &mut summary)))
+        {
+            Ok(Ok(ins))=>Some((transcript_name,Ok(ins))),
+            Ok(Err(PpggError::MissingTranscript(_))) | Ok(Err(PpggError::NoSupportedMutations{..}))=>None,
+            Ok(Err(err))=>Some((transcript_name,Err(err.to_string()))),
+            Err(reason)=>Some((transcript_name,Err(reason)))
+        }; 
+        (instruction,summary)
+    }; 
+    let results=match engine
+    {
+        Engine::ST=>alt_trans_vec.into_iter().map(get_instruction).collect::<Vec<_>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>
+        {
+            let min_len=inner_min_len(alt_trans_vec.len()); 
+            alt_trans_vec.into_par_iter().with_min_len(min_len).map(get_instruction).collect::<Vec<_>>()
+        }
+    }; 
+    let mut summary=OverlapSummary::default(); 
+    let instructions=results.into_iter()
+        .filter_map(|(instruction,transcript_summary)|
+        {
+            summary.merge(&transcript_summary); 
+            instruction
+        })
+        .collect(); 
+    (instructions,summary)
+}
+/// ## Summary 
+/// Execute each transcript of a haplotype on its own and return the haplotype without the transcripts that fail, the failing transcripts 
+/// are pushed into failures 
+fn drop_failing_transcripts(haplotype_instruction:HaplotypeInstruction, haplotype:u8, proband_name:&str, ref_seq:&HashMap<String,String>,
+    options:&ExecutionOptions, failures:&mut Vec<ExecutionFailure>)->HaplotypeInstruction
+{
+    let instructions=haplotype_instruction.consume_and_get_instructions().into_iter()
+        .filter(|ins|
+        {
+            match catch_failure(||ins.get_g_rep_with_checks(ref_seq,options.placeholder_policy.residue,&options.diagnostic_options)?.execute(Engine::ST))
+            {
+                Ok(_)=>true,
+                Err(reason)=>
+                {
+                    failures.push(ExecutionFailure{proband_name:proband_name.to_string(),haplotype:Some(haplotype),
+                        transcript_name:Some(ins.get_transcript_name().clone()),reason}); 
+                    false
+                }
+            }
+        })
+        .collect::<Vec<_>>(); 
+    HaplotypeInstruction::new(instructions)
+}
+/// ## Summary 
+/// Run an operation and return its error, or the message of its panic, as a string 
+fn catch_failure<T,F:FnOnce()->Result<T,PpggError>>(operation:F)->Result<T,String>
+{
+    match panic::catch_unwind(AssertUnwindSafe(operation))
+    {
+        Ok(Ok(res))=>Ok(res),
+        Ok(Err(err))=>Err(err.to_string()),
+        Err(payload)=>Err(get_panic_message(payload.as_ref()))
+    }
+}
+/// ## Summary 
+/// Return the message of a caught panic 
+fn get_panic_message(payload:&(dyn Any+Send))->String
+{
+    match (payload.downcast_ref::<&str>(),payload.downcast_ref::<String>())
+    {
+        (Some(msg),_)=>format!("panicked: {}",msg),
+        (_,Some(msg))=>format!("panicked: {}",msg),
+        _=>"panicked".to_string()
+    }
+}
+/// ## Summary 
+/// Similar to build_personalized_genome, however, a failure while executing one of the haplotypes is returned as an error 
+fn try_build_personalized_genome(proband_instruction:ProbandInstruction, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    options:&ExecutionOptions)->Result<PersonalizedGenome,PpggError>
+{
+    match compute_features
+    {
+        true=>PersonalizedGenome::try_from_proband_instruction_with_features(proband_instruction, exec_engine, ref_seq, &options.placeholder_policy, &options.diagnostic_options),
+        false=>PersonalizedGenome::try_from_proband_instruction(proband_instruction, exec_engine, ref_seq, &options.placeholder_policy, &options.diagnostic_options)
+    }
+}
+/// ## Summary 
+/// A function to compute the state from the vec_maps, it launches 3 threads to compute each metric on parallel
+#[cfg(feature = "stats")]
+pub fn compute_states(vec_maps:&Vec<IntMap>)->StatSummary
+{
+    thread::scope(|scope|
+    {
+        // launch the threads 
+        let mutation_per_proband=scope.spawn(|_|compute_number_mutation_per_proband(vec_maps)); 
+        let type_mutation_per_proband=scope.spawn(|_|compute_type_mutations_per_patient(vec_maps)); 
+        let number_mut_per_transcript=scope.spawn(|_|compute_number_of_mutations_per_transcript(vec_maps)); 
+        // wait for the results 
+        let mut_per_proband=mutation_per_proband.join().unwrap(); 
+        let type_mut_per_proband=type_mutation_per_proband.join().unwrap(); 
+        let number_mut_per_transcript=number_mut_per_transcript.join().unwrap(); 
+        // return the results 
+        StatSummary
+        {
+            num_mutation_per_proband:mut_per_proband,
+            type_mutation_per_proband:type_mut_per_proband,
+            number_of_mutations_per_transcript:number_mut_per_transcript,
+        }   
+    }).unwrap()
+}
+#[cfg(test)]
+mod test_exec
+{
+    use super::*;
+    use crate::parts::{demo,io};
+    fn get_demo_input(name:&str)->(Vec<IntMap>,HashMap<String,String>)
+    {
+        let output_dir=std::env::temp_dir().join(name);
+        std::fs::create_dir_all(&output_dir).unwrap();
+        let path2vcf=output_dir.join("demo.vcf");
+        let path2fasta=output_dir.join("demo_reference.fasta");
+        std::fs::write(&path2vcf,demo::DEMO_VCF).unwrap();
+        std::fs::write(&path2fasta,demo::DEMO_REFERENCE).unwrap();
+        (io::parse_vcf(&path2vcf,Engine::ST,None).unwrap(),io::read_fasta(&path2fasta,Engine::ST))
+    }
+    #[test]
+    fn test_execute_to_map()
+    {
+        let (vec_int_repr,ref_seq)=get_demo_input("vcf2prot_execute_to_map_unit_test");
+        let genomes=execute(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&ExecutionOptions::default());
+        let sequences=execute_to_map(vec_int_repr.clone(),Engine::ST,&ref_seq,&ExecutionOptions::default());
+        assert_eq!(sequences.len(),genomes.len());
+        for genome in genomes.iter()
+        {
+            let proband_sequences=&sequences[genome.get_proband_name()];
+            let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+            assert!(!proband_sequences.is_empty());
+            for (transcript,(hap1_seq,hap2_seq)) in proband_sequences.iter()
+            {
+                assert_eq!(hap1_seq.as_str(),seq_tape1.get_seq(transcript).unwrap_or(ref_seq[transcript].as_str()));
+                assert_eq!(hap2_seq.as_str(),seq_tape2.get_seq(transcript).unwrap_or(ref_seq[transcript].as_str()));
+            }
+        }
+        assert_eq!(execute_to_map(vec_int_repr,Engine::MT,&ref_seq,&ExecutionOptions::default()),sequences);
+    }
+    #[test]
+    fn test_count_sequences_matches_execution()
+    {
+        let (vec_int_repr,ref_seq)=get_demo_input("vcf2prot_count_sequences_unit_test");
+        let genomes=execute(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&ExecutionOptions::default());
+        let counts=count_sequences(vec_int_repr.clone(),Engine::ST,&ref_seq,&ExecutionOptions::default());
+        assert_eq!(counts.len(),genomes.len());
+        for (count,genome) in counts.iter().zip(genomes.iter())
+        {
+            let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+            assert_eq!(&count.proband_name,genome.get_proband_name());
+            assert_eq!(count.num_haplotype1,seq_tape1.get_annotation().len());
+            assert_eq!(count.num_haplotype2,seq_tape2.get_annotation().len());
+        }
+        assert_eq!(count_sequences(vec_int_repr,Engine::MT,&ref_seq,&ExecutionOptions::default()),counts);
+        let report=sequence_counts_to_report(&counts);
+        assert!(report.ends_with(&format!("Total\t{}\t{}\t{}\n",counts.iter().map(|count|count.num_haplotype1).sum::<usize>(),
+            counts.iter().map(|count|count.num_haplotype2).sum::<usize>(),counts.iter().map(|count|count.total()).sum::<usize>())));
+    }
+    #[test]
+    fn test_execute_to_channel()
+    {
+        let (vec_int_repr,ref_seq)=get_demo_input("vcf2prot_execute_to_channel_unit_test");
+        let genomes=execute(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&ExecutionOptions::default());
+        for engine in IntoIterator::into_iter([Engine::ST,Engine::MT])
+        {
+            // a channel of capacity one throttles the execution to the pace of the receiver 
+            let (sender,receiver)=crossbeam::channel::bounded(1);
+            let mut received=crossbeam::thread::scope(|scope|
+            {
+                let receiver=scope.spawn(move |_|receiver.iter().collect::<Vec<(usize,PersonalizedGenome)>>());
+                execute_to_channel(vec_int_repr.clone(),engine,&ref_seq,false,sender,&ExecutionOptions::default());
+                receiver.join().unwrap()
+            }).unwrap();
+            received.sort_by_key(|(index,_)|*index);
+            assert_eq!(received.len(),genomes.len());
+            for ((index,genome),expected) in received.iter().zip(genomes.iter())
+            {
+                assert_eq!(genome.get_proband_name(),vec_int_repr[*index].get_name());
+                assert_eq!(genome.get_seq_tapes().0.get_annotation(),expected.get_seq_tapes().0.get_annotation());
+            }
+        }
+    }
+    #[test]
+    fn test_execute_with_recovery()
+    {
+        use crate::data_structures::vcf_ds::AltTranscript;
+        let mut ref_seq=HashMap::new();
+        ref_seq.insert("T1".to_string(),"MVAW".to_string());
+        ref_seq.insert("T2".to_string(),"MVAW".to_string());
+        let valid=AltTranscript::new("T1".to_string(),vec!["*missense|G1|T1|protein_coding|-|2V>2H|1936821C>T".to_string()]);
+        // the mutation starts beyond the end of the reference 
+        let out_of_range=AltTranscript::new("T2".to_string(),vec!["*missense|G2|T2|protein_coding|-|12V>12H|1936821C>T".to_string()]);
+        let vec_int_repr=vec![IntMap::new("P1".to_string(),vec![valid.clone(),out_of_range],vec![valid])];
+        let (genomes,failures)=execute_with_recovery(vec_int_repr.clone(),Engine::ST,&ref_seq,false,&ExecutionOptions::default());
+        assert_eq!(genomes.len(),1);
+        let (seq_tape1,seq_tape2)=genomes[0].get_seq_tapes();
+        assert_eq!(seq_tape1.get_seq(&"T1".to_string()).unwrap(),"MHAW");
+        assert_eq!(seq_tape2.get_seq(&"T1".to_string()).unwrap(),"MHAW");
+        assert!(seq_tape1.get_seq(&"T2".to_string()).is_err());
+        assert_eq!(failures.len(),1);
+        assert_eq!((failures[0].proband_name.as_str(),failures[0].haplotype,failures[0].transcript_name.as_deref()),("P1",Some(1),Some("T2")));
+        assert!(failures[0].reason.starts_with("Invalid span"));
+        let issues=genomes[0].get_coordinate_issues();
+        assert_eq!(issues.iter().map(|issue|(issue.transcript.as_str(),issue.haplotype,issue.kind)).collect::<Vec<_>>(),
+            vec![("T2",1,crate::functions::coordinate_audit::IssueKind::InvalidSpan)]);
+        assert_eq!(execute_with_recovery(vec_int_repr,Engine::MT,&ref_seq,true,&ExecutionOptions::default()).1,failures);
+    }
+    #[test]
+    fn test_trace_matches_execution()
+    {
+        let (mut vec_int_repr,mut ref_seq)=get_demo_input("vcf2prot_trace_transcript_unit_test");
+        let full_sequences=execute_to_map(vec_int_repr.clone(),Engine::ST,&ref_seq,&ExecutionOptions::default());
+        let transcript=vec_int_repr[0].get_mutations_ref().0[0].name.clone();
+        restrict_to_transcript(&mut vec_int_repr,&mut ref_seq,&transcript).unwrap();
+        assert_eq!(ref_seq.len(),1);
+        let trace=trace_transcript(&vec_int_repr,&transcript,&ref_seq,&ExecutionOptions::default()).unwrap();
+        assert!(!trace.haplotypes.is_empty());
+        for haplotype in trace.haplotypes.iter().filter(|haplotype|haplotype.error.is_none())
+        {
+            let (hap1_seq,hap2_seq)=&full_sequences[&haplotype.proband_name][&transcript];
+            match haplotype.haplotype
+            {
+                1=>assert_eq!(&haplotype.sequence,hap1_seq),
+                _=>assert_eq!(&haplotype.sequence,hap2_seq)
+            }
+        }
+        let restricted=execute_to_map(vec_int_repr,Engine::ST,&ref_seq,&ExecutionOptions::default());
+        assert!(restricted.values().all(|sequences|sequences.keys().all(|name|name==&transcript)));
+        assert!(restrict_to_transcript(&mut [],&mut ref_seq,"ENST_UNKNOWN").is_err());
+    }
+}
@@ -0,0 +1,850 @@
+// load the libraries and crates 
+use std::collections::HashMap;
+use std::path::Path; 
+use std::sync::atomic::AtomicU64;
+use rayon::prelude::*; 
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome;
+use crate::data_structures::InternalRep::consensus::ConsensusRule;
+use crate::data_structures::InternalRep::cohort_aggregate::{AggregateSummary,CohortAggregate};
+use crate::data_structures::InternalRep::sequence_pool::{PoolMembers,PoolSummary,SequencePool};
+use crate::readers; 
+use crate::readers::tabix::Region;
+use crate::data_structures::Map::{self, IntMap}; 
+use crate::data_structures::csq_map::CsqMap;
+use crate::data_structures::vcf_ds;
+use crate::functions::{vcf_tools,summary,effect_validation,hgvs,gene_report}; 
+use crate::functions::vcf_tools::DecodingSummary; 
+use crate::functions::hgvs::HgvsChange; 
+use crate::functions::compatibility::CompatibilityReport;
+use crate::functions::contigs::ContigPolicy;
+use crate::functions::missing_genotypes::{MissingGenotypePolicy,MissingGenotypeSummary};
+use crate::functions::duplicate_records::DuplicateRecordSummary;
+use crate::functions::maf::MafSummary;
+use crate::functions::sex_chromosomes::{self,ParBuild,SexChromosomeSummary};
+use crate::functions::length_stats::SampleLengthStats;
+use crate::functions::incremental::{RunManifest,RunProgress};
+use crate::functions::sample_vcfs::{self,SampleVcfSubsets};
+use crate::functions::sample_selection;
+use crate::functions::metrics::MetricsSnapshot;
+use crate::functions::smoke::SmokeConfig;
+use crate::parts::exec; 
+use crate::writers;
+/// ## Summary  
+/// Parsing a VCF file and return a result object containing a vector of internal representations, i.e. one per sample, see Map::IntMap for 
+/// the layout of the representation, consequence types are translated using csq_map, if provided, before unsupported consequences are filtered 
+pub fn parse_vcf(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>)->Result<Vec<Map::IntMap>,String>
+{
+    parse_vcf_with_contigs(path2load, engine, csq_map, ContigPolicy::default())
+}
+/// ## Summary  
+/// Similar to parse_vcf, however, the records are filtered by their contig using the provided policy, see readers::read_vcf_with_contigs, 
+/// parse_vcf skips the records of non-primary contigs, e.g. ALT, HLA and decoy contigs, while ContigPolicy::All parses every record 
+pub fn parse_vcf_with_contigs(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>, contig_policy:ContigPolicy)->Result<Vec<Map::IntMap>,String>
+{
+    parse_vcf_with_policies(path2load, engine, csq_map, contig_policy, MissingGenotypePolicy::default(), &readers::ParseOptions::default())
+        .map(|outcome|outcome.vec_int_map)
+}
+/// ## Summary 
+/// The outcome of parsing a VCF file, see parse_vcf_with_policies 
+#[derive(Debug,Clone,Default)]
+pub struct ParseOutcome
+{
+    /// the intermediate representation of each proband, in the order of the VCF header 
+    pub vec_int_map:Vec<IntMap>,
+    /// the missing genotype calls of each proband 
+    pub missing_genotypes:MissingGenotypeSummary,
+    /// the duplicated records that were collapsed 
+    pub duplicate_records:DuplicateRecordSummary,
+    /// the summary of decoding the records, e.g. the number of unphased heterozygous calls 
+    pub decoding_summary:DecodingSummary,
+    /// the annotations collected from the records, e.g. the support of the variants of the tumor in the somatic mode 
+    pub annotations:readers::RecordAnnotations
+}
+/// ## Summary  
+/// Similar to parse_vcf_with_contigs, however, the missing genotype calls are handled using the provided policy, see readers::read_vcf_with_policies, 
+/// and the summaries of the missing calls, of the collapsed duplicated records and of the decoding are returned along with the internal 
+/// representations, see ParseOutcome, the remaining options of the parse, e.g. the decoding options of the records, are provided by 
+/// options, see readers::ParseOptions 
+pub fn parse_vcf_with_policies(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>, contig_policy:ContigPolicy, 
+    missing_gt_policy:MissingGenotypePolicy, options:&readers::ParseOptions)->Result<ParseOutcome,String>
+{
+    // Get the proband name 
+    let (probands,records,missing_genotypes,duplicate_records,annotations)=match readers::read_vcf_with_policies(path2load, engine.clone(), csq_map, contig_policy, missing_gt_policy, options) // clone the engine which is a cheap enum so we can use it later 
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!(" reading the file failed: \n {} \n, formatting the string failed",err_msg))
+    }; 
+    // generate an intermediate map, routing the consequences of each record to their transcripts 
+    let (vec_int_map,decoding_summary)=vcf_tools::get_int_maps(probands, records, engine.clone()); 
+    Ok(ParseOutcome{vec_int_map,missing_genotypes,duplicate_records,decoding_summary,annotations})
+}
+/// ## Summary
+/// Similar to parse_vcf_with_policies, however, only the records overlapping at least one of the regions are parsed, where the records are
+/// fetched from a bgzipped VCF file through its tabix or CSI index, see readers::read_vcf_regions
+pub fn parse_vcf_regions(path2load:&Path, regions:&[Region], engine:Engine, csq_map:Option<&CsqMap>, contig_policy:ContigPolicy,
+    missing_gt_policy:MissingGenotypePolicy, options:&readers::ParseOptions)->Result<ParseOutcome,String>
+{
+    let (probands,records,missing_genotypes,duplicate_records,annotations)=match readers::read_vcf_regions(path2load, regions, engine.clone(), csq_map, contig_policy, missing_gt_policy, options)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!(" reading the regions of the file failed: \n {} \n, formatting the string failed",err_msg))
+    };
+    let (vec_int_map,decoding_summary)=vcf_tools::get_int_maps(probands, records, engine.clone()); 
+    Ok(ParseOutcome{vec_int_map,missing_genotypes,duplicate_records,decoding_summary,annotations})
+}
+/// ## Summary
+/// Similar to parse_vcf_with_policies, however, the file is streamed, i.e. read line by line in chunks of at least chunk_size records, and 
+/// the consequences of each chunk are routed to the representations before the next chunk is read, see readers::stream_vcf and 
+/// vcf_tools::IntMapBuilder, hence, the file is never loaded into memory, which is needed for population-scale files. The representations 
+/// are identical to the ones of parse_vcf_with_policies, except for duplicated records that are not part of the chunk of their first record, 
+/// which only happens with files that are not sorted by their position. 
+pub fn parse_vcf_streaming(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>, contig_policy:ContigPolicy, 
+    missing_gt_policy:MissingGenotypePolicy, chunk_size:usize, options:&readers::ParseOptions)->Result<ParseOutcome,String>
+{
+    let mut stream=readers::stream_vcf(path2load, engine.clone(), csq_map, contig_policy, missing_gt_policy, chunk_size, options)?; 
+    let mut builder=vcf_tools::IntMapBuilder::new(stream.get_probands()); 
+    for records in stream.by_ref()
+    {
+        builder.add_records(records?, engine.clone()); 
+    }
+    if builder.get_num_records()==0
+    {
+        return Err("Could not extract any records from the provided file!!".to_string()); 
+    }
+    if let Some(vep_summary)=stream.get_vep_summary()
+    {
+        readers::report_vep_summary(vep_summary); 
+    }
+    let skipped_contigs=stream.get_skipped_contigs(); 
+    if skipped_contigs.get_num_records()!=0
+    {
+        log::warn!("{}",skipped_contigs.to_report(10).trim_end()); 
+    }
+    let (missing_genotypes,duplicate_records)=stream.get_summaries(); 
+    if duplicate_records.num_duplicate_records!=0
+    {
+        log::warn!("{}",duplicate_records.to_report(10).trim_end()); 
+    }
+    if missing_genotypes.get_num_missing()!=0
+    {
+        log::warn!("{}",missing_genotypes.to_report(10).trim_end()); 
+    }
+    let decoding_summary=builder.get_decoding_summary(); 
+    Ok(ParseOutcome{vec_int_map:builder.build(engine),missing_genotypes:missing_genotypes.clone(),duplicate_records:duplicate_records.clone(),decoding_summary,
+        annotations:stream.get_annotations().clone()})
+}
+/// ## Summary
+/// Similar to parse_vcf_streaming, however, only the first config.num_records supported records and the first config.num_samples probands 
+/// of the file are parsed, where the file is only read until enough records are found, hence, the run time does not depend on the size of 
+/// the file, see smoke::SmokeConfig 
+pub fn parse_vcf_smoke(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>, contig_policy:ContigPolicy, 
+    missing_gt_policy:MissingGenotypePolicy, config:&SmokeConfig, options:&readers::ParseOptions)->Result<ParseOutcome,String>
+{
+    let mut stream=readers::stream_vcf(path2load, engine.clone(), csq_map, contig_policy, missing_gt_policy, config.num_records, options)?; 
+    let mut records=Vec::with_capacity(config.num_records); 
+    for chunk in stream.by_ref()
+    {
+        records.extend(chunk?.get_records().iter().cloned()); 
+        if records.len()>=config.num_records
+        {
+            break
+        }
+    }
+    if records.is_empty()
+    {
+        return Err("Could not extract any records from the provided file!!".to_string()); 
+    }
+    records.truncate(config.num_records); 
+    let probands=stream.get_probands(); 
+    let samples=probands.clone().get_probands().into_iter().take(config.num_samples).collect::<Vec<String>>(); 
+    let records=match stream.get_vep_summary()
+    {
+        Some(_)=>vcf_ds::VCFRecords::from_vep_records(records),
+        None=>vcf_ds::VCFRecords::new(records)
+    }.with_decoding_options(options.decoding_options); 
+    let (vec_int_map,decoding_summary)=vcf_tools::get_int_maps_for_samples(probands, records, &samples, engine)?; 
+    let (missing_genotypes,duplicate_records)=stream.get_summaries(); 
+    Ok(ParseOutcome{vec_int_map,missing_genotypes:missing_genotypes.clone(),duplicate_records:duplicate_records.clone(),decoding_summary,
+        annotations:stream.get_annotations().clone()})
+}
+/// ## Summary  
+/// Parsing a VCF file for a subset of the probands and return a result object containing a vector of internal representations for the 
+/// selected probands in the order of the VCF header, only the columns of the selected probands are parsed, see parse_vcf for the other 
+/// parameters. An error is returned if a sample is not part of the VCF file.
+pub fn parse_vcf_for_samples(path2load:&Path, engine:Engine, csq_map:Option<&CsqMap>, samples:&[String])->Result<Vec<Map::IntMap>,String>
+{
+    let (probands,records)=match readers::read_vcf(path2load, engine.clone(), csq_map)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!(" reading the file failed: \n {} \n, formatting the string failed",err_msg))
+    }; 
+    vcf_tools::get_int_maps_for_samples(probands, records, samples, engine).map(|(vec_int_map,_)|vec_int_map)
+}
+/// ## Summary 
+/// Parsing a MAF file, e.g. of a TCGA cohort, instead of a VCF file and return one internal representation per tumor sample along with a 
+/// summary of the conversion, the reference sequences anchor the pure insertions and deletions, see maf::maf_to_int_maps for the 
+/// supported variant classifications 
+pub fn parse_maf(path2load:&Path, engine:Engine, ref_seq:&HashMap<String,String>)->Result<(Vec<IntMap>,MafSummary),String>
+{
+    match readers::read_maf(path2load, engine, ref_seq)
+    {
+        Ok(res)=>Ok(res),
+        Err(err_msg)=>Err(format!(" reading the MAF file failed: \n {} \n",err_msg))
+    }
+}
+/// ## Summary 
+/// Parsing the mutation lists of a set of samples, i.e. one tab-separated file per sample with the transcript, the consequence type and 
+/// the protein change of each mutation, instead of a VCF file and return one internal representation per sample, see 
+/// readers::read_mutation_tsvs for the layout of the files 
+pub fn parse_mutation_tsvs(paths2load:&[&Path], engine:Engine)->Result<Vec<IntMap>,String>
+{
+    match readers::read_mutation_tsvs(paths2load, engine)
+    {
+        Ok(res)=>Ok(res),
+        Err(err_msg)=>Err(format!(" reading the mutation lists failed: \n {} \n",err_msg))
+    }
+}
+/// ## Summary 
+/// Read a fasta file and return a hashmap with sequence id as keys and sequences as values 
+pub fn read_fasta(path2load:&Path,engine:Engine)->HashMap<String,String>
+{
+    readers::read_fasta_file(path2load,engine).unwrap().consume_and_get_hash_map()
+}
+/// ## Summary 
+/// Read the custom records of a fasta file, e.g. contaminants, that shall be appended to the generated fasta files, in the order of the file 
+pub fn read_custom_records(path2load:&Path,engine:Engine)->Result<Vec<(String,String)>,String>
+{
+    readers::read_fasta_records(path2load,engine)
+}
+/// ## Summary 
+/// Write the personalized genomes as fasta files to the disk, sequences shorter than min_length are dropped before writing, while 
+/// the custom records, e.g. contaminants, are appended to every file, see PersonalizedGenome::write_with_length_stats.
+/// Returns a hashmap with proband names as keys and the number of dropped sequences as values along with the length statistics of 
+/// the sequences written for each proband, see length_stats::SampleLengthStats. The function panics if the files of a proband can not be 
+/// written, see write_personalized_genomes_with_failures for collecting the failed probands instead. 
+#[allow(clippy::too_many_arguments)]
+pub fn write_personalized_genomes(vec_genomes:Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:String,
+    use_single_thread:bool, write_all:bool, write_compressed:bool, ref_seq:&HashMap<String,String>, min_length:usize, 
+    custom_records:&[(String,String)], options:&writers::WriterOptions)->(HashMap<String,u64>,Vec<SampleLengthStats>)
+{
+    let (num_dropped,length_stats,failures)=write_personalized_genomes_with_failures(vec_genomes, exec_engines, output_dir, use_single_thread, 
+        write_all, write_compressed, ref_seq, min_length, custom_records, options); 
+    if let Some((proband_name,err_msg))=failures.first()
+    {
+        panic!("Writing the personalized genome of: {} failed with the following error: {}",proband_name,err_msg); 
+    }
+    (num_dropped,length_stats)
+}
+/// ## Summary 
+/// The probands whose files could not be written along with their error messages 
+pub type WriteFailures=Vec<(String,String)>; 
+/// ## Summary 
+/// Similar to write_personalized_genomes, however, a proband whose files can not be written, even after retrying the transient failures 
+/// of the file system, see writers::RetryPolicy, does not abort the writing of the other probands. The names of the failed probands are 
+/// returned along with their error messages, in the order of vec_genomes, so they can be marked in the run manifest and written again 
+/// with --resume, while the dropped sequences and the length statistics are only returned for the written probands. 
+#[allow(clippy::too_many_arguments)]
+pub fn write_personalized_genomes_with_failures(vec_genomes:Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:String,
+    use_single_thread:bool, write_all:bool, write_compressed:bool, ref_seq:&HashMap<String,String>, min_length:usize, 
+    custom_records:&[(String,String)], options:&writers::WriterOptions)->(HashMap<String,u64>,Vec<SampleLengthStats>,WriteFailures)
+{
+    let outcome=write_personalized_genomes_under_quota(vec_genomes, exec_engines, output_dir, use_single_thread, write_all, write_compressed, 
+        ref_seq, min_length, custom_records, options); 
+    (outcome.num_dropped,outcome.length_stats,outcome.failures)
+}
+/// ## Summary 
+/// The outcome of writing the personalized genomes, see write_personalized_genomes_under_quota 
+#[derive(Debug,Clone,Default)]
+pub struct WriteOutcome
+{
+    /// the number of dropped sequences of each written proband 
+    pub num_dropped:HashMap<String,u64>,
+    /// the length statistics of each written proband 
+    pub length_stats:Vec<SampleLengthStats>,
+    /// the probands whose files could not be written 
+    pub failures:WriteFailures,
+    /// the probands whose fasta files were truncated by the output quota along with the number of sequences that were not written 
+    pub truncated:Vec<(String,u64)>,
+    /// the bytes written to the per-proband fasta files under the output quota, counted before compression 
+    pub num_written_bytes:u64
+}
+/// The result of writing a personalized genome, i.e. the proband name, the number of dropped sequences, the length statistics and the number 
+/// of truncated sequences, or the proband name and the error message 
+type WriteResult=Result<(String,u64,SampleLengthStats,u64),(String,String)>;
+/// ## Summary 
+/// Similar to write_personalized_genomes_with_failures, however, the probands whose fasta files were truncated because they exceeded the 
+/// output quota of the options, see writers::OutputQuota, are returned as well, in the order of vec_genomes, so they can be marked in the 
+/// run manifest. A truncated proband is still a written proband, i.e. its dropped sequences and length statistics are returned. The total 
+/// quota covers the probands written by the call, whose written bytes are returned in the outcome. 
+#[allow(clippy::too_many_arguments)]
+pub fn write_personalized_genomes_under_quota(mut vec_genomes:Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:String,
+    use_single_thread:bool, write_all:bool, write_compressed:bool, ref_seq:&HashMap<String,String>, min_length:usize, 
+    custom_records:&[(String,String)], options:&writers::WriterOptions)->WriteOutcome
+{
+    let total_bytes=AtomicU64::new(0); 
+    let write_genome=|genome:&PersonalizedGenome|
+    {
+        to_write_result(genome, genome.write_under_quota(&output_dir,&write_all,&write_compressed,ref_seq,&min_length,custom_records,&total_bytes,options), options)
+    };
+    // this parameter has precedence over the engine and it forces the writing to be carried out in a single threaded manner
+    let results=if use_single_thread
+    {
+        vec_genomes.iter().map(write_genome).collect::<Vec<_>>()
+    }
+    else
+    {
+        // if the use_single_thread is not there, then we fallback to the engine guided execution
+        match exec_engines
+        {
+            Engine::ST=>vec_genomes.iter().map(write_genome).collect::<Vec<_>>(),
+            Engine::MT | Engine::GPU | Engine::Auto=>vec_genomes.par_iter_mut().map(|genome|write_genome(genome)).collect::<Vec<_>>()
+        }
+    };
+    WriteOutcome{num_written_bytes:total_bytes.into_inner(),..collect_write_outcome(results)}
+}
+/// ## Summary 
+/// Execute the representations and write the generated personalized genomes while the execution is still running, where the genomes are 
+/// passed from the execution to the writers through a bounded channel of the provided capacity, see exec::execute_to_channel. At most 
+/// capacity genomes wait for a writer at any time, once the channel is full the execution blocks until a writer takes a genome, hence, 
+/// the memory is bounded regardless of the speed of the storage, e.g. compressed files on a network file system. prepare is called on 
+/// each genome before it is written, e.g. to set its record order. The genomes are written by a single writer if use_single_thread is set 
+/// or with the single-thread engine and by one writer per thread of the pool otherwise. The outcome is ordered like vec_int_repr, see 
+/// write_personalized_genomes_under_quota for the rest of the parameters and exec::ExecutionOptions for the options of the execution. 
+/// ## Panics
+/// if capacity is zero 
+/// ## Example
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::exec::ExecutionOptions;
+/// use ppgg::writers::WriterOptions;
+/// let output_dir=std::env::temp_dir().join("vcf2prot_execute_and_write_pipelined_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// let path2fasta=output_dir.join("demo_reference.fasta");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let num_probands=vec_int_repr.len();
+/// let outcome=io::execute_and_write_pipelined(vec_int_repr,Engine::MT,&ref_seq,false,1,|_|(),
+///     output_dir.to_str().unwrap().to_string(),false,false,false,0,&[],&ExecutionOptions::default(),&WriterOptions::default());
+/// assert_eq!(outcome.num_dropped.len(),num_probands);
+/// assert!(outcome.failures.is_empty());
+///```
+#[allow(clippy::too_many_arguments)]
+pub fn execute_and_write_pipelined<F>(vec_int_repr:Vec<IntMap>, exec_engine:Engine, ref_seq:&HashMap<String,String>, compute_features:bool, 
+    capacity:usize, prepare:F, output_dir:String, use_single_thread:bool, write_all:bool, write_compressed:bool, min_length:usize, 
+    custom_records:&[(String,String)], execution_options:&exec::ExecutionOptions, options:&writers::WriterOptions)->WriteOutcome
+    where F:Fn(&mut PersonalizedGenome)+Sync
+{
+    assert!(capacity!=0,"The capacity of the channel between the execution and the writers must be at least 1"); 
+    let num_writers=match (use_single_thread,&exec_engine)
+    {
+        (true,_) | (false,Engine::ST)=>1,
+        (false,_)=>rayon::current_num_threads()
+    };
+    let (sender,receiver)=crossbeam::channel::bounded::<(usize,PersonalizedGenome)>(capacity); 
+    let total_bytes=AtomicU64::new(0); 
+    let mut results=crossbeam::thread::scope(|scope|
+    {
+        let writers=(0..num_writers).map(|_|
+        {
+            let receiver=receiver.clone(); 
+            let (prepare,output_dir,total_bytes)=(&prepare,&output_dir,&total_bytes); 
+            scope.spawn(move |_|
+            {
+                receiver.iter().map(|(index,mut genome)|
+                {
+                    prepare(&mut genome); 
+                    let result=to_write_result(&genome, genome.write_under_quota(output_dir,&write_all,&write_compressed,ref_seq,&min_length,custom_records,total_bytes,options), options); 
+                    (index,result)
+                }).collect::<Vec<_>>()
+            })
+        }).collect::<Vec<_>>(); 
+        // the writers own the remaining receivers, hence, the execution stops if all of them are gone 
+        drop(receiver); 
+        exec::execute_to_channel(vec_int_repr, exec_engine, ref_seq, compute_features, sender, execution_options); 
+        writers.into_iter().flat_map(|writer|writer.join().unwrap()).collect::<Vec<_>>()
+    }).unwrap(); 
+    results.sort_by_key(|(index,_)|*index); 
+    WriteOutcome{num_written_bytes:total_bytes.into_inner(),..collect_write_outcome(results.into_iter().map(|(_,result)|result).collect())}
+}
+/// ## Summary 
+/// Write the personalized genomes with the layout of the writer options, see writers::FastaLayout. With OutputLayout::PerProband, the 
+/// genomes are written as with write_personalized_genomes_under_quota, where the records are named with the template of the layout. 
+/// With PerTranscript, each transcript altered in at least one genome, or every transcript of the reference if write_all is set, is 
+/// written to its own file holding the records of every genome, the files are named after the template, see NameTemplate::render_file_name, 
+/// and sanitized, see writers::sanitize_file_stems. With Combined, the records of every genome are written to a single file, i.e. 
+/// writers::COMBINED_FASTA_FILE_STEM. As the files of the shared layouts hold several probands, the output quota is not applied and the 
+/// custom records are not supported, an error is returned on the first file that can not be written. 
+/// ## Example
+///```rust
+/// use std::collections::HashMap; 
+/// use ppgg::{io, Engine};
+/// use ppgg::data_structures::InternalRep::{personalized_genome::PersonalizedGenome,sequence_tape::SequenceTape};
+/// use ppgg::writers::{FastaLayout,NameTemplate,OutputLayout,WriterOptions}; 
+/// let output_dir=std::env::temp_dir().join("vcf2prot_write_with_layout_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let mut annotation=HashMap::new(); 
+/// annotation.insert("T1".to_string(),(0,3)); 
+/// let genome=PersonalizedGenome::new("P1".to_string(), SequenceTape::new("MKV".to_string(),annotation).unwrap(), SequenceTape::new(String::new(),HashMap::new()).unwrap()); 
+/// let fasta_layout=FastaLayout::new(OutputLayout::Combined,NameTemplate::default_for(OutputLayout::Combined)).unwrap(); 
+/// let outcome=io::write_personalized_genomes_with_layout(vec![genome],Engine::ST,output_dir.to_str().unwrap().to_string(),false,false,false,
+///     &HashMap::new(),0,&[],&WriterOptions{fasta_layout,..WriterOptions::default()}).unwrap(); 
+/// assert_eq!(outcome.num_dropped["P1"],0); 
+/// let written=std::fs::read_to_string(output_dir.join("personalized_proteomes.fasta")).unwrap(); 
+/// assert_eq!(written,">P1_T1_1\nMKV\n"); 
+///```
+#[allow(clippy::too_many_arguments)]
+pub fn write_personalized_genomes_with_layout(vec_genomes:Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:String, 
+    use_single_thread:bool, write_all:bool, write_compressed:bool, ref_seq:&HashMap<String,String>, min_length:usize, 
+    custom_records:&[(String,String)], options:&writers::WriterOptions)->Result<WriteOutcome,String>
+{
+    let layout=&options.fasta_layout; 
+    if layout.layout==writers::OutputLayout::PerProband
+    {
+        return Ok(write_personalized_genomes_under_quota(vec_genomes, exec_engines, output_dir, use_single_thread, write_all, write_compressed, 
+            ref_seq, min_length, custom_records, options))
+    }
+    if !custom_records.is_empty()
+    {
+        return Err(format!("Custom records can not be appended with the {} layout, as its files hold the records of several probands",layout.layout.as_str()))
+    }
+    let use_single_thread=use_single_thread || matches!(exec_engines,Engine::ST); 
+    let with_reference=match write_all
+    {
+        true=>Some(ref_seq),
+        false=>None
+    }; 
+    let num_dropped=match layout.layout
+    {
+        writers::OutputLayout::PerTranscript=>
+        {
+            let mut transcripts=vec_genomes.iter()
+                .flat_map(|genome|{let (seq_tape1,seq_tape2)=genome.get_seq_tapes(); seq_tape1.get_annotation().keys().chain(seq_tape2.get_annotation().keys())})
+                .chain(with_reference.into_iter().flat_map(|ref_seq|ref_seq.keys()))
+                .collect::<Vec<&String>>(); 
+            transcripts.sort_unstable(); 
+            transcripts.dedup(); 
+            let file_names=transcripts.iter().map(|transcript|layout.template.render_file_name(transcript)).collect::<Vec<String>>(); 
+            let file_stems=writers::sanitize_file_stems(&file_names.iter().map(|name|name.as_str()).collect::<Vec<&str>>()); 
+            let write_transcript=|(transcript,file_stem):(&&String,&String)|
+            {
+                let records=vec_genomes.iter()
+                    .map(|genome|genome.get_transcript_records(transcript, with_reference, &layout.template, options.id_map.as_ref()))
+                    .collect::<Vec<_>>(); 
+                writers::write_grouped_fasta(Path::new(&output_dir), file_stem, &records, write_compressed, min_length, options)
+            };
+            let per_file=match use_single_thread
+            {
+                true=>transcripts.iter().zip(file_stems.iter()).map(write_transcript).collect::<Result<Vec<Vec<u64>>,String>>()?,
+                false=>transcripts.par_iter().zip(file_stems.par_iter()).map(write_transcript).collect::<Result<Vec<Vec<u64>>,String>>()?
+            };
+            (0..vec_genomes.len()).map(|index|per_file.iter().map(|dropped|dropped[index]).sum::<u64>()).collect::<Vec<u64>>()
+        },
+        _=>
+        {
+            let records=match use_single_thread
+            {
+                true=>vec_genomes.iter().map(|genome|genome.get_fasta_records(with_reference, &layout.template, options.id_map.as_ref())).collect::<Vec<_>>(),
+                false=>vec_genomes.par_iter().map(|genome|genome.get_fasta_records(with_reference, &layout.template, options.id_map.as_ref())).collect::<Vec<_>>()
+            };
+            writers::write_grouped_fasta(Path::new(&output_dir), writers::COMBINED_FASTA_FILE_STEM, &records, write_compressed, min_length, options)?
+        }
+    };
+    let results=vec_genomes.iter().zip(num_dropped)
+        .map(|(genome,num_dropped)|Ok((genome.get_proband_name().clone(),num_dropped,genome.get_length_stats(ref_seq),0)))
+        .collect::<Vec<WriteResult>>(); 
+    Ok(collect_write_outcome(results))
+}
+/// ## Summary 
+/// Convert the result of writing the fasta file of a personalized genome into a write result, a written genome is recorded in the progress 
+/// file of the run, see writers::record_run_progress, and counts as failed if it can not be recorded, a recorded genome is counted in the 
+/// progress tracker of the options, if any 
+fn to_write_result(genome:&PersonalizedGenome, result:Result<(u64,SampleLengthStats,u64),String>, options:&writers::WriterOptions)->WriteResult
+{
+    let proband_name=genome.get_proband_name().clone(); 
+    match result.and_then(|written|writers::record_run_progress(&proband_name, genome.get_file_stem(), written.2!=0, options).map(|_|written))
+    {
+        Ok((num_dropped,length_stats,num_truncated))=>
+        {
+            if let Some(progress)=options.progress.as_ref()
+            {
+                progress.add_written_proband(); 
+            }
+            Ok((proband_name,num_dropped,length_stats,num_truncated))
+        },
+        Err(err_msg)=>Err((proband_name,err_msg))
+    }
+}
+/// ## Summary 
+/// Collect the results of writing each personalized genome into a write outcome 
+fn collect_write_outcome(results:Vec<WriteResult>)->WriteOutcome
+{
+    let mut outcome=WriteOutcome::default(); 
+    for result in results
+    {
+        match result
+        {
+            Ok((proband_name,num_dropped_sequences,stats,num_truncated))=>
+            {
+                if num_truncated!=0
+                {
+                    outcome.truncated.push((proband_name.clone(),num_truncated)); 
+                }
+                outcome.num_dropped.insert(proband_name,num_dropped_sequences); 
+                outcome.length_stats.push(stats); 
+            },
+            Err(failure)=>outcome.failures.push(failure)
+        }
+    }
+    outcome
+}
+/// ## Summary 
+/// Read the run manifest of a previous run from the output directory, see functions::incremental, None is returned if the directory does 
+/// not contain a manifest 
+pub fn read_run_manifest(output_dir:&String)->Result<Option<RunManifest>,String>
+{
+    let path2manifest=Path::new(output_dir).join(writers::RUN_MANIFEST_FILE_NAME); 
+    if !path2manifest.exists()
+    {
+        return Ok(None)
+    }
+    match std::fs::read_to_string(&path2manifest)
+    {
+        Ok(table)=>RunManifest::from_table(&table).map(Some),
+        Err(err_msg)=>Err(format!("Reading the run manifest: {:#?} failed due to the following error: {}",path2manifest,err_msg))
+    }
+}
+/// ## Summary 
+/// Read the progress of an interrupted run from the output directory, see writers::start_run_progress, None is returned if the directory 
+/// does not contain a progress file, i.e. the previous run was completed 
+pub fn read_run_progress(output_dir:&String)->Result<Option<RunProgress>,String>
+{
+    let path2progress=Path::new(output_dir).join(writers::RUN_PROGRESS_FILE_NAME); 
+    if !path2progress.exists()
+    {
+        return Ok(None)
+    }
+    match std::fs::read_to_string(&path2progress)
+    {
+        Ok(table)=>RunProgress::from_table(&table).map(Some),
+        Err(err_msg)=>Err(format!("Reading the run progress: {:#?} failed due to the following error: {}",path2progress,err_msg))
+    }
+}
+/// ## Summary 
+/// Assign a file stem that is safe on all platforms and unique within the cohort to each personalized genome, see writers::sanitize_file_stems, 
+/// if the file stem of at least one proband differs from its name, the mapping of all probands is written to sample_file_names.tsv 
+/// in the output directory. Returns the number of renamed probands.
+pub fn assign_file_stems(vec_genomes:&mut [PersonalizedGenome], output_dir:&String, options:&writers::WriterOptions)->Result<usize,String>
+{
+    let cohort=vec_genomes.iter().map(|genome|genome.get_proband_name().clone()).collect::<Vec<String>>(); 
+    assign_file_stems_for_cohort(vec_genomes, &cohort, output_dir, options)
+}
+/// ## Summary 
+/// Similar to assign_file_stems, however, the file stems are unique within the provided cohort, which might contain probands that are not 
+/// part of vec_genomes, e.g. the probands that are reused by an incremental run, the mapping is written for every proband of the cohort. 
+pub fn assign_file_stems_for_cohort(vec_genomes:&mut [PersonalizedGenome], cohort:&[String], output_dir:&String, 
+    options:&writers::WriterOptions)->Result<usize,String>
+{
+    let (file_stems,num_renamed)=get_file_stems_for_cohort(cohort, output_dir, options)?; 
+    for genome in vec_genomes.iter_mut()
+    {
+        if let Some(file_stem)=file_stems.get(genome.get_proband_name())
+        {
+            genome.set_file_stem(file_stem.clone()); 
+        }
+    }
+    Ok(num_renamed)
+}
+/// ## Summary 
+/// Return the file stem of each proband of the cohort along with the number of renamed probands, see assign_file_stems_for_cohort, which 
+/// is used if the genomes are not available at once, e.g. with execute_and_write_pipelined. The mapping is written to sample_file_names.tsv 
+/// if at least one proband is renamed. 
+pub fn get_file_stems_for_cohort(cohort:&[String], output_dir:&String, options:&writers::WriterOptions)->Result<(HashMap<String,String>,usize),String>
+{
+    let file_stems=writers::sanitize_file_stems(&cohort.iter().map(|name|name.as_str()).collect::<Vec<&str>>()); 
+    let mapping=cohort.iter().cloned().zip(file_stems).collect::<Vec<(String,String)>>(); 
+    let num_renamed=mapping.iter().filter(|(name,file_stem)|name!=file_stem).count(); 
+    if num_renamed!=0
+    {
+        writers::write_file_stem_mapping(Path::new(output_dir), &mapping, options)?; 
+    }
+    Ok((mapping.into_iter().collect::<HashMap<String,String>>(),num_renamed))
+}
+/// ## Summary 
+/// Write a consensus fasta file per personalized genome to the disk, where the two haplotypes of each transcript are collapsed into 
+/// a single sequence using the provided rule, see PersonalizedGenome::write_consensus for the layout of the generated files, the write_all 
+/// and write_compressed flags are read from the options. Returns a hashmap with proband names as keys and the number of dropped consensus 
+/// sequences as values 
+pub fn write_consensus_genomes(vec_genomes:&Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:&String, ref_seq:&HashMap<String,String>, 
+    min_length:usize, rule:&ConsensusRule, options:&writers::WriterOptions)->HashMap<String,u64>
+{
+    match exec_engines
+    {
+        Engine::ST=>
+        {
+            vec_genomes.iter()
+            .map(|genome|(genome.get_proband_name().clone(),genome.write_consensus(output_dir,ref_seq,&min_length,rule,options).unwrap()))
+            .collect::<HashMap<_,_>>()
+        },
+        Engine::MT | Engine::GPU | Engine::Auto=>
+        {
+            vec_genomes.par_iter()
+            .map(|genome|(genome.get_proband_name().clone(),genome.write_consensus(output_dir,ref_seq,&min_length,rule,options).unwrap()))
+            .collect::<HashMap<_,_>>()
+        }
+    }
+}
+/// ## Summary 
+/// Write the applied variants of each personalized genome as a GFF3 file to the output directory, the genomes must have been 
+/// generated with features, see exec::execute, otherwise, the files only contain the GFF3 header 
+pub fn write_variant_features(vec_genomes:&Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:&String, options:&writers::WriterOptions)
+{
+    match exec_engines
+    {
+        Engine::ST=>vec_genomes.iter().for_each(|genome|genome.write_gff3(output_dir,options).unwrap()),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_genomes.par_iter().for_each(|genome|genome.write_gff3(output_dir,options).unwrap())
+    }
+}
+/// ## Summary 
+/// Write the peptide windows of the changes applied to each personalized genome, see PersonalizedGenome::write_peptide_windows, instead of 
+/// their full personalized proteomes, the genomes must have been generated with features, otherwise, no window is written. The length 
+/// statistics of the genomes are collected as with the full proteomes and the probands whose windows could not be written are returned 
+/// as failures. 
+pub fn write_peptide_windows(vec_genomes:&[PersonalizedGenome], exec_engines:Engine, output_dir:&String, write_compressed:bool, 
+    ref_seq:&HashMap<String,String>, flank:usize, options:&writers::WriterOptions)->WriteOutcome
+{
+    let write_genome=|genome:&PersonalizedGenome|->WriteResult
+    {
+        match genome.write_peptide_windows(output_dir, ref_seq, flank, write_compressed, options)
+        {
+            Ok(_)=>Ok((genome.get_proband_name().clone(),0,genome.get_length_stats(ref_seq),0)),
+            Err(err_msg)=>Err((genome.get_proband_name().clone(),err_msg))
+        }
+    };
+    let results=match exec_engines
+    {
+        Engine::ST=>vec_genomes.iter().map(write_genome).collect::<Vec<WriteResult>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_genomes.par_iter().map(write_genome).collect::<Vec<WriteResult>>()
+    };
+    collect_write_outcome(results)
+}
+/// ## Summary 
+/// Write the changes applied to each personalized genome as a table to the output directory, see PersonalizedGenome::write_changes, 
+/// similar to write_variant_features, the genomes must have been generated with features, otherwise, the tables are empty 
+pub fn write_applied_changes(vec_genomes:&Vec<PersonalizedGenome>, exec_engines:Engine, output_dir:&String, ref_seq:&HashMap<String,String>, 
+    format:writers::ChangesFormat, options:&writers::WriterOptions)
+{
+    match exec_engines
+    {
+        Engine::ST=>vec_genomes.iter().for_each(|genome|genome.write_changes(output_dir,ref_seq,format,options).unwrap()),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_genomes.par_iter().for_each(|genome|genome.write_changes(output_dir,ref_seq,format,options).unwrap())
+    }
+}
+/// ## Summary 
+/// Write the personalized genomes as a pool of unique sequences, see SequencePool::write_pooled_sequences, instead of a fasta file per proband, 
+/// where each unique sequence of a transcript is written once along with the probands and haplotypes carrying it. Returns the summary of the 
+/// pool along with the length statistics of each proband, as collected for the per-proband fasta files. 
+#[allow(clippy::too_many_arguments)]
+pub fn write_pooled_genomes(vec_genomes:&[PersonalizedGenome], exec_engines:Engine, output_dir:&String, write_compressed:bool, 
+    ref_seq:&HashMap<String,String>, min_length:usize, members:PoolMembers, custom_records:&[(String,String)], 
+    options:&writers::WriterOptions)->Result<(PoolSummary,WriteOutcome),String>
+{
+    let pool=SequencePool::from_genomes(vec_genomes, exec_engines.clone()); 
+    let summary=pool.write_pooled_sequences(output_dir, &write_compressed, &min_length, members, custom_records, options)?; 
+    let length_stats=match exec_engines
+    {
+        Engine::ST=>vec_genomes.iter().map(|genome|genome.get_length_stats(ref_seq)).collect::<Vec<SampleLengthStats>>(),
+        Engine::MT | Engine::GPU | Engine::Auto=>vec_genomes.par_iter().map(|genome|genome.get_length_stats(ref_seq)).collect::<Vec<SampleLengthStats>>()
+    };
+    Ok((summary,WriteOutcome{length_stats,..WriteOutcome::default()}))
+}
+/// ## Summary 
+/// Write only cohort-level results of the personalized genomes to the output directory, i.e. the unique sequences carried by at least 
+/// min_carriers probands along with the custom records, see CohortAggregate::write_unique_sequences, the number of carriers per transcript and a cohort summary, 
+/// only cohort-level files are written. Returns the summary of the reported results.
+#[allow(clippy::too_many_arguments)]
+pub fn write_cohort_aggregate(vec_genomes:&[PersonalizedGenome], exec_engines:Engine, output_dir:&String, write_compressed:bool, 
+    min_length:usize, min_carriers:u64, custom_records:&[(String,String)], options:&writers::WriterOptions)->Result<AggregateSummary,String>
+{
+    let aggregate=CohortAggregate::from_genomes(vec_genomes, exec_engines); 
+    let summary=aggregate.write_unique_sequences(output_dir, &write_compressed, &min_length, min_carriers, custom_records, options)?; 
+    writers::write_cohort_carriers_per_transcript(Path::new(output_dir), &aggregate, min_carriers, options)?; 
+    writers::write_cohort_summary(Path::new(output_dir), &summary, options)?; 
+    Ok(summary)
+}
+/// ## Summary 
+/// Compute the HGVS.p descriptions of the mutations applied to each haplotype and write them to hgvs_changes.tsv in the output directory, 
+/// the changes are returned so they can be added to the fasta headers using add_hgvs_header_tags 
+pub fn compute_and_write_hgvs_changes(vec_maps:&[IntMap], ref_seq:&HashMap<String,String>, exec_engines:Engine, output_dir:&String, 
+    options:&writers::WriterOptions)->Result<Vec<HgvsChange>,String>
+{
+    let changes=hgvs::get_hgvs_changes(vec_maps, ref_seq, exec_engines); 
+    writers::write_hgvs_changes(Path::new(output_dir), &changes, options)?; 
+    Ok(changes)
+}
+/// ## Summary 
+/// Roll up the protein changes by gene symbol and write the report to gene_report.tsv, see gene_report::compute_gene_report, where the 
+/// gene symbols of the transcripts are collected while the VCF file is parsed, see readers::RecordAnnotations. Returns the number of 
+/// genes in the report. 
+pub fn compute_and_write_gene_report(changes:&[HgvsChange], transcript_genes:&HashMap<String,String>, path2write:&Path, 
+    options:&writers::WriterOptions)->Result<usize,String>
+{
+    let report=gene_report::compute_gene_report(changes, transcript_genes); 
+    writers::write_gene_report(path2write, &report, options)?; 
+    Ok(report.len())
+}
+/// ## Summary 
+/// Collapse the transcripts located outside the pseudoautosomal regions of chrX and chrY into a single haplotype for the XY samples of the 
+/// sample-sex table, see sex_chromosomes::apply_sample_sexes, as the loci are not part of the intermediate representation the records of 
+/// the VCF file are read again, using the same consequence map, contig policy and parse options as the run. 
+#[allow(clippy::too_many_arguments)]
+pub fn apply_sample_sexes(path2vcf:&Path, vec_maps:&mut [IntMap], path2table:&Path, build:ParBuild, engine:Engine, csq_map:Option<&CsqMap>, 
+    contig_policy:ContigPolicy, options:&readers::ParseOptions)->Result<SexChromosomeSummary,String>
+{
+    let sexes=readers::read_sample_sex_table(path2table)?; 
+    let (_,records,_,_,_)=match readers::read_vcf_with_policies(path2vcf, engine.clone(), csq_map, contig_policy, MissingGenotypePolicy::default(), options)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!("Reading the VCF file for locating the sex-chromosome transcripts failed with the following error: {}",err_msg))
+    };
+    let hemizygous_transcripts=sex_chromosomes::get_hemizygous_transcripts(&records, build, engine); 
+    Ok(sex_chromosomes::apply_sample_sexes(vec_maps, &sexes, hemizygous_transcripts))
+}
+/// ## Summary 
+/// Extract the per-sample subsets of the VCF file, i.e. for each intermediate representation the original records whose consequences were 
+/// applied to it, see sample_vcfs::get_applied_records, the records are read again, using the same consequence map, contig policy, 
+/// missing genotype policy and parse options as the run, and aligned to the original records, whose INFO and sample fields are written 
+/// unchanged. 
+#[allow(clippy::too_many_arguments)]
+pub fn compute_sample_vcf_subsets(path2vcf:&Path, vec_maps:&[IntMap], ref_seq:&HashMap<String,String>, engine:Engine, csq_map:Option<&CsqMap>, 
+    contig_policy:ContigPolicy, missing_gt_policy:MissingGenotypePolicy, options:&readers::ParseOptions)->Result<SampleVcfSubsets,String>
+{
+    let (probands,records,_,_,_)=match readers::read_vcf_with_policies(path2vcf, engine.clone(), csq_map, contig_policy, missing_gt_policy, options)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!("Reading the VCF file for extracting the per-sample subsets failed with the following error: {}",err_msg))
+    };
+    let (header,original):(Vec<String>,Vec<String>)=readers::vcf_helpers::read_file(path2vcf, engine.clone())?.into_iter()
+        .partition(|line|line.starts_with('#')); 
+    // the processed records only hold the fields of the selected samples, if any, hence, the original records are sliced alike 
+    let original=match sample_selection::select_probands(readers::vcf_helpers::get_probands_names(&mut header.clone(), engine.clone())?, options.sample_selection.as_ref())?
+    {
+        (_,Some(columns))=>sample_selection::slice_records(original, &columns, engine.clone()),
+        (_,None)=>original
+    }; 
+    let record_indices=sample_vcfs::align_records(&original, records.get_records())?; 
+    let samples=sample_vcfs::get_applied_records(&probands.get_probands(), records.get_records(), &record_indices, vec_maps, ref_seq, 
+        records.get_decoding_options(), engine)?; 
+    Ok(SampleVcfSubsets{header,records:original,samples})
+}
+/// ## Summary 
+/// Write the per-sample VCF subsets to the output directory, the files are named after the file stems of the personalized genomes, see 
+/// assign_file_stems_for_cohort, returns the number of written records summed over all samples 
+pub fn write_sample_vcfs(subsets:&SampleVcfSubsets, vec_genomes:&[PersonalizedGenome], output_dir:&String, options:&writers::WriterOptions)->Result<usize,String>
+{
+    let file_stems=vec_genomes.iter()
+        .map(|genome|(genome.get_proband_name().clone(),genome.get_file_stem().to_string()))
+        .collect::<HashMap<String,String>>(); 
+    writers::write_sample_vcfs(Path::new(output_dir), subsets, &file_stems, options)
+}
+/// ## Summary 
+/// Add the HGVS.p descriptions of the changes as an hgvs tag to the fasta headers of the altered transcripts of each personalized genome
+pub fn add_hgvs_header_tags(vec_genomes:&mut [PersonalizedGenome], changes:&[HgvsChange])
+{
+    let mut header_tags=hgvs::get_header_tags(changes); 
+    for genome in vec_genomes.iter_mut()
+    {
+        if let Some(tags)=header_tags.remove(genome.get_proband_name())
+        {
+            genome.set_header_tags(tags); 
+        }
+    }
+}
+/// ## Summary 
+/// A wrapper function for computing and writing the summary results 
+pub fn compute_and_write_summary(path2write:&Path, vec_maps:&Vec<IntMap>, options:&writers::WriterOptions)
+{
+    // compute the stats, write the files sequentially 
+    let computed_stats=exec::compute_states(&vec_maps); 
+    let (mut_per_patient,type_mut_per_patient, num_mut_per_transcript )=(
+        computed_stats.num_mutation_per_proband,computed_stats.type_mutation_per_proband,
+        computed_stats.number_of_mutations_per_transcript); // get the results as three variables 
+    // write the results
+    writers::write_num_number_mutation_per_proband(path2write, mut_per_patient, options).unwrap(); 
+    writers::write_type_mutations_per_patient(path2write, type_mut_per_patient, options).unwrap(); 
+    writers::write_number_of_mutations_per_transcript(path2write, num_mut_per_transcript, options).unwrap(); 
+    writers::write_haplotype_spread_per_proband(path2write, summary::compute_haplotype_spread(vec_maps), options).unwrap(); 
+}
+/// ## Summary 
+/// A wrapper function for writing the stats accumulated while the representations were parsed and executed, i.e. the number of mutations 
+/// per proband, the type of mutations per proband and the number of mutations per transcript, as written by compute_and_write_summary, 
+/// along with the execution metrics per proband. In contrast to compute_and_write_summary no pass over the representations is needed and 
+/// only the executed alterations are counted, i.e. the alterations of transcripts missing from the reference or skipped by the execution 
+/// are not counted, see metrics::MetricsAccumulator. 
+pub fn write_accumulated_summary(path2write:&Path, snapshot:&MetricsSnapshot, options:&writers::WriterOptions)->Result<(),String>
+{
+    let summary=snapshot.to_stat_summary(); 
+    writers::write_num_number_mutation_per_proband(path2write, summary.num_mutation_per_proband, options)?; 
+    writers::write_type_mutations_per_patient(path2write, summary.type_mutation_per_proband, options)?; 
+    writers::write_number_of_mutations_per_transcript(path2write, summary.number_of_mutations_per_transcript, options)?; 
+    writers::write_execution_metrics_per_proband(path2write, snapshot, options)
+}
+/// ## Summary 
+/// A wrapper function for computing and writing the mutational load per proband normalized by the number of 
+/// callable transcripts and by the length of the reference proteome 
+pub fn compute_and_write_normalized_load(path2write:&Path, vec_maps:&Vec<IntMap>, ref_seq:&HashMap<String,String>, options:&writers::WriterOptions)
+{
+    let normalized_load=summary::compute_normalized_mutation_load(vec_maps, ref_seq); 
+    writers::write_normalized_mutation_load_per_proband(path2write, normalized_load, options).unwrap(); 
+}
+/// ## Summary 
+/// A wrapper function for decorating every transcript of the reference with the number of samples carrying its variants and the distinct 
+/// positions of these variants, and writing the decoration to transcript_decoration.tsv, see summary::compute_transcript_decoration 
+pub fn compute_and_write_transcript_decoration(path2write:&Path, vec_maps:&[IntMap], ref_seq:&HashMap<String,String>, 
+    options:&writers::WriterOptions)->Result<(),String>
+{
+    writers::write_transcript_decoration(path2write, &summary::compute_transcript_decoration(vec_maps, ref_seq), options)
+}
+/// ## Summary 
+/// Cross-check the consequences of a VCF file against a genome fasta file and write the inconsistent consequences to the 
+/// inconsistent_annotations.tsv file in the provided directory, consequence types are translated using csq_map, if provided, 
+/// before the checks as with parse_vcf, and records are filtered by their contig using contig_policy, see parse_vcf_with_contigs, and 
+/// by the parse options of the run. Returns the number of inconsistent consequences.
+#[allow(clippy::too_many_arguments)]
+pub fn validate_variant_effects(path2vcf:&Path, path2genome:&Path, path2write:&Path, engine:Engine, csq_map:Option<&CsqMap>, 
+    contig_policy:ContigPolicy, parse_options:&readers::ParseOptions, options:&writers::WriterOptions)->Result<usize,String>
+{
+    let (_,records,_,_,_)=match readers::read_vcf_with_policies(path2vcf, engine.clone(), csq_map, contig_policy, MissingGenotypePolicy::default(), parse_options)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!("Reading the VCF file for validating the consequences failed with the following error: {}",err_msg))
+    };
+    let genome=match readers::read_fasta_file(path2genome, engine.clone())
+    {
+        Ok(fasta_file)=>fasta_file.consume_and_get_hash_map(),
+        Err(err_msg)=>return Err(format!("Reading the genome file failed with the following error: {}",err_msg))
+    };
+    let inconsistent=effect_validation::validate_records(&records, &genome, engine); 
+    writers::write_inconsistent_annotations(path2write, &inconsistent, options)?; 
+    Ok(inconsistent.len())
+}
+/// ## Summary
+/// Check whether a reference proteome matches the annotation of a VCF file, i.e. whether the transcript ids, their versions and the
+/// reference residues stated by the consequences agree with the reference, consequence types are translated using csq_map, if provided.
+pub fn check_compatibility(path2vcf:&Path, path2fasta:&Path, engine:Engine, csq_map:Option<&CsqMap>)->Result<CompatibilityReport,String>
+{
+    let vec_maps=match parse_vcf(path2vcf, engine.clone(), csq_map)
+    {
+        Ok(res)=>res,
+        Err(err_msg)=>return Err(format!("Reading the VCF file for checking the compatibility failed with the following error: {}",err_msg))
+    };
+    let ref_seq=match readers::read_fasta_file(path2fasta, engine)
+    {
+        Ok(fasta_file)=>fasta_file.consume_and_get_hash_map(),
+        Err(err_msg)=>return Err(format!("Reading the reference file failed with the following error: {}",err_msg))
+    };
+    Ok(CompatibilityReport::new(&vec_maps, &ref_seq))
+}
@@ -0,0 +1,11 @@
+/// a collection of high level assemblies that can either be used in a standalone version or in integration tests 
+/// 
+#[cfg(feature = "writers")]
+pub mod io; 
+pub mod exec; 
+#[cfg(feature = "parser")]
+pub mod api;
+#[cfg(feature = "writers")]
+pub mod demo;
+#[cfg(feature = "server")]
+pub mod server;
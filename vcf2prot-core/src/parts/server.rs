@@ -0,0 +1,491 @@
+/// The module provides an in-process sequence query server, i.e. the reference proteome is loaded once and each request, i.e. the
+/// consequences of the haplotypes of a sample, is answered with the personalized sequences of the altered transcripts, hence, interactive
+/// applications, e.g. the generation of clinical reports, do not pay for starting a process and for reading and writing fasta files per
+/// request. The requests and the responses are JSON documents exchanged over a minimal HTTP/1.1 interface, see SequenceServer::serve.
+use std::collections::{BTreeMap,HashMap};
+use std::io::{BufRead,BufReader,Read,Write};
+use std::net::{TcpListener,TcpStream};
+use std::str::FromStr;
+use std::sync::{Arc,Mutex};
+use std::sync::mpsc::{self,Receiver};
+use serde::{Deserialize,Serialize};
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::data_structures::InternalRep::gir::PlaceholderPolicy;
+use crate::data_structures::InternalRep::transcript_instructions::OverlapPolicy;
+use crate::data_structures::Map::IntMap;
+use crate::data_structures::mutation_ds::Mutation;
+use crate::data_structures::vcf_ds::AltTranscript;
+use crate::functions::diagnostics::CheckPolicy;
+use crate::functions::text_parser;
+use crate::parts::exec::{self,ExecutionOptions};
+
+/// ## Summary
+/// The maximum size of the body of a request in bytes, larger requests are rejected with the status 413
+pub const MAX_REQUEST_SIZE:usize=16*1024*1024;
+/// ## Summary
+/// The maximum size of the request line and of each header line in bytes, including the line break, longer request lines are rejected 
+/// with the status 400 and longer header lines with the status 431
+pub const MAX_LINE_SIZE:usize=8*1024;
+/// ## Summary
+/// The maximum size of the request line and the headers of a request in bytes, larger headers are rejected with the status 431
+pub const MAX_HEADER_SIZE:usize=64*1024;
+/// ## Summary
+/// The maximum number of connections that are answered at the same time, further connections wait in the backlog of the listener until
+/// a connection is closed
+pub const MAX_CONNECTIONS:usize=64;
+/// ## Summary
+/// The path of the requests for personalized sequences, see SequenceRequest
+pub const SEQUENCES_PATH:&str="/sequences";
+/// ## Summary
+/// The path of the health check, which returns the number of transcripts of the loaded reference proteome
+pub const HEALTH_PATH:&str="/health";
+/// ## Summary
+/// The time a connection may stall while its request is read or its response is written before it is closed
+pub const READ_TIMEOUT:std::time::Duration=std::time::Duration::from_secs(30);
+
+/// ## Summary
+/// A request for the personalized sequences of a sample, i.e. the consequences carried by each haplotype as written by bcftools csq, e.g.
+/// missense|BRAF|ENST00000646891|protein_coding|-|640V>640E|140753336A>T, a missing haplotype carries no consequence, along with the 
+/// settings of the request, see RequestSettings 
+/// ## Example
+///```json
+/// {"sample":"S1","haplotype1":["missense|G1|T1|protein_coding|+|2K>2N|10A>T"],"haplotype2":[],"settings":{"overlap_policy":"keep-first"}}
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+pub struct SequenceRequest
+{
+    pub sample:String,
+    #[serde(default)]
+    pub haplotype1:Vec<String>,
+    #[serde(default)]
+    pub haplotype2:Vec<String>,
+    #[serde(default)]
+    pub settings:RequestSettings
+}
+/// ## Summary
+/// The settings of a single request, which override the execution options of the server for this request only, hence, concurrent 
+/// requests can use different settings, a missing setting keeps the option of the server. The settings are named after the flags of the 
+/// command line, i.e. the overlap policy, see OverlapPolicy, the check policy, see diagnostics::CheckPolicy, the placeholder residue and 
+/// whether the transcripts with an unwritten residue are rejected, see gir::PlaceholderPolicy 
+#[derive(Debug,Clone,PartialEq,Eq,Default,Serialize,Deserialize)]
+pub struct RequestSettings
+{
+    #[serde(default)]
+    pub overlap_policy:Option<String>,
+    #[serde(default)]
+    pub checks:Option<String>,
+    #[serde(default)]
+    pub placeholder_residue:Option<char>,
+    #[serde(default)]
+    pub strict_placeholder_check:Option<bool>
+}
+impl RequestSettings
+{
+    /// ## Summary
+    /// Return the execution options of the request, i.e. the options of the server overridden by the provided settings, an error is 
+    /// returned if one of the settings is not valid
+    pub fn get_execution_options(&self, defaults:&ExecutionOptions)->Result<ExecutionOptions,String>
+    {
+        let mut options=defaults.clone();
+        if let Some(policy)=self.overlap_policy.as_ref()
+        {
+            options.overlap_policy=Some(OverlapPolicy::from_str(policy)?);
+        }
+        if let Some(policy)=self.checks.as_ref()
+        {
+            options.diagnostic_options.check_policy=CheckPolicy::from_str(policy)?;
+        }
+        if self.placeholder_residue.is_some() || self.strict_placeholder_check.is_some()
+        {
+            options.placeholder_policy=PlaceholderPolicy::new(self.placeholder_residue.unwrap_or(defaults.placeholder_policy.residue),
+                self.strict_placeholder_check.unwrap_or(defaults.placeholder_policy.is_strict))?;
+        }
+        Ok(options)
+    }
+}
+/// ## Summary
+/// The personalized sequences of an altered transcript in both haplotypes, an unaltered haplotype has the reference sequence
+#[derive(Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+pub struct HaplotypePair
+{
+    pub haplotype1:String,
+    pub haplotype2:String
+}
+/// ## Summary
+/// A transcript that could not be personalized, i.e. a transcript that is not part of the reference proteome or whose mutations could
+/// not be executed, see exec::execute_with_recovery, the haplotype is None if the whole sample failed
+#[derive(Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+pub struct ServedFailure
+{
+    pub haplotype:Option<u8>,
+    pub transcript:Option<String>,
+    pub reason:String
+}
+/// ## Summary
+/// The response to a SequenceRequest, i.e. the personalized sequences of the altered transcripts keyed by transcript along with the
+/// transcripts that could not be personalized
+#[derive(Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+pub struct SequenceResponse
+{
+    pub sample:String,
+    pub sequences:BTreeMap<String,HaplotypePair>,
+    pub failures:Vec<ServedFailure>
+}
+
+/// ## Summary
+/// A sequence query server holding the reference proteome and the execution options of its requests, which each request can override, 
+/// see RequestSettings, cloning the server shares the reference, see serve for the interface
+/// ## Example
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::parts::server::{SequenceServer,SequenceRequest};
+/// let ref_seq=vec![("T1".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+/// let server=SequenceServer::new(ref_seq);
+/// let request=SequenceRequest{sample:"S1".to_string(),haplotype1:vec!["missense|G1|T1|protein_coding|+|2K>2N|10A>T".to_string()],haplotype2:Vec::new(),
+///     settings:Default::default()};
+/// let response=server.get_sequences(request).unwrap();
+/// assert_eq!((response.sequences["T1"].haplotype1.as_str(),response.sequences["T1"].haplotype2.as_str()),("MNLV","MKLV"));
+///```
+#[derive(Debug,Clone)]
+pub struct SequenceServer
+{
+    ref_seq:Arc<HashMap<String,String>>,
+    options:Arc<ExecutionOptions>
+}
+impl SequenceServer
+{
+    /// ## Summary
+    /// Create a server for the provided reference proteome with the default execution options, see exec::ExecutionOptions
+    pub fn new(ref_seq:HashMap<String,String>)->Self
+    {
+        SequenceServer::with_options(ref_seq, ExecutionOptions::default())
+    }
+    /// ## Summary
+    /// Create a server for the provided reference proteome, where the requests are executed with the provided options unless their 
+    /// settings override them, see RequestSettings
+    pub fn with_options(ref_seq:HashMap<String,String>, options:ExecutionOptions)->Self
+    {
+        SequenceServer{ref_seq:Arc::new(ref_seq),options:Arc::new(options)}
+    }
+    /// ## Summary
+    /// Return the number of transcripts of the reference proteome
+    pub fn get_num_transcripts(&self)->usize
+    {
+        self.ref_seq.len()
+    }
+    /// ## Summary
+    /// Personalize the transcripts altered by the consequences of a request, an error is returned if the sample name is empty or if a
+    /// consequence can not be parsed or is not supported, or if a setting of the request is not valid, while the transcripts that can not 
+    /// be personalized are reported as failures
+    pub fn get_sequences(&self, request:SequenceRequest)->Result<SequenceResponse,String>
+    {
+        if request.sample.is_empty()
+        {
+            return Err("The sample name of the request can not be empty".to_string())
+        }
+        let options=request.settings.get_execution_options(&self.options)?;
+        let mut failures=Vec::new();
+        let haplotype1=self.group_consequences(&request.haplotype1, 1, &mut failures)?;
+        let haplotype2=self.group_consequences(&request.haplotype2, 2, &mut failures)?;
+        let int_map=IntMap::new(request.sample.clone(), haplotype1, haplotype2);
+        let (genomes,execution_failures)=exec::execute_with_recovery(vec![int_map], Engine::ST, &self.ref_seq, false, &options);
+        failures.extend(execution_failures.into_iter()
+            .map(|failure|ServedFailure{haplotype:failure.haplotype,transcript:failure.transcript_name,reason:failure.reason}));
+        let sequences=genomes.iter()
+            .flat_map(|genome|genome.to_haplotype_sequences(&self.ref_seq))
+            .map(|(transcript,(haplotype1,haplotype2))|(transcript,HaplotypePair{haplotype1,haplotype2}))
+            .collect::<BTreeMap<String,HaplotypePair>>();
+        Ok(SequenceResponse{sample:request.sample,sequences,failures})
+    }
+    /// parse the consequences of a haplotype and group them per transcript, the transcripts that are not part of the reference proteome
+    /// are reported as failures
+    fn group_consequences(&self, consequences:&[String], haplotype:u8, failures:&mut Vec<ServedFailure>)->Result<Vec<AltTranscript>,String>
+    {
+        let mut transcripts:BTreeMap<String,Vec<Mutation>>=BTreeMap::new();
+        for consequence in consequences.iter()
+        {
+            let mutation=match text_parser::try_parse_consequence(consequence)
+            {
+                Ok(mutation)=>mutation,
+                Err(err_msg)=>return Err(format!("The consequence: {} of haplotype {} could not be parsed: {}",consequence,haplotype,err_msg))
+            };
+            transcripts.entry(mutation.transcript_name.to_string()).or_default().push(mutation);
+        }
+        let mut alt_transcripts=Vec::with_capacity(transcripts.len());
+        for (transcript,mutations) in transcripts
+        {
+            match self.ref_seq.contains_key(&transcript)
+            {
+                true=>alt_transcripts.push(AltTranscript::from_mutations(transcript,mutations)),
+                false=>failures.push(ServedFailure{haplotype:Some(haplotype),transcript:Some(transcript),
+                    reason:"the transcript is not part of the reference proteome".to_string()})
+            }
+        }
+        Ok(alt_transcripts)
+    }
+    /// ## Summary
+    /// Answer a request given its method, path and body, and return the HTTP status code along with the JSON body of the response.
+    /// POST /sequences expects a SequenceRequest and returns a SequenceResponse, GET /health returns the status and the number of
+    /// transcripts of the reference proteome, every error is returned as a JSON object with an error field.
+    pub fn route(&self, method:&str, path:&str, body:&[u8])->(u16,String)
+    {
+        let error=|status:u16,err_msg:String|(status,serde_json::json!({"error":err_msg}).to_string());
+        match (method,path)
+        {
+            ("GET",HEALTH_PATH)=>(200,serde_json::json!({"status":"ok","num_transcripts":self.get_num_transcripts()}).to_string()),
+            ("POST",SEQUENCES_PATH)=>
+            {
+                let request=match serde_json::from_slice::<SequenceRequest>(body)
+                {
+                    Ok(request)=>request,
+                    Err(err_msg)=>return error(400,format!("The body of the request is not a valid sequence request: {}",err_msg))
+                };
+                match self.get_sequences(request)
+                {
+                    Ok(response)=>(200,serde_json::to_string(&response).unwrap()),
+                    Err(err_msg)=>error(400,err_msg)
+                }
+            },
+            (_,HEALTH_PATH) | (_,SEQUENCES_PATH)=>error(405,format!("The method: {} is not supported for the path: {}",method,path)),
+            _=>error(404,format!("The path: {} is not known, the supported paths are {} and {}",path,SEQUENCES_PATH,HEALTH_PATH))
+        }
+    }
+    /// ## Summary
+    /// Answer the requests of the connections accepted by the listener until the listener fails, the connections are handled by a pool of
+    /// MAX_CONNECTIONS threads and each of them answers one request, i.e. the connection is closed after the response. The listener stops
+    /// accepting connections while all threads are busy. The request line, the headers and a body of Content-Length bytes are read, see
+    /// route for the paths, malformed requests are answered with the status 400, lines longer than MAX_LINE_SIZE and headers larger than
+    /// MAX_HEADER_SIZE with the status 431, except a long request line which is answered with the status 400, and bodies larger than
+    /// MAX_REQUEST_SIZE with the status 413.
+    /// ## Example
+    ///```rust,no_run
+    /// use std::collections::HashMap;
+    /// use std::net::TcpListener;
+    /// use ppgg::parts::server::SequenceServer;
+    /// let ref_seq=vec![("T1".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+    /// let listener=TcpListener::bind("127.0.0.1:8080").unwrap();
+    /// SequenceServer::new(ref_seq).serve(listener).unwrap(); // e.g. curl -X POST -d '{"sample":"S1"}' http://127.0.0.1:8080/sequences
+    ///```
+    pub fn serve(&self, listener:TcpListener)->Result<(),String>
+    {
+        // a rendezvous channel, i.e. a connection is only accepted once a thread of the pool takes the previous one 
+        let (sender,receiver)=mpsc::sync_channel::<TcpStream>(0);
+        let receiver=Arc::new(Mutex::new(receiver));
+        for _ in 0..MAX_CONNECTIONS
+        {
+            let (server,receiver)=(self.clone(),receiver.clone());
+            std::thread::spawn(move||server.handle_connections(&receiver));
+        }
+        for stream in listener.incoming()
+        {
+            match stream
+            {
+                Ok(stream)=>
+                {
+                    if sender.send(stream).is_err()
+                    {
+                        return Err("The threads answering the connections stopped".to_string())
+                    }
+                },
+                Err(err_msg)=>return Err(format!("Accepting a connection failed with the following error: {}",err_msg))
+            }
+        }
+        Ok(())
+    }
+    /// answer the connections received from serve until its sender is dropped, i.e. the loop of a thread of the pool 
+    fn handle_connections(&self, receiver:&Mutex<Receiver<TcpStream>>)
+    {
+        loop
+        {
+            let stream=match receiver.lock().map(|receiver|receiver.recv())
+            {
+                Ok(Ok(stream))=>stream,
+                _=>return
+            };
+            if let Err(err_msg)=self.handle_connection(stream)
+            {
+                log::warn!("answering a request failed with the following error: {}",err_msg);
+            }
+        }
+    }
+    /// read the request of a connection and write the response, a request that stalls for longer than READ_TIMEOUT is answered with the status 400
+    /// and a response that stalls for longer than READ_TIMEOUT is dropped
+    fn handle_connection(&self, stream:TcpStream)->Result<(),String>
+    {
+        stream.set_read_timeout(Some(READ_TIMEOUT)).map_err(|err_msg|err_msg.to_string())?;
+        stream.set_write_timeout(Some(READ_TIMEOUT)).map_err(|err_msg|err_msg.to_string())?;
+        let mut reader=BufReader::new(stream.try_clone().map_err(|err_msg|err_msg.to_string())?);
+        let (status,body)=match read_request(&mut reader)
+        {
+            Ok((method,path,body))=>self.route(&method, &path, &body),
+            Err((status,err_msg))=>(status,serde_json::json!({"error":err_msg}).to_string())
+        };
+        let reason=match status
+        {
+            200=>"OK",
+            400=>"Bad Request",
+            404=>"Not Found",
+            405=>"Method Not Allowed",
+            413=>"Payload Too Large",
+            431=>"Request Header Fields Too Large",
+            _=>"Error"
+        };
+        let mut stream=stream;
+        write!(stream,"HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",status,reason,body.len(),body)
+            .and_then(|_|stream.flush())
+            .map_err(|err_msg|err_msg.to_string())
+    }
+}
+/// read the method, the path and the body of a request, or the status and the reason of a malformed request, each line is read through 
+/// a limit, hence, a client sending no line break is rejected once MAX_LINE_SIZE bytes are read instead of filling the memory 
+fn read_request(reader:&mut BufReader<TcpStream>)->Result<(String,String,Vec<u8>),(u16,String)>
+{
+    let mut header_size=0;
+    // the status of a line exceeding MAX_LINE_SIZE is provided by the caller, while exceeding MAX_HEADER_SIZE is always answered with 431
+    let mut read_line=|status:u16|->Result<String,(u16,String)>
+    {
+        let mut line=String::new();
+        let limit=MAX_LINE_SIZE.min(MAX_HEADER_SIZE-header_size);
+        match reader.by_ref().take(limit as u64+1).read_line(&mut line)
+        {
+            Ok(num_bytes) if num_bytes>limit && limit<MAX_LINE_SIZE=>Err((431,format!("The request line and the headers exceed {} bytes",MAX_HEADER_SIZE))),
+            Ok(num_bytes) if num_bytes>limit=>Err((status,format!("A line of the request exceeds {} bytes",MAX_LINE_SIZE))),
+            Ok(num_bytes)=>
+            {
+                header_size+=num_bytes;
+                Ok(line.trim_end().to_string())
+            },
+            Err(err_msg)=>Err((400,format!("Reading the request failed with the following error: {}",err_msg)))
+        }
+    };
+    let request_line=read_line(400)?;
+    let mut fields=request_line.split_whitespace();
+    let (method,path)=match (fields.next(),fields.next())
+    {
+        (Some(method),Some(path))=>(method.to_string(),path.split('?').next().unwrap().to_string()),
+        _=>return Err((400,format!("The request line: {} is not a valid HTTP request line",request_line)))
+    };
+    let mut content_length=0;
+    loop
+    {
+        let header=read_line(431)?;
+        if header.is_empty()
+        {
+            break
+        }
+        if let Some((name,value))=header.split_once(':')
+        {
+            if name.trim().eq_ignore_ascii_case("content-length")
+            {
+                content_length=match value.trim().parse::<usize>()
+                {
+                    Ok(content_length)=>content_length,
+                    Err(_)=>return Err((400,format!("The Content-Length: {} is not a valid number",value.trim())))
+                };
+            }
+        }
+    }
+    if content_length>MAX_REQUEST_SIZE
+    {
+        return Err((413,format!("The body of the request has {} bytes, while at most {} bytes are accepted",content_length,MAX_REQUEST_SIZE)))
+    }
+    let mut body=vec![0_u8;content_length];
+    if let Err(err_msg)=reader.read_exact(&mut body)
+    {
+        return Err((400,format!("Reading the body of the request failed with the following error: {}",err_msg)))
+    }
+    Ok((method,path,body))
+}
+
+#[cfg(test)]
+mod test_server
+{
+    use super::*;
+    #[test]
+    fn test_serve_sequences()
+    {
+        let ref_seq=vec![("T1".to_string(),"MKLV".to_string()),("T2".to_string(),"MEDLGENTMV".to_string())].into_iter().collect::<HashMap<String,String>>();
+        let listener=TcpListener::bind("127.0.0.1:0").unwrap();
+        let address=listener.local_addr().unwrap();
+        let server=SequenceServer::new(ref_seq);
+        std::thread::spawn(move||server.serve(listener));
+        let query=|request:&str|->String
+        {
+            let mut stream=TcpStream::connect(address).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response=String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+        let body=r#"{"sample":"S1","haplotype1":["missense|G1|T1|protein_coding|+|2K>2N|10A>T","stop_gained|G2|T2|protein_coding|+|5G>5*|20G>T"],
+            "haplotype2":["missense|G3|T3|protein_coding|+|2K>2N|30A>T"]}"#;
+        let response=query(&format!("POST /sequences HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",body.len(),body));
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        let response:SequenceResponse=serde_json::from_str(response.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(response.sequences["T1"],HaplotypePair{haplotype1:"MNLV".to_string(),haplotype2:"MKLV".to_string()});
+        assert_eq!(response.sequences["T2"].haplotype1,"MEDL");
+        // T3 is not part of the reference proteome
+        assert_eq!(response.failures.iter().map(|failure|(failure.haplotype,failure.transcript.clone())).collect::<Vec<_>>(),vec![(Some(2),Some("T3".to_string()))]);
+        // the settings of a request apply to this request only 
+        let overlapping=r#"{"sample":"S1","haplotype1":["missense|G1|T1|protein_coding|+|2K>2N|10A>T","missense|G1|T1|protein_coding|+|2K>2Q|10A>C"]"#;
+        let post=|body:String|query(&format!("POST /sequences HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",body.len(),body));
+        let get_sequences=|response:String|serde_json::from_str::<SequenceResponse>(response.split("\r\n\r\n").nth(1).unwrap()).unwrap().sequences;
+        let dropped=get_sequences(post(format!(r#"{},"settings":{{"overlap_policy":"drop-transcript"}}}}"#,overlapping)));
+        let kept=get_sequences(post(format!(r#"{},"settings":{{"overlap_policy":"keep-first"}}}}"#,overlapping)));
+        assert!(!dropped.contains_key("T1") && kept.contains_key("T1"));
+        assert!(post(format!(r#"{},"settings":{{"checks":"unknown"}}}}"#,overlapping)).starts_with("HTTP/1.1 400"));
+        assert!(query("GET /health HTTP/1.1\r\n\r\n").ends_with(r#"{"num_transcripts":2,"status":"ok"}"#));
+        assert!(query("POST /sequences HTTP/1.1\r\nContent-Length: 13\r\n\r\n{\"sample\":\"\"}").starts_with("HTTP/1.1 400"));
+        assert!(query("GET /unknown HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 404"));
+    }
+    #[test]
+    fn test_request_limits()
+    {
+        let ref_seq=vec![("T1".to_string(),"MKLV".to_string())].into_iter().collect::<HashMap<String,String>>();
+        let listener=TcpListener::bind("127.0.0.1:0").unwrap();
+        let address=listener.local_addr().unwrap();
+        let server=SequenceServer::new(ref_seq);
+        std::thread::spawn(move||server.serve(listener));
+        // the unread part of a rejected request may reset the connection after the response, hence, the response is read until an error 
+        let read_response=|stream:&mut TcpStream|->String
+        {
+            let mut response=Vec::new();
+            let mut buffer=[0_u8;1024];
+            while let Ok(num_bytes)=stream.read(&mut buffer)
+            {
+                if num_bytes==0
+                {
+                    break
+                }
+                response.extend_from_slice(&buffer[..num_bytes]);
+            }
+            String::from_utf8_lossy(&response).to_string()
+        };
+        let query=|request:&str|->String
+        {
+            let mut stream=TcpStream::connect(address).unwrap();
+            let _=stream.write_all(request.as_bytes());
+            read_response(&mut stream)
+        };
+        let long_value="A".repeat(MAX_LINE_SIZE);
+        assert!(query(&format!("GET /health?{} HTTP/1.1\r\n\r\n",long_value)).starts_with("HTTP/1.1 400"));
+        assert!(query(&format!("GET /health HTTP/1.1\r\nX-Long: {}\r\n\r\n",long_value)).starts_with("HTTP/1.1 431"));
+        // a line without a line break is rejected once the limit is read, although the client does not close the connection 
+        let mut stream=TcpStream::connect(address).unwrap();
+        stream.write_all(format!("GET /health HTTP/1.1\r\nX-Long: {}",long_value).as_bytes()).unwrap();
+        assert!(read_response(&mut stream).starts_with("HTTP/1.1 431"));
+        let get_headers=|num_headers:usize|(0..num_headers).map(|index|format!("X-Header-{}: {}\r\n",index,"A".repeat(1000))).collect::<String>();
+        assert!(query(&format!("GET /health HTTP/1.1\r\n{}\r\n",get_headers(MAX_HEADER_SIZE/1000))).starts_with("HTTP/1.1 431"));
+        assert!(query(&format!("GET /health HTTP/1.1\r\n{}\r\n",get_headers(MAX_HEADER_SIZE/2000))).starts_with("HTTP/1.1 200"));
+        // the connections beyond MAX_CONNECTIONS wait until a connection is closed 
+        let idle=(0..MAX_CONNECTIONS).map(|_|TcpStream::connect(address).unwrap()).collect::<Vec<TcpStream>>();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        let mut waiting=TcpStream::connect(address).unwrap();
+        waiting.write_all(b"GET /health HTTP/1.1\r\n\r\n").unwrap();
+        waiting.set_read_timeout(Some(std::time::Duration::from_millis(500))).unwrap();
+        assert!(waiting.read(&mut [0_u8;16]).is_err());
+        drop(idle);
+        waiting.set_read_timeout(None).unwrap();
+        assert!(read_response(&mut waiting).starts_with("HTTP/1.1 200"));
+    }
+}
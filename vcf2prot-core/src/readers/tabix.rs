@@ -0,0 +1,646 @@
+/// The module fetches the records of a bgzipped VCF file that overlap a set of genomic regions through the tabix (.tbi) or CSI (.csi) index
+/// of the file, i.e. only the BGZF blocks the index lists for the bins of a region are decompressed instead of the whole file, which makes
+/// the analysis of a single gene independent of the size of the cohort file. The records are returned in the order of the file, each record
+/// once, even if it overlaps more than one region, along with the header of the file, so they can be processed like the lines of read_file.
+use std::collections::{BTreeMap,HashMap};
+use std::convert::TryInto;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read,Seek,SeekFrom};
+use std::path::{Path,PathBuf};
+use std::str::FromStr;
+use flate2::read::{GzDecoder,MultiGzDecoder};
+
+/// The largest position a tabix index can address, i.e. 2^29 for the default binning scheme of .tbi files
+const TBI_MAX_POSITION:u64=1<<29;
+
+/// ## Summary
+/// A genomic region, i.e. a contig and a one-based closed interval on it, parsed from CHR, CHR:POS or CHR:START-END, where the thousands
+/// separators of the positions are ignored, e.g. chr17:43,044,295-43,125,483
+/// ## Example
+///```rust
+/// use std::str::FromStr;
+/// use ppgg::readers::tabix::Region;
+/// let region=Region::from_str("chr17:43,044,295-43,125,483").unwrap();
+/// assert_eq!((region.chrom.as_str(),region.start,region.end),("chr17",43_044_295,43_125_483));
+/// assert_eq!(Region::from_str("chr1").unwrap().start,1);
+/// assert_eq!(Region::from_str("chr1:100").unwrap().end,100);
+/// assert!(Region::from_str("chr1:200-100").is_err());
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Region
+{
+    pub chrom:String,
+    pub start:u64,
+    pub end:u64
+}
+impl FromStr for Region
+{
+    type Err=String;
+    fn from_str(region:&str)->Result<Region,String>
+    {
+        let parse_position=|position:&str|match position.replace(',',"").parse::<u64>()
+        {
+            Ok(0) | Err(_)=>Err(format!("The position: {} of the region: {} is not a valid one-based position",position,region)),
+            Ok(position)=>Ok(position)
+        };
+        let (chrom,start,end)=match region.trim().rsplit_once(':')
+        {
+            None=>(region.trim(),1,u64::MAX),
+            Some((chrom,interval))=>match interval.split_once('-')
+            {
+                Some((start,end))=>(chrom,parse_position(start)?,parse_position(end)?),
+                None=>(chrom,parse_position(interval)?,parse_position(interval)?)
+            }
+        };
+        if chrom.is_empty()
+        {
+            return Err(format!("The region: {} does not define a contig, regions are defined as CHR, CHR:POS or CHR:START-END",region))
+        }
+        if start>end
+        {
+            return Err(format!("The start of the region: {} is after its end",region))
+        }
+        Ok(Region{chrom:chrom.to_string(),start,end})
+    }
+}
+impl fmt::Display for Region
+{
+    fn fmt(&self, f:&mut fmt::Formatter)->fmt::Result
+    {
+        match self.end
+        {
+            u64::MAX=>write!(f,"{}",self.chrom),
+            end=>write!(f,"{}:{}-{}",self.chrom,self.start,end)
+        }
+    }
+}
+impl Region
+{
+    /// ## Summary
+    /// Return whether a record, i.e. its contig, its one-based position and the length of its reference allele, overlaps the region
+    pub fn overlaps(&self, chrom:&str, position:u64, ref_length:u64)->bool
+    {
+        chrom==self.chrom && position<=self.end && position+ref_length.max(1)>self.start
+    }
+}
+
+/// A range of virtual offsets of a BGZF file, i.e. the offset of a compressed block shifted by 16 bits plus the offset within the block
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+struct Chunk
+{
+    begin:u64,
+    end:u64
+}
+
+/// The index of a contig, i.e. the chunks of each bin, the smallest virtual offset of each bin of a CSI index and the linear index of a
+/// tabix index
+#[derive(Debug,Clone,Default)]
+struct ContigIndex
+{
+    bins:HashMap<u32,Vec<Chunk>>,
+    bin_offsets:HashMap<u32,u64>,
+    linear_index:Vec<u64>
+}
+
+/// ## Summary
+/// A tabix (.tbi) or CSI (.csi) index of a bgzipped VCF file, i.e. the names of the indexed contigs and the binning index of each of them,
+/// see the tabix and CSI specifications of htslib
+#[derive(Debug,Clone)]
+pub struct TabixIndex
+{
+    min_shift:u32,
+    depth:u32,
+    contigs:Vec<String>,
+    indices:Vec<ContigIndex>
+}
+impl TabixIndex
+{
+    /// ## Summary
+    /// Return the path of the index of a bgzipped VCF file, i.e. the file with a .tbi or a .csi suffix next to it, the tabix index is
+    /// preferred if both exist, an error is returned if none of them exists
+    pub fn locate(path2vcf:&Path)->Result<PathBuf,String>
+    {
+        for suffix in [".tbi",".csi"]
+        {
+            let mut path2index=path2vcf.as_os_str().to_owned();
+            path2index.push(suffix);
+            let path2index=PathBuf::from(path2index);
+            if path2index.exists()
+            {
+                return Ok(path2index)
+            }
+        }
+        Err(format!("Could not find the index of: {}, neither {}.tbi nor {}.csi exists, the file can be indexed using: tabix -p vcf {}",
+            path2vcf.display(),path2vcf.display(),path2vcf.display(),path2vcf.display()))
+    }
+    /// ## Summary
+    /// Read a tabix or a CSI index, the format is detected from the magic bytes of the decompressed index
+    pub fn from_file(path2index:&Path)->Result<Self,String>
+    {
+        let mut content=Vec::new();
+        match File::open(path2index).and_then(|file|MultiGzDecoder::new(file).read_to_end(&mut content))
+        {
+            Ok(_)=>(),
+            Err(err_msg)=>return Err(format!("Reading the index: {} failed with the following error: {}",path2index.display(),err_msg))
+        };
+        match TabixIndex::from_bytes(&content)
+        {
+            Ok(index)=>Ok(index),
+            Err(err_msg)=>Err(format!("Parsing the index: {} failed with the following error: {}",path2index.display(),err_msg))
+        }
+    }
+    /// ## Summary
+    /// Parse the decompressed content of a tabix or a CSI index
+    pub fn from_bytes(content:&[u8])->Result<Self,String>
+    {
+        let mut cursor=ByteCursor{content,position:0};
+        let magic=cursor.read_bytes(4)?;
+        let is_csi=match magic
+        {
+            b"TBI\x01"=>false,
+            b"CSI\x01"=>true,
+            _=>return Err("The file is neither a tabix (.tbi) nor a CSI (.csi) index".to_string())
+        };
+        let (min_shift,depth,contigs)=match is_csi
+        {
+            false=>
+            {
+                let num_contigs=cursor.read_count()?;
+                let contigs=TabixIndex::parse_header(&mut cursor)?;
+                if contigs.len()!=num_contigs
+                {
+                    return Err(format!("The index defines {} contigs, however, {} contig names were found",num_contigs,contigs.len()))
+                }
+                (14,5,contigs)
+            },
+            true=>
+            {
+                let min_shift=cursor.read_count()? as u32;
+                let depth=cursor.read_count()? as u32;
+                if min_shift as u64+3*depth as u64>62
+                {
+                    return Err(format!("The CSI index uses an unsupported binning scheme, i.e. a minimum shift of {} and a depth of {}",min_shift,depth))
+                }
+                let aux_length=cursor.read_count()?;
+                let mut aux=ByteCursor{content:cursor.read_bytes(aux_length)?,position:0};
+                let contigs=match aux_length
+                {
+                    0=>Vec::new(),
+                    _=>TabixIndex::parse_header(&mut aux)?
+                };
+                let num_contigs=cursor.read_count()?;
+                if !contigs.is_empty() && contigs.len()!=num_contigs
+                {
+                    return Err(format!("The index defines {} contigs, however, {} contig names were found",num_contigs,contigs.len()))
+                }
+                if contigs.is_empty() && num_contigs!=0
+                {
+                    return Err("The CSI index does not store the names of its contigs, which is needed to query it by contig names".to_string())
+                }
+                (min_shift,depth,contigs)
+            }
+        };
+        let mut indices=Vec::with_capacity(contigs.len());
+        for _ in 0..contigs.len()
+        {
+            let mut contig_index=ContigIndex::default();
+            for _ in 0..cursor.read_count()?
+            {
+                let bin=cursor.read_u32()?;
+                if is_csi
+                {
+                    contig_index.bin_offsets.insert(bin,cursor.read_u64()?);
+                }
+                let num_chunks=cursor.read_count()?;
+                let mut chunks=Vec::with_capacity(num_chunks);
+                for _ in 0..num_chunks
+                {
+                    chunks.push(Chunk{begin:cursor.read_u64()?,end:cursor.read_u64()?});
+                }
+                contig_index.bins.insert(bin,chunks);
+            }
+            if !is_csi
+            {
+                for _ in 0..cursor.read_count()?
+                {
+                    contig_index.linear_index.push(cursor.read_u64()?);
+                }
+            }
+            indices.push(contig_index);
+        }
+        Ok(TabixIndex{min_shift,depth,contigs,indices})
+    }
+    /// ## Summary
+    /// Return the names of the indexed contigs in the order of the index
+    pub fn get_contigs(&self)->&[String]
+    {
+        &self.contigs
+    }
+    /// parse the tabix header, i.e. the format, the columns, the meta character, the number of skipped lines and the contig names
+    fn parse_header(cursor:&mut ByteCursor)->Result<Vec<String>,String>
+    {
+        // the format, the sequence, begin and end columns, the meta character and the number of skipped lines are fixed for VCF files
+        cursor.read_bytes(24)?;
+        let names_length=cursor.read_count()?;
+        let names=cursor.read_bytes(names_length)?;
+        Ok(names.split(|byte|*byte==0).filter(|name|!name.is_empty()).map(|name|String::from_utf8_lossy(name).to_string()).collect())
+    }
+    /// return the merged chunks that might contain records overlapping the region, in the order of the file, or None if the contig is not indexed
+    fn get_chunks(&self, region:&Region)->Option<Vec<Chunk>>
+    {
+        let contig_index=&self.indices[self.contigs.iter().position(|contig|*contig==region.chrom)?];
+        let max_position=1_u64<<(self.min_shift+3*self.depth);
+        let max_position=if self.depth==5 && self.min_shift==14 {max_position.min(TBI_MAX_POSITION)} else {max_position};
+        // the bins use zero-based half-open intervals
+        let begin=(region.start-1).min(max_position-1);
+        let end=region.end.min(max_position);
+        let min_offset=match contig_index.linear_index.is_empty()
+        {
+            false=>contig_index.linear_index[((begin>>self.min_shift) as usize).min(contig_index.linear_index.len()-1)],
+            true=>self.get_bin_offset(contig_index,begin)
+        };
+        let mut chunks=TabixIndex::get_bins(begin,end,self.min_shift,self.depth).iter()
+            .filter_map(|bin|contig_index.bins.get(bin))
+            .flat_map(|chunks|chunks.iter().filter(|chunk|chunk.end>min_offset).copied())
+            .collect::<Vec<Chunk>>();
+        chunks.sort_by_key(|chunk|chunk.begin);
+        let mut merged:Vec<Chunk>=Vec::with_capacity(chunks.len());
+        for chunk in chunks
+        {
+            match merged.last_mut()
+            {
+                Some(last) if chunk.begin<=last.end=>last.end=last.end.max(chunk.end),
+                _=>merged.push(Chunk{begin:chunk.begin.max(min_offset),end:chunk.end})
+            }
+        }
+        Some(merged)
+    }
+    /// return the smallest virtual offset of the records starting at or after a position in a CSI index, i.e. the offset of the deepest
+    /// indexed bin containing the position
+    fn get_bin_offset(&self, contig_index:&ContigIndex, position:u64)->u64
+    {
+        let mut bin=(((1_u64<<(3*self.depth))-1)/7+(position>>self.min_shift)) as u32;
+        loop
+        {
+            if let Some(offset)=contig_index.bin_offsets.get(&bin)
+            {
+                return *offset
+            }
+            if bin==0
+            {
+                return 0
+            }
+            bin=(bin-1)>>3;
+        }
+    }
+    /// return the bins overlapping a zero-based half-open interval, see hts_reg2bins of htslib
+    fn get_bins(begin:u64, end:u64, min_shift:u32, depth:u32)->Vec<u32>
+    {
+        let mut bins=Vec::new();
+        if begin>=end
+        {
+            return bins
+        }
+        let mut shift=min_shift+3*depth;
+        let end=end.min(1_u64<<shift)-1;
+        let mut first_bin=0_u64;
+        for level in 0..=depth
+        {
+            for bin in first_bin+(begin>>shift)..=first_bin+(end>>shift)
+            {
+                bins.push(bin as u32);
+            }
+            first_bin+=1_u64<<(3*level);
+            shift-=3;
+        }
+        bins
+    }
+}
+
+/// A cursor over the little-endian content of an index
+struct ByteCursor<'a>
+{
+    content:&'a [u8],
+    position:usize
+}
+impl<'a> ByteCursor<'a>
+{
+    fn read_bytes(&mut self, num_bytes:usize)->Result<&'a [u8],String>
+    {
+        match self.content.get(self.position..self.position+num_bytes)
+        {
+            Some(bytes)=>
+            {
+                self.position+=num_bytes;
+                Ok(bytes)
+            },
+            None=>Err("The index is truncated".to_string())
+        }
+    }
+    fn read_u32(&mut self)->Result<u32,String>
+    {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+    fn read_u64(&mut self)->Result<u64,String>
+    {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+    /// read a signed 32 bits count, negative counts are invalid
+    fn read_count(&mut self)->Result<usize,String>
+    {
+        match i32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap())
+        {
+            count if count<0=>Err(format!("The index contains a negative count: {}",count)),
+            count=>Ok(count as usize)
+        }
+    }
+}
+
+/// A reader of the lines of a BGZF file that can be positioned at a virtual offset, where only the blocks that are read are decompressed
+struct BgzfReader
+{
+    file:File,
+    block:Vec<u8>,
+    block_offset:Option<u64>,
+    next_block_offset:u64,
+    position:usize
+}
+impl BgzfReader
+{
+    fn new(file:File)->Self
+    {
+        BgzfReader{file,block:Vec::new(),block_offset:None,next_block_offset:0,position:0}
+    }
+    /// position the reader at a virtual offset
+    fn seek(&mut self, virtual_offset:u64)->Result<(),String>
+    {
+        let block_offset=virtual_offset>>16;
+        if self.block_offset!=Some(block_offset)
+        {
+            self.load_block(block_offset)?;
+        }
+        self.position=((virtual_offset & 0xffff) as usize).min(self.block.len());
+        Ok(())
+    }
+    /// return the virtual offset of the next byte, the end of a block is reported as the beginning of the next block
+    fn tell(&self)->u64
+    {
+        match self.block_offset
+        {
+            Some(block_offset) if self.position<self.block.len()=>(block_offset<<16)|self.position as u64,
+            _=>self.next_block_offset<<16
+        }
+    }
+    /// decompress the block at the provided offset of the file, returns false at the end of the file
+    fn load_block(&mut self, block_offset:u64)->Result<bool,String>
+    {
+        self.block.clear();
+        self.position=0;
+        self.block_offset=Some(block_offset);
+        self.next_block_offset=block_offset;
+        let mut header=[0_u8;12];
+        let num_read=self.file.seek(SeekFrom::Start(block_offset)).and_then(|_|read_up_to(&mut self.file,&mut header));
+        match num_read
+        {
+            Ok(0)=>return Ok(false),
+            Ok(12)=>(),
+            Ok(_)=>return Err(format!("The BGZF block at offset: {} is truncated",block_offset)),
+            Err(err_msg)=>return Err(format!("Reading the BGZF block at offset: {} failed with the following error: {}",block_offset,err_msg))
+        }
+        if header[0..4]!=[0x1f,0x8b,8,4]
+        {
+            return Err("The VCF file is not compressed with bgzip, which is needed for querying it through its index, the file can be \
+                compressed using: bgzip FILE".to_string())
+        }
+        let mut extra=vec![0_u8;u16::from_le_bytes([header[10],header[11]]) as usize];
+        if let Err(err_msg)=self.file.read_exact(&mut extra)
+        {
+            return Err(format!("Reading the BGZF block at offset: {} failed with the following error: {}",block_offset,err_msg))
+        }
+        let mut block_size=None;
+        let mut sub_fields=&extra[..];
+        while sub_fields.len()>=4
+        {
+            let length=u16::from_le_bytes([sub_fields[2],sub_fields[3]]) as usize;
+            if sub_fields[0..2]==*b"BC" && length==2 && sub_fields.len()>=6
+            {
+                block_size=Some(u16::from_le_bytes([sub_fields[4],sub_fields[5]]) as usize+1);
+            }
+            sub_fields=&sub_fields[(4+length).min(sub_fields.len())..];
+        }
+        let block_size=match block_size
+        {
+            Some(block_size) if block_size>=12+extra.len()=>block_size,
+            _=>return Err(format!("The gzip member at offset: {} is not a BGZF block, the VCF file must be compressed with bgzip",block_offset))
+        };
+        let mut compressed=header.to_vec();
+        compressed.extend_from_slice(&extra);
+        compressed.resize(block_size,0);
+        if let Err(err_msg)=self.file.read_exact(&mut compressed[12+extra.len()..])
+        {
+            return Err(format!("Reading the BGZF block at offset: {} failed with the following error: {}",block_offset,err_msg))
+        }
+        if let Err(err_msg)=GzDecoder::new(&compressed[..]).read_to_end(&mut self.block)
+        {
+            return Err(format!("Decompressing the BGZF block at offset: {} failed with the following error: {}",block_offset,err_msg))
+        }
+        self.next_block_offset=block_offset+block_size as u64;
+        Ok(true)
+    }
+    /// read the next line without its line break, returns None at the end of the file
+    fn read_line(&mut self)->Result<Option<String>,String>
+    {
+        let mut line=Vec::new();
+        loop
+        {
+            if self.block_offset.is_none() || self.position>=self.block.len()
+            {
+                let next_block_offset=self.next_block_offset;
+                if !self.load_block(next_block_offset)?
+                {
+                    return Ok(match line.is_empty()
+                    {
+                        true=>None,
+                        false=>Some(String::from_utf8_lossy(&line).to_string())
+                    })
+                }
+                continue
+            }
+            match self.block[self.position..].iter().position(|byte|*byte==b'\n')
+            {
+                Some(length)=>
+                {
+                    line.extend_from_slice(&self.block[self.position..self.position+length]);
+                    self.position+=length+1;
+                    if line.last()==Some(&b'\r')
+                    {
+                        line.pop();
+                    }
+                    return Ok(Some(String::from_utf8_lossy(&line).to_string()))
+                },
+                None=>
+                {
+                    line.extend_from_slice(&self.block[self.position..]);
+                    self.position=self.block.len();
+                }
+            }
+        }
+    }
+}
+
+/// read as many bytes as possible into the buffer, i.e. until the buffer is full or the end of the file is reached
+fn read_up_to(file:&mut File, buffer:&mut [u8])->std::io::Result<usize>
+{
+    let mut num_read=0;
+    while num_read<buffer.len()
+    {
+        match file.read(&mut buffer[num_read..])?
+        {
+            0=>break,
+            count=>num_read+=count
+        }
+    }
+    Ok(num_read)
+}
+
+/// ## Summary
+/// Return the header lines of a bgzipped VCF file followed by its records overlapping at least one of the regions, in the order of the
+/// file, where only the blocks the index lists for the regions are decompressed, see TabixIndex::locate. A record overlaps a region if
+/// the interval spanned by its reference allele intersects the region. The regions of contigs that are not part of the index are
+/// returned separately, as they do not contain any record.
+pub fn fetch_regions(path2vcf:&Path, regions:&[Region])->Result<(Vec<String>,Vec<Region>),String>
+{
+    let index=TabixIndex::from_file(&TabixIndex::locate(path2vcf)?)?;
+    let file=match File::open(path2vcf)
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Could not open the VCF file: {}, the following error was generated: {}",path2vcf.display(),err_msg))
+    };
+    let mut reader=BgzfReader::new(file);
+    let mut lines=Vec::new();
+    reader.seek(0)?;
+    while let Some(line)=reader.read_line()?
+    {
+        let is_column_header=line.starts_with("#CHROM");
+        if !line.starts_with('#')
+        {
+            return Err("The VCF file does not contain a column header, i.e. a line starting with #CHROM".to_string())
+        }
+        lines.push(line);
+        if is_column_header
+        {
+            break
+        }
+    }
+    let mut records=BTreeMap::new();
+    let mut missing_regions=Vec::new();
+    for region in regions.iter()
+    {
+        let chunks=match index.get_chunks(region)
+        {
+            Some(chunks)=>chunks,
+            None=>
+            {
+                missing_regions.push(region.clone());
+                continue
+            }
+        };
+        for chunk in chunks
+        {
+            reader.seek(chunk.begin)?;
+            while reader.tell()<chunk.end
+            {
+                let virtual_offset=reader.tell();
+                let line=match reader.read_line()?
+                {
+                    Some(line)=>line,
+                    None=>break
+                };
+                let mut fields=line.splitn(5,'\t');
+                let (chrom,position,ref_allele)=match (fields.next(),fields.next().map(|position|position.parse::<u64>()),fields.nth(1))
+                {
+                    (Some(chrom),Some(Ok(position)),Some(ref_allele))=>(chrom,position,ref_allele),
+                    _=>continue
+                };
+                if chrom==region.chrom && position>region.end
+                {
+                    break
+                }
+                if region.overlaps(chrom,position,ref_allele.len() as u64)
+                {
+                    records.entry(virtual_offset).or_insert(line);
+                }
+            }
+        }
+    }
+    lines.extend(records.into_values());
+    Ok((lines,missing_regions))
+}
+
+#[cfg(test)]
+mod test_tabix
+{
+    use super::*;
+    use std::io::Write;
+    use flate2::write::DeflateEncoder;
+    use flate2::{Compression,Crc};
+    /// compress the provided content into a single BGZF block
+    fn to_bgzf_block(content:&[u8])->Vec<u8>
+    {
+        let mut encoder=DeflateEncoder::new(Vec::new(),Compression::default());
+        encoder.write_all(content).unwrap();
+        let compressed=encoder.finish().unwrap();
+        let mut block=vec![0x1f,0x8b,8,4,0,0,0,0,0,0xff,6,0,b'B',b'C',2,0];
+        block.extend_from_slice(&((compressed.len()+25) as u16).to_le_bytes());
+        block.extend_from_slice(&compressed);
+        let mut crc=Crc::new();
+        crc.update(content);
+        block.extend_from_slice(&crc.sum().to_le_bytes());
+        block.extend_from_slice(&(content.len() as u32).to_le_bytes());
+        block
+    }
+    #[test]
+    fn test_fetch_regions()
+    {
+        // the header and the records of each contig are stored in separate blocks, followed by the empty end-of-file block
+        let header="##fileformat=VCFv4.2\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tS1\n";
+        let contig1="1\t100\t.\tA\tT\t.\tPASS\t.\tGT\t0|1\n1\t200\t.\tACGT\tA\t.\tPASS\t.\tGT\t0|1\n1\t20000\t.\tA\tT\t.\tPASS\t.\tGT\t0|1\n";
+        let contig2="2\t150\t.\tC\tG\t.\tPASS\t.\tGT\t1|1\n";
+        let blocks=[header,contig1,contig2,""].iter().map(|content|to_bgzf_block(content.as_bytes())).collect::<Vec<Vec<u8>>>();
+        let offsets=blocks.iter().scan(0_u64,|offset,block|{let start=*offset; *offset+=block.len() as u64; Some(start)}).collect::<Vec<u64>>();
+        let path2vcf=std::env::temp_dir().join("vcf2prot_test_fetch_regions.vcf.gz");
+        std::fs::write(&path2vcf,blocks.concat()).unwrap();
+        // a tabix index, where all the records of a contig are stored in the root bin
+        let mut index=b"TBI\x01".to_vec();
+        index.extend_from_slice(&2_i32.to_le_bytes());
+        for value in [2_i32,1,2,0,b'#' as i32,0]
+        {
+            index.extend_from_slice(&value.to_le_bytes());
+        }
+        index.extend_from_slice(&4_i32.to_le_bytes());
+        index.extend_from_slice(b"1\x002\x00");
+        for (begin,end) in [(offsets[1],offsets[2]),(offsets[2],offsets[3])]
+        {
+            index.extend_from_slice(&1_i32.to_le_bytes());
+            index.extend_from_slice(&0_u32.to_le_bytes());
+            index.extend_from_slice(&1_i32.to_le_bytes());
+            index.extend_from_slice(&(begin<<16).to_le_bytes());
+            index.extend_from_slice(&(end<<16).to_le_bytes());
+            index.extend_from_slice(&1_i32.to_le_bytes());
+            index.extend_from_slice(&(begin<<16).to_le_bytes());
+        }
+        let mut path2index=path2vcf.as_os_str().to_owned();
+        path2index.push(".tbi");
+        std::fs::write(&path2index,to_bgzf_block(&index)).unwrap();
+        let regions=["1:150-300","2","1:180-210","3:1-10"].iter().map(|region|Region::from_str(region).unwrap()).collect::<Vec<Region>>();
+        let (lines,missing_regions)=fetch_regions(&path2vcf,&regions).unwrap();
+        // the deletion at 200 spans the first region and is returned once, the record at 100 ends before the region starts
+        assert_eq!(lines.len(),4);
+        assert!(lines[1].starts_with("#CHROM"));
+        assert_eq!(lines[2..].iter().map(|line|line.split('\t').nth(1).unwrap()).collect::<Vec<&str>>(),vec!["200","150"]);
+        assert_eq!(missing_regions,vec![Region::from_str("3:1-10").unwrap()]);
+        assert!(fetch_regions(&std::env::temp_dir().join("vcf2prot_test_fetch_regions_missing.vcf.gz"),&regions).is_err());
+        assert_eq!(TabixIndex::get_bins(0,1,14,5),vec![0,1,9,73,585,4681]);
+    }
+}
@@ -0,0 +1,193 @@
+/// The module provides the entry point of the wasm32 builds, e.g. a web page previewing the effect of a few variants on the proteins of a
+/// gene, i.e. a small VCF file and the reference sequences of the affected transcripts are passed as strings and the mutated sequences are
+/// returned as a fasta string, see preview, where the settings of each call, e.g. the overlap policy, are passed with the call, see
+/// preview_with_options. The pipeline runs on the calling thread with Engine::ST, hence, no thread pool is started and
+/// nothing is read from or written to the disk. The raw exports below let JavaScript call preview through the memory of the module.
+use std::cell::RefCell;
+use std::slice;
+use crate::data_structures::InternalRep::engines::Engine;
+use crate::parts::api::{self,Options};
+use crate::readers;
+
+thread_local!
+{
+    /// the output of the last call to vcf2prot_wasm_preview, i.e. the fasta string or the error message
+    static OUTPUT:RefCell<Vec<u8>>=const { RefCell::new(Vec::new()) };
+}
+
+/// ## Summary
+/// Generate the mutated sequences of the samples of a VCF file using the reference sequences, both provided as strings, and return them as a
+/// fasta string, where the altered transcripts of each sample are written haplotype by haplotype and named {transcript}_{haplotype} followed
+/// by the sample, e.g. >ENST00000001_1 sample=HG00096. Transcripts that can not be personalized are skipped, while an error is returned if
+/// one of the strings can not be parsed.
+/// ## Example
+///```rust
+/// let sequences=ppgg::wasm::preview(ppgg::demo::DEMO_VCF,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// assert!(sequences.starts_with('>') && sequences.contains(" sample=HG00096\n"));
+///```
+pub fn preview(vcf:&str, reference:&str)->Result<String,String>
+{
+    preview_with_options(vcf, reference, Options::default())
+}
+
+/// ## Summary
+/// Similar to preview, however, the pipeline runs with the provided options, e.g. a selection of samples or an overlap policy, see
+/// Options::set, which apply to this call only
+/// ## Example
+///```rust
+/// let mut options=ppgg::Options::default();
+/// options.set("overlap_policy","drop-transcript").unwrap();
+/// let sequences=ppgg::wasm::preview_with_options(ppgg::demo::DEMO_VCF,ppgg::demo::DEMO_REFERENCE,options).unwrap();
+/// assert!(sequences.is_empty() || sequences.contains(" sample=HG00096\n"));
+///```
+pub fn preview_with_options(vcf:&str, reference:&str, options:Options)->Result<String,String>
+{
+    let reference=readers::read_fasta_from_reader(reference.as_bytes(), Engine::ST)?;
+    let proteomes=api::generate(vcf.as_bytes(), &reference, options)?;
+    let mut sequences=String::new();
+    for genome in proteomes.genomes.iter()
+    {
+        let (seq_tape1,seq_tape2)=genome.get_seq_tapes();
+        for (haplotype,seq_tape) in [(1,seq_tape1),(2,seq_tape2)]
+        {
+            for transcript in seq_tape.get_ordered_keys()
+            {
+                sequences.push_str(&format!(">{}_{} sample={}\n{}\n",transcript,haplotype,genome.get_proband_name(),seq_tape.get_seq(transcript)?));
+            }
+        }
+    }
+    Ok(sequences)
+}
+
+/// ## Summary
+/// Allocate a buffer of len bytes in the memory of the module, used by the caller to pass the VCF and the fasta strings to vcf2prot_wasm_preview
+#[no_mangle]
+pub extern "C" fn vcf2prot_wasm_alloc(len:usize)->*mut u8
+{
+    let mut buffer=Vec::<u8>::with_capacity(len);
+    let ptr=buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// ## Summary
+/// Release a buffer allocated by vcf2prot_wasm_alloc
+/// ## Safety
+/// the buffer must be null or allocated by vcf2prot_wasm_alloc with the same len and not yet released
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_wasm_free(ptr:*mut u8, len:usize)
+{
+    if !ptr.is_null()
+    {
+        drop(Vec::from_raw_parts(ptr, 0, len));
+    }
+}
+
+/// ## Summary
+/// Run preview on the UTF-8 strings stored in the memory of the module, returns 0 on success and 1 on failure, where the output, i.e. the
+/// fasta string or the error message, is read using vcf2prot_wasm_output_ptr and vcf2prot_wasm_output_len
+/// ## Safety
+/// the pointers must point to vcf_len and fasta_len readable bytes, respectively
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_wasm_preview(vcf_ptr:*const u8, vcf_len:usize, fasta_ptr:*const u8, fasta_len:usize)->i32
+{
+    vcf2prot_wasm_preview_with_settings(vcf_ptr, vcf_len, fasta_ptr, fasta_len, std::ptr::null(), 0)
+}
+
+/// ## Summary
+/// Similar to vcf2prot_wasm_preview, however, the settings of the call are passed as a UTF-8 string of name=value lines, e.g.
+/// overlap_policy=drop-transcript, see Options::set, where the default options are used if the settings are null or empty. The settings
+/// apply to this call only.
+/// ## Safety
+/// the pointers must point to vcf_len, fasta_len and settings_len readable bytes, respectively, where the settings may be null
+#[no_mangle]
+pub unsafe extern "C" fn vcf2prot_wasm_preview_with_settings(vcf_ptr:*const u8, vcf_len:usize, fasta_ptr:*const u8, fasta_len:usize,
+    settings_ptr:*const u8, settings_len:usize)->i32
+{
+    let to_str=|ptr:*const u8, len:usize, name:&str|match ptr.is_null()
+    {
+        true=>Err(format!("The {} is null",name)),
+        false=>std::str::from_utf8(slice::from_raw_parts(ptr, len)).map_err(|err_msg|format!("The {} is not valid UTF-8: {}",name,err_msg))
+    };
+    let settings=match settings_ptr.is_null()
+    {
+        true=>Ok(""),
+        false=>to_str(settings_ptr, settings_len, "settings string")
+    };
+    let results=settings.and_then(get_options).and_then(|options|to_str(vcf_ptr, vcf_len, "VCF string")
+        .and_then(|vcf|to_str(fasta_ptr, fasta_len, "fasta string").and_then(|reference|preview_with_options(vcf, reference, options))));
+    let (code,output)=match results
+    {
+        Ok(sequences)=>(0,sequences),
+        Err(err_msg)=>(1,err_msg)
+    };
+    OUTPUT.with(|buffer|*buffer.borrow_mut()=output.into_bytes());
+    code
+}
+
+/// parse the name=value lines of the settings into the options of a call, empty lines are skipped
+fn get_options(settings:&str)->Result<Options,String>
+{
+    let mut options=Options::default();
+    for line in settings.lines().map(|line|line.trim()).filter(|line|!line.is_empty())
+    {
+        match line.split_once('=')
+        {
+            Some((name,value))=>options.set(name.trim(), value.trim())?,
+            None=>return Err(format!("The setting: {} is not of the form name=value",line))
+        }
+    }
+    Ok(options)
+}
+
+/// ## Summary
+/// Return a pointer to the output of the last call to vcf2prot_wasm_preview, which is valid until the next call
+#[no_mangle]
+pub extern "C" fn vcf2prot_wasm_output_ptr()->*const u8
+{
+    OUTPUT.with(|buffer|buffer.borrow().as_ptr())
+}
+
+/// ## Summary
+/// Return the length in bytes of the output of the last call to vcf2prot_wasm_preview
+#[no_mangle]
+pub extern "C" fn vcf2prot_wasm_output_len()->usize
+{
+    OUTPUT.with(|buffer|buffer.borrow().len())
+}
+
+#[cfg(test)]
+mod test_wasm
+{
+    use super::*;
+    #[test]
+    fn test_preview_through_the_exports()
+    {
+        let (vcf,reference)=(crate::parts::demo::DEMO_VCF,crate::parts::demo::DEMO_REFERENCE);
+        let sequences=preview(vcf,reference).unwrap();
+        let fasta=readers::read_fasta_from_reader(reference.as_bytes(),Engine::ST).unwrap();
+        let proteomes=api::generate(vcf.as_bytes(),&fasta,Options::default()).unwrap();
+        let (seq_tape1,seq_tape2)=proteomes.genomes[0].get_seq_tapes();
+        assert_eq!(sequences.matches('>').count(),seq_tape1.get_annotation().len()+seq_tape2.get_annotation().len());
+        unsafe
+        {
+            let (vcf_ptr,fasta_ptr)=(vcf2prot_wasm_alloc(vcf.len()),vcf2prot_wasm_alloc(reference.len()));
+            std::ptr::copy_nonoverlapping(vcf.as_ptr(),vcf_ptr,vcf.len());
+            std::ptr::copy_nonoverlapping(reference.as_ptr(),fasta_ptr,reference.len());
+            assert_eq!(vcf2prot_wasm_preview(vcf_ptr,vcf.len(),fasta_ptr,reference.len()),0);
+            assert_eq!(slice::from_raw_parts(vcf2prot_wasm_output_ptr(),vcf2prot_wasm_output_len()),sequences.as_bytes());
+            // an empty VCF string can not be parsed, the error message replaces the output
+            assert_eq!(vcf2prot_wasm_preview(vcf_ptr,0,fasta_ptr,reference.len()),1);
+            assert!(vcf2prot_wasm_output_len()>0 && vcf2prot_wasm_output_len()<sequences.len());
+            // the settings apply to the call they are passed with, the next call runs with the default options again
+            let settings="overlap_policy=drop-transcript\nchecks=sometimes";
+            assert_eq!(vcf2prot_wasm_preview_with_settings(vcf_ptr,vcf.len(),fasta_ptr,reference.len(),settings.as_ptr(),settings.len()),1);
+            let settings="checks=off\nsamples=HG00096";
+            assert_eq!(vcf2prot_wasm_preview_with_settings(vcf_ptr,vcf.len(),fasta_ptr,reference.len(),settings.as_ptr(),settings.len()),0);
+            assert_eq!(vcf2prot_wasm_preview(vcf_ptr,vcf.len(),fasta_ptr,reference.len()),0);
+            assert_eq!(slice::from_raw_parts(vcf2prot_wasm_output_ptr(),vcf2prot_wasm_output_len()),sequences.as_bytes());
+            vcf2prot_wasm_free(vcf_ptr,vcf.len());
+            vcf2prot_wasm_free(fasta_ptr,reference.len());
+        }
+    }
+}
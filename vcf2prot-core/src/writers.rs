@@ -0,0 +1,2432 @@
+use std::path::{Path, PathBuf}; 
+use std::collections::{HashMap,HashSet}; 
+use crate::data_structures::Constants;
+use crate::data_structures::Map;
+use crate::functions::summary::{self,CohortStatistics,HaplotypeSpread,LengthDeltas,MutationLoad,TranscriptDecoration};
+use crate::functions::effect_validation::InconsistentAnnotation;
+use crate::functions::hgvs::HgvsChange;
+use crate::functions::gene_report::GeneSummary;
+use crate::functions::sex_chromosomes::SexChromosomeSummary;
+use crate::functions::length_stats::{self,SampleLengthStats};
+use crate::functions::missing_genotypes::MissingGenotypeSummary;
+use crate::functions::duplicate_records::DuplicateRecordSummary;
+use crate::functions::silent_variants::SilentVariant;
+use crate::functions::incremental::{ProgressEntry,RunManifest,RunProgress};
+use crate::functions::provenance::ProvenanceManifest;
+use crate::functions::progress::ProgressTracker;
+use crate::functions::sample_vcfs::SampleVcfSubsets;
+use crate::functions::metrics::MetricsSnapshot;
+use crate::functions::coordinate_audit::CoordinateAudit;
+use crate::functions::compatibility::CompatibilityReport;
+use crate::data_structures::InternalRep::validation::ValidationReport;
+use crate::functions::smoke::{SmokeFile,smoke_files_to_table};
+use crate::data_structures::InternalRep::cohort_aggregate::{AggregateSummary,CohortAggregate};
+use crate::data_structures::InternalRep::personalized_genome::PersonalizedGenome;
+use crate::data_structures::InternalRep::sequence_tape::StopCodonPolicy;
+use crate::data_structures::InternalRep::variant_feature::AppliedChange;
+use crate::parts::exec::ExecutionFailure;
+use crate::data_structures::id_map::IdMap;
+use serde_json; 
+use std::io::{BufWriter,ErrorKind,Write};
+use std::str::FromStr;
+use serde::Serialize;
+use std::fs::{File,create_dir};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc,Mutex};
+use std::time::Duration;
+#[cfg(target_os = "linux")]
+use std::os::unix::io::AsRawFd;
+use flate2::write::{DeflateEncoder,GzEncoder};
+use flate2::{Compression,Crc};
+use zstd::ZstdWriter;
+
+pub mod zstd;
+
+/// ## Summary 
+/// The policy of the writer layer, with the PerSample policy, which is the default, all files can be written, while with the AggregateOnly 
+/// policy only cohort-level files, i.e. the unique sequences of the cohort and cohort summaries, can be written and every writer of a 
+/// per-sample file returns an error. The policy is passed to the writers through WriterOptions. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum OutputPolicy
+{
+    PerSample,
+    AggregateOnly
+}
+impl Default for OutputPolicy
+{
+    fn default()->Self
+    {
+        OutputPolicy::PerSample
+    }
+}
+impl OutputPolicy
+{
+    /// ## Summary 
+    /// check whether a per-sample file, described by what, can be written under the policy, returns an error if the policy is AggregateOnly
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::OutputPolicy; 
+    /// assert!(OutputPolicy::PerSample.check_per_sample("the personalized fasta files").is_ok()); 
+    /// assert!(OutputPolicy::AggregateOnly.check_per_sample("the personalized fasta files").is_err()); 
+    ///```
+    pub fn check_per_sample(&self, what:&str)->Result<(),String>
+    {
+        match self
+        {
+            OutputPolicy::PerSample=>Ok(()),
+            OutputPolicy::AggregateOnly=>Err(format!("Writing {} is not allowed, because only cohort-level outputs can be written in the aggregate-only mode",what))
+        }
+    }
+}
+/// ## Summary 
+/// The options of the writer layer, which are passed to every writer instead of being set for the whole process, hence, two runs of the 
+/// library in the same process, e.g. two requests of a server, can write their files with different options. The write_all and 
+/// write_compressed flags are read by the consensus writers, i.e. io::write_consensus_genomes, the other writers take them as arguments. 
+/// The written probands are counted in the progress tracker of the options, if any, see progress::ProgressTracker, and are appended to 
+/// the progress file of the options once it is started, see start_run_progress. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::{OutputPolicy,WriterOptions}; 
+/// let options=WriterOptions{output_policy:OutputPolicy::AggregateOnly,..WriterOptions::default()}; 
+/// assert!(options.output_policy.check_per_sample("the GFF3 files").is_err()); 
+/// assert!(WriterOptions::default().output_policy.check_per_sample("the GFF3 files").is_ok()); 
+///```
+#[derive(Debug,Clone,Default)]
+pub struct WriterOptions
+{
+    pub output_policy:OutputPolicy,
+    pub retry_policy:RetryPolicy,
+    pub buffer_policy:BufferPolicy,
+    pub output_quota:OutputQuota,
+    pub fasta_layout:FastaLayout,
+    pub compression_policy:CompressionPolicy,
+    pub id_map:Option<IdMap>,
+    pub stop_codon_policy:Option<StopCodonPolicy>,
+    pub write_all:bool,
+    pub write_compressed:bool,
+    pub progress:Option<Arc<ProgressTracker>>,
+    pub run_progress:Arc<RunProgressFile>
+}
+
+/// The default number of retries of a transient write failure 
+pub const DEFAULT_WRITE_RETRIES:u32=3; 
+/// The default waiting time before the first retry in milliseconds, which is doubled for every further retry 
+pub const DEFAULT_WRITE_BACKOFF_MS:u64=500; 
+/// The maximum waiting time between two retries in milliseconds 
+const MAX_WRITE_BACKOFF_MS:u64=60_000; 
+/// The raw OS errors that are treated as transient on Linux, i.e. EIO, EAGAIN, ENOSPC, ESTALE and EDQUOT, which networked file systems, 
+/// e.g. Lustre or NFS, report when a server is briefly unavailable or a quota is being recomputed 
+const TRANSIENT_OS_ERRORS:[i32;5]=[5,11,28,116,122]; 
+
+/// ## Summary 
+/// The retry policy of the writer layer, the creation of a file and every write to it are retried up to max_retries times if they fail 
+/// with a transient error, see is_transient_error, where the waiting time starts at backoff_ms milliseconds and is doubled for every retry. 
+/// Other errors, e.g. a missing directory or a denied permission, are returned immediately. The policy is passed to the writers through 
+/// WriterOptions. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct RetryPolicy
+{
+    pub max_retries:u32,
+    pub backoff_ms:u64
+}
+impl Default for RetryPolicy
+{
+    fn default()->Self
+    {
+        RetryPolicy{max_retries:DEFAULT_WRITE_RETRIES,backoff_ms:DEFAULT_WRITE_BACKOFF_MS}
+    }
+}
+impl RetryPolicy
+{
+    /// ## Summary 
+    /// return the waiting time before the provided retry, starting at zero 
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::RetryPolicy; 
+    /// let policy=RetryPolicy{max_retries:3,backoff_ms:500}; 
+    /// assert_eq!(policy.get_backoff(0).as_millis(),500); 
+    /// assert_eq!(policy.get_backoff(2).as_millis(),2000); 
+    /// assert_eq!(policy.get_backoff(20).as_millis(),60_000); 
+    ///```
+    pub fn get_backoff(&self, retry:u32)->Duration
+    {
+        let backoff_ms=self.backoff_ms.saturating_mul(1_u64.checked_shl(retry).unwrap_or(u64::MAX)); 
+        Duration::from_millis(std::cmp::min(backoff_ms,MAX_WRITE_BACKOFF_MS))
+    }
+    /// ## Summary 
+    /// run an IO operation and retry it with the policy as long as it fails with a transient error 
+    pub fn run<T,F:FnMut()->std::io::Result<T>>(&self, mut operation:F)->std::io::Result<T>
+    {
+        let mut retry=0; 
+        loop 
+        {
+            match operation()
+            {
+                Err(err) if retry < self.max_retries && is_transient_error(&err)=>
+                {
+                    std::thread::sleep(self.get_backoff(retry)); 
+                    retry+=1; 
+                },
+                result=>return result
+            }
+        }
+    }
+}
+/// ## Summary 
+/// Return whether an IO error is transient, i.e. the operation might succeed if it is retried, e.g. an interrupted call, a time out or 
+/// one of the TRANSIENT_OS_ERRORS 
+/// ## Example 
+///```rust
+/// use ppgg::writers::is_transient_error; 
+/// use std::io::{Error,ErrorKind}; 
+/// assert!(is_transient_error(&Error::from_raw_os_error(5))); 
+/// assert!(is_transient_error(&Error::from(ErrorKind::TimedOut))); 
+/// assert!(!is_transient_error(&Error::from(ErrorKind::NotFound))); 
+///```
+pub fn is_transient_error(err:&std::io::Error)->bool
+{
+    match err.raw_os_error()
+    {
+        Some(code)=>TRANSIENT_OS_ERRORS.contains(&code),
+        None=>matches!(err.kind(),ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock)
+    }
+}
+/// ## Summary 
+/// A writer that retries every write and flush of the wrapped writer that fails with a transient error using the retry policy it was 
+/// created with, see RetryPolicy. A failed write call has not written any byte, hence, it can be repeated as is. 
+#[derive(Debug)]
+pub struct RetryWriter<W:Write>
+{
+    inner:W,
+    policy:RetryPolicy
+}
+impl<W:Write> RetryWriter<W>
+{
+    /// ## Summary 
+    /// wrap a writer using the provided retry policy 
+    pub fn new(inner:W, policy:RetryPolicy)->Self
+    {
+        RetryWriter{inner,policy}
+    }
+    /// ## Summary 
+    /// return a reference to the wrapped writer 
+    pub fn get_ref(&self)->&W
+    {
+        &self.inner
+    }
+}
+impl<W:Write> Write for RetryWriter<W>
+{
+    fn write(&mut self, buf:&[u8])->std::io::Result<usize>
+    {
+        let (policy,inner)=(self.policy,&mut self.inner); 
+        policy.run(||inner.write(buf))
+    }
+    fn flush(&mut self)->std::io::Result<()>
+    {
+        let (policy,inner)=(self.policy,&mut self.inner); 
+        policy.run(||inner.flush())
+    }
+}
+/// A file whose creation, writes and flushes are retried on transient errors 
+pub type RetryFile=RetryWriter<File>; 
+/// ## Summary 
+/// Create a file, or truncate an existing one, and retry the creation on transient errors with the provided policy, the writes to the 
+/// returned file are retried as well, see RetryWriter, which is used by every writer of the library 
+pub fn create_file<P:AsRef<Path>>(path2file:P, policy:&RetryPolicy)->std::io::Result<RetryFile>
+{
+    policy.run(||File::create(path2file.as_ref())).map(|file|RetryWriter::new(file,*policy))
+}
+
+/// The default upper bound of an automatically sized output buffer in bytes, i.e. 64 MiB 
+pub const DEFAULT_MAX_OUTPUT_BUFFER_SIZE:usize=64<<20; 
+/// The lower bound of an automatically sized output buffer in bytes, which is the default capacity of a BufWriter 
+const MIN_OUTPUT_BUFFER_SIZE:usize=8<<10; 
+
+/// ## Summary 
+/// How the buffer of a per-sample fasta file is sized:
+/// 1. Auto => the buffer holds the expected size of the file, computed from the lengths of its records, bounded by the maximum buffer size, the default.
+/// 2. Fixed => every buffer has the provided number of bytes regardless of the expected size of the file. 
+///
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum BufferSizing
+{
+    Auto,
+    Fixed(usize)
+}
+
+/// ## Summary 
+/// The buffer policy of the writer layer for the per-sample fasta files, the writes of a file are collected in a buffer sized by the 
+/// sizing, see BufferSizing, so a sample with tens of thousands of sequences is written with a few large writes. If preallocate is set, 
+/// the expected size of an uncompressed file is allocated on the disk before it is written, which reduces the fragmentation of large files, 
+/// the allocation is a hint and only takes place on Linux file systems supporting it, the size of the file is not changed. The policy is 
+/// passed to the writers through WriterOptions. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct BufferPolicy
+{
+    pub sizing:BufferSizing,
+    pub max_buffer_size:usize,
+    pub preallocate:bool
+}
+impl Default for BufferPolicy
+{
+    fn default()->Self
+    {
+        BufferPolicy{sizing:BufferSizing::Auto,max_buffer_size:DEFAULT_MAX_OUTPUT_BUFFER_SIZE,preallocate:false}
+    }
+}
+impl BufferPolicy
+{
+    /// ## Summary 
+    /// return the capacity of the buffer of a file with the provided expected size in bytes 
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::{BufferPolicy,BufferSizing}; 
+    /// let policy=BufferPolicy{sizing:BufferSizing::Auto,max_buffer_size:1<<20,preallocate:false}; 
+    /// assert_eq!(policy.get_capacity(100_000),100_000); 
+    /// assert_eq!(policy.get_capacity(10),8192); 
+    /// assert_eq!(policy.get_capacity(1<<30),1<<20); 
+    /// assert_eq!(BufferPolicy{sizing:BufferSizing::Fixed(512),..policy}.get_capacity(100_000),512); 
+    ///```
+    pub fn get_capacity(&self, expected_size:usize)->usize
+    {
+        match self.sizing
+        {
+            BufferSizing::Auto=>expected_size.min(self.max_buffer_size).max(MIN_OUTPUT_BUFFER_SIZE),
+            BufferSizing::Fixed(buffer_size)=>buffer_size.max(1)
+        }
+    }
+    /// ## Summary 
+    /// create a buffered writer of a fasta file with the provided expected size in bytes, the file is compressed with the compression policy 
+    /// provided compression policy if write_compressed is set, see CompressionPolicy, where the buffer holds the uncompressed records and the preallocation is skipped as the compressed size is unknown. The 
+    /// writer must be flushed, see flush_output, as the errors of the final write are lost otherwise. The creation of the file and the writes 
+    /// to it are retried with the provided retry policy. 
+    pub fn create_writer(&self, path2file:&Path, expected_size:usize, write_compressed:bool, retry_policy:&RetryPolicy, 
+        compression_policy:&CompressionPolicy)->Result<Box<dyn Write>,String>
+    {
+        let file_handle=match create_file(path2file, retry_policy)
+        {
+            Ok(file)=>file,
+            Err(err_msg)=>return Err(format!("Could not create {} because {}",path2file.display(),err_msg))
+        }; 
+        let capacity=self.get_capacity(expected_size); 
+        match write_compressed
+        {
+            true=>Ok(Box::new(BufWriter::with_capacity(capacity,compression_policy.create_encoder(file_handle)))),
+            false=>
+            {
+                if self.preallocate
+                {
+                    preallocate_file(file_handle.get_ref(),expected_size); 
+                }
+                Ok(Box::new(BufWriter::with_capacity(capacity,file_handle)))
+            }
+        }
+    }
+}
+/// The compression level used so far for the compressed fasta files, i.e. the best compression 
+pub const DEFAULT_COMPRESSION_LEVEL:u32=9; 
+/// The maximum number of uncompressed bytes in a BGZF block, as used by bgzip, so a compressed block never exceeds 64 KiB 
+const BGZF_BLOCK_SIZE:usize=0xff00; 
+/// The empty BGZF block marking the end of a BGZF file 
+const BGZF_EOF:[u8;28]=[0x1f,0x8b,8,4,0,0,0,0,0,0xff,6,0,b'B',b'C',2,0,0x1b,0,3,0,0,0,0,0,0,0,0,0]; 
+
+/// ## Summary 
+/// The format of the compressed fasta files, i.e. the files written with --write_compressed:
+/// 1. Gzip => a single gzip stream, the default. 
+/// 2. Bgzip => a series of BGZF blocks as written by bgzip, which can be indexed with samtools faidx, i.e. a single record is read without decompressing the whole file. 
+/// 3. Zstd => a zstd frame, see zstd::ZstdWriter, which is read by any zstd decompressor. 
+///
+/// The gzip formats are read by any gzip decompressor and use the .fasta.gz extension, while the zstd files use the .fasta.zst extension. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum CompressionFormat
+{
+    Gzip,
+    Bgzip,
+    Zstd
+}
+impl Default for CompressionFormat
+{
+    fn default()->Self
+    {
+        CompressionFormat::Gzip
+    }
+}
+impl CompressionFormat
+{
+    /// ## Summary 
+    /// Return the name of the format as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            CompressionFormat::Gzip=>"gzip",
+            CompressionFormat::Bgzip=>"bgzip",
+            CompressionFormat::Zstd=>"zstd"
+        }
+    }
+    /// ## Summary 
+    /// Return the extension of the compressed files, which follows the extension of the uncompressed file, e.g. fasta.gz 
+    pub fn get_extension(&self)->&'static str
+    {
+        match self
+        {
+            CompressionFormat::Gzip | CompressionFormat::Bgzip=>"gz",
+            CompressionFormat::Zstd=>"zst"
+        }
+    }
+}
+impl FromStr for CompressionFormat
+{
+    type Err=String;
+    fn from_str(format:&str)->Result<CompressionFormat,String>
+    {
+        match format.to_lowercase().as_str()
+        {
+            "gzip" | "gz"=>Ok(CompressionFormat::Gzip),
+            "bgzip" | "bgzf"=>Ok(CompressionFormat::Bgzip),
+            "zstd" | "zst"=>Ok(CompressionFormat::Zstd),
+            _=>Err(format!("{} is not a supported compression format, supported formats are: gzip, bgzip and zstd",format))
+        }
+    }
+}
+/// ## Summary 
+/// The compression policy of the writer layer for the compressed fasta files, i.e. the format and the level, from 0, no compression, to 9, 
+/// the best and slowest compression, which is the default. The policy is passed to the writers through WriterOptions. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::{CompressionFormat,CompressionPolicy,WriterOptions}; 
+/// assert!(CompressionPolicy::new(CompressionFormat::Bgzip,6).is_ok()); 
+/// assert!(CompressionPolicy::new(CompressionFormat::Gzip,10).is_err()); 
+/// assert_eq!(WriterOptions::default().compression_policy,CompressionPolicy::default()); 
+///```
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct CompressionPolicy
+{
+    pub format:CompressionFormat,
+    pub level:u32
+}
+impl Default for CompressionPolicy
+{
+    fn default()->Self
+    {
+        CompressionPolicy{format:CompressionFormat::Gzip,level:DEFAULT_COMPRESSION_LEVEL}
+    }
+}
+impl CompressionPolicy
+{
+    /// ## Summary 
+    /// Create a policy, returns an error if the level is larger than 9 
+    pub fn new(format:CompressionFormat, level:u32)->Result<Self,String>
+    {
+        match level
+        {
+            0..=9=>Ok(CompressionPolicy{format,level}),
+            _=>Err(format!("The compression level: {} is not supported, the level must be between 0 and 9",level))
+        }
+    }
+    /// ## Summary 
+    /// Wrap a writer into an encoder of the format, the stream is completed when the encoder is dropped 
+    pub fn create_encoder<W:Write+'static>(&self, writer:W)->Box<dyn Write>
+    {
+        match self.format
+        {
+            CompressionFormat::Gzip=>Box::new(GzEncoder::new(writer,Compression::new(self.level))),
+            CompressionFormat::Bgzip=>Box::new(BgzfWriter::new(writer,self.level)),
+            CompressionFormat::Zstd=>Box::new(ZstdWriter::new(writer,self.level))
+        }
+    }
+    /// ## Summary 
+    /// Return the name of a fasta file with the provided stem, whose extension follows the format if the file is compressed 
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::{CompressionFormat,CompressionPolicy}; 
+    /// let policy=CompressionPolicy::new(CompressionFormat::Zstd,3).unwrap(); 
+    /// assert_eq!((policy.get_fasta_name("S1",true),policy.get_fasta_name("S1",false)),("S1.fasta.zst".to_string(),"S1.fasta".to_string())); 
+    ///```
+    pub fn get_fasta_name(&self, file_stem:&str, write_compressed:bool)->String
+    {
+        match write_compressed
+        {
+            true=>format!("{}.fasta.{}",file_stem,self.format.get_extension()),
+            false=>format!("{}.fasta",file_stem)
+        }
+    }
+}
+/// ## Summary 
+/// A writer of BGZF files, i.e. the written bytes are compressed into gzip members of at most 64 KiB along with their size, as written by 
+/// bgzip, a flush completes the current block and the end-of-file block is appended when the writer is finished or dropped 
+/// ## Example 
+///```rust
+/// use std::io::{Read,Write}; 
+/// use ppgg::writers::BgzfWriter; 
+/// let mut writer=BgzfWriter::new(Vec::new(),6); 
+/// writer.write_all(b">T1_1\nMKV\n").unwrap(); 
+/// let compressed=writer.finish().unwrap(); 
+/// let mut content=String::new(); 
+/// flate2::read::MultiGzDecoder::new(compressed.as_slice()).read_to_string(&mut content).unwrap(); 
+/// assert_eq!(content,">T1_1\nMKV\n"); 
+///```
+pub struct BgzfWriter<W:Write>
+{
+    inner:Option<W>,
+    buffer:Vec<u8>,
+    level:u32
+}
+impl<W:Write> BgzfWriter<W>
+{
+    /// ## Summary 
+    /// Create a writer compressing the blocks with the provided level, from 0 to 9 
+    pub fn new(inner:W, level:u32)->Self
+    {
+        BgzfWriter{inner:Some(inner),buffer:Vec::with_capacity(BGZF_BLOCK_SIZE),level:level.min(9)}
+    }
+    /// ## Summary 
+    /// Write the remaining bytes and the end-of-file block, and return the underlying writer 
+    pub fn finish(mut self)->std::io::Result<W>
+    {
+        self.write_eof()?; 
+        Ok(self.inner.take().unwrap())
+    }
+    /// write the remaining bytes followed by the end-of-file block 
+    fn write_eof(&mut self)->std::io::Result<()>
+    {
+        self.write_block()?; 
+        match self.inner.as_mut()
+        {
+            Some(inner)=>{inner.write_all(&BGZF_EOF)?; inner.flush()},
+            None=>Ok(())
+        }
+    }
+    /// compress the buffered bytes into a block, the block is stored if compressing it does not shrink it enough to fit into 64 KiB 
+    fn write_block(&mut self)->std::io::Result<()>
+    {
+        if self.inner.is_none() || self.buffer.is_empty()
+        {
+            return Ok(())
+        }
+        let deflate=|level:u32|->std::io::Result<Vec<u8>>
+        {
+            let mut encoder=DeflateEncoder::new(Vec::with_capacity(self.buffer.len()),Compression::new(level)); 
+            encoder.write_all(&self.buffer)?; 
+            encoder.finish()
+        };
+        let mut compressed=deflate(self.level)?; 
+        if compressed.len()+26>1<<16
+        {
+            compressed=deflate(0)?; 
+        }
+        let mut crc=Crc::new(); 
+        crc.update(&self.buffer); 
+        let inner=self.inner.as_mut().unwrap(); 
+        inner.write_all(&[0x1f,0x8b,8,4,0,0,0,0,0,0xff,6,0,b'B',b'C',2,0])?; 
+        inner.write_all(&((compressed.len()+25) as u16).to_le_bytes())?; 
+        inner.write_all(&compressed)?; 
+        inner.write_all(&crc.sum().to_le_bytes())?; 
+        inner.write_all(&(self.buffer.len() as u32).to_le_bytes())?; 
+        self.buffer.clear(); 
+        Ok(())
+    }
+}
+impl<W:Write> Write for BgzfWriter<W>
+{
+    fn write(&mut self, buf:&[u8])->std::io::Result<usize>
+    {
+        let num_bytes=buf.len().min(BGZF_BLOCK_SIZE-self.buffer.len()); 
+        self.buffer.extend_from_slice(&buf[..num_bytes]); 
+        if self.buffer.len()==BGZF_BLOCK_SIZE
+        {
+            self.write_block()?; 
+        }
+        Ok(num_bytes)
+    }
+    fn flush(&mut self)->std::io::Result<()>
+    {
+        self.write_block()?; 
+        match self.inner.as_mut()
+        {
+            Some(inner)=>inner.flush(),
+            None=>Ok(())
+        }
+    }
+}
+impl<W:Write> Drop for BgzfWriter<W>
+{
+    fn drop(&mut self)
+    {
+        // as for the gzip encoder, the errors of completing the file while dropping the writer are lost, see flush_output 
+        if self.inner.is_some()
+        {
+            let _=self.write_eof(); 
+        }
+    }
+}
+/// ## Summary 
+/// create a buffered writer of a fasta file using the buffer and the retry policies of the options, see BufferPolicy::create_writer 
+pub fn create_output_writer(path2file:&Path, expected_size:usize, write_compressed:bool, options:&WriterOptions)->Result<Box<dyn Write>,String>
+{
+    options.buffer_policy.create_writer(path2file, expected_size, write_compressed, &options.retry_policy, &options.compression_policy)
+}
+/// ## Summary 
+/// flush a writer created by create_output_writer, the file described by what is named in the error message 
+pub fn flush_output(writer:&mut Box<dyn Write>, what:&str)->Result<(),String>
+{
+    match writer.flush()
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Writing {} failed because {}",what,err_msg))
+    }
+}
+
+/// ## Summary 
+/// The output quota of the writer layer for the per-sample fasta files, max_sample_bytes bounds the bytes of the records written to the 
+/// fasta file of a sample and max_total_bytes the bytes written to the fasta files of all samples of a run, where None means unlimited. 
+/// The size of a record is counted before compression. A record that does not fit into one of the quotas is not written, nor is any 
+/// further record of the sample, i.e. its file is truncated, while the other samples are written as long as their records fit into the 
+/// quotas. The custom records appended to a file are not counted and are skipped if the file is truncated. The quota is passed to the 
+/// writers through WriterOptions, while the bytes written by a run are counted by the caller, see start_sample. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Default)]
+pub struct OutputQuota
+{
+    pub max_sample_bytes:Option<u64>,
+    pub max_total_bytes:Option<u64>
+}
+impl OutputQuota
+{
+    /// ## Summary 
+    /// start tracking the records written to the fasta file of a sample under the quota, where total_bytes counts the bytes written to the 
+    /// fasta files of all samples of the run and is shared by the samples written in parallel 
+    pub fn start_sample<'a>(&self, total_bytes:&'a AtomicU64)->SampleQuota<'a>
+    {
+        SampleQuota{quota:*self,total_bytes,num_bytes:0,num_truncated:0}
+    }
+}
+/// ## Summary 
+/// The bytes written to the fasta file of a sample under an output quota along with the number of records that were not written because 
+/// they did not fit into the quota, see OutputQuota 
+#[derive(Debug)]
+pub struct SampleQuota<'a>
+{
+    quota:OutputQuota,
+    total_bytes:&'a AtomicU64,
+    num_bytes:u64,
+    num_truncated:u64
+}
+impl SampleQuota<'_>
+{
+    /// ## Summary 
+    /// reserve the bytes of a record, returns whether the record can be written, once a record was rejected every further record of the 
+    /// sample is rejected as well 
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::OutputQuota; 
+    /// use std::sync::atomic::AtomicU64; 
+    /// let total_bytes=AtomicU64::new(0); 
+    /// let mut sample_quota=OutputQuota{max_sample_bytes:Some(10),max_total_bytes:None}.start_sample(&total_bytes); 
+    /// assert!(sample_quota.reserve(6)); 
+    /// assert!(!sample_quota.reserve(6)); 
+    /// assert!(!sample_quota.reserve(1)); 
+    /// assert_eq!((sample_quota.get_num_bytes(),sample_quota.get_num_truncated()),(6,2)); 
+    ///```
+    pub fn reserve(&mut self, num_bytes:u64)->bool
+    {
+        let fits_sample=self.num_truncated==0 && self.quota.max_sample_bytes.map_or(true,|max_bytes|self.num_bytes+num_bytes<=max_bytes); 
+        // the bytes of all samples are reserved atomically, so samples written in parallel never exceed the total quota together 
+        let max_total_bytes=self.quota.max_total_bytes.unwrap_or(u64::MAX); 
+        let fits_total=fits_sample && self.total_bytes.fetch_update(Ordering::SeqCst, Ordering::SeqCst, 
+            |total_bytes|total_bytes.checked_add(num_bytes).filter(|total_bytes|*total_bytes<=max_total_bytes)).is_ok(); 
+        match fits_total
+        {
+            true=>self.num_bytes+=num_bytes,
+            false=>self.num_truncated+=1
+        }
+        fits_total
+    }
+    /// ## Summary 
+    /// return whether at least one record of the sample did not fit into the quota 
+    pub fn is_truncated(&self)->bool
+    {
+        self.num_truncated!=0
+    }
+    /// ## Summary 
+    /// return the number of bytes reserved for the records of the sample 
+    pub fn get_num_bytes(&self)->u64
+    {
+        self.num_bytes
+    }
+    /// ## Summary 
+    /// return the number of records of the sample that were not written because of the quota 
+    pub fn get_num_truncated(&self)->u64
+    {
+        self.num_truncated
+    }
+}
+/// ## Summary 
+/// allocate the provided number of bytes of an empty file on the disk without changing its size, failures, e.g. file systems that do 
+/// not support the allocation, are ignored as the file is written regardless 
+#[cfg(target_os = "linux")]
+fn preallocate_file(file:&File, num_bytes:usize)
+{
+    if num_bytes!=0
+    {
+        unsafe { libc::fallocate(file.as_raw_fd(), libc::FALLOC_FL_KEEP_SIZE, 0, num_bytes as libc::off_t); }
+    }
+}
+#[cfg(not(target_os = "linux"))]
+fn preallocate_file(_file:&File, _num_bytes:usize)
+{
+}
+
+/// The maximum length, in bytes, of a file stem derived from a sample name, leaving room for the extensions and the uniqueness suffix 
+/// within the 255 bytes most file systems allow for a file name 
+const MAX_FILE_STEM_LENGTH:usize=200; 
+/// File names that are reserved on Windows regardless of their extension 
+const RESERVED_FILE_STEMS:[&str;22]=["CON","PRN","AUX","NUL","COM1","COM2","COM3","COM4","COM5","COM6","COM7","COM8","COM9",
+    "LPT1","LPT2","LPT3","LPT4","LPT5","LPT6","LPT7","LPT8","LPT9"]; 
+
+/// ## Summary 
+/// Map a sample name to a file stem that can be created on Linux, macOS and Windows, ASCII letters, digits, '-', '_' and '.' are kept 
+/// while every other character, e.g. '/', ':' or a unicode character, is replaced by '_'. Additionally, a leading '.', which would 
+/// hide the file, and names reserved on Windows, e.g. CON or NUL, are prefixed with '_', and long names are truncated. 
+/// Note, distinct names might be mapped to the same stem, see sanitize_file_stems for unique stems. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::sanitize_file_stem; 
+/// assert_eq!(sanitize_file_stem("HG00096"),"HG00096"); 
+/// assert_eq!(sanitize_file_stem("batch1/sample:2"),"batch1_sample_2"); 
+/// assert_eq!(sanitize_file_stem("Müller"),"M_ller"); 
+/// assert_eq!(sanitize_file_stem("nul"),"_nul"); 
+///```
+pub fn sanitize_file_stem(name:&str)->String
+{
+    let mut stem=name.chars()
+        .map(|character| if character.is_ascii_alphanumeric() || character=='-' || character=='_' || character=='.' {character} else {'_'})
+        .take(MAX_FILE_STEM_LENGTH)
+        .collect::<String>(); 
+    // Windows does not allow trailing dots in file names 
+    while stem.ends_with('.')
+    {
+        stem.pop(); 
+    }
+    if stem.is_empty() || stem.starts_with('.') || RESERVED_FILE_STEMS.contains(&stem.split('.').next().unwrap().to_uppercase().as_str())
+    {
+        stem.insert(0,'_'); 
+    }
+    stem
+}
+/// ## Summary 
+/// Map each of the provided sample names to a sanitized file stem, see sanitize_file_stem, that is unique among the returned stems, 
+/// the stems are compared case-insensitively as the default file systems of macOS and Windows are case-insensitive. Names that are 
+/// already safe and unique keep their name, while the stem of every other name gets the smallest numerical suffix, i.e. _2, _3, etc., 
+/// that makes it unique. The stems are returned in the order of the names. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::sanitize_file_stems; 
+/// let stems=sanitize_file_stems(&["a/b","a_b","A:b","c"]); 
+/// assert_eq!(stems,vec!["a_b_2".to_string(),"a_b".to_string(),"A_b_3".to_string(),"c".to_string()]); 
+///```
+pub fn sanitize_file_stems(names:&[&str])->Vec<String>
+{
+    let mut used_stems=HashSet::new(); 
+    // first, reserve the names that can be used as they are, so their files are not renamed 
+    let is_kept=names.iter()
+        .map(|name|sanitize_file_stem(name)==*name && used_stems.insert(name.to_lowercase()))
+        .collect::<Vec<bool>>(); 
+    names.iter().zip(is_kept.iter())
+        .map(|(name,is_kept)|
+        {
+            if *is_kept
+            {
+                return name.to_string()
+            }
+            let stem=sanitize_file_stem(name); 
+            let mut candidate=stem.clone(); 
+            let mut suffix=2; 
+            while !used_stems.insert(candidate.to_lowercase())
+            {
+                candidate=format!("{}_{}",stem,suffix); 
+                suffix+=1; 
+            }
+            candidate
+        })
+        .collect::<Vec<String>>()
+}
+/// ## Summary 
+/// Write the mapping between the sample names and the file stems of their per-sample files, see sanitize_file_stems, to a file 
+/// named sample_file_names.tsv, which serves as the manifest for recovering the sample name of a renamed file 
+pub fn write_file_stem_mapping(path2file:&Path,mapping:&[(String,String)], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the file names of each proband")?; 
+    let mut path2write=path2file.to_path_buf(); 
+    path2write.push("sample_file_names.tsv"); 
+    let mut file_handle=match create_file(path2write, &options.retry_policy)
+    {
+        Ok(file)=>std::io::BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Could not create a file to write the file names of each proband, error: {}",err_msg))
+    };
+    writeln!(&mut file_handle,"Proband Name\tFile Name").unwrap(); 
+    for (name,stem) in mapping.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}",name,stem).unwrap(); 
+    }
+    Ok(())
+}
+
+/// The stem of the fasta file written with OutputLayout::Combined 
+pub const COMBINED_FASTA_FILE_STEM:&str="personalized_proteomes"; 
+
+/// ## Summary 
+/// How the personalized sequences are distributed over fasta files:
+/// 1. PerProband => one file per proband named after its file stem, e.g. HG00096.fasta, the default. 
+/// 2. PerTranscript => one file per transcript holding the records of every proband, e.g. ENST00000001.fasta. 
+/// 3. Combined => a single file holding the records of every proband, i.e. personalized_proteomes.fasta. 
+///
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum OutputLayout
+{
+    PerProband,
+    PerTranscript,
+    Combined
+}
+impl Default for OutputLayout
+{
+    fn default()->Self
+    {
+        OutputLayout::PerProband
+    }
+}
+impl OutputLayout
+{
+    /// ## Summary 
+    /// Return the name of the layout as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            OutputLayout::PerProband=>"per-proband",
+            OutputLayout::PerTranscript=>"per-transcript",
+            OutputLayout::Combined=>"combined"
+        }
+    }
+    /// ## Summary 
+    /// Return the fields a record name must contain, so the names are unique within a file of the layout 
+    pub fn get_required_fields(&self)->&'static [TemplateField]
+    {
+        match self
+        {
+            OutputLayout::PerProband=>&[TemplateField::Transcript,TemplateField::Haplotype],
+            OutputLayout::PerTranscript=>&[TemplateField::Proband,TemplateField::Haplotype],
+            OutputLayout::Combined=>&[TemplateField::Proband,TemplateField::Transcript,TemplateField::Haplotype]
+        }
+    }
+}
+impl FromStr for OutputLayout
+{
+    type Err=String;
+    fn from_str(layout:&str)->Result<OutputLayout,String>
+    {
+        match layout.to_lowercase().replace('_',"-").as_str()
+        {
+            "per-proband" | "proband" | "per-sample"=>Ok(OutputLayout::PerProband),
+            "per-transcript" | "transcript"=>Ok(OutputLayout::PerTranscript),
+            "combined" | "single"=>Ok(OutputLayout::Combined),
+            _=>Err(format!("{} is not a supported output layout, supported layouts are: per-proband, per-transcript and combined",layout))
+        }
+    }
+}
+/// ## Summary 
+/// A field of a name template, i.e. {proband}, {haplotype} or {transcript} 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum TemplateField
+{
+    Proband,
+    Haplotype,
+    Transcript
+}
+impl TemplateField
+{
+    /// ## Summary 
+    /// Return the placeholder of the field in a template 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            TemplateField::Proband=>"{proband}",
+            TemplateField::Haplotype=>"{haplotype}",
+            TemplateField::Transcript=>"{transcript}"
+        }
+    }
+}
+/// a part of a name template, either text written as it is or a field 
+#[derive(Debug,Clone,PartialEq,Eq)]
+enum TemplatePart
+{
+    Literal(String),
+    Field(TemplateField)
+}
+/// ## Summary 
+/// The template of the record names of the personalized fasta files, where {proband} is replaced by the proband name, {haplotype} by 
+/// the haplotype, i.e. 1 or 2, and {transcript} by the transcript id, e.g. {proband}_{haplotype}_{transcript}. The name is the id of the 
+/// record, hence, the tags of the header, e.g. hgvs=, follow it as before. With OutputLayout::PerTranscript, the template also names the 
+/// files, see render_file_name, e.g. tx-{transcript}|{proband}_{haplotype} writes the records of ENST00000001 to tx-ENST00000001.fasta. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::NameTemplate; 
+/// let template=NameTemplate::parse("{proband}_{haplotype}_{transcript}").unwrap(); 
+/// assert_eq!(template.render("HG00096",2,"ENST00000001"),"HG00096_2_ENST00000001"); 
+/// assert_eq!(NameTemplate::default().render("HG00096",1,"ENST00000001"),"ENST00000001_1"); 
+/// assert!(NameTemplate::parse("{sample}_{haplotype}").is_err()); 
+///```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct NameTemplate
+{
+    template:String,
+    parts:Vec<TemplatePart>
+}
+impl Default for NameTemplate
+{
+    /// the template of the record names used by vcf2prot so far, i.e. {transcript}_{haplotype}, e.g. ENST00000001_1 
+    fn default()->Self
+    {
+        NameTemplate::parse("{transcript}_{haplotype}").unwrap()
+    }
+}
+impl NameTemplate
+{
+    /// ## Summary 
+    /// Parse a template, returns an error if it contains an unknown field or an unmatched brace, whitespace, which would end the id of 
+    /// the record, or if it is empty 
+    pub fn parse(template:&str)->Result<Self,String>
+    {
+        if template.is_empty() || template.chars().any(|character|character.is_whitespace())
+        {
+            return Err(format!("The name template: '{}' is empty or contains whitespace, which ends the id of a fasta record",template))
+        }
+        let mut parts=Vec::new(); 
+        let mut rest=template; 
+        while !rest.is_empty()
+        {
+            match rest.find(['{','}'])
+            {
+                Some(0)=>
+                {
+                    let field=[TemplateField::Proband,TemplateField::Haplotype,TemplateField::Transcript].iter()
+                        .find(|field|rest.starts_with(field.as_str()))
+                        .ok_or(format!("The name template: {} contains an unknown field at: {}, supported fields are: {{proband}}, {{haplotype}} and {{transcript}}",
+                            template,rest))?; 
+                    parts.push(TemplatePart::Field(*field)); 
+                    rest=&rest[field.as_str().len()..]; 
+                },
+                Some(index)=>
+                {
+                    parts.push(TemplatePart::Literal(rest[..index].to_string())); 
+                    rest=&rest[index..]; 
+                },
+                None=>
+                {
+                    parts.push(TemplatePart::Literal(rest.to_string())); 
+                    rest=""; 
+                }
+            }
+        }
+        Ok(NameTemplate{template:template.to_string(),parts})
+    }
+    /// ## Summary 
+    /// Return the default template of a layout, i.e. {transcript}_{haplotype} for the per-proband files, {proband}_{haplotype} for the 
+    /// per-transcript files and {proband}_{transcript}_{haplotype} for the combined file 
+    pub fn default_for(layout:OutputLayout)->Self
+    {
+        match layout
+        {
+            OutputLayout::PerProband=>NameTemplate::default(),
+            OutputLayout::PerTranscript=>NameTemplate::parse("{proband}_{haplotype}").unwrap(),
+            OutputLayout::Combined=>NameTemplate::parse("{proband}_{transcript}_{haplotype}").unwrap()
+        }
+    }
+    /// ## Summary 
+    /// Return the template as provided 
+    pub fn as_str(&self)->&str
+    {
+        &self.template
+    }
+    /// ## Summary 
+    /// Return whether the template contains the field 
+    pub fn contains(&self, field:TemplateField)->bool
+    {
+        self.parts.contains(&TemplatePart::Field(field))
+    }
+    /// ## Summary 
+    /// Return the name of the record of a transcript in a haplotype of a proband 
+    pub fn render(&self, proband:&str, haplotype:u8, transcript:&str)->String
+    {
+        let mut name=String::with_capacity(proband.len()+transcript.len()+self.template.len()); 
+        for part in self.parts.iter()
+        {
+            match part
+            {
+                TemplatePart::Literal(text)=>name.push_str(text),
+                TemplatePart::Field(TemplateField::Proband)=>name.push_str(proband),
+                TemplatePart::Field(TemplateField::Haplotype)=>name.push(if haplotype==1 {'1'} else {'2'}),
+                TemplatePart::Field(TemplateField::Transcript)=>name.push_str(transcript)
+            }
+        }
+        name
+    }
+    /// ## Summary 
+    /// Return the name of the per-transcript file of a transcript, i.e. {transcript} along with the text preceding it, if no field precedes 
+    /// it, and the text following it, if no field follows it, or the transcript id if the template has no {transcript}. The name is not 
+    /// sanitized, see sanitize_file_stems 
+    /// ## Example 
+    ///```rust
+    /// use ppgg::writers::NameTemplate; 
+    /// assert_eq!(NameTemplate::parse("tx-{transcript}|{proband}_{haplotype}").unwrap().render_file_name("ENST1"),"tx-ENST1"); 
+    /// assert_eq!(NameTemplate::parse("{proband}_{haplotype}").unwrap().render_file_name("ENST1"),"ENST1"); 
+    ///```
+    pub fn render_file_name(&self, transcript:&str)->String
+    {
+        let position=match self.parts.iter().position(|part|*part==TemplatePart::Field(TemplateField::Transcript))
+        {
+            Some(position)=>position,
+            None=>return transcript.to_string()
+        };
+        let mut name=String::new(); 
+        if let (1,TemplatePart::Literal(text))=(position,&self.parts[0])
+        {
+            name.push_str(text); 
+        }
+        name.push_str(transcript); 
+        if let Some(TemplatePart::Literal(text))=self.parts.get(position+1).filter(|_|position+2==self.parts.len())
+        {
+            name.push_str(text); 
+        }
+        name
+    }
+}
+
+/// ## Summary 
+/// The layout of the personalized fasta files and the template of their record names, the template must contain the fields required by
+/// the layout, see OutputLayout::get_required_fields, so the record names are unique within each file. The layout is passed to the writers 
+/// through WriterOptions. 
+/// ## Example 
+///```rust
+/// use ppgg::writers::{FastaLayout,NameTemplate,OutputLayout,WriterOptions}; 
+/// let template=NameTemplate::parse("{proband}_{haplotype}_{transcript}").unwrap(); 
+/// assert!(FastaLayout::new(OutputLayout::Combined,template.clone()).is_ok()); 
+/// assert!(FastaLayout::new(OutputLayout::PerTranscript,NameTemplate::default()).is_err()); 
+/// assert_eq!(WriterOptions::default().fasta_layout,FastaLayout::new(OutputLayout::PerProband,NameTemplate::default()).unwrap()); 
+///```
+#[derive(Debug,Clone,PartialEq,Eq,Default)]
+pub struct FastaLayout
+{
+    pub layout:OutputLayout,
+    pub template:NameTemplate
+}
+impl FastaLayout
+{
+    /// ## Summary 
+    /// Create a layout, returns an error if the template misses a field required by the layout 
+    pub fn new(layout:OutputLayout, template:NameTemplate)->Result<Self,String>
+    {
+        let missing=layout.get_required_fields().iter()
+            .filter(|field|!template.contains(**field))
+            .map(|field|field.as_str())
+            .collect::<Vec<&str>>(); 
+        match missing.is_empty()
+        {
+            true=>Ok(FastaLayout{layout,template}),
+            false=>Err(format!("The name template: {} must contain {} with the {} layout, as the record names of a file must be unique",
+                template.as_str(),missing.join(" and "),layout.as_str()))
+        }
+    }
+}
+/// ## Summary 
+/// Write fasta records, i.e. the header and the sequence of each record, grouped by proband to a file with the provided stem, e.g. the 
+/// records of a transcript across the probands, see OutputLayout::PerTranscript, sequences shorter than min_length are dropped and the 
+/// number of dropped sequences of each group is returned in the order of the groups 
+pub fn write_grouped_fasta(path2file:&Path, file_stem:&str, records:&[Vec<(String,&str)>], write_compressed:bool, min_length:usize, 
+    options:&WriterOptions)->Result<Vec<u64>,String>
+{
+    let path2file=path2file.join(options.compression_policy.get_fasta_name(file_stem, write_compressed));
+    let expected_size=records.iter().flatten().map(|(header,seq)|header.len()+seq.len()+3).sum::<usize>(); 
+    let mut writer=create_output_writer(&path2file, expected_size, write_compressed, options)?; 
+    let mut num_dropped=Vec::with_capacity(records.len()); 
+    for group in records.iter()
+    {
+        let mut dropped=0; 
+        for (header,seq) in group.iter()
+        {
+            let seq=StopCodonPolicy::apply_optional(options.stop_codon_policy, seq); 
+            if seq.len()<min_length
+            {
+                dropped+=1; 
+                continue; 
+            }
+            if let Err(err_msg)=write!(writer,">{}\n{}\n",header,seq)
+            {
+                return Err(format!("Writing the sequence of: {} to {} failed because {}",header,path2file.display(),err_msg))
+            }
+        }
+        num_dropped.push(dropped); 
+    }
+    flush_output(&mut writer, &path2file.display().to_string())?; 
+    Ok(num_dropped)
+}
+
+/// ## Summary 
+/// Write the provided earlymap representation into a json file, the function create a directory and write 
+/// a JSON file per patient in the directory, the function returns an error if the directory already exists.
+pub fn write_earlymap2json(path2write:&Path, vec_earlymap: &Vec<Map::EarlyMap> , options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the early map of each proband")?; 
+    match create_dir(path2write)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Creating the output directory failed because of: {} ",err_msg))
+    };
+    let file_stems=sanitize_file_stems(&vec_earlymap.iter().map(|e_map|e_map.get_proband_name().as_str()).collect::<Vec<&str>>()); 
+    for (e_map,file_stem) in vec_earlymap.iter().zip(file_stems.iter())
+    {
+        let mut temp_path=path2write.clone().to_path_buf(); 
+        temp_path.push(format!("{}.json",file_stem)); 
+        let writer= match create_file(temp_path.as_path(), &options.retry_policy)
+        {
+            Ok(file)=>file,
+            Err(err_msg)=>return Err(format!("Creating a file for {} failed with the following error message",err_msg))
+        };
+        serde_json::to_writer(writer, e_map).unwrap();
+    }
+    Ok(())
+}
+/// ## Summary 
+/// The layout of the written intermediate maps, with Json, which is the default, a JSON file is written per proband, 
+/// while with Ndjson all maps are written to a single file with one JSON document per line, i.e. newline-delimited JSON, 
+/// which avoids creating one file per proband for large cohorts and can be read line by line 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum IntMapFormat
+{
+    Json,
+    Ndjson
+}
+impl Default for IntMapFormat
+{
+    fn default()->Self
+    {
+        IntMapFormat::Json
+    }
+}
+impl FromStr for IntMapFormat
+{
+    type Err=String; 
+    fn from_str(input_str:&str)->Result<Self,Self::Err>
+    {
+        match input_str
+        {
+            "json" | "JSON"=>Ok(IntMapFormat::Json),
+            "ndjson" | "NDJSON"=>Ok(IntMapFormat::Ndjson),
+            _=>Err(format!("The provided format of the intermediate maps: {} is not supported, the supported formats are: json and ndjson",input_str))
+        }
+    }
+}
+/// ## Summary 
+/// Write the provided intermediate representation into a json file, the function create a directory and write 
+/// a JSON file per patient in the directory, the function returns an error if the directory already exists, 
+/// see write_intmaps for the other formats 
+/// ## Example 
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::writers::{write_intmap2json,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_write_intmap2json_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// let int_map_test=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let path2write=output_dir.join("int_maps");
+/// let _=std::fs::remove_dir_all(&path2write);
+/// write_intmap2json(&path2write,&int_map_test,&WriterOptions::default()).unwrap();
+///```
+pub fn write_intmap2json(path2write:&Path, vec_intmap: &Vec<Map::IntMap>, options:&WriterOptions)->Result<(),String>
+{
+    write_intmaps(path2write, vec_intmap, IntMapFormat::Json, options)
+}
+/// ## Summary 
+/// Write the provided intermediate representations into a newly created directory using the provided format, i.e. a JSON file named 
+/// after each proband, see sanitize_file_stems, or a single int_maps.ndjson file with one line per proband, in the order of the maps. 
+/// Each map is serialized directly into a buffered file, hence, no intermediate string of the map is created. The function returns an 
+/// error if the directory already exists. 
+/// ## Example 
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::writers::{write_intmaps,IntMapFormat,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_write_intmaps_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// let vec_int_repr=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let path2write=output_dir.join("int_maps");
+/// let _=std::fs::remove_dir_all(&path2write);
+/// write_intmaps(&path2write,&vec_int_repr,IntMapFormat::Ndjson,&WriterOptions::default()).unwrap();
+/// let lines=std::fs::read_to_string(path2write.join("int_maps.ndjson")).unwrap();
+/// assert_eq!(lines.lines().count(),vec_int_repr.len());
+///```
+pub fn write_intmaps(path2write:&Path, vec_intmap:&[Map::IntMap], format:IntMapFormat, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the intermediate map of each proband")?; 
+    match create_dir(path2write)
+    {
+        Ok(_)=>(),
+        Err(err_msg)=>return Err(format!("Creating the output directory failed because of: {} ",err_msg))
+    };
+    match format
+    {
+        IntMapFormat::Json=>
+        {
+            let file_stems=sanitize_file_stems(&vec_intmap.iter().map(|i_map|i_map.get_name().as_str()).collect::<Vec<&str>>()); 
+            for (i_map,file_stem) in vec_intmap.iter().zip(file_stems.iter())
+            {
+                let mut writer=create_buffered_file(&path2write.join(format!("{}.json",file_stem)), &options.retry_policy)?; 
+                write_json_document(&mut writer, i_map)?; 
+                flush_writer(writer)?; 
+            }
+        },
+        IntMapFormat::Ndjson=>
+        {
+            let mut writer=create_buffered_file(&path2write.join("int_maps.ndjson"), &options.retry_policy)?; 
+            for i_map in vec_intmap.iter()
+            {
+                write_json_document(&mut writer, i_map)?; 
+                match writer.write_all(b"\n")
+                {
+                    Ok(_)=>(),
+                    Err(err_msg)=>return Err(format!("Writing the intermediate map of {} failed with the following error: {}",i_map.get_name(),err_msg))
+                };
+            }
+            flush_writer(writer)?; 
+        }
+    }
+    Ok(())
+}
+/// ## Summary 
+/// The format of the per-sample tables of the applied changes, see write_applied_changes, i.e. either a tab-separated table, the default, 
+/// with one change per line or a JSON array with one object per change. 
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum ChangesFormat
+{
+    Tsv,
+    Json
+}
+impl Default for ChangesFormat
+{
+    fn default()->Self
+    {
+        ChangesFormat::Tsv
+    }
+}
+impl ChangesFormat
+{
+    /// ## Summary 
+    /// Return the name of the format as used on the command line 
+    pub fn as_str(&self)->&'static str
+    {
+        match self
+        {
+            ChangesFormat::Tsv=>"tsv",
+            ChangesFormat::Json=>"json"
+        }
+    }
+    /// ## Summary 
+    /// Return the extension of the files written in the format 
+    pub fn get_extension(&self)->&'static str
+    {
+        match self
+        {
+            ChangesFormat::Tsv=>"changes.tsv",
+            ChangesFormat::Json=>"changes.json"
+        }
+    }
+}
+impl FromStr for ChangesFormat
+{
+    type Err=String;
+    fn from_str(format:&str)->Result<ChangesFormat,String>
+    {
+        match format.to_lowercase().as_str()
+        {
+            "tsv"=>Ok(ChangesFormat::Tsv),
+            "json"=>Ok(ChangesFormat::Json),
+            _=>Err(format!("{} is not a supported format for the table of applied changes, supported formats are: tsv and json",format))
+        }
+    }
+}
+/// ## Summary 
+/// Write the changes applied to the personalized sequences of a sample to the provided file, either as a tab-separated table with a header 
+/// line or as a JSON array, see AppliedChange for the columns. 
+/// ## Example 
+///```rust
+/// use std::path::Path; 
+/// use ppgg::data_structures::InternalRep::variant_feature::VariantFeature;
+/// use ppgg::data_structures::InternalRep::coordinates::{RefPos0,Len};
+/// use ppgg::writers::{ChangesFormat,WriterOptions,write_applied_changes}; 
+/// let change=VariantFeature::new("T1".to_string(),'M',RefPos0::new(1),RefPos0::new(1),Len::new(1))
+///     .with_reference_length(Len::new(1)).to_applied_change(1,"MKLV","MQLV");
+/// let path2file=std::env::temp_dir().join("doc_sample.changes.tsv"); 
+/// write_applied_changes(&path2file, &[change], ChangesFormat::Tsv, &WriterOptions::default()).unwrap(); 
+/// assert_eq!(std::fs::read_to_string(&path2file).unwrap().lines().count(),2); 
+///```
+pub fn write_applied_changes(path2file:&Path, changes:&[AppliedChange], format:ChangesFormat, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the tables of applied changes")?; 
+    let mut writer=create_buffered_file(path2file, &options.retry_policy)?; 
+    let results=match format
+    {
+        ChangesFormat::Tsv=>
+        {
+            let mut lines=Vec::with_capacity(changes.len()+1); 
+            lines.push(AppliedChange::TSV_HEADER.to_string()); 
+            lines.extend(changes.iter().map(|change|change.to_tsv())); 
+            writeln!(writer,"{}",lines.join("\n")).map_err(|err_msg|err_msg.to_string())
+        },
+        ChangesFormat::Json=>serde_json::to_writer_pretty(&mut writer, changes).map_err(|err_msg|err_msg.to_string())
+    }; 
+    match results.and_then(|_|writer.flush().map_err(|err_msg|err_msg.to_string()))
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Writing the applied changes to: {} failed with the following error: {}",path2file.display(),err_msg))
+    }
+}
+/// ## Summary 
+/// Create a file with the provided retry policy and wrap it into a buffered writer 
+fn create_buffered_file(path2file:&Path, policy:&RetryPolicy)->Result<BufWriter<RetryFile>,String>
+{
+    match create_file(path2file, policy)
+    {
+        Ok(file)=>Ok(BufWriter::new(file)),
+        Err(err_msg)=>Err(format!("Creating the file: {} failed with the following error: {}",path2file.display(),err_msg))
+    }
+}
+/// ## Summary 
+/// Serialize an intermediate map as a compact JSON document, i.e. a single line, into the writer 
+fn write_json_document(writer:&mut BufWriter<RetryFile>, i_map:&Map::IntMap)->Result<(),String>
+{
+    let mut serializer=serde_json::Serializer::new(writer); 
+    match i_map.serialize(&mut serializer)
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Serializing the intermediate map of {} failed with the following error: {}",i_map.get_name(),err_msg))
+    }
+}
+/// ## Summary 
+/// Flush a buffered writer, as errors are silently ignored when a buffered writer is dropped 
+fn flush_writer<W:Write>(mut writer:BufWriter<W>)->Result<(),String>
+{
+    match writer.flush()
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Flushing the written intermediate maps failed with the following error: {}",err_msg))
+    }
+}
+/// ## Summary 
+/// Return the rows of a statistics table sorted by their key, e.g. the proband or the transcript name, hence, the tables of identical runs 
+/// are identical regardless of the iteration order of the hash map 
+fn get_sorted_rows<T>(stats_table:&HashMap<String,T>)->Vec<(&String,&T)>
+{
+    let mut rows=stats_table.iter().collect::<Vec<(&String,&T)>>(); 
+    rows.sort_unstable_by_key(|(key,_)|*key); 
+    rows
+}
+/// ## Summary 
+/// Write the generated number of mutations per proband to a file 
+/// ## Example 
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::functions::summary;
+/// use ppgg::writers::{write_num_number_mutation_per_proband,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_number_of_mutations_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// let int_map_test=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let test_case=summary::compute_number_mutation_per_proband(&int_map_test); 
+/// write_num_number_mutation_per_proband(&output_dir, test_case, &WriterOptions::default()).unwrap();
+/// assert!(output_dir.join("number_of_mutations_per_proband.tsv").exists());
+///```
+pub fn write_num_number_mutation_per_proband(path2file:&Path,stats_table:HashMap<String,u64>, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the number of mutations per proband")?; 
+    // set the path 2 buffer 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("number_of_mutations_per_proband"); 
+    pathbuf.set_extension("tsv"); 
+    // create the file
+    let mut file_handle=match create_file(pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",path2file, err_msg))    
+    };
+    // write the file 
+    write!(&mut file_handle,"Proband Name \t Number of mutations\n").unwrap();
+    for (key,state) in get_sorted_rows(&stats_table)
+    {
+        write!(&mut file_handle,"{},\t{}\n", key, state).unwrap(); 
+    }
+    Ok(())
+}
+/// write a TSV table containing the number of mutation per probands 
+/// ## Example
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::functions::summary;
+/// use ppgg::writers::{write_type_mutations_per_patient,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_type_of_mutations_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// let int_map_test=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let test_case=summary::compute_type_mutations_per_patient(&int_map_test); 
+/// write_type_mutations_per_patient(&output_dir, test_case, &WriterOptions::default()).unwrap();
+/// assert!(output_dir.join("type_of_mutations_per_patient.tsv").exists());
+///```
+pub fn write_type_mutations_per_patient(path2file:&Path,stats_table:HashMap<String,Vec<u64>>, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the type of mutations per proband")?; 
+    // create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("type_of_mutations_per_patient"); 
+    pathbuf.set_extension("tsv");
+    // create a mutable handle to write the results 
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}", pathbuf, err_msg))    
+    };
+    // write the files 
+    write!(&mut file_handle,"Proband Name\t").unwrap();
+    for mutation in Constants::SUP_TYPE.iter()
+    {
+        write!(&mut file_handle,"{}\t",mutation).unwrap();
+    }
+    for (key,stats) in get_sorted_rows(&stats_table)
+    {
+        write!(&mut file_handle, "{}\t",key).unwrap();
+        for stat in stats
+        {
+            write!(&mut file_handle,"{}\t",stat).unwrap(); 
+        } 
+    }
+    Ok(())
+}
+/// write a TSV table containing number of mutations per transcript 
+/// ## Example 
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::functions::summary;
+/// use ppgg::writers::{write_number_of_mutations_per_transcript,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_mutations_per_transcript_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let path2vcf=output_dir.join("demo.vcf");
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// let int_map_test=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let test_case=summary::compute_number_of_mutations_per_transcript(&int_map_test); 
+/// write_number_of_mutations_per_transcript(&output_dir, test_case, &WriterOptions::default()).unwrap();
+/// assert!(output_dir.join("number_of_mutations_per_transcript.tsv").exists());
+///```
+pub fn write_number_of_mutations_per_transcript(path2file:&Path,stats_table:HashMap<String,u64>, options:&WriterOptions)->Result<(),String>
+{
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("number_of_mutations_per_transcript"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    write!(&mut file_handle,"Transcript Name \t Number of mutations\n").unwrap();
+    for (key,state) in get_sorted_rows(&stats_table)
+    {
+        write!(&mut file_handle,"{},\t{}\n", key, state).unwrap(); 
+    }
+    Ok(())
+}
+
+/// write a TSV table containing the number of sequences per proband that were shorter than the minimum length and have not been written 
+/// ## Example 
+///```rust
+/// use std::collections::HashMap;
+/// use ppgg::writers::{write_number_of_dropped_sequences_per_proband,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_dropped_sequences_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let mut dropped=HashMap::new(); 
+/// dropped.insert("proband_1".to_string(),5); 
+/// write_number_of_dropped_sequences_per_proband(&output_dir, dropped, &WriterOptions::default()).unwrap();
+/// let table=std::fs::read_to_string(output_dir.join("number_of_dropped_sequences_per_proband.tsv")).unwrap();
+/// assert!(table.contains("proband_1"));
+///```
+pub fn write_number_of_dropped_sequences_per_proband(path2file:&Path,stats_table:HashMap<String,u64>, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the number of dropped sequences per proband")?; 
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("number_of_dropped_sequences_per_proband"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name \t Number of dropped sequences").unwrap();
+    for (key,state) in get_sorted_rows(&stats_table)
+    {
+        writeln!(&mut file_handle,"{},\t{}", key, state).unwrap(); 
+    }
+    Ok(())
+}
+
+/// write a TSV table containing the mutational load per proband normalized by the number of callable transcripts and by the proteome length
+/// ## Example 
+///```rust
+/// use ppgg::{io, Engine};
+/// use ppgg::functions::summary;
+/// use ppgg::writers::{write_normalized_mutation_load_per_proband,WriterOptions};
+/// let output_dir=std::env::temp_dir().join("vcf2prot_normalized_load_doc_test");
+/// std::fs::create_dir_all(&output_dir).unwrap();
+/// let (path2vcf,path2fasta)=(output_dir.join("demo.vcf"),output_dir.join("demo_reference.fasta"));
+/// std::fs::write(&path2vcf,ppgg::demo::DEMO_VCF).unwrap();
+/// std::fs::write(&path2fasta,ppgg::demo::DEMO_REFERENCE).unwrap();
+/// let int_map_test=io::parse_vcf(&path2vcf,Engine::ST,None).unwrap();
+/// let ref_seq=io::read_fasta(&path2fasta,Engine::ST);
+/// let test_case=summary::compute_normalized_mutation_load(&int_map_test,&ref_seq); 
+/// write_normalized_mutation_load_per_proband(&output_dir, test_case, &WriterOptions::default()).unwrap();
+/// assert!(output_dir.join("normalized_mutation_load_per_proband.tsv").exists());
+///```
+pub fn write_normalized_mutation_load_per_proband(path2file:&Path,stats_table:HashMap<String,MutationLoad>, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the mutational load per proband")?; 
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("normalized_mutation_load_per_proband"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tNumber of edits\tNumber of altered transcripts\tNumber of callable transcripts\t\
+    Proteome length\tEdits per callable transcript\tEdits per kb of diploid proteome").unwrap();
+    let mut probands=stats_table.keys().collect::<Vec<&String>>(); 
+    probands.sort(); 
+    for proband in probands
+    {
+        let load=&stats_table[proband]; 
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{:.6}\t{:.6}", proband, load.num_edits, load.num_altered_transcripts,
+            load.num_callable_transcripts, load.proteome_length, load.edits_per_transcript, load.edits_per_kb).unwrap(); 
+    }
+    Ok(())
+}
+/// ## Summary 
+/// Write a TSV table containing, for every transcript of the reference, the number of samples with at least one variant of the transcript and 
+/// the distinct positions of these variants to a file named transcript_decoration.tsv, the positions are written comma-separated or as - if the 
+/// transcript has no variant, see summary::compute_transcript_decoration for the order of the transcripts 
+pub fn write_transcript_decoration(path2file:&Path,decoration:&[TranscriptDecoration], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the transcript decoration")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("transcript_decoration"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Transcript\tLength\tSamples with variants\tDistinct variant positions\tPositions").unwrap();
+    for entry in decoration.iter()
+    {
+        let positions=match entry.positions.is_empty()
+        {
+            true=>"-".to_string(),
+            false=>entry.positions.iter().map(|pos|pos.to_string()).collect::<Vec<String>>().join(",")
+        };
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}", entry.transcript, entry.length, entry.num_samples, entry.positions.len(), positions).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+/// ## Summary 
+/// Write a TSV table containing the number of transcripts with variants on the first haplotype only, on the second haplotype only and on 
+/// both haplotypes for each proband to a file named single_haplotype_transcripts_per_proband.tsv, see summary::compute_haplotype_spread 
+pub fn write_haplotype_spread_per_proband(path2file:&Path,stats_table:HashMap<String,HaplotypeSpread>, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the single-haplotype transcripts per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("single_haplotype_transcripts_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tHaplotype 1 only\tHaplotype 2 only\tBoth haplotypes").unwrap();
+    let mut probands=stats_table.keys().collect::<Vec<&String>>(); 
+    probands.sort(); 
+    for proband in probands
+    {
+        let spread=&stats_table[proband]; 
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}", proband, spread.num_haplotype1_only, spread.num_haplotype2_only, spread.num_both).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Write the diagnostics of the execution of each haplotype of each proband to a file named execution_status_per_proband.tsv, i.e. the status 
+/// of the haplotype, the number of applied instructions, executed tasks, clamped tasks and unwritten residues along with the skipped, the 
+/// recovered and the rejected transcripts, see gir::ExecutionReport 
+pub fn write_execution_reports(path2file:&Path,genomes:&[PersonalizedGenome], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the execution status per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("execution_status_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tHaplotype\tStatus\tApplied instructions\tExecuted tasks\tClamped tasks\tUnfilled residues\tSkipped transcripts\tRecovered transcripts\tRejected transcripts").unwrap();
+    for genome in genomes.iter()
+    {
+        let (report1,report2)=genome.get_execution_reports(); 
+        for (haplotype,report) in [(1,report1),(2,report2)]
+        {
+            writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", genome.get_proband_name(), haplotype, report.get_status().as_str(), 
+                report.num_applied_instructions, report.num_executed_tasks, report.clamps.len(), report.num_unfilled_residues, 
+                report.skipped_transcripts.join(","), report.recovered_transcripts.join(","), report.rejected_transcripts.join(",")).unwrap(); 
+        }
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// write a TSV table containing the metrics accumulated per proband while the run was parsed and executed, i.e. the decoded, parsed and 
+/// dropped consequences along with the executed transcripts, mutations, instructions and tasks, and the clamped, skipped and rejected 
+/// transcripts, see metrics::MetricsAccumulator, the probands are sorted by their name 
+pub fn write_execution_metrics_per_proband(path2file:&Path,snapshot:&MetricsSnapshot, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the execution metrics per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("execution_metrics_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tDecoded consequences\tParsed mutations\tDropped consequences\tExecuted transcripts\tExecuted mutations\tApplied instructions\tExecuted tasks\tClamped tasks\tSkipped transcripts\tRejected transcripts").unwrap();
+    let mut proband_names=snapshot.probands.keys().collect::<Vec<&String>>(); 
+    proband_names.sort(); 
+    for proband_name in proband_names
+    {
+        let metrics=&snapshot.probands[proband_name]; 
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", proband_name, metrics.num_decoded_consequences, metrics.num_parsed_mutations, 
+            metrics.get_num_dropped_consequences(), metrics.num_altered_transcripts, metrics.get_num_executed_mutations(), metrics.num_applied_instructions, 
+            metrics.num_executed_tasks, metrics.num_clamped_tasks, metrics.num_skipped_transcripts, metrics.num_rejected_transcripts).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// The name of the JSON document holding the cohort statistics in the output directory, see write_cohort_statistics 
+pub const COHORT_STATISTICS_FILE_NAME:&str="cohort_statistics.json"; 
+
+/// ## Summary 
+/// Write the statistics of a cohort run, see summary::CohortStatistics, as four TSV tables, i.e. stats_per_proband.tsv, stats_per_gene.tsv, 
+/// stats_per_transcript.tsv and stats_per_consequence.tsv, along with a single JSON document holding all of them, i.e. cohort_statistics.json, 
+/// transcripts without a gene symbol are written with - as their gene 
+pub fn write_cohort_statistics(path2file:&Path,statistics:&CohortStatistics, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the cohort statistics")?; 
+    let mut tables=Vec::with_capacity(4); 
+    let mut lines=vec!["Proband Name\tAltered transcripts\tMutations\tMissing transcripts\tMissing mutations\tDropped consequences\tSkipped transcripts".to_string()]; 
+    lines.extend(statistics.probands.iter().map(|proband|format!("{}\t{}\t{}\t{}\t{}\t{}\t{}",proband.proband_name,proband.num_altered_transcripts,
+        proband.num_mutations,proband.num_missing_transcripts,proband.num_missing_mutations,proband.num_dropped_consequences,proband.num_skipped_transcripts))); 
+    tables.push(("stats_per_proband.tsv",lines)); 
+    let mut lines=vec!["Gene\tAltered transcripts\tCarriers\tMutations".to_string()]; 
+    lines.extend(statistics.genes.iter().map(|gene|format!("{}\t{}\t{}\t{}",gene.gene,gene.num_transcripts,gene.num_carriers,gene.num_mutations))); 
+    tables.push(("stats_per_gene.tsv",lines)); 
+    let mut lines=vec!["Transcript\tGene\tIn reference\tCarriers\tAltered haplotypes\tMutations".to_string()]; 
+    lines.extend(statistics.transcripts.iter().map(|transcript|format!("{}\t{}\t{}\t{}\t{}\t{}",transcript.transcript,transcript.gene.as_deref().unwrap_or("-"),
+        transcript.is_in_reference,transcript.num_carriers,transcript.num_altered_haplotypes,transcript.num_mutations))); 
+    tables.push(("stats_per_transcript.tsv",lines)); 
+    let mut lines=vec!["Consequence\tCarriers\tMutations\tMissing mutations".to_string()]; 
+    lines.extend(statistics.consequences.iter().map(|consequence|format!("{}\t{}\t{}\t{}",consequence.consequence,consequence.num_carriers,
+        consequence.num_mutations,consequence.num_missing_mutations))); 
+    tables.push(("stats_per_consequence.tsv",lines)); 
+    for (file_name,lines) in tables.into_iter()
+    {
+        let pathbuf=path2file.join(file_name); 
+        let mut writer=create_buffered_file(&pathbuf, &options.retry_policy)?; 
+        match writeln!(writer,"{}",lines.join("\n")).and_then(|_|writer.flush())
+        {
+            Ok(_)=>(),
+            Err(err_msg)=>return Err(format!("Writing the cohort statistics to: {} failed with the following error: {}",pathbuf.display(),err_msg))
+        }
+    }
+    let pathbuf=path2file.join(COHORT_STATISTICS_FILE_NAME); 
+    let mut writer=create_buffered_file(&pathbuf, &options.retry_policy)?; 
+    match serde_json::to_writer_pretty(&mut writer, statistics).map_err(|err_msg|err_msg.to_string()).and_then(|_|writer.flush().map_err(|err_msg|err_msg.to_string()))
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Writing the cohort statistics to: {} failed with the following error: {}",pathbuf.display(),err_msg))
+    }
+}
+
+/// ## Summary 
+/// Write the length delta histograms of a cohort, see summary::compute_length_deltas, to two files named length_delta_histogram_per_proband.tsv 
+/// and length_delta_histogram_per_transcript.tsv, each row holds the number of shortened, unchanged and lengthened sequences, the minimum, 
+/// mean and maximum delta and the histogram of the deltas, see summary::LENGTH_DELTA_BIN_BOUNDS 
+pub fn write_length_delta_histograms(path2file:&Path,deltas:&LengthDeltas, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the length delta histograms")?; 
+    for (file_name,name_column,histograms) in [("length_delta_histogram_per_proband.tsv","Proband Name",&deltas.probands),
+        ("length_delta_histogram_per_transcript.tsv","Transcript",&deltas.transcripts)]
+    {
+        let mut lines=Vec::with_capacity(histograms.len()+1); 
+        lines.push(format!("{}\tShortened\tUnchanged\tLengthened\tMin delta\tMean delta\tMax delta\t{}",name_column,summary::get_delta_bin_labels().join("\t"))); 
+        lines.extend(histograms.iter().map(|histogram|format!("{}\t{}\t{}\t{}\t{}\t{:.2}\t{}\t{}",histogram.name,histogram.num_shortened,histogram.num_unchanged,
+            histogram.num_lengthened,histogram.min_delta.unwrap_or(0),histogram.get_mean_delta(),histogram.max_delta.unwrap_or(0),
+            histogram.histogram.iter().map(|num|num.to_string()).collect::<Vec<String>>().join("\t")))); 
+        let pathbuf=path2file.join(file_name); 
+        let mut writer=create_buffered_file(&pathbuf, &options.retry_policy)?; 
+        match writeln!(writer,"{}",lines.join("\n")).and_then(|_|writer.flush())
+        {
+            Ok(_)=>(),
+            Err(err_msg)=>return Err(format!("Writing the length delta histograms to: {} failed with the following error: {}",pathbuf.display(),err_msg))
+        }
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Write the sex of each proband listed in the sample-sex table along with the number of its altered transcripts that were collapsed into 
+/// a single haplotype to a file named sex_chromosomes_per_proband.tsv, see sex_chromosomes::apply_sample_sexes 
+pub fn write_sex_chromosomes_per_proband(path2file:&Path,summary:&SexChromosomeSummary, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the sex chromosomes per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("sex_chromosomes_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tSex\tSingle-haplotype transcripts").unwrap();
+    let mut probands=summary.sexes.keys().collect::<Vec<&String>>(); 
+    probands.sort(); 
+    for proband in probands
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}", proband, summary.sexes[proband].as_str(), 
+            summary.num_collapsed_per_sample.get(proband).unwrap_or(&0)).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// The name of the run manifest in the output directory, see write_run_manifest 
+pub const RUN_MANIFEST_FILE_NAME:&str="run_manifest.tsv"; 
+
+/// ## Summary 
+/// Write the manifest of a run, i.e. the digest of the run settings and the file stem and the variant digest of each proband, to the output 
+/// directory, the manifest is read by the next incremental run to find the probands whose files can be reused, see functions::incremental 
+pub fn write_run_manifest(path2file:&Path,manifest:&RunManifest, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the run manifest")?; 
+    let pathbuf=path2file.join(RUN_MANIFEST_FILE_NAME); 
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    write!(&mut file_handle,"{}",manifest.to_table()).unwrap();
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// The name of the provenance manifest in the output directory, see write_provenance_manifest 
+pub const PROVENANCE_MANIFEST_FILE_NAME:&str="manifest.json"; 
+
+/// ## Summary 
+/// Write the provenance of a run, i.e. the tool version, the command line, the digests of the input files, the counts of probands and 
+/// transcripts and the time spent per stage, as a JSON document to the output directory, see provenance::ProvenanceManifest. The manifest 
+/// describes the whole cohort, hence, it is written in the aggregate-only mode as well. 
+pub fn write_provenance_manifest(path2file:&Path,manifest:&ProvenanceManifest, options:&WriterOptions)->Result<(),String>
+{
+    let pathbuf=path2file.join(PROVENANCE_MANIFEST_FILE_NAME); 
+    let mut writer=create_buffered_file(&pathbuf, &options.retry_policy)?; 
+    match serde_json::to_writer_pretty(&mut writer, manifest).map_err(|err_msg|err_msg.to_string()).and_then(|_|writer.flush().map_err(|err_msg|err_msg.to_string()))
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg)=>Err(format!("Writing the provenance manifest to: {} failed with the following error: {}",pathbuf.display(),err_msg))
+    }
+}
+
+/// ## Summary 
+/// The name of the progress file in the output directory, see start_run_progress 
+pub const RUN_PROGRESS_FILE_NAME:&str="run_progress.tsv"; 
+/// ## Summary 
+/// The progress file of a run, which is empty until the file is opened by start_run_progress, the file is shared by the writers of the 
+/// run through their options, see WriterOptions, hence, the options of another run in the same process record their samples elsewhere 
+#[derive(Debug,Default)]
+pub struct RunProgressFile
+{
+    file_handle:Mutex<Option<RetryFile>>
+}
+
+/// ## Summary 
+/// Write the provided progress, i.e. the samples that are already complete, to the progress file in the output directory and keep the file 
+/// open in the options, so every sample whose fasta file is written afterwards with these options is appended to it, see 
+/// record_run_progress. The file is removed by 
+/// finish_run_progress once the run manifest is written, hence, a progress file that is still present belongs to an interrupted run, which 
+/// can be resumed from it, see functions::incremental::RunProgress 
+pub fn start_run_progress(path2file:&Path,progress:&RunProgress, options:&WriterOptions)->Result<(),String>
+{
+    let pathbuf=path2file.join(RUN_PROGRESS_FILE_NAME); 
+    let mut file_handle=match create_file(&pathbuf, &options.retry_policy)
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    match file_handle.write_all(progress.to_table().as_bytes()).and_then(|_|file_handle.flush())
+    {
+        Ok(_)=>*options.run_progress.file_handle.lock().unwrap()=Some(file_handle),
+        Err(err_msg)=>return Err(format!("Writing the run progress: {:#?} failed due to the following error: {}",pathbuf, err_msg))
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Append a sample whose fasta file was completely written to the progress file, the line is written at once and flushed, so it survives 
+/// the process being killed. Nothing is recorded if the progress file of the options was not started, see start_run_progress 
+pub fn record_run_progress(proband_name:&str,file_stem:&str,is_truncated:bool, options:&WriterOptions)->Result<(),String>
+{
+    let entry=ProgressEntry{proband_name:proband_name.to_string(),file_stem:file_stem.to_string(),is_truncated}; 
+    match options.run_progress.file_handle.lock().unwrap().as_mut()
+    {
+        Some(file_handle)=>file_handle.write_all(entry.to_line().as_bytes()).and_then(|_|file_handle.flush())
+            .map_err(|err_msg|format!("Recording the progress of: {} failed due to the following error: {}",proband_name,err_msg)),
+        None=>Ok(())
+    }
+}
+
+/// ## Summary 
+/// Close the progress file of the options and remove it from the output directory, which is called once the run manifest is written, i.e. 
+/// the run is complete 
+pub fn finish_run_progress(path2file:&Path, options:&WriterOptions)->Result<(),String>
+{
+    options.run_progress.file_handle.lock().unwrap().take(); 
+    let pathbuf=path2file.join(RUN_PROGRESS_FILE_NAME); 
+    match std::fs::remove_file(&pathbuf)
+    {
+        Ok(_)=>Ok(()),
+        Err(err_msg) if err_msg.kind()==ErrorKind::NotFound=>Ok(()),
+        Err(err_msg)=>Err(format!("Removing the run progress: {:#?} failed due to the following error: {}",pathbuf, err_msg))
+    }
+}
+
+/// ## Summary 
+/// The name of the smoke manifest in the output directory, see write_smoke_manifest 
+pub const SMOKE_MANIFEST_FILE_NAME:&str="smoke_manifest.tsv"; 
+
+/// ## Summary 
+/// Write the files of a smoke run along with their size and digest to the output directory, see functions::smoke, the manifest only depends 
+/// on the input and the version of the library, hence, it can be compared across runs by the tests of downstream pipelines 
+pub fn write_smoke_manifest(path2file:&Path,files:&[SmokeFile], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the smoke manifest")?; 
+    let pathbuf=path2file.join(SMOKE_MANIFEST_FILE_NAME); 
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    write!(&mut file_handle,"{}",smoke_files_to_table(files)).unwrap();
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the number of missing genotype calls of each proband along with the number of calls imputed as carriers and the applied policy 
+/// to a file named missing_genotypes_per_proband.tsv, see missing_genotypes::MissingGenotypePolicy 
+pub fn write_missing_genotypes_per_proband(path2file:&Path,summary:&MissingGenotypeSummary, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the missing genotypes per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("missing_genotypes_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tMissing genotypes\tImputed as carrier\tPolicy").unwrap();
+    for ((name,num_missing),num_imputed) in summary.proband_names.iter().zip(summary.num_missing_per_sample.iter()).zip(summary.num_imputed_per_sample.iter())
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}", name, num_missing, num_imputed, summary.policy.as_str()).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the silent variants carried by the probands, i.e. their synonymous and start retained consequences, to a file named 
+/// silent_variants.tsv, where each variant is reported as a zero-change annotation of the haplotype, see silent_variants::SilentVariantPolicy 
+pub fn write_silent_variants(path2file:&Path,variants:&[SilentVariant], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the silent variants")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("silent_variants"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tHaplotype\tGene\tTranscript\tConsequence\tProtein change\tDNA change\tSequence change").unwrap();
+    for variant in variants.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}\t{}\t0", variant.proband_name, variant.haplotype, variant.gene, variant.transcript, 
+            variant.csq_type, variant.protein_change, variant.dna_change).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the number of collapsed duplicated genotype calls of each proband to a file named duplicate_records_per_proband.tsv, along with 
+/// the number of duplicated and removed records of the file, see duplicate_records::collapse_duplicate_records 
+pub fn write_duplicate_records_per_proband(path2file:&Path,summary:&DuplicateRecordSummary, options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the duplicate records per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("duplicate_records_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tCollapsed calls\tDuplicated records\tRemoved records").unwrap();
+    for (name,num_collapsed) in summary.proband_names.iter().zip(summary.num_collapsed_per_sample.iter())
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}", name, num_collapsed, summary.num_duplicate_records, summary.num_removed_records).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the transcripts and the probands that were skipped while executing in the recovery mode to a file named failures.tsv, i.e. one line
+/// per failure with the proband name, the haplotype, the transcript and the reason, where the haplotype and the transcript are "-" if the 
+/// whole proband was skipped, see exec::execute_with_recovery 
+pub fn write_execution_failures(path2file:&Path,failures:&[ExecutionFailure], options:&WriterOptions)->Result<(),String>
+{
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("failures"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tHaplotype\tTranscript\tReason").unwrap();
+    for failure in failures.iter()
+    {
+        // panic messages may span multiple lines 
+        let reason=failure.reason.split(['\t','\n','\r']).filter(|part|!part.is_empty()).collect::<Vec<&str>>().join(" "); 
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}", failure.proband_name, failure.haplotype.map_or("-".to_string(),|haplotype|haplotype.to_string()),
+            failure.transcript_name.as_deref().unwrap_or("-"), reason).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+/// ## Summary 
+/// Write the coordinate and length issues of the cohort to a file named coordinate_audit.tsv, i.e. one line per transcript, issue, delta and
+/// instruction codes with the number of haplotypes and probands that encountered it, grouped by transcript, see coordinate_audit::CoordinateAudit 
+pub fn write_coordinate_audit(path2file:&Path,audit:&CoordinateAudit, options:&WriterOptions)->Result<(),String>
+{
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("coordinate_audit"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Transcript\tIssue\tDelta\tInstruction codes\tHaplotypes\tProbands").unwrap();
+    for pattern in audit.get_patterns()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}", pattern.transcript, pattern.kind.as_str(), pattern.delta, pattern.instruction_codes,
+            pattern.num_haplotypes, pattern.num_probands).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+/// ## Summary 
+/// Write the residue mismatches of a pre-flight check per transcript to a file named reference_mismatches.tsv, i.e. the transcript, the 
+/// number of checked and mismatched positions and the first mismatch, see CompatibilityReport::get_transcript_mismatches 
+pub fn write_reference_mismatches(path2file:&Path,report:&CompatibilityReport, options:&WriterOptions)->Result<(),String>
+{
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("reference_mismatches"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Transcript\tChecked positions\tMismatched positions\tFirst mismatch\tExpected\tObserved").unwrap();
+    for mismatch in report.get_transcript_mismatches()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}", mismatch.transcript, mismatch.num_checked, mismatch.num_mismatched, 
+            mismatch.first_mismatch.position, mismatch.first_mismatch.expected, mismatch.first_mismatch.observed).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+/// ## Summary 
+/// Write the violations found by the validation pass to a file named validation_report.tsv, i.e. the proband, the haplotype, the transcript,
+/// the kind of the violation and its message, see validation::validate_representations 
+pub fn write_validation_report(path2file:&Path,report:&ValidationReport, options:&WriterOptions)->Result<(),String>
+{
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("validation_report"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    write!(&mut file_handle,"{}",report.to_table()).unwrap();
+    flush_writer(file_handle)
+}
+/// ## Summary 
+/// Write the length histogram of the altered sequences of each proband to a file named sequence_length_histogram_per_proband.tsv, i.e. the 
+/// number of sequences, their minimum, mean and maximum length and the number of sequences in each bin, see length_stats::LENGTH_BIN_BOUNDS 
+pub fn write_sequence_length_histograms(path2file:&Path,stats:&[SampleLengthStats], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the sequence length histogram per proband")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("sequence_length_histogram_per_proband"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tNumber of sequences\tMin length\tMean length\tMax length\t{}",length_stats::get_bin_labels().join("\t")).unwrap();
+    for sample in stats.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{:.2}\t{}\t{}", sample.proband_name, sample.num_sequences, sample.min_length.unwrap_or(0), 
+            sample.get_mean_length(), sample.max_length.unwrap_or(0), 
+            sample.histogram.iter().map(|num|num.to_string()).collect::<Vec<String>>().join("\t")).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the sequences with an implausible length to a file named sequence_length_anomalies.tsv, with one row per sequence holding the 
+/// responsible transcript, the haplotype, the kind of anomaly, the lengths and the codes of the applied instructions, see length_stats::get_anomaly_kind 
+pub fn write_sequence_length_anomalies(path2file:&Path,stats:&[SampleLengthStats], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the sequence length anomalies")?; 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("sequence_length_anomalies"); 
+    pathbuf.set_extension("tsv");
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband Name\tTranscript\tHaplotype\tAnomaly\tLength\tReference length\tInstruction codes").unwrap();
+    for sample in stats.iter()
+    {
+        for anomaly in sample.anomalies.iter()
+        {
+            writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}\t{}", sample.proband_name, anomaly.transcript, anomaly.haplotype, anomaly.kind.as_str(), 
+                anomaly.length, anomaly.reference_length.map_or("NA".to_string(),|length|length.to_string()), anomaly.instruction_codes).unwrap(); 
+        }
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the consequences whose genomic change is inconsistent with the genome or with the stated protein change to a file named
+/// inconsistent_annotations.tsv, with one row per consequence, see functions::effect_validation for the performed checks 
+pub fn write_inconsistent_annotations(path2file:&Path,annotations:&[InconsistentAnnotation], options:&WriterOptions)->Result<(),String>
+{
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("inconsistent_annotations"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Locus\tTranscript\tConsequence\tProtein change\tGenomic change\tReason").unwrap();
+    for annotation in annotations.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}", annotation.locus, annotation.transcript, annotation.consequence,
+            annotation.protein_change, annotation.genomic_change, annotation.reason).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Write the applied mutations along with their HGVS.p descriptions to a file named hgvs_changes.tsv, with one row per mutation 
+/// in each haplotype of each proband, positions are one-based, see functions::hgvs for the generated descriptions 
+pub fn write_hgvs_changes(path2file:&Path,changes:&[HgvsChange], options:&WriterOptions)->Result<(),String>
+{
+    options.output_policy.check_per_sample("the HGVS.p change table")?; 
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("hgvs_changes"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Proband\tHaplotype\tTranscript\tConsequence\tPosition\tHGVS.p").unwrap();
+    for change in changes.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}\t{}", change.proband_name, change.haplotype, change.transcript_name,
+            change.consequence, change.ref_position, change.hgvs_p).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// The name of the directory inside the output directory the per-sample VCF subsets are written to 
+pub const SAMPLE_VCF_DIR_NAME:&str="sample_vcfs"; 
+
+/// ## Summary 
+/// Write the VCF subset of each sample, i.e. its applied records, see SampleVcfSubsets::get_sample_lines, to a file named after the file 
+/// stem of the sample, e.g. sample_vcfs/S1.vcf, file_stems maps the proband names to their file stems, the proband name is sanitized 
+/// for the samples without a stem. The directory is reused if it exists, e.g. in incremental runs. Returns the number of written records. 
+pub fn write_sample_vcfs(path2file:&Path,subsets:&SampleVcfSubsets,file_stems:&HashMap<String,String>, options:&WriterOptions)->Result<usize,String>
+{
+    options.output_policy.check_per_sample("the per-sample VCF subsets")?; 
+    let path2dir=path2file.join(SAMPLE_VCF_DIR_NAME); 
+    match create_dir(&path2dir)
+    {
+        Ok(_)=>(),
+        Err(err_msg) if err_msg.kind()==ErrorKind::AlreadyExists=>(),
+        Err(err_msg)=>return Err(format!("Creating the directory: {} failed because of: {} ",path2dir.display(),err_msg))
+    };
+    let mut num_records=0; 
+    for sample in subsets.samples.iter()
+    {
+        let file_stem=match file_stems.get(&sample.proband_name)
+        {
+            Some(file_stem)=>file_stem.clone(),
+            None=>sanitize_file_stem(&sample.proband_name)
+        };
+        let pathbuf=path2dir.join(format!("{}.vcf",file_stem)); 
+        let mut file_handle=create_buffered_file(&pathbuf, &options.retry_policy)?; 
+        for line in subsets.get_sample_lines(sample).iter()
+        {
+            if let Err(err_msg)=writeln!(&mut file_handle,"{}",line)
+            {
+                return Err(format!("Writing the VCF subset of {} failed with the following error: {}",sample.proband_name,err_msg))
+            }
+        }
+        flush_writer(file_handle)?; 
+        num_records+=sample.record_indices.len(); 
+    }
+    Ok(num_records)
+}
+
+/// ## Summary 
+/// Write the per-gene roll-up of the protein changes to a file named gene_report.tsv, with one row per gene, where the transcripts and the 
+/// protein changes are comma-separated, the carriers are only counted and not listed, hence, the report is a cohort-level file and it can 
+/// be written in the aggregate-only mode 
+pub fn write_gene_report(path2file:&Path,report:&[GeneSummary], options:&WriterOptions)->Result<(),String>
+{
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("gene_report"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>BufWriter::new(file),
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Gene\tNumber of carriers\tNumber of protein changes\tTranscripts\tProtein changes").unwrap();
+    for summary in report.iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}\t{}\t{}", summary.gene, summary.num_carriers(), summary.protein_changes.len(),
+            summary.transcripts.join(","), summary.protein_changes.join(",")).unwrap(); 
+    }
+    flush_writer(file_handle)
+}
+
+/// ## Summary 
+/// Write the cohort-level summary of the aggregate-only mode to a file named cohort_summary.tsv, with one row per metric, 
+/// see CohortAggregate::write_unique_sequences for the definition of the metrics 
+pub fn write_cohort_summary(path2file:&Path,summary:&AggregateSummary, options:&WriterOptions)->Result<(),String>
+{
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("cohort_summary"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Metric\tValue").unwrap();
+    for (metric,value) in [("Number of probands",summary.num_probands),("Minimum number of carriers",summary.min_carriers),
+        ("Number of altered transcripts",summary.num_altered_transcripts),("Number of reported transcripts",summary.num_reported_transcripts),
+        ("Number of unique sequences",summary.num_unique_sequences),("Number of reported sequences",summary.num_reported_sequences),
+        ("Number of suppressed sequences",summary.num_suppressed_sequences),("Number of dropped sequences",summary.num_dropped_sequences)].iter()
+    {
+        writeln!(&mut file_handle,"{}\t{}", metric, value).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Write the number of carriers and the number of reported unique sequences of each transcript carried by at least min_carriers probands
+/// to a file named cohort_carriers_per_transcript.tsv, transcripts with less carriers are not written 
+pub fn write_cohort_carriers_per_transcript(path2file:&Path,aggregate:&CohortAggregate,min_carriers:u64, options:&WriterOptions)->Result<(),String>
+{
+    // Create the path to store the files 
+    let mut pathbuf=PathBuf::from(path2file); 
+    pathbuf.push("cohort_carriers_per_transcript"); 
+    pathbuf.set_extension("tsv");
+    // create a file handle
+    let mut file_handle= match create_file(&pathbuf, &options.retry_policy) 
+    {
+        Ok(file)=>file,
+        Err(err_msg)=>return Err(format!("Creating the file: {:#?} failed due to the following error: {}",pathbuf, err_msg))    
+    };
+    writeln!(&mut file_handle,"Transcript Name\tNumber of carriers\tNumber of reported sequences").unwrap();
+    for (transcript,num_carriers,num_reported) in aggregate.get_carriers_per_transcript(min_carriers)
+    {
+        writeln!(&mut file_handle,"{}\t{}\t{}", transcript, num_carriers, num_reported).unwrap(); 
+    }
+    Ok(())
+}
+
+/// ## Summary 
+/// Append custom records, e.g. contaminants, to a fasta writer after the generated records, where a custom record is skipped if its id, 
+/// i.e. the first word of the header, or its sequence is among the ids or the sequences that have already been written, or if it repeats 
+/// a previous custom record. Returns the number of skipped records.
+/// ## Example 
+///```rust
+/// use ppgg::writers::append_custom_records; 
+/// use std::collections::HashSet; 
+/// use std::io::Write; 
+/// let records=vec![("CONT_1 trypsin".to_string(),"IVGG".to_string()),("CONT_2".to_string(),"MKV".to_string()),("T1_1".to_string(),"MSC".to_string())]; 
+/// let mut buffer:Vec<u8>=Vec::new(); 
+/// {
+///     let mut writer:Box<dyn Write>=Box::new(&mut buffer); 
+///     let num_skipped=append_custom_records(&mut writer,&records,&["T1_1"].iter().copied().collect(),&["MKV"].iter().copied().collect()).unwrap(); 
+///     assert_eq!(num_skipped,2); 
+/// }
+/// assert_eq!(String::from_utf8(buffer).unwrap(),">CONT_1 trypsin\nIVGG\n"); 
+///```
+pub fn append_custom_records(writer:&mut Box<dyn Write+'_>, records:&[(String,String)], written_ids:&HashSet<&str>, written_seqs:&HashSet<&str>)->Result<u64,String>
+{
+    let mut appended_ids=HashSet::new(); 
+    let mut appended_seqs=HashSet::new(); 
+    let mut num_skipped=0; 
+    for (header,seq) in records.iter()
+    {
+        let id=header.split_whitespace().next().unwrap_or(""); 
+        if written_ids.contains(id) || written_seqs.contains(seq.as_str()) || !appended_ids.insert(id) || !appended_seqs.insert(seq.as_str())
+        {
+            num_skipped+=1; 
+            continue
+        }
+        if let Err(err_msg)=write!(writer,">{}\n{}\n",header,seq)
+        {
+            return Err(format!("Appending the custom record: {} failed because {}",header,err_msg))
+        }
+    }
+    Ok(num_skipped)
+}
+
+#[cfg(test)]
+pub mod test_json_parsing
+{
+    use super::*; 
+    use crate::data_structures::InternalRep::engines::Engine;
+    use crate::parts::io::parse_vcf; 
+    use crate::functions::summary;  
+    #[test]
+    fn test_intmap2json()
+    {
+        let int_map_test=parse_vcf(Path::new("/Users/heshamelabd/projects/test_data/test_case_int1.vcf"),Engine::ST,None).unwrap();
+        write_intmap2json(Path::new("/Users/heshamelabd/projects/test_data/test_writer"),&int_map_test, &WriterOptions::default()).unwrap();
+    }
+    #[test]
+    fn test_num_number_mutation_per_proband()
+    {
+        let int_map_test=parse_vcf(Path::new("/Users/heshamelabd/projects/test_data/test_case_int1.vcf"),Engine::ST,None).unwrap();
+        let test_case=summary::compute_number_mutation_per_proband(&int_map_test); 
+        write_num_number_mutation_per_proband(Path::new("/Users/heshamelabd/projects/test_data"), test_case, &WriterOptions::default()).unwrap();
+    }
+    #[test]
+    fn test_type_mutations_per_patient()
+    {
+        let int_map_test=parse_vcf(Path::new("/Users/heshamelabd/projects/test_data/test_case_int1.vcf"),Engine::ST,None).unwrap();
+        let test_case=summary::compute_type_mutations_per_patient(&int_map_test); 
+        write_type_mutations_per_patient(Path::new("/Users/heshamelabd/projects/test_data"), test_case, &WriterOptions::default()).unwrap();
+    }
+    #[test]
+    fn test_num_mut_per_transcript()
+    {
+        let int_map_test=parse_vcf(Path::new("/Users/heshamelabd/projects/test_data/test_case_int1.vcf"),Engine::ST,None).unwrap();
+        let test_case=summary::compute_number_of_mutations_per_transcript(&int_map_test); 
+        write_number_of_mutations_per_transcript(Path::new("/Users/heshamelabd/projects/test_data"), test_case, &WriterOptions::default()).unwrap();
+    }
+    #[test]
+    fn test_sanitize_file_stems()
+    {
+        assert_eq!(sanitize_file_stem(""),"_"); 
+        assert_eq!(sanitize_file_stem(".hidden."),"_.hidden"); 
+        assert_eq!(sanitize_file_stem("COM1.vcf"),"_COM1.vcf"); 
+        assert_eq!(sanitize_file_stem(&"x".repeat(300)).len(),MAX_FILE_STEM_LENGTH); 
+        // a generated suffix must not collide with a name that is kept as it is 
+        let stems=sanitize_file_stems(&["s/1","s_1_2","s_1","S:1","s_1"]); 
+        assert_eq!(stems,vec!["s_1_3","s_1_2","s_1","S_1_4","s_1_5"]); 
+        assert_eq!(stems.iter().map(|stem|stem.to_lowercase()).collect::<HashSet<String>>().len(),stems.len()); 
+    }
+    /// A writer that fails with EIO for the first num_failures calls 
+    struct FlakyWriter
+    {
+        num_failures:u32,
+        written:Vec<u8>
+    }
+    impl Write for FlakyWriter
+    {
+        fn write(&mut self, buf:&[u8])->std::io::Result<usize>
+        {
+            if self.num_failures!=0
+            {
+                self.num_failures-=1; 
+                return Err(std::io::Error::from_raw_os_error(5))
+            }
+            self.written.extend_from_slice(buf); 
+            Ok(buf.len())
+        }
+        fn flush(&mut self)->std::io::Result<()>
+        {
+            Ok(())
+        }
+    }
+    #[test]
+    fn test_retry_writer()
+    {
+        let policy=RetryPolicy{max_retries:2,backoff_ms:0}; 
+        let mut writer=RetryWriter{inner:FlakyWriter{num_failures:2,written:Vec::new()},policy}; 
+        writer.write_all(b">T1_1\nMKV\n").unwrap(); 
+        assert_eq!(writer.inner.written,b">T1_1\nMKV\n".to_vec()); 
+        let mut writer=RetryWriter{inner:FlakyWriter{num_failures:3,written:Vec::new()},policy}; 
+        assert_eq!(writer.write_all(b"MKV").unwrap_err().raw_os_error(),Some(5)); 
+        // a non-transient error is not retried 
+        let mut num_calls=0; 
+        let result:std::io::Result<()>=policy.run(||{num_calls+=1; Err(std::io::Error::from(ErrorKind::PermissionDenied))}); 
+        assert!(result.is_err()); 
+        assert_eq!(num_calls,1); 
+    }
+    #[test]
+    fn test_total_output_quota()
+    {
+        let total_bytes=AtomicU64::new(0); 
+        let quota=OutputQuota{max_sample_bytes:Some(8),max_total_bytes:Some(12)}; 
+        let mut sample1=quota.start_sample(&total_bytes); 
+        let mut sample2=quota.start_sample(&total_bytes); 
+        assert!(sample1.reserve(5) && sample2.reserve(5)); 
+        // the record fits into the quota of the sample but not into the total quota 
+        assert!(!sample1.reserve(3)); 
+        assert!(sample2.reserve(2)); 
+        assert!(!sample2.reserve(1)); 
+        assert_eq!((sample1.is_truncated(),sample2.is_truncated(),total_bytes.load(Ordering::SeqCst)),(true,true,12)); 
+    }
+    #[test]
+    fn test_fasta_layout()
+    {
+        for template in ["","{proband} {haplotype}","{proband}_{haplo","}{transcript}"].iter()
+        {
+            assert!(NameTemplate::parse(template).is_err()); 
+        }
+        let template=NameTemplate::parse("tx-{transcript}.v1").unwrap(); 
+        assert_eq!(template.render_file_name("ENST1"),"tx-ENST1.v1"); 
+        assert_eq!(NameTemplate::parse("{proband}-{transcript}.{haplotype}").unwrap().render_file_name("ENST1"),"ENST1"); 
+        assert!(FastaLayout::new(OutputLayout::PerProband,template).is_err()); 
+        for layout in [OutputLayout::PerProband,OutputLayout::PerTranscript,OutputLayout::Combined].iter()
+        {
+            assert_eq!(layout.as_str().parse::<OutputLayout>(),Ok(*layout)); 
+            assert!(FastaLayout::new(*layout,NameTemplate::default_for(*layout)).is_ok()); 
+        }
+        // the records are written by group and the short sequences are dropped per group 
+        let records=vec![vec![("P1_1".to_string(),"MKV"),("P1_2".to_string(),"M")],vec![("P2_1".to_string(),"MKW")]]; 
+        let out_dir=std::env::temp_dir(); 
+        assert_eq!(write_grouped_fasta(&out_dir, "grouped_fasta_test", &records, false, 2, &WriterOptions::default()).unwrap(),vec![1,0]); 
+        let written=std::fs::read_to_string(out_dir.join("grouped_fasta_test.fasta")).unwrap(); 
+        assert_eq!(written,">P1_1\nMKV\n>P2_1\nMKW\n"); 
+    }
+    #[test]
+    fn test_bgzf_writer()
+    {
+        use std::io::Read; 
+        let content=(0..5000).map(|index|format!(">T{}_1\nMKV{}\n",index,index*7919)).collect::<String>(); 
+        for level in [0,6,9].iter()
+        {
+            let mut writer=BgzfWriter::new(Vec::new(),*level); 
+            writer.write_all(content.as_bytes()).unwrap(); 
+            let compressed=writer.finish().unwrap(); 
+            // the file is a series of BGZF blocks of at most 64 KiB each, ending with the end-of-file block 
+            let mut offset=0; 
+            let mut num_blocks=0; 
+            while offset<compressed.len()
+            {
+                assert_eq!(&compressed[offset..offset+4],&[0x1f,0x8b,8,4]); 
+                assert_eq!(&compressed[offset+12..offset+14],b"BC"); 
+                let block_size=u16::from_le_bytes([compressed[offset+16],compressed[offset+17]]) as usize+1; 
+                assert!(block_size<=1<<16); 
+                offset+=block_size; 
+                num_blocks+=1; 
+            }
+            assert_eq!(offset,compressed.len()); 
+            assert!(num_blocks>2); 
+            assert!(compressed.ends_with(&BGZF_EOF)); 
+            let mut decompressed=String::new(); 
+            flate2::read::MultiGzDecoder::new(compressed.as_slice()).read_to_string(&mut decompressed).unwrap(); 
+            assert_eq!(decompressed,content); 
+        }
+        assert!("xz".parse::<CompressionFormat>().is_err()); 
+        assert_eq!("BGZF".parse::<CompressionFormat>(),Ok(CompressionFormat::Bgzip)); 
+    }
+    #[test]
+    fn test_run_progress_per_options()
+    {
+        let output_dir=std::env::temp_dir().join("vcf2prot_run_progress_per_options_test"); 
+        let other_dir=output_dir.join("other"); 
+        std::fs::create_dir_all(&other_dir).unwrap(); 
+        let (options,other_options)=(WriterOptions::default(),WriterOptions::default()); 
+        let progress=RunProgress{settings_digest:"00ff".to_string(),entries:Vec::new()}; 
+        start_run_progress(&output_dir, &progress, &options).unwrap(); 
+        start_run_progress(&other_dir, &progress, &other_options).unwrap(); 
+        record_run_progress("S1", "S1", false, &options).unwrap(); 
+        record_run_progress("S2", "S2", true, &other_options).unwrap(); 
+        // the options that did not start a progress file record nothing 
+        record_run_progress("S3", "S3", false, &WriterOptions::default()).unwrap(); 
+        let read=|path2dir:&Path|RunProgress::from_table(&std::fs::read_to_string(path2dir.join(RUN_PROGRESS_FILE_NAME)).unwrap()).unwrap(); 
+        assert_eq!(read(&output_dir).entries.iter().map(|entry|entry.proband_name.as_str()).collect::<Vec<_>>(),vec!["S1"]); 
+        assert_eq!(read(&other_dir).entries.iter().map(|entry|(entry.proband_name.as_str(),entry.is_truncated)).collect::<Vec<_>>(),vec![("S2",true)]); 
+        finish_run_progress(&output_dir, &options).unwrap(); 
+        assert!(!output_dir.join(RUN_PROGRESS_FILE_NAME).exists() && other_dir.join(RUN_PROGRESS_FILE_NAME).exists()); 
+        std::fs::remove_dir_all(&output_dir).unwrap(); 
+    }
+}
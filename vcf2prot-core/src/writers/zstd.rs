@@ -0,0 +1,718 @@
+/// The module writes zstd frames, see RFC 8878, without an external zstd library. The written bytes are cut into blocks of at most 128 KiB,
+/// the repeated substrings of a block are replaced by matches reaching back at most 1 MiB, which are found with hash chains, the literals
+/// are coded with a Huffman code of the block and the sequences with the predefined FSE tables of the format. A block that is not shrunk
+/// by the compression is stored as is. The frames carry the XXH64 checksum of their content, hence, any zstd decompressor, e.g. zstd -d
+/// or zstdcat, reads and verifies them.
+use std::io::Write;
+
+/// The four bytes starting a zstd frame
+const MAGIC_NUMBER:[u8;4]=[0x28,0xb5,0x2f,0xfd];
+/// The log2 of the window of the frames, i.e. the largest distance of a match
+const WINDOW_LOG:u32=20;
+const WINDOW_SIZE:usize=1<<WINDOW_LOG;
+/// The maximum number of uncompressed bytes in a block, as defined by the format
+const BLOCK_SIZE:usize=1<<17;
+/// The shortest match that is encoded, shorter repeats are cheaper as literals
+const MIN_MATCH:usize=4;
+/// The estimated number of bits of a literal and of a sequence besides the extra bits of its offset, which decide whether a match is
+/// cheaper than its literals, e.g. a short match far away costs more bits than the literals it replaces
+const LITERAL_COST:usize=6;
+const SEQUENCE_COST:usize=15;
+/// The length of a match that ends the search for a longer one
+const GOOD_MATCH:usize=256;
+/// The log2 of the number of hash chains
+const HASH_LOG:u32=17;
+/// The longest Huffman code of the literals allowed by the format
+const MAX_HUFFMAN_BITS:u32=11;
+/// The largest literal that can be described by the direct representation of the Huffman weights, i.e. the literals of text files
+const MAX_HUFFMAN_SYMBOL:usize=128;
+/// The fewest literals of a block that are Huffman coded, fewer literals are stored as is
+const MIN_HUFFMAN_LITERALS:usize=32;
+/// The baselines and the number of extra bits of the literal length codes 16 to 35, the codes 0 to 15 are the lengths themselves
+const LITERAL_LENGTH_CODES:[(u32,u32);20]=[(16,1),(18,1),(20,1),(22,1),(24,2),(28,2),(32,3),(40,3),(48,4),(64,6),(128,7),(256,8),(512,9),
+    (1024,10),(2048,11),(4096,12),(8192,13),(16384,14),(32768,15),(65536,16)];
+/// The baselines and the number of extra bits of the match length codes 32 to 52, the codes 0 to 31 are the lengths minus three
+const MATCH_LENGTH_CODES:[(u32,u32);21]=[(35,1),(37,1),(39,1),(41,1),(43,2),(47,2),(51,3),(59,3),(67,4),(83,4),(99,5),(131,7),(259,8),(515,9),
+    (1027,10),(2051,11),(4099,12),(8195,13),(16387,14),(32771,15),(65539,16)];
+/// The predefined distributions of the literal lengths, the match lengths and the offsets, along with their accuracy log
+const LITERAL_LENGTH_DISTRIBUTION:(u32,[i16;36])=(6,[4,3,2,2,2,2,2,2,2,2,2,2,2,1,1,1,2,2,2,2,2,2,2,2,2,3,2,1,1,1,1,1,-1,-1,-1,-1]);
+const MATCH_LENGTH_DISTRIBUTION:(u32,[i16;53])=(6,[1,4,3,2,2,2,2,2,2,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,
+    -1,-1,-1,-1,-1,-1,-1]);
+const OFFSET_DISTRIBUTION:(u32,[i16;29])=(5,[1,1,1,1,1,1,2,2,2,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,-1,-1,-1,-1,-1]);
+
+/// ## Summary
+/// A writer of zstd files, i.e. the written bytes are compressed into the blocks of a single zstd frame with the provided level, from 0, the
+/// blocks are stored as is, to 9, the longest search for matches, a flush completes the current block and the checksum of the frame is
+/// appended when the writer is finished or dropped
+/// ## Example
+///```rust
+/// use std::io::Write;
+/// use ppgg::writers::zstd::ZstdWriter;
+/// let mut writer=ZstdWriter::new(Vec::new(),6);
+/// writer.write_all(b">T1_1\nMKV\n").unwrap();
+/// let compressed=writer.finish().unwrap();
+/// assert_eq!(&compressed[..4],&[0x28,0xb5,0x2f,0xfd]);
+///```
+pub struct ZstdWriter<W:Write>
+{
+    inner:Option<W>,
+    /// the last bytes of the previous blocks, at least a window of them if present, followed by the bytes of the current block
+    history:Vec<u8>,
+    /// the position of the first byte of the history in the frame
+    history_start:usize,
+    /// the index of the current block in the history
+    block_start:usize,
+    /// the last position of each hash plus one, zero if the hash was not seen, the positions are truncated to 32 bits
+    heads:Vec<u32>,
+    /// the previous position with the same hash of each position of the window, plus one
+    chains:Vec<u32>,
+    /// the number of candidates checked for each match, zero if the blocks are stored
+    max_candidates:usize,
+    checksum:Xxh64,
+    is_header_written:bool
+}
+impl<W:Write> ZstdWriter<W>
+{
+    /// ## Summary
+    /// Create a writer compressing the blocks with the provided level, from 0 to 9
+    pub fn new(inner:W, level:u32)->Self
+    {
+        let max_candidates=4*level.min(9) as usize;
+        ZstdWriter{inner:Some(inner),history:Vec::with_capacity(BLOCK_SIZE),history_start:0,block_start:0,heads:vec![0;1<<HASH_LOG],
+            chains:vec![0;WINDOW_SIZE],max_candidates,checksum:Xxh64::new(),is_header_written:false}
+    }
+    /// ## Summary
+    /// Write the remaining bytes, the last block and the checksum of the frame, and return the underlying writer
+    pub fn finish(mut self)->std::io::Result<W>
+    {
+        self.write_end()?;
+        Ok(self.inner.take().unwrap())
+    }
+    /// write the remaining bytes as the last block of the frame followed by its checksum
+    fn write_end(&mut self)->std::io::Result<()>
+    {
+        if self.inner.is_none()
+        {
+            return Ok(())
+        }
+        self.write_block(true)?;
+        let checksum=self.checksum.digest() as u32;
+        let inner=self.inner.as_mut().unwrap();
+        inner.write_all(&checksum.to_le_bytes())?;
+        inner.flush()
+    }
+    /// compress the bytes of the current block into a block of the frame, the frame header is written before the first block and the last
+    /// block is written even if it is empty, the bytes are stored if the compression does not shrink them
+    fn write_block(&mut self, is_last:bool)->std::io::Result<()>
+    {
+        if self.inner.is_none() || (self.block_start==self.history.len() && !is_last)
+        {
+            return Ok(())
+        }
+        let block_size=self.history.len()-self.block_start;
+        self.checksum.update(&self.history[self.block_start..]);
+        let compressed=match self.max_candidates
+        {
+            0=>None,
+            _=>Some(self.compress_block()).filter(|compressed|compressed.len()<block_size)
+        };
+        let inner=self.inner.as_mut().unwrap();
+        if !self.is_header_written
+        {
+            // no content size, no dictionary, a checksum and the window descriptor of a window of 2^WINDOW_LOG bytes
+            inner.write_all(&MAGIC_NUMBER)?;
+            inner.write_all(&[0x04,((WINDOW_LOG-10)<<3) as u8])?;
+            self.is_header_written=true;
+        }
+        match compressed
+        {
+            Some(compressed)=>
+            {
+                inner.write_all(&block_header(is_last, 2, compressed.len()))?;
+                inner.write_all(&compressed)?;
+            },
+            None=>
+            {
+                inner.write_all(&block_header(is_last, 0, block_size))?;
+                inner.write_all(&self.history[self.block_start..])?;
+            }
+        }
+        // only the last window is kept as the history of the next blocks, it is moved once the history holds two windows 
+        if self.history.len()>2*WINDOW_SIZE
+        {
+            let num_dropped=self.history.len()-WINDOW_SIZE;
+            self.history.drain(..num_dropped);
+            self.history_start+=num_dropped;
+        }
+        self.block_start=self.history.len();
+        Ok(())
+    }
+    /// return the hash of the four bytes starting at the index of the history
+    fn get_hash(&self, index:usize)->usize
+    {
+        let bytes=u32::from_le_bytes([self.history[index],self.history[index+1],self.history[index+2],self.history[index+3]]);
+        (bytes.wrapping_mul(2_654_435_761)>>(32-HASH_LOG)) as usize
+    }
+    /// add the position at the index of the history to its hash chain
+    fn insert(&mut self, index:usize)
+    {
+        let hash=self.get_hash(index);
+        let position=(self.history_start+index) as u32;
+        self.chains[position as usize&(WINDOW_SIZE-1)]=self.heads[hash];
+        self.heads[hash]=position.wrapping_add(1);
+    }
+    /// return the match of the bytes at the index of the history saving the most bits, i.e. its length and its distance, None if no match
+    /// is cheaper than its literals, the match may overlap the current position but does not extend beyond the current block
+    fn find_match(&self, index:usize)->Option<(usize,usize)>
+    {
+        let position=(self.history_start+index) as u32;
+        let max_length=self.history.len()-index;
+        let get_savings=|length:usize,distance:usize|(length*LITERAL_COST) as isize-(SEQUENCE_COST+get_num_offset_bits(distance)) as isize;
+        let mut best:Option<(usize,usize)>=None;
+        let (mut candidate,mut last_distance)=(self.heads[self.get_hash(index)],0);
+        for _ in 0..self.max_candidates
+        {
+            // a candidate is valid if it is a previous position of the window that is still part of the history
+            let distance=position.wrapping_sub(candidate.wrapping_sub(1)) as usize;
+            if candidate==0 || distance<=last_distance || distance>WINDOW_SIZE || distance>index
+            {
+                break
+            }
+            let start=index-distance;
+            let length=self.history[start..].iter().zip(self.history[index..].iter()).take(max_length).take_while(|(first,second)|first==second).count();
+            if length>=MIN_MATCH && get_savings(length,distance)>best.map_or(0,|(best_length,best_distance)|get_savings(best_length,best_distance))
+            {
+                best=Some((length,distance));
+                if length>=GOOD_MATCH || length==max_length
+                {
+                    break
+                }
+            }
+            last_distance=distance;
+            candidate=self.chains[candidate.wrapping_sub(1) as usize&(WINDOW_SIZE-1)];
+        }
+        best
+    }
+    /// return the compressed representation of the current block, i.e. its literals section and its sequences section
+    fn compress_block(&mut self)->Vec<u8>
+    {
+        let mut sequences=Vec::new();
+        let mut literals=Vec::new();
+        let (mut index,mut literals_start)=(self.block_start,self.block_start);
+        while index+MIN_MATCH<=self.history.len()
+        {
+            match self.find_match(index)
+            {
+                Some((length,distance))=>
+                {
+                    literals.extend_from_slice(&self.history[literals_start..index]);
+                    sequences.push(Sequence{literal_length:(index-literals_start) as u32,offset:distance as u32,match_length:length as u32});
+                    for matched in index..(index+length).min(self.history.len()+1-MIN_MATCH)
+                    {
+                        self.insert(matched);
+                    }
+                    index+=length;
+                    literals_start=index;
+                },
+                None=>
+                {
+                    self.insert(index);
+                    index+=1;
+                }
+            }
+        }
+        literals.extend_from_slice(&self.history[literals_start..]);
+        let mut compressed=Vec::with_capacity(self.history.len()-self.block_start);
+        encode_literals(&literals, &mut compressed);
+        encode_sequences(&sequences, &mut compressed);
+        compressed
+    }
+}
+impl<W:Write> Write for ZstdWriter<W>
+{
+    fn write(&mut self, buf:&[u8])->std::io::Result<usize>
+    {
+        let num_bytes=buf.len().min(BLOCK_SIZE-(self.history.len()-self.block_start));
+        self.history.extend_from_slice(&buf[..num_bytes]);
+        if self.history.len()-self.block_start==BLOCK_SIZE
+        {
+            self.write_block(false)?;
+        }
+        Ok(num_bytes)
+    }
+    fn flush(&mut self)->std::io::Result<()>
+    {
+        self.write_block(false)?;
+        match self.inner.as_mut()
+        {
+            Some(inner)=>inner.flush(),
+            None=>Ok(())
+        }
+    }
+}
+impl<W:Write> Drop for ZstdWriter<W>
+{
+    fn drop(&mut self)
+    {
+        // as for the gzip encoder, the errors of completing the file while dropping the writer are lost, see writers::flush_output
+        if self.inner.is_some()
+        {
+            let _=self.write_end();
+        }
+    }
+}
+
+/// ## Summary
+/// A sequence of a block, i.e. the number of literals copied before a match, the distance of the match and its length
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+struct Sequence
+{
+    literal_length:u32,
+    offset:u32,
+    match_length:u32
+}
+/// return the header of a block of the provided type, i.e. 0 for a raw and 2 for a compressed block
+fn block_header(is_last:bool, block_type:u32, block_size:usize)->[u8;3]
+{
+    let header=is_last as u32|block_type<<1|(block_size as u32)<<3;
+    [header as u8,(header>>8) as u8,(header>>16) as u8]
+}
+/// return the number of extra bits of the offset code of a distance
+fn get_num_offset_bits(distance:usize)->usize
+{
+    (31-(distance as u32+3).leading_zeros()) as usize
+}
+/// return the code of a value, its extra bits and the number of extra bits, using the baselines of the codes above the direct codes
+fn get_code(value:u32, num_direct:u32, baselines:&[(u32,u32)])->(u8,u32,u32)
+{
+    match baselines.iter().rposition(|(baseline,_)|*baseline<=value)
+    {
+        Some(index)=>(num_direct as u8+index as u8,value-baselines[index].0,baselines[index].1),
+        None=>(value as u8,0,0)
+    }
+}
+
+/// ## Summary
+/// A writer of the bitstreams of the format, the bits are packed from the lowest bit of the first byte, and the stream is closed by a set
+/// bit, as the decoder reads the stream backward starting at the last set bit
+struct BitWriter
+{
+    bytes:Vec<u8>,
+    container:u64,
+    num_bits:u32
+}
+impl BitWriter
+{
+    fn new()->Self
+    {
+        BitWriter{bytes:Vec::new(),container:0,num_bits:0}
+    }
+    fn add_bits(&mut self, value:u32, num_bits:u32)
+    {
+        self.container|=(value as u64&((1<<num_bits)-1))<<self.num_bits;
+        self.num_bits+=num_bits;
+        while self.num_bits>=8
+        {
+            self.bytes.push(self.container as u8);
+            self.container>>=8;
+            self.num_bits-=8;
+        }
+    }
+    fn finish(mut self)->Vec<u8>
+    {
+        self.add_bits(1, 1);
+        if self.num_bits!=0
+        {
+            self.bytes.push(self.container as u8);
+        }
+        self.bytes
+    }
+}
+
+/// write the literals section of a block, the literals are Huffman coded in four streams if they are numerous enough, if all of them are
+/// text, i.e. at most MAX_HUFFMAN_SYMBOL, and if the code shrinks them, otherwise they are stored as is
+fn encode_literals(literals:&[u8], output:&mut Vec<u8>)
+{
+    if let Some(compressed)=Some(literals).filter(|literals|literals.len()>=MIN_HUFFMAN_LITERALS).and_then(encode_huffman_literals)
+    {
+        if compressed.len()<literals.len()
+        {
+            output.extend_from_slice(&compressed);
+            return
+        }
+    }
+    let size=literals.len() as u32;
+    match size
+    {
+        0..=31=>output.push((size<<3) as u8),
+        32..=4095=>output.extend_from_slice(&[(0b0100|size<<4) as u8,(size>>4) as u8]),
+        _=>output.extend_from_slice(&[(0b1100|size<<4) as u8,(size>>4) as u8,(size>>12) as u8])
+    }
+    output.extend_from_slice(literals);
+}
+/// return the Huffman coded literals section, i.e. its header, the weights of the code, the jump table and the four streams, None if the
+/// literals are not all text or are a single repeated byte
+fn encode_huffman_literals(literals:&[u8])->Option<Vec<u8>>
+{
+    let mut counts=[0_u64;MAX_HUFFMAN_SYMBOL+1];
+    for literal in literals.iter()
+    {
+        *counts.get_mut(*literal as usize)?+=1;
+    }
+    let lengths=get_huffman_lengths(&counts)?;
+    let max_bits=*lengths.iter().max().unwrap();
+    let last_symbol=lengths.iter().rposition(|length|*length!=0).unwrap();
+    // the weights are stored with four bits each, the weight of the last symbol is implied by the others
+    let weights=lengths.iter().map(|length|match length {0=>0,length=>(max_bits+1-length) as u8}).collect::<Vec<u8>>();
+    let mut compressed=vec![(127+last_symbol) as u8];
+    compressed.extend(weights[..last_symbol].chunks(2).map(|pair|pair[0]<<4|pair.get(1).copied().unwrap_or(0)));
+    // the codes are assigned in the order of increasing weights, and in the order of the symbols within a weight
+    let mut codes=[0_u32;MAX_HUFFMAN_SYMBOL+1];
+    let mut next_code=0_u32;
+    for weight in 1..=max_bits as u8
+    {
+        for symbol in (0..=last_symbol).filter(|symbol|weights[*symbol]==weight)
+        {
+            codes[symbol]=next_code>>(weight-1);
+            next_code+=1<<(weight-1);
+        }
+    }
+    let stream_size=(literals.len()+3)/4;
+    let streams=literals.chunks(stream_size).map(|stream|
+    {
+        // the decoder reads the stream backward, hence, the last literal is written first
+        let mut writer=BitWriter::new();
+        stream.iter().rev().for_each(|literal|writer.add_bits(codes[*literal as usize], lengths[*literal as usize]));
+        writer.finish()
+    }).collect::<Vec<Vec<u8>>>();
+    if streams.len()!=4
+    {
+        return None
+    }
+    for stream in streams[..3].iter()
+    {
+        compressed.extend_from_slice(&(stream.len() as u16).to_le_bytes());
+    }
+    streams.iter().for_each(|stream|compressed.extend_from_slice(stream));
+    let (regenerated_size,compressed_size)=(literals.len() as u64,compressed.len() as u64);
+    let (size_format,num_bits,num_bytes)=match regenerated_size.max(compressed_size)
+    {
+        0..=1023=>(1,10,3),
+        1024..=16383=>(2,14,4),
+        _=>(3,18,5)
+    };
+    let header=2|size_format<<2|regenerated_size<<4|compressed_size<<(4+num_bits);
+    let mut section=header.to_le_bytes()[..num_bytes].to_vec();
+    section.extend_from_slice(&compressed);
+    Some(section)
+}
+/// return the lengths of the Huffman code of the symbols with the provided counts, where no code is longer than MAX_HUFFMAN_BITS, the
+/// counts are halved until the code is short enough, None if less than two symbols are present
+fn get_huffman_lengths(counts:&[u64])->Option<Vec<u32>>
+{
+    if counts.iter().filter(|count|**count!=0).count()<2
+    {
+        return None
+    }
+    let mut counts=counts.to_vec();
+    loop
+    {
+        // each node is a weight along with the symbols below it, the two lightest nodes are merged until a single node is left
+        let mut lengths=vec![0_u32;counts.len()];
+        let mut nodes=counts.iter().enumerate().filter(|(_,count)|**count!=0).map(|(symbol,count)|(*count,vec![symbol])).collect::<Vec<_>>();
+        while nodes.len()>1
+        {
+            nodes.sort_by(|first,second|second.0.cmp(&first.0));
+            let (weight1,symbols1)=nodes.pop().unwrap();
+            let (weight2,symbols2)=nodes.pop().unwrap();
+            symbols1.iter().chain(symbols2.iter()).for_each(|symbol|lengths[*symbol]+=1);
+            nodes.push((weight1+weight2,symbols1.into_iter().chain(symbols2.into_iter()).collect()));
+        }
+        if lengths.iter().all(|length|*length<=MAX_HUFFMAN_BITS)
+        {
+            return Some(lengths)
+        }
+        counts.iter_mut().filter(|count|**count!=0).for_each(|count|*count=*count/2+1);
+    }
+}
+
+/// ## Summary
+/// An FSE table built from a predefined distribution, i.e. the symbol, the baseline and the number of bits of each state as used by the
+/// decoder, along with the state of each symbol that leads to a given next state, which is used by the encoder
+struct FseTable
+{
+    accuracy_log:u32,
+    baselines:Vec<u32>,
+    num_bits:Vec<u32>,
+    /// the state of each symbol whose range of next states contains a given state
+    states:Vec<Vec<u32>>
+}
+impl FseTable
+{
+    /// build the table of a distribution as done by the decoder, the symbols with a probability of -1 take the last states
+    fn new(accuracy_log:u32, distribution:&[i16])->Self
+    {
+        let table_size=1_usize<<accuracy_log;
+        let mut symbols=vec![0_usize;table_size];
+        let mut high_threshold=table_size-1;
+        for (symbol,_) in distribution.iter().enumerate().filter(|(_,probability)|**probability==-1)
+        {
+            symbols[high_threshold]=symbol;
+            high_threshold-=1;
+        }
+        let (step,mut position)=((table_size>>1)+(table_size>>3)+3,0);
+        for (symbol,probability) in distribution.iter().enumerate().filter(|(_,probability)|**probability>0)
+        {
+            for _ in 0..*probability
+            {
+                symbols[position]=symbol;
+                position=(position+step)&(table_size-1);
+                while position>high_threshold
+                {
+                    position=(position+step)&(table_size-1);
+                }
+            }
+        }
+        let mut next_states=distribution.iter().map(|probability|probability.unsigned_abs() as u32).collect::<Vec<u32>>();
+        let (mut baselines,mut num_bits)=(vec![0;table_size],vec![0;table_size]);
+        let mut states=vec![vec![0;table_size];distribution.len()];
+        for (state,symbol) in symbols.iter().enumerate()
+        {
+            let next_state=next_states[*symbol];
+            next_states[*symbol]+=1;
+            num_bits[state]=accuracy_log-(31-next_state.leading_zeros());
+            baselines[state]=(next_state<<num_bits[state])-table_size as u32;
+            for target in baselines[state]..baselines[state]+(1<<num_bits[state])
+            {
+                states[*symbol][target as usize]=state as u32;
+            }
+        }
+        FseTable{accuracy_log,baselines,num_bits,states}
+    }
+    /// return the state of the symbol leading to the provided next state, and write the bits selecting the next state
+    fn encode(&self, symbol:u8, next_state:u32, writer:&mut BitWriter)->u32
+    {
+        let state=self.states[symbol as usize][next_state as usize];
+        writer.add_bits(next_state-self.baselines[state as usize], self.num_bits[state as usize]);
+        state
+    }
+}
+
+/// write the sequences section of a block using the predefined distributions, the sequences are written backward, as the decoder reads
+/// the bitstream from its end, and the offsets are never coded as repeated offsets
+fn encode_sequences(sequences:&[Sequence], output:&mut Vec<u8>)
+{
+    let num_sequences=sequences.len();
+    match num_sequences
+    {
+        0=>
+        {
+            output.push(0);
+            return
+        },
+        1..=127=>output.push(num_sequences as u8),
+        128..=0x7eff=>output.extend_from_slice(&[((num_sequences>>8)+128) as u8,num_sequences as u8]),
+        _=>output.extend_from_slice(&[255,(num_sequences-0x7f00) as u8,((num_sequences-0x7f00)>>8) as u8])
+    }
+    // the literal lengths, the offsets and the match lengths use the predefined mode
+    output.push(0);
+    let literal_lengths=FseTable::new(LITERAL_LENGTH_DISTRIBUTION.0, &LITERAL_LENGTH_DISTRIBUTION.1);
+    let match_lengths=FseTable::new(MATCH_LENGTH_DISTRIBUTION.0, &MATCH_LENGTH_DISTRIBUTION.1);
+    let offsets=FseTable::new(OFFSET_DISTRIBUTION.0, &OFFSET_DISTRIBUTION.1);
+    let codes=sequences.iter().map(|sequence|
+    {
+        let offset_value=sequence.offset+3;
+        let offset_code=31-offset_value.leading_zeros();
+        (get_code(sequence.literal_length, 16, &LITERAL_LENGTH_CODES),get_code(sequence.match_length-3, 32, &MATCH_LENGTH_CODES.map(|(baseline,num_bits)|(baseline-3,num_bits))),
+            (offset_code as u8,offset_value-(1<<offset_code),offset_code))
+    }).collect::<Vec<_>>();
+    let mut writer=BitWriter::new();
+    let add_extra_bits=|writer:&mut BitWriter,(literal_length,match_length,offset):&((u8,u32,u32),(u8,u32,u32),(u8,u32,u32))|
+    {
+        writer.add_bits(literal_length.1, literal_length.2);
+        writer.add_bits(match_length.1, match_length.2);
+        writer.add_bits(offset.1, offset.2);
+    };
+    // the last sequence is decoded from the initial states, which can be any state of its symbols
+    let last=&codes[num_sequences-1];
+    let mut literal_length_state=literal_lengths.states[last.0.0 as usize][0];
+    let mut match_length_state=match_lengths.states[last.1.0 as usize][0];
+    let mut offset_state=offsets.states[last.2.0 as usize][0];
+    add_extra_bits(&mut writer, last);
+    for code in codes[..num_sequences-1].iter().rev()
+    {
+        offset_state=offsets.encode(code.2.0, offset_state, &mut writer);
+        match_length_state=match_lengths.encode(code.1.0, match_length_state, &mut writer);
+        literal_length_state=literal_lengths.encode(code.0.0, literal_length_state, &mut writer);
+        add_extra_bits(&mut writer, code);
+    }
+    writer.add_bits(match_length_state, match_lengths.accuracy_log);
+    writer.add_bits(offset_state, offsets.accuracy_log);
+    writer.add_bits(literal_length_state, literal_lengths.accuracy_log);
+    output.extend_from_slice(&writer.finish());
+}
+
+const PRIME64_1:u64=0x9E37_79B1_85EB_CA87;
+const PRIME64_2:u64=0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3:u64=0x1656_67B1_9E37_79F9;
+const PRIME64_4:u64=0x85EB_CA77_C2B2_AE63;
+const PRIME64_5:u64=0x27D4_EB2F_1656_67C5;
+
+/// ## Summary
+/// The streaming XXH64 hash with a seed of zero, whose lower 32 bits are the checksum of a zstd frame
+struct Xxh64
+{
+    accumulators:[u64;4],
+    /// the bytes that do not fill a stripe of 32 bytes yet
+    buffer:Vec<u8>,
+    total_length:u64
+}
+impl Xxh64
+{
+    fn new()->Self
+    {
+        Xxh64{accumulators:[PRIME64_1.wrapping_add(PRIME64_2),PRIME64_2,0,0_u64.wrapping_sub(PRIME64_1)],buffer:Vec::with_capacity(32),total_length:0}
+    }
+    fn round(accumulator:u64, lane:u64)->u64
+    {
+        accumulator.wrapping_add(lane.wrapping_mul(PRIME64_2)).rotate_left(31).wrapping_mul(PRIME64_1)
+    }
+    fn read_u64(bytes:&[u8])->u64
+    {
+        u64::from_le_bytes([bytes[0],bytes[1],bytes[2],bytes[3],bytes[4],bytes[5],bytes[6],bytes[7]])
+    }
+    fn update(&mut self, bytes:&[u8])
+    {
+        self.total_length+=bytes.len() as u64;
+        let mut bytes=bytes;
+        if !self.buffer.is_empty()
+        {
+            let num_bytes=bytes.len().min(32-self.buffer.len());
+            self.buffer.extend_from_slice(&bytes[..num_bytes]);
+            bytes=&bytes[num_bytes..];
+            if self.buffer.len()<32
+            {
+                return
+            }
+            let stripe=std::mem::take(&mut self.buffer);
+            self.consume(&stripe);
+        }
+        let num_stripes=bytes.len()/32;
+        for stripe in bytes[..num_stripes*32].chunks(32)
+        {
+            self.consume(stripe);
+        }
+        self.buffer.extend_from_slice(&bytes[num_stripes*32..]);
+    }
+    fn consume(&mut self, stripe:&[u8])
+    {
+        for (index,accumulator) in self.accumulators.iter_mut().enumerate()
+        {
+            *accumulator=Xxh64::round(*accumulator, Xxh64::read_u64(&stripe[index*8..]));
+        }
+    }
+    fn digest(&self)->u64
+    {
+        let mut hash=match self.total_length
+        {
+            0..=31=>PRIME64_5,
+            _=>
+            {
+                let [v1,v2,v3,v4]=self.accumulators;
+                let hash=v1.rotate_left(1).wrapping_add(v2.rotate_left(7)).wrapping_add(v3.rotate_left(12)).wrapping_add(v4.rotate_left(18));
+                self.accumulators.iter().fold(hash,|hash,accumulator|(hash^Xxh64::round(0, *accumulator)).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4))
+            }
+        }.wrapping_add(self.total_length);
+        let mut remaining=&self.buffer[..];
+        while remaining.len()>=8
+        {
+            hash=(hash^Xxh64::round(0, Xxh64::read_u64(remaining))).rotate_left(27).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4);
+            remaining=&remaining[8..];
+        }
+        if remaining.len()>=4
+        {
+            let lane=u32::from_le_bytes([remaining[0],remaining[1],remaining[2],remaining[3]]) as u64;
+            hash=(hash^lane.wrapping_mul(PRIME64_1)).rotate_left(23).wrapping_mul(PRIME64_2).wrapping_add(PRIME64_3);
+            remaining=&remaining[4..];
+        }
+        for byte in remaining.iter()
+        {
+            hash=(hash^(*byte as u64).wrapping_mul(PRIME64_5)).rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+        hash^=hash>>33;
+        hash=hash.wrapping_mul(PRIME64_2);
+        hash^=hash>>29;
+        hash=hash.wrapping_mul(PRIME64_3);
+        hash^(hash>>32)
+    }
+}
+#[cfg(test)]
+mod test_zstd
+{
+    use super::*;
+    /// split a frame into its blocks, i.e. the type and the content of each block, and return them along with the checksum of the frame
+    fn get_blocks(frame:&[u8])->(Vec<(u8,Vec<u8>)>,u32)
+    {
+        assert_eq!(&frame[..4],&MAGIC_NUMBER);
+        assert_eq!(frame[4],0x04);
+        let mut offset=6;
+        let mut blocks=Vec::new();
+        loop
+        {
+            let header=u32::from_le_bytes([frame[offset],frame[offset+1],frame[offset+2],0]);
+            let (block_type,block_size)=(((header>>1)&3) as u8,(header>>3) as usize);
+            let content=match block_type
+            {
+                1=>vec![frame[offset+3];block_size],
+                _=>frame[offset+3..offset+3+block_size].to_vec()
+            };
+            offset+=3+if block_type==1 {1} else {block_size};
+            blocks.push((block_type,content));
+            if header&1==1
+            {
+                break
+            }
+        }
+        assert_eq!(offset+4,frame.len());
+        (blocks,u32::from_le_bytes([frame[offset],frame[offset+1],frame[offset+2],frame[offset+3]]))
+    }
+    #[test]
+    fn test_xxh64()
+    {
+        let mut hasher=Xxh64::new();
+        assert_eq!(hasher.digest(),0xef46db3751d8e999);
+        hasher.update(b"abc");
+        assert_eq!(hasher.digest(),0x44bc2cf5ad770999);
+    }
+    #[test]
+    fn test_zstd_writer()
+    {
+        let content=(0..20000).map(|index|format!(">T{}_1\nMKVLAAGIVALLLAAGCSS{}\n",index,index*7919)).collect::<String>();
+        // the blocks are stored as is at level 0, hence, their content is the written content
+        let mut writer=ZstdWriter::new(Vec::new(),0);
+        for chunk in content.as_bytes().chunks(5000)
+        {
+            writer.write_all(chunk).unwrap();
+        }
+        let (blocks,checksum)=get_blocks(&writer.finish().unwrap());
+        assert!(blocks.len()>2 && blocks.iter().all(|(block_type,content)|*block_type==0 && content.len()<=BLOCK_SIZE));
+        assert_eq!(blocks.iter().flat_map(|(_,content)|content.iter().copied()).collect::<Vec<u8>>(),content.as_bytes());
+        let mut hasher=Xxh64::new();
+        hasher.update(content.as_bytes());
+        assert_eq!(checksum,hasher.digest() as u32);
+        // the repeated records are compressed, the checksum does not depend on the level
+        for level in [1,6,9].iter()
+        {
+            let mut writer=ZstdWriter::new(Vec::new(),*level);
+            writer.write_all(content.as_bytes()).unwrap();
+            let frame=writer.finish().unwrap();
+            assert!(frame.len()*3<content.len());
+            let (blocks,level_checksum)=get_blocks(&frame);
+            assert!(blocks.iter().filter(|(_,content)|!content.is_empty()).all(|(block_type,_)|*block_type==2));
+            assert_eq!(level_checksum,checksum);
+        }
+        // an empty frame holds a single empty block
+        let (blocks,_)=get_blocks(&ZstdWriter::new(Vec::new(),3).finish().unwrap());
+        assert_eq!(blocks,vec![(0,Vec::new())]);
+    }
+}